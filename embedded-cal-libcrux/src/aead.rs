@@ -4,7 +4,8 @@
 use libcrux_aesgcm::AeadConsts as _;
 use libcrux_traits::aead::typed_owned;
 
-use embedded_cal::AeadProvider;
+use embedded_cal::{AeadProvider, DecryptionFailed, OperationError, TryError, util::WipeOnDrop};
+use zeroize::{ZeroizeOnDrop, Zeroizing};
 
 use super::*;
 
@@ -20,10 +21,12 @@ pub enum AeadAlgorithm<EC: ExtenderConfig> {
 
 pub enum Key<EC: ExtenderConfig> {
     Direct(AeadKeyOf<EC::Base>),
-    AesGcm128(libcrux_aesgcm::AesGcm128Key),
-    AesGcm256(libcrux_aesgcm::AesGcm256Key),
+    AesGcm128(WipeOnDrop<libcrux_aesgcm::AesGcm128Key>),
+    AesGcm256(WipeOnDrop<libcrux_aesgcm::AesGcm256Key>),
 }
 
+impl<EC: ExtenderConfig> ZeroizeOnDrop for Key<EC> where AeadKeyOf<EC::Base>: ZeroizeOnDrop {}
+
 pub enum Tag<EC: ExtenderConfig> {
     Direct(AeadTagOf<EC::Base>),
     AesGcm128(libcrux_aesgcm::AesGcm128Tag),
@@ -35,36 +38,44 @@ impl<EC: ExtenderConfig> AeadProvider for Extender<EC> {
     type Key = Key<EC>;
     type Tag = Tag<EC>;
 
-    fn load_from_keydata(&mut self, alg: Self::Algorithm, key: &[u8]) -> Self::Key {
-        match alg {
-            AeadAlgorithm::Direct(alg) => Key::Direct(self.0.aead().load_from_keydata(alg, key)),
-            AeadAlgorithm::AesGcm128 => Key::AesGcm128(
+    fn try_load_from_keydata(
+        &mut self,
+        alg: Self::Algorithm,
+        key: &[u8],
+    ) -> Result<Self::Key, OperationError> {
+        Ok(match alg {
+            AeadAlgorithm::Direct(alg) => {
+                Key::Direct(self.0.aead().try_load_from_keydata(alg, key)?)
+            }
+            AeadAlgorithm::AesGcm128 => Key::AesGcm128(WipeOnDrop::new(
                 <[u8; _]>::try_from(key)
-                    .expect("key length mismatch")
+                    .map_err(|_| OperationError::LengthMismatch)?
                     .into(),
-            ),
-            AeadAlgorithm::AesGcm256 => Key::AesGcm256(
+            )),
+            AeadAlgorithm::AesGcm256 => Key::AesGcm256(WipeOnDrop::new(
                 <[u8; _]>::try_from(key)
-                    .expect("key length mismatch")
+                    .map_err(|_| OperationError::LengthMismatch)?
                     .into(),
-            ),
-        }
+            )),
+        })
     }
 
-    fn encrypt_in_place(
+    fn try_encrypt_in_place(
         &mut self,
         key: &Self::Key,
         nonce: &[u8],
         message: &mut [u8],
         aad: impl embedded_cal::AadGenerator,
-    ) -> Self::Tag {
+    ) -> Result<Self::Tag, OperationError> {
         // Handle the simple case quicly; everything else needs the allocations
         if let Key::Direct(k) = key {
-            return Tag::Direct(self.0.aead().encrypt_in_place(k, nonce, message, aad));
+            return Ok(Tag::Direct(
+                self.0.aead().try_encrypt_in_place(k, nonce, message, aad)?,
+            ));
         };
 
         let mut ciphertext = vec![0; message.len()];
-        let aad: Vec<_> = aad.items().flatten().copied().collect();
+        let aad = Zeroizing::new(aad.items().flatten().copied().collect::<Vec<_>>());
 
         // I hope this explicitness mess pays off when we run not 2 but many types through
         // the match below.
@@ -75,7 +86,7 @@ impl<EC: ExtenderConfig> AeadProvider for Extender<EC> {
             nonce: &[u8],
             aad: &Vec<u8>,
             message: &[u8],
-        ) -> typed_owned::Tag<Alg>
+        ) -> Result<typed_owned::Tag<Alg>, OperationError>
         where
             Alg: typed_owned::Aead,
             typed_owned::Tag<Alg>: From<[u8; T]>,
@@ -83,7 +94,7 @@ impl<EC: ExtenderConfig> AeadProvider for Extender<EC> {
         {
             let mut tag: typed_owned::Tag<Alg> = [0u8; _].into();
             let nonce: typed_owned::Nonce<Alg> =
-                (<[u8; N]>::try_from(nonce).expect("nonce length mismatch")).into();
+                (<[u8; N]>::try_from(nonce).map_err(|_| OperationError::LengthMismatch)?).into();
             Alg::encrypt(
                 ciphertext.as_mut_slice(),
                 &mut tag,
@@ -92,8 +103,8 @@ impl<EC: ExtenderConfig> AeadProvider for Extender<EC> {
                 aad.as_slice(),
                 message,
             )
-            .expect("slice lenghts match");
-            tag
+            .map_err(|_| OperationError::LengthMismatch)?;
+            Ok(tag)
         }
 
         let tag = match key {
@@ -104,34 +115,37 @@ impl<EC: ExtenderConfig> AeadProvider for Extender<EC> {
                 nonce,
                 &aad,
                 message,
-            )),
+            )?),
             Key::AesGcm256(key) => Tag::AesGcm256(encrypt::<libcrux_aesgcm::AesGcm256, _, _>(
                 &mut ciphertext,
                 key,
                 nonce,
                 &aad,
                 message,
-            )),
+            )?),
         };
         message.copy_from_slice(&ciphertext);
-        tag
+        Ok(tag)
     }
 
-    fn decrypt_in_place(
+    fn try_decrypt_in_place(
         &mut self,
         key: &Self::Key,
         nonce: &[u8],
         message: &mut [u8],
         tag: &[u8],
         aad: impl embedded_cal::AadGenerator,
-    ) -> Result<(), embedded_cal::DecryptionFailed> {
+    ) -> Result<(), TryError<DecryptionFailed>> {
         // Handle the simple case quicly; everything else needs the allocations
         if let Key::Direct(k) = key {
-            return self.0.aead().decrypt_in_place(k, nonce, message, tag, aad);
+            return self
+                .0
+                .aead()
+                .try_decrypt_in_place(k, nonce, message, tag, aad);
         };
 
         let mut ciphertext = Vec::from(&*message);
-        let aad: Vec<_> = aad.items().flatten().copied().collect();
+        let aad = Zeroizing::new(aad.items().flatten().copied().collect::<Vec<_>>());
 
         // I hope this explicitness mess pays off when we run not 2 but many types through
         // the match below.
@@ -143,16 +157,16 @@ impl<EC: ExtenderConfig> AeadProvider for Extender<EC> {
             aad: &Vec<u8>,
             message: &mut [u8],
             tag: &[u8],
-        ) -> Result<(), embedded_cal::DecryptionFailed>
+        ) -> Result<(), TryError<DecryptionFailed>>
         where
             Alg: typed_owned::Aead,
             typed_owned::Tag<Alg>: From<[u8; T]>,
             typed_owned::Nonce<Alg>: From<[u8; N]>,
         {
             let tag: typed_owned::Tag<Alg> =
-                (<[u8; T]>::try_from(tag).expect("tag length mismatch")).into();
+                (<[u8; T]>::try_from(tag).map_err(|_| OperationError::LengthMismatch)?).into();
             let nonce: typed_owned::Nonce<Alg> =
-                (<[u8; N]>::try_from(nonce).expect("nonce length mismatch")).into();
+                (<[u8; N]>::try_from(nonce).map_err(|_| OperationError::LengthMismatch)?).into();
             Alg::decrypt(
                 ciphertext.as_mut_slice(),
                 key,
@@ -161,7 +175,7 @@ impl<EC: ExtenderConfig> AeadProvider for Extender<EC> {
                 message,
                 &tag,
             )
-            .map_err(|_| embedded_cal::DecryptionFailed)
+            .map_err(|_| TryError::Failed(DecryptionFailed))
         }
 
         match key {
@@ -210,6 +224,64 @@ impl<EC: ExtenderConfig> embedded_cal::AeadAlgorithm for AeadAlgorithm<EC> {
             AeadAlgorithm::AesGcm256 => libcrux_aesgcm::AesGcm256::NONCE_LEN,
         }
     }
+
+    #[inline]
+    fn from_cose_number(number: impl Into<i128>) -> Option<Self> {
+        let number: i128 = number.into();
+
+        match number {
+            1 => Some(AeadAlgorithm::AesGcm128),
+            3 => Some(AeadAlgorithm::AesGcm256),
+            _ => AeadAlgorithmOf::<EC::Base>::from_cose_number(number).map(AeadAlgorithm::Direct),
+        }
+    }
+
+    #[inline]
+    fn from_tls_cipher_suite(suite: u16) -> Option<Self> {
+        match suite {
+            0x1301 => Some(AeadAlgorithm::AesGcm128),
+            0x1302 => Some(AeadAlgorithm::AesGcm256),
+            _ => {
+                AeadAlgorithmOf::<EC::Base>::from_tls_cipher_suite(suite).map(AeadAlgorithm::Direct)
+            }
+        }
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        let selected = [0x1301, 0x1302].map(|n| match Self::from_tls_cipher_suite(n) {
+            Some(AeadAlgorithm::Direct(_)) | None => (None, None),
+            own => (own, AeadAlgorithmOf::<EC::Base>::from_tls_cipher_suite(n)),
+        });
+        let shadowed = selected.clone().map(|(_, base)| base);
+        selected.into_iter().filter_map(|(own, _)| own).chain(
+            AeadAlgorithmOf::<EC::Base>::all()
+                .filter(move |a| !shadowed.iter().any(|s| s.as_ref() == Some(a)))
+                .map(AeadAlgorithm::Direct),
+        )
+    }
+
+    fn cose_number(&self) -> Option<i128> {
+        match self {
+            AeadAlgorithm::Direct(a) => a.cose_number(),
+            AeadAlgorithm::AesGcm128 => Some(1),
+            AeadAlgorithm::AesGcm256 => Some(3),
+        }
+    }
+
+    fn ni_id(&self) -> Option<u8> {
+        match self {
+            AeadAlgorithm::Direct(a) => a.ni_id(),
+            _ => None,
+        }
+    }
+
+    fn ni_name(&self) -> Option<&'static str> {
+        match self {
+            AeadAlgorithm::Direct(a) => a.ni_name(),
+            _ => None,
+        }
+    }
 }
 
 impl<EC: ExtenderConfig> Clone for AeadAlgorithm<EC> {
@@ -258,3 +330,25 @@ impl<EC: ExtenderConfig> AsRef<[u8]> for Tag<EC> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::test_base::TestConfig;
+
+    use super::*;
+
+    #[test]
+    fn test_aead_algorithm_aesgcm() {
+        use embedded_cal::AeadAlgorithm as _;
+
+        embedded_cal::test_aead_algorithm_aesgcm_128::<Extender<TestConfig>>();
+        assert_eq!(
+            AeadAlgorithm::<TestConfig>::from_tls_cipher_suite(0x1302),
+            Some(AeadAlgorithm::AesGcm256)
+        );
+        assert_eq!(
+            AeadAlgorithm::<TestConfig>::from_tls_cipher_suite(0x1303),
+            None
+        );
+    }
+}
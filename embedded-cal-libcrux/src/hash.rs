@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
 
-use embedded_cal::HashProvider;
+use embedded_cal::{HashProvider, OperationError};
 
 use super::*;
 
@@ -28,31 +28,55 @@ impl<EC: ExtenderConfig> HashProvider for Extender<EC> {
     type Algorithm = HashAlgorithm<EC>;
     type State = HashState<EC>;
     type Output = HashResult<EC>;
+    // No XOFs are implemented here, but those of the base are usable.
+    type XofReader = HashXofReaderOf<EC::Base>;
 
-    fn init(&mut self, algorithm: Self::Algorithm) -> Self::State {
-        match algorithm {
+    fn try_init(&mut self, algorithm: Self::Algorithm) -> Result<Self::State, OperationError> {
+        Ok(match algorithm {
             HashAlgorithm::Sha256 => HashState::Sha256(Sha256State(libcrux_sha2::Sha256::new())),
-            HashAlgorithm::Direct(alg) => HashState::Direct(self.0.hash().init(alg)),
-        }
+            HashAlgorithm::Direct(alg) => HashState::Direct(self.0.hash().try_init(alg)?),
+        })
     }
 
-    fn update(&mut self, instance: &mut Self::State, data: &[u8]) {
+    fn try_update(
+        &mut self,
+        instance: &mut Self::State,
+        data: &[u8],
+    ) -> Result<(), OperationError> {
         match instance {
-            HashState::Direct(i) => self.0.hash().update(i, data),
+            HashState::Direct(i) => return self.0.hash().try_update(i, data),
             HashState::Sha256(s) => s.0.update(data),
         }
+        Ok(())
     }
 
-    fn finalize(&mut self, instance: Self::State) -> Self::Output {
-        match instance {
-            HashState::Direct(underlying) => HashResult::Direct(self.0.hash().finalize(underlying)),
+    fn try_finalize(&mut self, instance: Self::State) -> Result<Self::Output, OperationError> {
+        Ok(match instance {
+            HashState::Direct(underlying) => {
+                HashResult::Direct(self.0.hash().try_finalize(underlying)?)
+            }
             HashState::Sha256(s) => {
                 let mut output = [0u8; 32];
                 s.0.finish(&mut output);
                 HashResult::Sha256(output)
             }
+        })
+    }
+
+    fn finalize_xof(&mut self, instance: Self::State) -> Result<Self::XofReader, Self::State> {
+        match instance {
+            HashState::Direct(underlying) => self
+                .0
+                .hash()
+                .finalize_xof(underlying)
+                .map_err(HashState::Direct),
+            sha256 @ HashState::Sha256(_) => Err(sha256),
         }
     }
+
+    fn squeeze(&mut self, reader: &mut Self::XofReader, output: &mut [u8]) {
+        self.0.hash().squeeze(reader, output)
+    }
 }
 
 pub enum HashAlgorithm<EC: ExtenderConfig> {
@@ -118,7 +142,7 @@ impl<EC: ExtenderConfig> embedded_cal::HashAlgorithm for HashAlgorithm<EC> {
     fn from_ni_id(number: u8) -> Option<Self> {
         match number {
             1 => Self::from_cose_number(-16),
-            _ => None,
+            _ => HashAlgorithmOf::<EC::Base>::from_ni_id(number).map(HashAlgorithm::Direct),
         }
     }
 
@@ -126,7 +150,49 @@ impl<EC: ExtenderConfig> embedded_cal::HashAlgorithm for HashAlgorithm<EC> {
     fn from_ni_name(name: &str) -> Option<Self> {
         match name {
             "sha-256" => Self::from_cose_number(-16),
-            _ => None,
+            _ => HashAlgorithmOf::<EC::Base>::from_ni_name(name).map(HashAlgorithm::Direct),
+        }
+    }
+
+    #[inline]
+    fn from_tls_hash_algorithm(number: u8) -> Option<Self> {
+        match number {
+            4 => Self::from_cose_number(-16),
+            _ => HashAlgorithmOf::<EC::Base>::from_tls_hash_algorithm(number)
+                .map(HashAlgorithm::Direct),
+        }
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        let selected = [-16].map(|n| match Self::from_cose_number(n) {
+            Some(HashAlgorithm::Direct(_)) | None => (None, None),
+            own => (own, HashAlgorithmOf::<EC::Base>::from_cose_number(n)),
+        });
+        let shadowed = selected.clone().map(|(_, base)| base);
+        selected.into_iter().filter_map(|(own, _)| own).chain(
+            HashAlgorithmOf::<EC::Base>::all()
+                .filter(move |a| !shadowed.iter().any(|s| s.as_ref() == Some(a)))
+                .map(HashAlgorithm::Direct),
+        )
+    }
+
+    fn cose_number(&self) -> Option<i128> {
+        match self {
+            HashAlgorithm::Sha256 => Some(-16),
+            HashAlgorithm::Direct(a) => a.cose_number(),
+        }
+    }
+    fn ni_id(&self) -> Option<u8> {
+        match self {
+            HashAlgorithm::Sha256 => Some(1),
+            HashAlgorithm::Direct(a) => a.ni_id(),
+        }
+    }
+    fn ni_name(&self) -> Option<&'static str> {
+        match self {
+            HashAlgorithm::Sha256 => Some("sha-256"),
+            HashAlgorithm::Direct(a) => a.ni_name(),
         }
     }
 }
@@ -147,18 +213,17 @@ impl<EC: ExtenderConfig> AsRef<[u8]> for HashResult<EC> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use crate::tests::test_base::{TestBase, TestConfig};
 
-    struct TestConfig;
-
-    impl ExtenderConfig for TestConfig {
-        type Base = embedded_cal::empty::EmptyCal<true>;
-    }
+    use super::*;
 
     #[test]
     fn test_hash_algorithm_sha256() {
-        let mut cal = Extender::<TestConfig>::new(embedded_cal::empty::EmptyCal);
+        let mut cal = Extender::<TestConfig>::new(TestBase::new());
 
+        embedded_cal::test_hash_algorithm_sha256::<HashAlgorithm<TestConfig>>();
+        testvectors::algorithms::test_algorithm_enumeration::<Extender<TestConfig>>();
+        testvectors::algorithms::test_algorithm_identifiers::<Extender<TestConfig>>();
         testvectors::test_hash_algorithm_sha256(&mut cal);
     }
 }
@@ -0,0 +1,411 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+use embedded_cal::{
+    ImportError, KemProvider, OperationError,
+    util::{Either, WipeOnDrop},
+};
+use libcrux_ml_kem::mlkem768;
+use zeroize::{ZeroizeOnDrop, Zeroizing};
+
+use super::*;
+
+pub enum KemAlgorithm<EC: ExtenderConfig> {
+    Direct(KemAlgorithmOf<EC::Base>),
+    MlKem768,
+}
+
+// can't derive because EC is not clone
+impl<EC: ExtenderConfig> Clone for KemAlgorithm<EC> {
+    fn clone(&self) -> Self {
+        match self {
+            KemAlgorithm::Direct(a) => KemAlgorithm::Direct(a.clone()),
+            KemAlgorithm::MlKem768 => KemAlgorithm::MlKem768,
+        }
+    }
+}
+
+// can't derive because EC is not Debug
+impl<EC: ExtenderConfig> core::fmt::Debug for KemAlgorithm<EC> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            KemAlgorithm::Direct(a) => f.debug_tuple("Direct").field(a).finish(),
+            KemAlgorithm::MlKem768 => f.write_str("MlKem768"),
+        }
+    }
+}
+
+// can't derive because EC is not PartialEq
+impl<EC: ExtenderConfig> PartialEq for KemAlgorithm<EC> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (KemAlgorithm::Direct(a), KemAlgorithm::Direct(b)) => a == b,
+            (KemAlgorithm::MlKem768, KemAlgorithm::MlKem768) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<EC: ExtenderConfig> Eq for KemAlgorithm<EC> {}
+
+impl<EC: ExtenderConfig> embedded_cal::KemAlgorithm for KemAlgorithm<EC> {
+    fn shared_secret_length(&self) -> usize {
+        match self {
+            KemAlgorithm::Direct(a) => a.shared_secret_length(),
+            KemAlgorithm::MlKem768 => 32,
+        }
+    }
+
+    fn ciphertext_length(&self) -> usize {
+        match self {
+            KemAlgorithm::Direct(a) => a.ciphertext_length(),
+            KemAlgorithm::MlKem768 => 1088,
+        }
+    }
+
+    fn public_key_length(&self) -> usize {
+        match self {
+            KemAlgorithm::Direct(a) => a.public_key_length(),
+            KemAlgorithm::MlKem768 => 1184,
+        }
+    }
+
+    #[inline]
+    fn from_hpke_kem_id(id: u16) -> Option<Self> {
+        if let Some(a) = KemAlgorithmOf::<EC::Base>::from_hpke_kem_id(id) {
+            return Some(KemAlgorithm::Direct(a));
+        }
+        match id {
+            0x0041 => Some(KemAlgorithm::MlKem768),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        let own = [Self::from_hpke_kem_id(0x0041)];
+        KemAlgorithmOf::<EC::Base>::all()
+            .map(KemAlgorithm::Direct)
+            .chain(
+                own.into_iter()
+                    .flatten()
+                    .filter(|a| !matches!(a, KemAlgorithm::Direct(_))),
+            )
+    }
+
+    fn hpke_kem_id(&self) -> Option<u16> {
+        match self {
+            KemAlgorithm::Direct(a) => a.hpke_kem_id(),
+            KemAlgorithm::MlKem768 => Some(0x0041),
+        }
+    }
+}
+
+pub enum VisibleSecretKey<EC: ExtenderConfig> {
+    Direct(KemVisibleSecretKeyOf<EC::Base>),
+    /// The seed `d || z` from which the key pair is generated.
+    MlKem768(Zeroizing<[u8; 64]>),
+}
+
+impl<EC: ExtenderConfig> ZeroizeOnDrop for VisibleSecretKey<EC> where
+    KemVisibleSecretKeyOf<EC::Base>: ZeroizeOnDrop
+{
+}
+
+#[allow(
+    clippy::large_enum_variant,
+    reason = "ML-KEM keys and ciphertexts are large by nature, and boxing them would require an allocator"
+)]
+pub enum SecretKey<EC: ExtenderConfig> {
+    Direct(KemSecretKeyOf<EC::Base>),
+    MlKem768(WipeOnDrop<mlkem768::MlKem768KeyPair>),
+}
+
+impl<EC: ExtenderConfig> ZeroizeOnDrop for SecretKey<EC> where
+    KemSecretKeyOf<EC::Base>: ZeroizeOnDrop
+{
+}
+
+impl<EC: ExtenderConfig> From<VisibleSecretKey<EC>> for SecretKey<EC> {
+    fn from(key: VisibleSecretKey<EC>) -> Self {
+        match key {
+            VisibleSecretKey::Direct(k) => SecretKey::Direct(k.into()),
+            VisibleSecretKey::MlKem768(seed) => {
+                SecretKey::MlKem768(WipeOnDrop::new(mlkem768::generate_key_pair(*seed)))
+            }
+        }
+    }
+}
+
+#[allow(
+    clippy::large_enum_variant,
+    reason = "ML-KEM keys and ciphertexts are large by nature, and boxing them would require an allocator"
+)]
+pub enum PublicKey<EC: ExtenderConfig> {
+    Direct(KemPublicKeyOf<EC::Base>),
+    MlKem768(mlkem768::MlKem768PublicKey),
+}
+
+#[allow(
+    clippy::large_enum_variant,
+    reason = "ML-KEM keys and ciphertexts are large by nature, and boxing them would require an allocator"
+)]
+pub enum Ciphertext<EC: ExtenderConfig> {
+    Direct(KemCiphertextOf<EC::Base>),
+    MlKem768(mlkem768::MlKem768Ciphertext),
+}
+
+impl<EC: ExtenderConfig> AsRef<[u8]> for Ciphertext<EC> {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Ciphertext::Direct(c) => c.as_ref(),
+            Ciphertext::MlKem768(c) => c.as_slice(),
+        }
+    }
+}
+
+pub enum SharedSecret<EC: ExtenderConfig> {
+    Direct(KemSharedSecretOf<EC::Base>),
+    MlKem768(Zeroizing<[u8; 32]>),
+}
+
+impl<EC: ExtenderConfig> ZeroizeOnDrop for SharedSecret<EC> where
+    KemSharedSecretOf<EC::Base>: ZeroizeOnDrop
+{
+}
+
+impl<EC: ExtenderConfig> KemProvider for Extender<EC> {
+    type Algorithm = KemAlgorithm<EC>;
+    type VisibleSecretKey = VisibleSecretKey<EC>;
+    type SecretKey = SecretKey<EC>;
+    type PublicKey = PublicKey<EC>;
+    type Ciphertext = Ciphertext<EC>;
+    type SharedSecret = SharedSecret<EC>;
+
+    fn try_generate_visible(
+        &mut self,
+        alg: Self::Algorithm,
+    ) -> Result<Self::VisibleSecretKey, OperationError> {
+        Ok(match alg {
+            KemAlgorithm::Direct(a) => {
+                VisibleSecretKey::Direct(self.0.kem().try_generate_visible(a)?)
+            }
+            KemAlgorithm::MlKem768 => {
+                VisibleSecretKey::MlKem768(Zeroizing::new(self.random_bytes()?))
+            }
+        })
+    }
+
+    fn try_generate(&mut self, alg: Self::Algorithm) -> Result<Self::SecretKey, OperationError> {
+        match alg {
+            // Not going through generate_visible, in case the base has a better way.
+            KemAlgorithm::Direct(a) => Ok(SecretKey::Direct(self.0.kem().try_generate(a)?)),
+            alg => self.try_generate_visible(alg).map(Into::into),
+        }
+    }
+
+    fn export_secretkey_bytes<'s>(
+        &mut self,
+        secretkey: &'s Self::VisibleSecretKey,
+    ) -> impl AsRef<[u8]> + use<'s, EC> {
+        match secretkey {
+            VisibleSecretKey::Direct(k) => Either::Direct(self.0.kem().export_secretkey_bytes(k)),
+            VisibleSecretKey::MlKem768(seed) => Either::Own(seed),
+        }
+    }
+
+    fn import_secretkey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        secret: &[u8],
+    ) -> Result<Self::VisibleSecretKey, ImportError> {
+        match alg {
+            KemAlgorithm::Direct(a) => Ok(VisibleSecretKey::Direct(
+                self.0.kem().import_secretkey_bytes(a, secret)?,
+            )),
+            KemAlgorithm::MlKem768 => Ok(VisibleSecretKey::MlKem768(Zeroizing::new(
+                secret.try_into().map_err(|_| ImportError)?,
+            ))),
+        }
+    }
+
+    fn export_publickey_bytes<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+    ) -> impl AsRef<[u8]> + use<'p, EC> {
+        match public {
+            PublicKey::Direct(p) => Either::Direct(self.0.kem().export_publickey_bytes(p)),
+            PublicKey::MlKem768(p) => Either::Own(p.as_slice()),
+        }
+    }
+
+    fn import_publickey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::PublicKey, ImportError> {
+        match alg {
+            KemAlgorithm::Direct(a) => Ok(PublicKey::Direct(
+                self.0.kem().import_publickey_bytes(a, data)?,
+            )),
+            KemAlgorithm::MlKem768 => {
+                let data: [u8; 1184] = data.try_into().map_err(|_| ImportError)?;
+                let public = mlkem768::MlKem768PublicKey::from(data);
+                if !mlkem768::validate_public_key(&public) {
+                    return Err(ImportError);
+                }
+                Ok(PublicKey::MlKem768(public))
+            }
+        }
+    }
+
+    fn public_key(&mut self, private: &Self::SecretKey) -> Self::PublicKey {
+        match private {
+            SecretKey::Direct(k) => PublicKey::Direct(self.0.kem().public_key(k)),
+            SecretKey::MlKem768(k) => PublicKey::MlKem768(mlkem768::MlKem768PublicKey::from(
+                *k.public_key().as_slice(),
+            )),
+        }
+    }
+
+    fn try_encapsulate(
+        &mut self,
+        public: &Self::PublicKey,
+    ) -> Result<(Self::Ciphertext, Self::SharedSecret), OperationError> {
+        Ok(match public {
+            PublicKey::Direct(p) => {
+                let (ciphertext, shared) = self.0.kem().try_encapsulate(p)?;
+                (Ciphertext::Direct(ciphertext), SharedSecret::Direct(shared))
+            }
+            PublicKey::MlKem768(p) => {
+                let (ciphertext, shared) = mlkem768::encapsulate(p, self.random_bytes()?);
+                (
+                    Ciphertext::MlKem768(ciphertext),
+                    SharedSecret::MlKem768(Zeroizing::new(shared)),
+                )
+            }
+        })
+    }
+
+    fn decapsulate(
+        &mut self,
+        private: &Self::SecretKey,
+        ciphertext: &[u8],
+    ) -> Result<Self::SharedSecret, ImportError> {
+        match private {
+            SecretKey::Direct(k) => Ok(SharedSecret::Direct(
+                self.0.kem().decapsulate(k, ciphertext)?,
+            )),
+            SecretKey::MlKem768(k) => {
+                let ciphertext: [u8; 1088] = ciphertext.try_into().map_err(|_| ImportError)?;
+                let ciphertext = mlkem768::MlKem768Ciphertext::from(ciphertext);
+                Ok(SharedSecret::MlKem768(Zeroizing::new(
+                    mlkem768::decapsulate(k.private_key(), &ciphertext),
+                )))
+            }
+        }
+    }
+
+    fn raw_secret_bytes<'s>(
+        &mut self,
+        secret: &'s Self::SharedSecret,
+    ) -> impl AsRef<[u8]> + use<'s, EC> {
+        match secret {
+            SharedSecret::Direct(s) => Either::Direct(self.0.kem().raw_secret_bytes(s)),
+            SharedSecret::MlKem768(s) => Either::Own(s),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::test_base::{ScriptedConfig, TestBase, TestConfig};
+
+    use super::*;
+
+    #[test]
+    fn test_kem_algorithm_mlkem768() {
+        embedded_cal::test_kem_algorithm_mlkem768::<Extender<TestConfig>>();
+    }
+
+    #[test]
+    fn test_kem_mlkem768() {
+        let mut cal = Extender::<TestConfig>::new(TestBase::new());
+
+        embedded_cal::test_kem_selftest(&mut cal, KemAlgorithm::MlKem768);
+
+        for vector in testvectors::kem::INTEROP_MLKEM768 {
+            vector.test_with(&mut cal);
+        }
+    }
+
+    #[test]
+    fn test_kem_mlkem768_keygen() {
+        let mut cal = Extender::<TestConfig>::new(TestBase::new());
+
+        for vector in testvectors::kem::INTEROP_MLKEM768_KEYGEN {
+            match vector.test_with(&mut cal) {
+                SecretKey::MlKem768(k) => {
+                    assert_eq!(k.private_key().as_slice(), vector.dk());
+                }
+                SecretKey::Direct(d) => match d {},
+            }
+        }
+    }
+
+    #[test]
+    fn test_kem_mlkem768_encap_decap() {
+        for vector in testvectors::kem::INTEROP_MLKEM768_ENCAP {
+            let mut cal = Extender::<ScriptedConfig>::new(TestBase::with_randomness(vector.m()));
+            vector.test_with(&mut cal);
+        }
+
+        let mut cal = Extender::<TestConfig>::new(TestBase::new());
+        for vector in testvectors::kem::INTEROP_MLKEM768_DECAP {
+            vector.test_with(&mut cal);
+        }
+    }
+
+    #[test]
+    fn test_kem_secrets_wiped() {
+        use testvectors::zeroize::memory_after_drop;
+
+        let mut cal = Extender::<TestConfig>::new(TestBase::new());
+
+        let seed = cal.generate_visible(KemAlgorithm::MlKem768);
+        let memory = memory_after_drop(seed, |seed| match seed {
+            VisibleSecretKey::MlKem768(seed) => &**seed,
+            VisibleSecretKey::Direct(d) => match *d {},
+        });
+        assert_eq!(memory, [0; 64]);
+
+        let secret = cal.generate(KemAlgorithm::MlKem768);
+        let public = cal.public_key(&secret);
+        let (_ciphertext, shared) = cal.encapsulate(&public);
+        let memory = memory_after_drop(shared, |shared| match shared {
+            SharedSecret::MlKem768(shared) => &**shared,
+            SharedSecret::Direct(d) => match *d {},
+        });
+        assert_eq!(memory, [0; 32]);
+    }
+
+    #[test]
+    fn test_kem_rng_failure() {
+        let mut cal = Extender::<ScriptedConfig>::new(TestBase::with_randomness(&[]));
+
+        assert!(matches!(
+            cal.try_generate_visible(KemAlgorithm::MlKem768),
+            Err(OperationError::HardwareFault)
+        ));
+        assert!(matches!(
+            cal.try_generate(KemAlgorithm::MlKem768),
+            Err(OperationError::HardwareFault)
+        ));
+        // Any key will do, as the randomness is drawn before it is used.
+        let public = PublicKey::MlKem768(mlkem768::MlKem768PublicKey::from([0; 1184]));
+        assert!(matches!(
+            cal.try_encapsulate(&public),
+            Err(OperationError::HardwareFault)
+        ));
+    }
+}
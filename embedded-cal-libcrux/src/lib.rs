@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
-//! libcrux-sha2 backed SHA-256, plus plumbing to extend any hardware-backed [`Cal`] with it.
+//! libcrux backed SHA-256, AES-GCM, ML-KEM and ML-DSA, plus plumbing to extend any hardware-backed
+//! [`Cal`] with them.
 #![no_std]
 
 use embedded_cal::{Cal, accessor::*, plumbing::Plumbing};
@@ -8,13 +9,18 @@ use libcrux_sha2::Digest;
 
 mod aead;
 mod hash;
+mod kem;
+mod rng;
+mod signature;
 
 pub trait ExtenderConfig {
     // Currently we could also just have a Base in the generic and do not use Plumbing, but we
     // *will* use it in the future, and that will need more options, so this is reusing the design
     // of -software-demo even though there is no immediate benefit.
 
-    type Base: Cal + Plumbing;
+    /// The base's RNG is used where libcrux needs randomness (e.g. for ML-KEM key generation and
+    /// encapsulation); hardware-backed CALs usually provide one.
+    type Base: Cal + Plumbing + rand_core::TryCryptoRng;
 }
 
 pub struct Extender<EC: ExtenderConfig>(EC::Base);
@@ -31,6 +37,12 @@ impl<EC: ExtenderConfig> Cal for Extender<EC> {
     type HashProvider = Self;
     // FIXME: This should just be provided as well.
     type HmacProvider = HmacProviderOf<EC::Base>;
+    type SignatureProvider = Self;
+    type KemProvider = Self;
+    type BlockCipherProvider = BlockCipherProviderOf<EC::Base>;
+    type EccProvider = EccProviderOf<EC::Base>;
+    type MacProvider = MacProviderOf<EC::Base>;
+    type KeyWrapProvider = KeyWrapProviderOf<EC::Base>;
 
     fn dh(&mut self) -> &mut Self::DhProvider {
         self.0.dh()
@@ -44,4 +56,28 @@ impl<EC: ExtenderConfig> Cal for Extender<EC> {
     fn hmac(&mut self) -> &mut Self::HmacProvider {
         self.0.hmac()
     }
+    fn signature(&mut self) -> &mut Self::SignatureProvider {
+        self
+    }
+    fn kem(&mut self) -> &mut Self::KemProvider {
+        self
+    }
+    fn block_cipher(&mut self) -> &mut Self::BlockCipherProvider {
+        self.0.block_cipher()
+    }
+    fn ecc(&mut self) -> &mut Self::EccProvider {
+        self.0.ecc()
+    }
+    fn mac(&mut self) -> &mut Self::MacProvider {
+        self.0.mac()
+    }
+
+    fn key_wrap(&mut self) -> &mut Self::KeyWrapProvider {
+        self.0.key_wrap()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    pub(crate) mod test_base;
 }
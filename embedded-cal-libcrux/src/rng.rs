@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+use embedded_cal::OperationError;
+use rand_core::TryRng as _;
+
+use super::{Extender, ExtenderConfig};
+
+impl<EC: ExtenderConfig> rand_core::TryCryptoRng for Extender<EC> {}
+
+impl<EC: ExtenderConfig> rand_core::TryRng for Extender<EC> {
+    type Error = <EC::Base as rand_core::TryRng>::Error;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        self.0.try_next_u32()
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        self.0.try_next_u64()
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        self.0.try_fill_bytes(dst)
+    }
+}
+
+impl<EC: ExtenderConfig> Extender<EC> {
+    /// Produces key generation or signing randomness for libcrux from the base's RNG.
+    ///
+    /// Any failure of the base's RNG is reported as [`OperationError::HardwareFault`].
+    pub(crate) fn random_bytes<const N: usize>(&mut self) -> Result<[u8; N], OperationError> {
+        let mut randomness = [0; N];
+        self.0
+            .try_fill_bytes(&mut randomness)
+            .map_err(|_| OperationError::HardwareFault)?;
+        Ok(randomness)
+    }
+}
@@ -0,0 +1,517 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+use embedded_cal::{
+    ImportError, OperationError, SignatureProvider, VerificationFailed,
+    util::{Either, WipeOnDrop},
+};
+use libcrux_ml_dsa::{ml_dsa_44, ml_dsa_65};
+use zeroize::{ZeroizeOnDrop, Zeroizing};
+
+use super::*;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+/// The context string passed to ML-DSA.
+///
+/// COSE does not use ML-DSA's context (see draft-ietf-cose-dilithium), so it is always empty.
+const CONTEXT: &[u8] = b"";
+
+/// A signature algorithm.
+///
+/// For the ML-DSA variants, the message is gathered in the signing or verification state, as
+/// libcrux expects it in contiguous memory. Without the `alloc` feature, messages longer than 1024
+/// bytes ([`MessageBuffer::MAX_LEN`]) are rejected with [`OperationError::ResourceExhausted`].
+pub enum SignatureAlgorithm<EC: ExtenderConfig> {
+    Direct(SignatureAlgorithmOf<EC::Base>),
+    MlDsa44,
+    MlDsa65,
+}
+
+// can't derive because EC is not clone
+impl<EC: ExtenderConfig> Clone for SignatureAlgorithm<EC> {
+    fn clone(&self) -> Self {
+        match self {
+            SignatureAlgorithm::Direct(a) => SignatureAlgorithm::Direct(a.clone()),
+            SignatureAlgorithm::MlDsa44 => SignatureAlgorithm::MlDsa44,
+            SignatureAlgorithm::MlDsa65 => SignatureAlgorithm::MlDsa65,
+        }
+    }
+}
+
+// can't derive because EC is not Debug
+impl<EC: ExtenderConfig> core::fmt::Debug for SignatureAlgorithm<EC> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SignatureAlgorithm::Direct(a) => f.debug_tuple("Direct").field(a).finish(),
+            SignatureAlgorithm::MlDsa44 => f.write_str("MlDsa44"),
+            SignatureAlgorithm::MlDsa65 => f.write_str("MlDsa65"),
+        }
+    }
+}
+
+// can't derive because EC is not PartialEq
+impl<EC: ExtenderConfig> PartialEq for SignatureAlgorithm<EC> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (SignatureAlgorithm::Direct(a), SignatureAlgorithm::Direct(b)) => a == b,
+            (SignatureAlgorithm::MlDsa44, SignatureAlgorithm::MlDsa44) => true,
+            (SignatureAlgorithm::MlDsa65, SignatureAlgorithm::MlDsa65) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<EC: ExtenderConfig> Eq for SignatureAlgorithm<EC> {}
+
+impl<EC: ExtenderConfig> embedded_cal::SignatureAlgorithm for SignatureAlgorithm<EC> {
+    fn signature_length(&self) -> usize {
+        match self {
+            SignatureAlgorithm::Direct(a) => a.signature_length(),
+            SignatureAlgorithm::MlDsa44 => 2420,
+            SignatureAlgorithm::MlDsa65 => 3309,
+        }
+    }
+
+    #[inline]
+    fn from_cose_number(number: impl Into<i128>) -> Option<Self> {
+        let number: i128 = number.into();
+        if let Some(a) = SignatureAlgorithmOf::<EC::Base>::from_cose_number(number) {
+            return Some(SignatureAlgorithm::Direct(a));
+        }
+        match number {
+            -48 => Some(SignatureAlgorithm::MlDsa44),
+            -49 => Some(SignatureAlgorithm::MlDsa65),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        let own = [Self::from_cose_number(-48), Self::from_cose_number(-49)];
+        SignatureAlgorithmOf::<EC::Base>::all()
+            .map(SignatureAlgorithm::Direct)
+            .chain(
+                own.into_iter()
+                    .flatten()
+                    .filter(|a| !matches!(a, SignatureAlgorithm::Direct(_))),
+            )
+    }
+
+    fn cose_number(&self) -> Option<i128> {
+        match self {
+            SignatureAlgorithm::Direct(a) => a.cose_number(),
+            SignatureAlgorithm::MlDsa44 => Some(-48),
+            SignatureAlgorithm::MlDsa65 => Some(-49),
+        }
+    }
+}
+
+pub enum VisibleSecretKey<EC: ExtenderConfig> {
+    Direct(SignatureVisibleSecretKeyOf<EC::Base>),
+    /// The seed ξ from which the key pair is generated.
+    MlDsa44(Zeroizing<[u8; 32]>),
+    /// The seed ξ from which the key pair is generated.
+    MlDsa65(Zeroizing<[u8; 32]>),
+}
+
+impl<EC: ExtenderConfig> ZeroizeOnDrop for VisibleSecretKey<EC> where
+    SignatureVisibleSecretKeyOf<EC::Base>: ZeroizeOnDrop
+{
+}
+
+#[allow(
+    clippy::large_enum_variant,
+    reason = "ML-DSA keys are large by nature, and boxing them would require an allocator"
+)]
+pub enum SecretKey<EC: ExtenderConfig> {
+    Direct(SignatureSecretKeyOf<EC::Base>),
+    MlDsa44(WipeOnDrop<ml_dsa_44::MLDSA44KeyPair>),
+    MlDsa65(WipeOnDrop<ml_dsa_65::MLDSA65KeyPair>),
+}
+
+impl<EC: ExtenderConfig> ZeroizeOnDrop for SecretKey<EC> where
+    SignatureSecretKeyOf<EC::Base>: ZeroizeOnDrop
+{
+}
+
+impl<EC: ExtenderConfig> From<VisibleSecretKey<EC>> for SecretKey<EC> {
+    fn from(key: VisibleSecretKey<EC>) -> Self {
+        match key {
+            VisibleSecretKey::Direct(k) => SecretKey::Direct(k.into()),
+            VisibleSecretKey::MlDsa44(seed) => {
+                SecretKey::MlDsa44(WipeOnDrop::new(ml_dsa_44::generate_key_pair(*seed)))
+            }
+            VisibleSecretKey::MlDsa65(seed) => {
+                SecretKey::MlDsa65(WipeOnDrop::new(ml_dsa_65::generate_key_pair(*seed)))
+            }
+        }
+    }
+}
+
+#[allow(
+    clippy::large_enum_variant,
+    reason = "ML-DSA keys are large by nature, and boxing them would require an allocator"
+)]
+pub enum PublicKey<EC: ExtenderConfig> {
+    Direct(SignaturePublicKeyOf<EC::Base>),
+    MlDsa44(ml_dsa_44::MLDSA44VerificationKey),
+    MlDsa65(ml_dsa_65::MLDSA65VerificationKey),
+}
+
+#[allow(
+    clippy::large_enum_variant,
+    reason = "states are expected to be held on the stack for the duration of an operation"
+)]
+pub enum SignState<EC: ExtenderConfig> {
+    Direct(SignatureSignStateOf<EC::Base>),
+    MlDsa44(WipeOnDrop<ml_dsa_44::MLDSA44SigningKey>, MessageBuffer),
+    MlDsa65(WipeOnDrop<ml_dsa_65::MLDSA65SigningKey>, MessageBuffer),
+}
+
+impl<EC: ExtenderConfig> ZeroizeOnDrop for SignState<EC> where
+    SignatureSignStateOf<EC::Base>: ZeroizeOnDrop
+{
+}
+
+#[allow(
+    clippy::large_enum_variant,
+    reason = "states are expected to be held on the stack for the duration of an operation"
+)]
+pub enum VerifyState<EC: ExtenderConfig> {
+    Direct(SignatureVerifyStateOf<EC::Base>),
+    MlDsa44(ml_dsa_44::MLDSA44VerificationKey, MessageBuffer),
+    MlDsa65(ml_dsa_65::MLDSA65VerificationKey, MessageBuffer),
+}
+
+/// Collects the message for ML-DSA, which libcrux only accepts in contiguous memory.
+///
+/// Without the `alloc` feature, this is a fixed size buffer inside the signing or verification
+/// state.
+#[derive(Default)]
+pub struct MessageBuffer {
+    #[cfg(feature = "alloc")]
+    data: alloc::vec::Vec<u8>,
+    #[cfg(not(feature = "alloc"))]
+    data: heapless::Vec<u8, { MessageBuffer::MAX_LEN }>,
+}
+
+impl MessageBuffer {
+    /// Longest message that can be gathered without the `alloc` feature.
+    pub const MAX_LEN: usize = 1024;
+
+    fn append(&mut self, data: &[u8]) -> Result<(), OperationError> {
+        #[cfg(feature = "alloc")]
+        self.data.extend_from_slice(data);
+
+        #[cfg(not(feature = "alloc"))]
+        self.data
+            .extend_from_slice(data)
+            .map_err(|_| OperationError::ResourceExhausted)?;
+
+        Ok(())
+    }
+}
+
+impl AsRef<[u8]> for MessageBuffer {
+    fn as_ref(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+#[allow(
+    clippy::large_enum_variant,
+    reason = "ML-DSA signatures are large by nature, and boxing them would require an allocator"
+)]
+pub enum Signature<EC: ExtenderConfig> {
+    Direct(SignatureOutputOf<EC::Base>),
+    MlDsa44(ml_dsa_44::MLDSA44Signature),
+    MlDsa65(ml_dsa_65::MLDSA65Signature),
+}
+
+impl<EC: ExtenderConfig> AsRef<[u8]> for Signature<EC> {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Signature::Direct(s) => s.as_ref(),
+            Signature::MlDsa44(s) => s.as_slice(),
+            Signature::MlDsa65(s) => s.as_slice(),
+        }
+    }
+}
+
+impl<EC: ExtenderConfig> SignatureProvider for Extender<EC> {
+    type Algorithm = SignatureAlgorithm<EC>;
+    type VisibleSecretKey = VisibleSecretKey<EC>;
+    type SecretKey = SecretKey<EC>;
+    type PublicKey = PublicKey<EC>;
+    type SignState = SignState<EC>;
+    type VerifyState = VerifyState<EC>;
+    type Output = Signature<EC>;
+
+    fn generate_visible(&mut self, alg: Self::Algorithm) -> Self::VisibleSecretKey {
+        match alg {
+            SignatureAlgorithm::Direct(a) => {
+                VisibleSecretKey::Direct(self.0.signature().generate_visible(a))
+            }
+            SignatureAlgorithm::MlDsa44 => VisibleSecretKey::MlDsa44(Zeroizing::new(
+                self.random_bytes().unwrap_or_else(|e| panic!("{e}")),
+            )),
+            SignatureAlgorithm::MlDsa65 => VisibleSecretKey::MlDsa65(Zeroizing::new(
+                self.random_bytes().unwrap_or_else(|e| panic!("{e}")),
+            )),
+        }
+    }
+
+    fn generate(&mut self, alg: Self::Algorithm) -> Self::SecretKey {
+        match alg {
+            // Not going through generate_visible, in case the base has a better way.
+            SignatureAlgorithm::Direct(a) => SecretKey::Direct(self.0.signature().generate(a)),
+            alg => self.generate_visible(alg).into(),
+        }
+    }
+
+    fn export_secretkey_bytes<'s>(
+        &mut self,
+        secretkey: &'s Self::VisibleSecretKey,
+    ) -> impl AsRef<[u8]> + use<'s, EC> {
+        match secretkey {
+            VisibleSecretKey::Direct(k) => {
+                Either::Direct(self.0.signature().export_secretkey_bytes(k))
+            }
+            VisibleSecretKey::MlDsa44(seed) | VisibleSecretKey::MlDsa65(seed) => Either::Own(seed),
+        }
+    }
+
+    fn import_secretkey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        secret: &[u8],
+    ) -> Result<Self::VisibleSecretKey, ImportError> {
+        Ok(match alg {
+            SignatureAlgorithm::Direct(a) => {
+                VisibleSecretKey::Direct(self.0.signature().import_secretkey_bytes(a, secret)?)
+            }
+            SignatureAlgorithm::MlDsa44 => VisibleSecretKey::MlDsa44(Zeroizing::new(
+                secret.try_into().map_err(|_| ImportError)?,
+            )),
+            SignatureAlgorithm::MlDsa65 => VisibleSecretKey::MlDsa65(Zeroizing::new(
+                secret.try_into().map_err(|_| ImportError)?,
+            )),
+        })
+    }
+
+    fn export_publickey_bytes<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+    ) -> impl AsRef<[u8]> + use<'p, EC> {
+        match public {
+            PublicKey::Direct(p) => Either::Direct(self.0.signature().export_publickey_bytes(p)),
+            PublicKey::MlDsa44(p) => Either::Own(p.as_slice()),
+            PublicKey::MlDsa65(p) => Either::Own(p.as_slice()),
+        }
+    }
+
+    fn import_publickey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::PublicKey, ImportError> {
+        // Any byte string of the right length is a valid ML-DSA public key (FIPS 204 Algorithm 23
+        // has no failure cases).
+        Ok(match alg {
+            SignatureAlgorithm::Direct(a) => {
+                PublicKey::Direct(self.0.signature().import_publickey_bytes(a, data)?)
+            }
+            SignatureAlgorithm::MlDsa44 => PublicKey::MlDsa44(
+                ml_dsa_44::MLDSA44VerificationKey::new(data.try_into().map_err(|_| ImportError)?),
+            ),
+            SignatureAlgorithm::MlDsa65 => PublicKey::MlDsa65(
+                ml_dsa_65::MLDSA65VerificationKey::new(data.try_into().map_err(|_| ImportError)?),
+            ),
+        })
+    }
+
+    fn public_key(&mut self, private: &Self::SecretKey) -> Self::PublicKey {
+        match private {
+            SecretKey::Direct(k) => PublicKey::Direct(self.0.signature().public_key(k)),
+            SecretKey::MlDsa44(k) => PublicKey::MlDsa44(k.verification_key.clone()),
+            SecretKey::MlDsa65(k) => PublicKey::MlDsa65(k.verification_key.clone()),
+        }
+    }
+
+    fn sign_init(&mut self, key: &Self::SecretKey) -> Self::SignState {
+        match key {
+            SecretKey::Direct(k) => SignState::Direct(self.0.signature().sign_init(k)),
+            SecretKey::MlDsa44(k) => {
+                SignState::MlDsa44(WipeOnDrop::new(k.signing_key.clone()), Default::default())
+            }
+            SecretKey::MlDsa65(k) => {
+                SignState::MlDsa65(WipeOnDrop::new(k.signing_key.clone()), Default::default())
+            }
+        }
+    }
+
+    fn try_sign_update(
+        &mut self,
+        state: &mut Self::SignState,
+        data: &[u8],
+    ) -> Result<(), OperationError> {
+        match state {
+            SignState::Direct(s) => self.0.signature().try_sign_update(s, data),
+            SignState::MlDsa44(_, message) | SignState::MlDsa65(_, message) => message.append(data),
+        }
+    }
+
+    fn sign_finalize(&mut self, state: Self::SignState) -> Self::Output {
+        // Signing only fails for overly long contexts, or when rejection sampling takes more
+        // rounds than libcrux allows; the latter is retried with fresh randomness.
+        match state {
+            SignState::Direct(s) => Signature::Direct(self.0.signature().sign_finalize(s)),
+            SignState::MlDsa44(key, message) => loop {
+                let randomness = self.random_bytes().unwrap_or_else(|e| panic!("{e}"));
+                if let Ok(signature) = ml_dsa_44::sign(&key, message.as_ref(), CONTEXT, randomness)
+                {
+                    break Signature::MlDsa44(signature);
+                }
+            },
+            SignState::MlDsa65(key, message) => loop {
+                let randomness = self.random_bytes().unwrap_or_else(|e| panic!("{e}"));
+                if let Ok(signature) = ml_dsa_65::sign(&key, message.as_ref(), CONTEXT, randomness)
+                {
+                    break Signature::MlDsa65(signature);
+                }
+            },
+        }
+    }
+
+    fn verify_init(&mut self, key: &Self::PublicKey) -> Self::VerifyState {
+        match key {
+            PublicKey::Direct(k) => VerifyState::Direct(self.0.signature().verify_init(k)),
+            PublicKey::MlDsa44(k) => VerifyState::MlDsa44(k.clone(), Default::default()),
+            PublicKey::MlDsa65(k) => VerifyState::MlDsa65(k.clone(), Default::default()),
+        }
+    }
+
+    fn try_verify_update(
+        &mut self,
+        state: &mut Self::VerifyState,
+        data: &[u8],
+    ) -> Result<(), OperationError> {
+        match state {
+            VerifyState::Direct(s) => self.0.signature().try_verify_update(s, data),
+            VerifyState::MlDsa44(_, message) | VerifyState::MlDsa65(_, message) => {
+                message.append(data)
+            }
+        }
+    }
+
+    fn verify_finalize(
+        &mut self,
+        state: Self::VerifyState,
+        signature: &[u8],
+    ) -> Result<(), VerificationFailed> {
+        match state {
+            VerifyState::Direct(s) => self.0.signature().verify_finalize(s, signature),
+            VerifyState::MlDsa44(key, message) => {
+                let signature = ml_dsa_44::MLDSA44Signature::new(
+                    signature.try_into().map_err(|_| VerificationFailed)?,
+                );
+                ml_dsa_44::verify(&key, message.as_ref(), CONTEXT, &signature)
+                    .map_err(|_| VerificationFailed)
+            }
+            VerifyState::MlDsa65(key, message) => {
+                let signature = ml_dsa_65::MLDSA65Signature::new(
+                    signature.try_into().map_err(|_| VerificationFailed)?,
+                );
+                ml_dsa_65::verify(&key, message.as_ref(), CONTEXT, &signature)
+                    .map_err(|_| VerificationFailed)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::test_base::{TestBase, TestConfig};
+
+    use super::*;
+
+    #[test]
+    fn test_signature_mldsa() {
+        let mut cal = Extender::<TestConfig>::new(TestBase::new());
+
+        embedded_cal::test_signature_selftest(&mut cal, SignatureAlgorithm::MlDsa44);
+        embedded_cal::test_signature_selftest(&mut cal, SignatureAlgorithm::MlDsa65);
+
+        for vector in testvectors::signature::INTEROP_MLDSA44 {
+            vector.test_with(&mut cal);
+        }
+        for vector in testvectors::signature::INTEROP_MLDSA65 {
+            vector.test_with(&mut cal);
+        }
+    }
+
+    #[test]
+    fn test_signature_mldsa_keygen() {
+        let mut cal = Extender::<TestConfig>::new(TestBase::new());
+
+        let vectors = testvectors::signature::INTEROP_MLDSA44_KEYGEN
+            .iter()
+            .chain(testvectors::signature::INTEROP_MLDSA65_KEYGEN);
+        for vector in vectors {
+            match vector.test_with(&mut cal) {
+                SecretKey::MlDsa44(k) => assert_eq!(k.signing_key.as_slice(), vector.sk()),
+                SecretKey::MlDsa65(k) => assert_eq!(k.signing_key.as_slice(), vector.sk()),
+                SecretKey::Direct(d) => match d {},
+            }
+        }
+    }
+
+    #[test]
+    fn test_signature_mldsa_sigver() {
+        let mut cal = Extender::<TestConfig>::new(TestBase::new());
+
+        for vector in testvectors::signature::INTEROP_MLDSA44_SIGVER {
+            vector.test_with(&mut cal);
+        }
+        for vector in testvectors::signature::INTEROP_MLDSA65_SIGVER {
+            vector.test_with(&mut cal);
+        }
+    }
+
+    #[test]
+    fn test_signature_mldsa_long_message() {
+        use embedded_cal::{SignatureAlgorithm as _, TryError};
+
+        let mut cal = Extender::<TestConfig>::new(TestBase::new());
+
+        let mldsa44 = SignatureAlgorithm::from_cose_number(-48).unwrap();
+        let message = [0x2a; MessageBuffer::MAX_LEN + 1];
+
+        // Without `alloc`, ML-DSA messages are limited by the buffer in the state.
+        let public = cal
+            .import_publickey_bytes(mldsa44.clone(), &[0; 1312])
+            .unwrap();
+        let verified = cal.try_verify(&public, &message, &[0; 2420]);
+        if cfg!(feature = "alloc") {
+            assert!(matches!(
+                verified,
+                Err(TryError::Failed(VerificationFailed))
+            ));
+        } else {
+            assert!(matches!(
+                verified,
+                Err(TryError::Operation(OperationError::ResourceExhausted))
+            ));
+        }
+
+        let secret = cal.generate(mldsa44);
+        let signed = cal.try_sign(&secret, &message);
+        if cfg!(feature = "alloc") {
+            assert!(signed.is_ok());
+        } else {
+            assert!(matches!(signed, Err(OperationError::ResourceExhausted)));
+        }
+    }
+}
@@ -0,0 +1,216 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+//! Testing-only module with a base that has no algorithms and no plumbing, but an RNG, as the
+//! [`Extender`][crate::Extender] requires one.
+
+use embedded_cal::{
+    OperationError,
+    empty::{EmptyCal, NoAlgorithms},
+    plumbing,
+};
+
+use crate::ExtenderConfig;
+
+pub struct TestConfig;
+
+impl ExtenderConfig for TestConfig {
+    type Base = TestBase;
+}
+
+/// Like [`TestConfig`], but with randomness from a fixed script (see
+/// [`TestBase::with_randomness()`]).
+pub struct ScriptedConfig;
+
+impl ExtenderConfig for ScriptedConfig {
+    type Base = TestBase<ScriptedRng>;
+}
+
+/// An [`EmptyCal`] with plumbing that gets randomness from the host, or from a fixed script.
+pub struct TestBase<R = HostRng>(EmptyCal<true>, R);
+
+impl TestBase {
+    pub fn new() -> Self {
+        Self(EmptyCal, HostRng)
+    }
+}
+
+impl TestBase<ScriptedRng> {
+    /// A base whose RNG hands out `randomness` in order, and reports a hardware fault once that is
+    /// used up.
+    ///
+    /// This makes randomized operations reproducible for known-answer tests; an empty script
+    /// tests how RNG failures are reported.
+    pub fn with_randomness(randomness: &'static [u8]) -> Self {
+        Self(EmptyCal, ScriptedRng(randomness))
+    }
+}
+
+impl<R> embedded_cal::Cal for TestBase<R> {
+    type DhProvider = EmptyCal<true>;
+    type AeadProvider = EmptyCal<true>;
+    type HashProvider = EmptyCal<true>;
+    type HmacProvider = EmptyCal<true>;
+    type SignatureProvider = EmptyCal<true>;
+    type KemProvider = EmptyCal<true>;
+    type BlockCipherProvider = EmptyCal<true>;
+    type EccProvider = EmptyCal<true>;
+    type MacProvider = EmptyCal<true>;
+    type KeyWrapProvider = EmptyCal<true>;
+
+    fn dh(&mut self) -> &mut Self::DhProvider {
+        &mut self.0
+    }
+
+    fn aead(&mut self) -> &mut Self::AeadProvider {
+        &mut self.0
+    }
+
+    fn hash(&mut self) -> &mut Self::HashProvider {
+        &mut self.0
+    }
+
+    fn hmac(&mut self) -> &mut Self::HmacProvider {
+        &mut self.0
+    }
+
+    fn signature(&mut self) -> &mut Self::SignatureProvider {
+        &mut self.0
+    }
+
+    fn kem(&mut self) -> &mut Self::KemProvider {
+        &mut self.0
+    }
+
+    fn block_cipher(&mut self) -> &mut Self::BlockCipherProvider {
+        &mut self.0
+    }
+
+    fn ecc(&mut self) -> &mut Self::EccProvider {
+        &mut self.0
+    }
+
+    fn mac(&mut self) -> &mut Self::MacProvider {
+        &mut self.0
+    }
+
+    fn key_wrap(&mut self) -> &mut Self::KeyWrapProvider {
+        &mut self.0
+    }
+}
+
+impl<R> plumbing::Plumbing for TestBase<R> {}
+
+impl<R> plumbing::hash::Hash for TestBase<R> {}
+
+impl<R> plumbing::hash::Sha2Short for TestBase<R> {
+    const SUPPORTED: bool = false;
+    const SEND_PADDING: bool = false;
+    const FIRST_CHUNK_SIZE: usize = 0;
+    const UPDATE_MULTICHUNK: bool = false;
+
+    type State = NoAlgorithms;
+
+    fn init(&mut self, _variant: plumbing::hash::Sha2ShortVariant) -> Self::State {
+        panic!("user disregarded SUPPORTED=false")
+    }
+
+    fn update(&mut self, instance: &mut Self::State, _data: &[u8]) {
+        match *instance {}
+    }
+
+    fn finalize(&mut self, instance: Self::State, _last_chunk: &[u8], _target: &mut [u8]) {
+        match instance {}
+    }
+}
+
+impl<R> plumbing::hash::Sha2Long for TestBase<R> {
+    const SUPPORTED: bool = false;
+    const SEND_PADDING: bool = false;
+    const FIRST_CHUNK_SIZE: usize = 0;
+    const UPDATE_MULTICHUNK: bool = false;
+
+    type State = NoAlgorithms;
+
+    fn init(&mut self, _variant: plumbing::hash::Sha2LongVariant) -> Self::State {
+        panic!("user disregarded SUPPORTED=false")
+    }
+
+    fn update(&mut self, instance: &mut Self::State, _data: &[u8]) {
+        match *instance {}
+    }
+
+    fn finalize(&mut self, instance: Self::State, _last_chunk: &[u8], _target: &mut [u8]) {
+        match instance {}
+    }
+}
+
+impl<R: rand_core::TryCryptoRng> rand_core::TryCryptoRng for TestBase<R> {}
+
+impl<R: rand_core::TryRng> rand_core::TryRng for TestBase<R> {
+    type Error = R::Error;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        self.1.try_next_u32()
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        self.1.try_next_u64()
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        self.1.try_fill_bytes(dst)
+    }
+}
+
+/// The host's RNG.
+pub struct HostRng;
+
+impl rand_core::TryCryptoRng for HostRng {}
+
+impl rand_core::TryRng for HostRng {
+    type Error = core::convert::Infallible;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        Ok(getrandom::u32().expect("host RNG failure"))
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        Ok(getrandom::u64().expect("host RNG failure"))
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        getrandom::fill(dst).expect("host RNG failure");
+        Ok(())
+    }
+}
+
+/// Randomness that was fixed in advance; see [`TestBase::with_randomness()`].
+pub struct ScriptedRng(&'static [u8]);
+
+// Not actually random, but this is what libcrux gets its randomness from in tests.
+impl rand_core::TryCryptoRng for ScriptedRng {}
+
+impl rand_core::TryRng for ScriptedRng {
+    type Error = OperationError;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        let mut bytes = [0u8; 4];
+        self.try_fill_bytes(&mut bytes)?;
+        Ok(u32::from_le_bytes(bytes))
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        let mut bytes = [0u8; 8];
+        self.try_fill_bytes(&mut bytes)?;
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        let Some((head, tail)) = self.0.split_at_checked(dst.len()) else {
+            return Err(OperationError::HardwareFault);
+        };
+        dst.copy_from_slice(head);
+        self.0 = tail;
+        Ok(())
+    }
+}
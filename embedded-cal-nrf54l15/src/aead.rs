@@ -32,6 +32,29 @@ const AES_CMD_CCM_ENCRYPT: u32 = AES_CCM_MODE; // 0x2000
 const AES_CMD_CCM_DECRYPT: u32 = AES_CCM_MODE | 1; // 0x2001
 
 use crate::descriptor::{DescriptorChain, Input, Output, dmatag_ign};
+use embedded_cal::{DecryptionFailed, OperationError, TryError};
+
+// Nonce and tag lengths of the supported CCM variants.
+const CCM_NONCE_LEN: usize = 13;
+const CCM_TAG_LEN: usize = 8;
+
+// Longest AAD and message that fit the header and data buffers of ccm_encrypt / ccm_decrypt.
+const MAX_AAD_LEN: usize = 255;
+const MAX_MESSAGE_LEN: usize = 255;
+
+/// Reports [`OperationError::ResourceExhausted`] for inputs that exceed the buffers.
+///
+/// This runs before the message is touched, as the message needs to be left unmodified on errors.
+fn check_lengths(
+    message: &[u8],
+    aad: &impl embedded_cal::AadGenerator,
+) -> Result<(), OperationError> {
+    let aad_len: usize = aad.items().map(<[u8]>::len).sum();
+    if message.len() > MAX_MESSAGE_LEN || aad_len > MAX_AAD_LEN {
+        return Err(OperationError::ResourceExhausted);
+    }
+    Ok(())
+}
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AeadAlgorithm {
@@ -62,6 +85,21 @@ impl embedded_cal::AeadAlgorithm for AeadAlgorithm {
             _ => None,
         }
     }
+
+    fn all() -> impl Iterator<Item = Self> {
+        [
+            AeadAlgorithm::AesCcm16_64_128,
+            AeadAlgorithm::AesCcm16_64_256,
+        ]
+        .into_iter()
+    }
+
+    fn cose_number(&self) -> Option<i128> {
+        match self {
+            AeadAlgorithm::AesCcm16_64_128 => Some(10),
+            AeadAlgorithm::AesCcm16_64_256 => Some(11),
+        }
+    }
 }
 
 pub enum AeadKey {
@@ -257,42 +295,55 @@ impl embedded_cal::AeadProvider for super::Nrf54l15Cal {
     type Key = AeadKey;
     type Tag = AeadTag;
 
-    fn load_from_keydata(&mut self, alg: Self::Algorithm, key: &[u8]) -> Self::Key {
-        match alg {
+    fn try_load_from_keydata(
+        &mut self,
+        alg: Self::Algorithm,
+        key: &[u8],
+    ) -> Result<Self::Key, OperationError> {
+        let mismatch = |_| OperationError::LengthMismatch;
+        Ok(match alg {
             AeadAlgorithm::AesCcm16_64_128 => {
-                AeadKey::AesCcm16_64_128(key.try_into().expect("key length mismatch"))
+                AeadKey::AesCcm16_64_128(key.try_into().map_err(mismatch)?)
             }
             AeadAlgorithm::AesCcm16_64_256 => {
-                AeadKey::AesCcm16_64_256(key.try_into().expect("key length mismatch"))
+                AeadKey::AesCcm16_64_256(key.try_into().map_err(mismatch)?)
             }
-        }
+        })
     }
 
-    fn encrypt_in_place(
+    fn try_encrypt_in_place(
         &mut self,
         key: &Self::Key,
         nonce: &[u8],
         message: &mut [u8],
         aad: impl embedded_cal::AadGenerator,
-    ) -> Self::Tag {
-        match key {
+    ) -> Result<Self::Tag, OperationError> {
+        if nonce.len() != CCM_NONCE_LEN {
+            return Err(OperationError::LengthMismatch);
+        }
+        check_lengths(message, &aad)?;
+        Ok(match key {
             AeadKey::AesCcm16_64_128(key_bytes) => {
                 AeadTag::AesCcm16_64_128(self.ccm_encrypt(key_bytes, nonce, message, aad))
             }
             AeadKey::AesCcm16_64_256(key_bytes) => {
                 AeadTag::AesCcm16_64_256(self.ccm_encrypt(key_bytes, nonce, message, aad))
             }
-        }
+        })
     }
 
-    fn decrypt_in_place(
+    fn try_decrypt_in_place(
         &mut self,
         key: &Self::Key,
         nonce: &[u8],
         cyphertext: &mut [u8],
         tag: &[u8],
         aad: impl embedded_cal::AadGenerator,
-    ) -> Result<(), embedded_cal::DecryptionFailed> {
+    ) -> Result<(), TryError<DecryptionFailed>> {
+        if nonce.len() != CCM_NONCE_LEN || tag.len() != CCM_TAG_LEN {
+            return Err(OperationError::LengthMismatch.into());
+        }
+        check_lengths(cyphertext, &aad)?;
         let ok = match key {
             AeadKey::AesCcm16_64_128(key_bytes) => {
                 self.ccm_decrypt(key_bytes, nonce, cyphertext, tag, aad)
@@ -304,7 +355,7 @@ impl embedded_cal::AeadProvider for super::Nrf54l15Cal {
         if ok {
             Ok(())
         } else {
-            Err(embedded_cal::DecryptionFailed)
+            Err(TryError::Failed(DecryptionFailed))
         }
     }
 }
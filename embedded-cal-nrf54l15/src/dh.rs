@@ -3,11 +3,12 @@
 
 use embedded_cal::DhAlgorithm as _;
 use embedded_cal::p256::{
-    P256_GX_BYTES, P256_GY_BYTES, P256_ORDER, bytes_to_words, ge, p256_recover_y,
+    P256_GX_BYTES, P256_GY_BYTES, P256_ORDER, bytes_to_words, ge, p256_decode_point,
+    p256_encode_point, p256_recover_y,
 };
+use embedded_cal::util::Either;
 use nrf_pac::common::{RW, Reg};
 use nrf_pac::cracencore::vals::{Selcurve, Swapbytes};
-use rand_core::Rng as _;
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
 // PKE data as described in nRF54L15_nRF54L10_nRF54L05_Datasheet_v1.0.pdf
@@ -149,6 +150,32 @@ impl embedded_cal::DhAlgorithm for DhAlgorithm {
             _ => None,
         }
     }
+
+    fn from_tls_named_group(group: u16) -> Option<Self> {
+        match group {
+            23 => Some(DhAlgorithm::EcdhP256),
+            29 => Some(DhAlgorithm::X25519),
+            30 => Some(DhAlgorithm::X448),
+            _ => None,
+        }
+    }
+
+    fn all() -> impl Iterator<Item = Self> {
+        [
+            DhAlgorithm::EcdhP256,
+            DhAlgorithm::X25519,
+            DhAlgorithm::X448,
+        ]
+        .into_iter()
+    }
+
+    fn cose_ecdh(&self) -> Option<i128> {
+        match self {
+            DhAlgorithm::EcdhP256 => Some(1),
+            DhAlgorithm::X25519 => Some(4),
+            DhAlgorithm::X448 => Some(5),
+        }
+    }
 }
 
 #[derive(Zeroize, ZeroizeOnDrop)]
@@ -341,25 +368,27 @@ impl embedded_cal::DhProvider for super::Nrf54l15Cal {
     type PublicKey = PublicKey;
     type SharedSecret = SharedSecret;
 
-    fn generate_visible(&mut self, alg: Self::Algorithm) -> Self::VisibleSecretKey {
+    fn try_generate_visible(
+        &mut self,
+        alg: Self::Algorithm,
+    ) -> Result<Self::VisibleSecretKey, embedded_cal::OperationError> {
         let mut scalar = [0u8; 56];
         match alg {
             DhAlgorithm::EcdhP256 => loop {
-                // Error = Infallible
-                self.fill_bytes(&mut scalar[..32]);
+                self.fill_random(&mut scalar[..32])?;
                 let scalar32: [u8; 32] = scalar[..32].try_into().expect("slice is always 32 bytes");
                 let w = bytes_to_words(&scalar32);
                 if w != [0u32; 8] && !ge(&w, &P256_ORDER) {
-                    return VisibleSecretKey(SecretKey { alg, scalar });
+                    return Ok(VisibleSecretKey(SecretKey { alg, scalar }));
                 }
             },
             DhAlgorithm::X25519 => {
-                self.fill_bytes(&mut scalar[..32]);
-                VisibleSecretKey(SecretKey { alg, scalar })
+                self.fill_random(&mut scalar[..32])?;
+                Ok(VisibleSecretKey(SecretKey { alg, scalar }))
             }
             DhAlgorithm::X448 => {
-                self.fill_bytes(&mut scalar[..56]);
-                VisibleSecretKey(SecretKey { alg, scalar })
+                self.fill_random(&mut scalar[..56])?;
+                Ok(VisibleSecretKey(SecretKey { alg, scalar }))
             }
         }
     }
@@ -413,13 +442,52 @@ impl embedded_cal::DhProvider for super::Nrf54l15Cal {
         Ok(PublicKey { alg, x, y })
     }
 
-    fn shared_secret(
+    fn export_publickey<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+        format: embedded_cal::PublicKeyFormat,
+    ) -> Result<impl AsRef<[u8]> + use<'p>, embedded_cal::UnsupportedFormat> {
+        match (&public.alg, format) {
+            (DhAlgorithm::EcdhP256, format) => {
+                let x32: &[u8; 32] = public.x[..32].try_into().expect("slice is always 32 bytes");
+                Ok(Either::Own(p256_encode_point(x32, &public.y, format)))
+            }
+            (DhAlgorithm::X25519 | DhAlgorithm::X448, embedded_cal::PublicKeyFormat::Compact) => {
+                Ok(Either::Direct(&public.x[..public.alg.output_length()]))
+            }
+            (DhAlgorithm::X25519 | DhAlgorithm::X448, _) => Err(embedded_cal::UnsupportedFormat),
+        }
+    }
+
+    fn import_publickey(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+        format: embedded_cal::PublicKeyFormat,
+    ) -> Result<Self::PublicKey, embedded_cal::ImportError> {
+        match (&alg, format) {
+            (DhAlgorithm::EcdhP256, format) => {
+                let (x32, y) = p256_decode_point(data, format)?;
+                let mut x = [0u8; 56];
+                x[..32].copy_from_slice(&x32);
+                Ok(PublicKey { alg, x, y })
+            }
+            (DhAlgorithm::X25519 | DhAlgorithm::X448, embedded_cal::PublicKeyFormat::Compact) => {
+                self.import_publickey_bytes(alg, data)
+            }
+            (DhAlgorithm::X25519 | DhAlgorithm::X448, _) => Err(embedded_cal::ImportError),
+        }
+    }
+
+    fn try_shared_secret(
         &mut self,
         private: &Self::SecretKey,
         public: &Self::PublicKey,
-    ) -> Result<Self::SharedSecret, embedded_cal::IncompatibleKeys> {
+    ) -> Result<Self::SharedSecret, embedded_cal::TryError<embedded_cal::IncompatibleKeys>> {
         if private.alg != public.alg {
-            return Err(embedded_cal::IncompatibleKeys);
+            return Err(embedded_cal::TryError::Failed(
+                embedded_cal::IncompatibleKeys,
+            ));
         }
         match private.alg {
             DhAlgorithm::EcdhP256 => {
@@ -457,8 +525,11 @@ impl embedded_cal::DhProvider for super::Nrf54l15Cal {
         }
     }
 
-    fn public_key(&mut self, private: &Self::SecretKey) -> Self::PublicKey {
-        match private.alg {
+    fn try_public_key(
+        &mut self,
+        private: &Self::SecretKey,
+    ) -> Result<Self::PublicKey, embedded_cal::OperationError> {
+        Ok(match private.alg {
             DhAlgorithm::EcdhP256 => {
                 let mut scalar32: [u8; 32] = private.scalar[..32]
                     .try_into()
@@ -503,7 +574,7 @@ impl embedded_cal::DhProvider for super::Nrf54l15Cal {
                     y: [0u8; 32],
                 }
             }
-        }
+        })
     }
 
     fn raw_secret_bytes<'s>(
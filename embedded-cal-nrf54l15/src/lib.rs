@@ -31,6 +31,12 @@ impl embedded_cal::Cal for Nrf54l15Cal {
     type AeadProvider = Self;
     type HashProvider = EmptyCal<false>;
     type HmacProvider = EmptyCal<false>;
+    type SignatureProvider = EmptyCal<false>;
+    type KemProvider = EmptyCal<false>;
+    type BlockCipherProvider = EmptyCal<false>;
+    type EccProvider = EmptyCal<false>;
+    type MacProvider = EmptyCal<false>;
+    type KeyWrapProvider = EmptyCal<false>;
 
     fn dh(&mut self) -> &mut Self::DhProvider {
         self
@@ -47,6 +53,30 @@ impl embedded_cal::Cal for Nrf54l15Cal {
     fn hmac(&mut self) -> &mut Self::HmacProvider {
         &mut self.empty
     }
+
+    fn signature(&mut self) -> &mut Self::SignatureProvider {
+        &mut self.empty
+    }
+
+    fn kem(&mut self) -> &mut Self::KemProvider {
+        &mut self.empty
+    }
+
+    fn block_cipher(&mut self) -> &mut Self::BlockCipherProvider {
+        &mut self.empty
+    }
+
+    fn ecc(&mut self) -> &mut Self::EccProvider {
+        &mut self.empty
+    }
+
+    fn mac(&mut self) -> &mut Self::MacProvider {
+        &mut self.empty
+    }
+
+    fn key_wrap(&mut self) -> &mut Self::KeyWrapProvider {
+        &mut self.empty
+    }
 }
 
 impl Nrf54l15Cal {
@@ -179,6 +209,27 @@ impl embedded_cal::plumbing::hash::Sha2Short for Nrf54l15Cal {
     }
 }
 
+impl embedded_cal::plumbing::hash::Sha2Long for Nrf54l15Cal {
+    const SUPPORTED: bool = false;
+    const SEND_PADDING: bool = false;
+    const FIRST_CHUNK_SIZE: usize = 0;
+    const UPDATE_MULTICHUNK: bool = false;
+
+    type State = embedded_cal::empty::NoAlgorithms;
+
+    fn init(&mut self, _variant: embedded_cal::plumbing::hash::Sha2LongVariant) -> Self::State {
+        panic!("user disregarded SUPPORTED=false")
+    }
+
+    fn update(&mut self, instance: &mut Self::State, _data: &[u8]) {
+        match *instance {}
+    }
+
+    fn finalize(&mut self, instance: Self::State, _last_chunk: &[u8], _target: &mut [u8]) {
+        match instance {}
+    }
+}
+
 impl Nrf54l15Cal {
     fn execute_cryptomaster_dma<const N: usize>(
         &mut self,
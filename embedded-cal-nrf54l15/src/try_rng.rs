@@ -2,26 +2,17 @@
 // SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
 
 use crate::Nrf54l15Cal;
+use embedded_cal::OperationError;
 use nrf_pac::cracencore::vals::{ControlSoftrst, State};
-const MAX_TRNG_RESTARTS: u32 = 3;
-
-impl rand_core::TryCryptoRng for Nrf54l15Cal {}
-impl rand_core::TryRng for Nrf54l15Cal {
-    type Error = core::convert::Infallible;
-
-    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
-        let mut bytes = [0u8; 4];
-        self.try_fill_bytes(&mut bytes)?;
-        Ok(u32::from_le_bytes(bytes))
-    }
 
-    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
-        let mut bytes = [0u8; 8];
-        self.try_fill_bytes(&mut bytes)?;
-        Ok(u64::from_le_bytes(bytes))
-    }
+const MAX_TRNG_RESTARTS: u32 = 3;
 
-    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+impl Nrf54l15Cal {
+    /// Fills `dst` with random bytes.
+    ///
+    /// Persistent hardware failure is reported as [`OperationError::HardwareFault`], also through
+    /// the [`rand_core::TryRng`] implementation.
+    pub(crate) fn fill_random(&mut self, dst: &mut [u8]) -> Result<(), OperationError> {
         let mut dst = dst;
         let mut restarts = 0;
 
@@ -44,7 +35,7 @@ impl rand_core::TryRng for Nrf54l15Cal {
             if fsm == State::ERROR {
                 restarts += 1;
                 if restarts > MAX_TRNG_RESTARTS {
-                    panic!("RNG hardware failure");
+                    return Err(OperationError::HardwareFault);
                 }
 
                 // Pulse softrst to flush the conditioner and FIFO
@@ -90,3 +81,24 @@ impl rand_core::TryRng for Nrf54l15Cal {
         Ok(())
     }
 }
+
+impl rand_core::TryCryptoRng for Nrf54l15Cal {}
+impl rand_core::TryRng for Nrf54l15Cal {
+    type Error = OperationError;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        let mut bytes = [0u8; 4];
+        self.try_fill_bytes(&mut bytes)?;
+        Ok(u32::from_le_bytes(bytes))
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        let mut bytes = [0u8; 8];
+        self.try_fill_bytes(&mut bytes)?;
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        self.fill_random(dst)
+    }
+}
@@ -74,19 +74,60 @@ mod tests {
         for v in testvectors::dh::RFC5903_P256 {
             v.test_with(state.cal.dh());
         }
+        for v in testvectors::dh::P256_FORMATS {
+            v.test_with(state.cal.dh());
+        }
+        testvectors::dh::test_public_key_format_errors(state.cal.dh());
     }
 
     #[test]
     fn test_dh_x25519(state: &mut super::TestState) {
+        embedded_cal::test_dh_algorithm_x25519::<Nrf54l15Cal>();
         for v in testvectors::dh::RFC7748_X25519 {
             v.test_with(state.cal.dh());
         }
+        for v in testvectors::dh::X25519_FORMATS {
+            v.test_with(state.cal.dh());
+        }
     }
 
     #[test]
     fn test_dh_x448(state: &mut super::TestState) {
+        embedded_cal::test_dh_algorithm_x448::<Nrf54l15Cal>();
         for v in testvectors::dh::RFC7748_X448 {
             v.test_with(state.cal.dh());
         }
+        for v in testvectors::dh::X448_FORMATS {
+            v.test_with(state.cal.dh());
+        }
+    }
+
+    #[test]
+    fn test_cose_key(state: &mut super::TestState) {
+        testvectors::cose_key::test_codec();
+        testvectors::cose_key::test_import_errors(&mut state.cal);
+        for v in testvectors::cose_key::RFC9529_P256 {
+            v.test_with(&mut state.cal);
+        }
+        for v in testvectors::cose_key::RFC7748_X25519 {
+            v.test_with(&mut state.cal);
+        }
+        for v in testvectors::cose_key::RFC7748_X448 {
+            v.test_with(&mut state.cal);
+        }
+    }
+
+    #[test]
+    fn test_der(state: &mut super::TestState) {
+        testvectors::der::test_malformed(&mut state.cal);
+        for v in testvectors::der::RFC5903_P256 {
+            v.test_with(&mut state.cal);
+        }
+        for v in testvectors::der::RFC7748_X25519 {
+            v.test_with(&mut state.cal);
+        }
+        for v in testvectors::der::RFC7748_X448 {
+            v.test_with(&mut state.cal);
+        }
     }
 }
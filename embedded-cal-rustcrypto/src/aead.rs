@@ -2,15 +2,20 @@
 // SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
 
 use super::*;
-use embedded_cal::{AeadProvider, Cal};
+use embedded_cal::{AeadProvider, Cal, DecryptionFailed, OperationError, TryError};
+use zeroize::{ZeroizeOnDrop, Zeroizing};
 
 type AesCcm16_64_128 = ccm::Ccm<aes::Aes128, ccm::consts::U8, ccm::consts::U13>;
 type AesCcm16_64_256 = ccm::Ccm<aes::Aes256, ccm::consts::U8, ccm::consts::U13>;
+type AesGcm128 = aes_gcm::Aes128Gcm;
+type AesGcm256 = aes_gcm::Aes256Gcm;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AeadAlgorithm<BA> {
     AesCcm16_64_128,
     AesCcm16_64_256,
+    AesGcm128,
+    AesGcm256,
     Direct(BA),
 }
 
@@ -19,6 +24,8 @@ impl<BA: embedded_cal::AeadAlgorithm> embedded_cal::AeadAlgorithm for AeadAlgori
         match self {
             AeadAlgorithm::AesCcm16_64_128 => 16,
             AeadAlgorithm::AesCcm16_64_256 => 32,
+            AeadAlgorithm::AesGcm128 => 16,
+            AeadAlgorithm::AesGcm256 => 32,
             AeadAlgorithm::Direct(a) => a.key_length(),
         }
     }
@@ -27,6 +34,8 @@ impl<BA: embedded_cal::AeadAlgorithm> embedded_cal::AeadAlgorithm for AeadAlgori
         match self {
             AeadAlgorithm::AesCcm16_64_128 => 8,
             AeadAlgorithm::AesCcm16_64_256 => 8,
+            AeadAlgorithm::AesGcm128 => 16,
+            AeadAlgorithm::AesGcm256 => 16,
             AeadAlgorithm::Direct(a) => a.tag_length(),
         }
     }
@@ -35,6 +44,8 @@ impl<BA: embedded_cal::AeadAlgorithm> embedded_cal::AeadAlgorithm for AeadAlgori
         match self {
             AeadAlgorithm::AesCcm16_64_128 => 13,
             AeadAlgorithm::AesCcm16_64_256 => 13,
+            AeadAlgorithm::AesGcm128 => 12,
+            AeadAlgorithm::AesGcm256 => 12,
             AeadAlgorithm::Direct(a) => a.nonce_length(),
         }
     }
@@ -46,143 +57,256 @@ impl<BA: embedded_cal::AeadAlgorithm> embedded_cal::AeadAlgorithm for AeadAlgori
             return Some(AeadAlgorithm::Direct(a));
         }
         match number {
+            1 => Some(AeadAlgorithm::AesGcm128),
+            3 => Some(AeadAlgorithm::AesGcm256),
             10 => Some(AeadAlgorithm::AesCcm16_64_128),
             11 => Some(AeadAlgorithm::AesCcm16_64_256),
             _ => None,
         }
     }
+
+    #[inline]
+    fn from_tls_cipher_suite(suite: u16) -> Option<Self> {
+        if let Some(a) = BA::from_tls_cipher_suite(suite) {
+            return Some(AeadAlgorithm::Direct(a));
+        }
+        match suite {
+            0x1301 => Some(AeadAlgorithm::AesGcm128),
+            0x1302 => Some(AeadAlgorithm::AesGcm256),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        let own = [
+            Self::from_cose_number(10),
+            Self::from_cose_number(11),
+            Self::from_cose_number(1),
+            Self::from_cose_number(3),
+        ];
+        BA::all().map(AeadAlgorithm::Direct).chain(
+            own.into_iter()
+                .flatten()
+                .filter(|a| !matches!(a, AeadAlgorithm::Direct(_))),
+        )
+    }
+
+    fn cose_number(&self) -> Option<i128> {
+        match self {
+            AeadAlgorithm::AesCcm16_64_128 => Some(10),
+            AeadAlgorithm::AesCcm16_64_256 => Some(11),
+            AeadAlgorithm::AesGcm128 => Some(1),
+            AeadAlgorithm::AesGcm256 => Some(3),
+            AeadAlgorithm::Direct(a) => a.cose_number(),
+        }
+    }
+
+    fn ni_id(&self) -> Option<u8> {
+        match self {
+            AeadAlgorithm::Direct(a) => a.ni_id(),
+            _ => None,
+        }
+    }
+
+    fn ni_name(&self) -> Option<&'static str> {
+        match self {
+            AeadAlgorithm::Direct(a) => a.ni_name(),
+            _ => None,
+        }
+    }
 }
 
 pub enum AeadKey<BK> {
-    AesCcm16_64_128([u8; 16]),
-    AesCcm16_64_256([u8; 32]),
+    AesCcm16_64_128(Zeroizing<[u8; 16]>),
+    AesCcm16_64_256(Zeroizing<[u8; 32]>),
+    AesGcm128(Zeroizing<[u8; 16]>),
+    AesGcm256(Zeroizing<[u8; 32]>),
     Direct(BK),
 }
 
+impl<BK: ZeroizeOnDrop> ZeroizeOnDrop for AeadKey<BK> {}
+
 pub enum AeadTag<BT> {
-    AesCcm16_64_128([u8; 8]),
-    AesCcm16_64_256([u8; 8]),
+    AesCcm16_64_128(Zeroizing<[u8; 8]>),
+    AesCcm16_64_256(Zeroizing<[u8; 8]>),
+    AesGcm128(Zeroizing<[u8; 16]>),
+    AesGcm256(Zeroizing<[u8; 16]>),
     Direct(BT),
 }
 
+impl<BT: ZeroizeOnDrop> ZeroizeOnDrop for AeadTag<BT> {}
+
 impl<BT: AsRef<[u8]>> AsRef<[u8]> for AeadTag<BT> {
     fn as_ref(&self) -> &[u8] {
         match self {
-            AeadTag::AesCcm16_64_128(t) => t,
-            AeadTag::AesCcm16_64_256(t) => t,
+            AeadTag::AesCcm16_64_128(t) => &t[..],
+            AeadTag::AesCcm16_64_256(t) => &t[..],
+            AeadTag::AesGcm128(t) => &t[..],
+            AeadTag::AesGcm256(t) => &t[..],
             AeadTag::Direct(t) => t.as_ref(),
         }
     }
 }
 
+/// Checks that a nonce or tag has the length the algorithm requires.
+///
+/// This needs to happen before conversion into a `GenericArray`, whose conversion from slices
+/// panics on length mismatch.
+fn checked_length(data: &[u8], length: usize) -> Result<&[u8], OperationError> {
+    if data.len() == length {
+        Ok(data)
+    } else {
+        Err(OperationError::LengthMismatch)
+    }
+}
+
 impl<Base: Cal> AeadProvider for RustcryptoCalExtender<Base> {
     type Algorithm = AeadAlgorithm<AeadAlgorithmOf<Base>>;
     type Key = AeadKey<AeadKeyOf<Base>>;
     type Tag = AeadTag<AeadTagOf<Base>>;
 
-    fn load_from_keydata(&mut self, alg: Self::Algorithm, key: &[u8]) -> Self::Key {
-        match alg {
+    fn try_load_from_keydata(
+        &mut self,
+        alg: Self::Algorithm,
+        key: &[u8],
+    ) -> Result<Self::Key, OperationError> {
+        let mismatch = |_| OperationError::LengthMismatch;
+        Ok(match alg {
             AeadAlgorithm::AesCcm16_64_128 => {
-                AeadKey::AesCcm16_64_128(key.try_into().expect("key length mismatch"))
+                AeadKey::AesCcm16_64_128(Zeroizing::new(key.try_into().map_err(mismatch)?))
             }
             AeadAlgorithm::AesCcm16_64_256 => {
-                AeadKey::AesCcm16_64_256(key.try_into().expect("key length mismatch"))
+                AeadKey::AesCcm16_64_256(Zeroizing::new(key.try_into().map_err(mismatch)?))
+            }
+            AeadAlgorithm::AesGcm128 => {
+                AeadKey::AesGcm128(Zeroizing::new(key.try_into().map_err(mismatch)?))
+            }
+            AeadAlgorithm::AesGcm256 => {
+                AeadKey::AesGcm256(Zeroizing::new(key.try_into().map_err(mismatch)?))
             }
             AeadAlgorithm::Direct(alg) => {
-                AeadKey::Direct(self.base.aead().load_from_keydata(alg, key))
+                AeadKey::Direct(self.base.aead().try_load_from_keydata(alg, key)?)
             }
-        }
+        })
     }
 
-    #[allow(
-        clippy::unnecessary_fallible_conversions,
-        reason = "GenericArray has infallible conversions but they panic"
-    )]
-    fn encrypt_in_place(
+    fn try_encrypt_in_place(
         &mut self,
         key: &Self::Key,
         nonce: &[u8],
         message: &mut [u8],
         aad: impl embedded_cal::AadGenerator,
-    ) -> Self::Tag {
+    ) -> Result<Self::Tag, OperationError> {
         use ccm::{AeadInPlace, KeyInit};
 
         if let AeadKey::Direct(key) = key {
-            return AeadTag::Direct(self.base.aead().encrypt_in_place(key, nonce, message, aad));
+            return Ok(AeadTag::Direct(
+                self.base
+                    .aead()
+                    .try_encrypt_in_place(key, nonce, message, aad)?,
+            ));
         }
 
-        let aad_linear = self.collect_aad(aad);
+        let aad_linear = self.collect_aad(aad)?;
 
-        match key {
-            AeadKey::AesCcm16_64_128(key) => AeadTag::AesCcm16_64_128(
-                AesCcm16_64_128::new(key.into())
+        Ok(match key {
+            AeadKey::AesCcm16_64_128(key) => AeadTag::AesCcm16_64_128(Zeroizing::new(
+                AesCcm16_64_128::new((&**key).into())
                     .encrypt_in_place_detached(
-                        nonce.try_into().expect("nonce length mismatch"),
+                        checked_length(nonce, 13)?.into(),
                         aad_linear.as_ref(),
                         message,
                     )
-                    .expect("Preconfigured sizes should not allow encryption to fail")
+                    .map_err(|_| OperationError::LengthMismatch)?
                     .into(),
-            ),
-            AeadKey::AesCcm16_64_256(key) => AeadTag::AesCcm16_64_256(
-                AesCcm16_64_256::new(key.into())
+            )),
+            AeadKey::AesCcm16_64_256(key) => AeadTag::AesCcm16_64_256(Zeroizing::new(
+                AesCcm16_64_256::new((&**key).into())
                     .encrypt_in_place_detached(
-                        nonce.try_into().expect("nonce length mismatch"),
+                        checked_length(nonce, 13)?.into(),
                         aad_linear.as_ref(),
                         message,
                     )
-                    .expect("Preconfigured sizes should not allow encryption to fail")
+                    .map_err(|_| OperationError::LengthMismatch)?
                     .into(),
-            ),
+            )),
+            AeadKey::AesGcm128(key) => AeadTag::AesGcm128(Zeroizing::new(
+                AesGcm128::new((&**key).into())
+                    .encrypt_in_place_detached(
+                        checked_length(nonce, 12)?.into(),
+                        aad_linear.as_ref(),
+                        message,
+                    )
+                    .map_err(|_| OperationError::LengthMismatch)?
+                    .into(),
+            )),
+            AeadKey::AesGcm256(key) => AeadTag::AesGcm256(Zeroizing::new(
+                AesGcm256::new((&**key).into())
+                    .encrypt_in_place_detached(
+                        checked_length(nonce, 12)?.into(),
+                        aad_linear.as_ref(),
+                        message,
+                    )
+                    .map_err(|_| OperationError::LengthMismatch)?
+                    .into(),
+            )),
             AeadKey::Direct(_) => {
                 unreachable!("Code path without common AAD collection was checked earlier")
             }
-        }
+        })
     }
 
-    #[allow(
-        clippy::unnecessary_fallible_conversions,
-        reason = "GenericArray has infallible conversions but they panic"
-    )]
-    fn decrypt_in_place(
+    fn try_decrypt_in_place(
         &mut self,
         key: &Self::Key,
         nonce: &[u8],
         message: &mut [u8],
         tag: &[u8],
         aad: impl embedded_cal::AadGenerator,
-    ) -> Result<(), embedded_cal::DecryptionFailed> {
+    ) -> Result<(), TryError<DecryptionFailed>> {
         use ccm::{AeadInPlace, KeyInit};
 
         if let AeadKey::Direct(key) = key {
             return self
                 .base
                 .aead()
-                .decrypt_in_place(key, nonce, message, tag, aad);
+                .try_decrypt_in_place(key, nonce, message, tag, aad);
         }
 
-        let aad_linear = self.collect_aad(aad);
+        let aad_linear = self.collect_aad(aad)?;
 
         match key {
-            AeadKey::AesCcm16_64_128(key) => AesCcm16_64_128::new(key.into())
+            AeadKey::AesCcm16_64_128(key) => AesCcm16_64_128::new((&**key).into())
                 .decrypt_in_place_detached(
-                    nonce.try_into().expect("nonce length mismatch"),
+                    checked_length(nonce, 13)?.into(),
                     aad_linear.as_ref(),
                     message,
-                    tag.try_into().expect("tag length mismatch"),
-                )
-                .map_err(|_| embedded_cal::DecryptionFailed),
-            AeadKey::AesCcm16_64_256(key) => AesCcm16_64_256::new(key.into())
+                    checked_length(tag, 8)?.into(),
+                ),
+            AeadKey::AesCcm16_64_256(key) => AesCcm16_64_256::new((&**key).into())
                 .decrypt_in_place_detached(
-                    nonce.try_into().expect("nonce length mismatch"),
+                    checked_length(nonce, 13)?.into(),
                     aad_linear.as_ref(),
                     message,
-                    tag.try_into().expect("tag length mismatch"),
-                )
-                .map_err(|_| embedded_cal::DecryptionFailed),
+                    checked_length(tag, 8)?.into(),
+                ),
+            AeadKey::AesGcm128(key) => AesGcm128::new((&**key).into()).decrypt_in_place_detached(
+                checked_length(nonce, 12)?.into(),
+                aad_linear.as_ref(),
+                message,
+                checked_length(tag, 16)?.into(),
+            ),
+            AeadKey::AesGcm256(key) => AesGcm256::new((&**key).into()).decrypt_in_place_detached(
+                checked_length(nonce, 12)?.into(),
+                aad_linear.as_ref(),
+                message,
+                checked_length(tag, 16)?.into(),
+            ),
             AeadKey::Direct(_) => {
                 unreachable!("Code path without common AAD collection was checked earlier")
             }
         }
+        .map_err(|_| TryError::Failed(DecryptionFailed))
     }
 }
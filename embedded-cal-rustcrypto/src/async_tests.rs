@@ -0,0 +1,310 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+//! Tests for the async provider traits, both through the [`Blocking`] adapter and through a
+//! simulated back-end whose operations complete from a timer thread, like a DMA- or
+//! interrupt-driven back-end would complete them from an interrupt.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::{Context, Poll, Wake, Waker};
+use std::time::Duration;
+
+use embedded_cal::asynch::{
+    AsyncAeadProvider, AsyncCal, AsyncDhProvider, AsyncHashProvider, AsyncHmacProvider, Blocking,
+};
+use embedded_cal::{
+    AeadAlgorithm, DhAlgorithm, DhProvider, HashAlgorithm, HashProvider, HmacAlgorithm,
+    ImportError, IncompatibleKeys, OperationError, PublicKeyFormat, TryError, UnsupportedFormat,
+};
+
+use super::*;
+
+/// Future that completes after a timer thread woke it.
+struct Delay {
+    done: Arc<AtomicBool>,
+    started: bool,
+}
+
+impl Delay {
+    fn new() -> Self {
+        Self {
+            done: Arc::new(AtomicBool::new(false)),
+            started: false,
+        }
+    }
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(mut self: core::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.done.load(Ordering::Acquire) {
+            return Poll::Ready(());
+        }
+        if !self.started {
+            self.started = true;
+            let done = self.done.clone();
+            let waker = cx.waker().clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(2));
+                done.store(true, Ordering::Release);
+                waker.wake();
+            });
+        }
+        Poll::Pending
+    }
+}
+
+struct ThreadWaker(std::thread::Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Runs a future to completion, returning its output and how often it was pending.
+fn block_on<F: Future>(future: F) -> (F::Output, usize) {
+    let mut future = core::pin::pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut pending = 0;
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return (output, pending),
+            Poll::Pending => {
+                pending += 1;
+                std::thread::park();
+            }
+        }
+    }
+}
+
+/// A simulated asynchronous back-end.
+///
+/// Hashing and key establishment are implemented natively, each operation waiting for a timer
+/// before doing its work in software. AEAD and HMAC are passed on to the [`Blocking`] adapter,
+/// as a back-end without asynchronous support for some algorithms would do.
+struct TimerCal(Blocking<RustcryptoCal>);
+
+impl AsyncCal for TimerCal {
+    type DhProvider = Self;
+    type AeadProvider = Blocking<RustcryptoCal>;
+    type HashProvider = Self;
+    type HmacProvider = Blocking<RustcryptoCal>;
+
+    fn dh(&mut self) -> &mut Self::DhProvider {
+        self
+    }
+    fn aead(&mut self) -> &mut Self::AeadProvider {
+        &mut self.0
+    }
+    fn hash(&mut self) -> &mut Self::HashProvider {
+        self
+    }
+    fn hmac(&mut self) -> &mut Self::HmacProvider {
+        &mut self.0
+    }
+}
+
+impl AsyncHashProvider for TimerCal {
+    type Algorithm = HashAlgorithmOf<RustcryptoCal>;
+    type State = HashStateOf<RustcryptoCal>;
+    type Output = HashOutputOf<RustcryptoCal>;
+
+    async fn try_init(
+        &mut self,
+        algorithm: Self::Algorithm,
+    ) -> Result<Self::State, OperationError> {
+        Delay::new().await;
+        HashProvider::try_init(&mut self.0.0, algorithm)
+    }
+
+    async fn try_update(
+        &mut self,
+        instance: &mut Self::State,
+        data: &[u8],
+    ) -> Result<(), OperationError> {
+        Delay::new().await;
+        HashProvider::try_update(&mut self.0.0, instance, data)
+    }
+
+    async fn try_finalize(
+        &mut self,
+        instance: Self::State,
+    ) -> Result<Self::Output, OperationError> {
+        Delay::new().await;
+        HashProvider::try_finalize(&mut self.0.0, instance)
+    }
+}
+
+impl AsyncDhProvider for TimerCal {
+    type Algorithm = DhAlgorithmOf<RustcryptoCal>;
+    type VisibleSecretKey = DhVisibleSecretKeyOf<RustcryptoCal>;
+    type SecretKey = DhSecretKeyOf<RustcryptoCal>;
+    type PublicKey = DhPublicKeyOf<RustcryptoCal>;
+    type SharedSecret = DhSharedSecretOf<RustcryptoCal>;
+
+    async fn try_generate_visible(
+        &mut self,
+        alg: Self::Algorithm,
+    ) -> Result<Self::VisibleSecretKey, OperationError> {
+        Delay::new().await;
+        DhProvider::try_generate_visible(&mut self.0.0, alg)
+    }
+
+    async fn try_shared_secret(
+        &mut self,
+        private: &Self::SecretKey,
+        public: &Self::PublicKey,
+    ) -> Result<Self::SharedSecret, TryError<IncompatibleKeys>> {
+        Delay::new().await;
+        DhProvider::try_shared_secret(&mut self.0.0, private, public)
+    }
+
+    async fn try_public_key(
+        &mut self,
+        private: &Self::SecretKey,
+    ) -> Result<Self::PublicKey, OperationError> {
+        Delay::new().await;
+        DhProvider::try_public_key(&mut self.0.0, private)
+    }
+
+    fn export_secretkey_bytes<'s>(
+        &mut self,
+        secretkey: &'s Self::VisibleSecretKey,
+    ) -> impl AsRef<[u8]> + use<'s> {
+        DhProvider::export_secretkey_bytes(&mut self.0.0, secretkey)
+    }
+
+    fn import_secretkey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        secret: &[u8],
+    ) -> Result<Self::VisibleSecretKey, ImportError> {
+        DhProvider::import_secretkey_bytes(&mut self.0.0, alg, secret)
+    }
+
+    fn export_publickey_bytes<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+    ) -> impl AsRef<[u8]> + use<'p> {
+        DhProvider::export_publickey_bytes(&mut self.0.0, public)
+    }
+
+    fn import_publickey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::PublicKey, ImportError> {
+        DhProvider::import_publickey_bytes(&mut self.0.0, alg, data)
+    }
+
+    fn export_publickey<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+        format: PublicKeyFormat,
+    ) -> Result<impl AsRef<[u8]> + use<'p>, UnsupportedFormat> {
+        DhProvider::export_publickey(&mut self.0.0, public, format)
+    }
+
+    fn import_publickey(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+        format: PublicKeyFormat,
+    ) -> Result<Self::PublicKey, ImportError> {
+        DhProvider::import_publickey(&mut self.0.0, alg, data, format)
+    }
+
+    fn raw_secret_bytes<'s>(
+        &mut self,
+        secret: &'s Self::SharedSecret,
+    ) -> impl AsRef<[u8]> + use<'s> {
+        DhProvider::raw_secret_bytes(&mut self.0.0, secret)
+    }
+}
+
+/// Runs operations of all async providers, returning the outputs for comparison.
+async fn exercise<C: AsyncCal>(cal: &mut C) -> [Vec<u8>; 4] {
+    let sha256 = <C::HashProvider as AsyncHashProvider>::Algorithm::from_cose_number(-16).unwrap();
+    let mut state = cal.hash().init(sha256).await;
+    cal.hash().update(&mut state, b"a").await;
+    cal.hash().update(&mut state, b"bc").await;
+    let hash = cal.hash().finalize(state).await.as_ref().to_vec();
+
+    let hmac256 = <C::HmacProvider as AsyncHmacProvider>::Algorithm::from_cose_number(5).unwrap();
+    let hmac = cal
+        .hmac()
+        .hmac_with_keydata(hmac256, b"key", b"data")
+        .await
+        .as_ref()
+        .to_vec();
+
+    let gcm = <C::AeadProvider as AsyncAeadProvider>::Algorithm::from_cose_number(1).unwrap();
+    let key = cal.aead().load_from_keydata(gcm, &[0x42; 16]).await;
+    let mut message = *b"message";
+    let tag = cal
+        .aead()
+        .encrypt_in_place(&key, &[0; 12], &mut message, &b"aad"[..])
+        .await;
+    let mut ciphertext = message.to_vec();
+    ciphertext.extend_from_slice(tag.as_ref());
+    let tag = tag.as_ref().to_vec();
+    cal.aead()
+        .decrypt_in_place(&key, &[0; 12], &mut message, &tag, &b"aad"[..])
+        .await
+        .unwrap();
+    assert_eq!(&message, b"message");
+
+    let x25519 = <C::DhProvider as AsyncDhProvider>::Algorithm::from_cose_ecdh(4).unwrap();
+    let alice = cal
+        .dh()
+        .import_secretkey_bytes(x25519.clone(), &[1; 32])
+        .unwrap()
+        .into();
+    let bob = cal.dh().generate(x25519).await;
+    let alice_public = cal.dh().public_key(&alice).await;
+    let bob_public = cal.dh().public_key(&bob).await;
+    let shared_alice = cal.dh().shared_secret(&alice, &bob_public).await.unwrap();
+    let shared_bob = cal.dh().shared_secret(&bob, &alice_public).await.unwrap();
+    assert_eq!(
+        cal.dh().raw_secret_bytes(&shared_alice).as_ref(),
+        cal.dh().raw_secret_bytes(&shared_bob).as_ref()
+    );
+    let alice_public = cal
+        .dh()
+        .export_publickey_bytes(&alice_public)
+        .as_ref()
+        .to_vec();
+
+    [hash, hmac, ciphertext, alice_public]
+}
+
+#[test]
+fn test_async_blocking() {
+    let (outputs, pending) = block_on(exercise(&mut Blocking(RustcryptoCal::new())));
+    assert_eq!(
+        pending, 0,
+        "the blocking adapter completes on the first poll"
+    );
+
+    let mut cal = RustcryptoCal::new();
+    let sha256 = HashAlgorithmOf::<RustcryptoCal>::from_cose_number(-16).unwrap();
+    assert_eq!(
+        outputs[0],
+        HashProvider::hash(&mut cal, sha256, b"abc").as_ref()
+    );
+}
+
+#[test]
+fn test_async_timer_driven() {
+    let (blocking, _) = block_on(exercise(&mut Blocking(RustcryptoCal::new())));
+    let (timer, pending) = block_on(exercise(&mut TimerCal(Blocking(RustcryptoCal::new()))));
+
+    // One wait for each of the 4 hash steps, the key generation, the 2 public keys and the 2
+    // shared secrets; AEAD and HMAC went through the blocking adapter.
+    assert_eq!(pending, 4 + 1 + 2 + 2);
+    assert_eq!(blocking, timer);
+}
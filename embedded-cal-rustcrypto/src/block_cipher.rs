@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+use super::*;
+use aes::cipher::{BlockDecrypt, BlockEncrypt, KeyInit};
+use embedded_cal::{BLOCK_SIZE, BlockCipherProvider, Cal};
+use zeroize::ZeroizeOnDrop;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BlockCipherAlgorithm<BA> {
+    Aes128,
+    Aes192,
+    Aes256,
+    Direct(BA),
+}
+
+impl<BA: embedded_cal::BlockCipherAlgorithm> embedded_cal::BlockCipherAlgorithm
+    for BlockCipherAlgorithm<BA>
+{
+    fn key_length(&self) -> usize {
+        match self {
+            BlockCipherAlgorithm::Aes128 => 16,
+            BlockCipherAlgorithm::Aes192 => 24,
+            BlockCipherAlgorithm::Aes256 => 32,
+            BlockCipherAlgorithm::Direct(a) => a.key_length(),
+        }
+    }
+
+    #[inline]
+    fn from_aes_key_length(length: usize) -> Option<Self> {
+        if let Some(a) = BA::from_aes_key_length(length) {
+            return Some(BlockCipherAlgorithm::Direct(a));
+        }
+        match length {
+            16 => Some(BlockCipherAlgorithm::Aes128),
+            24 => Some(BlockCipherAlgorithm::Aes192),
+            32 => Some(BlockCipherAlgorithm::Aes256),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        let own = [
+            Self::from_aes_key_length(16),
+            Self::from_aes_key_length(24),
+            Self::from_aes_key_length(32),
+        ];
+        BA::all().map(BlockCipherAlgorithm::Direct).chain(
+            own.into_iter()
+                .flatten()
+                .filter(|a| !matches!(a, BlockCipherAlgorithm::Direct(_))),
+        )
+    }
+}
+
+#[allow(
+    clippy::large_enum_variant,
+    reason = "expanded AES keys are large by nature, and keys are expected to be used in place"
+)]
+pub enum BlockCipherKey<BK> {
+    Aes128(aes::Aes128),
+    Aes192(aes::Aes192),
+    Aes256(aes::Aes256),
+    Direct(BK),
+}
+
+impl<BK: ZeroizeOnDrop> ZeroizeOnDrop for BlockCipherKey<BK> {}
+
+impl<Base: Cal> BlockCipherProvider for RustcryptoCalExtender<Base> {
+    type Algorithm = BlockCipherAlgorithm<BlockCipherAlgorithmOf<Base>>;
+    type Key = BlockCipherKey<BlockCipherKeyOf<Base>>;
+
+    fn load_from_keydata(&mut self, alg: Self::Algorithm, key: &[u8]) -> Self::Key {
+        match alg {
+            BlockCipherAlgorithm::Aes128 => BlockCipherKey::Aes128(
+                aes::Aes128::new_from_slice(key).expect("key length mismatch"),
+            ),
+            BlockCipherAlgorithm::Aes192 => BlockCipherKey::Aes192(
+                aes::Aes192::new_from_slice(key).expect("key length mismatch"),
+            ),
+            BlockCipherAlgorithm::Aes256 => BlockCipherKey::Aes256(
+                aes::Aes256::new_from_slice(key).expect("key length mismatch"),
+            ),
+            BlockCipherAlgorithm::Direct(a) => {
+                BlockCipherKey::Direct(self.base.block_cipher().load_from_keydata(a, key))
+            }
+        }
+    }
+
+    fn encrypt_block(&mut self, key: &Self::Key, block: &mut [u8; BLOCK_SIZE]) {
+        match key {
+            BlockCipherKey::Aes128(k) => k.encrypt_block(block.into()),
+            BlockCipherKey::Aes192(k) => k.encrypt_block(block.into()),
+            BlockCipherKey::Aes256(k) => k.encrypt_block(block.into()),
+            BlockCipherKey::Direct(k) => self.base.block_cipher().encrypt_block(k, block),
+        }
+    }
+
+    fn decrypt_block(&mut self, key: &Self::Key, block: &mut [u8; BLOCK_SIZE]) {
+        match key {
+            BlockCipherKey::Aes128(k) => k.decrypt_block(block.into()),
+            BlockCipherKey::Aes192(k) => k.decrypt_block(block.into()),
+            BlockCipherKey::Aes256(k) => k.decrypt_block(block.into()),
+            BlockCipherKey::Direct(k) => self.base.block_cipher().decrypt_block(k, block),
+        }
+    }
+
+    fn encrypt_blocks(&mut self, key: &Self::Key, blocks: &mut [[u8; BLOCK_SIZE]]) {
+        match key {
+            // Forwarding so that the base's multi-block implementation is used.
+            BlockCipherKey::Direct(k) => self.base.block_cipher().encrypt_blocks(k, blocks),
+            _ => {
+                for block in blocks {
+                    self.encrypt_block(key, block);
+                }
+            }
+        }
+    }
+
+    fn decrypt_blocks(&mut self, key: &Self::Key, blocks: &mut [[u8; BLOCK_SIZE]]) {
+        match key {
+            // Forwarding so that the base's multi-block implementation is used.
+            BlockCipherKey::Direct(k) => self.base.block_cipher().decrypt_blocks(k, blocks),
+            _ => {
+                for block in blocks {
+                    self.decrypt_block(key, block);
+                }
+            }
+        }
+    }
+}
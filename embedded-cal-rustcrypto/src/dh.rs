@@ -2,7 +2,11 @@
 // SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
 
 use super::*;
-use embedded_cal::{Cal, DhProvider, ImportError, util::Either};
+use embedded_cal::{
+    Cal, DhProvider, ImportError, OperationError, PublicKeyFormat, TryError, UnsupportedFormat,
+    util::Either,
+};
+use zeroize::{ZeroizeOnDrop, Zeroizing};
 
 impl<Base: Cal> DhProvider for RustcryptoCalExtender<Base> {
     type Algorithm = DhAlgorithm<DhAlgorithmOf<Base>>;
@@ -11,15 +15,20 @@ impl<Base: Cal> DhProvider for RustcryptoCalExtender<Base> {
     type PublicKey = PublicKey<DhPublicKeyOf<Base>>;
     type SharedSecret = SharedSecret<DhSharedSecretOf<Base>>;
 
-    fn generate_visible(&mut self, alg: Self::Algorithm) -> Self::VisibleSecretKey {
+    fn try_generate_visible(
+        &mut self,
+        alg: Self::Algorithm,
+    ) -> Result<Self::VisibleSecretKey, OperationError> {
         // We're not wrapping anything, so no point in deferring to the self RNG.
-        match alg {
+        Ok(match alg {
             DhAlgorithm::P256 => VisibleSecretKey::P256(p256::SecretKey::random(&mut OldRng(self))),
             DhAlgorithm::X25519 => {
                 VisibleSecretKey::X25519(x25519_dalek::StaticSecret::random_from_rng(OldRng(self)))
             }
-            DhAlgorithm::Direct(d) => VisibleSecretKey::Direct(self.base.dh().generate_visible(d)),
-        }
+            DhAlgorithm::Direct(d) => {
+                VisibleSecretKey::Direct(self.base.dh().try_generate_visible(d)?)
+            }
+        })
     }
 
     fn export_secretkey_bytes<'s>(
@@ -45,14 +54,13 @@ impl<Base: Cal> DhProvider for RustcryptoCalExtender<Base> {
         secret: &[u8],
     ) -> Result<Self::VisibleSecretKey, ImportError> {
         Ok(match alg {
-            DhAlgorithm::P256 => VisibleSecretKey::P256(
-                #[allow(
-                    clippy::unnecessary_fallible_conversions,
-                    reason = "GenericArray has panicking From for slices"
-                )]
-                p256::SecretKey::from_bytes(secret.try_into().map_err(|_| ImportError)?)
-                    .map_err(|_| ImportError)?,
-            ),
+            DhAlgorithm::P256 => {
+                // Going through an array because GenericArray has a panicking From for slices.
+                let secret = <[u8; 32]>::try_from(secret).map_err(|_| ImportError)?;
+                VisibleSecretKey::P256(
+                    p256::SecretKey::from_bytes(&secret.into()).map_err(|_| ImportError)?,
+                )
+            }
             // It's one of the nice aspects of x25519 that all values of [u8; 32] are valid curve
             // points, so the only fallible point is the key length.
             DhAlgorithm::X25519 => VisibleSecretKey::X25519(x25519_dalek::StaticSecret::from(
@@ -64,36 +72,43 @@ impl<Base: Cal> DhProvider for RustcryptoCalExtender<Base> {
         })
     }
 
-    fn shared_secret(
+    fn try_shared_secret(
         &mut self,
         private: &Self::SecretKey,
         public: &Self::PublicKey,
-    ) -> Result<Self::SharedSecret, embedded_cal::IncompatibleKeys> {
+    ) -> Result<Self::SharedSecret, TryError<embedded_cal::IncompatibleKeys>> {
         Ok(match (private, public) {
-            (SecretKey::P256(secret_key), PublicKey::P256(public_key)) => SharedSecret::Length32(
-                (*p256::ecdh::diffie_hellman(
-                    secret_key.to_nonzero_scalar(),
-                    public_key.as_affine(),
-                )
-                .raw_secret_bytes())
-                .into(),
-            ),
+            (SecretKey::P256(secret_key), PublicKey::P256(public_key)) => {
+                SharedSecret::Length32(Zeroizing::new(
+                    (*p256::ecdh::diffie_hellman(
+                        secret_key.to_nonzero_scalar(),
+                        public_key.as_affine(),
+                    )
+                    .raw_secret_bytes())
+                    .into(),
+                ))
+            }
             (SecretKey::X25519(secret_key), PublicKey::X25519(public_key)) => {
-                SharedSecret::Length32(secret_key.diffie_hellman(public_key).to_bytes())
+                SharedSecret::Length32(Zeroizing::new(
+                    secret_key.diffie_hellman(public_key).to_bytes(),
+                ))
             }
             (SecretKey::Direct(secret_key), PublicKey::Direct(public_key)) => {
-                SharedSecret::Direct(self.base.dh().shared_secret(secret_key, public_key)?)
+                SharedSecret::Direct(self.base.dh().try_shared_secret(secret_key, public_key)?)
             }
-            _ => return Err(embedded_cal::IncompatibleKeys),
+            _ => return Err(TryError::Failed(embedded_cal::IncompatibleKeys)),
         })
     }
 
-    fn public_key(&mut self, private: &Self::SecretKey) -> Self::PublicKey {
-        match private {
+    fn try_public_key(
+        &mut self,
+        private: &Self::SecretKey,
+    ) -> Result<Self::PublicKey, OperationError> {
+        Ok(match private {
             SecretKey::P256(secret_key) => PublicKey::P256(secret_key.public_key()),
             SecretKey::X25519(secret_key) => PublicKey::X25519(secret_key.into()),
-            SecretKey::Direct(d) => PublicKey::Direct(self.base.dh().public_key(d)),
-        }
+            SecretKey::Direct(d) => PublicKey::Direct(self.base.dh().try_public_key(d)?),
+        })
     }
 
     fn raw_secret_bytes<'s>(
@@ -159,6 +174,70 @@ impl<Base: Cal> DhProvider for RustcryptoCalExtender<Base> {
                 .map(PublicKey::Direct),
         }
     }
+
+    fn export_publickey<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+        format: PublicKeyFormat,
+    ) -> Result<impl AsRef<[u8]> + use<'p, Base>, UnsupportedFormat> {
+        use p256::elliptic_curve::sec1::ToEncodedPoint;
+        const MAX_PUBLICKEY_BYTES_LEN: usize = 65;
+        let own = |data: &[u8]| {
+            Either::Own(
+                heapless::Vec::<u8, MAX_PUBLICKEY_BYTES_LEN>::from_slice(data)
+                    .expect("public keys fit the buffer"),
+            )
+        };
+        Ok(match (public, format) {
+            (PublicKey::P256(public_key), PublicKeyFormat::Compact) => {
+                own(public_key.to_encoded_point(false).x().unwrap())
+            }
+            (PublicKey::P256(public_key), PublicKeyFormat::Compressed) => {
+                own(public_key.to_encoded_point(true).as_bytes())
+            }
+            (PublicKey::P256(public_key), PublicKeyFormat::Uncompressed) => {
+                own(public_key.to_encoded_point(false).as_bytes())
+            }
+            (PublicKey::X25519(public_key), PublicKeyFormat::Compact) => own(public_key.as_bytes()),
+            (PublicKey::X25519(_), _) => return Err(UnsupportedFormat),
+            (PublicKey::Direct(d), format) => {
+                Either::Direct(self.base.dh().export_publickey(d, format)?)
+            }
+        })
+    }
+
+    fn import_publickey(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+        format: PublicKeyFormat,
+    ) -> Result<Self::PublicKey, ImportError> {
+        match (alg, format) {
+            (DhAlgorithm::Direct(d), format) => self
+                .base
+                .dh()
+                .import_publickey(d, data, format)
+                .map(PublicKey::Direct),
+            (alg, PublicKeyFormat::Compact) => self.import_publickey_bytes(alg, data),
+            (DhAlgorithm::P256, format) => {
+                // `from_sec1_bytes` takes either SEC1 form, so the form is checked first.
+                let prefix_ok = match format {
+                    PublicKeyFormat::Compressed => {
+                        data.len() == 33 && matches!(data[0], 0x02 | 0x03)
+                    }
+                    _ => data.len() == 65 && data[0] == 0x04,
+                };
+                if !prefix_ok {
+                    return Err(ImportError);
+                }
+                // This checks that the point is on the curve.
+                Ok(PublicKey::P256(
+                    p256::PublicKey::from_sec1_bytes(data).map_err(|_| ImportError)?,
+                ))
+            }
+            (DhAlgorithm::X25519, _) => Err(ImportError),
+        }
+    }
 }
 
 #[derive(PartialEq, Eq, Debug, Clone)]
@@ -189,6 +268,50 @@ impl<BA: embedded_cal::DhAlgorithm> embedded_cal::DhAlgorithm for DhAlgorithm<BA
             _ => return None,
         })
     }
+
+    #[inline]
+    fn from_tls_named_group(group: u16) -> Option<Self> {
+        if let Some(d) = BA::from_tls_named_group(group) {
+            return Some(DhAlgorithm::Direct(d));
+        };
+        Some(match group {
+            23 => DhAlgorithm::P256,
+            29 => DhAlgorithm::X25519,
+            _ => return None,
+        })
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        let own = [Self::from_cose_ecdh(1), Self::from_cose_ecdh(4)];
+        BA::all().map(DhAlgorithm::Direct).chain(
+            own.into_iter()
+                .flatten()
+                .filter(|a| !matches!(a, DhAlgorithm::Direct(_))),
+        )
+    }
+
+    fn cose_ecdh(&self) -> Option<i128> {
+        match self {
+            DhAlgorithm::P256 => Some(1),
+            DhAlgorithm::X25519 => Some(4),
+            DhAlgorithm::Direct(d) => d.cose_ecdh(),
+        }
+    }
+
+    fn ni_id(&self) -> Option<u8> {
+        match self {
+            DhAlgorithm::Direct(d) => d.ni_id(),
+            _ => None,
+        }
+    }
+
+    fn ni_name(&self) -> Option<&'static str> {
+        match self {
+            DhAlgorithm::Direct(d) => d.ni_name(),
+            _ => None,
+        }
+    }
 }
 
 pub enum VisibleSecretKey<BVSK> {
@@ -197,6 +320,8 @@ pub enum VisibleSecretKey<BVSK> {
     Direct(BVSK),
 }
 
+impl<BVSK: ZeroizeOnDrop> ZeroizeOnDrop for VisibleSecretKey<BVSK> {}
+
 impl<BVSK, BSK> From<VisibleSecretKey<BVSK>> for SecretKey<BSK>
 where
     BVSK: Into<BSK>,
@@ -218,6 +343,8 @@ pub enum SecretKey<BSK> {
     Direct(BSK),
 }
 
+impl<BSK: ZeroizeOnDrop> ZeroizeOnDrop for SecretKey<BSK> {}
+
 pub enum PublicKey<BPK> {
     P256(p256::PublicKey),
     X25519(x25519_dalek::PublicKey),
@@ -225,11 +352,13 @@ pub enum PublicKey<BPK> {
 }
 
 pub enum SharedSecret<BSS> {
-    Length32([u8; 32]),
+    Length32(Zeroizing<[u8; 32]>),
     Direct(BSS),
 }
 
-struct OldRng<'c, C: embedded_cal::Cal>(&'c mut C);
+impl<BSS: ZeroizeOnDrop> ZeroizeOnDrop for SharedSecret<BSS> {}
+
+pub(crate) struct OldRng<'c, C: embedded_cal::Cal>(pub(crate) &'c mut C);
 
 impl<'c, C: embedded_cal::Cal + rand_core::CryptoRng> rand_core_06::CryptoRng for OldRng<'c, C> {}
 impl<'c, C: embedded_cal::Cal + rand_core::CryptoRng> rand_core_06::RngCore for OldRng<'c, C> {
@@ -0,0 +1,177 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+use super::*;
+use embedded_cal::{Cal, EccProvider, ImportError, IncompatibleKeys, util::Either};
+use p256::elliptic_curve::{
+    PrimeField,
+    group::Group,
+    sec1::{FromEncodedPoint, ToEncodedPoint},
+};
+use zeroize::{ZeroizeOnDrop, Zeroizing};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EccAlgorithm<BA> {
+    P256,
+    Direct(BA),
+}
+
+impl<BA: embedded_cal::EccAlgorithm> embedded_cal::EccAlgorithm for EccAlgorithm<BA> {
+    fn coordinate_length(&self) -> usize {
+        match self {
+            EccAlgorithm::P256 => 32,
+            EccAlgorithm::Direct(a) => a.coordinate_length(),
+        }
+    }
+
+    fn scalar_length(&self) -> usize {
+        match self {
+            EccAlgorithm::P256 => 32,
+            EccAlgorithm::Direct(a) => a.scalar_length(),
+        }
+    }
+
+    #[inline]
+    fn from_cose_curve(curve: impl Into<i128>) -> Option<Self> {
+        let curve: i128 = curve.into();
+        if let Some(a) = BA::from_cose_curve(curve) {
+            return Some(EccAlgorithm::Direct(a));
+        }
+        match curve {
+            1 => Some(EccAlgorithm::P256),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        let own = [Self::from_cose_curve(1)];
+        BA::all().map(EccAlgorithm::Direct).chain(
+            own.into_iter()
+                .flatten()
+                .filter(|a| !matches!(a, EccAlgorithm::Direct(_))),
+        )
+    }
+
+    fn cose_curve(&self) -> Option<i128> {
+        match self {
+            EccAlgorithm::P256 => Some(1),
+            EccAlgorithm::Direct(a) => a.cose_curve(),
+        }
+    }
+}
+
+pub enum EccPoint<BP> {
+    P256(p256::ProjectivePoint),
+    Direct(BP),
+}
+
+pub enum EccScalar<BS> {
+    P256(Zeroizing<p256::Scalar>),
+    Direct(BS),
+}
+
+impl<BS: ZeroizeOnDrop> ZeroizeOnDrop for EccScalar<BS> {}
+
+impl<Base: Cal> EccProvider for RustcryptoCalExtender<Base> {
+    type Algorithm = EccAlgorithm<EccAlgorithmOf<Base>>;
+    type Point = EccPoint<EccPointOf<Base>>;
+    type Scalar = EccScalar<EccScalarOf<Base>>;
+
+    fn import_point(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::Point, ImportError> {
+        match alg {
+            EccAlgorithm::P256 => {
+                let encoded = p256::EncodedPoint::from_bytes(data).map_err(|_| ImportError)?;
+                // This checks that the coordinates are reduced and the point is on the curve.
+                let affine: Option<p256::AffinePoint> =
+                    p256::AffinePoint::from_encoded_point(&encoded).into();
+                Ok(EccPoint::P256(affine.ok_or(ImportError)?.into()))
+            }
+            EccAlgorithm::Direct(a) => Ok(EccPoint::Direct(self.base.ecc().import_point(a, data)?)),
+        }
+    }
+
+    fn export_point<'p>(
+        &mut self,
+        point: &'p Self::Point,
+        compressed: bool,
+    ) -> impl AsRef<[u8]> + use<'p, Base> {
+        match point {
+            EccPoint::P256(p) => Either::Own(p.to_affine().to_encoded_point(compressed)),
+            EccPoint::Direct(p) => Either::Direct(self.base.ecc().export_point(p, compressed)),
+        }
+    }
+
+    fn import_scalar(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::Scalar, ImportError> {
+        match alg {
+            EccAlgorithm::P256 => {
+                let repr: [u8; 32] = data.try_into().map_err(|_| ImportError)?;
+                let scalar: Option<p256::Scalar> = p256::Scalar::from_repr(repr.into()).into();
+                Ok(EccScalar::P256(Zeroizing::new(scalar.ok_or(ImportError)?)))
+            }
+            EccAlgorithm::Direct(a) => {
+                Ok(EccScalar::Direct(self.base.ecc().import_scalar(a, data)?))
+            }
+        }
+    }
+
+    fn generator(&mut self, alg: Self::Algorithm) -> Self::Point {
+        match alg {
+            EccAlgorithm::P256 => EccPoint::P256(p256::ProjectivePoint::GENERATOR),
+            EccAlgorithm::Direct(a) => EccPoint::Direct(self.base.ecc().generator(a)),
+        }
+    }
+
+    fn identity(&mut self, alg: Self::Algorithm) -> Self::Point {
+        match alg {
+            EccAlgorithm::P256 => EccPoint::P256(p256::ProjectivePoint::IDENTITY),
+            EccAlgorithm::Direct(a) => EccPoint::Direct(self.base.ecc().identity(a)),
+        }
+    }
+
+    fn is_identity(&mut self, point: &Self::Point) -> bool {
+        match point {
+            EccPoint::P256(p) => p.is_identity().into(),
+            EccPoint::Direct(p) => self.base.ecc().is_identity(p),
+        }
+    }
+
+    fn negate(&mut self, point: &Self::Point) -> Self::Point {
+        match point {
+            EccPoint::P256(p) => EccPoint::P256(-*p),
+            EccPoint::Direct(p) => EccPoint::Direct(self.base.ecc().negate(p)),
+        }
+    }
+
+    fn add(&mut self, a: &Self::Point, b: &Self::Point) -> Result<Self::Point, IncompatibleKeys> {
+        match (a, b) {
+            (EccPoint::P256(a), EccPoint::P256(b)) => Ok(EccPoint::P256(a + b)),
+            (EccPoint::Direct(a), EccPoint::Direct(b)) => {
+                Ok(EccPoint::Direct(self.base.ecc().add(a, b)?))
+            }
+            _ => Err(IncompatibleKeys),
+        }
+    }
+
+    fn mul(
+        &mut self,
+        scalar: &Self::Scalar,
+        point: &Self::Point,
+    ) -> Result<Self::Point, IncompatibleKeys> {
+        match (scalar, point) {
+            (EccScalar::P256(k), EccPoint::P256(p)) => Ok(EccPoint::P256(p * &**k)),
+            (EccScalar::Direct(k), EccPoint::Direct(p)) => {
+                Ok(EccPoint::Direct(self.base.ecc().mul(k, p)?))
+            }
+            _ => Err(IncompatibleKeys),
+        }
+    }
+}
@@ -0,0 +1,469 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+//! Tests for the [`Fallback`] extender, with a simulated hardware back-end that fails the way the
+//! nRF54L15 back-end can: Its random number generator may report a fault, and it only takes AADs
+//! of up to 255 bytes.
+
+use embedded_cal::empty::EmptyCal;
+use embedded_cal::fallback::{EventLog, Fallback, FallbackEvent, Mirrored, Operation};
+use embedded_cal::router::{Routed, RoutedVisible};
+use embedded_cal::{
+    AadGenerator, AeadAlgorithm, AeadProvider, Cal, DecryptionFailed, DhAlgorithm, DhProvider,
+    HashAlgorithm, HashProvider, HmacAlgorithm, ImportError, IncompatibleKeys, OperationError,
+    PublicKeyFormat, TryError, UnsupportedFormat,
+};
+
+use super::*;
+
+const MAX_AAD_LEN: usize = 255;
+
+/// A simulated hardware back-end.
+///
+/// AEAD, key establishment and hashing are performed in software, but fail where configured to;
+/// everything else is left to an [`EmptyCal`].
+struct FaultyCal {
+    software: RustcryptoCal,
+    empty: EmptyCal<false>,
+    /// Makes key generation fail with [`OperationError::HardwareFault`].
+    rng_fault: bool,
+    /// Makes starting a hash operation fail with [`OperationError::HardwareFault`].
+    hash_fault: bool,
+}
+
+impl FaultyCal {
+    fn new() -> Self {
+        Self {
+            software: RustcryptoCal::new(),
+            empty: EmptyCal,
+            rng_fault: false,
+            hash_fault: false,
+        }
+    }
+}
+
+fn check_aad(aad: &impl AadGenerator) -> Result<(), OperationError> {
+    if aad.items().map(<[u8]>::len).sum::<usize>() > MAX_AAD_LEN {
+        return Err(OperationError::ResourceExhausted);
+    }
+    Ok(())
+}
+
+impl Cal for FaultyCal {
+    type DhProvider = Self;
+    type AeadProvider = Self;
+    type HashProvider = Self;
+    type HmacProvider = EmptyCal<false>;
+    type SignatureProvider = EmptyCal<false>;
+    type KemProvider = EmptyCal<false>;
+    type BlockCipherProvider = EmptyCal<false>;
+    type EccProvider = EmptyCal<false>;
+    type MacProvider = EmptyCal<false>;
+    type KeyWrapProvider = EmptyCal<false>;
+
+    fn dh(&mut self) -> &mut Self::DhProvider {
+        self
+    }
+    fn aead(&mut self) -> &mut Self::AeadProvider {
+        self
+    }
+    fn hash(&mut self) -> &mut Self::HashProvider {
+        self
+    }
+    fn hmac(&mut self) -> &mut Self::HmacProvider {
+        &mut self.empty
+    }
+    fn signature(&mut self) -> &mut Self::SignatureProvider {
+        &mut self.empty
+    }
+    fn kem(&mut self) -> &mut Self::KemProvider {
+        &mut self.empty
+    }
+    fn block_cipher(&mut self) -> &mut Self::BlockCipherProvider {
+        &mut self.empty
+    }
+    fn ecc(&mut self) -> &mut Self::EccProvider {
+        &mut self.empty
+    }
+    fn mac(&mut self) -> &mut Self::MacProvider {
+        &mut self.empty
+    }
+    fn key_wrap(&mut self) -> &mut Self::KeyWrapProvider {
+        &mut self.empty
+    }
+}
+
+impl AeadProvider for FaultyCal {
+    type Algorithm = AeadAlgorithmOf<RustcryptoCal>;
+    type Key = AeadKeyOf<RustcryptoCal>;
+    type Tag = AeadTagOf<RustcryptoCal>;
+
+    fn try_load_from_keydata(
+        &mut self,
+        alg: Self::Algorithm,
+        key: &[u8],
+    ) -> Result<Self::Key, OperationError> {
+        AeadProvider::try_load_from_keydata(&mut self.software, alg, key)
+    }
+
+    fn try_encrypt_in_place(
+        &mut self,
+        key: &Self::Key,
+        nonce: &[u8],
+        message: &mut [u8],
+        aad: impl AadGenerator,
+    ) -> Result<Self::Tag, OperationError> {
+        check_aad(&aad)?;
+        AeadProvider::try_encrypt_in_place(&mut self.software, key, nonce, message, aad)
+    }
+
+    fn try_decrypt_in_place(
+        &mut self,
+        key: &Self::Key,
+        nonce: &[u8],
+        message: &mut [u8],
+        tag: &[u8],
+        aad: impl AadGenerator,
+    ) -> Result<(), TryError<DecryptionFailed>> {
+        check_aad(&aad)?;
+        AeadProvider::try_decrypt_in_place(&mut self.software, key, nonce, message, tag, aad)
+    }
+}
+
+impl DhProvider for FaultyCal {
+    type Algorithm = DhAlgorithmOf<RustcryptoCal>;
+    type VisibleSecretKey = DhVisibleSecretKeyOf<RustcryptoCal>;
+    type SecretKey = DhSecretKeyOf<RustcryptoCal>;
+    type PublicKey = DhPublicKeyOf<RustcryptoCal>;
+    type SharedSecret = DhSharedSecretOf<RustcryptoCal>;
+
+    fn try_generate_visible(
+        &mut self,
+        alg: Self::Algorithm,
+    ) -> Result<Self::VisibleSecretKey, OperationError> {
+        if self.rng_fault {
+            return Err(OperationError::HardwareFault);
+        }
+        DhProvider::try_generate_visible(&mut self.software, alg)
+    }
+
+    fn export_secretkey_bytes<'s>(
+        &mut self,
+        secretkey: &'s Self::VisibleSecretKey,
+    ) -> impl AsRef<[u8]> + use<'s> {
+        DhProvider::export_secretkey_bytes(&mut self.software, secretkey)
+    }
+
+    fn import_secretkey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        secret: &[u8],
+    ) -> Result<Self::VisibleSecretKey, ImportError> {
+        DhProvider::import_secretkey_bytes(&mut self.software, alg, secret)
+    }
+
+    fn export_publickey_bytes<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+    ) -> impl AsRef<[u8]> + use<'p> {
+        DhProvider::export_publickey_bytes(&mut self.software, public)
+    }
+
+    fn import_publickey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::PublicKey, ImportError> {
+        DhProvider::import_publickey_bytes(&mut self.software, alg, data)
+    }
+
+    fn export_publickey<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+        format: PublicKeyFormat,
+    ) -> Result<impl AsRef<[u8]> + use<'p>, UnsupportedFormat> {
+        DhProvider::export_publickey(&mut self.software, public, format)
+    }
+
+    fn import_publickey(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+        format: PublicKeyFormat,
+    ) -> Result<Self::PublicKey, ImportError> {
+        DhProvider::import_publickey(&mut self.software, alg, data, format)
+    }
+
+    fn try_shared_secret(
+        &mut self,
+        private: &Self::SecretKey,
+        public: &Self::PublicKey,
+    ) -> Result<Self::SharedSecret, TryError<IncompatibleKeys>> {
+        DhProvider::try_shared_secret(&mut self.software, private, public)
+    }
+
+    fn try_public_key(
+        &mut self,
+        private: &Self::SecretKey,
+    ) -> Result<Self::PublicKey, OperationError> {
+        DhProvider::try_public_key(&mut self.software, private)
+    }
+
+    fn raw_secret_bytes<'s>(
+        &mut self,
+        secret: &'s Self::SharedSecret,
+    ) -> impl AsRef<[u8]> + use<'s> {
+        DhProvider::raw_secret_bytes(&mut self.software, secret)
+    }
+}
+
+impl HashProvider for FaultyCal {
+    type Algorithm = HashAlgorithmOf<RustcryptoCal>;
+    type State = HashStateOf<RustcryptoCal>;
+    type Output = HashOutputOf<RustcryptoCal>;
+    type XofReader = HashXofReaderOf<RustcryptoCal>;
+
+    fn try_init(&mut self, algorithm: Self::Algorithm) -> Result<Self::State, OperationError> {
+        if self.hash_fault {
+            return Err(OperationError::HardwareFault);
+        }
+        HashProvider::try_init(&mut self.software, algorithm)
+    }
+
+    fn try_update(
+        &mut self,
+        instance: &mut Self::State,
+        data: &[u8],
+    ) -> Result<(), OperationError> {
+        HashProvider::try_update(&mut self.software, instance, data)
+    }
+
+    fn try_finalize(&mut self, instance: Self::State) -> Result<Self::Output, OperationError> {
+        HashProvider::try_finalize(&mut self.software, instance)
+    }
+
+    fn finalize_xof(&mut self, instance: Self::State) -> Result<Self::XofReader, Self::State> {
+        HashProvider::finalize_xof(&mut self.software, instance)
+    }
+
+    fn squeeze(&mut self, reader: &mut Self::XofReader, output: &mut [u8]) {
+        HashProvider::squeeze(&mut self.software, reader, output)
+    }
+}
+
+type TestCal = Fallback<FaultyCal, RustcryptoCal>;
+type MirroringCal = Fallback<FaultyCal, RustcryptoCal, EventLog, true>;
+
+fn event_log<const MIRROR: bool>(
+    cal: &mut Fallback<FaultyCal, RustcryptoCal, EventLog, MIRROR>,
+) -> (usize, Option<FallbackEvent>) {
+    (cal.recorder().count(), cal.recorder().last())
+}
+
+#[test]
+fn test_enumeration() {
+    testvectors::algorithms::test_algorithm_enumeration::<TestCal>();
+    testvectors::algorithms::test_algorithm_identifiers::<TestCal>();
+
+    // The simulated hardware has no HMAC, so that comes from software.
+    assert!(HmacAlgorithmOf::<TestCal>::all().all(|a| matches!(a.routed(), Routed::B(_))));
+}
+
+#[test]
+fn test_aead_fallback() {
+    let mut cal = MirroringCal::new(FaultyCal::new(), RustcryptoCal::new());
+    let mut reference = RustcryptoCal::new();
+
+    testvectors::test_aead_aesccm_16_64_128(&mut cal);
+    assert_eq!(event_log(&mut cal), (0, None));
+
+    let alg = AeadAlgorithmOf::<MirroringCal>::from_cose_number(10).unwrap();
+    let key = cal.aead().load_from_keydata(alg, &[0x2a; 16]);
+    assert!(matches!(key, Mirrored::Both(..)));
+    let reference_alg = AeadAlgorithmOf::<RustcryptoCal>::from_cose_number(10).unwrap();
+    let reference_key = AeadProvider::load_from_keydata(&mut reference, reference_alg, &[0x2a; 16]);
+
+    let nonce = [0; 13];
+    let aad = [0x55; MAX_AAD_LEN + 1];
+    let mut message = *b"attack at dawn";
+    let mut expected = message;
+
+    let tag = cal
+        .aead()
+        .encrypt_in_place(&key, &nonce, &mut message, &aad[..MAX_AAD_LEN]);
+    assert!(matches!(tag, Routed::A(_)));
+    assert_eq!(event_log(&mut cal), (0, None));
+    let decrypted = cal.aead().decrypt_in_place(
+        &key,
+        &nonce,
+        &mut message,
+        tag.as_ref(),
+        &aad[..MAX_AAD_LEN],
+    );
+    assert!(decrypted.is_ok());
+
+    // Too long for the hardware
+    let tag = cal
+        .aead()
+        .encrypt_in_place(&key, &nonce, &mut message, &aad[..]);
+    assert!(matches!(tag, Routed::B(_)));
+    let long_aad_encryption = FallbackEvent {
+        operation: Operation::AeadEncrypt,
+        error: OperationError::ResourceExhausted,
+    };
+    assert_eq!(event_log(&mut cal), (1, Some(long_aad_encryption)));
+    let expected_tag = AeadProvider::encrypt_in_place(
+        &mut reference,
+        &reference_key,
+        &nonce,
+        &mut expected,
+        &aad[..],
+    );
+    assert_eq!(tag.as_ref(), expected_tag.as_ref());
+    assert_eq!(message, expected);
+
+    let mut tampered = message;
+    tampered[0] ^= 1;
+    assert!(matches!(
+        cal.aead()
+            .decrypt_in_place(&key, &nonce, &mut tampered, tag.as_ref(), &aad[..]),
+        Err(DecryptionFailed)
+    ));
+    cal.aead()
+        .decrypt_in_place(&key, &nonce, &mut message, tag.as_ref(), &aad[..])
+        .unwrap();
+    assert_eq!(&message, b"attack at dawn");
+    let long_aad_decryption = FallbackEvent {
+        operation: Operation::AeadDecrypt,
+        error: OperationError::ResourceExhausted,
+    };
+    assert_eq!(event_log(&mut cal), (3, Some(long_aad_decryption)));
+}
+
+#[test]
+fn test_aead_without_mirroring() {
+    let mut cal = TestCal::new(FaultyCal::new(), RustcryptoCal::new());
+
+    testvectors::test_aead_aesccm_16_64_128(&mut cal);
+
+    // The key stays in hardware, so the operation can not be retried.
+    let alg = AeadAlgorithmOf::<TestCal>::from_cose_number(10).unwrap();
+    let key = cal.aead().load_from_keydata(alg, &[0x2a; 16]);
+    assert!(matches!(key, Mirrored::Hardware(_)));
+    let aad = [0; MAX_AAD_LEN + 1];
+    let mut message = *b"attack at dawn";
+    assert_eq!(
+        cal.aead()
+            .try_encrypt_in_place(&key, &[0; 13], &mut message, &aad[..])
+            .err(),
+        Some(OperationError::ResourceExhausted)
+    );
+    assert_eq!(&message, b"attack at dawn");
+    assert_eq!(event_log(&mut cal), (0, None));
+}
+
+#[test]
+fn test_no_fallback_available() {
+    // A software back-end without the algorithm can not take over.
+    type TestCal = Fallback<FaultyCal, EmptyCal<false>, EventLog, true>;
+    let mut cal = TestCal::new(FaultyCal::new(), EmptyCal::<false>);
+
+    let alg = AeadAlgorithmOf::<TestCal>::from_cose_number(10).unwrap();
+    let key = cal.aead().load_from_keydata(alg, &[0x2a; 16]);
+    assert!(matches!(key, Mirrored::Hardware(_)));
+    let aad = [0; MAX_AAD_LEN + 1];
+    assert_eq!(
+        cal.aead()
+            .try_encrypt_in_place(&key, &[0; 13], &mut [], &aad[..])
+            .err(),
+        Some(OperationError::ResourceExhausted)
+    );
+    assert_eq!(cal.recorder().count(), 0);
+}
+
+#[test]
+fn test_dh_fallback() {
+    let mut cal = TestCal::new(FaultyCal::new(), RustcryptoCal::new());
+    let mut peer = RustcryptoCal::new();
+
+    for vec in testvectors::dh::RFC5903_P256 {
+        vec.test_with(&mut cal);
+    }
+
+    cal.hardware().rng_fault = true;
+
+    let p256 = DhAlgorithmOf::<TestCal>::from_cose_ecdh(1).unwrap();
+    let private = cal.dh().generate_visible(p256.clone());
+    assert!(matches!(private, RoutedVisible::B(_)));
+    let generation = FallbackEvent {
+        operation: Operation::DhGenerate,
+        error: OperationError::HardwareFault,
+    };
+    assert_eq!(event_log(&mut cal), (1, Some(generation)));
+    let private = private.into();
+    let public = cal.dh().public_key(&private);
+    let public = cal.dh().export_publickey_bytes(&public).as_ref().to_vec();
+
+    let peer_alg = DhAlgorithmOf::<RustcryptoCal>::from_cose_ecdh(1).unwrap();
+    let peer_private = DhProvider::generate(&mut peer, peer_alg.clone());
+    let peer_public = DhProvider::public_key(&mut peer, &peer_private);
+    let peer_public = DhProvider::export_publickey_bytes(&mut peer, &peer_public)
+        .as_ref()
+        .to_vec();
+
+    // The peer's key is selected through the hardware's algorithm, but still usable with the key
+    // that was generated in software.
+    let peer_public = cal.dh().import_publickey_bytes(p256, &peer_public).unwrap();
+    assert!(matches!(peer_public, Mirrored::Both(..)));
+    let shared = cal.dh().shared_secret(&private, &peer_public).unwrap();
+
+    let public = DhProvider::import_publickey_bytes(&mut peer, peer_alg, &public).unwrap();
+    let peer_shared = DhProvider::shared_secret(&mut peer, &peer_private, &public).unwrap();
+    assert_eq!(
+        cal.dh().raw_secret_bytes(&shared).as_ref(),
+        DhProvider::raw_secret_bytes(&mut peer, &peer_shared).as_ref()
+    );
+    assert_eq!(cal.recorder().count(), 1);
+}
+
+#[test]
+fn test_hash_fallback() {
+    let mut cal = TestCal::new(FaultyCal::new(), RustcryptoCal::new());
+    cal.hardware().hash_fault = true;
+
+    let sha256 = HashAlgorithmOf::<TestCal>::from_cose_number(-16).unwrap();
+    assert!(matches!(sha256.routed(), Routed::A(_)));
+    let state = HashProvider::init(&mut cal, sha256.clone());
+    assert!(matches!(state, Routed::B(_)));
+    let output = HashProvider::finalize(&mut cal, state);
+    assert_eq!(
+        event_log(&mut cal),
+        (
+            1,
+            Some(FallbackEvent {
+                operation: Operation::HashInit,
+                error: OperationError::HardwareFault,
+            })
+        )
+    );
+    assert_eq!(
+        output.as_ref(),
+        HashProvider::hash(&mut cal, sha256, b"").as_ref()
+    );
+    assert_eq!(cal.recorder().last().unwrap().operation, Operation::Hash);
+
+    testvectors::test_hash_algorithm_sha256(&mut cal);
+    assert!(cal.recorder().count() > 2);
+}
+
+#[test]
+fn test_event_log() {
+    let mut log = EventLog::default();
+    assert_eq!((log.count(), log.last()), (0, None));
+    let event = FallbackEvent {
+        operation: Operation::HmacInit,
+        error: OperationError::HardwareFault,
+    };
+    embedded_cal::fallback::Recorder::record(&mut log, event);
+    assert_eq!((log.count(), log.last()), (1, Some(event)));
+}
@@ -2,11 +2,19 @@
 // SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
 
 use super::*;
-use embedded_cal::{Cal, HashProvider};
+use digest::{ExtendableOutput, XofReader};
+use embedded_cal::{Cal, HashProvider, OperationError};
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub enum HashAlgorithm<BA> {
     Sha256,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+    /// SHAKE128, with a fixed output length of 32 bytes unless used as an XOF.
+    Shake128,
+    /// SHAKE256, with a fixed output length of 64 bytes unless used as an XOF.
+    Shake256,
     Direct(BA),
 }
 
@@ -14,6 +22,11 @@ impl<BA: embedded_cal::HashAlgorithm> embedded_cal::HashAlgorithm for HashAlgori
     fn len(&self) -> usize {
         match self {
             HashAlgorithm::Sha256 => 32,
+            HashAlgorithm::Sha3_256 => 32,
+            HashAlgorithm::Sha3_384 => 48,
+            HashAlgorithm::Sha3_512 => 64,
+            HashAlgorithm::Shake128 => 32,
+            HashAlgorithm::Shake256 => 64,
             HashAlgorithm::Direct(a) => a.len(),
         }
     }
@@ -26,6 +39,8 @@ impl<BA: embedded_cal::HashAlgorithm> embedded_cal::HashAlgorithm for HashAlgori
         }
         match number {
             -16 => Some(HashAlgorithm::Sha256),
+            -18 => Some(HashAlgorithm::Shake128),
+            -45 => Some(HashAlgorithm::Shake256),
             _ => None,
         }
     }
@@ -36,6 +51,9 @@ impl<BA: embedded_cal::HashAlgorithm> embedded_cal::HashAlgorithm for HashAlgori
         }
         match number {
             1 => Some(HashAlgorithm::Sha256),
+            10 => Some(HashAlgorithm::Sha3_256),
+            11 => Some(HashAlgorithm::Sha3_384),
+            12 => Some(HashAlgorithm::Sha3_512),
             _ => None,
         }
     }
@@ -46,19 +64,96 @@ impl<BA: embedded_cal::HashAlgorithm> embedded_cal::HashAlgorithm for HashAlgori
         }
         match name {
             "sha-256" => Some(HashAlgorithm::Sha256),
+            "sha3-256" => Some(HashAlgorithm::Sha3_256),
+            "sha3-384" => Some(HashAlgorithm::Sha3_384),
+            "sha3-512" => Some(HashAlgorithm::Sha3_512),
             _ => None,
         }
     }
+
+    #[inline]
+    fn from_tls_hash_algorithm(number: u8) -> Option<Self> {
+        if let Some(a) = BA::from_tls_hash_algorithm(number) {
+            return Some(HashAlgorithm::Direct(a));
+        }
+        match number {
+            4 => Some(HashAlgorithm::Sha256),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        // Where the base takes precedence, the constructors produce its variant, which is then
+        // already listed.
+        let own = [
+            Self::from_cose_number(-16),
+            Self::from_ni_id(10),
+            Self::from_ni_id(11),
+            Self::from_ni_id(12),
+            Self::from_cose_number(-18),
+            Self::from_cose_number(-45),
+        ];
+        BA::all().map(HashAlgorithm::Direct).chain(
+            own.into_iter()
+                .flatten()
+                .filter(|a| !matches!(a, HashAlgorithm::Direct(_))),
+        )
+    }
+
+    fn cose_number(&self) -> Option<i128> {
+        match self {
+            HashAlgorithm::Sha256 => Some(-16),
+            HashAlgorithm::Shake128 => Some(-18),
+            HashAlgorithm::Shake256 => Some(-45),
+            HashAlgorithm::Sha3_256 | HashAlgorithm::Sha3_384 | HashAlgorithm::Sha3_512 => None,
+            HashAlgorithm::Direct(a) => a.cose_number(),
+        }
+    }
+    fn ni_id(&self) -> Option<u8> {
+        match self {
+            HashAlgorithm::Sha256 => Some(1),
+            HashAlgorithm::Sha3_256 => Some(10),
+            HashAlgorithm::Sha3_384 => Some(11),
+            HashAlgorithm::Sha3_512 => Some(12),
+            HashAlgorithm::Shake128 | HashAlgorithm::Shake256 => None,
+            HashAlgorithm::Direct(a) => a.ni_id(),
+        }
+    }
+    fn ni_name(&self) -> Option<&'static str> {
+        match self {
+            HashAlgorithm::Sha256 => Some("sha-256"),
+            HashAlgorithm::Sha3_256 => Some("sha3-256"),
+            HashAlgorithm::Sha3_384 => Some("sha3-384"),
+            HashAlgorithm::Sha3_512 => Some("sha3-512"),
+            HashAlgorithm::Shake128 | HashAlgorithm::Shake256 => None,
+            HashAlgorithm::Direct(a) => a.ni_name(),
+        }
+    }
 }
 
+#[allow(
+    clippy::large_enum_variant,
+    reason = "the Keccak state is large by nature, and states are expected to be used in place"
+)]
 #[derive(Clone)]
 pub enum HashState<BHS> {
     Sha256(sha2::Sha256),
+    Sha3_256(sha3::Sha3_256),
+    Sha3_384(sha3::Sha3_384),
+    Sha3_512(sha3::Sha3_512),
+    Shake128(sha3::Shake128),
+    Shake256(sha3::Shake256),
     Direct(BHS),
 }
 
 pub enum HashResult<BHR> {
     Sha256([u8; 32]),
+    Sha3_256([u8; 32]),
+    Sha3_384([u8; 48]),
+    Sha3_512([u8; 64]),
+    Shake128([u8; 32]),
+    Shake256([u8; 64]),
     Direct(BHR),
 }
 
@@ -66,48 +161,118 @@ impl<BHR: AsRef<[u8]>> AsRef<[u8]> for HashResult<BHR> {
     fn as_ref(&self) -> &[u8] {
         match self {
             HashResult::Sha256(r) => &r[..],
+            HashResult::Sha3_256(r) => &r[..],
+            HashResult::Sha3_384(r) => &r[..],
+            HashResult::Sha3_512(r) => &r[..],
+            HashResult::Shake128(r) => &r[..],
+            HashResult::Shake256(r) => &r[..],
             HashResult::Direct(r) => r.as_ref(),
         }
     }
 }
 
+#[allow(
+    clippy::large_enum_variant,
+    reason = "the Keccak state is large by nature, and readers are expected to be used in place"
+)]
+pub enum HashXofReader<BXR> {
+    Shake128(sha3::Shake128Reader),
+    Shake256(sha3::Shake256Reader),
+    Direct(BXR),
+}
+
 impl<Base: Cal> HashProvider for RustcryptoCalExtender<Base> {
     type Algorithm = HashAlgorithm<HashAlgorithmOf<Base>>;
     type State = HashState<HashStateOf<Base>>;
     type Output = HashResult<HashOutputOf<Base>>;
+    type XofReader = HashXofReader<HashXofReaderOf<Base>>;
 
-    fn init(&mut self, algorithm: Self::Algorithm) -> Self::State {
-        match algorithm {
+    fn try_init(&mut self, algorithm: Self::Algorithm) -> Result<Self::State, OperationError> {
+        Ok(match algorithm {
             // Same for any, really
             HashAlgorithm::Sha256 => HashState::Sha256(Default::default()),
-            HashAlgorithm::Direct(a) => HashState::Direct(self.base.hash().init(a)),
-        }
+            HashAlgorithm::Sha3_256 => HashState::Sha3_256(Default::default()),
+            HashAlgorithm::Sha3_384 => HashState::Sha3_384(Default::default()),
+            HashAlgorithm::Sha3_512 => HashState::Sha3_512(Default::default()),
+            HashAlgorithm::Shake128 => HashState::Shake128(Default::default()),
+            HashAlgorithm::Shake256 => HashState::Shake256(Default::default()),
+            HashAlgorithm::Direct(a) => HashState::Direct(self.base.hash().try_init(a)?),
+        })
     }
 
-    fn update(&mut self, instance: &mut Self::State, data: &[u8]) {
+    fn try_update(
+        &mut self,
+        instance: &mut Self::State,
+        data: &[u8],
+    ) -> Result<(), OperationError> {
         match instance {
             // Same for any, really
-            HashState::Sha256(s) => s.update(data),
-            HashState::Direct(i) => self.base.hash().update(i, data),
+            HashState::Sha256(s) => Digest::update(s, data),
+            HashState::Sha3_256(s) => Digest::update(s, data),
+            HashState::Sha3_384(s) => Digest::update(s, data),
+            HashState::Sha3_512(s) => Digest::update(s, data),
+            HashState::Shake128(s) => digest::Update::update(s, data),
+            HashState::Shake256(s) => digest::Update::update(s, data),
+            HashState::Direct(i) => return self.base.hash().try_update(i, data),
         }
+        Ok(())
     }
 
-    fn finalize(&mut self, instance: Self::State) -> Self::Output {
-        match instance {
+    fn try_finalize(&mut self, instance: Self::State) -> Result<Self::Output, OperationError> {
+        Ok(match instance {
             // Same for any, really
             HashState::Sha256(s) => HashResult::Sha256(s.finalize().into()),
-            HashState::Direct(i) => HashResult::Direct(self.base.hash().finalize(i)),
+            HashState::Sha3_256(s) => HashResult::Sha3_256(s.finalize().into()),
+            HashState::Sha3_384(s) => HashResult::Sha3_384(s.finalize().into()),
+            HashState::Sha3_512(s) => HashResult::Sha3_512(s.finalize().into()),
+            HashState::Shake128(s) => {
+                let mut output = [0; 32];
+                s.finalize_xof_into(&mut output);
+                HashResult::Shake128(output)
+            }
+            HashState::Shake256(s) => {
+                let mut output = [0; 64];
+                s.finalize_xof_into(&mut output);
+                HashResult::Shake256(output)
+            }
+            HashState::Direct(i) => HashResult::Direct(self.base.hash().try_finalize(i)?),
+        })
+    }
+
+    fn finalize_xof(&mut self, instance: Self::State) -> Result<Self::XofReader, Self::State> {
+        match instance {
+            HashState::Shake128(s) => Ok(HashXofReader::Shake128(s.finalize_xof())),
+            HashState::Shake256(s) => Ok(HashXofReader::Shake256(s.finalize_xof())),
+            HashState::Direct(i) => self
+                .base
+                .hash()
+                .finalize_xof(i)
+                .map(HashXofReader::Direct)
+                .map_err(HashState::Direct),
+            other => Err(other),
+        }
+    }
+
+    fn squeeze(&mut self, reader: &mut Self::XofReader, output: &mut [u8]) {
+        match reader {
+            HashXofReader::Shake128(r) => r.read(output),
+            HashXofReader::Shake256(r) => r.read(output),
+            HashXofReader::Direct(r) => self.base.hash().squeeze(r, output),
         }
     }
 
-    fn hash(&mut self, algorithm: Self::Algorithm, data: &[u8]) -> Self::Output {
+    fn try_hash(
+        &mut self,
+        algorithm: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::Output, OperationError> {
         if let HashAlgorithm::Direct(a) = algorithm {
-            return HashResult::Direct(self.base.hash().hash(a, data));
+            return Ok(HashResult::Direct(self.base.hash().try_hash(a, data)?));
         };
 
         // FIXME: Is there any sensible deduplication to be done with the provided impl?
-        let mut state = self.init(algorithm);
-        self.update(&mut state, data);
-        self.finalize(state)
+        let mut state = self.try_init(algorithm)?;
+        self.try_update(&mut state, data)?;
+        self.try_finalize(state)
     }
 }
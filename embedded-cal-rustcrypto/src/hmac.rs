@@ -0,0 +1,201 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+use super::*;
+use ::hmac::Mac;
+use embedded_cal::{
+    Cal, HmacProvider, OperationError, TryError, VerificationFailed,
+    util::{LongerBuf, WipeOnDrop},
+};
+use zeroize::{ZeroizeOnDrop, Zeroizing};
+
+type HmacSha256 = ::hmac::Hmac<sha2::Sha256>;
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum HmacAlgorithm<BA> {
+    HmacSha256,
+    Direct(BA),
+}
+
+impl<BA: embedded_cal::HmacAlgorithm> embedded_cal::HmacAlgorithm for HmacAlgorithm<BA> {
+    const MAX_LEN: usize = if BA::MAX_LEN >= 32 { BA::MAX_LEN } else { 32 };
+
+    // Const generics can not express the maximum of our and the base's length in the buffer type,
+    // so this holds both and hands out the longer one.
+    type MaxLenBuf = LongerBuf<[u8; 32], BA::MaxLenBuf>;
+
+    fn len(&self) -> usize {
+        match self {
+            HmacAlgorithm::HmacSha256 => 32,
+            HmacAlgorithm::Direct(a) => a.len(),
+        }
+    }
+
+    #[inline]
+    fn from_cose_number(number: impl Into<i128>) -> Option<Self> {
+        let number: i128 = number.into();
+        if let Some(a) = BA::from_cose_number(number) {
+            return Some(HmacAlgorithm::Direct(a));
+        }
+        match number {
+            5 => Some(HmacAlgorithm::HmacSha256),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn from_tls_hash_algorithm(number: u8) -> Option<Self> {
+        if let Some(a) = BA::from_tls_hash_algorithm(number) {
+            return Some(HmacAlgorithm::Direct(a));
+        }
+        match number {
+            4 => Some(HmacAlgorithm::HmacSha256),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        let own = [Self::from_cose_number(5)];
+        BA::all().map(HmacAlgorithm::Direct).chain(
+            own.into_iter()
+                .flatten()
+                .filter(|a| !matches!(a, HmacAlgorithm::Direct(_))),
+        )
+    }
+
+    fn cose_number(&self) -> Option<i128> {
+        match self {
+            HmacAlgorithm::HmacSha256 => Some(5),
+            HmacAlgorithm::Direct(a) => a.cose_number(),
+        }
+    }
+
+    fn ni_id(&self) -> Option<u8> {
+        match self {
+            HmacAlgorithm::Direct(a) => a.ni_id(),
+            _ => None,
+        }
+    }
+
+    fn ni_name(&self) -> Option<&'static str> {
+        match self {
+            HmacAlgorithm::Direct(a) => a.ni_name(),
+            _ => None,
+        }
+    }
+}
+
+// The `hmac` crate's state can not be zeroized, but is as good as the key once it has absorbed the
+// padded keys; it is wiped by the container instead.
+
+#[derive(Clone)]
+pub enum HmacKey<BK> {
+    HmacSha256(WipeOnDrop<HmacSha256>),
+    Direct(BK),
+}
+
+impl<BK: ZeroizeOnDrop> ZeroizeOnDrop for HmacKey<BK> {}
+
+pub enum HmacState<BS> {
+    HmacSha256(WipeOnDrop<HmacSha256>),
+    Direct(BS),
+}
+
+impl<BS: ZeroizeOnDrop> ZeroizeOnDrop for HmacState<BS> {}
+
+pub enum HmacResult<BR> {
+    HmacSha256(Zeroizing<[u8; 32]>),
+    Direct(BR),
+}
+
+impl<BR: ZeroizeOnDrop> ZeroizeOnDrop for HmacResult<BR> {}
+
+impl<BR: AsRef<[u8]>> AsRef<[u8]> for HmacResult<BR> {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            HmacResult::HmacSha256(r) => &r[..],
+            HmacResult::Direct(r) => r.as_ref(),
+        }
+    }
+}
+
+impl<Base: Cal> HmacProvider for RustcryptoCalExtender<Base> {
+    type Algorithm = HmacAlgorithm<HmacAlgorithmOf<Base>>;
+    type Key = HmacKey<HmacKeyOf<Base>>;
+    type State = HmacState<HmacStateOf<Base>>;
+    type Output = HmacResult<HmacOutputOf<Base>>;
+
+    fn try_load_from_keydata(
+        &mut self,
+        algorithm: Self::Algorithm,
+        key: &[u8],
+    ) -> Result<Self::Key, OperationError> {
+        Ok(match algorithm {
+            HmacAlgorithm::HmacSha256 => HmacKey::HmacSha256(WipeOnDrop::new(
+                HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length"),
+            )),
+            HmacAlgorithm::Direct(a) => {
+                HmacKey::Direct(self.base.hmac().try_load_from_keydata(a, key)?)
+            }
+        })
+    }
+
+    fn try_init(&mut self, key: Self::Key) -> Result<Self::State, OperationError> {
+        Ok(match key {
+            // The key already is the state after absorbing the inner padded key
+            HmacKey::HmacSha256(k) => HmacState::HmacSha256(k),
+            HmacKey::Direct(k) => HmacState::Direct(self.base.hmac().try_init(k)?),
+        })
+    }
+
+    fn try_update(&mut self, state: &mut Self::State, data: &[u8]) -> Result<(), OperationError> {
+        match state {
+            HmacState::HmacSha256(s) => s.update(data),
+            HmacState::Direct(s) => return self.base.hmac().try_update(s, data),
+        }
+        Ok(())
+    }
+
+    fn try_finalize(&mut self, state: Self::State) -> Result<Self::Output, OperationError> {
+        Ok(match state {
+            HmacState::HmacSha256(s) => HmacResult::HmacSha256(Zeroizing::new(
+                s.into_inner().finalize().into_bytes().into(),
+            )),
+            HmacState::Direct(s) => HmacResult::Direct(self.base.hmac().try_finalize(s)?),
+        })
+    }
+
+    fn try_finalize_into_key(
+        &mut self,
+        algorithm: Self::Algorithm,
+        state: Self::State,
+    ) -> Result<Self::Key, OperationError> {
+        match (algorithm, state) {
+            // The base may be able to keep the key internal.
+            (HmacAlgorithm::Direct(a), HmacState::Direct(s)) => Ok(HmacKey::Direct(
+                self.base.hmac().try_finalize_into_key(a, s)?,
+            )),
+            (algorithm, state) => {
+                let output = self.try_finalize(state)?;
+                self.try_load_from_keydata(algorithm, output.as_ref())
+            }
+        }
+    }
+
+    fn try_verify(
+        &mut self,
+        state: Self::State,
+        expected: &[u8],
+    ) -> Result<(), TryError<VerificationFailed>> {
+        match state {
+            HmacState::HmacSha256(s) => {
+                let output =
+                    Zeroizing::new(<[u8; 32]>::from(s.into_inner().finalize().into_bytes()));
+                embedded_cal::verify_hmac_tag(&output[..], expected).map_err(TryError::Failed)
+            }
+            // The base may be able to compare without exposing its output.
+            HmacState::Direct(s) => self.base.hmac().try_verify(s, expected),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+use super::*;
+use embedded_cal::{Cal, DecryptionFailed, KeyWrapProvider};
+use zeroize::ZeroizeOnDrop;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum KeyWrapAlgorithm<BA> {
+    A128Kw,
+    A192Kw,
+    A256Kw,
+    Direct(BA),
+}
+
+impl<BA: embedded_cal::KeyWrapAlgorithm> embedded_cal::KeyWrapAlgorithm for KeyWrapAlgorithm<BA> {
+    fn key_length(&self) -> usize {
+        match self {
+            KeyWrapAlgorithm::A128Kw => 16,
+            KeyWrapAlgorithm::A192Kw => 24,
+            KeyWrapAlgorithm::A256Kw => 32,
+            KeyWrapAlgorithm::Direct(a) => a.key_length(),
+        }
+    }
+
+    #[inline]
+    fn from_cose_number(number: impl Into<i128>) -> Option<Self> {
+        let number: i128 = number.into();
+        if let Some(a) = BA::from_cose_number(number) {
+            return Some(KeyWrapAlgorithm::Direct(a));
+        }
+        match number {
+            -3 => Some(KeyWrapAlgorithm::A128Kw),
+            -4 => Some(KeyWrapAlgorithm::A192Kw),
+            -5 => Some(KeyWrapAlgorithm::A256Kw),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        let own = [
+            Self::from_cose_number(-3),
+            Self::from_cose_number(-4),
+            Self::from_cose_number(-5),
+        ];
+        BA::all().map(KeyWrapAlgorithm::Direct).chain(
+            own.into_iter()
+                .flatten()
+                .filter(|a| !matches!(a, KeyWrapAlgorithm::Direct(_))),
+        )
+    }
+
+    fn cose_number(&self) -> Option<i128> {
+        match self {
+            KeyWrapAlgorithm::A128Kw => Some(-3),
+            KeyWrapAlgorithm::A192Kw => Some(-4),
+            KeyWrapAlgorithm::A256Kw => Some(-5),
+            KeyWrapAlgorithm::Direct(a) => a.cose_number(),
+        }
+    }
+}
+
+#[allow(
+    clippy::large_enum_variant,
+    reason = "expanded AES keys are large by nature, and keys are expected to be used in place"
+)]
+pub enum KeyWrapKey<BK> {
+    A128Kw(aes_kw::KekAes128),
+    A192Kw(aes_kw::KekAes192),
+    A256Kw(aes_kw::KekAes256),
+    Direct(BK),
+}
+
+impl<BK: ZeroizeOnDrop> ZeroizeOnDrop for KeyWrapKey<BK> {}
+
+impl<Base: Cal> KeyWrapProvider for RustcryptoCalExtender<Base> {
+    type Algorithm = KeyWrapAlgorithm<KeyWrapAlgorithmOf<Base>>;
+    type Key = KeyWrapKey<KeyWrapKeyOf<Base>>;
+
+    fn load_from_keydata(&mut self, alg: Self::Algorithm, key: &[u8]) -> Self::Key {
+        match alg {
+            KeyWrapAlgorithm::A128Kw => {
+                KeyWrapKey::A128Kw(key.try_into().expect("key length mismatch"))
+            }
+            KeyWrapAlgorithm::A192Kw => {
+                KeyWrapKey::A192Kw(key.try_into().expect("key length mismatch"))
+            }
+            KeyWrapAlgorithm::A256Kw => {
+                KeyWrapKey::A256Kw(key.try_into().expect("key length mismatch"))
+            }
+            KeyWrapAlgorithm::Direct(a) => {
+                KeyWrapKey::Direct(self.base.key_wrap().load_from_keydata(a, key))
+            }
+        }
+    }
+
+    fn wrap_key(&mut self, kek: &Self::Key, key_data: &[u8], output: &mut [u8]) {
+        // aes-kw also accepts a single semiblock, which RFC 3394 does not.
+        assert!(key_data.len() >= 16, "key data too short");
+        let result = match kek {
+            KeyWrapKey::A128Kw(k) => k.wrap(key_data, output),
+            KeyWrapKey::A192Kw(k) => k.wrap(key_data, output),
+            KeyWrapKey::A256Kw(k) => k.wrap(key_data, output),
+            KeyWrapKey::Direct(k) => return self.base.key_wrap().wrap_key(k, key_data, output),
+        };
+        result.expect("input or output length mismatch");
+    }
+
+    fn unwrap_key(
+        &mut self,
+        kek: &Self::Key,
+        wrapped: &[u8],
+        output: &mut [u8],
+    ) -> Result<(), DecryptionFailed> {
+        assert!(wrapped.len() >= 24, "wrapped key too short");
+        let result = match kek {
+            KeyWrapKey::A128Kw(k) => k.unwrap(wrapped, output),
+            KeyWrapKey::A192Kw(k) => k.unwrap(wrapped, output),
+            KeyWrapKey::A256Kw(k) => k.unwrap(wrapped, output),
+            KeyWrapKey::Direct(k) => return self.base.key_wrap().unwrap_key(k, wrapped, output),
+        };
+        match result {
+            Ok(()) => Ok(()),
+            Err(aes_kw::Error::IntegrityCheckFailed) => {
+                output.fill(0);
+                Err(DecryptionFailed)
+            }
+            Err(e) => panic!("input or output length mismatch: {e}"),
+        }
+    }
+}
@@ -2,9 +2,19 @@
 // SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
 
 mod aead;
+#[cfg(test)]
+mod async_tests;
+mod block_cipher;
 mod dh;
+mod ecc;
+#[cfg(test)]
+mod fallback_tests;
 mod hash;
+mod hmac;
+mod key_wrap;
+mod mac;
 mod rng;
+mod signature;
 
 use digest::Digest;
 use embedded_cal::{accessor::*, empty};
@@ -34,24 +44,57 @@ impl<Base> RustcryptoCalExtender<Base> {
         }
     }
 
-    fn collect_aad(&mut self, aad: impl embedded_cal::AadGenerator) -> impl AsRef<[u8]> {
+    /// Linearizes the AAD into a single slice, which is wiped when dropped.
+    ///
+    /// Without the `alloc` feature, this fails with [`OperationError::ResourceExhausted`] if the
+    /// AAD exceeds the internal buffer.
+    ///
+    /// [`OperationError::ResourceExhausted`]: embedded_cal::OperationError::ResourceExhausted
+    fn collect_aad(
+        &mut self,
+        aad: impl embedded_cal::AadGenerator,
+    ) -> Result<impl AsRef<[u8]>, embedded_cal::OperationError> {
         #[cfg(feature = "alloc")]
         {
-            aad.items().flatten().copied().collect::<Vec<_>>()
+            Ok(zeroize::Zeroizing::new(
+                aad.items().flatten().copied().collect::<Vec<_>>(),
+            ))
         }
 
         #[cfg(not(feature = "alloc"))]
         {
             let mut cursor = 0;
             for slice in aad.items() {
-                self.aead_buffer[cursor..][..slice.len()].copy_from_slice(slice);
-                cursor += slice.len();
+                let end = cursor + slice.len();
+                if end > self.aead_buffer.len() {
+                    return Err(embedded_cal::OperationError::ResourceExhausted);
+                }
+                self.aead_buffer[cursor..end].copy_from_slice(slice);
+                cursor = end;
             }
-            &self.aead_buffer[..cursor]
+            Ok(CollectedAad(&mut self.aead_buffer[..cursor]))
         }
     }
 }
 
+/// The used part of [`RustcryptoCalExtender`]'s AAD buffer.
+#[cfg(not(feature = "alloc"))]
+struct CollectedAad<'a>(&'a mut [u8]);
+
+#[cfg(not(feature = "alloc"))]
+impl AsRef<[u8]> for CollectedAad<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.0
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
+impl Drop for CollectedAad<'_> {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self.0);
+    }
+}
+
 impl Default for RustcryptoCal {
     fn default() -> Self {
         Self::new()
@@ -62,7 +105,13 @@ impl<Base: embedded_cal::Cal> embedded_cal::Cal for RustcryptoCalExtender<Base>
     type DhProvider = Self;
     type AeadProvider = Self;
     type HashProvider = Self;
-    type HmacProvider = HmacProviderOf<Base>;
+    type HmacProvider = Self;
+    type SignatureProvider = Self;
+    type KemProvider = KemProviderOf<Base>;
+    type BlockCipherProvider = Self;
+    type EccProvider = Self;
+    type MacProvider = Self;
+    type KeyWrapProvider = Self;
 
     fn dh(&mut self) -> &mut Self::DhProvider {
         self
@@ -74,7 +123,26 @@ impl<Base: embedded_cal::Cal> embedded_cal::Cal for RustcryptoCalExtender<Base>
         self
     }
     fn hmac(&mut self) -> &mut Self::HmacProvider {
-        self.base.hmac()
+        self
+    }
+    fn signature(&mut self) -> &mut Self::SignatureProvider {
+        self
+    }
+    fn kem(&mut self) -> &mut Self::KemProvider {
+        self.base.kem()
+    }
+    fn block_cipher(&mut self) -> &mut Self::BlockCipherProvider {
+        self
+    }
+    fn ecc(&mut self) -> &mut Self::EccProvider {
+        self
+    }
+    fn mac(&mut self) -> &mut Self::MacProvider {
+        self
+    }
+
+    fn key_wrap(&mut self) -> &mut Self::KeyWrapProvider {
+        self
     }
 }
 
@@ -90,6 +158,43 @@ mod tests {
         testvectors::test_hash_algorithm_sha256(&mut cal);
     }
 
+    #[test]
+    fn test_hash_algorithm_sha3() {
+        let mut cal = RustcryptoCal::new();
+
+        embedded_cal::test_hash_algorithm_sha3_256::<HashAlgorithmOf<RustcryptoCal>>();
+        testvectors::test_hash_algorithm_sha3_256(&mut cal);
+        testvectors::test_hash_algorithm_sha3_384(&mut cal);
+        testvectors::test_hash_algorithm_sha3_512(&mut cal);
+    }
+
+    #[test]
+    fn test_hash_algorithm_shake() {
+        let mut cal = RustcryptoCal::new();
+
+        embedded_cal::test_hash_algorithm_shake256::<HashAlgorithmOf<RustcryptoCal>>();
+        testvectors::test_hash_algorithm_shake128(&mut cal);
+        testvectors::test_hash_algorithm_shake256(&mut cal);
+    }
+
+    #[test]
+    fn test_hash_xof_unsupported() {
+        use embedded_cal::{HashAlgorithm, HashProvider};
+
+        let mut cal = RustcryptoCal::new();
+
+        let sha256 = HashAlgorithmOf::<RustcryptoCal>::from_cose_number(-16).unwrap();
+        let state = HashProvider::init(&mut cal, sha256.clone());
+        let Err(state) = HashProvider::finalize_xof(&mut cal, state) else {
+            panic!("SHA-256 produced an XOF reader");
+        };
+        // The state is still usable after the rejection.
+        assert_eq!(
+            HashProvider::finalize(&mut cal, state).as_ref(),
+            HashProvider::hash(&mut cal, sha256, b"").as_ref()
+        );
+    }
+
     #[test]
     fn test_aead_aesccm_16_64_128() {
         let mut cal = RustcryptoCal::new();
@@ -104,6 +209,7 @@ mod tests {
         let mut cal = RustcryptoCal::new();
 
         embedded_cal::test_dh_algorithm_ecdh_p256::<RustcryptoCal>();
+        embedded_cal::test_dh_algorithm_x25519::<RustcryptoCal>();
 
         // For lack of loading, we only run a live test
 
@@ -120,6 +226,30 @@ mod tests {
         for vec in testvectors::dh::RFC5903_P256 {
             vec.test_with(&mut cal);
         }
+
+        for vec in testvectors::dh::P256_FORMATS
+            .iter()
+            .chain(testvectors::dh::X25519_FORMATS)
+        {
+            vec.test_with(&mut cal);
+        }
+        testvectors::dh::test_public_key_format_errors(&mut cal);
+    }
+
+    #[test]
+    fn test_ecc() {
+        use embedded_cal::EccAlgorithm;
+
+        let mut cal = RustcryptoCal::new();
+
+        embedded_cal::test_ecc_algorithm_p256::<RustcryptoCal>();
+
+        let p256 = EccAlgorithm::from_cose_curve(1).unwrap();
+        embedded_cal::test_ecc_selftest(&mut cal, p256);
+
+        for vec in testvectors::ecc::P256_MUL {
+            vec.test_with(&mut cal);
+        }
     }
 
     #[test]
@@ -128,4 +258,569 @@ mod tests {
 
         testvectors::test_aead_aesccm_16_64_256(&mut cal);
     }
+
+    #[test]
+    fn test_aead_aesgcm() {
+        let mut cal = RustcryptoCal::new();
+
+        embedded_cal::test_aead_algorithm_aesgcm_128::<RustcryptoCal>();
+        testvectors::test_aead_aesgcm_128(&mut cal);
+        testvectors::test_aead_aesgcm_256(&mut cal);
+    }
+
+    #[test]
+    fn test_tls_identifiers() {
+        use embedded_cal::{AeadAlgorithm, DhAlgorithm, HashAlgorithm, HmacAlgorithm};
+
+        type Aead = AeadAlgorithmOf<RustcryptoCal>;
+        type Dh = DhAlgorithmOf<RustcryptoCal>;
+
+        assert_eq!(
+            Aead::from_tls_cipher_suite(0x1302),
+            Aead::from_cose_number(3)
+        );
+        // ChaCha20-Poly1305, and the AES-CCM suites whose 12 byte nonce COSE does not use
+        for suite in [0x1303, 0x1304, 0x1305] {
+            assert_eq!(Aead::from_tls_cipher_suite(suite), None);
+        }
+
+        // x448
+        assert_eq!(Dh::from_tls_named_group(30), None);
+
+        // SHA-1 is not provided
+        assert_eq!(
+            HashAlgorithmOf::<RustcryptoCal>::from_tls_hash_algorithm(2),
+            None
+        );
+        assert_eq!(
+            HmacAlgorithmOf::<RustcryptoCal>::from_tls_hash_algorithm(2),
+            None
+        );
+
+        // Identifiers recognized by the base are forwarded to it.
+        type Nested = RustcryptoCalExtender<RustcryptoCal>;
+        assert_eq!(
+            AeadAlgorithmOf::<Nested>::from_tls_cipher_suite(0x1301),
+            Some(aead::AeadAlgorithm::Direct(Aead::AesGcm128))
+        );
+        assert_eq!(
+            DhAlgorithmOf::<Nested>::from_tls_named_group(29),
+            Some(dh::DhAlgorithm::Direct(Dh::X25519))
+        );
+        assert_eq!(
+            HashAlgorithmOf::<Nested>::from_tls_hash_algorithm(4),
+            Some(hash::HashAlgorithm::Direct(hash::HashAlgorithm::Sha256))
+        );
+        assert_eq!(
+            HmacAlgorithmOf::<Nested>::from_tls_hash_algorithm(4),
+            Some(hmac::HmacAlgorithm::Direct(hmac::HmacAlgorithm::HmacSha256))
+        );
+    }
+
+    #[test]
+    fn test_algorithm_enumeration() {
+        use embedded_cal::HashAlgorithm;
+
+        testvectors::algorithms::test_algorithm_enumeration::<RustcryptoCal>();
+        testvectors::algorithms::test_algorithm_identifiers::<RustcryptoCal>();
+        assert_eq!(HashAlgorithmOf::<RustcryptoCal>::all().count(), 6);
+
+        // All algorithms are implemented by the base as well, which takes precedence.
+        type Nested = RustcryptoCalExtender<RustcryptoCal>;
+        testvectors::algorithms::test_algorithm_enumeration::<Nested>();
+        testvectors::algorithms::test_algorithm_identifiers::<Nested>();
+        assert_eq!(HashAlgorithmOf::<Nested>::all().count(), 6);
+        assert!(
+            HashAlgorithmOf::<Nested>::all().all(|a| matches!(a, hash::HashAlgorithm::Direct(_)))
+        );
+    }
+
+    #[test]
+    fn test_router() {
+        use embedded_cal::router::{Backend, Preference, PreferenceLists, Routed, Router};
+        use embedded_cal::{Cal, DhAlgorithm, DhProvider, HashAlgorithm, IncompatibleKeys};
+
+        // Only one backend has algorithms.
+        type Single = Router<embedded_cal::empty::EmptyCal<false>, RustcryptoCal>;
+        testvectors::algorithms::test_algorithm_enumeration::<Single>();
+        testvectors::algorithms::test_algorithm_identifiers::<Single>();
+
+        // Both backends have all algorithms; DH and key wrap come from A, as they would from a
+        // hardware backend, and everything else from B.
+        struct DhAndKeyWrapFromA;
+        impl Preference for DhAndKeyWrapFromA {
+            const AEAD: &'static [Backend] = Backend::B_FIRST;
+            const HASH: &'static [Backend] = Backend::B_FIRST;
+            const HMAC: &'static [Backend] = Backend::B_FIRST;
+            const SIGNATURE: &'static [Backend] = Backend::B_FIRST;
+            const KEM: &'static [Backend] = Backend::B_FIRST;
+            const BLOCK_CIPHER: &'static [Backend] = Backend::B_FIRST;
+            const ECC: &'static [Backend] = Backend::B_FIRST;
+            const MAC: &'static [Backend] = Backend::B_FIRST;
+        }
+        type Both = Router<RustcryptoCal, RustcryptoCal, DhAndKeyWrapFromA>;
+        testvectors::algorithms::test_algorithm_enumeration::<Both>();
+        testvectors::algorithms::test_algorithm_identifiers::<Both>();
+        assert!(DhAlgorithmOf::<Both>::all().all(|a| matches!(a.routed(), Routed::A(_))));
+        assert!(HashAlgorithmOf::<Both>::all().all(|a| matches!(a.routed(), Routed::B(_))));
+        assert_eq!(HashAlgorithmOf::<Both>::all().count(), 6);
+
+        let mut cal = Both::new(RustcryptoCal::new(), RustcryptoCal::new());
+
+        for vec in testvectors::dh::RFC5903_P256 {
+            vec.test_with(&mut cal);
+        }
+        testvectors::test_aead_aesgcm_128(&mut cal);
+        testvectors::test_hmac_sha256(&mut cal);
+        testvectors::test_hkdf_sha256(&mut cal);
+        // Unwraps with a key of A into AEAD keys of B
+        for vec in testvectors::key_wrap::RFC3394 {
+            vec.test_with(&mut cal);
+        }
+        for vec in testvectors::signature::RFC8032_ED25519 {
+            vec.test_with(&mut cal);
+        }
+
+        // The preference can be changed at runtime for the router's lookups.
+        assert_eq!(
+            cal.preference(),
+            &PreferenceLists::of::<DhAndKeyWrapFromA>()
+        );
+        let p256_a = cal.dh_algorithm(1).unwrap();
+        assert!(matches!(p256_a.routed(), Routed::A(_)));
+        cal.set_preference(PreferenceLists {
+            dh: Backend::B_FIRST,
+            ..*cal.preference()
+        });
+        let p256_b = cal.dh_algorithm(1).unwrap();
+        assert!(matches!(p256_b.routed(), Routed::B(_)));
+        assert!(matches!(
+            cal.hash_algorithm(-16).unwrap().routed(),
+            Routed::B(_)
+        ));
+        cal.set_preference(PreferenceLists {
+            dh: &[Backend::A],
+            ..*cal.preference()
+        });
+        assert!(cal.dh_algorithm(1).is_some());
+        // The type's preference is unaffected.
+        assert!(matches!(
+            DhAlgorithmOf::<Both>::from_cose_ecdh(1).unwrap().routed(),
+            Routed::A(_)
+        ));
+
+        // Keys of different backends do not mix, even for the same curve.
+        let private_a = cal.dh().generate(p256_a);
+        let private_b = cal.dh().generate(p256_b);
+        let public_b = cal.dh().public_key(&private_b);
+        assert!(matches!(
+            cal.dh().shared_secret(&private_a, &public_b),
+            Err(IncompatibleKeys)
+        ));
+        assert!(cal.dh().shared_secret(&private_b, &public_b).is_ok());
+    }
+
+    #[test]
+    fn test_aead_try_errors() {
+        use embedded_cal::{
+            AeadAlgorithm, AeadProvider, DecryptionFailed, OperationError, TryError,
+        };
+
+        let mut cal = RustcryptoCal::new();
+
+        let gcm = AeadAlgorithmOf::<RustcryptoCal>::from_cose_number(1).unwrap();
+        assert_eq!(
+            cal.try_load_from_keydata(gcm.clone(), &[0; 15]).err(),
+            Some(OperationError::LengthMismatch)
+        );
+        let key = cal.try_load_from_keydata(gcm, &[0; 16]).unwrap();
+
+        let mut message = *b"message";
+        assert_eq!(
+            cal.try_encrypt_in_place(&key, &[0; 13], &mut message, &b""[..])
+                .err(),
+            Some(OperationError::LengthMismatch)
+        );
+        let tag = cal
+            .try_encrypt_in_place(&key, &[0; 12], &mut message, &b""[..])
+            .unwrap();
+
+        let mut short_tag = [0; 15];
+        short_tag.copy_from_slice(&tag.as_ref()[..15]);
+        assert!(matches!(
+            cal.try_decrypt_in_place(&key, &[0; 12], &mut message, &short_tag, &b""[..]),
+            Err(TryError::Operation(OperationError::LengthMismatch))
+        ));
+
+        let mut bad_tag = [0; 16];
+        bad_tag.copy_from_slice(tag.as_ref());
+        bad_tag[0] ^= 1;
+        assert!(matches!(
+            cal.try_decrypt_in_place(&key, &[0; 12], &mut message, &bad_tag, &b""[..]),
+            Err(TryError::Failed(DecryptionFailed))
+        ));
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    #[test]
+    fn test_aead_aad_exhausted() {
+        use embedded_cal::{AeadAlgorithm, AeadProvider, OperationError};
+
+        let mut cal = RustcryptoCal::new();
+
+        let gcm = AeadAlgorithmOf::<RustcryptoCal>::from_cose_number(1).unwrap();
+        let key = cal.load_from_keydata(gcm, &[0; 16]);
+
+        let aad = [0; 1000];
+        let aad_parts: &[&[u8]] = &[&aad, &aad];
+        let mut message = *b"message";
+        assert_eq!(
+            cal.try_encrypt_in_place(&key, &[0; 12], &mut message, aad_parts)
+                .err(),
+            Some(OperationError::ResourceExhausted)
+        );
+
+        // Up to the buffer size, things work
+        let aad_parts: &[&[u8]] = &[&aad, &aad[..24]];
+        cal.try_encrypt_in_place(&key, &[0; 12], &mut message, aad_parts)
+            .unwrap();
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    #[test]
+    fn test_aead_aad_wiped() {
+        use embedded_cal::{AeadAlgorithm, AeadProvider};
+
+        let mut cal = RustcryptoCal::new();
+
+        let gcm = AeadAlgorithmOf::<RustcryptoCal>::from_cose_number(1).unwrap();
+        let key = cal.load_from_keydata(gcm, &[0; 16]);
+        let mut message = *b"message";
+        cal.encrypt_in_place(&key, &[0; 12], &mut message, &[0x55; 100][..]);
+
+        assert_eq!(cal.aead_buffer, [0; 1024]);
+    }
+
+    #[test]
+    fn test_zeroize_on_drop() {
+        fn assert_zeroize_on_drop<T: zeroize::ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<AeadKeyOf<RustcryptoCal>>();
+        assert_zeroize_on_drop::<AeadTagOf<RustcryptoCal>>();
+        assert_zeroize_on_drop::<BlockCipherKeyOf<RustcryptoCal>>();
+        assert_zeroize_on_drop::<DhVisibleSecretKeyOf<RustcryptoCal>>();
+        assert_zeroize_on_drop::<DhSecretKeyOf<RustcryptoCal>>();
+        assert_zeroize_on_drop::<DhSharedSecretOf<RustcryptoCal>>();
+        assert_zeroize_on_drop::<EccScalarOf<RustcryptoCal>>();
+        assert_zeroize_on_drop::<HmacKeyOf<RustcryptoCal>>();
+        assert_zeroize_on_drop::<HmacStateOf<RustcryptoCal>>();
+        assert_zeroize_on_drop::<HmacOutputOf<RustcryptoCal>>();
+        assert_zeroize_on_drop::<KeyWrapKeyOf<RustcryptoCal>>();
+        assert_zeroize_on_drop::<MacKeyOf<RustcryptoCal>>();
+        assert_zeroize_on_drop::<MacStateOf<RustcryptoCal>>();
+        assert_zeroize_on_drop::<MacOutputOf<RustcryptoCal>>();
+        assert_zeroize_on_drop::<SignatureVisibleSecretKeyOf<RustcryptoCal>>();
+        assert_zeroize_on_drop::<SignatureSecretKeyOf<RustcryptoCal>>();
+        assert_zeroize_on_drop::<SignatureSignStateOf<RustcryptoCal>>();
+
+        let memory = testvectors::zeroize::memory_after_drop(
+            embedded_cal::util::WipeOnDrop::new([0x55; 64]),
+            |wrapped| &**wrapped,
+        );
+        assert_eq!(memory, [0; 64]);
+    }
+
+    #[test]
+    fn test_aead_key_wiped() {
+        use embedded_cal::{AeadAlgorithm, AeadProvider};
+
+        let mut cal = RustcryptoCal::new();
+
+        let gcm = AeadAlgorithmOf::<RustcryptoCal>::from_cose_number(1).unwrap();
+        let key = cal.load_from_keydata(gcm, &[0x55; 16]);
+        let memory = testvectors::zeroize::memory_after_drop(key, |key| match key {
+            aead::AeadKey::AesGcm128(key) => &**key,
+            _ => unreachable!(),
+        });
+        assert_eq!(memory, [0; 16]);
+    }
+
+    #[test]
+    fn test_dh_shared_secret_wiped() {
+        use embedded_cal::{DhAlgorithm, DhProvider};
+
+        let mut cal = RustcryptoCal::new();
+
+        let x25519 = DhAlgorithmOf::<RustcryptoCal>::from_cose_ecdh(4).unwrap();
+        let secret = cal.generate(x25519.clone());
+        let other = cal.generate(x25519);
+        let public = cal.public_key(&other);
+        let shared = cal.shared_secret(&secret, &public).unwrap();
+        let memory = testvectors::zeroize::memory_after_drop(shared, |shared| match shared {
+            dh::SharedSecret::Length32(shared) => &**shared,
+            _ => unreachable!(),
+        });
+        assert_eq!(memory, [0; 32]);
+    }
+
+    #[test]
+    fn test_hmac_output_wiped() {
+        use embedded_cal::{HmacAlgorithm, HmacProvider};
+
+        let mut cal = RustcryptoCal::new();
+
+        let sha256 = HmacAlgorithmOf::<RustcryptoCal>::from_cose_number(5).unwrap();
+        let key = cal.load_from_keydata(sha256, b"key");
+        let state = cal.init(key);
+        let output = cal.finalize(state);
+        let memory = testvectors::zeroize::memory_after_drop(output, |output| match output {
+            hmac::HmacResult::HmacSha256(output) => &**output,
+            _ => unreachable!(),
+        });
+        assert_eq!(memory, [0; 32]);
+    }
+
+    #[test]
+    fn test_block_cipher_aes() {
+        let mut cal = RustcryptoCal::new();
+
+        embedded_cal::test_block_cipher_algorithm_aes128::<RustcryptoCal>();
+        testvectors::test_block_cipher_aes(&mut cal);
+    }
+
+    #[test]
+    fn test_key_wrap_aes() {
+        let mut cal = RustcryptoCal::new();
+
+        embedded_cal::test_key_wrap_algorithm_a128kw::<RustcryptoCal>();
+
+        for vec in testvectors::key_wrap::RFC3394 {
+            vec.test_with(&mut cal);
+        }
+    }
+
+    #[test]
+    fn test_mac_aes_cmac() {
+        let mut cal = RustcryptoCal::new();
+
+        embedded_cal::test_mac_algorithm_aes_cmac_128::<RustcryptoCal>();
+
+        for vec in testvectors::mac::RFC4493_AES_CMAC_128 {
+            vec.test_with(&mut cal);
+        }
+
+        for vec in testvectors::mac::SP800_38B_AES_CMAC_256 {
+            vec.test_with(&mut cal);
+        }
+    }
+
+    /// A [`RawMutex`][embedded_cal::shared::RawMutex] as it would be built around any blocking
+    /// mutex.
+    struct StdRawMutex(std::sync::Mutex<()>);
+
+    unsafe impl embedded_cal::shared::RawMutex for StdRawMutex {
+        fn lock<R>(&self, f: impl FnOnce() -> R) -> R {
+            let _guard = self.0.lock().unwrap();
+            f()
+        }
+    }
+
+    // Every step takes the lock separately; the state is kept outside.
+    fn hash_in_parts<M: embedded_cal::shared::RawMutex, C: embedded_cal::Cal>(
+        shared: &embedded_cal::shared::SharedCal<M, C>,
+        alg: HashAlgorithmOf<C>,
+        data: &[u8],
+    ) -> HashOutputOf<C> {
+        use embedded_cal::HashProvider;
+
+        let mut state = shared.lock(|cal| cal.hash().init(alg));
+        for chunk in data.chunks(7) {
+            shared.lock(|cal| cal.hash().update(&mut state, chunk));
+            std::thread::yield_now();
+        }
+        shared.lock(|cal| cal.hash().finalize(state))
+    }
+
+    fn hmac_in_parts<M: embedded_cal::shared::RawMutex, C: embedded_cal::Cal>(
+        shared: &embedded_cal::shared::SharedCal<M, C>,
+        alg: HmacAlgorithmOf<C>,
+        key: &[u8],
+        data: &[u8],
+    ) -> HmacOutputOf<C> {
+        use embedded_cal::HmacProvider;
+
+        let key = shared.lock(|cal| cal.hmac().load_from_keydata(alg, key));
+        let mut state = shared.lock(|cal| cal.hmac().init(key));
+        for chunk in data.chunks(7) {
+            shared.lock(|cal| cal.hmac().update(&mut state, chunk));
+            std::thread::yield_now();
+        }
+        shared.lock(|cal| cal.hmac().finalize(state))
+    }
+
+    #[test]
+    fn test_shared() {
+        use embedded_cal::shared::SharedCal;
+        use embedded_cal::{HashAlgorithm, HmacAlgorithm};
+
+        let shared = SharedCal::new(StdRawMutex(Default::default()), RustcryptoCal::new());
+
+        std::thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    let sha256 = HashAlgorithmOf::<RustcryptoCal>::from_cose_number(-16).unwrap();
+                    for (tv_data, tv_result) in testvectors::SHA256HASHES {
+                        let result = hash_in_parts(&shared, sha256.clone(), tv_data);
+                        assert_eq!(result.as_ref(), tv_result);
+                    }
+                });
+                s.spawn(|| {
+                    let hmac_sha256 =
+                        HmacAlgorithmOf::<RustcryptoCal>::from_cose_number(5).unwrap();
+                    for (tv_key, tv_data, tv_mac) in testvectors::HMAC_SHA256 {
+                        let result = hmac_in_parts(&shared, hmac_sha256.clone(), tv_key, tv_data);
+                        assert_eq!(result.as_ref(), tv_mac);
+                    }
+                });
+            }
+        });
+    }
+
+    #[test]
+    #[should_panic = "SharedCal was locked reentrantly"]
+    fn test_shared_reentrant() {
+        use embedded_cal::shared::SharedCal;
+
+        // A reentrant mutex, as a critical section would be
+        struct Reentrant;
+        unsafe impl embedded_cal::shared::RawMutex for Reentrant {
+            fn lock<R>(&self, f: impl FnOnce() -> R) -> R {
+                f()
+            }
+        }
+
+        let shared = SharedCal::new(Reentrant, RustcryptoCal::new());
+        shared.lock(|_outer| shared.lock(|_inner| ()));
+    }
+
+    #[test]
+    fn test_hmac_hkdf() {
+        let mut cal = RustcryptoCal::new();
+
+        embedded_cal::test_hmac_algorithm_hmacsha256::<HmacAlgorithmOf<RustcryptoCal>>();
+        testvectors::test_hmac_sha256(&mut cal);
+        testvectors::test_hkdf_sha256(&mut cal);
+    }
+
+    #[test]
+    fn test_cose_key() {
+        let mut cal = RustcryptoCal::new();
+
+        testvectors::cose_key::test_codec();
+        testvectors::cose_key::test_import_errors(&mut cal);
+
+        for vec in testvectors::cose_key::RFC9052_P256 {
+            vec.test_with(&mut cal);
+        }
+
+        for vec in testvectors::cose_key::RFC9529_P256 {
+            vec.test_with(&mut cal);
+        }
+
+        for vec in testvectors::cose_key::RFC7748_X25519 {
+            vec.test_with(&mut cal);
+        }
+    }
+
+    #[test]
+    fn test_der() {
+        let mut cal = RustcryptoCal::new();
+
+        testvectors::der::test_malformed(&mut cal);
+
+        for vec in testvectors::der::RFC5903_P256 {
+            vec.test_with(&mut cal);
+        }
+
+        for vec in testvectors::der::RFC7748_X25519 {
+            vec.test_with(&mut cal);
+        }
+    }
+
+    #[test]
+    fn test_hpke() {
+        use embedded_cal::hpke::Suite;
+
+        let mut cal = RustcryptoCal::new();
+
+        for (kem, aead) in [(0x10, 0x1), (0x10, 0x2), (0x20, 0x1), (0x20, 0x2)] {
+            let suite = Suite::new(kem, 0x1, aead).unwrap();
+            embedded_cal::hpke::test_hpke_selftest(&mut cal, suite);
+        }
+
+        for vec in testvectors::hpke::RFC9180_A1_1 {
+            vec.test_with(&mut cal);
+        }
+
+        for vec in testvectors::hpke::RFC9180_A1_3 {
+            vec.test_with(&mut cal);
+        }
+
+        for vec in testvectors::hpke::RFC9180_A3_1 {
+            vec.test_with(&mut cal);
+        }
+    }
+
+    #[test]
+    fn test_signature() {
+        use embedded_cal::SignatureAlgorithm;
+
+        let mut cal = RustcryptoCal::new();
+
+        embedded_cal::test_signature_algorithm_es256::<SignatureProviderOf<RustcryptoCal>>();
+
+        let es256 = SignatureAlgorithm::from_cose_number(-7).unwrap();
+        embedded_cal::test_signature_selftest(&mut cal, es256);
+
+        for vec in testvectors::signature::RFC6979_P256_SHA256 {
+            vec.test_with(&mut cal);
+        }
+
+        let ed25519 = SignatureAlgorithm::from_cose_number(-19).unwrap();
+        embedded_cal::test_signature_selftest(&mut cal, ed25519);
+
+        for vec in testvectors::signature::RFC8032_ED25519 {
+            vec.test_with(&mut cal);
+        }
+    }
+
+    #[test]
+    fn test_signature_long_message() {
+        use embedded_cal::{
+            OperationError, SignatureAlgorithm, SignatureProvider, TryError, VerificationFailed,
+        };
+
+        let mut cal = RustcryptoCal::new();
+
+        let ed25519 = SignatureAlgorithm::from_cose_number(-8).unwrap();
+        let secret = SignatureProvider::generate(&mut cal, ed25519);
+        let public = SignatureProvider::public_key(&mut cal, &secret);
+        let message = [0x2a; 1025];
+
+        // Without `alloc`, PureEdDSA messages are limited by the buffer in the state.
+        let signed = SignatureProvider::try_sign(&mut cal, &secret, &message);
+        let verified = SignatureProvider::try_verify(&mut cal, &public, &message, &[0; 64]);
+        if cfg!(feature = "alloc") {
+            assert!(signed.is_ok());
+            assert!(matches!(
+                verified,
+                Err(TryError::Failed(VerificationFailed))
+            ));
+        } else {
+            assert_eq!(signed.err(), Some(OperationError::ResourceExhausted));
+            assert!(matches!(
+                verified,
+                Err(TryError::Operation(OperationError::ResourceExhausted))
+            ));
+        }
+    }
 }
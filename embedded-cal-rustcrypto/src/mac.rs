@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+use super::*;
+use aes::cipher::KeyInit;
+use cmac::Mac;
+use embedded_cal::{Cal, MacProvider};
+use zeroize::{ZeroizeOnDrop, Zeroizing};
+
+type AesCmac128 = cmac::Cmac<aes::Aes128>;
+type AesCmac256 = cmac::Cmac<aes::Aes256>;
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum MacAlgorithm<BA> {
+    AesCmac128,
+    AesCmac256,
+    Direct(BA),
+}
+
+impl<BA: embedded_cal::MacAlgorithm> embedded_cal::MacAlgorithm for MacAlgorithm<BA> {
+    fn len(&self) -> usize {
+        match self {
+            MacAlgorithm::AesCmac128 | MacAlgorithm::AesCmac256 => 16,
+            MacAlgorithm::Direct(a) => a.len(),
+        }
+    }
+
+    fn key_length(&self) -> usize {
+        match self {
+            MacAlgorithm::AesCmac128 => 16,
+            MacAlgorithm::AesCmac256 => 32,
+            MacAlgorithm::Direct(a) => a.key_length(),
+        }
+    }
+
+    #[inline]
+    fn from_aes_cmac_key_length(length: usize) -> Option<Self> {
+        if let Some(a) = BA::from_aes_cmac_key_length(length) {
+            return Some(MacAlgorithm::Direct(a));
+        }
+        match length {
+            16 => Some(MacAlgorithm::AesCmac128),
+            32 => Some(MacAlgorithm::AesCmac256),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        let own = [
+            Self::from_aes_cmac_key_length(16),
+            Self::from_aes_cmac_key_length(32),
+        ];
+        BA::all().map(MacAlgorithm::Direct).chain(
+            own.into_iter()
+                .flatten()
+                .filter(|a| !matches!(a, MacAlgorithm::Direct(_))),
+        )
+    }
+}
+
+#[allow(
+    clippy::large_enum_variant,
+    reason = "expanded AES keys are large by nature, and keys are expected to be used in place"
+)]
+#[derive(Clone)]
+pub enum MacKey<BK> {
+    AesCmac128(AesCmac128),
+    AesCmac256(AesCmac256),
+    Direct(BK),
+}
+
+impl<BK: ZeroizeOnDrop> ZeroizeOnDrop for MacKey<BK> {}
+
+#[allow(
+    clippy::large_enum_variant,
+    reason = "expanded AES keys are large by nature, and states are expected to be used in place"
+)]
+pub enum MacState<BS> {
+    AesCmac128(AesCmac128),
+    AesCmac256(AesCmac256),
+    Direct(BS),
+}
+
+impl<BS: ZeroizeOnDrop> ZeroizeOnDrop for MacState<BS> {}
+
+pub enum MacResult<BR> {
+    AesCmac(Zeroizing<[u8; 16]>),
+    Direct(BR),
+}
+
+impl<BR: ZeroizeOnDrop> ZeroizeOnDrop for MacResult<BR> {}
+
+impl<BR: AsRef<[u8]>> AsRef<[u8]> for MacResult<BR> {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            MacResult::AesCmac(r) => &r[..],
+            MacResult::Direct(r) => r.as_ref(),
+        }
+    }
+}
+
+impl<Base: Cal> MacProvider for RustcryptoCalExtender<Base> {
+    type Algorithm = MacAlgorithm<MacAlgorithmOf<Base>>;
+    type Key = MacKey<MacKeyOf<Base>>;
+    type State = MacState<MacStateOf<Base>>;
+    type Output = MacResult<MacOutputOf<Base>>;
+
+    fn load_from_keydata(&mut self, algorithm: Self::Algorithm, key: &[u8]) -> Self::Key {
+        match algorithm {
+            MacAlgorithm::AesCmac128 => MacKey::AesCmac128(
+                <AesCmac128 as KeyInit>::new_from_slice(key).expect("key length mismatch"),
+            ),
+            MacAlgorithm::AesCmac256 => MacKey::AesCmac256(
+                <AesCmac256 as KeyInit>::new_from_slice(key).expect("key length mismatch"),
+            ),
+            MacAlgorithm::Direct(a) => MacKey::Direct(self.base.mac().load_from_keydata(a, key)),
+        }
+    }
+
+    fn init(&mut self, key: Self::Key) -> Self::State {
+        match key {
+            // The key already is the state before absorbing any data
+            MacKey::AesCmac128(k) => MacState::AesCmac128(k),
+            MacKey::AesCmac256(k) => MacState::AesCmac256(k),
+            MacKey::Direct(k) => MacState::Direct(self.base.mac().init(k)),
+        }
+    }
+
+    fn update(&mut self, state: &mut Self::State, data: &[u8]) {
+        match state {
+            MacState::AesCmac128(s) => s.update(data),
+            MacState::AesCmac256(s) => s.update(data),
+            MacState::Direct(s) => self.base.mac().update(s, data),
+        }
+    }
+
+    fn finalize(&mut self, state: Self::State) -> Self::Output {
+        match state {
+            MacState::AesCmac128(s) => {
+                MacResult::AesCmac(Zeroizing::new(s.finalize().into_bytes().into()))
+            }
+            MacState::AesCmac256(s) => {
+                MacResult::AesCmac(Zeroizing::new(s.finalize().into_bytes().into()))
+            }
+            MacState::Direct(s) => MacResult::Direct(self.base.mac().finalize(s)),
+        }
+    }
+}
@@ -0,0 +1,393 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+use super::*;
+use ed25519_dalek::{Signer, Verifier};
+use embedded_cal::{
+    Cal, ImportError, OperationError, SignatureProvider, VerificationFailed, util::Either,
+};
+use p256::ecdsa::signature::{DigestSigner, DigestVerifier};
+use zeroize::ZeroizeOnDrop;
+
+/// Largest exported public key (uncompressed P-256).
+const MAX_PUBLICKEY_BYTES_LEN: usize = 65;
+
+impl<Base: Cal> SignatureProvider for RustcryptoCalExtender<Base> {
+    type Algorithm = SignatureAlgorithm<SignatureAlgorithmOf<Base>>;
+    type VisibleSecretKey = VisibleSecretKey<SignatureVisibleSecretKeyOf<Base>>;
+    type SecretKey = SecretKey<SignatureSecretKeyOf<Base>>;
+    type PublicKey = PublicKey<SignaturePublicKeyOf<Base>>;
+    type SignState = SignState<SignatureSignStateOf<Base>>;
+    type VerifyState = VerifyState<SignatureVerifyStateOf<Base>>;
+    type Output = Signature<SignatureOutputOf<Base>>;
+
+    fn generate_visible(&mut self, alg: Self::Algorithm) -> Self::VisibleSecretKey {
+        match alg {
+            SignatureAlgorithm::EcdsaP256 => VisibleSecretKey::EcdsaP256(
+                p256::ecdsa::SigningKey::random(&mut crate::dh::OldRng(self)),
+            ),
+            SignatureAlgorithm::Ed25519 => {
+                // All 32-byte strings are valid seeds.
+                let mut seed = zeroize::Zeroizing::new([0; 32]);
+                rand_core::Rng::fill_bytes(self, &mut *seed);
+                VisibleSecretKey::Ed25519(ed25519_dalek::SigningKey::from_bytes(&seed))
+            }
+            SignatureAlgorithm::Direct(d) => {
+                VisibleSecretKey::Direct(self.base.signature().generate_visible(d))
+            }
+        }
+    }
+
+    fn export_secretkey_bytes<'s>(
+        &mut self,
+        secret: &'s Self::VisibleSecretKey,
+    ) -> impl AsRef<[u8]> + use<'s, Base> {
+        match secret {
+            VisibleSecretKey::EcdsaP256(secret_key) => {
+                let bytes: [u8; 32] = secret_key.to_bytes().into();
+                Either::Own(zeroize::Zeroizing::new(bytes))
+            }
+            VisibleSecretKey::Ed25519(secret_key) => {
+                Either::Own(zeroize::Zeroizing::new(secret_key.to_bytes()))
+            }
+            VisibleSecretKey::Direct(d) => {
+                Either::Direct(self.base.signature().export_secretkey_bytes(d))
+            }
+        }
+    }
+
+    fn import_secretkey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        secret: &[u8],
+    ) -> Result<Self::VisibleSecretKey, ImportError> {
+        Ok(match alg {
+            SignatureAlgorithm::EcdsaP256 => VisibleSecretKey::EcdsaP256(
+                #[allow(
+                    clippy::unnecessary_fallible_conversions,
+                    reason = "GenericArray has panicking From for slices"
+                )]
+                p256::ecdsa::SigningKey::from_bytes(secret.try_into().map_err(|_| ImportError)?)
+                    .map_err(|_| ImportError)?,
+            ),
+            SignatureAlgorithm::Ed25519 => VisibleSecretKey::Ed25519(
+                ed25519_dalek::SigningKey::from_bytes(secret.try_into().map_err(|_| ImportError)?),
+            ),
+            SignatureAlgorithm::Direct(d) => {
+                VisibleSecretKey::Direct(self.base.signature().import_secretkey_bytes(d, secret)?)
+            }
+        })
+    }
+
+    fn export_publickey_bytes<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+    ) -> impl AsRef<[u8]> + use<'p, Base> {
+        match public {
+            PublicKey::EcdsaP256(public_key) => Either::Own(
+                heapless::Vec::<u8, MAX_PUBLICKEY_BYTES_LEN>::from_slice(
+                    public_key.to_encoded_point(false).as_bytes(),
+                )
+                .unwrap(),
+            ),
+            PublicKey::Ed25519(public_key) => Either::Own(
+                heapless::Vec::<u8, MAX_PUBLICKEY_BYTES_LEN>::from_slice(public_key.as_bytes())
+                    .unwrap(),
+            ),
+            PublicKey::Direct(d) => Either::Direct(self.base.signature().export_publickey_bytes(d)),
+        }
+    }
+
+    fn import_publickey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::PublicKey, ImportError> {
+        match alg {
+            SignatureAlgorithm::EcdsaP256 => {
+                // `from_sec1_bytes` would also take the compressed form, which is not what
+                // `export_publickey_bytes` produces.
+                if data.len() != 65 {
+                    return Err(ImportError);
+                }
+                // This checks that the point is on the curve.
+                Ok(PublicKey::EcdsaP256(
+                    p256::ecdsa::VerifyingKey::from_sec1_bytes(data).map_err(|_| ImportError)?,
+                ))
+            }
+            // This checks that the point decompresses.
+            SignatureAlgorithm::Ed25519 => Ok(PublicKey::Ed25519(
+                ed25519_dalek::VerifyingKey::from_bytes(data.try_into().map_err(|_| ImportError)?)
+                    .map_err(|_| ImportError)?,
+            )),
+            SignatureAlgorithm::Direct(d) => self
+                .base
+                .signature()
+                .import_publickey_bytes(d, data)
+                .map(PublicKey::Direct),
+        }
+    }
+
+    fn public_key(&mut self, private: &Self::SecretKey) -> Self::PublicKey {
+        match private {
+            SecretKey::EcdsaP256(secret_key) => PublicKey::EcdsaP256(*secret_key.verifying_key()),
+            SecretKey::Ed25519(secret_key) => PublicKey::Ed25519(secret_key.verifying_key()),
+            SecretKey::Direct(d) => PublicKey::Direct(self.base.signature().public_key(d)),
+        }
+    }
+
+    fn sign_init(&mut self, key: &Self::SecretKey) -> Self::SignState {
+        match key {
+            SecretKey::EcdsaP256(secret_key) => {
+                SignState::EcdsaP256(secret_key.clone(), Default::default())
+            }
+            SecretKey::Ed25519(secret_key) => {
+                SignState::Ed25519(secret_key.clone(), Default::default())
+            }
+            SecretKey::Direct(d) => SignState::Direct(self.base.signature().sign_init(d)),
+        }
+    }
+
+    fn try_sign_update(
+        &mut self,
+        state: &mut Self::SignState,
+        data: &[u8],
+    ) -> Result<(), OperationError> {
+        match state {
+            SignState::EcdsaP256(_, digest) => {
+                digest.update(data);
+                Ok(())
+            }
+            SignState::Ed25519(_, message) => message.append(data),
+            SignState::Direct(d) => self.base.signature().try_sign_update(d, data),
+        }
+    }
+
+    fn sign_finalize(&mut self, state: Self::SignState) -> Self::Output {
+        match state {
+            SignState::EcdsaP256(secret_key, digest) => {
+                // Deterministic as per RFC6979, so there is no need to go through our RNG.
+                let signature: p256::ecdsa::Signature = secret_key.sign_digest(digest);
+                Signature::Length64(signature.to_bytes().into())
+            }
+            SignState::Ed25519(secret_key, message) => {
+                Signature::Length64(secret_key.sign(message.as_ref()).to_bytes())
+            }
+            SignState::Direct(d) => Signature::Direct(self.base.signature().sign_finalize(d)),
+        }
+    }
+
+    fn verify_init(&mut self, key: &Self::PublicKey) -> Self::VerifyState {
+        match key {
+            PublicKey::EcdsaP256(public_key) => {
+                VerifyState::EcdsaP256(*public_key, Default::default())
+            }
+            PublicKey::Ed25519(public_key) => VerifyState::Ed25519(*public_key, Default::default()),
+            PublicKey::Direct(d) => VerifyState::Direct(self.base.signature().verify_init(d)),
+        }
+    }
+
+    fn try_verify_update(
+        &mut self,
+        state: &mut Self::VerifyState,
+        data: &[u8],
+    ) -> Result<(), OperationError> {
+        match state {
+            VerifyState::EcdsaP256(_, digest) => {
+                digest.update(data);
+                Ok(())
+            }
+            VerifyState::Ed25519(_, message) => message.append(data),
+            VerifyState::Direct(d) => self.base.signature().try_verify_update(d, data),
+        }
+    }
+
+    fn verify_finalize(
+        &mut self,
+        state: Self::VerifyState,
+        signature: &[u8],
+    ) -> Result<(), VerificationFailed> {
+        match state {
+            VerifyState::EcdsaP256(public_key, digest) => {
+                let signature = p256::ecdsa::Signature::from_slice(signature)
+                    .map_err(|_| VerificationFailed)?;
+                public_key
+                    .verify_digest(digest, &signature)
+                    .map_err(|_| VerificationFailed)
+            }
+            VerifyState::Ed25519(public_key, message) => {
+                let signature = ed25519_dalek::Signature::from_slice(signature)
+                    .map_err(|_| VerificationFailed)?;
+                public_key
+                    .verify(message.as_ref(), &signature)
+                    .map_err(|_| VerificationFailed)
+            }
+            VerifyState::Direct(d) => self.base.signature().verify_finalize(d, signature),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum SignatureAlgorithm<BA> {
+    /// ECDSA over P-256 with SHA-256
+    EcdsaP256,
+    /// PureEdDSA over Ed25519
+    ///
+    /// The message is gathered in the signing or verification state. Without the `alloc` feature,
+    /// messages longer than 1024 bytes ([`MessageBuffer::MAX_LEN`]) are rejected with
+    /// [`OperationError::ResourceExhausted`].
+    Ed25519,
+    Direct(BA),
+}
+
+impl<BA: embedded_cal::SignatureAlgorithm> embedded_cal::SignatureAlgorithm
+    for SignatureAlgorithm<BA>
+{
+    fn signature_length(&self) -> usize {
+        match self {
+            SignatureAlgorithm::EcdsaP256 => 64,
+            SignatureAlgorithm::Ed25519 => 64,
+            SignatureAlgorithm::Direct(d) => d.signature_length(),
+        }
+    }
+
+    #[inline]
+    fn from_cose_number(number: impl Into<i128>) -> Option<Self> {
+        let number: i128 = number.into();
+        if let Some(d) = BA::from_cose_number(number) {
+            return Some(SignatureAlgorithm::Direct(d));
+        };
+        Some(match number {
+            // ES256 and ESP256
+            -7 | -9 => SignatureAlgorithm::EcdsaP256,
+            // EdDSA and Ed25519
+            -8 | -19 => SignatureAlgorithm::Ed25519,
+            _ => return None,
+        })
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        let own = [Self::from_cose_number(-7), Self::from_cose_number(-8)];
+        BA::all().map(SignatureAlgorithm::Direct).chain(
+            own.into_iter()
+                .flatten()
+                .filter(|a| !matches!(a, SignatureAlgorithm::Direct(_))),
+        )
+    }
+
+    fn cose_number(&self) -> Option<i128> {
+        match self {
+            SignatureAlgorithm::EcdsaP256 => Some(-7),
+            SignatureAlgorithm::Ed25519 => Some(-8),
+            SignatureAlgorithm::Direct(d) => d.cose_number(),
+        }
+    }
+}
+
+pub enum VisibleSecretKey<BVSK> {
+    EcdsaP256(p256::ecdsa::SigningKey),
+    Ed25519(ed25519_dalek::SigningKey),
+    Direct(BVSK),
+}
+
+impl<BVSK: ZeroizeOnDrop> ZeroizeOnDrop for VisibleSecretKey<BVSK> {}
+
+impl<BVSK, BSK> From<VisibleSecretKey<BVSK>> for SecretKey<BSK>
+where
+    BVSK: Into<BSK>,
+{
+    fn from(value: VisibleSecretKey<BVSK>) -> Self {
+        match value {
+            VisibleSecretKey::EcdsaP256(k) => SecretKey::EcdsaP256(k),
+            VisibleSecretKey::Ed25519(k) => SecretKey::Ed25519(k),
+            VisibleSecretKey::Direct(d) => SecretKey::Direct(d.into()),
+        }
+    }
+}
+
+pub enum SecretKey<BSK> {
+    EcdsaP256(p256::ecdsa::SigningKey),
+    Ed25519(ed25519_dalek::SigningKey),
+    Direct(BSK),
+}
+
+impl<BSK: ZeroizeOnDrop> ZeroizeOnDrop for SecretKey<BSK> {}
+
+pub enum PublicKey<BPK> {
+    EcdsaP256(p256::ecdsa::VerifyingKey),
+    Ed25519(ed25519_dalek::VerifyingKey),
+    Direct(BPK),
+}
+
+#[allow(
+    clippy::large_enum_variant,
+    reason = "states are expected to be held on the stack for the duration of an operation"
+)]
+pub enum SignState<BSS> {
+    EcdsaP256(p256::ecdsa::SigningKey, sha2::Sha256),
+    Ed25519(ed25519_dalek::SigningKey, MessageBuffer),
+    Direct(BSS),
+}
+
+impl<BSS: ZeroizeOnDrop> ZeroizeOnDrop for SignState<BSS> {}
+
+#[allow(
+    clippy::large_enum_variant,
+    reason = "states are expected to be held on the stack for the duration of an operation"
+)]
+pub enum VerifyState<BVS> {
+    EcdsaP256(p256::ecdsa::VerifyingKey, sha2::Sha256),
+    Ed25519(ed25519_dalek::VerifyingKey, MessageBuffer),
+    Direct(BVS),
+}
+
+pub enum Signature<BO> {
+    Length64([u8; 64]),
+    Direct(BO),
+}
+
+impl<BO: AsRef<[u8]>> AsRef<[u8]> for Signature<BO> {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Signature::Length64(s) => &s[..],
+            Signature::Direct(s) => s.as_ref(),
+        }
+    }
+}
+
+/// Collects the message for algorithms that need to process it in multiple passes.
+///
+/// PureEdDSA hashes the message twice, the second time prefixed with data that depends on the
+/// first pass; verification needs the signature before the message. Neither fits the streaming
+/// interface, so the message is gathered first (see the `alloc` feature for its size limits).
+#[derive(Default)]
+pub struct MessageBuffer {
+    #[cfg(feature = "alloc")]
+    data: Vec<u8>,
+    #[cfg(not(feature = "alloc"))]
+    data: heapless::Vec<u8, { MessageBuffer::MAX_LEN }>,
+}
+
+impl MessageBuffer {
+    /// Longest message that can be gathered without the `alloc` feature.
+    pub const MAX_LEN: usize = 1024;
+
+    fn append(&mut self, data: &[u8]) -> Result<(), OperationError> {
+        #[cfg(feature = "alloc")]
+        self.data.extend_from_slice(data);
+
+        #[cfg(not(feature = "alloc"))]
+        self.data
+            .extend_from_slice(data)
+            .map_err(|_| OperationError::ResourceExhausted)?;
+
+        Ok(())
+    }
+}
+
+impl AsRef<[u8]> for MessageBuffer {
+    fn as_ref(&self) -> &[u8] {
+        &self.data
+    }
+}
@@ -2,14 +2,19 @@
 // SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
 
 use embedded_cal::{
-    Cal, HashProvider,
+    Cal, HashProvider, OperationError,
     accessor::*,
-    plumbing::hash::{SHA2SHORT_BLOCK_SIZE, Sha2Short, Sha2ShortVariant},
+    plumbing::hash::{
+        SHA2LONG_BLOCK_SIZE, SHA2SHORT_BLOCK_SIZE, Sha2Long, Sha2LongVariant, Sha2Short,
+        Sha2ShortVariant,
+    },
 };
 
 use super::{Extender, ExtenderConfig};
 
 const HASH_WRAPPER_MAX_BLOCKSIZE: usize = 68;
+// Unlike for the short variants, no hardware is known yet that needs a larger first chunk.
+const HASH_WRAPPER_MAX_LONG_BLOCKSIZE: usize = SHA2LONG_BLOCK_SIZE;
 
 impl<EC: ExtenderConfig> HashProvider for Extender<EC> {
     type Algorithm = HashAlgorithm<EC>;
@@ -18,105 +23,83 @@ impl<EC: ExtenderConfig> HashProvider for Extender<EC> {
 
     type Output = HashResult<EC>;
 
-    fn init(&mut self, algorithm: Self::Algorithm) -> Self::State {
-        match algorithm {
+    // No XOFs are implemented here, but those of the base are usable.
+    type XofReader = HashXofReaderOf<EC::Base>;
+
+    fn try_init(&mut self, algorithm: Self::Algorithm) -> Result<Self::State, OperationError> {
+        Ok(match algorithm {
             HashAlgorithm::Sha256 => HashState::Sha256 {
                 written: 0,
                 buffer: [0; _],
                 instance: Sha2Short::init(&mut self.0, Sha2ShortVariant::Sha256),
             },
-            HashAlgorithm::Direct(alg) => HashState::Direct(self.0.hash().init(alg)),
-        }
+            HashAlgorithm::Sha384 => self.init_sha2long(Sha2LongVariant::Sha384),
+            HashAlgorithm::Sha512 => self.init_sha2long(Sha2LongVariant::Sha512),
+            HashAlgorithm::Direct(alg) => HashState::Direct(self.0.hash().try_init(alg)?),
+        })
     }
 
-    fn update(&mut self, instance: &mut Self::State, mut data: &[u8]) {
+    fn try_update(
+        &mut self,
+        instance: &mut Self::State,
+        data: &[u8],
+    ) -> Result<(), OperationError> {
         match instance {
-            HashState::Direct(i) => self.0.hash().update(i, data),
+            HashState::Direct(i) => return self.0.hash().try_update(i, data),
             HashState::Sha256 {
                 written,
                 buffer,
                 instance,
-            } => {
-                // In the common case of this also being 64, the compiler has all it needs to fold
-                // this in with the line after it.
-                let mut written_in_buffer = if *written < <EC::Base as Sha2Short>::FIRST_CHUNK_SIZE
-                {
-                    // First chunk not yet sent to hardware; all bytes are still buffered.
-                    *written
-                } else {
-                    // First chunk already sent; remaining bytes cycle through SHA2SHORT_BLOCK_SIZE blocks.
-                    (*written - <EC::Base as Sha2Short>::FIRST_CHUNK_SIZE) % SHA2SHORT_BLOCK_SIZE
-                };
-
-                // Not trying to be efficient here: This is a demo implementation.
-                // In particular, this does *not* test sending more than a single buffer multiple in;
-                // that'll be tested soon enough (and easy to fix).
-                loop {
-                    let buffer_max = if *written < <EC::Base as Sha2Short>::FIRST_CHUNK_SIZE {
-                        <EC::Base as Sha2Short>::FIRST_CHUNK_SIZE
-                    } else {
-                        SHA2SHORT_BLOCK_SIZE
-                    };
-
-                    let buffer_to_fill = &mut buffer[written_in_buffer..buffer_max];
-                    let fill_bytes = if data.len() > buffer_to_fill.len() {
-                        buffer_to_fill.len()
-                    } else {
-                        data.len()
-                    };
-                    buffer_to_fill[..fill_bytes].copy_from_slice(&data[..fill_bytes]);
-                    data = &data[fill_bytes..];
-                    *written += fill_bytes;
-                    written_in_buffer += fill_bytes;
-                    if written_in_buffer < buffer_max {
-                        return;
-                    }
-                    Sha2Short::update(&mut self.0, instance, &buffer[..buffer_max]);
-                    written_in_buffer = 0;
-                }
-            }
+            } => spool(
+                written,
+                buffer,
+                <EC::Base as Sha2Short>::FIRST_CHUNK_SIZE,
+                SHA2SHORT_BLOCK_SIZE,
+                data,
+                |chunk| Sha2Short::update(&mut self.0, instance, chunk),
+            ),
+            HashState::Sha2Long {
+                written,
+                buffer,
+                instance,
+                ..
+            } => spool(
+                written,
+                buffer,
+                <EC::Base as Sha2Long>::FIRST_CHUNK_SIZE,
+                SHA2LONG_BLOCK_SIZE,
+                data,
+                |chunk| Sha2Long::update(&mut self.0, instance, chunk),
+            ),
         }
+        Ok(())
     }
 
-    fn finalize(&mut self, instance: Self::State) -> Self::Output {
-        match instance {
-            HashState::Direct(underlying) => HashResult::Direct(self.0.hash().finalize(underlying)),
+    fn try_finalize(&mut self, instance: Self::State) -> Result<Self::Output, OperationError> {
+        Ok(match instance {
+            HashState::Direct(underlying) => {
+                HashResult::Direct(self.0.hash().try_finalize(underlying)?)
+            }
             HashState::Sha256 {
-                written,
-                buffer,
-                instance,
+                mut written,
+                mut buffer,
+                mut instance,
             } => {
-                // FIXME: deduplicate with update
-                let mut written_in_buffer = if written < <EC::Base as Sha2Short>::FIRST_CHUNK_SIZE {
-                    // First chunk not yet sent to hardware; all bytes are still buffered.
-                    written
-                } else {
-                    // First chunk already sent; remaining bytes cycle through SHA2SHORT_BLOCK_SIZE blocks.
-                    (written - <EC::Base as Sha2Short>::FIRST_CHUNK_SIZE) % SHA2SHORT_BLOCK_SIZE
-                };
-                // END FIXME
-
-                let (instance, buffer) = if <EC::Base as Sha2Short>::SEND_PADDING {
+                let first_chunk_size = <EC::Base as Sha2Short>::FIRST_CHUNK_SIZE;
+                if <EC::Base as Sha2Short>::SEND_PADDING {
                     let mut padding = [0; _];
                     let padding_size = sha256_padding(written, &mut padding);
-                    let mut rewrapped = HashState::Sha256 {
-                        written,
-                        buffer,
-                        instance,
-                    };
-                    HashProvider::update(self, &mut rewrapped, &padding[..padding_size]);
-                    let HashState::Sha256 {
-                        instance, buffer, ..
-                    } = rewrapped
-                    else {
-                        unreachable!("Updating doesn't change the hash state type");
-                    };
-                    written_in_buffer = 0;
-                    // Actually buffer will be unused, but we still need to have something
-                    (instance, buffer)
-                } else {
-                    (instance, buffer)
-                };
+                    spool(
+                        &mut written,
+                        &mut buffer,
+                        first_chunk_size,
+                        SHA2SHORT_BLOCK_SIZE,
+                        &padding[..padding_size],
+                        |chunk| Sha2Short::update(&mut self.0, &mut instance, chunk),
+                    );
+                }
+                let written_in_buffer =
+                    buffered_length(written, first_chunk_size, SHA2SHORT_BLOCK_SIZE);
 
                 let mut output = [0; 32];
                 Sha2Short::finalize(
@@ -127,14 +110,130 @@ impl<EC: ExtenderConfig> HashProvider for Extender<EC> {
                 );
                 HashResult::Sha256(output)
             }
+            HashState::Sha2Long {
+                variant,
+                mut written,
+                mut buffer,
+                mut instance,
+            } => {
+                let first_chunk_size = <EC::Base as Sha2Long>::FIRST_CHUNK_SIZE;
+                if <EC::Base as Sha2Long>::SEND_PADDING {
+                    let mut padding = [0; _];
+                    let padding_size = sha512_padding(written, &mut padding);
+                    spool(
+                        &mut written,
+                        &mut buffer,
+                        first_chunk_size,
+                        SHA2LONG_BLOCK_SIZE,
+                        &padding[..padding_size],
+                        |chunk| Sha2Long::update(&mut self.0, &mut instance, chunk),
+                    );
+                }
+                let written_in_buffer =
+                    buffered_length(written, first_chunk_size, SHA2LONG_BLOCK_SIZE);
+
+                let mut output = [0; 64];
+                Sha2Long::finalize(
+                    &mut self.0,
+                    instance,
+                    &buffer[..written_in_buffer],
+                    &mut output,
+                );
+                HashResult::Sha2Long {
+                    output,
+                    length: variant.output_length(),
+                }
+            }
+        })
+    }
+
+    fn finalize_xof(&mut self, instance: Self::State) -> Result<Self::XofReader, Self::State> {
+        match instance {
+            HashState::Direct(underlying) => self
+                .0
+                .hash()
+                .finalize_xof(underlying)
+                .map_err(HashState::Direct),
+            other => Err(other),
+        }
+    }
+
+    fn squeeze(&mut self, reader: &mut Self::XofReader, output: &mut [u8]) {
+        self.0.hash().squeeze(reader, output)
+    }
+}
+
+impl<EC: ExtenderConfig> Extender<EC> {
+    fn init_sha2long(&mut self, variant: Sha2LongVariant) -> HashState<EC> {
+        HashState::Sha2Long {
+            variant,
+            written: 0,
+            buffer: [0; _],
+            instance: Sha2Long::init(&mut self.0, variant),
         }
     }
 }
 
+/// Number of bytes that are kept in the buffer of a SHA-2 state after `written` bytes were sent
+/// in, given the chunking parameters of the back-end.
+fn buffered_length(written: usize, first_chunk_size: usize, block_size: usize) -> usize {
+    if written < first_chunk_size {
+        // First chunk not yet sent to hardware; all bytes are still buffered.
+        written
+    } else {
+        // First chunk already sent; remaining bytes cycle through blocks.
+        (written - first_chunk_size) % block_size
+    }
+}
+
+/// Sends data through the buffer, passing it on to `send` in the chunks the back-end expects.
+fn spool(
+    written: &mut usize,
+    buffer: &mut [u8],
+    first_chunk_size: usize,
+    block_size: usize,
+    mut data: &[u8],
+    mut send: impl FnMut(&[u8]),
+) {
+    // In the common case of the first chunk size being the block size, the compiler has all it
+    // needs to fold this in with the line after it.
+    let mut written_in_buffer = buffered_length(*written, first_chunk_size, block_size);
+
+    // Not trying to be efficient here: This is a demo implementation.
+    // In particular, this does *not* test sending more than a single buffer multiple in;
+    // that'll be tested soon enough (and easy to fix).
+    loop {
+        let buffer_max = if *written < first_chunk_size {
+            first_chunk_size
+        } else {
+            block_size
+        };
+
+        let buffer_to_fill = &mut buffer[written_in_buffer..buffer_max];
+        let fill_bytes = if data.len() > buffer_to_fill.len() {
+            buffer_to_fill.len()
+        } else {
+            data.len()
+        };
+        buffer_to_fill[..fill_bytes].copy_from_slice(&data[..fill_bytes]);
+        data = &data[fill_bytes..];
+        *written += fill_bytes;
+        written_in_buffer += fill_bytes;
+        if written_in_buffer < buffer_max {
+            return;
+        }
+        send(&buffer[..buffer_max]);
+        written_in_buffer = 0;
+    }
+}
+
 pub enum HashAlgorithm<EC: ExtenderConfig> {
     // FIXME: Ideally we'd employ some witness type of <EC::Base as Sha2Short>::SUPPORTED
     // to render this uninhabited when unused.
     Sha256,
+    // Unlike SHA-256, these are only produced if the base's Sha2Long is SUPPORTED.
+    Sha384,
+    Sha512,
     Direct(HashAlgorithmOf<EC::Base>),
 }
 
@@ -145,6 +244,8 @@ impl<EC: ExtenderConfig> Clone for HashAlgorithm<EC> {
     fn clone(&self) -> Self {
         match self {
             HashAlgorithm::Sha256 => HashAlgorithm::Sha256,
+            HashAlgorithm::Sha384 => HashAlgorithm::Sha384,
+            HashAlgorithm::Sha512 => HashAlgorithm::Sha512,
             HashAlgorithm::Direct(a) => HashAlgorithm::Direct(a.clone()),
         }
     }
@@ -155,6 +256,8 @@ impl<EC: ExtenderConfig> core::fmt::Debug for HashAlgorithm<EC> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             HashAlgorithm::Sha256 => write!(f, "Sha256"),
+            HashAlgorithm::Sha384 => write!(f, "Sha384"),
+            HashAlgorithm::Sha512 => write!(f, "Sha512"),
             HashAlgorithm::Direct(arg0) => f.debug_tuple("Direct").field(arg0).finish(),
         }
     }
@@ -166,6 +269,8 @@ impl<EC: ExtenderConfig> PartialEq for HashAlgorithm<EC> {
         match (self, other) {
             (HashAlgorithm::Direct(l0), HashAlgorithm::Direct(r0)) => l0 == r0,
             (HashAlgorithm::Sha256, HashAlgorithm::Sha256) => true,
+            (HashAlgorithm::Sha384, HashAlgorithm::Sha384) => true,
+            (HashAlgorithm::Sha512, HashAlgorithm::Sha512) => true,
             _ => false,
         }
     }
@@ -178,6 +283,8 @@ impl<EC: ExtenderConfig> embedded_cal::HashAlgorithm for HashAlgorithm<EC> {
     fn len(&self) -> usize {
         match self {
             HashAlgorithm::Sha256 => 32,
+            HashAlgorithm::Sha384 => 48,
+            HashAlgorithm::Sha512 => 64,
             HashAlgorithm::Direct(a) => a.len(),
         }
     }
@@ -188,6 +295,8 @@ impl<EC: ExtenderConfig> embedded_cal::HashAlgorithm for HashAlgorithm<EC> {
 
         match number {
             -16 => Some(HashAlgorithm::Sha256),
+            -43 if <EC::Base as Sha2Long>::SUPPORTED => Some(HashAlgorithm::Sha384),
+            -44 if <EC::Base as Sha2Long>::SUPPORTED => Some(HashAlgorithm::Sha512),
             _ => HashAlgorithmOf::<EC::Base>::from_cose_number(number).map(HashAlgorithm::Direct),
         }
     }
@@ -196,7 +305,9 @@ impl<EC: ExtenderConfig> embedded_cal::HashAlgorithm for HashAlgorithm<EC> {
     fn from_ni_id(number: u8) -> Option<Self> {
         match number {
             1 => Self::from_cose_number(-16),
-            _ => None,
+            7 => Self::from_cose_number(-43),
+            8 => Self::from_cose_number(-44),
+            _ => HashAlgorithmOf::<EC::Base>::from_ni_id(number).map(HashAlgorithm::Direct),
         }
     }
 
@@ -204,7 +315,60 @@ impl<EC: ExtenderConfig> embedded_cal::HashAlgorithm for HashAlgorithm<EC> {
     fn from_ni_name(name: &str) -> Option<Self> {
         match name {
             "sha-256" => Self::from_cose_number(-16),
-            _ => None,
+            "sha-384" => Self::from_cose_number(-43),
+            "sha-512" => Self::from_cose_number(-44),
+            _ => HashAlgorithmOf::<EC::Base>::from_ni_name(name).map(HashAlgorithm::Direct),
+        }
+    }
+
+    #[inline]
+    fn from_tls_hash_algorithm(number: u8) -> Option<Self> {
+        match number {
+            4 => Self::from_cose_number(-16),
+            5 => Self::from_cose_number(-43),
+            6 => Self::from_cose_number(-44),
+            _ => HashAlgorithmOf::<EC::Base>::from_tls_hash_algorithm(number)
+                .map(HashAlgorithm::Direct),
+        }
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        let selected = [-16, -43, -44].map(|n| match Self::from_cose_number(n) {
+            Some(HashAlgorithm::Direct(_)) | None => (None, None),
+            own => (own, HashAlgorithmOf::<EC::Base>::from_cose_number(n)),
+        });
+        // The base's algorithms for the identifiers we take are never selected.
+        let shadowed = selected.clone().map(|(_, base)| base);
+        selected.into_iter().filter_map(|(own, _)| own).chain(
+            HashAlgorithmOf::<EC::Base>::all()
+                .filter(move |a| !shadowed.iter().any(|s| s.as_ref() == Some(a)))
+                .map(HashAlgorithm::Direct),
+        )
+    }
+
+    fn cose_number(&self) -> Option<i128> {
+        match self {
+            HashAlgorithm::Sha256 => Some(-16),
+            HashAlgorithm::Sha384 => Some(-43),
+            HashAlgorithm::Sha512 => Some(-44),
+            HashAlgorithm::Direct(a) => a.cose_number(),
+        }
+    }
+    fn ni_id(&self) -> Option<u8> {
+        match self {
+            HashAlgorithm::Sha256 => Some(1),
+            HashAlgorithm::Sha384 => Some(7),
+            HashAlgorithm::Sha512 => Some(8),
+            HashAlgorithm::Direct(a) => a.ni_id(),
+        }
+    }
+    fn ni_name(&self) -> Option<&'static str> {
+        match self {
+            HashAlgorithm::Sha256 => Some("sha-256"),
+            HashAlgorithm::Sha384 => Some("sha-384"),
+            HashAlgorithm::Sha512 => Some("sha-512"),
+            HashAlgorithm::Direct(a) => a.ni_name(),
         }
     }
 }
@@ -223,6 +387,13 @@ pub enum HashState<EC: ExtenderConfig> {
         buffer: [u8; HASH_WRAPPER_MAX_BLOCKSIZE],
         instance: <EC::Base as Sha2Short>::State,
     },
+    Sha2Long {
+        variant: Sha2LongVariant,
+        written: usize,
+        // FIXME: as for Sha256
+        buffer: [u8; HASH_WRAPPER_MAX_LONG_BLOCKSIZE],
+        instance: <EC::Base as Sha2Long>::State,
+    },
 }
 
 impl<EC: ExtenderConfig> Clone for HashState<EC> {
@@ -240,12 +411,24 @@ impl<EC: ExtenderConfig> Clone for HashState<EC> {
                 buffer: *buffer,
                 instance: instance.clone(),
             },
+            Self::Sha2Long {
+                variant,
+                written,
+                buffer,
+                instance,
+            } => Self::Sha2Long {
+                variant: *variant,
+                written: *written,
+                buffer: *buffer,
+                instance: instance.clone(),
+            },
         }
     }
 }
 
 pub enum HashResult<EC: ExtenderConfig> {
     Sha256([u8; 32]),
+    Sha2Long { output: [u8; 64], length: usize },
     Direct(HashOutputOf<EC::Base>),
 }
 
@@ -253,6 +436,7 @@ impl<EC: ExtenderConfig> AsRef<[u8]> for HashResult<EC> {
     fn as_ref(&self) -> &[u8] {
         match self {
             HashResult::Sha256(data) => data.as_slice(),
+            HashResult::Sha2Long { output, length } => &output[..*length],
             HashResult::Direct(result) => result.as_ref(),
         }
     }
@@ -262,6 +446,10 @@ fn sha256_padding(msg_len: usize, out: &mut [u8; 256]) -> usize {
     sha2_padding(msg_len, 64, 56, 8, out)
 }
 
+fn sha512_padding(msg_len: usize, out: &mut [u8; 256]) -> usize {
+    sha2_padding(msg_len, 128, 112, 16, out)
+}
+
 fn sha2_padding(
     msg_len: usize,
     block_size: usize,
@@ -295,7 +483,7 @@ fn sha2_padding(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tests::dummy_sha256;
+    use crate::tests::{dummy_sha256, dummy_sha512};
 
     struct ImplementSha256Short;
 
@@ -308,6 +496,44 @@ mod tests {
     fn test_hash_algorithm_sha256_on_dummy() {
         let mut cal = Extender::<ImplementSha256Short>(dummy_sha256::DummySha256::new());
 
+        embedded_cal::test_hash_algorithm_sha256::<HashAlgorithm<ImplementSha256Short>>();
+        testvectors::algorithms::test_algorithm_enumeration::<Extender<ImplementSha256Short>>();
+        testvectors::algorithms::test_algorithm_identifiers::<Extender<ImplementSha256Short>>();
+        assert_eq!(
+            <HashAlgorithm<ImplementSha256Short> as embedded_cal::HashAlgorithm>::all().count(),
+            1
+        );
         testvectors::test_hash_algorithm_sha256(&mut cal);
     }
+
+    struct ImplementSha2Long;
+
+    impl ExtenderConfig for ImplementSha2Long {
+        const IMPLEMENT_SHA2SHORT: bool = false;
+        type Base = dummy_sha512::DummySha512;
+    }
+
+    #[test]
+    fn test_hash_algorithm_sha384_sha512_on_dummy() {
+        let mut cal = Extender::<ImplementSha2Long>(dummy_sha512::DummySha512::new());
+
+        embedded_cal::test_hash_algorithm_sha384::<HashAlgorithm<ImplementSha2Long>>();
+        embedded_cal::test_hash_algorithm_sha512::<HashAlgorithm<ImplementSha2Long>>();
+        testvectors::algorithms::test_algorithm_enumeration::<Extender<ImplementSha2Long>>();
+        testvectors::algorithms::test_algorithm_identifiers::<Extender<ImplementSha2Long>>();
+        assert_eq!(
+            <HashAlgorithm<ImplementSha2Long> as embedded_cal::HashAlgorithm>::all().count(),
+            3
+        );
+        testvectors::test_hash_algorithm_sha384(&mut cal);
+        testvectors::test_hash_algorithm_sha512(&mut cal);
+    }
+
+    #[test]
+    fn test_sha2long_unsupported_is_forwarded() {
+        use embedded_cal::HashAlgorithm as _;
+
+        // DummySha256 has no Sha2Long support, and its base has no SHA-512 either.
+        assert!(HashAlgorithm::<ImplementSha256Short>::from_cose_number(-44).is_none());
+    }
 }
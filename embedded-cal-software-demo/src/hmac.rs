@@ -1,7 +1,11 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
 
-use embedded_cal::{HashProvider, HmacProvider, plumbing::hash::SHA2SHORT_BLOCK_SIZE};
+use embedded_cal::{
+    HashProvider, HmacProvider, OperationError, plumbing::hash::SHA2SHORT_BLOCK_SIZE,
+    util::WipeOnDrop,
+};
+use zeroize::{ZeroizeOnDrop, Zeroizing};
 
 use crate::hash::{HashAlgorithm, HashResult};
 
@@ -31,6 +35,25 @@ impl embedded_cal::HmacAlgorithm for HmacAlgorithm {
             _ => None,
         }
     }
+
+    #[inline]
+    fn from_tls_hash_algorithm(number: u8) -> Option<Self> {
+        match number {
+            4 => Some(HmacAlgorithm::HmacSha256),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        [HmacAlgorithm::HmacSha256].into_iter()
+    }
+
+    fn cose_number(&self) -> Option<i128> {
+        match self {
+            HmacAlgorithm::HmacSha256 => Some(5),
+        }
+    }
 }
 
 pub enum HmacKey<EC: ExtenderConfig> {
@@ -38,11 +61,13 @@ pub enum HmacKey<EC: ExtenderConfig> {
     // HmacState -- but that would incur a Clone requirement (and a Clone guarantee on HmacState)
     // that we can't keep up when we forward to the underlying implementation.
     HmacSha256 {
-        inner: <Extender<EC> as HashProvider>::State,
-        outer_key: [u8; SHA2SHORT_BLOCK_SIZE],
+        inner: WipeOnDrop<<Extender<EC> as HashProvider>::State>,
+        outer_key: Zeroizing<[u8; SHA2SHORT_BLOCK_SIZE]>,
     },
 }
 
+impl<EC: ExtenderConfig> ZeroizeOnDrop for HmacKey<EC> {}
+
 impl<EC: ExtenderConfig> Clone for HmacKey<EC> {
     // This is the default implemnentation, but we can't derive it because EC is not clone. (We
     // don't expect it to, but we'd need "minimal derives" in Rust to make it derivable).
@@ -50,7 +75,7 @@ impl<EC: ExtenderConfig> Clone for HmacKey<EC> {
         match self {
             Self::HmacSha256 { inner, outer_key } => Self::HmacSha256 {
                 inner: inner.clone(),
-                outer_key: *outer_key,
+                outer_key: outer_key.clone(),
             },
         }
     }
@@ -59,16 +84,22 @@ impl<EC: ExtenderConfig> Clone for HmacKey<EC> {
 pub enum HmacState<EC: ExtenderConfig> {
     HmacSha256 {
         /// Inner hash state accumulating `H((K XOR ipad) || message)`.
-        inner: <Extender<EC> as HashProvider>::State,
+        ///
+        /// It is wiped like the key, as it is as good as the key before the message is absorbed.
+        inner: WipeOnDrop<<Extender<EC> as HashProvider>::State>,
         /// Key material XORed with opad, ready for the outer hash in `finalize`.
-        outer_key: [u8; SHA2SHORT_BLOCK_SIZE],
+        outer_key: Zeroizing<[u8; SHA2SHORT_BLOCK_SIZE]>,
     },
 }
 
+impl<EC: ExtenderConfig> ZeroizeOnDrop for HmacState<EC> {}
+
 pub enum HmacResult {
-    HmacSha256([u8; 32]),
+    HmacSha256(Zeroizing<[u8; 32]>),
 }
 
+impl ZeroizeOnDrop for HmacResult {}
+
 impl AsRef<[u8]> for HmacResult {
     fn as_ref(&self) -> &[u8] {
         match self {
@@ -83,14 +114,18 @@ impl<EC: ExtenderConfig> HmacProvider for Extender<EC> {
     type State = HmacState<EC>;
     type Output = HmacResult;
 
-    fn load_from_keydata(&mut self, algorithm: Self::Algorithm, key: &[u8]) -> Self::Key {
-        match algorithm {
+    fn try_load_from_keydata(
+        &mut self,
+        algorithm: Self::Algorithm,
+        key: &[u8],
+    ) -> Result<Self::Key, OperationError> {
+        Ok(match algorithm {
             HmacAlgorithm::HmacSha256 => {
                 // Normalise key to exactly SHA2SHORT_BLOCK_SIZE bytes.
                 // If key is longer than the block size, hash it first (RFC 2104).
-                let mut key_block = [0u8; SHA2SHORT_BLOCK_SIZE];
+                let mut key_block = Zeroizing::new([0u8; SHA2SHORT_BLOCK_SIZE]);
                 if key.len() > SHA2SHORT_BLOCK_SIZE {
-                    let hashed = HashProvider::hash(self, HashAlgorithm::Sha256, key);
+                    let hashed = HashProvider::try_hash(self, HashAlgorithm::Sha256, key)?;
                     let h = hashed.as_ref();
                     debug_assert_eq!(h.len(), 32, "SHA-256 must produce 32 bytes");
                     key_block[..h.len()].copy_from_slice(h);
@@ -99,50 +134,51 @@ impl<EC: ExtenderConfig> HmacProvider for Extender<EC> {
                 }
 
                 // outer_key = key_block XOR opad (0x5c)
-                let mut outer_key = [0u8; SHA2SHORT_BLOCK_SIZE];
+                let mut outer_key = Zeroizing::new([0u8; SHA2SHORT_BLOCK_SIZE]);
                 for (o, &k) in outer_key.iter_mut().zip(key_block.iter()) {
                     *o = k ^ 0x5c;
                 }
 
                 // ipad_block = key_block XOR ipad (0x36)
-                let mut ipad_block = [0u8; SHA2SHORT_BLOCK_SIZE];
+                let mut ipad_block = Zeroizing::new([0u8; SHA2SHORT_BLOCK_SIZE]);
                 for (i, &k) in ipad_block.iter_mut().zip(key_block.iter()) {
                     *i = k ^ 0x36;
                 }
 
                 // Start inner hash: H((key XOR ipad) || ...)
-                let mut inner = HashProvider::init(self, HashAlgorithm::Sha256);
-                HashProvider::update(self, &mut inner, &ipad_block);
+                let mut inner = HashProvider::try_init(self, HashAlgorithm::Sha256)?;
+                HashProvider::try_update(self, &mut inner, &*ipad_block)?;
 
-                HmacKey::HmacSha256 { inner, outer_key }
+                HmacKey::HmacSha256 {
+                    inner: WipeOnDrop::new(inner),
+                    outer_key,
+                }
             }
-        }
+        })
     }
 
-    fn init(&mut self, key: Self::Key) -> Self::State {
-        match key {
+    fn try_init(&mut self, key: Self::Key) -> Result<Self::State, OperationError> {
+        Ok(match key {
             HmacKey::HmacSha256 { inner, outer_key } => HmacState::HmacSha256 { inner, outer_key },
-        }
+        })
     }
 
-    fn update(&mut self, state: &mut Self::State, data: &[u8]) {
+    fn try_update(&mut self, state: &mut Self::State, data: &[u8]) -> Result<(), OperationError> {
         match state {
-            HmacState::HmacSha256 { inner, .. } => {
-                HashProvider::update(self, inner, data);
-            }
+            HmacState::HmacSha256 { inner, .. } => HashProvider::try_update(self, inner, data),
         }
     }
 
-    fn finalize(&mut self, state: Self::State) -> Self::Output {
+    fn try_finalize(&mut self, state: Self::State) -> Result<Self::Output, OperationError> {
         match state {
             HmacState::HmacSha256 { inner, outer_key } => {
                 // Finish inner hash, then compute outer: H(outer_key || inner_result)
-                let inner_result = HashProvider::finalize(self, inner);
-                let mut outer = HashProvider::init(self, HashAlgorithm::Sha256);
-                HashProvider::update(self, &mut outer, &outer_key);
-                HashProvider::update(self, &mut outer, inner_result.as_ref());
-                match HashProvider::finalize(self, outer) {
-                    HashResult::Sha256(buf) => HmacResult::HmacSha256(buf),
+                let inner_result = HashProvider::try_finalize(self, inner.into_inner())?;
+                let mut outer = HashProvider::try_init(self, HashAlgorithm::Sha256)?;
+                HashProvider::try_update(self, &mut outer, &*outer_key)?;
+                HashProvider::try_update(self, &mut outer, inner_result.as_ref())?;
+                match HashProvider::try_finalize(self, outer)? {
+                    HashResult::Sha256(buf) => Ok(HmacResult::HmacSha256(Zeroizing::new(buf))),
                     _ => unreachable!("Sha256 init produces Sha256 result"),
                 }
             }
@@ -166,6 +202,20 @@ pub mod tests {
     fn test_hmac_sha256_on_dummy() {
         let mut cal = Extender::<ImplementSha256Short>(dummy_sha256::DummySha256::new());
 
+        embedded_cal::test_hmac_algorithm_hmacsha256::<HmacAlgorithm>();
         testvectors::test_hmac_sha256(&mut cal);
     }
+
+    #[test]
+    fn test_hmac_key_wiped() {
+        let mut cal = Extender::<ImplementSha256Short>(dummy_sha256::DummySha256::new());
+
+        let key = cal
+            .try_load_from_keydata(HmacAlgorithm::HmacSha256, &[0x55; 32])
+            .unwrap();
+        let memory = testvectors::zeroize::memory_after_drop(key, |key| match key {
+            HmacKey::HmacSha256 { outer_key, .. } => &**outer_key,
+        });
+        assert_eq!(memory, [0; SHA2SHORT_BLOCK_SIZE]);
+    }
 }
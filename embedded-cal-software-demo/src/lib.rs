@@ -33,6 +33,12 @@ impl<EC: ExtenderConfig> embedded_cal::Cal for Extender<EC> {
     type AeadProvider = AeadProviderOf<EC::Base>;
     type HashProvider = Self;
     type HmacProvider = Self;
+    type SignatureProvider = SignatureProviderOf<EC::Base>;
+    type KemProvider = KemProviderOf<EC::Base>;
+    type BlockCipherProvider = BlockCipherProviderOf<EC::Base>;
+    type EccProvider = EccProviderOf<EC::Base>;
+    type MacProvider = MacProviderOf<EC::Base>;
+    type KeyWrapProvider = KeyWrapProviderOf<EC::Base>;
 
     fn dh(&mut self) -> &mut Self::DhProvider {
         self.0.dh()
@@ -49,9 +55,34 @@ impl<EC: ExtenderConfig> embedded_cal::Cal for Extender<EC> {
     fn hmac(&mut self) -> &mut Self::HmacProvider {
         self
     }
+
+    fn signature(&mut self) -> &mut Self::SignatureProvider {
+        self.0.signature()
+    }
+
+    fn kem(&mut self) -> &mut Self::KemProvider {
+        self.0.kem()
+    }
+
+    fn block_cipher(&mut self) -> &mut Self::BlockCipherProvider {
+        self.0.block_cipher()
+    }
+
+    fn ecc(&mut self) -> &mut Self::EccProvider {
+        self.0.ecc()
+    }
+
+    fn mac(&mut self) -> &mut Self::MacProvider {
+        self.0.mac()
+    }
+
+    fn key_wrap(&mut self) -> &mut Self::KeyWrapProvider {
+        self.0.key_wrap()
+    }
 }
 
 #[cfg(test)]
 pub(crate) mod tests {
     pub(crate) mod dummy_sha256;
+    pub(crate) mod dummy_sha512;
 }
@@ -37,6 +37,12 @@ impl embedded_cal::Cal for DummySha256 {
     type AeadProvider = EmptyCal<false>;
     type HashProvider = EmptyCal<false>;
     type HmacProvider = EmptyCal<false>;
+    type SignatureProvider = EmptyCal<false>;
+    type KemProvider = EmptyCal<false>;
+    type BlockCipherProvider = EmptyCal<false>;
+    type EccProvider = EmptyCal<false>;
+    type MacProvider = EmptyCal<false>;
+    type KeyWrapProvider = EmptyCal<false>;
 
     fn dh(&mut self) -> &mut Self::DhProvider {
         &mut self.0
@@ -53,6 +59,30 @@ impl embedded_cal::Cal for DummySha256 {
     fn hmac(&mut self) -> &mut Self::HmacProvider {
         &mut self.0
     }
+
+    fn signature(&mut self) -> &mut Self::SignatureProvider {
+        &mut self.0
+    }
+
+    fn kem(&mut self) -> &mut Self::KemProvider {
+        &mut self.0
+    }
+
+    fn block_cipher(&mut self) -> &mut Self::BlockCipherProvider {
+        &mut self.0
+    }
+
+    fn ecc(&mut self) -> &mut Self::EccProvider {
+        &mut self.0
+    }
+
+    fn mac(&mut self) -> &mut Self::MacProvider {
+        &mut self.0
+    }
+
+    fn key_wrap(&mut self) -> &mut Self::KeyWrapProvider {
+        &mut self.0
+    }
 }
 
 impl embedded_cal::plumbing::Plumbing for DummySha256 {}
@@ -139,3 +169,24 @@ impl embedded_cal::plumbing::hash::Sha2Short for DummySha256 {
         }
     }
 }
+
+impl embedded_cal::plumbing::hash::Sha2Long for DummySha256 {
+    const SUPPORTED: bool = false;
+    const SEND_PADDING: bool = false;
+    const FIRST_CHUNK_SIZE: usize = 0;
+    const UPDATE_MULTICHUNK: bool = false;
+
+    type State = embedded_cal::empty::NoAlgorithms;
+
+    fn init(&mut self, _variant: embedded_cal::plumbing::hash::Sha2LongVariant) -> Self::State {
+        panic!("user disregarded SUPPORTED=false")
+    }
+
+    fn update(&mut self, instance: &mut Self::State, _data: &[u8]) {
+        match *instance {}
+    }
+
+    fn finalize(&mut self, instance: Self::State, _last_chunk: &[u8], _target: &mut [u8]) {
+        match instance {}
+    }
+}
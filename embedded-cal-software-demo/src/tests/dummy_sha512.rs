@@ -0,0 +1,298 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+//! Testing-only module with a manual version of SHA-512 that does *not* do the padding, as the
+//! [`DummySha256`][super::dummy_sha256::DummySha256] does for SHA-256.
+#![allow(
+    non_snake_case,
+    non_upper_case_globals,
+    reason = "folling algorithm convention"
+)]
+
+use embedded_cal::{
+    empty::{EmptyCal, NoAlgorithms},
+    plumbing::hash::{Sha2LongVariant, Sha2ShortVariant},
+};
+
+/// A minimal testable version of the SHA-512 family that only processes whole blocks.
+///
+/// All implementation follows the Wikipedia pseudocode. Unlike the SHA-256 dummy, this does not
+/// use a special first chunk size.
+pub struct DummySha512(EmptyCal<false>);
+
+impl DummySha512 {
+    pub fn new() -> Self {
+        Self(EmptyCal)
+    }
+}
+
+const k: [u64; 80] = [
+    0x428a2f98d728ae22,
+    0x7137449123ef65cd,
+    0xb5c0fbcfec4d3b2f,
+    0xe9b5dba58189dbbc,
+    0x3956c25bf348b538,
+    0x59f111f1b605d019,
+    0x923f82a4af194f9b,
+    0xab1c5ed5da6d8118,
+    0xd807aa98a3030242,
+    0x12835b0145706fbe,
+    0x243185be4ee4b28c,
+    0x550c7dc3d5ffb4e2,
+    0x72be5d74f27b896f,
+    0x80deb1fe3b1696b1,
+    0x9bdc06a725c71235,
+    0xc19bf174cf692694,
+    0xe49b69c19ef14ad2,
+    0xefbe4786384f25e3,
+    0x0fc19dc68b8cd5b5,
+    0x240ca1cc77ac9c65,
+    0x2de92c6f592b0275,
+    0x4a7484aa6ea6e483,
+    0x5cb0a9dcbd41fbd4,
+    0x76f988da831153b5,
+    0x983e5152ee66dfab,
+    0xa831c66d2db43210,
+    0xb00327c898fb213f,
+    0xbf597fc7beef0ee4,
+    0xc6e00bf33da88fc2,
+    0xd5a79147930aa725,
+    0x06ca6351e003826f,
+    0x142929670a0e6e70,
+    0x27b70a8546d22ffc,
+    0x2e1b21385c26c926,
+    0x4d2c6dfc5ac42aed,
+    0x53380d139d95b3df,
+    0x650a73548baf63de,
+    0x766a0abb3c77b2a8,
+    0x81c2c92e47edaee6,
+    0x92722c851482353b,
+    0xa2bfe8a14cf10364,
+    0xa81a664bbc423001,
+    0xc24b8b70d0f89791,
+    0xc76c51a30654be30,
+    0xd192e819d6ef5218,
+    0xd69906245565a910,
+    0xf40e35855771202a,
+    0x106aa07032bbd1b8,
+    0x19a4c116b8d2d0c8,
+    0x1e376c085141ab53,
+    0x2748774cdf8eeb99,
+    0x34b0bcb5e19b48a8,
+    0x391c0cb3c5c95a63,
+    0x4ed8aa4ae3418acb,
+    0x5b9cca4f7763e373,
+    0x682e6ff3d6b2b8a3,
+    0x748f82ee5defb2fc,
+    0x78a5636f43172f60,
+    0x84c87814a1f0ab72,
+    0x8cc702081a6439ec,
+    0x90befffa23631e28,
+    0xa4506cebde82bde9,
+    0xbef9a3f7b2c67915,
+    0xc67178f2e372532b,
+    0xca273eceea26619c,
+    0xd186b8c721c0c207,
+    0xeada7dd6cde0eb1e,
+    0xf57d4f7fee6ed178,
+    0x06f067aa72176fba,
+    0x0a637dc5a2c898a6,
+    0x113f9804bef90dae,
+    0x1b710b35131c471b,
+    0x28db77f523047d84,
+    0x32caab7b40c72493,
+    0x3c9ebe0a15c9bebc,
+    0x431d67c49c100d4c,
+    0x4cc5d4becb3e42b6,
+    0x597f299cfc657e2a,
+    0x5fcb6fab3ad6faec,
+    0x6c44198c4a475817,
+];
+
+impl embedded_cal::Cal for DummySha512 {
+    type DhProvider = EmptyCal<false>;
+    type AeadProvider = EmptyCal<false>;
+    type HashProvider = EmptyCal<false>;
+    type HmacProvider = EmptyCal<false>;
+    type SignatureProvider = EmptyCal<false>;
+    type KemProvider = EmptyCal<false>;
+    type BlockCipherProvider = EmptyCal<false>;
+    type EccProvider = EmptyCal<false>;
+    type MacProvider = EmptyCal<false>;
+    type KeyWrapProvider = EmptyCal<false>;
+
+    fn dh(&mut self) -> &mut Self::DhProvider {
+        &mut self.0
+    }
+
+    fn aead(&mut self) -> &mut Self::AeadProvider {
+        &mut self.0
+    }
+
+    fn hash(&mut self) -> &mut Self::HashProvider {
+        &mut self.0
+    }
+
+    fn hmac(&mut self) -> &mut Self::HmacProvider {
+        &mut self.0
+    }
+
+    fn signature(&mut self) -> &mut Self::SignatureProvider {
+        &mut self.0
+    }
+
+    fn kem(&mut self) -> &mut Self::KemProvider {
+        &mut self.0
+    }
+
+    fn block_cipher(&mut self) -> &mut Self::BlockCipherProvider {
+        &mut self.0
+    }
+
+    fn ecc(&mut self) -> &mut Self::EccProvider {
+        &mut self.0
+    }
+
+    fn mac(&mut self) -> &mut Self::MacProvider {
+        &mut self.0
+    }
+
+    fn key_wrap(&mut self) -> &mut Self::KeyWrapProvider {
+        &mut self.0
+    }
+}
+
+impl embedded_cal::plumbing::Plumbing for DummySha512 {}
+
+impl embedded_cal::plumbing::hash::Hash for DummySha512 {}
+
+impl embedded_cal::plumbing::hash::Sha2Short for DummySha512 {
+    const SUPPORTED: bool = false;
+    const SEND_PADDING: bool = false;
+    const FIRST_CHUNK_SIZE: usize = 0;
+    const UPDATE_MULTICHUNK: bool = false;
+
+    type State = NoAlgorithms;
+
+    fn init(&mut self, _variant: Sha2ShortVariant) -> Self::State {
+        panic!("user disregarded SUPPORTED=false")
+    }
+
+    fn update(&mut self, instance: &mut Self::State, _data: &[u8]) {
+        match *instance {}
+    }
+
+    fn finalize(&mut self, instance: Self::State, _last_chunk: &[u8], _target: &mut [u8]) {
+        match instance {}
+    }
+}
+
+impl embedded_cal::plumbing::hash::Sha2Long for DummySha512 {
+    const SUPPORTED: bool = true;
+    const SEND_PADDING: bool = true;
+    const FIRST_CHUNK_SIZE: usize = 0;
+    const UPDATE_MULTICHUNK: bool = false;
+
+    type State = [u64; 8];
+
+    fn init(&mut self, variant: Sha2LongVariant) -> Self::State {
+        match variant {
+            Sha2LongVariant::Sha384 => [
+                0xcbbb9d5dc1059ed8,
+                0x629a292a367cd507,
+                0x9159015a3070dd17,
+                0x152fecd8f70e5939,
+                0x67332667ffc00b31,
+                0x8eb44a8768581511,
+                0xdb0c2e0d64f98fa7,
+                0x47b5481dbefa4fa4,
+            ],
+            Sha2LongVariant::Sha512 => [
+                0x6a09e667f3bcc908,
+                0xbb67ae8584caa73b,
+                0x3c6ef372fe94f82b,
+                0xa54ff53a5f1d36f1,
+                0x510e527fade682d1,
+                0x9b05688c2b3e6c1f,
+                0x1f83d9abfb41bd6b,
+                0x5be0cd19137e2179,
+            ],
+            Sha2LongVariant::Sha512_224 => [
+                0x8c3d37c819544da2,
+                0x73e1996689dcd4d6,
+                0x1dfab7ae32ff9c82,
+                0x679dd514582f9fcf,
+                0x0f6d2b697bd44da8,
+                0x77e36f7304c48942,
+                0x3f9d85a86a1d36c8,
+                0x1112e6ad91d692a1,
+            ],
+            Sha2LongVariant::Sha512_256 => [
+                0x22312194fc2bf72c,
+                0x9f555fa3c84c64c2,
+                0x2393b86b6f53b151,
+                0x963877195940eabd,
+                0x96283ee2a88effe3,
+                0xbe5e1e2553863992,
+                0x2b0199fc2c85b8aa,
+                0x0eb72ddc81c52ca2,
+            ],
+        }
+    }
+
+    fn update(&mut self, instance: &mut Self::State, data: &[u8]) {
+        assert!(data.len() == 128, "Not feeding exactly 1024bit");
+
+        let mut w: [u64; 80] = [0; _];
+        for (i, chunkword) in data.as_chunks::<8>().0.iter().enumerate() {
+            w[i] = u64::from_be_bytes(*chunkword);
+        }
+
+        for i in 16..80 {
+            let s0 = w[i - 15].rotate_right(1) ^ w[i - 15].rotate_right(8) ^ (w[i - 15] >> 7);
+            let s1 = w[i - 2].rotate_right(19) ^ w[i - 2].rotate_right(61) ^ (w[i - 2] >> 6);
+            w[i] = w[i - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[i - 7])
+                .wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *instance;
+
+        for i in 0..80 {
+            let S1 = e.rotate_right(14) ^ e.rotate_right(18) ^ e.rotate_right(41);
+            let ch = (e & f) ^ (!e & g);
+            let temp1 = h
+                .wrapping_add(S1)
+                .wrapping_add(ch)
+                .wrapping_add(k[i])
+                .wrapping_add(w[i]);
+            let S0 = a.rotate_right(28) ^ a.rotate_right(34) ^ a.rotate_right(39);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let temp2 = S0.wrapping_add(maj);
+
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(temp1);
+            d = c;
+            c = b;
+            b = a;
+            a = temp1.wrapping_add(temp2);
+        }
+
+        for (big_h, add_this) in instance.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *big_h = big_h.wrapping_add(add_this);
+        }
+    }
+
+    fn finalize(&mut self, instance: Self::State, last_chunk: &[u8], target: &mut [u8]) {
+        assert!(
+            last_chunk.is_empty(),
+            "Implementation requested that padding be sent, so we're not expecting a non-empty last chunk"
+        );
+
+        for (i, word) in instance.into_iter().enumerate() {
+            target[8 * i..][..8].copy_from_slice(&word.to_be_bytes());
+        }
+    }
+}
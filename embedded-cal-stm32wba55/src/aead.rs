@@ -1,6 +1,11 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
 
+use embedded_cal::{DecryptionFailed, OperationError, TryError};
+
+/// Nonce length of the supported CCM variants.
+const NONCE_LEN: usize = 13;
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AeadAlgorithm {
     AesCcm16_64_128,
@@ -20,7 +25,7 @@ impl embedded_cal::AeadAlgorithm for AeadAlgorithm {
     }
 
     fn nonce_length(&self) -> usize {
-        13
+        NONCE_LEN
     }
 
     fn from_cose_number(number: impl Into<i128>) -> Option<Self> {
@@ -30,6 +35,21 @@ impl embedded_cal::AeadAlgorithm for AeadAlgorithm {
             _ => None,
         }
     }
+
+    fn all() -> impl Iterator<Item = Self> {
+        [
+            AeadAlgorithm::AesCcm16_64_128,
+            AeadAlgorithm::AesCcm16_64_256,
+        ]
+        .into_iter()
+    }
+
+    fn cose_number(&self) -> Option<i128> {
+        match self {
+            AeadAlgorithm::AesCcm16_64_128 => Some(10),
+            AeadAlgorithm::AesCcm16_64_256 => Some(11),
+        }
+    }
 }
 
 pub enum AeadKey {
@@ -283,27 +303,36 @@ impl embedded_cal::AeadProvider for super::Stm32wba55Cal {
     type Key = AeadKey;
     type Tag = AeadTag;
 
-    fn load_from_keydata(&mut self, alg: Self::Algorithm, key: &[u8]) -> Self::Key {
-        match alg {
+    fn try_load_from_keydata(
+        &mut self,
+        alg: Self::Algorithm,
+        key: &[u8],
+    ) -> Result<Self::Key, OperationError> {
+        let mismatch = |_| OperationError::LengthMismatch;
+        Ok(match alg {
             AeadAlgorithm::AesCcm16_64_128 => {
-                AeadKey::AesCcm16_64_128(key.try_into().expect("key length mismatch"))
+                AeadKey::AesCcm16_64_128(key.try_into().map_err(mismatch)?)
             }
             AeadAlgorithm::AesCcm16_64_256 => {
-                AeadKey::AesCcm16_64_256(key.try_into().expect("key length mismatch"))
+                AeadKey::AesCcm16_64_256(key.try_into().map_err(mismatch)?)
             }
-        }
+        })
     }
 
-    fn encrypt_in_place(
+    fn try_encrypt_in_place(
         &mut self,
         key: &Self::Key,
         nonce: &[u8],
         message: &mut [u8],
         aad: impl embedded_cal::AadGenerator,
-    ) -> Self::Tag {
+    ) -> Result<Self::Tag, OperationError> {
         use stm32_metapac::aes::vals::Mode;
 
-        match key {
+        if nonce.len() != NONCE_LEN {
+            return Err(OperationError::LengthMismatch);
+        }
+
+        Ok(match key {
             AeadKey::AesCcm16_64_128(key_bytes) => {
                 // Follows the "CCM encryption and decryption process" from RM0493 (STM32WBA5x
                 // Reference Manual), which runs four sequential hardware phases:
@@ -368,19 +397,23 @@ impl embedded_cal::AeadProvider for super::Stm32wba55Cal {
                 tag.copy_from_slice(&tag_full[..TAG_LEN]);
                 AeadTag::AesCcm16_64_256(tag)
             }
-        }
+        })
     }
 
-    fn decrypt_in_place(
+    fn try_decrypt_in_place(
         &mut self,
         key: &Self::Key,
         nonce: &[u8],
         message: &mut [u8],
         tag: &[u8],
         aad: impl embedded_cal::AadGenerator,
-    ) -> Result<(), embedded_cal::DecryptionFailed> {
+    ) -> Result<(), TryError<DecryptionFailed>> {
         use stm32_metapac::aes::vals::Mode;
 
+        if nonce.len() != NONCE_LEN || tag.len() != 8 {
+            return Err(OperationError::LengthMismatch.into());
+        }
+
         match key {
             AeadKey::AesCcm16_64_128(key_bytes) => {
                 const TAG_LEN: usize = 8;
@@ -421,7 +454,7 @@ impl embedded_cal::AeadProvider for super::Stm32wba55Cal {
                 if tags_match {
                     Ok(())
                 } else {
-                    Err(embedded_cal::DecryptionFailed)
+                    Err(TryError::Failed(DecryptionFailed))
                 }
             }
             AeadKey::AesCcm16_64_256(key_bytes) => {
@@ -459,7 +492,7 @@ impl embedded_cal::AeadProvider for super::Stm32wba55Cal {
                 if tags_match {
                     Ok(())
                 } else {
-                    Err(embedded_cal::DecryptionFailed)
+                    Err(TryError::Failed(DecryptionFailed))
                 }
             }
         }
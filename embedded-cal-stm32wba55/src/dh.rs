@@ -2,9 +2,9 @@
 // SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
 
 use embedded_cal::p256::{
-    B, P, P256_GX, P256_GY, P256_ORDER, bytes_to_words, ge, p256_recover_y, words_to_bytes,
+    B, P, P256_GX, P256_GY, P256_ORDER, bytes_to_words, ge, p256_decode_point, p256_encode_point,
+    p256_recover_y, words_to_bytes,
 };
-use rand_core::Rng;
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
 // P-256 curve constants (little-endian word order: LSW at index 0)
@@ -51,6 +51,23 @@ impl embedded_cal::DhAlgorithm for DhAlgorithm {
             _ => None,
         }
     }
+
+    fn from_tls_named_group(group: u16) -> Option<Self> {
+        match group {
+            23 => Some(DhAlgorithm::EcdhP256),
+            _ => None,
+        }
+    }
+
+    fn all() -> impl Iterator<Item = Self> {
+        [DhAlgorithm::EcdhP256].into_iter()
+    }
+
+    fn cose_ecdh(&self) -> Option<i128> {
+        match self {
+            DhAlgorithm::EcdhP256 => Some(1),
+        }
+    }
 }
 
 #[derive(Zeroize, ZeroizeOnDrop)]
@@ -168,15 +185,17 @@ impl embedded_cal::DhProvider for super::Stm32wba55Cal {
     type PublicKey = PublicKey;
     type SharedSecret = SharedSecret;
 
-    fn generate_visible(&mut self, alg: Self::Algorithm) -> Self::VisibleSecretKey {
+    fn try_generate_visible(
+        &mut self,
+        alg: Self::Algorithm,
+    ) -> Result<Self::VisibleSecretKey, embedded_cal::OperationError> {
         match alg {
             DhAlgorithm::EcdhP256 => loop {
                 let mut scalar = [0u8; 32];
-                // Error = Infallible for this RNG
-                self.fill_bytes(&mut scalar);
+                self.fill_random(&mut scalar)?;
                 let w = bytes_to_words(&scalar);
                 if w != [0u32; 8] && !ge(&w, &P256_ORDER) {
-                    return VisibleSecretKey(SecretKey { alg, scalar });
+                    return Ok(VisibleSecretKey(SecretKey { alg, scalar }));
                 }
             },
         }
@@ -215,13 +234,33 @@ impl embedded_cal::DhProvider for super::Stm32wba55Cal {
         Ok(PublicKey { alg, x, y })
     }
 
-    fn shared_secret(
+    fn export_publickey<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+        format: embedded_cal::PublicKeyFormat,
+    ) -> Result<impl AsRef<[u8]> + use<'p>, embedded_cal::UnsupportedFormat> {
+        Ok(p256_encode_point(&public.x, &public.y, format))
+    }
+
+    fn import_publickey(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+        format: embedded_cal::PublicKeyFormat,
+    ) -> Result<Self::PublicKey, embedded_cal::ImportError> {
+        let (x, y) = p256_decode_point(data, format)?;
+        Ok(PublicKey { alg, x, y })
+    }
+
+    fn try_shared_secret(
         &mut self,
         private: &Self::SecretKey,
         public: &Self::PublicKey,
-    ) -> Result<Self::SharedSecret, embedded_cal::IncompatibleKeys> {
+    ) -> Result<Self::SharedSecret, embedded_cal::TryError<embedded_cal::IncompatibleKeys>> {
         if private.alg != public.alg {
-            return Err(embedded_cal::IncompatibleKeys);
+            return Err(embedded_cal::TryError::Failed(
+                embedded_cal::IncompatibleKeys,
+            ));
         }
         let mut scalar_words = bytes_to_words(&private.scalar);
         let (result_x, _) = self.pka_ecc_mult(
@@ -233,15 +272,18 @@ impl embedded_cal::DhProvider for super::Stm32wba55Cal {
         Ok(SharedSecret(words_to_bytes(&result_x)))
     }
 
-    fn public_key(&mut self, private: &Self::SecretKey) -> Self::PublicKey {
+    fn try_public_key(
+        &mut self,
+        private: &Self::SecretKey,
+    ) -> Result<Self::PublicKey, embedded_cal::OperationError> {
         let mut scalar_words = bytes_to_words(&private.scalar);
         let (result_x, result_y) = self.pka_ecc_mult(&scalar_words, &P256_GX, &P256_GY);
         scalar_words.zeroize();
-        PublicKey {
+        Ok(PublicKey {
             alg: private.alg.clone(),
             x: words_to_bytes(&result_x),
             y: words_to_bytes(&result_y),
-        }
+        })
     }
 
     fn raw_secret_bytes<'s>(
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #![no_std]
 
+use embedded_cal::OperationError;
 use embedded_cal::empty::EmptyCal;
 use embedded_cal::plumbing::hash::SHA2SHORT_BLOCK_SIZE;
 use stm32_metapac::{
@@ -38,6 +39,12 @@ impl embedded_cal::Cal for Stm32wba55Cal {
     type AeadProvider = Self;
     type HashProvider = EmptyCal<false>;
     type HmacProvider = Self;
+    type SignatureProvider = EmptyCal<false>;
+    type KemProvider = EmptyCal<false>;
+    type BlockCipherProvider = EmptyCal<false>;
+    type EccProvider = EmptyCal<false>;
+    type MacProvider = EmptyCal<false>;
+    type KeyWrapProvider = EmptyCal<false>;
 
     fn dh(&mut self) -> &mut Self::DhProvider {
         self
@@ -52,16 +59,37 @@ impl embedded_cal::Cal for Stm32wba55Cal {
     fn hmac(&mut self) -> &mut Self::HmacProvider {
         self
     }
+    fn signature(&mut self) -> &mut Self::SignatureProvider {
+        &mut self.empty
+    }
+    fn kem(&mut self) -> &mut Self::KemProvider {
+        &mut self.empty
+    }
+    fn block_cipher(&mut self) -> &mut Self::BlockCipherProvider {
+        &mut self.empty
+    }
+    fn ecc(&mut self) -> &mut Self::EccProvider {
+        &mut self.empty
+    }
+    fn mac(&mut self) -> &mut Self::MacProvider {
+        &mut self.empty
+    }
+    fn key_wrap(&mut self) -> &mut Self::KeyWrapProvider {
+        &mut self.empty
+    }
 }
 
 impl Stm32wba55Cal {
-    pub fn new(
+    /// Takes over the peripherals and brings up their clocks, the RNG and the PKA.
+    ///
+    /// Fails with [`OperationError::HardwareFault`] if the RNG does not become operational.
+    pub fn try_new(
         hash: hash::Hash,
         rcc: rcc::Rcc,
         rng: rng::Rng,
         aes: aes::Aes,
         pka: pka::Pka,
-    ) -> Self {
+    ) -> Result<Self, OperationError> {
         // Select HSI as the RNG kernel clock source (default is LSE which may not be running)
         rcc.ccipr2().modify(|w| w.set_rngsel(Rngsel::HSI));
 
@@ -81,14 +109,25 @@ impl Stm32wba55Cal {
             pka,
             empty: EmptyCal,
         };
-        cal.init_rng();
+        cal.init_rng()?;
 
         // PKA INITOK depends on the RNG being operational; initialize after RNG is ready.
         cal.pka.cr().modify(|w| w.set_en(false));
         cal.pka.cr().modify(|w| w.set_en(true));
         while !cal.pka.sr().read().initok() {}
 
-        cal
+        Ok(cal)
+    }
+
+    /// Like [`Self::try_new()`], but panics if the RNG does not become operational.
+    pub fn new(
+        hash: hash::Hash,
+        rcc: rcc::Rcc,
+        rng: rng::Rng,
+        aes: aes::Aes,
+        pka: pka::Pka,
+    ) -> Self {
+        Self::try_new(hash, rcc, rng, aes, pka).unwrap_or_else(|e| panic!("{e}"))
     }
 
     /// Initialize the RNG peripheral using the rng_v3 conditioning sequence.
@@ -96,7 +135,9 @@ impl Stm32wba55Cal {
     /// Must be called once after enabling the RNG clock, and again on seed error recovery.
     /// Uses NIST config A (certifiable). The HTCR magic number must precede any HTCR write
     /// per the RM0493 requirement.
-    fn init_rng(&mut self) {
+    ///
+    /// Fails with [`OperationError::HardwareFault`] if the RNG does not become operational.
+    fn init_rng(&mut self) -> Result<(), OperationError> {
         // Enter conditioning reset with NIST config A settings
         self.rng.cr().write(|w| {
             w.set_condrst(true);
@@ -111,7 +152,7 @@ impl Stm32wba55Cal {
         });
 
         // Wait for conditioning reset to take effect
-        wait_for(|| self.rng.cr().read().condrst());
+        wait_for(|| self.rng.cr().read().condrst())?;
 
         // Write health test config: magic number must immediately precede the actual value
         self.rng.htcr().write(|w| w.set_htcfg(Htcfg::MAGIC));
@@ -124,7 +165,7 @@ impl Stm32wba55Cal {
         });
 
         // Wait for conditioning reset to deassert (RM0493 requires waiting for both assert and deassert)
-        wait_for(|| !self.rng.cr().read().condrst());
+        wait_for(|| !self.rng.cr().read().condrst())?;
 
         // Clear any latched seed error from the reset
         self.rng.sr().modify(|w| w.set_seis(false));
@@ -134,22 +175,23 @@ impl Stm32wba55Cal {
         wait_for(|| {
             let sr = self.rng.sr().read();
             sr.drdy() || sr.seis()
-        });
+        })?;
         if self.rng.sr().read().seis() {
-            panic!("RNG hardware error");
+            return Err(OperationError::HardwareFault);
         }
         let _ = self.rng.dr().read();
+        Ok(())
     }
 }
 
-fn wait_for(mut condition: impl FnMut() -> bool) {
+fn wait_for(mut condition: impl FnMut() -> bool) -> Result<(), OperationError> {
     for _ in 0..1000 {
         if condition() {
-            return;
+            return Ok(());
         }
         core::hint::spin_loop();
     }
-    panic!("RNG hardware failure");
+    Err(OperationError::HardwareFault)
 }
 
 impl Drop for Stm32wba55Cal {
@@ -209,6 +251,23 @@ impl embedded_cal::HmacAlgorithm for HmacAlgorithm {
             _ => None,
         }
     }
+
+    fn from_tls_hash_algorithm(number: u8) -> Option<Self> {
+        match number {
+            4 => Some(HmacAlgorithm::HmacSha256),
+            _ => None,
+        }
+    }
+
+    fn all() -> impl Iterator<Item = Self> {
+        [HmacAlgorithm::HmacSha256].into_iter()
+    }
+
+    fn cose_number(&self) -> Option<i128> {
+        match self {
+            HmacAlgorithm::HmacSha256 => Some(5),
+        }
+    }
 }
 
 /// State for an in-progress HMAC-SHA256 operation.
@@ -240,8 +299,12 @@ impl embedded_cal::HmacProvider for Stm32wba55Cal {
     type State = HmacState;
     type Output = HmacResult;
 
-    fn load_from_keydata(&mut self, algorithm: Self::Algorithm, key: &[u8]) -> Self::Key {
-        match algorithm {
+    fn try_load_from_keydata(
+        &mut self,
+        algorithm: Self::Algorithm,
+        key: &[u8],
+    ) -> Result<Self::Key, embedded_cal::OperationError> {
+        Ok(match algorithm {
             HmacAlgorithm::HmacSha256 => {
                 // Normalise key: zero-pad short keys; hash long keys per RFC 2104.
                 let mut key_block = [0u8; SHA2SHORT_BLOCK_SIZE];
@@ -252,45 +315,20 @@ impl embedded_cal::HmacProvider for Stm32wba55Cal {
                     key_block[..key.len()].copy_from_slice(key);
                 }
 
-                // Initialise the HASH peripheral in HMAC-SHA256 mode.
-                self.hash.cr().write(|w| {
-                    w.set_mode(true); // HMAC
-                    w.set_dmae(false);
-                    w.set_algo(3); // SHA-256
-                    w.set_datatype(0); // 32-bit words, no byte-swap
-                    w.set_lkey(false); // always false, because we save the key as sha-256
-                    w.set_init(true);
-                });
-                while self.hash.cr().read().init() {}
-
-                // Feed the inner key (64 bytes = 16 full 32-bit words).
-                for chunk in key_block.chunks_exact(WORD_SIZE) {
-                    let mut bytes = [0u8; WORD_SIZE];
-                    bytes.copy_from_slice(chunk);
-                    self.hash.din().write_value(u32::from_be_bytes(bytes));
-                }
-                // NBLW = 0: all 16 words are full (no partial last word).
-                self.hash.str().write(|w| w.set_nblw(0));
-                self.hash.str().write(|w| w.set_dcal(true));
-
-                // Wait until the hardware has processed the key and is ready for message data.
-                while !self.hash.sr().read().dinis() {}
-
-                HmacState {
-                    context: Some(self.read_context()),
-                    buf: [0; HMAC_MAX_BLOCK_SIZE],
-                    buf_len: 0,
-                    key_block,
-                }
+                self.hmac_key(key_block)
             }
-        }
+        })
     }
 
-    fn init(&mut self, key: Self::Key) -> Self::State {
-        key
+    fn try_init(&mut self, key: Self::Key) -> Result<Self::State, embedded_cal::OperationError> {
+        Ok(key)
     }
 
-    fn update(&mut self, state: &mut Self::State, data: &[u8]) {
+    fn try_update(
+        &mut self,
+        state: &mut Self::State,
+        data: &[u8],
+    ) -> Result<(), embedded_cal::OperationError> {
         let mut remaining = data;
         let mut wrote_blocks = false;
 
@@ -324,48 +362,33 @@ impl embedded_cal::HmacProvider for Stm32wba55Cal {
             // Capture the updated hardware context for the next call.
             state.context = Some(self.read_context());
         }
+        Ok(())
     }
 
-    fn finalize(&mut self, mut state: Self::State) -> Self::Output {
-        // Restore hardware to the last saved context.
-        if let Some(ctx) = state.context.take() {
-            self.restore_context_hmac(&ctx);
-        }
-
-        // Write the remaining buffered bytes (the last, possibly partial, message block).
-        let buf_len = state.buf_len;
-        for chunk in state.buf[..buf_len].chunks(WORD_SIZE) {
-            let mut bytes = [0u8; WORD_SIZE];
-            bytes[..chunk.len()].copy_from_slice(chunk);
-            self.hash.din().write_value(u32::from_be_bytes(bytes));
-        }
-        // NBLW: number of valid bits in the last word (0 = full 32-bit word).
-        let nblw = (buf_len % WORD_SIZE) as u8 * 8;
-        self.hash.str().write(|w| w.set_nblw(nblw));
-        self.hash.str().write(|w| w.set_dcal(true));
-        while !self.hash.sr().read().dinis() {}
-
-        // Feed the outer key (same 64-byte block as the inner key).
-        for chunk in state.key_block.chunks_exact(WORD_SIZE) {
-            let mut bytes = [0u8; WORD_SIZE];
-            bytes.copy_from_slice(chunk);
-            self.hash.din().write_value(u32::from_be_bytes(bytes));
-        }
-        // NBLW = 0: all 16 words of the outer key are full.
-        self.hash.str().write(|w| w.set_nblw(0));
-        self.hash.str().write(|w| w.set_dcal(true));
-
-        // Wait for DCIS: HMAC digest is ready.
-        self.wait_busy();
-
-        let mut words = [0u32; 8];
-        self.read_digest(&mut words);
+    fn try_finalize(
+        &mut self,
+        state: Self::State,
+    ) -> Result<Self::Output, embedded_cal::OperationError> {
+        let mut result = [0u8; SHA256_OUT_BYTES];
+        self.hmac_digest(state, &mut result);
+        Ok(HmacResult(result))
+    }
 
-        let mut result = [0u8; 32];
-        for (i, w) in words.iter().enumerate() {
-            result[i * WORD_SIZE..(i + 1) * WORD_SIZE].copy_from_slice(&w.to_be_bytes());
-        }
-        HmacResult(result)
+    fn try_finalize_into_key(
+        &mut self,
+        algorithm: Self::Algorithm,
+        state: Self::State,
+    ) -> Result<Self::Key, embedded_cal::OperationError> {
+        Ok(match algorithm {
+            HmacAlgorithm::HmacSha256 => {
+                // The peripheral has no key storage, so the key ends up in the key block of the
+                // state like any other key, but it is not handed out as an `HmacResult`. As it is
+                // shorter than a block, it is used zero-padded.
+                let mut key_block = [0u8; SHA2SHORT_BLOCK_SIZE];
+                self.hmac_digest(state, &mut key_block[..SHA256_OUT_BYTES]);
+                self.hmac_key(key_block)
+            }
+        })
     }
 }
 
@@ -441,6 +464,27 @@ impl embedded_cal::plumbing::hash::Sha2Short for Stm32wba55Cal {
     }
 }
 
+impl embedded_cal::plumbing::hash::Sha2Long for Stm32wba55Cal {
+    const SUPPORTED: bool = false;
+    const SEND_PADDING: bool = false;
+    const FIRST_CHUNK_SIZE: usize = 0;
+    const UPDATE_MULTICHUNK: bool = false;
+
+    type State = embedded_cal::empty::NoAlgorithms;
+
+    fn init(&mut self, _variant: embedded_cal::plumbing::hash::Sha2LongVariant) -> Self::State {
+        panic!("user disregarded SUPPORTED=false")
+    }
+
+    fn update(&mut self, instance: &mut Self::State, _data: &[u8]) {
+        match *instance {}
+    }
+
+    fn finalize(&mut self, instance: Self::State, _last_chunk: &[u8], _target: &mut [u8]) {
+        match instance {}
+    }
+}
+
 impl Stm32wba55Cal {
     /// Reinitializes the HASH peripheral, configures it for SHA-256, and restores
     /// any previously saved intermediate context.
@@ -576,4 +620,80 @@ impl Stm32wba55Cal {
             *slot = self.hash.hr(i).read();
         }
     }
+
+    /// Sets the HASH peripheral up for HMAC-SHA256 with a key that is already normalised to one
+    /// block, and feeds it the inner key.
+    fn hmac_key(&mut self, key_block: [u8; SHA2SHORT_BLOCK_SIZE]) -> HmacState {
+        // Initialise the HASH peripheral in HMAC-SHA256 mode.
+        self.hash.cr().write(|w| {
+            w.set_mode(true); // HMAC
+            w.set_dmae(false);
+            w.set_algo(3); // SHA-256
+            w.set_datatype(0); // 32-bit words, no byte-swap
+            w.set_lkey(false); // always false, because we save the key as sha-256
+            w.set_init(true);
+        });
+        while self.hash.cr().read().init() {}
+
+        // Feed the inner key (64 bytes = 16 full 32-bit words).
+        for chunk in key_block.chunks_exact(WORD_SIZE) {
+            let mut bytes = [0u8; WORD_SIZE];
+            bytes.copy_from_slice(chunk);
+            self.hash.din().write_value(u32::from_be_bytes(bytes));
+        }
+        // NBLW = 0: all 16 words are full (no partial last word).
+        self.hash.str().write(|w| w.set_nblw(0));
+        self.hash.str().write(|w| w.set_dcal(true));
+
+        // Wait until the hardware has processed the key and is ready for message data.
+        while !self.hash.sr().read().dinis() {}
+
+        HmacState {
+            context: Some(self.read_context()),
+            buf: [0; HMAC_MAX_BLOCK_SIZE],
+            buf_len: 0,
+            key_block,
+        }
+    }
+
+    /// Finishes an HMAC-SHA256 operation, writing the 32 byte output to `out`.
+    fn hmac_digest(&mut self, mut state: HmacState, out: &mut [u8]) {
+        // Restore hardware to the last saved context.
+        if let Some(ctx) = state.context.take() {
+            self.restore_context_hmac(&ctx);
+        }
+
+        // Write the remaining buffered bytes (the last, possibly partial, message block).
+        let buf_len = state.buf_len;
+        for chunk in state.buf[..buf_len].chunks(WORD_SIZE) {
+            let mut bytes = [0u8; WORD_SIZE];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            self.hash.din().write_value(u32::from_be_bytes(bytes));
+        }
+        // NBLW: number of valid bits in the last word (0 = full 32-bit word).
+        let nblw = (buf_len % WORD_SIZE) as u8 * 8;
+        self.hash.str().write(|w| w.set_nblw(nblw));
+        self.hash.str().write(|w| w.set_dcal(true));
+        while !self.hash.sr().read().dinis() {}
+
+        // Feed the outer key (same 64-byte block as the inner key).
+        for chunk in state.key_block.chunks_exact(WORD_SIZE) {
+            let mut bytes = [0u8; WORD_SIZE];
+            bytes.copy_from_slice(chunk);
+            self.hash.din().write_value(u32::from_be_bytes(bytes));
+        }
+        // NBLW = 0: all 16 words of the outer key are full.
+        self.hash.str().write(|w| w.set_nblw(0));
+        self.hash.str().write(|w| w.set_dcal(true));
+
+        // Wait for DCIS: HMAC digest is ready.
+        self.wait_busy();
+
+        let mut words = [0u32; 8];
+        self.read_digest(&mut words);
+
+        for (chunk, w) in out.chunks_exact_mut(WORD_SIZE).zip(words) {
+            chunk.copy_from_slice(&w.to_be_bytes());
+        }
+    }
 }
@@ -2,25 +2,16 @@
 // SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
 
 use crate::Stm32wba55Cal;
-const MAX_SEED_RETRIES: u32 = 3;
-
-impl rand_core::TryCryptoRng for Stm32wba55Cal {}
-impl rand_core::TryRng for Stm32wba55Cal {
-    type Error = core::convert::Infallible;
-
-    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
-        let mut bytes = [0u8; 4];
-        self.try_fill_bytes(&mut bytes)?;
-        Ok(u32::from_le_bytes(bytes))
-    }
+use embedded_cal::OperationError;
 
-    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
-        let mut bytes = [0u8; 8];
-        self.try_fill_bytes(&mut bytes)?;
-        Ok(u64::from_le_bytes(bytes))
-    }
+const MAX_SEED_RETRIES: u32 = 3;
 
-    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+impl Stm32wba55Cal {
+    /// Fills `dst` with random bytes.
+    ///
+    /// Persistent hardware failure is reported as [`OperationError::HardwareFault`], also through
+    /// the [`rand_core::TryRng`] implementation.
+    pub(crate) fn fill_random(&mut self, dst: &mut [u8]) -> Result<(), OperationError> {
         let mut dst = dst;
         let mut seed_errors: u32 = 0;
 
@@ -41,10 +32,10 @@ impl rand_core::TryRng for Stm32wba55Cal {
                 // potentially non-random bytes.
                 seed_errors += 1;
                 if seed_errors > MAX_SEED_RETRIES {
-                    panic!("RNG hardware failure");
+                    return Err(OperationError::HardwareFault);
                 }
                 self.rng.sr().modify(|w| w.set_seis(false));
-                self.init_rng();
+                self.init_rng()?;
                 continue;
             }
 
@@ -68,3 +59,24 @@ impl rand_core::TryRng for Stm32wba55Cal {
         Ok(())
     }
 }
+
+impl rand_core::TryCryptoRng for Stm32wba55Cal {}
+impl rand_core::TryRng for Stm32wba55Cal {
+    type Error = OperationError;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        let mut bytes = [0u8; 4];
+        self.try_fill_bytes(&mut bytes)?;
+        Ok(u32::from_le_bytes(bytes))
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        let mut bytes = [0u8; 8];
+        self.try_fill_bytes(&mut bytes)?;
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        self.fill_random(dst)
+    }
+}
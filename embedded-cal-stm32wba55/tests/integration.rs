@@ -24,13 +24,14 @@ mod tests {
     use embedded_cal_stm32wba55::Stm32wba55Cal;
     #[init]
     fn init() -> super::TestState {
-        let base = embedded_cal_stm32wba55::Stm32wba55Cal::new(
+        let base = embedded_cal_stm32wba55::Stm32wba55Cal::try_new(
             stm32_metapac::HASH,
             stm32_metapac::RCC,
             stm32_metapac::RNG,
             stm32_metapac::AES,
             stm32_metapac::PKA,
-        );
+        )
+        .expect("RNG did not start up");
 
         let cal = embedded_cal_software_demo::Extender::<ImplementSha256Short>::new(base);
         super::TestState { cal }
@@ -79,5 +80,16 @@ mod tests {
         for v in testvectors::dh::RFC5903_P256 {
             v.test_with(state.cal.dh());
         }
+        for v in testvectors::dh::P256_FORMATS {
+            v.test_with(state.cal.dh());
+        }
+        testvectors::dh::test_public_key_format_errors(state.cal.dh());
+    }
+
+    #[test]
+    fn test_der(state: &mut super::TestState) {
+        for v in testvectors::der::RFC5903_P256 {
+            v.test_with(&mut state.cal);
+        }
     }
 }
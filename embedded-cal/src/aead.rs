@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
 
+use crate::{OperationError, TryError};
+
 // FIXME: Document that we don't do variable length tags (or more precisely, overhead of encryption
 // like in plain AES), and that we expect the tag to be separate (although we could consider
 // changing interfaces if it turns out that everyone appends the tag to the ciphertext anyway, to
@@ -21,38 +23,51 @@ pub trait AeadProvider {
 
     /// Loads a key from the key's bytes.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// … if key's length is not `alg.key_length()`.
-    fn load_from_keydata(&mut self, alg: Self::Algorithm, key: &[u8]) -> Self::Key;
+    /// … are [`OperationError::LengthMismatch`] if key's length is not `alg.key_length()`.
+    fn try_load_from_keydata(
+        &mut self,
+        alg: Self::Algorithm,
+        key: &[u8],
+    ) -> Result<Self::Key, OperationError>;
 
     /// Encrypts data in place.
     ///
     /// The AEAD tag is returned separately; depending on the higher-layer protocol it is appended
     /// to the message or gets sent separately.
     ///
-    /// # Panics
+    /// # Errors
+    ///
+    /// … are [`OperationError::LengthMismatch`] if nonce's length is not `alg.nonce_length()` of
+    /// the algorithm that generated the key, and [`OperationError::ResourceExhausted`] if the
+    /// implementation has limited space for gathering the AAD (or the message) and that was
+    /// exceeded.
     ///
-    /// … if nonce's length is not `alg.nonce_length()` of the algorithm that generated the key.
+    /// When an [`OperationError`] is returned, `message` is left unmodified, so that the operation
+    /// can be retried on it (e.g. with a different implementation). Implementations that can only
+    /// detect an error after writing to `message` need to restore it.
     // Potential for enhancement: Create a key-and-nonce type that moves the nonce length check
     // from encryption time to preparation time?
-    fn encrypt_in_place(
+    fn try_encrypt_in_place(
         &mut self,
         key: &Self::Key,
         nonce: &[u8],
         message: &mut [u8],
         aad: impl AadGenerator,
-    ) -> Self::Tag;
+    ) -> Result<Self::Tag, OperationError>;
 
     /// Decrypts data in place.
     ///
     /// The AEAD tag is returned separately; depending on the higher-layer protocol it is appended
     /// to the message or gets sent separately.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// … if nonce's length is not `alg.nonce_length()` of the algorithm that generated the key, or
-    /// the tag's length is not `alg.tag_length()`.
+    /// … are [`TryError::Failed`] if the tag does not match, and [`TryError::Operation`] in the
+    /// same cases as for [`.try_encrypt_in_place()`][Self::try_encrypt_in_place()], as well as
+    /// with [`OperationError::LengthMismatch`] if tag's length is not `alg.tag_length()`. As with
+    /// encryption, `message` is left unmodified when a [`TryError::Operation`] is returned.
     ///
     /// # Implementation guidance
     ///
@@ -61,6 +76,52 @@ pub trait AeadProvider {
     /// is handled badly, an attacker can not hope to place crafted content in a place that might
     /// be mistaken for verified data.
     #[must_use = "message must not be accessed after a failed decryption"]
+    fn try_decrypt_in_place(
+        &mut self,
+        key: &Self::Key,
+        nonce: &[u8],
+        message: &mut [u8],
+        tag: &[u8],
+        aad: impl AadGenerator,
+    ) -> Result<(), TryError<DecryptionFailed>>;
+
+    /// Loads a key from the key's bytes.
+    ///
+    /// # Panics
+    ///
+    /// … if key's length is not `alg.key_length()`; see
+    /// [`.try_load_from_keydata()`][Self::try_load_from_keydata()].
+    fn load_from_keydata(&mut self, alg: Self::Algorithm, key: &[u8]) -> Self::Key {
+        self.try_load_from_keydata(alg, key)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Encrypts data in place.
+    ///
+    /// # Panics
+    ///
+    /// … if nonce's length is not `alg.nonce_length()` of the algorithm that generated the key, or
+    /// in any other error case of [`.try_encrypt_in_place()`][Self::try_encrypt_in_place()].
+    fn encrypt_in_place(
+        &mut self,
+        key: &Self::Key,
+        nonce: &[u8],
+        message: &mut [u8],
+        aad: impl AadGenerator,
+    ) -> Self::Tag {
+        self.try_encrypt_in_place(key, nonce, message, aad)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Decrypts data in place.
+    ///
+    /// # Panics
+    ///
+    /// … if nonce's length is not `alg.nonce_length()` of the algorithm that generated the key, or
+    /// the tag's length is not `alg.tag_length()`, or in any other case in which
+    /// [`.try_decrypt_in_place()`][Self::try_decrypt_in_place()] reports an
+    /// [`OperationError`].
+    #[must_use = "message must not be accessed after a failed decryption"]
     fn decrypt_in_place(
         &mut self,
         key: &Self::Key,
@@ -68,7 +129,10 @@ pub trait AeadProvider {
         message: &mut [u8],
         tag: &[u8],
         aad: impl AadGenerator,
-    ) -> Result<(), DecryptionFailed>;
+    ) -> Result<(), DecryptionFailed> {
+        self.try_decrypt_in_place(key, nonce, message, tag, aad)
+            .map_err(TryError::unwrap_failed)
+    }
 }
 
 /// Error indicating that an AEAD decryption failed.
@@ -108,6 +172,57 @@ pub trait AeadAlgorithm: Sized + PartialEq + Eq + core::fmt::Debug + Clone {
     fn from_cose_number(number: impl Into<i128>) -> Option<Self> {
         None
     }
+
+    /// Selects the AEAD algorithm of a TLS 1.3 cipher suite.
+    ///
+    /// The cipher suite comes from the ["TLS Cipher Suites"
+    /// registry](https://www.iana.org/assignments/tls-parameters/tls-parameters.xhtml#tls-parameters-4)
+    /// maintained by IANA (e.g. 0x1301 for `TLS_AES_128_GCM_SHA256`). Only the AEAD part of the
+    /// suite is selected; its hash is selected through
+    /// [`HashAlgorithm::from_tls_hash_algorithm()`][crate::HashAlgorithm::from_tls_hash_algorithm()].
+    #[inline]
+    #[allow(
+        unused_variables,
+        reason = "Argument names are part of the documentation"
+    )]
+    fn from_tls_cipher_suite(suite: u16) -> Option<Self> {
+        None
+    }
+
+    /// Lists all algorithms supported by the implementation, as selected by
+    /// [`Self::from_cose_number()`].
+    ///
+    /// See [`HashAlgorithm::all()`][crate::HashAlgorithm::all()] for how implementations that
+    /// extend a base compose the list.
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        core::iter::empty()
+    }
+
+    /// The algorithm's number in the COSE Algorithms registry, if it has one; the inverse of
+    /// [`Self::from_cose_number()`].
+    ///
+    /// This is the value to put into the `alg` header parameter when emitting COSE messages.
+    #[inline]
+    fn cose_number(&self) -> Option<i128> {
+        None
+    }
+
+    /// The algorithm's Suite ID in the Named Information Hash Algorithm Registry, if it has one.
+    ///
+    /// That registry (RFC 6920) only names hash algorithms, so no AEAD algorithm has an entry;
+    /// this exists for symmetry with [`HashAlgorithm::ni_id()`][crate::HashAlgorithm::ni_id()].
+    #[inline]
+    fn ni_id(&self) -> Option<u8> {
+        None
+    }
+
+    /// The algorithm's Hash Name String in the Named Information Hash Algorithm Registry, if it
+    /// has one; like [`Self::ni_id()`], this is `None` for all AEAD algorithms.
+    #[inline]
+    fn ni_name(&self) -> Option<&'static str> {
+        None
+    }
 }
 
 /// Tool for providing the AAD (Additional Authenticated Data) in a scatter-gather fashion.
@@ -148,5 +263,15 @@ pub fn test_aead_algorithm_aesccm_16_64_128<AP: AeadProvider>() {
     let cose_10 = AP::Algorithm::from_cose_number(10i8).expect(
         "test for type claiming AES-CCM-16-64-128 compatibility did not recognize COSE number 10",
     );
-    assert_eq!(cose_10.tag_length(), 8)
+    assert_eq!(cose_10.tag_length(), 8);
+    assert_eq!(cose_10.cose_number(), Some(10));
+}
+
+pub fn test_aead_algorithm_aesgcm_128<AP: AeadProvider>() {
+    let tls_1301 = AP::Algorithm::from_tls_cipher_suite(0x1301).expect(
+        "test for type claiming AES-128-GCM compatibility did not recognize TLS_AES_128_GCM_SHA256",
+    );
+    assert_eq!(tls_1301.key_length(), 16);
+    assert_eq!(tls_1301.nonce_length(), 12);
+    assert_eq!(tls_1301.tag_length(), 16);
 }
@@ -0,0 +1,601 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+//! Asynchronous counterparts of the providers.
+//!
+//! Back-ends that drive hardware through DMA or interrupts can implement these traits to suspend
+//! the calling task while an operation (e.g. a long ECDH multiplication) is running, rather than
+//! busy-waiting and blocking the executor.
+//!
+//! The traits mirror their synchronous counterparts: The `try_` methods are required, and the
+//! others are provided wrappers that panic on [`OperationError`]. Operations that merely convert
+//! data (e.g. [`AsyncDhProvider::export_publickey_bytes()`]) are not expected to wait for hardware,
+//! and stay synchronous.
+//!
+//! Any synchronous [`Cal`] can be used through the async interfaces by wrapping it in
+//! [`Blocking`]; its futures complete on the first poll:
+//!
+//! ```
+//! # use embedded_cal::{Cal, asynch::{AsyncCal, AsyncHashProvider, Blocking}};
+//! async fn hash_hello<C: AsyncCal>(
+//!     cal: &mut C,
+//!     alg: <C::HashProvider as AsyncHashProvider>::Algorithm,
+//! ) {
+//!     let _result = cal.hash().hash(alg, b"hello").await;
+//! }
+//!
+//! async fn hash_hello_sync<C: Cal>(cal: C, alg: embedded_cal::accessor::HashAlgorithmOf<C>) {
+//!     hash_hello(&mut Blocking(cal), alg).await;
+//! }
+//! ```
+//!
+//! Like their synchronous counterparts, operations do not borrow the provider across calls, so
+//! the operation states can be kept while other tasks use the same provider. Dropping a future
+//! before it completes cancels the operation; implementations need to leave their hardware in a
+//! state in which the next operation can start.
+#![allow(
+    async_fn_in_trait,
+    reason = "The traits are intended for single-threaded executors; Send bounds would exclude implementations that hold peripherals."
+)]
+
+use crate::accessor::*;
+// The synchronous traits are only used for forwarding in Blocking.
+use crate::{
+    AadGenerator, AeadAlgorithm, Cal, DecryptionFailed, DhAlgorithm, HashAlgorithm, HmacAlgorithm,
+    ImportError, IncompatibleKeys, OperationError, PublicKeyFormat, TryError, UnsupportedFormat,
+    VerificationFailed,
+};
+use crate::{AeadProvider as _, DhProvider as _, HashProvider as _, HmacProvider as _};
+
+/// Asynchronous counterpart of [`Cal`].
+pub trait AsyncCal {
+    type DhProvider: AsyncDhProvider;
+    type AeadProvider: AsyncAeadProvider;
+    type HashProvider: AsyncHashProvider;
+    type HmacProvider: AsyncHmacProvider;
+
+    fn dh(&mut self) -> &mut Self::DhProvider;
+    fn aead(&mut self) -> &mut Self::AeadProvider;
+    fn hash(&mut self) -> &mut Self::HashProvider;
+    fn hmac(&mut self) -> &mut Self::HmacProvider;
+}
+
+/// Asynchronous counterpart of [`HashProvider`][crate::HashProvider].
+pub trait AsyncHashProvider {
+    type Algorithm: HashAlgorithm;
+    /// State carried between rounds of feeding data; see
+    /// [`HashProvider::State`][crate::HashProvider::State].
+    type State: Sized + Clone;
+    type Output: AsRef<[u8]>;
+
+    async fn try_init(&mut self, algorithm: Self::Algorithm)
+    -> Result<Self::State, OperationError>;
+    async fn try_update(
+        &mut self,
+        instance: &mut Self::State,
+        data: &[u8],
+    ) -> Result<(), OperationError>;
+    async fn try_finalize(&mut self, instance: Self::State)
+    -> Result<Self::Output, OperationError>;
+
+    /// Hash contiguous in-memory data in a single pass; see
+    /// [`HashProvider::try_hash()`][crate::HashProvider::try_hash()].
+    async fn try_hash(
+        &mut self,
+        algorithm: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::Output, OperationError> {
+        let mut state = self.try_init(algorithm).await?;
+        self.try_update(&mut state, data).await?;
+        self.try_finalize(state).await
+    }
+
+    async fn init(&mut self, algorithm: Self::Algorithm) -> Self::State {
+        self.try_init(algorithm)
+            .await
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    async fn update(&mut self, instance: &mut Self::State, data: &[u8]) {
+        self.try_update(instance, data)
+            .await
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    async fn finalize(&mut self, instance: Self::State) -> Self::Output {
+        self.try_finalize(instance)
+            .await
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    async fn hash(&mut self, algorithm: Self::Algorithm, data: &[u8]) -> Self::Output {
+        self.try_hash(algorithm, data)
+            .await
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+/// Asynchronous counterpart of [`HmacProvider`][crate::HmacProvider].
+pub trait AsyncHmacProvider {
+    type Algorithm: HmacAlgorithm;
+    type Key: Clone + Sized;
+    type State: Sized;
+    type Output: AsRef<[u8]>;
+
+    async fn try_load_from_keydata(
+        &mut self,
+        algorithm: Self::Algorithm,
+        key: &[u8],
+    ) -> Result<Self::Key, OperationError>;
+    async fn try_init(&mut self, key: Self::Key) -> Result<Self::State, OperationError>;
+    async fn try_update(
+        &mut self,
+        state: &mut Self::State,
+        data: &[u8],
+    ) -> Result<(), OperationError>;
+    async fn try_finalize(&mut self, state: Self::State) -> Result<Self::Output, OperationError>;
+
+    async fn load_from_keydata(&mut self, algorithm: Self::Algorithm, key: &[u8]) -> Self::Key {
+        self.try_load_from_keydata(algorithm, key)
+            .await
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    async fn init(&mut self, key: Self::Key) -> Self::State {
+        self.try_init(key).await.unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    async fn update(&mut self, state: &mut Self::State, data: &[u8]) {
+        self.try_update(state, data)
+            .await
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    async fn finalize(&mut self, state: Self::State) -> Self::Output {
+        self.try_finalize(state)
+            .await
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Computes the HMAC of contiguous data with a key entered as raw bytes.
+    async fn hmac_with_keydata(
+        &mut self,
+        algorithm: Self::Algorithm,
+        key: &[u8],
+        data: &[u8],
+    ) -> Self::Output {
+        let key = self.load_from_keydata(algorithm, key).await;
+        let mut state = self.init(key).await;
+        self.update(&mut state, data).await;
+        self.finalize(state).await
+    }
+
+    /// Finishes an HMAC operation, and checks its output against an expected (possibly
+    /// truncated) tag; see [`HmacProvider::try_verify()`][crate::HmacProvider::try_verify()].
+    async fn try_verify(
+        &mut self,
+        state: Self::State,
+        expected: &[u8],
+    ) -> Result<(), TryError<VerificationFailed>> {
+        let output = self.try_finalize(state).await?;
+        crate::verify_hmac_tag(output.as_ref(), expected).map_err(TryError::Failed)
+    }
+
+    async fn verify(
+        &mut self,
+        state: Self::State,
+        expected: &[u8],
+    ) -> Result<(), VerificationFailed> {
+        self.try_verify(state, expected)
+            .await
+            .map_err(TryError::unwrap_failed)
+    }
+
+    /// Checks the HMAC of contiguous data with a key entered as raw bytes.
+    async fn verify_with_keydata(
+        &mut self,
+        algorithm: Self::Algorithm,
+        key: &[u8],
+        data: &[u8],
+        expected: &[u8],
+    ) -> Result<(), VerificationFailed> {
+        let key = self.load_from_keydata(algorithm, key).await;
+        let mut state = self.init(key).await;
+        self.update(&mut state, data).await;
+        self.verify(state, expected).await
+    }
+}
+
+/// Asynchronous counterpart of [`AeadProvider`][crate::AeadProvider].
+pub trait AsyncAeadProvider {
+    type Algorithm: AeadAlgorithm;
+    type Key: Sized;
+    type Tag: AsRef<[u8]>;
+
+    async fn try_load_from_keydata(
+        &mut self,
+        alg: Self::Algorithm,
+        key: &[u8],
+    ) -> Result<Self::Key, OperationError>;
+
+    /// Encrypts data in place; see
+    /// [`AeadProvider::try_encrypt_in_place()`][crate::AeadProvider::try_encrypt_in_place()].
+    async fn try_encrypt_in_place(
+        &mut self,
+        key: &Self::Key,
+        nonce: &[u8],
+        message: &mut [u8],
+        aad: impl AadGenerator,
+    ) -> Result<Self::Tag, OperationError>;
+
+    /// Decrypts data in place; see
+    /// [`AeadProvider::try_decrypt_in_place()`][crate::AeadProvider::try_decrypt_in_place()].
+    #[must_use = "message must not be accessed after a failed decryption"]
+    async fn try_decrypt_in_place(
+        &mut self,
+        key: &Self::Key,
+        nonce: &[u8],
+        message: &mut [u8],
+        tag: &[u8],
+        aad: impl AadGenerator,
+    ) -> Result<(), TryError<DecryptionFailed>>;
+
+    async fn load_from_keydata(&mut self, alg: Self::Algorithm, key: &[u8]) -> Self::Key {
+        self.try_load_from_keydata(alg, key)
+            .await
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    async fn encrypt_in_place(
+        &mut self,
+        key: &Self::Key,
+        nonce: &[u8],
+        message: &mut [u8],
+        aad: impl AadGenerator,
+    ) -> Self::Tag {
+        self.try_encrypt_in_place(key, nonce, message, aad)
+            .await
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    #[must_use = "message must not be accessed after a failed decryption"]
+    async fn decrypt_in_place(
+        &mut self,
+        key: &Self::Key,
+        nonce: &[u8],
+        message: &mut [u8],
+        tag: &[u8],
+        aad: impl AadGenerator,
+    ) -> Result<(), DecryptionFailed> {
+        self.try_decrypt_in_place(key, nonce, message, tag, aad)
+            .await
+            .map_err(TryError::unwrap_failed)
+    }
+}
+
+/// Asynchronous counterpart of [`DhProvider`][crate::DhProvider].
+pub trait AsyncDhProvider {
+    type Algorithm: DhAlgorithm;
+    type VisibleSecretKey: Sized + Into<Self::SecretKey>;
+    type SecretKey: Sized;
+    type PublicKey: Sized;
+    type SharedSecret: Sized;
+
+    async fn try_generate_visible(
+        &mut self,
+        alg: Self::Algorithm,
+    ) -> Result<Self::VisibleSecretKey, OperationError>;
+
+    async fn try_generate(
+        &mut self,
+        alg: Self::Algorithm,
+    ) -> Result<Self::SecretKey, OperationError> {
+        self.try_generate_visible(alg).await.map(Into::into)
+    }
+
+    async fn try_shared_secret(
+        &mut self,
+        private: &Self::SecretKey,
+        public: &Self::PublicKey,
+    ) -> Result<Self::SharedSecret, TryError<IncompatibleKeys>>;
+
+    async fn try_public_key(
+        &mut self,
+        private: &Self::SecretKey,
+    ) -> Result<Self::PublicKey, OperationError>;
+
+    async fn generate_visible(&mut self, alg: Self::Algorithm) -> Self::VisibleSecretKey {
+        self.try_generate_visible(alg)
+            .await
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    async fn generate(&mut self, alg: Self::Algorithm) -> Self::SecretKey {
+        self.try_generate(alg)
+            .await
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    async fn shared_secret(
+        &mut self,
+        private: &Self::SecretKey,
+        public: &Self::PublicKey,
+    ) -> Result<Self::SharedSecret, IncompatibleKeys> {
+        self.try_shared_secret(private, public)
+            .await
+            .map_err(TryError::unwrap_failed)
+    }
+
+    async fn public_key(&mut self, private: &Self::SecretKey) -> Self::PublicKey {
+        self.try_public_key(private)
+            .await
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn export_secretkey_bytes<'s>(
+        &mut self,
+        secretkey: &'s Self::VisibleSecretKey,
+    ) -> impl AsRef<[u8]> + use<'s, Self>;
+    fn import_secretkey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        secret: &[u8],
+    ) -> Result<Self::VisibleSecretKey, ImportError>;
+    fn export_publickey_bytes<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+    ) -> impl AsRef<[u8]> + use<'p, Self>;
+    fn import_publickey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::PublicKey, ImportError>;
+    fn export_publickey<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+        format: PublicKeyFormat,
+    ) -> Result<impl AsRef<[u8]> + use<'p, Self>, UnsupportedFormat>;
+    fn import_publickey(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+        format: PublicKeyFormat,
+    ) -> Result<Self::PublicKey, ImportError>;
+    fn raw_secret_bytes<'s>(
+        &mut self,
+        secret: &'s Self::SharedSecret,
+    ) -> impl AsRef<[u8]> + use<'s, Self>;
+}
+
+/// Adapter that makes a synchronous [`Cal`] usable as an [`AsyncCal`].
+///
+/// All operations run to completion when their future is first polled, blocking the executor for
+/// that time. This is suitable for software implementations and for hardware that completes
+/// quickly; implementations that wait for hardware for long should implement the async traits
+/// natively.
+///
+/// The wrapped Cal remains accessible through the public field, e.g. for the providers that have
+/// no async counterpart yet.
+pub struct Blocking<C>(pub C);
+
+impl<C: Cal> AsyncCal for Blocking<C> {
+    type DhProvider = Self;
+    type AeadProvider = Self;
+    type HashProvider = Self;
+    type HmacProvider = Self;
+
+    fn dh(&mut self) -> &mut Self::DhProvider {
+        self
+    }
+    fn aead(&mut self) -> &mut Self::AeadProvider {
+        self
+    }
+    fn hash(&mut self) -> &mut Self::HashProvider {
+        self
+    }
+    fn hmac(&mut self) -> &mut Self::HmacProvider {
+        self
+    }
+}
+
+impl<C: Cal> AsyncHashProvider for Blocking<C> {
+    type Algorithm = HashAlgorithmOf<C>;
+    type State = HashStateOf<C>;
+    type Output = HashOutputOf<C>;
+
+    async fn try_init(
+        &mut self,
+        algorithm: Self::Algorithm,
+    ) -> Result<Self::State, OperationError> {
+        self.0.hash().try_init(algorithm)
+    }
+
+    async fn try_update(
+        &mut self,
+        instance: &mut Self::State,
+        data: &[u8],
+    ) -> Result<(), OperationError> {
+        self.0.hash().try_update(instance, data)
+    }
+
+    async fn try_finalize(
+        &mut self,
+        instance: Self::State,
+    ) -> Result<Self::Output, OperationError> {
+        self.0.hash().try_finalize(instance)
+    }
+
+    async fn try_hash(
+        &mut self,
+        algorithm: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::Output, OperationError> {
+        self.0.hash().try_hash(algorithm, data)
+    }
+}
+
+impl<C: Cal> AsyncHmacProvider for Blocking<C> {
+    type Algorithm = HmacAlgorithmOf<C>;
+    type Key = HmacKeyOf<C>;
+    type State = HmacStateOf<C>;
+    type Output = HmacOutputOf<C>;
+
+    async fn try_load_from_keydata(
+        &mut self,
+        algorithm: Self::Algorithm,
+        key: &[u8],
+    ) -> Result<Self::Key, OperationError> {
+        self.0.hmac().try_load_from_keydata(algorithm, key)
+    }
+
+    async fn try_init(&mut self, key: Self::Key) -> Result<Self::State, OperationError> {
+        self.0.hmac().try_init(key)
+    }
+
+    async fn try_update(
+        &mut self,
+        state: &mut Self::State,
+        data: &[u8],
+    ) -> Result<(), OperationError> {
+        self.0.hmac().try_update(state, data)
+    }
+
+    async fn try_finalize(&mut self, state: Self::State) -> Result<Self::Output, OperationError> {
+        self.0.hmac().try_finalize(state)
+    }
+
+    async fn try_verify(
+        &mut self,
+        state: Self::State,
+        expected: &[u8],
+    ) -> Result<(), TryError<VerificationFailed>> {
+        self.0.hmac().try_verify(state, expected)
+    }
+}
+
+impl<C: Cal> AsyncAeadProvider for Blocking<C> {
+    type Algorithm = AeadAlgorithmOf<C>;
+    type Key = AeadKeyOf<C>;
+    type Tag = AeadTagOf<C>;
+
+    async fn try_load_from_keydata(
+        &mut self,
+        alg: Self::Algorithm,
+        key: &[u8],
+    ) -> Result<Self::Key, OperationError> {
+        self.0.aead().try_load_from_keydata(alg, key)
+    }
+
+    async fn try_encrypt_in_place(
+        &mut self,
+        key: &Self::Key,
+        nonce: &[u8],
+        message: &mut [u8],
+        aad: impl AadGenerator,
+    ) -> Result<Self::Tag, OperationError> {
+        self.0.aead().try_encrypt_in_place(key, nonce, message, aad)
+    }
+
+    async fn try_decrypt_in_place(
+        &mut self,
+        key: &Self::Key,
+        nonce: &[u8],
+        message: &mut [u8],
+        tag: &[u8],
+        aad: impl AadGenerator,
+    ) -> Result<(), TryError<DecryptionFailed>> {
+        self.0
+            .aead()
+            .try_decrypt_in_place(key, nonce, message, tag, aad)
+    }
+}
+
+impl<C: Cal> AsyncDhProvider for Blocking<C> {
+    type Algorithm = DhAlgorithmOf<C>;
+    type VisibleSecretKey = DhVisibleSecretKeyOf<C>;
+    type SecretKey = DhSecretKeyOf<C>;
+    type PublicKey = DhPublicKeyOf<C>;
+    type SharedSecret = DhSharedSecretOf<C>;
+
+    async fn try_generate_visible(
+        &mut self,
+        alg: Self::Algorithm,
+    ) -> Result<Self::VisibleSecretKey, OperationError> {
+        self.0.dh().try_generate_visible(alg)
+    }
+
+    async fn try_generate(
+        &mut self,
+        alg: Self::Algorithm,
+    ) -> Result<Self::SecretKey, OperationError> {
+        self.0.dh().try_generate(alg)
+    }
+
+    async fn try_shared_secret(
+        &mut self,
+        private: &Self::SecretKey,
+        public: &Self::PublicKey,
+    ) -> Result<Self::SharedSecret, TryError<IncompatibleKeys>> {
+        self.0.dh().try_shared_secret(private, public)
+    }
+
+    async fn try_public_key(
+        &mut self,
+        private: &Self::SecretKey,
+    ) -> Result<Self::PublicKey, OperationError> {
+        self.0.dh().try_public_key(private)
+    }
+
+    fn export_secretkey_bytes<'s>(
+        &mut self,
+        secretkey: &'s Self::VisibleSecretKey,
+    ) -> impl AsRef<[u8]> + use<'s, C> {
+        self.0.dh().export_secretkey_bytes(secretkey)
+    }
+
+    fn import_secretkey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        secret: &[u8],
+    ) -> Result<Self::VisibleSecretKey, ImportError> {
+        self.0.dh().import_secretkey_bytes(alg, secret)
+    }
+
+    fn export_publickey_bytes<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+    ) -> impl AsRef<[u8]> + use<'p, C> {
+        self.0.dh().export_publickey_bytes(public)
+    }
+
+    fn import_publickey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::PublicKey, ImportError> {
+        self.0.dh().import_publickey_bytes(alg, data)
+    }
+
+    fn export_publickey<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+        format: PublicKeyFormat,
+    ) -> Result<impl AsRef<[u8]> + use<'p, C>, UnsupportedFormat> {
+        self.0.dh().export_publickey(public, format)
+    }
+
+    fn import_publickey(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+        format: PublicKeyFormat,
+    ) -> Result<Self::PublicKey, ImportError> {
+        self.0.dh().import_publickey(alg, data, format)
+    }
+
+    fn raw_secret_bytes<'s>(
+        &mut self,
+        secret: &'s Self::SharedSecret,
+    ) -> impl AsRef<[u8]> + use<'s, C> {
+        self.0.dh().raw_secret_bytes(secret)
+    }
+}
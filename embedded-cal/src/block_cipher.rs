@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+/// Size of a block in bytes.
+///
+/// All block ciphers currently expressible through [`BlockCipherProvider`] (i.e. AES) have 128 bit
+/// blocks.
+pub const BLOCK_SIZE: usize = 16;
+
+/// Low-level access to a block cipher.
+///
+/// This is the raw permutation (sometimes called ECB mode when applied to several blocks), and is
+/// not secure encryption on its own: It is intended for building protocol specific constructions
+/// such as BLE's `e` function or the key derivation of LoRaWAN and IEEE 802.15.4. For encrypting
+/// data, use an [`AeadProvider`][crate::AeadProvider].
+///
+/// This trait is modelled after the
+/// [`cipher::BlockEncrypt`](https://docs.rs/cipher/latest/cipher/trait.BlockEncrypt.html) and
+/// [`cipher::BlockDecrypt`](https://docs.rs/cipher/latest/cipher/trait.BlockDecrypt.html) traits,
+/// but does not use them directly because
+/// - `embedded-cal` passes around an exclusive reference to its engine, and
+/// - its operation is cryptographically agile rather than monomorphized over algorithms.
+pub trait BlockCipherProvider {
+    type Algorithm: BlockCipherAlgorithm;
+    type Key: Sized;
+
+    /// Loads a key from the key's bytes.
+    ///
+    /// # Panics
+    ///
+    /// … if key's length is not `alg.key_length()`.
+    fn load_from_keydata(&mut self, alg: Self::Algorithm, key: &[u8]) -> Self::Key;
+
+    /// Encrypts a single block in place.
+    fn encrypt_block(&mut self, key: &Self::Key, block: &mut [u8; BLOCK_SIZE]);
+
+    /// Decrypts a single block in place.
+    fn decrypt_block(&mut self, key: &Self::Key, block: &mut [u8; BLOCK_SIZE]);
+
+    /// Encrypts any number of blocks in place, each independently of the others.
+    ///
+    /// This is equivalent to calling [`.encrypt_block()`][Self::encrypt_block()] on each block,
+    /// but allows implementations to process blocks in parallel or to keep the key loaded in
+    /// hardware.
+    fn encrypt_blocks(&mut self, key: &Self::Key, blocks: &mut [[u8; BLOCK_SIZE]]) {
+        for block in blocks {
+            self.encrypt_block(key, block);
+        }
+    }
+
+    /// Decrypts any number of blocks in place, each independently of the others.
+    ///
+    /// See [`.encrypt_blocks()`][Self::encrypt_blocks()].
+    fn decrypt_blocks(&mut self, key: &Self::Key, blocks: &mut [[u8; BLOCK_SIZE]]) {
+        for block in blocks {
+            self.decrypt_block(key, block);
+        }
+    }
+}
+
+/// A block cipher identifier.
+///
+/// This encodes the full set of parameters, i.e. for AES the key length.
+pub trait BlockCipherAlgorithm: Sized + PartialEq + Eq + core::fmt::Debug + Clone {
+    /// Length of a key in bytes.
+    fn key_length(&self) -> usize;
+
+    /// Selects AES with the given key length in bytes.
+    ///
+    /// There is no registry of block ciphers in COSE, as COSE only uses them as part of complete
+    /// constructions (such as AES-CCM or AES Key Wrap).
+    ///
+    /// Implementations are expected to return `Some` for 16 (AES-128), 24 (AES-192) or 32
+    /// (AES-256) if they support that variant.
+    #[inline]
+    #[allow(
+        unused_variables,
+        reason = "Argument names are part of the documentation"
+    )]
+    fn from_aes_key_length(length: usize) -> Option<Self> {
+        None
+    }
+
+    /// Lists all algorithms supported by the implementation, as selected by
+    /// [`Self::from_aes_key_length()`].
+    ///
+    /// See [`HashAlgorithm::all()`][crate::HashAlgorithm::all()] for how implementations that
+    /// extend a base compose the list.
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        core::iter::empty()
+    }
+}
+
+pub fn test_block_cipher_algorithm_aes128<BP: BlockCipherProvider>() {
+    let aes128 = BP::Algorithm::from_aes_key_length(16)
+        .expect("test for type claiming AES-128 compatibility did not recognize key length 16");
+    assert_eq!(aes128.key_length(), 16)
+}
@@ -0,0 +1,528 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+//! COSE_Key encoding and decoding ([RFC9052 Section 7](https://datatracker.ietf.org/doc/html/rfc9052#section-7))
+//! for the keys of any [`DhProvider`].
+//!
+//! A [`CoseKey`] is a decoded COSE_Key of key type OKP or EC2 ([RFC9053 Section
+//! 7](https://datatracker.ietf.org/doc/html/rfc9053#section-7)) that borrows its byte strings from
+//! the encoded data. It is turned into keys of a `DhProvider` by [`CoseKey::import_public_key()`]
+//! and [`CoseKey::import_secret_key()`]; [`encode_public_key()`] and [`encode_secret_key()`] go
+//! the other way. The key's curve selects the algorithm through
+//! [`DhAlgorithm::from_cose_ecdh()`] and [`DhAlgorithm::cose_ecdh()`], so this works for any curve
+//! the provider supports, as long as its keys are exported in the format COSE uses for `x` and `d`.
+//!
+//! Nothing here allocates: Decoding borrows from the input, and encoding writes into a buffer
+//! provided by the caller. Keys are encoded in the deterministic encoding of [RFC8949 Section
+//! 4.2.1](https://datatracker.ietf.org/doc/html/rfc8949#section-4.2.1) (which is also what EDHOC
+//! expects of credentials it builds from COSE_Key). Decoding accepts the parameters in any order,
+//! and skips parameters other than `kty`, `kid`, `crv`, `x`, `y` and `d` (e.g. `alg` or
+//! `key_ops`). It rejects duplicate parameters and indefinite-length items.
+//!
+//! # EC2 keys
+//!
+//! EC2 keys are exported in the uncompressed [`PublicKeyFormat`], and imported in the format
+//! that matches their `y` parameter: uncompressed if it is a coordinate, compressed if it is a
+//! sign bit. The provider checks that the point is on the curve. Keys that the provider only got
+//! in compact form are encoded with a `y` coordinate that may be the negation of the one the key
+//! was generated with (see the [`hpke`][crate::hpke] module for why that is still usable).
+
+use crate::{DhAlgorithm, DhProvider, PublicKeyFormat};
+
+const LABEL_KTY: i128 = 1;
+const LABEL_KID: i128 = 2;
+const LABEL_CRV: i128 = -1;
+const LABEL_X: i128 = -2;
+const LABEL_Y: i128 = -3;
+const LABEL_D: i128 = -4;
+
+/// Length of the longest SEC1 point built from `x` and `y` (an uncompressed P-521 point).
+const MAX_POINT_LEN: usize = 1 + 2 * 66;
+
+/// Nesting depth up to which values of skipped parameters are decoded.
+const MAX_DEPTH: usize = 8;
+
+/// Errors from encoding, decoding and importing COSE_Key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoseKeyError {
+    /// The data is not a well-formed COSE_Key: It is not a CBOR map, parameters are missing,
+    /// duplicated or of the wrong type, or there is data after the map.
+    Malformed,
+    /// The key type or curve is not supported by this module or by the [`DhProvider`].
+    Unsupported,
+    /// The key data is not valid for the curve (e.g. it has the wrong length, is not on the curve,
+    /// or `x` does not belong to `d`).
+    InvalidKey,
+    /// The output buffer is too small for the encoded key.
+    BufferTooSmall,
+}
+
+impl core::fmt::Display for CoseKeyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            CoseKeyError::Malformed => "malformed COSE_Key",
+            CoseKeyError::Unsupported => "unsupported key type or curve",
+            CoseKeyError::InvalidKey => "key data not valid for curve",
+            CoseKeyError::BufferTooSmall => "buffer too small",
+        })
+    }
+}
+
+impl core::error::Error for CoseKeyError {}
+
+/// Key type (`kty`) of a [`CoseKey`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyType {
+    /// Octet Key Pair, used for X25519 and X448 (COSE key type 1).
+    Okp,
+    /// Elliptic curve key with x and y coordinate pair, used for the NIST curves (COSE key type
+    /// 2).
+    Ec2,
+}
+
+impl KeyType {
+    fn number(self) -> i128 {
+        match self {
+            KeyType::Okp => 1,
+            KeyType::Ec2 => 2,
+        }
+    }
+
+    /// The key type that the COSE Elliptic Curves registry lists for a curve.
+    pub fn of_curve(crv: i128) -> Option<Self> {
+        match crv {
+            1..=3 | 8 | 256..=258 => Some(KeyType::Ec2),
+            4..=7 => Some(KeyType::Okp),
+            _ => None,
+        }
+    }
+}
+
+/// The `y` parameter of an EC2 key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Y<'a> {
+    /// The full y coordinate.
+    Coordinate(&'a [u8]),
+    /// The sign bit of the y coordinate (point compression).
+    Sign(bool),
+}
+
+/// A COSE_Key of key type OKP or EC2.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoseKey<'a> {
+    pub kty: KeyType,
+    /// The curve from the ["COSE Elliptic Curves"
+    /// registry](https://www.iana.org/assignments/cose/cose.xhtml#elliptic-curves).
+    pub crv: i128,
+    /// The public key (OKP) or its x coordinate (EC2); optional for secret keys.
+    pub x: Option<&'a [u8]>,
+    /// The y coordinate of an EC2 public key; optional for secret keys.
+    pub y: Option<Y<'a>>,
+    /// The secret key.
+    pub d: Option<&'a [u8]>,
+    pub kid: Option<&'a [u8]>,
+}
+
+impl<'a> CoseKey<'a> {
+    /// Decodes a COSE_Key.
+    ///
+    /// Only `kty` and `crv` are required; whether `x`, `y` and `d` are present is checked when the
+    /// key is imported.
+    pub fn decode(data: &'a [u8]) -> Result<Self, CoseKeyError> {
+        fn set<T>(slot: &mut Option<T>, value: T) -> Result<(), CoseKeyError> {
+            match slot.replace(value) {
+                None => Ok(()),
+                Some(_) => Err(CoseKeyError::Malformed),
+            }
+        }
+
+        let mut decoder = Decoder { data };
+        let (5, entries) = decoder.head()? else {
+            return Err(CoseKeyError::Malformed);
+        };
+
+        let mut kty = None;
+        let mut crv = None;
+        let mut x = None;
+        let mut y = None;
+        let mut d = None;
+        let mut kid = None;
+        for _ in 0..entries {
+            let label = match decoder.data.first().map(|initial| initial >> 5) {
+                Some(0 | 1) => decoder.int()?,
+                // Parameters with text labels are not used by OKP and EC2 keys.
+                _ => {
+                    decoder.skip(MAX_DEPTH)?;
+                    decoder.skip(MAX_DEPTH)?;
+                    continue;
+                }
+            };
+            match label {
+                LABEL_KTY => set(&mut kty, decoder.int()?)?,
+                LABEL_KID => set(&mut kid, decoder.bstr()?)?,
+                LABEL_CRV => set(&mut crv, decoder.int()?)?,
+                LABEL_X => set(&mut x, decoder.bstr()?)?,
+                LABEL_Y => {
+                    let value = match decoder.data.first() {
+                        Some(0xf4) => Y::Sign(false),
+                        Some(0xf5) => Y::Sign(true),
+                        _ => Y::Coordinate(decoder.bstr()?),
+                    };
+                    if matches!(value, Y::Sign(_)) {
+                        decoder.data = &decoder.data[1..];
+                    }
+                    set(&mut y, value)?
+                }
+                LABEL_D => set(&mut d, decoder.bstr()?)?,
+                _ => decoder.skip(MAX_DEPTH)?,
+            }
+        }
+        if !decoder.data.is_empty() {
+            return Err(CoseKeyError::Malformed);
+        }
+
+        let kty = match kty.ok_or(CoseKeyError::Malformed)? {
+            1 => KeyType::Okp,
+            2 => KeyType::Ec2,
+            _ => return Err(CoseKeyError::Unsupported),
+        };
+        let crv = crv.ok_or(CoseKeyError::Malformed)?;
+        if KeyType::of_curve(crv).is_some_and(|expected| expected != kty)
+            || (kty == KeyType::Okp && y.is_some())
+        {
+            return Err(CoseKeyError::Malformed);
+        }
+
+        Ok(Self {
+            kty,
+            crv,
+            x,
+            y,
+            d,
+            kid,
+        })
+    }
+
+    /// Encodes the key into `buf`, returning the part of `buf` that was written to.
+    pub fn encode<'b>(&self, buf: &'b mut [u8]) -> Result<&'b [u8], CoseKeyError> {
+        let mut encoder = Encoder { buf, len: 0 };
+
+        let optional = [
+            self.kid.is_some(),
+            self.x.is_some(),
+            self.y.is_some(),
+            self.d.is_some(),
+        ];
+        let entries = 2 + optional.into_iter().filter(|present| *present).count();
+        encoder.head(5, entries as u64)?;
+
+        // Labels are in the order of their encoded bytes, as required for deterministic encoding.
+        encoder.int(LABEL_KTY)?;
+        encoder.int(self.kty.number())?;
+        if let Some(kid) = self.kid {
+            encoder.int(LABEL_KID)?;
+            encoder.bstr(kid)?;
+        }
+        encoder.int(LABEL_CRV)?;
+        encoder.int(self.crv)?;
+        if let Some(x) = self.x {
+            encoder.int(LABEL_X)?;
+            encoder.bstr(x)?;
+        }
+        match self.y {
+            Some(Y::Coordinate(y)) => {
+                encoder.int(LABEL_Y)?;
+                encoder.bstr(y)?;
+            }
+            Some(Y::Sign(sign)) => {
+                encoder.int(LABEL_Y)?;
+                encoder.bytes(&[if sign { 0xf5 } else { 0xf4 }])?;
+            }
+            None => (),
+        }
+        if let Some(d) = self.d {
+            encoder.int(LABEL_D)?;
+            encoder.bstr(d)?;
+        }
+
+        let Encoder { buf, len } = encoder;
+        let buf: &'b [u8] = buf;
+        Ok(&buf[..len])
+    }
+
+    /// Imports the public key.
+    ///
+    /// This requires `x`, and for EC2 keys also `y`.
+    pub fn import_public_key<D: DhProvider>(
+        &self,
+        dh: &mut D,
+    ) -> Result<(D::Algorithm, D::PublicKey), CoseKeyError> {
+        let alg = self.algorithm::<D::Algorithm>()?;
+        if self.kty == KeyType::Ec2 && self.y.is_none() {
+            return Err(CoseKeyError::Malformed);
+        }
+        let mut buf = [0; MAX_POINT_LEN];
+        let (point, format) = self.point(&mut buf)?;
+        let public = dh
+            .import_publickey(alg.clone(), point, format)
+            .map_err(|_| CoseKeyError::InvalidKey)?;
+        Ok((alg, public))
+    }
+
+    /// Imports the secret key.
+    ///
+    /// This requires `d`. If `x` (and `y`) are present, they are checked to be the public key
+    /// belonging to `d`.
+    pub fn import_secret_key<D: DhProvider>(
+        &self,
+        dh: &mut D,
+    ) -> Result<(D::Algorithm, D::VisibleSecretKey), CoseKeyError> {
+        let alg = self.algorithm::<D::Algorithm>()?;
+        let d = self.d.ok_or(CoseKeyError::Malformed)?;
+        let import = |dh: &mut D| {
+            dh.import_secretkey_bytes(alg.clone(), d)
+                .map_err(|_| CoseKeyError::InvalidKey)
+        };
+
+        if self.x.is_some() {
+            let mut buf = [0; MAX_POINT_LEN];
+            let (point, format) = self.point(&mut buf)?;
+            let private = import(dh)?.into();
+            let public = dh.public_key(&private);
+            let derived = dh
+                .export_publickey(&public, format)
+                .map_err(|_| CoseKeyError::InvalidKey)?;
+            if derived.as_ref() != point {
+                return Err(CoseKeyError::InvalidKey);
+            }
+        }
+
+        Ok((alg.clone(), import(dh)?))
+    }
+
+    fn algorithm<A: DhAlgorithm>(&self) -> Result<A, CoseKeyError> {
+        A::from_cose_ecdh(self.crv).ok_or(CoseKeyError::Unsupported)
+    }
+
+    /// Writes the public key into `buf` in the format that `y` calls for: SEC1 uncompressed or
+    /// compressed if it is a coordinate or a sign bit, and compact if it is absent.
+    fn point<'b>(
+        &self,
+        buf: &'b mut [u8; MAX_POINT_LEN],
+    ) -> Result<(&'b [u8], PublicKeyFormat), CoseKeyError> {
+        let x = self.x.ok_or(CoseKeyError::Malformed)?;
+        let (prefix, y, format): (&[u8], &[u8], _) = match self.y {
+            None => (&[], &[], PublicKeyFormat::Compact),
+            Some(Y::Sign(false)) => (&[0x02], &[], PublicKeyFormat::Compressed),
+            Some(Y::Sign(true)) => (&[0x03], &[], PublicKeyFormat::Compressed),
+            Some(Y::Coordinate(y)) if y.len() == x.len() => {
+                (&[0x04], y, PublicKeyFormat::Uncompressed)
+            }
+            Some(Y::Coordinate(_)) => return Err(CoseKeyError::InvalidKey),
+        };
+        let len = prefix.len() + x.len() + y.len();
+        let point = buf.get_mut(..len).ok_or(CoseKeyError::InvalidKey)?;
+        let (point_prefix, coordinates) = point.split_at_mut(prefix.len());
+        let (point_x, point_y) = coordinates.split_at_mut(x.len());
+        point_prefix.copy_from_slice(prefix);
+        point_x.copy_from_slice(x);
+        point_y.copy_from_slice(y);
+        Ok((point, format))
+    }
+}
+
+/// Encodes a public key as a COSE_Key into `buf`, returning the part of `buf` that was written to.
+///
+/// `alg` is the algorithm the key was created for.
+pub fn encode_public_key<'b, D: DhProvider>(
+    dh: &mut D,
+    alg: &D::Algorithm,
+    public: &D::PublicKey,
+    kid: Option<&[u8]>,
+    buf: &'b mut [u8],
+) -> Result<&'b [u8], CoseKeyError> {
+    encode_key(dh, alg, public, None, kid, buf)
+}
+
+/// Encodes a secret key as a COSE_Key into `buf`, returning the part of `buf` that was written to.
+///
+/// Along with `d`, the key's public part is encoded.
+pub fn encode_secret_key<'b, D: DhProvider>(
+    dh: &mut D,
+    alg: &D::Algorithm,
+    secret: &D::VisibleSecretKey,
+    kid: Option<&[u8]>,
+    buf: &'b mut [u8],
+) -> Result<&'b [u8], CoseKeyError> {
+    let d = dh.export_secretkey_bytes(secret);
+    let private = dh
+        .import_secretkey_bytes(alg.clone(), d.as_ref())
+        .map_err(|_| CoseKeyError::InvalidKey)?
+        .into();
+    let public = dh.public_key(&private);
+    encode_key(dh, alg, &public, Some(d.as_ref()), kid, buf)
+}
+
+fn encode_key<'b, D: DhProvider>(
+    dh: &mut D,
+    alg: &D::Algorithm,
+    public: &D::PublicKey,
+    d: Option<&[u8]>,
+    kid: Option<&[u8]>,
+    buf: &'b mut [u8],
+) -> Result<&'b [u8], CoseKeyError> {
+    let crv = alg.cose_ecdh().ok_or(CoseKeyError::Unsupported)?;
+    let kty = KeyType::of_curve(crv).ok_or(CoseKeyError::Unsupported)?;
+    let format = match kty {
+        KeyType::Okp => PublicKeyFormat::Compact,
+        KeyType::Ec2 => PublicKeyFormat::Uncompressed,
+    };
+    let point = dh
+        .export_publickey(public, format)
+        .map_err(|_| CoseKeyError::Unsupported)?;
+    let point = point.as_ref();
+    let (x, y) = match kty {
+        KeyType::Okp => (point, None),
+        KeyType::Ec2 => match point.split_first() {
+            Some((0x04, coordinates)) if coordinates.len() % 2 == 0 => {
+                let (x, y) = coordinates.split_at(coordinates.len() / 2);
+                (x, Some(Y::Coordinate(y)))
+            }
+            _ => return Err(CoseKeyError::InvalidKey),
+        },
+    };
+    CoseKey {
+        kty,
+        crv,
+        x: Some(x),
+        y,
+        d,
+        kid,
+    }
+    .encode(buf)
+}
+
+/// Reader for the few CBOR items that make up a COSE_Key.
+struct Decoder<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Decoder<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], CoseKeyError> {
+        if len > self.data.len() {
+            return Err(CoseKeyError::Malformed);
+        }
+        let (taken, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(taken)
+    }
+
+    /// Reads the head of an item, returning its major type and argument.
+    fn head(&mut self) -> Result<(u8, u64), CoseKeyError> {
+        let initial = self.take(1)?[0];
+        let argument = match initial & 0x1f {
+            n @ 0..=23 => u64::from(n),
+            24 => u64::from(self.take(1)?[0]),
+            25 => u64::from(u16::from_be_bytes(
+                self.take(2)?.try_into().expect("length was checked"),
+            )),
+            26 => u64::from(u32::from_be_bytes(
+                self.take(4)?.try_into().expect("length was checked"),
+            )),
+            27 => u64::from_be_bytes(self.take(8)?.try_into().expect("length was checked")),
+            // Reserved values and indefinite lengths
+            _ => return Err(CoseKeyError::Malformed),
+        };
+        Ok((initial >> 5, argument))
+    }
+
+    fn int(&mut self) -> Result<i128, CoseKeyError> {
+        match self.head()? {
+            (0, n) => Ok(i128::from(n)),
+            (1, n) => Ok(-1 - i128::from(n)),
+            _ => Err(CoseKeyError::Malformed),
+        }
+    }
+
+    fn bstr(&mut self) -> Result<&'a [u8], CoseKeyError> {
+        match self.head()? {
+            (2, len) => self.take(usize::try_from(len).map_err(|_| CoseKeyError::Malformed)?),
+            _ => Err(CoseKeyError::Malformed),
+        }
+    }
+
+    /// Skips over an item, descending into arrays, maps and tags up to `depth` levels.
+    fn skip(&mut self, depth: usize) -> Result<(), CoseKeyError> {
+        let (major, argument) = self.head()?;
+        let items = match major {
+            2 | 3 => {
+                self.take(usize::try_from(argument).map_err(|_| CoseKeyError::Malformed)?)?;
+                return Ok(());
+            }
+            4 => argument,
+            5 => argument.checked_mul(2).ok_or(CoseKeyError::Malformed)?,
+            6 => 1,
+            // Integers and simple values are completely described by their head.
+            _ => return Ok(()),
+        };
+        let depth = depth.checked_sub(1).ok_or(CoseKeyError::Malformed)?;
+        for _ in 0..items {
+            self.skip(depth)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writer for the few CBOR items that make up a COSE_Key.
+struct Encoder<'b> {
+    buf: &'b mut [u8],
+    len: usize,
+}
+
+impl Encoder<'_> {
+    fn bytes(&mut self, data: &[u8]) -> Result<(), CoseKeyError> {
+        let end = self.len + data.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(CoseKeyError::BufferTooSmall)?
+            .copy_from_slice(data);
+        self.len = end;
+        Ok(())
+    }
+
+    /// Writes the head of an item in its shortest form.
+    fn head(&mut self, major: u8, argument: u64) -> Result<(), CoseKeyError> {
+        let major = major << 5;
+        match argument {
+            0..=23 => self.bytes(&[major | argument as u8]),
+            24..=0xff => self.bytes(&[major | 24, argument as u8]),
+            0x100..=0xffff => {
+                self.bytes(&[major | 25])?;
+                self.bytes(&(argument as u16).to_be_bytes())
+            }
+            0x1_0000..=0xffff_ffff => {
+                self.bytes(&[major | 26])?;
+                self.bytes(&(argument as u32).to_be_bytes())
+            }
+            _ => {
+                self.bytes(&[major | 27])?;
+                self.bytes(&argument.to_be_bytes())
+            }
+        }
+    }
+
+    fn int(&mut self, value: i128) -> Result<(), CoseKeyError> {
+        let (major, argument) = if value >= 0 {
+            (0, value)
+        } else {
+            (1, -1 - value)
+        };
+        let argument = u64::try_from(argument).map_err(|_| CoseKeyError::Unsupported)?;
+        self.head(major, argument)
+    }
+
+    fn bstr(&mut self, data: &[u8]) -> Result<(), CoseKeyError> {
+        self.head(2, data.len() as u64)?;
+        self.bytes(data)
+    }
+}
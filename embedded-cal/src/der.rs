@@ -0,0 +1,541 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+//! DER encoding and decoding of the keys of any [`DhProvider`], in the formats used by key
+//! management tools:
+//!
+//! - SubjectPublicKeyInfo (SPKI, [RFC5280 Section
+//!   4.1](https://datatracker.ietf.org/doc/html/rfc5280#section-4.1)) for public keys,
+//! - PKCS#8 (OneAsymmetricKey of [RFC5958](https://datatracker.ietf.org/doc/html/rfc5958), which
+//!   extends the PrivateKeyInfo of RFC5208) for secret keys, and
+//! - SEC1 ECPrivateKey ([RFC5915](https://datatracker.ietf.org/doc/html/rfc5915)) for P-256 secret
+//!   keys.
+//!
+//! P-256 keys are identified as described in
+//! [RFC5480](https://datatracker.ietf.org/doc/html/rfc5480), X25519 and X448 keys as in
+//! [RFC8410](https://datatracker.ietf.org/doc/html/rfc8410). The algorithm is then selected
+//! through the curve's COSE number ([`DhAlgorithm::from_cose_ecdh()`]), as in
+//! [`cose_key`][crate::cose_key]. Nothing here allocates: Decoding works on the input, and
+//! encoding writes into a buffer provided by the caller. PEM armor is left to the caller.
+//!
+//! # Public keys
+//!
+//! PKCS#8 and SEC1 can carry the public key along with the secret key. If it is present on import,
+//! it is checked to belong to the secret key. It is not exported, as it can be derived from the
+//! secret key; this is also what OpenSSL does for PKCS#8.
+
+use zeroize::Zeroize;
+
+use crate::{DhAlgorithm, DhProvider, PublicKeyFormat};
+
+const TAG_INTEGER: u8 = 0x02;
+const TAG_BIT_STRING: u8 = 0x03;
+const TAG_OCTET_STRING: u8 = 0x04;
+const TAG_OID: u8 = 0x06;
+const TAG_SEQUENCE: u8 = 0x30;
+/// `[0]` of ECPrivateKey's `parameters` (explicit) and of OneAsymmetricKey's `attributes`
+/// (implicit, constructed).
+const TAG_CONTEXT_0: u8 = 0xa0;
+/// `[1]` of ECPrivateKey's `publicKey` (explicit).
+const TAG_CONTEXT_1_EXPLICIT: u8 = 0xa1;
+/// `[1]` of OneAsymmetricKey's `publicKey` (implicit, primitive).
+const TAG_CONTEXT_1_IMPLICIT: u8 = 0x81;
+
+/// Content of the `id-ecPublicKey` object identifier (1.2.840.10045.2.1).
+const OID_EC_PUBLIC_KEY: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01];
+/// Content of the `secp256r1` object identifier (1.2.840.10045.3.1.7).
+const OID_SECP256R1: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07];
+/// Content of the `id-X25519` object identifier (1.3.101.110).
+const OID_X25519: &[u8] = &[0x2b, 0x65, 0x6e];
+/// Content of the `id-X448` object identifier (1.3.101.111).
+const OID_X448: &[u8] = &[0x2b, 0x65, 0x6f];
+
+/// Errors from encoding, decoding and importing DER keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DerError {
+    /// The data is not well-formed DER of the expected structure, or there is data after it.
+    Malformed,
+    /// The key's algorithm or curve is not supported by this module or by the [`DhProvider`].
+    Unsupported,
+    /// The key data is not valid for the curve (e.g. it has the wrong length, is not on the curve,
+    /// or the public key does not belong to the secret key).
+    InvalidKey,
+    /// The output buffer is too small for the encoded key.
+    BufferTooSmall,
+}
+
+impl core::fmt::Display for DerError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            DerError::Malformed => "malformed DER key",
+            DerError::Unsupported => "unsupported algorithm or curve",
+            DerError::InvalidKey => "key data not valid for curve",
+            DerError::BufferTooSmall => "buffer too small",
+        })
+    }
+}
+
+impl core::error::Error for DerError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Curve {
+    P256,
+    X25519,
+    X448,
+}
+
+impl Curve {
+    fn of<A: DhAlgorithm>(alg: &A) -> Result<Self, DerError> {
+        match alg.cose_ecdh() {
+            Some(1) => Ok(Curve::P256),
+            Some(4) => Ok(Curve::X25519),
+            Some(5) => Ok(Curve::X448),
+            _ => Err(DerError::Unsupported),
+        }
+    }
+
+    fn algorithm<A: DhAlgorithm>(self) -> Result<A, DerError> {
+        let crv = match self {
+            Curve::P256 => 1,
+            Curve::X25519 => 4,
+            Curve::X448 => 5,
+        };
+        A::from_cose_ecdh(crv).ok_or(DerError::Unsupported)
+    }
+}
+
+/// Imports a public key from a SubjectPublicKeyInfo.
+///
+/// P-256 points may be compressed or uncompressed.
+pub fn import_spki<D: DhProvider>(
+    dh: &mut D,
+    data: &[u8],
+) -> Result<(D::Algorithm, D::PublicKey), DerError> {
+    let mut outer = Reader { data };
+    let mut info = outer.nested(TAG_SEQUENCE)?;
+    outer.finish()?;
+    let curve = read_algorithm(&mut info)?;
+    let bits = info.read(TAG_BIT_STRING)?;
+    info.finish()?;
+
+    let (point, format) = read_point(curve, bits)?;
+    let alg = curve.algorithm::<D::Algorithm>()?;
+    let public = dh
+        .import_publickey(alg.clone(), point, format)
+        .map_err(|_| DerError::InvalidKey)?;
+    Ok((alg, public))
+}
+
+/// Imports a secret key from PKCS#8 (PrivateKeyInfo or OneAsymmetricKey).
+///
+/// Attributes are ignored.
+pub fn import_pkcs8<D: DhProvider>(
+    dh: &mut D,
+    data: &[u8],
+) -> Result<(D::Algorithm, D::VisibleSecretKey), DerError> {
+    let mut outer = Reader { data };
+    let mut info = outer.nested(TAG_SEQUENCE)?;
+    outer.finish()?;
+    let version = info.read(TAG_INTEGER)?;
+    let curve = read_algorithm(&mut info)?;
+    let private_key = info.read(TAG_OCTET_STRING)?;
+    info.read_optional(TAG_CONTEXT_0)?;
+    let bits = info.read_optional(TAG_CONTEXT_1_IMPLICIT)?;
+    info.finish()?;
+    // Version 1 (v2 in RFC5958) is needed for the public key, but does not require it.
+    match (version, bits) {
+        ([0], None) | ([1], _) => (),
+        _ => return Err(DerError::Malformed),
+    }
+
+    let (d, inner_public) = match curve {
+        Curve::P256 => {
+            let key = read_ec_private_key(private_key, Some(curve))?;
+            (key.d, key.public)
+        }
+        // CurvePrivateKey of RFC8410
+        Curve::X25519 | Curve::X448 => {
+            let mut inner = Reader { data: private_key };
+            let d = inner.read(TAG_OCTET_STRING)?;
+            inner.finish()?;
+            (d, None)
+        }
+    };
+    let public = bits.map(|bits| read_point(curve, bits)).transpose()?;
+    if inner_public.is_some() && public.is_some() && inner_public != public {
+        return Err(DerError::InvalidKey);
+    }
+    import_secret(dh, curve, d, public.or(inner_public))
+}
+
+/// Imports a secret key from a SEC1 ECPrivateKey.
+///
+/// The ECPrivateKey needs to contain its parameters, as required by RFC5915.
+pub fn import_sec1<D: DhProvider>(
+    dh: &mut D,
+    data: &[u8],
+) -> Result<(D::Algorithm, D::VisibleSecretKey), DerError> {
+    let key = read_ec_private_key(data, None)?;
+    import_secret(dh, key.curve, key.d, key.public)
+}
+
+/// Encodes a public key as SubjectPublicKeyInfo into `buf`, returning the part of `buf` that was
+/// written to.
+///
+/// `alg` is the algorithm the key was created for. P-256 points are written uncompressed.
+pub fn export_spki<'b, D: DhProvider>(
+    dh: &mut D,
+    alg: &D::Algorithm,
+    public: &D::PublicKey,
+    buf: &'b mut [u8],
+) -> Result<&'b [u8], DerError> {
+    let curve = Curve::of(alg)?;
+    let format = match curve {
+        Curve::P256 => PublicKeyFormat::Uncompressed,
+        Curve::X25519 | Curve::X448 => PublicKeyFormat::Compact,
+    };
+    let point = dh
+        .export_publickey(public, format)
+        .map_err(|_| DerError::Unsupported)?;
+
+    // The writer goes from back to front.
+    let mut writer = Writer::new(buf);
+    writer.prepend(point.as_ref())?;
+    // Number of unused bits
+    writer.prepend(&[0])?;
+    writer.header(TAG_BIT_STRING, 0)?;
+    write_algorithm(&mut writer, curve)?;
+    writer.header(TAG_SEQUENCE, 0)?;
+    Ok(writer.finish())
+}
+
+/// Encodes a secret key as PKCS#8 (in the version 1 PrivateKeyInfo form) into `buf`, returning
+/// the part of `buf` that was written to.
+///
+/// `alg` is the algorithm the key was created for.
+pub fn export_pkcs8<'b, D: DhProvider>(
+    dh: &mut D,
+    alg: &D::Algorithm,
+    secret: &D::VisibleSecretKey,
+    buf: &'b mut [u8],
+) -> Result<&'b [u8], DerError> {
+    let curve = Curve::of(alg)?;
+    let d = dh.export_secretkey_bytes(secret);
+
+    // The writer goes from back to front.
+    let mut writer = Writer::new(buf);
+    match curve {
+        // The curve is already in the algorithm identifier.
+        Curve::P256 => write_ec_private_key(&mut writer, d.as_ref(), false)?,
+        Curve::X25519 | Curve::X448 => writer.value(TAG_OCTET_STRING, d.as_ref())?,
+    }
+    writer.header(TAG_OCTET_STRING, 0)?;
+    write_algorithm(&mut writer, curve)?;
+    writer.value(TAG_INTEGER, &[0])?;
+    writer.header(TAG_SEQUENCE, 0)?;
+    Ok(writer.finish())
+}
+
+/// Encodes a P-256 secret key as SEC1 ECPrivateKey into `buf`, returning the part of `buf` that
+/// was written to.
+///
+/// `alg` is the algorithm the key was created for; other curves than P-256 are
+/// [unsupported][DerError::Unsupported].
+pub fn export_sec1<'b, D: DhProvider>(
+    dh: &mut D,
+    alg: &D::Algorithm,
+    secret: &D::VisibleSecretKey,
+    buf: &'b mut [u8],
+) -> Result<&'b [u8], DerError> {
+    if Curve::of(alg)? != Curve::P256 {
+        return Err(DerError::Unsupported);
+    }
+    let d = dh.export_secretkey_bytes(secret);
+
+    let mut writer = Writer::new(buf);
+    write_ec_private_key(&mut writer, d.as_ref(), true)?;
+    Ok(writer.finish())
+}
+
+/// Imports a secret key, checking that the public key belongs to it if given.
+fn import_secret<D: DhProvider>(
+    dh: &mut D,
+    curve: Curve,
+    d: &[u8],
+    public: Option<(&[u8], PublicKeyFormat)>,
+) -> Result<(D::Algorithm, D::VisibleSecretKey), DerError> {
+    let alg = curve.algorithm::<D::Algorithm>()?;
+    let import = |dh: &mut D| {
+        dh.import_secretkey_bytes(alg.clone(), d)
+            .map_err(|_| DerError::InvalidKey)
+    };
+
+    if let Some((point, format)) = public {
+        let private = import(dh)?.into();
+        let derived = dh.public_key(&private);
+        let derived = dh
+            .export_publickey(&derived, format)
+            .map_err(|_| DerError::InvalidKey)?;
+        if derived.as_ref() != point {
+            return Err(DerError::InvalidKey);
+        }
+    }
+
+    Ok((alg.clone(), import(dh)?))
+}
+
+/// Reads an AlgorithmIdentifier of a supported curve.
+fn read_algorithm(reader: &mut Reader<'_>) -> Result<Curve, DerError> {
+    let mut identifier = reader.nested(TAG_SEQUENCE)?;
+    let curve = match identifier.read(TAG_OID)? {
+        OID_EC_PUBLIC_KEY => read_named_curve(&mut identifier)?,
+        OID_X25519 => Curve::X25519,
+        OID_X448 => Curve::X448,
+        _ => return Err(DerError::Unsupported),
+    };
+    // RFC8410 requires the parameters of X25519 and X448 to be absent.
+    identifier.finish()?;
+    Ok(curve)
+}
+
+/// Reads the namedCurve choice of ECParameters; the other choices are not supported.
+fn read_named_curve(reader: &mut Reader<'_>) -> Result<Curve, DerError> {
+    match reader.read(TAG_OID)? {
+        OID_SECP256R1 => Ok(Curve::P256),
+        _ => Err(DerError::Unsupported),
+    }
+}
+
+/// The parts of an ECPrivateKey.
+struct EcPrivateKey<'a> {
+    curve: Curve,
+    d: &'a [u8],
+    /// The public key and its format, if present
+    public: Option<(&'a [u8], PublicKeyFormat)>,
+}
+
+/// Reads an ECPrivateKey.
+///
+/// If `curve` is given (from the PKCS#8 algorithm identifier), the parameters are optional, but
+/// need to match if present.
+fn read_ec_private_key(data: &[u8], curve: Option<Curve>) -> Result<EcPrivateKey<'_>, DerError> {
+    let mut outer = Reader { data };
+    let mut key = outer.nested(TAG_SEQUENCE)?;
+    outer.finish()?;
+    if key.read(TAG_INTEGER)? != [1] {
+        return Err(DerError::Malformed);
+    }
+    let d = key.read(TAG_OCTET_STRING)?;
+    let parameters = match key.read_optional(TAG_CONTEXT_0)? {
+        Some(data) => {
+            let mut parameters = Reader { data };
+            let curve = read_named_curve(&mut parameters)?;
+            parameters.finish()?;
+            Some(curve)
+        }
+        None => None,
+    };
+    let bits = match key.read_optional(TAG_CONTEXT_1_EXPLICIT)? {
+        Some(data) => {
+            let mut public = Reader { data };
+            let bits = public.read(TAG_BIT_STRING)?;
+            public.finish()?;
+            Some(bits)
+        }
+        None => None,
+    };
+    key.finish()?;
+
+    let curve = match (parameters, curve) {
+        (Some(parameters), Some(curve)) if parameters != curve => return Err(DerError::Malformed),
+        (Some(curve), _) | (None, Some(curve)) => curve,
+        (None, None) => return Err(DerError::Malformed),
+    };
+    let public = bits.map(|bits| read_point(curve, bits)).transpose()?;
+    Ok(EcPrivateKey { curve, d, public })
+}
+
+/// Checks the contents of a BIT STRING that holds a public key, and returns the key along with
+/// its [`PublicKeyFormat`].
+///
+/// Whether the point is valid is left to the [`DhProvider`].
+fn read_point(curve: Curve, bits: &[u8]) -> Result<(&[u8], PublicKeyFormat), DerError> {
+    let [0, point @ ..] = bits else {
+        // Keys are whole bytes, so there must not be any unused bits.
+        return Err(DerError::Malformed);
+    };
+    let format = match (curve, point.first()) {
+        (Curve::P256, Some(0x04)) => PublicKeyFormat::Uncompressed,
+        (Curve::P256, Some(0x02 | 0x03)) => PublicKeyFormat::Compressed,
+        (Curve::P256, _) => return Err(DerError::InvalidKey),
+        (Curve::X25519 | Curve::X448, _) => PublicKeyFormat::Compact,
+    };
+    Ok((point, format))
+}
+
+/// Writes an AlgorithmIdentifier.
+fn write_algorithm(writer: &mut Writer<'_>, curve: Curve) -> Result<(), DerError> {
+    let mark = writer.written();
+    match curve {
+        Curve::P256 => {
+            writer.value(TAG_OID, OID_SECP256R1)?;
+            writer.value(TAG_OID, OID_EC_PUBLIC_KEY)?;
+        }
+        Curve::X25519 => writer.value(TAG_OID, OID_X25519)?,
+        Curve::X448 => writer.value(TAG_OID, OID_X448)?,
+    }
+    writer.header(TAG_SEQUENCE, mark)
+}
+
+/// Writes a P-256 ECPrivateKey without public key, and with or without its parameters.
+fn write_ec_private_key(
+    writer: &mut Writer<'_>,
+    d: &[u8],
+    with_parameters: bool,
+) -> Result<(), DerError> {
+    let mark = writer.written();
+    if with_parameters {
+        let parameters_mark = writer.written();
+        writer.value(TAG_OID, OID_SECP256R1)?;
+        writer.header(TAG_CONTEXT_0, parameters_mark)?;
+    }
+    writer.value(TAG_OCTET_STRING, d)?;
+    // ecPrivkeyVer1
+    writer.value(TAG_INTEGER, &[1])?;
+    writer.header(TAG_SEQUENCE, mark)
+}
+
+/// Reader over the contents of a DER value.
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], DerError> {
+        if len > self.data.len() {
+            return Err(DerError::Malformed);
+        }
+        let (taken, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(taken)
+    }
+
+    /// Reads a value with the given tag, returning its contents.
+    fn read(&mut self, tag: u8) -> Result<&'a [u8], DerError> {
+        if self.take(1)?[0] != tag {
+            return Err(DerError::Malformed);
+        }
+        let len = match self.take(1)?[0] {
+            len @ 0..=0x7f => usize::from(len),
+            0x81 => match self.take(1)?[0] {
+                // DER requires the short form for these
+                0..=0x7f => return Err(DerError::Malformed),
+                len => usize::from(len),
+            },
+            0x82 => match u16::from_be_bytes(self.take(2)?.try_into().expect("length was checked"))
+            {
+                0..=0xff => return Err(DerError::Malformed),
+                len => usize::from(len),
+            },
+            // Indefinite lengths are not DER, and none of the keys come anywhere near 64KiB.
+            _ => return Err(DerError::Malformed),
+        };
+        self.take(len)
+    }
+
+    /// Reads a value if it has the given tag.
+    fn read_optional(&mut self, tag: u8) -> Result<Option<&'a [u8]>, DerError> {
+        if self.data.first() == Some(&tag) {
+            self.read(tag).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Reads a value with the given tag, returning a reader over its contents.
+    fn nested(&mut self, tag: u8) -> Result<Reader<'a>, DerError> {
+        Ok(Reader {
+            data: self.read(tag)?,
+        })
+    }
+
+    /// Checks that all data was read.
+    fn finish(&self) -> Result<(), DerError> {
+        match self.data {
+            [] => Ok(()),
+            _ => Err(DerError::Malformed),
+        }
+    }
+}
+
+/// Writer that fills a buffer from its end, so that the length of a value's contents is known
+/// by the time its header is written.
+///
+/// As the written data may contain a secret key, anything that is not returned from
+/// [`.finish()`](Self::finish()) is zeroized, also when the writer is dropped on an error.
+struct Writer<'b> {
+    buf: &'b mut [u8],
+    start: usize,
+}
+
+impl<'b> Writer<'b> {
+    fn new(buf: &'b mut [u8]) -> Self {
+        let start = buf.len();
+        Self { buf, start }
+    }
+
+    /// Number of bytes written so far.
+    fn written(&self) -> usize {
+        self.buf.len() - self.start
+    }
+
+    fn prepend(&mut self, data: &[u8]) -> Result<(), DerError> {
+        let start = self
+            .start
+            .checked_sub(data.len())
+            .ok_or(DerError::BufferTooSmall)?;
+        self.buf[start..self.start].copy_from_slice(data);
+        self.start = start;
+        Ok(())
+    }
+
+    /// Prepends the header of a value whose contents were written since [`.written()`] was `mark`.
+    ///
+    /// [`.written()`]: Self::written()
+    fn header(&mut self, tag: u8, mark: usize) -> Result<(), DerError> {
+        let len = self.written() - mark;
+        match len {
+            0..=0x7f => self.prepend(&[len as u8])?,
+            0x80..=0xff => self.prepend(&[0x81, len as u8])?,
+            _ => {
+                let len = u16::try_from(len).map_err(|_| DerError::BufferTooSmall)?;
+                self.prepend(&len.to_be_bytes())?;
+                self.prepend(&[0x82])?;
+            }
+        }
+        self.prepend(&[tag])
+    }
+
+    fn value(&mut self, tag: u8, contents: &[u8]) -> Result<(), DerError> {
+        let mark = self.written();
+        self.prepend(contents)?;
+        self.header(tag, mark)
+    }
+
+    /// Moves the written data to the start of the buffer, and returns it.
+    fn finish(mut self) -> &'b [u8] {
+        let buf = core::mem::take(&mut self.buf);
+        let start = core::mem::replace(&mut self.start, 0);
+        let len = buf.len() - start;
+        buf.copy_within(start.., 0);
+        buf[len..].zeroize();
+        let buf: &'b [u8] = buf;
+        &buf[..len]
+    }
+}
+
+impl Drop for Writer<'_> {
+    fn drop(&mut self) {
+        self.buf[self.start..].zeroize();
+    }
+}
@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
 
+use crate::{OperationError, TryError};
+
 /// Diffie-Hellman style key establishment.
 ///
 /// This trait does not distinguish between prime factor DH and Elliptic Curve DH (ECDH); it
@@ -46,11 +48,34 @@ pub trait DhProvider {
 
     /// Generates a secret key that is intended to be exported / shared (e.g. to be persisted
     /// across program executions).
-    fn generate_visible(&mut self, alg: Self::Algorithm) -> Self::VisibleSecretKey;
+    ///
+    /// # Errors
+    ///
+    /// … are [`OperationError::HardwareFault`] if the random number generator failed.
+    fn try_generate_visible(
+        &mut self,
+        alg: Self::Algorithm,
+    ) -> Result<Self::VisibleSecretKey, OperationError>;
 
     /// Generates a secret key.
+    ///
+    /// # Errors
+    ///
+    /// … as for [`.try_generate_visible()`][Self::try_generate_visible()].
+    fn try_generate(&mut self, alg: Self::Algorithm) -> Result<Self::SecretKey, OperationError> {
+        self.try_generate_visible(alg).map(Into::into)
+    }
+
+    /// Generates a secret key that is intended to be exported / shared, panicking where
+    /// [`.try_generate_visible()`][Self::try_generate_visible()] errs.
+    fn generate_visible(&mut self, alg: Self::Algorithm) -> Self::VisibleSecretKey {
+        self.try_generate_visible(alg)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Generates a secret key, panicking where [`.try_generate()`][Self::try_generate()] errs.
     fn generate(&mut self, alg: Self::Algorithm) -> Self::SecretKey {
-        self.generate_visible(alg).into()
+        self.try_generate(alg).unwrap_or_else(|e| panic!("{e}"))
     }
 
     /// Exposes a visible secret key's secret.
@@ -89,21 +114,84 @@ pub trait DhProvider {
         data: &[u8],
     ) -> Result<Self::PublicKey, ImportError>;
 
+    /// Exposes a public key in the given format.
+    ///
+    /// With [`PublicKeyFormat::Compact`], this produces the same as
+    /// [`.export_publickey_bytes()`][Self::export_publickey_bytes()]. The SEC1 formats contain
+    /// the point's actual y coordinate (or its parity) for keys that were generated, derived from
+    /// a secret key or imported in a SEC1 format; only for keys imported in compact form it is
+    /// whichever of the two candidates the implementation picked.
+    ///
+    /// # Errors
+    ///
+    /// … are [`UnsupportedFormat`] if the key's algorithm has no representation in that format
+    /// (e.g. X25519 keys in SEC1 formats).
+    fn export_publickey<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+        format: PublicKeyFormat,
+    ) -> Result<impl AsRef<[u8]> + use<'p, Self>, UnsupportedFormat>;
+
+    /// Imports a public key in the given format, in the inverse operation of
+    /// [`.export_publickey()`][Self::export_publickey()].
+    ///
+    /// Implementations must reject points that are not on the curve.
+    fn import_publickey(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+        format: PublicKeyFormat,
+    ) -> Result<Self::PublicKey, ImportError>;
+
     /// Derives a shared secret from a public and a private key.
     ///
     /// # Errors
     ///
-    /// … are produced only if the private and the public key are for different algorithms.
+    /// … are [`TryError::Failed`] if the private and the public key are for different
+    /// algorithms, and [`TryError::Operation`] if the hardware failed.
     // FIXME: Is this really an error we should raise? People who don't check algorithms will also
     // reach into nonexistent offsets in output material, and that too is punishable by panics.
-    fn shared_secret(
+    fn try_shared_secret(
         &mut self,
         private: &Self::SecretKey,
         public: &Self::PublicKey,
-    ) -> Result<Self::SharedSecret, IncompatibleKeys>;
+    ) -> Result<Self::SharedSecret, TryError<IncompatibleKeys>>;
 
     /// Produces the public key corresponding to a private key.
-    fn public_key(&mut self, private: &Self::SecretKey) -> Self::PublicKey;
+    ///
+    /// # Errors
+    ///
+    /// … are [`OperationError::HardwareFault`] if the hardware failed.
+    fn try_public_key(
+        &mut self,
+        private: &Self::SecretKey,
+    ) -> Result<Self::PublicKey, OperationError>;
+
+    /// Derives a shared secret from a public and a private key.
+    ///
+    /// # Errors
+    ///
+    /// … are produced only if the private and the public key are for different algorithms.
+    ///
+    /// # Panics
+    ///
+    /// … where [`.try_shared_secret()`][Self::try_shared_secret()] reports an
+    /// [`OperationError`].
+    fn shared_secret(
+        &mut self,
+        private: &Self::SecretKey,
+        public: &Self::PublicKey,
+    ) -> Result<Self::SharedSecret, IncompatibleKeys> {
+        self.try_shared_secret(private, public)
+            .map_err(TryError::unwrap_failed)
+    }
+
+    /// Produces the public key corresponding to a private key, panicking where
+    /// [`.try_public_key()`][Self::try_public_key()] errs.
+    fn public_key(&mut self, private: &Self::SecretKey) -> Self::PublicKey {
+        self.try_public_key(private)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
 
     /// Produces the bytes of the shared secret, in the algorithm's
     /// [`.output_length()`][DhAlgorithm::output_length()].
@@ -144,6 +232,40 @@ impl core::fmt::Display for ImportError {
 
 impl core::error::Error for ImportError {}
 
+/// Encodings of public keys for [`DhProvider::export_publickey()`] and
+/// [`DhProvider::import_publickey()`].
+///
+/// Curves without a y coordinate (X25519, X448) only have the compact format, which is their
+/// regular RFC7748 encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PublicKeyFormat {
+    /// The x coordinate only, as described in [RFC6090 Appendix
+    /// C](https://datatracker.ietf.org/doc/html/rfc6090#appendix-C).
+    ///
+    /// This is sufficient for ECDH, as the shared secret is the same for both points with that x
+    /// coordinate. It is the format of [`DhProvider::export_publickey_bytes()`].
+    Compact,
+    /// `0x02` or `0x03` (depending on the parity of y) followed by the x coordinate, as in [SEC1
+    /// Section 2.3.3](https://www.secg.org/sec1-v2.pdf).
+    Compressed,
+    /// `0x04` followed by the x and the y coordinate, as in [SEC1 Section
+    /// 2.3.3](https://www.secg.org/sec1-v2.pdf).
+    Uncompressed,
+}
+
+/// Error indicating that a public key has no representation in the requested
+/// [`PublicKeyFormat`].
+#[derive(Debug)]
+pub struct UnsupportedFormat;
+
+impl core::fmt::Display for UnsupportedFormat {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("format not available for algorithm")
+    }
+}
+
+impl core::error::Error for UnsupportedFormat {}
+
 /// An algorithm for diffie-hellman style key establishment.
 ///
 /// This not only encodes the cryptographic algorithm, but also the curve, but not post-processing
@@ -174,13 +296,80 @@ pub trait DhAlgorithm: Sized + PartialEq + Eq + core::fmt::Debug + Clone {
     fn from_cose_ecdh(curve: impl Into<i128>) -> Option<Self> {
         None
     }
+
+    /// Selects a DH algorithm from its TLS NamedGroup value.
+    ///
+    /// The group comes from the ["TLS Supported Groups"
+    /// registry](https://www.iana.org/assignments/tls-parameters/tls-parameters.xhtml#tls-parameters-8)
+    /// maintained by IANA (e.g. 23 for secp256r1, 29 for x25519 and 30 for x448).
+    #[inline]
+    #[allow(
+        unused_variables,
+        reason = "Argument names are part of the documentation"
+    )]
+    fn from_tls_named_group(group: u16) -> Option<Self> {
+        None
+    }
+
+    /// Lists all algorithms supported by the implementation, as selected by
+    /// [`Self::from_cose_ecdh()`].
+    ///
+    /// See [`HashAlgorithm::all()`][crate::HashAlgorithm::all()] for how implementations that
+    /// extend a base compose the list.
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        core::iter::empty()
+    }
+
+    /// The algorithm's curve number in the COSE Elliptic Curves registry, if it has one; the
+    /// inverse of [`Self::from_cose_ecdh()`].
+    ///
+    /// This is the value of the `crv` parameter of COSE keys for the algorithm.
+    #[inline]
+    fn cose_ecdh(&self) -> Option<i128> {
+        None
+    }
+
+    /// The algorithm's Suite ID in the Named Information Hash Algorithm Registry, if it has one.
+    ///
+    /// That registry (RFC 6920) only names hash algorithms, so no DH algorithm has an entry; this
+    /// exists for symmetry with [`HashAlgorithm::ni_id()`][crate::HashAlgorithm::ni_id()].
+    #[inline]
+    fn ni_id(&self) -> Option<u8> {
+        None
+    }
+
+    /// The algorithm's Hash Name String in the Named Information Hash Algorithm Registry, if it
+    /// has one; like [`Self::ni_id()`], this is `None` for all DH algorithms.
+    #[inline]
+    fn ni_name(&self) -> Option<&'static str> {
+        None
+    }
 }
 
 pub fn test_dh_algorithm_ecdh_p256<DP: DhProvider>() {
     let cose_ecdh_1 = DP::Algorithm::from_cose_ecdh(1i8).expect(
         "test for type claiming ECDH on P-256 compatibility did not recognize COSE curve 1",
     );
-    assert_eq!(cose_ecdh_1.output_length(), 32)
+    assert_eq!(cose_ecdh_1.output_length(), 32);
+    assert_eq!(cose_ecdh_1.cose_ecdh(), Some(1));
+    assert_eq!(DP::Algorithm::from_tls_named_group(23), Some(cose_ecdh_1));
+}
+
+pub fn test_dh_algorithm_x25519<DP: DhProvider>() {
+    let cose_ecdh_4 = DP::Algorithm::from_cose_ecdh(4i8)
+        .expect("test for type claiming X25519 compatibility did not recognize COSE curve 4");
+    assert_eq!(cose_ecdh_4.output_length(), 32);
+    assert_eq!(cose_ecdh_4.cose_ecdh(), Some(4));
+    assert_eq!(DP::Algorithm::from_tls_named_group(29), Some(cose_ecdh_4));
+}
+
+pub fn test_dh_algorithm_x448<DP: DhProvider>() {
+    let cose_ecdh_5 = DP::Algorithm::from_cose_ecdh(5i8)
+        .expect("test for type claiming X448 compatibility did not recognize COSE curve 5");
+    assert_eq!(cose_ecdh_5.output_length(), 56);
+    assert_eq!(cose_ecdh_5.cose_ecdh(), Some(5));
+    assert_eq!(DP::Algorithm::from_tls_named_group(30), Some(cose_ecdh_5));
 }
 
 pub fn test_dh_selftest<C: crate::Cal + rand_core::CryptoRng>(
@@ -0,0 +1,257 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+use crate::{ImportError, IncompatibleKeys};
+
+/// Largest point encoding supported by the provided methods (an uncompressed P-521 point).
+const MAX_UNCOMPRESSED_LEN: usize = 1 + 2 * 66;
+
+/// Low-level operations on elliptic curve points.
+///
+/// These are building blocks for protocols that are not covered by higher-level traits such as
+/// [`DhProvider`][crate::DhProvider] or [`SignatureProvider`][crate::SignatureProvider] (e.g.
+/// SPAKE2+, EC-JPAKE or Schnorr proofs of knowledge). They give none of the guarantees of those
+/// traits: Scalars are not secret keys, and applications need to take care of the mathematical
+/// soundness of their constructions.
+///
+/// Points may be the point at infinity (the identity element of the group). Operations that
+/// combine several points or scalars fail with [`IncompatibleKeys`] if they are on different
+/// curves.
+pub trait EccProvider {
+    /// The curve (including all of its domain parameters).
+    type Algorithm: EccAlgorithm;
+    /// A point on the curve, or the point at infinity.
+    type Point: Sized;
+    /// A scalar, i.e. an integer modulo the order of the curve's generator.
+    type Scalar: Sized;
+
+    /// Imports a point from its SEC1 encoding.
+    ///
+    /// Both the uncompressed (`0x04 || x || y`) and the compressed (`0x02 || x` or `0x03 || x`)
+    /// form are accepted, as well as the single `0x00` byte that encodes the point at infinity.
+    ///
+    /// Implementations must reject coordinates that are not reduced modulo the field's prime, and
+    /// points that are not on the curve.
+    fn import_point(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::Point, ImportError>;
+
+    /// Imports a point from its affine coordinates, each in big-endian with the algorithm's
+    /// [`.coordinate_length()`][EccAlgorithm::coordinate_length()].
+    ///
+    /// See [`.import_point()`][Self::import_point()] for the validation that is performed.
+    fn import_affine(
+        &mut self,
+        alg: Self::Algorithm,
+        x: &[u8],
+        y: &[u8],
+    ) -> Result<Self::Point, ImportError> {
+        let coordinate_length = alg.coordinate_length();
+        if x.len() != coordinate_length || y.len() != coordinate_length {
+            return Err(ImportError);
+        }
+        let mut buf = [0; MAX_UNCOMPRESSED_LEN];
+        let buf = buf
+            .get_mut(..1 + 2 * coordinate_length)
+            .expect("curves with larger coordinates need to implement import_affine");
+        buf[0] = 0x04;
+        buf[1..][..coordinate_length].copy_from_slice(x);
+        buf[1 + coordinate_length..].copy_from_slice(y);
+        self.import_point(alg, buf)
+    }
+
+    /// Exposes the SEC1 encoding of a point, either in uncompressed or compressed form.
+    ///
+    /// The point at infinity is encoded as a single `0x00` byte in either form.
+    fn export_point<'p>(
+        &mut self,
+        point: &'p Self::Point,
+        compressed: bool,
+    ) -> impl AsRef<[u8]> + use<'p, Self>;
+
+    /// Imports a scalar from its big-endian encoding with the algorithm's
+    /// [`.scalar_length()`][EccAlgorithm::scalar_length()].
+    ///
+    /// Values that are not reduced modulo the group order are rejected.
+    fn import_scalar(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::Scalar, ImportError>;
+
+    /// Produces the curve's generator (base point).
+    fn generator(&mut self, alg: Self::Algorithm) -> Self::Point;
+
+    /// Produces the point at infinity.
+    fn identity(&mut self, alg: Self::Algorithm) -> Self::Point;
+
+    /// Checks whether a point is the point at infinity.
+    fn is_identity(&mut self, point: &Self::Point) -> bool;
+
+    /// Negates a point.
+    fn negate(&mut self, point: &Self::Point) -> Self::Point;
+
+    /// Adds two points.
+    fn add(&mut self, a: &Self::Point, b: &Self::Point) -> Result<Self::Point, IncompatibleKeys>;
+
+    /// Multiplies a point with a scalar.
+    ///
+    /// Implementations must not leak the scalar through timing side channels.
+    fn mul(
+        &mut self,
+        scalar: &Self::Scalar,
+        point: &Self::Point,
+    ) -> Result<Self::Point, IncompatibleKeys>;
+
+    /// Computes the sum of several point-scalar products (`k1 * P1 + k2 * P2 + …`).
+    ///
+    /// The default implementation adds up the individual products; implementations may use
+    /// faster algorithms.
+    ///
+    /// # Panics
+    ///
+    /// … if `terms` is empty, as the curve of the result would be unknown.
+    fn multi_mul(
+        &mut self,
+        terms: &[(&Self::Scalar, &Self::Point)],
+    ) -> Result<Self::Point, IncompatibleKeys> {
+        let ((first_scalar, first_point), rest) =
+            terms.split_first().expect("terms must not be empty");
+        let mut sum = self.mul(first_scalar, first_point)?;
+        for (scalar, point) in rest {
+            let product = self.mul(scalar, point)?;
+            sum = self.add(&sum, &product)?;
+        }
+        Ok(sum)
+    }
+}
+
+/// An elliptic curve identifier.
+pub trait EccAlgorithm: Sized + PartialEq + Eq + core::fmt::Debug + Clone {
+    /// Length of an encoded coordinate (a field element) in bytes.
+    fn coordinate_length(&self) -> usize;
+
+    /// Length of an encoded scalar in bytes.
+    fn scalar_length(&self) -> usize;
+
+    /// Selects a curve from its COSE number.
+    ///
+    /// The curve number comes from the ["COSE Elliptic Curves"](https://www.iana.org/assignments/cose/cose.xhtml#elliptic-curves)
+    /// registry maintained by IANA. Only curves in short Weierstrass form (key type EC2) are
+    /// expected to be supported.
+    #[inline]
+    #[allow(
+        unused_variables,
+        reason = "Argument names are part of the documentation"
+    )]
+    fn from_cose_curve(curve: impl Into<i128>) -> Option<Self> {
+        None
+    }
+
+    /// Lists all algorithms supported by the implementation, as selected by
+    /// [`Self::from_cose_curve()`].
+    ///
+    /// See [`HashAlgorithm::all()`][crate::HashAlgorithm::all()] for how implementations that
+    /// extend a base compose the list.
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        core::iter::empty()
+    }
+
+    /// The curve's number in the COSE Elliptic Curves registry, if it has one; the inverse of
+    /// [`Self::from_cose_curve()`].
+    #[inline]
+    fn cose_curve(&self) -> Option<i128> {
+        None
+    }
+}
+
+pub fn test_ecc_algorithm_p256<EP: EccProvider>() {
+    let cose_1 = EP::Algorithm::from_cose_curve(1i8)
+        .expect("test for type claiming P-256 compatibility did not recognize COSE curve 1");
+    assert_eq!(cose_1.coordinate_length(), 32);
+    assert_eq!(cose_1.scalar_length(), 32);
+}
+
+pub fn test_ecc_selftest<C: crate::Cal>(
+    cal: &mut C,
+    alg: <C::EccProvider as EccProvider>::Algorithm,
+) {
+    let cal = cal.ecc();
+
+    let scalar = |cal: &mut C::EccProvider, value: u8| {
+        let mut buf = [0; 66];
+        let buf = &mut buf[..alg.scalar_length()];
+        *buf.last_mut().unwrap() = value;
+        cal.import_scalar(alg.clone(), buf)
+            .expect("small scalar could not be imported")
+    };
+    let export = |cal: &mut C::EccProvider, point: &_| {
+        let mut buf = [0; MAX_UNCOMPRESSED_LEN];
+        let exported = cal.export_point(point, false);
+        let exported = exported.as_ref();
+        buf[..exported.len()].copy_from_slice(exported);
+        (buf, exported.len())
+    };
+
+    let g = cal.generator(alg.clone());
+    let three = scalar(cal, 3);
+    let five = scalar(cal, 5);
+    let eight = scalar(cal, 8);
+
+    // 3G + 5G = 8G, computed in various ways
+    let g3 = cal.mul(&three, &g).unwrap();
+    let g5 = cal.mul(&five, &g).unwrap();
+    let g8 = cal.mul(&eight, &g).unwrap();
+    let g8_sum = cal.add(&g3, &g5).unwrap();
+    assert_eq!(export(cal, &g8_sum), export(cal, &g8));
+    let g8_multi = cal.multi_mul(&[(&three, &g), (&five, &g)]).unwrap();
+    assert_eq!(export(cal, &g8_multi), export(cal, &g8));
+
+    // Both encodings round-trip
+    for compressed in [false, true] {
+        let encoded = cal.export_point(&g8, compressed);
+        let reimported = cal
+            .import_point(alg.clone(), encoded.as_ref())
+            .expect("exported point could not be imported");
+        assert_eq!(export(cal, &reimported), export(cal, &g8));
+    }
+    let (uncompressed, len) = export(cal, &g8);
+    let coordinate_length = alg.coordinate_length();
+    assert_eq!(len, 1 + 2 * coordinate_length);
+    let (x, y) = uncompressed[1..len].split_at(coordinate_length);
+    let affine = cal
+        .import_affine(alg.clone(), x, y)
+        .expect("affine coordinates could not be imported");
+    assert_eq!(export(cal, &affine), export(cal, &g8));
+
+    // The identity behaves as such
+    let minus_g8 = cal.negate(&g8);
+    let identity = cal.add(&g8, &minus_g8).unwrap();
+    assert!(cal.is_identity(&identity));
+    assert!(!cal.is_identity(&g8));
+    let fresh_identity = cal.identity(alg.clone());
+    assert_eq!(export(cal, &identity), export(cal, &fresh_identity));
+    assert_eq!(export(cal, &identity).0[..1], [0]);
+    assert_eq!(export(cal, &identity).1, 1);
+    let g8_plus_identity = cal.add(&g8, &identity).unwrap();
+    assert_eq!(export(cal, &g8_plus_identity), export(cal, &g8));
+
+    // Points off the curve are rejected
+    let mut off_curve = uncompressed;
+    off_curve[len - 1] ^= 1;
+    assert!(
+        cal.import_point(alg.clone(), &off_curve[..len]).is_err(),
+        "point off the curve was accepted"
+    );
+
+    // Scalars that are not reduced are rejected
+    let all_ones = [0xff; 66];
+    assert!(
+        cal.import_scalar(alg.clone(), &all_ones[..alg.scalar_length()])
+            .is_err(),
+        "unreduced scalar was accepted"
+    );
+}
@@ -20,6 +20,12 @@ impl<const PLUMBING: bool> Cal for EmptyCal<PLUMBING> {
     type AeadProvider = Self;
     type HashProvider = Self;
     type HmacProvider = Self;
+    type SignatureProvider = Self;
+    type KemProvider = Self;
+    type BlockCipherProvider = Self;
+    type EccProvider = Self;
+    type MacProvider = Self;
+    type KeyWrapProvider = Self;
 
     fn dh(&mut self) -> &mut Self::DhProvider {
         self
@@ -36,6 +42,30 @@ impl<const PLUMBING: bool> Cal for EmptyCal<PLUMBING> {
     fn hmac(&mut self) -> &mut Self::HmacProvider {
         self
     }
+
+    fn signature(&mut self) -> &mut Self::SignatureProvider {
+        self
+    }
+
+    fn kem(&mut self) -> &mut Self::KemProvider {
+        self
+    }
+
+    fn block_cipher(&mut self) -> &mut Self::BlockCipherProvider {
+        self
+    }
+
+    fn ecc(&mut self) -> &mut Self::EccProvider {
+        self
+    }
+
+    fn mac(&mut self) -> &mut Self::MacProvider {
+        self
+    }
+
+    fn key_wrap(&mut self) -> &mut Self::KeyWrapProvider {
+        self
+    }
 }
 
 // Those should all be shorter when <https://github.com/lake-rs/embedded-cal/issues/40> is
@@ -46,18 +76,27 @@ impl<const PLUMBING: bool> HashProvider for EmptyCal<PLUMBING> {
     type Algorithm = NoAlgorithms;
     type State = NoAlgorithms;
     type Output = NoAlgorithms;
+    type XofReader = NoAlgorithms;
 
-    fn init(&mut self, algorithm: Self::Algorithm) -> Self::State {
+    fn try_init(&mut self, algorithm: Self::Algorithm) -> Result<Self::State, OperationError> {
         match algorithm {}
     }
 
-    fn update(&mut self, instance: &mut Self::State, _data: &[u8]) {
+    fn try_update(
+        &mut self,
+        instance: &mut Self::State,
+        _data: &[u8],
+    ) -> Result<(), OperationError> {
         match *instance {}
     }
 
-    fn finalize(&mut self, instance: Self::State) -> Self::Output {
+    fn try_finalize(&mut self, instance: Self::State) -> Result<Self::Output, OperationError> {
         match instance {}
     }
+
+    fn squeeze(&mut self, reader: &mut Self::XofReader, _output: &mut [u8]) {
+        match *reader {}
+    }
 }
 
 impl<const PLUMBING: bool> HmacProvider for EmptyCal<PLUMBING> {
@@ -66,6 +105,55 @@ impl<const PLUMBING: bool> HmacProvider for EmptyCal<PLUMBING> {
     type State = NoAlgorithms;
     type Output = NoAlgorithms;
 
+    fn try_load_from_keydata(
+        &mut self,
+        algorithm: Self::Algorithm,
+        _key: &[u8],
+    ) -> Result<Self::Key, OperationError> {
+        match algorithm {}
+    }
+
+    fn try_init(&mut self, key: Self::Key) -> Result<Self::State, OperationError> {
+        match key {}
+    }
+
+    fn try_update(&mut self, state: &mut Self::State, _data: &[u8]) -> Result<(), OperationError> {
+        match *state {}
+    }
+
+    fn try_finalize(&mut self, state: Self::State) -> Result<Self::Output, OperationError> {
+        match state {}
+    }
+}
+
+impl<const PLUMBING: bool> KeyWrapProvider for EmptyCal<PLUMBING> {
+    type Algorithm = NoAlgorithms;
+    type Key = NoAlgorithms;
+
+    fn load_from_keydata(&mut self, alg: Self::Algorithm, _key: &[u8]) -> Self::Key {
+        match alg {}
+    }
+
+    fn wrap_key(&mut self, kek: &Self::Key, _key_data: &[u8], _output: &mut [u8]) {
+        match *kek {}
+    }
+
+    fn unwrap_key(
+        &mut self,
+        kek: &Self::Key,
+        _wrapped: &[u8],
+        _output: &mut [u8],
+    ) -> Result<(), DecryptionFailed> {
+        match *kek {}
+    }
+}
+
+impl<const PLUMBING: bool> MacProvider for EmptyCal<PLUMBING> {
+    type Algorithm = NoAlgorithms;
+    type Key = NoAlgorithms;
+    type State = NoAlgorithms;
+    type Output = NoAlgorithms;
+
     fn load_from_keydata(&mut self, algorithm: Self::Algorithm, _key: &[u8]) -> Self::Key {
         match algorithm {}
     }
@@ -88,28 +176,49 @@ impl<const PLUMBING: bool> AeadProvider for EmptyCal<PLUMBING> {
     type Key = NoAlgorithms;
     type Tag = NoAlgorithms;
 
-    fn load_from_keydata(&mut self, alg: Self::Algorithm, _key: &[u8]) -> Self::Key {
+    fn try_load_from_keydata(
+        &mut self,
+        alg: Self::Algorithm,
+        _key: &[u8],
+    ) -> Result<Self::Key, OperationError> {
         match alg {}
     }
 
-    fn encrypt_in_place(
+    fn try_encrypt_in_place(
         &mut self,
         key: &Self::Key,
         _nonce: &[u8],
         _message: &mut [u8],
         _aad: impl AadGenerator,
-    ) -> Self::Tag {
+    ) -> Result<Self::Tag, OperationError> {
         match *key {}
     }
 
-    fn decrypt_in_place(
+    fn try_decrypt_in_place(
         &mut self,
         key: &Self::Key,
         _nonce: &[u8],
         _message: &mut [u8],
         _tag: &[u8],
         _aad: impl AadGenerator,
-    ) -> Result<(), DecryptionFailed> {
+    ) -> Result<(), TryError<DecryptionFailed>> {
+        match *key {}
+    }
+}
+
+impl<const PLUMBING: bool> BlockCipherProvider for EmptyCal<PLUMBING> {
+    type Algorithm = NoAlgorithms;
+    type Key = NoAlgorithms;
+
+    fn load_from_keydata(&mut self, alg: Self::Algorithm, _key: &[u8]) -> Self::Key {
+        match alg {}
+    }
+
+    fn encrypt_block(&mut self, key: &Self::Key, _block: &mut [u8; BLOCK_SIZE]) {
+        match *key {}
+    }
+
+    fn decrypt_block(&mut self, key: &Self::Key, _block: &mut [u8; BLOCK_SIZE]) {
         match *key {}
     }
 }
@@ -121,19 +230,25 @@ impl<const PLUMBING: bool> DhProvider for EmptyCal<PLUMBING> {
     type PublicKey = NoAlgorithms;
     type SharedSecret = NoAlgorithms;
 
-    fn generate_visible(&mut self, alg: Self::Algorithm) -> Self::VisibleSecretKey {
+    fn try_generate_visible(
+        &mut self,
+        alg: Self::Algorithm,
+    ) -> Result<Self::VisibleSecretKey, OperationError> {
         match alg {}
     }
 
-    fn shared_secret(
+    fn try_shared_secret(
         &mut self,
         private: &Self::SecretKey,
         _public: &Self::PublicKey,
-    ) -> Result<Self::SharedSecret, IncompatibleKeys> {
+    ) -> Result<Self::SharedSecret, TryError<IncompatibleKeys>> {
         match *private {}
     }
 
-    fn public_key(&mut self, private: &Self::SecretKey) -> Self::PublicKey {
+    fn try_public_key(
+        &mut self,
+        private: &Self::SecretKey,
+    ) -> Result<Self::PublicKey, OperationError> {
         match *private {}
     }
 
@@ -178,6 +293,251 @@ impl<const PLUMBING: bool> DhProvider for EmptyCal<PLUMBING> {
     ) -> Result<Self::PublicKey, dh::ImportError> {
         match alg {}
     }
+
+    #[allow(unreachable_code, reason = "needed to satisfy RPIT")]
+    fn export_publickey<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+        _format: PublicKeyFormat,
+    ) -> Result<impl AsRef<[u8]> + use<'p, PLUMBING>, UnsupportedFormat> {
+        match *public {};
+        Ok(&[])
+    }
+
+    fn import_publickey(
+        &mut self,
+        alg: Self::Algorithm,
+        _data: &[u8],
+        _format: PublicKeyFormat,
+    ) -> Result<Self::PublicKey, dh::ImportError> {
+        match alg {}
+    }
+}
+
+impl<const PLUMBING: bool> EccProvider for EmptyCal<PLUMBING> {
+    type Algorithm = NoAlgorithms;
+    type Point = NoAlgorithms;
+    type Scalar = NoAlgorithms;
+
+    fn import_point(
+        &mut self,
+        alg: Self::Algorithm,
+        _data: &[u8],
+    ) -> Result<Self::Point, ImportError> {
+        match alg {}
+    }
+
+    #[allow(unreachable_code, reason = "needed to satisfy RPIT")]
+    fn export_point<'p>(
+        &mut self,
+        point: &'p Self::Point,
+        _compressed: bool,
+    ) -> impl AsRef<[u8]> + use<'p, PLUMBING> {
+        match *point {};
+        &[]
+    }
+
+    fn import_scalar(
+        &mut self,
+        alg: Self::Algorithm,
+        _data: &[u8],
+    ) -> Result<Self::Scalar, ImportError> {
+        match alg {}
+    }
+
+    fn generator(&mut self, alg: Self::Algorithm) -> Self::Point {
+        match alg {}
+    }
+
+    fn identity(&mut self, alg: Self::Algorithm) -> Self::Point {
+        match alg {}
+    }
+
+    fn is_identity(&mut self, point: &Self::Point) -> bool {
+        match *point {}
+    }
+
+    fn negate(&mut self, point: &Self::Point) -> Self::Point {
+        match *point {}
+    }
+
+    fn add(&mut self, a: &Self::Point, _b: &Self::Point) -> Result<Self::Point, IncompatibleKeys> {
+        match *a {}
+    }
+
+    fn mul(
+        &mut self,
+        scalar: &Self::Scalar,
+        _point: &Self::Point,
+    ) -> Result<Self::Point, IncompatibleKeys> {
+        match *scalar {}
+    }
+}
+
+impl<const PLUMBING: bool> SignatureProvider for EmptyCal<PLUMBING> {
+    type Algorithm = NoAlgorithms;
+    type VisibleSecretKey = NoAlgorithms;
+    type SecretKey = NoAlgorithms;
+    type PublicKey = NoAlgorithms;
+    type SignState = NoAlgorithms;
+    type VerifyState = NoAlgorithms;
+    type Output = NoAlgorithms;
+
+    fn generate_visible(&mut self, alg: Self::Algorithm) -> Self::VisibleSecretKey {
+        match alg {}
+    }
+
+    #[allow(unreachable_code, reason = "needed to satisfy RPIT")]
+    fn export_secretkey_bytes<'s>(
+        &mut self,
+        secretkey: &'s Self::VisibleSecretKey,
+    ) -> impl AsRef<[u8]> + use<'s, PLUMBING> {
+        match *secretkey {};
+        &[]
+    }
+
+    fn import_secretkey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        _secret: &[u8],
+    ) -> Result<Self::VisibleSecretKey, dh::ImportError> {
+        match alg {}
+    }
+
+    #[allow(unreachable_code, reason = "needed to satisfy RPIT")]
+    fn export_publickey_bytes<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+    ) -> impl AsRef<[u8]> + use<'p, PLUMBING> {
+        match *public {};
+        &[]
+    }
+
+    fn import_publickey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        _data: &[u8],
+    ) -> Result<Self::PublicKey, dh::ImportError> {
+        match alg {}
+    }
+
+    fn public_key(&mut self, private: &Self::SecretKey) -> Self::PublicKey {
+        match *private {}
+    }
+
+    fn sign_init(&mut self, key: &Self::SecretKey) -> Self::SignState {
+        match *key {}
+    }
+
+    fn try_sign_update(
+        &mut self,
+        state: &mut Self::SignState,
+        _data: &[u8],
+    ) -> Result<(), OperationError> {
+        match *state {}
+    }
+
+    fn sign_finalize(&mut self, state: Self::SignState) -> Self::Output {
+        match state {}
+    }
+
+    fn verify_init(&mut self, key: &Self::PublicKey) -> Self::VerifyState {
+        match *key {}
+    }
+
+    fn try_verify_update(
+        &mut self,
+        state: &mut Self::VerifyState,
+        _data: &[u8],
+    ) -> Result<(), OperationError> {
+        match *state {}
+    }
+
+    fn verify_finalize(
+        &mut self,
+        state: Self::VerifyState,
+        _signature: &[u8],
+    ) -> Result<(), VerificationFailed> {
+        match state {}
+    }
+}
+
+impl<const PLUMBING: bool> KemProvider for EmptyCal<PLUMBING> {
+    type Algorithm = NoAlgorithms;
+    type VisibleSecretKey = NoAlgorithms;
+    type SecretKey = NoAlgorithms;
+    type PublicKey = NoAlgorithms;
+    type Ciphertext = NoAlgorithms;
+    type SharedSecret = NoAlgorithms;
+
+    fn try_generate_visible(
+        &mut self,
+        alg: Self::Algorithm,
+    ) -> Result<Self::VisibleSecretKey, OperationError> {
+        match alg {}
+    }
+
+    #[allow(unreachable_code, reason = "needed to satisfy RPIT")]
+    fn export_secretkey_bytes<'s>(
+        &mut self,
+        secretkey: &'s Self::VisibleSecretKey,
+    ) -> impl AsRef<[u8]> + use<'s, PLUMBING> {
+        match *secretkey {};
+        &[]
+    }
+
+    fn import_secretkey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        _secret: &[u8],
+    ) -> Result<Self::VisibleSecretKey, dh::ImportError> {
+        match alg {}
+    }
+
+    #[allow(unreachable_code, reason = "needed to satisfy RPIT")]
+    fn export_publickey_bytes<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+    ) -> impl AsRef<[u8]> + use<'p, PLUMBING> {
+        match *public {};
+        &[]
+    }
+
+    fn import_publickey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        _data: &[u8],
+    ) -> Result<Self::PublicKey, dh::ImportError> {
+        match alg {}
+    }
+
+    fn public_key(&mut self, private: &Self::SecretKey) -> Self::PublicKey {
+        match *private {}
+    }
+
+    fn try_encapsulate(
+        &mut self,
+        public: &Self::PublicKey,
+    ) -> Result<(Self::Ciphertext, Self::SharedSecret), OperationError> {
+        match *public {}
+    }
+
+    fn decapsulate(
+        &mut self,
+        private: &Self::SecretKey,
+        _ciphertext: &[u8],
+    ) -> Result<Self::SharedSecret, dh::ImportError> {
+        match *private {}
+    }
+
+    #[allow(unreachable_code, reason = "needed to satisfy RPIT")]
+    fn raw_secret_bytes<'s>(
+        &mut self,
+        secret: &'s Self::SharedSecret,
+    ) -> impl AsRef<[u8]> + use<'s, PLUMBING> {
+        match *secret {};
+        &[]
+    }
 }
 
 impl plumbing::Plumbing for EmptyCal<true> {}
@@ -205,6 +565,27 @@ impl plumbing::hash::Sha2Short for EmptyCal<true> {
     }
 }
 
+impl plumbing::hash::Sha2Long for EmptyCal<true> {
+    const SUPPORTED: bool = false;
+    const SEND_PADDING: bool = false;
+    const FIRST_CHUNK_SIZE: usize = 0;
+    const UPDATE_MULTICHUNK: bool = false;
+
+    type State = NoAlgorithms;
+
+    fn init(&mut self, _variant: plumbing::hash::Sha2LongVariant) -> Self::State {
+        panic!("user disregarded SUPPORTED=false")
+    }
+
+    fn update(&mut self, instance: &mut Self::State, _data: &[u8]) {
+        match *instance {}
+    }
+
+    fn finalize(&mut self, instance: Self::State, _last_chunk: &[u8], _target: &mut [u8]) {
+        match instance {}
+    }
+}
+
 /// Type which an implementation of [`Cal`] can use when it implements no algorithm for a
 /// particular provider.
 ///
@@ -227,6 +608,22 @@ impl AeadAlgorithm for NoAlgorithms {
     }
 }
 
+impl BlockCipherAlgorithm for NoAlgorithms {
+    fn key_length(&self) -> usize {
+        match *self {}
+    }
+}
+
+impl EccAlgorithm for NoAlgorithms {
+    fn coordinate_length(&self) -> usize {
+        match *self {}
+    }
+
+    fn scalar_length(&self) -> usize {
+        match *self {}
+    }
+}
+
 impl HashAlgorithm for NoAlgorithms {
     fn len(&self) -> usize {
         match *self {}
@@ -243,6 +640,22 @@ impl HmacAlgorithm for NoAlgorithms {
     }
 }
 
+impl KeyWrapAlgorithm for NoAlgorithms {
+    fn key_length(&self) -> usize {
+        match *self {}
+    }
+}
+
+impl MacAlgorithm for NoAlgorithms {
+    fn len(&self) -> usize {
+        match *self {}
+    }
+
+    fn key_length(&self) -> usize {
+        match *self {}
+    }
+}
+
 impl AsRef<[u8]> for NoAlgorithms {
     fn as_ref(&self) -> &[u8] {
         match *self {}
@@ -254,3 +667,26 @@ impl DhAlgorithm for NoAlgorithms {
         match *self {}
     }
 }
+
+impl SignatureAlgorithm for NoAlgorithms {
+    fn signature_length(&self) -> usize {
+        match *self {}
+    }
+}
+
+impl KemAlgorithm for NoAlgorithms {
+    fn shared_secret_length(&self) -> usize {
+        match *self {}
+    }
+
+    fn ciphertext_length(&self) -> usize {
+        match *self {}
+    }
+
+    fn public_key_length(&self) -> usize {
+        match *self {}
+    }
+}
+
+// Vacuously true; this allows extenders over an empty base to promise zeroization for their types.
+impl zeroize::ZeroizeOnDrop for NoAlgorithms {}
@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+/// Error indicating that an operation could not be performed.
+///
+/// This is produced by the `try_` methods of the providers (e.g.
+/// [`AeadProvider::try_encrypt_in_place()`][crate::AeadProvider::try_encrypt_in_place()]) in the
+/// situations in which their non-`try_` counterparts panic. Unlike cryptographic outcomes such as
+/// a failed decryption, these generally indicate that the inputs were not suitable for the
+/// algorithm, or that the implementation is in trouble.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OperationError {
+    /// An input (e.g. a key, nonce or tag) does not have the length the algorithm requires.
+    LengthMismatch,
+    /// A resource of limited size (e.g. an internal buffer or a hardware key slot) is exhausted.
+    ResourceExhausted,
+    /// The hardware reported a failure (e.g. a random number generator failed its health tests).
+    HardwareFault,
+}
+
+impl core::fmt::Display for OperationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            OperationError::LengthMismatch => "input length mismatch",
+            OperationError::ResourceExhausted => "resource exhausted",
+            OperationError::HardwareFault => "hardware fault",
+        })
+    }
+}
+
+impl core::error::Error for OperationError {}
+
+/// Error of a `try_` method whose non-`try_` counterpart already reports an error `E`.
+///
+/// For example, [`AeadProvider::try_decrypt_in_place()`][crate::AeadProvider::try_decrypt_in_place()]
+/// reports `TryError<DecryptionFailed>`: The non-`try_` method returns the `Failed` case and
+/// panics on the `Operation` case.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryError<E> {
+    /// The operation was performed, and produced its regular error.
+    Failed(E),
+    /// The operation could not be performed.
+    Operation(OperationError),
+}
+
+impl<E> TryError<E> {
+    /// Returns the regular error, panicking if the operation could not be performed.
+    ///
+    /// This is the behavior of the non-`try_` methods.
+    #[track_caller]
+    pub fn unwrap_failed(self) -> E {
+        match self {
+            TryError::Failed(e) => e,
+            TryError::Operation(e) => panic!("{e}"),
+        }
+    }
+}
+
+impl<E> From<OperationError> for TryError<E> {
+    fn from(e: OperationError) -> Self {
+        TryError::Operation(e)
+    }
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for TryError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TryError::Failed(e) => e.fmt(f),
+            TryError::Operation(e) => e.fmt(f),
+        }
+    }
+}
+
+impl<E: core::error::Error> core::error::Error for TryError<E> {}
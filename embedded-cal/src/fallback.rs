@@ -0,0 +1,645 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+//! A [`Cal`] that uses a hardware implementation, and repeats operations in a software
+//! implementation when the hardware fails at them.
+//!
+//! Like a [`Router`] with the default [`PreferA`], a [`Fallback`] serves each algorithm from the
+//! hardware if it has it, and from the software otherwise. In addition, where the hardware reports
+//! an [`OperationError`] (e.g. a faulty random number generator, or an AAD that exceeds a size
+//! limit), the operation is retried with the software's counterpart of the algorithm, which is
+//! found through the algorithm's COSE identifier. Every such retry is passed to a [`Recorder`].
+//!
+//! Operations are only retried where everything they need is available in software:
+//!
+//! * Generating DH keys, and starting hash operations (including one-shot hashing).
+//! * Only if key mirroring is enabled through the `MIRROR` parameter of [`Fallback`]: Loading AEAD
+//!   and HMAC keys, AEAD operations, and starting HMAC operations. Keys of these are then loaded
+//!   into both implementations, see [`Mirrored`].
+//!
+//! Failures in the middle of a hash or HMAC operation, or with a key that only exists in hardware,
+//! are reported as they are. The remaining interfaces do not report failures, and are only routed.
+
+use super::*;
+use accessor::*;
+use router::{PreferA, Routed, RoutedAlgorithm, RoutedVisible, Router};
+
+/// An operation that was retried in software.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Operation {
+    AeadLoad,
+    AeadEncrypt,
+    AeadDecrypt,
+    DhGenerate,
+    HashInit,
+    Hash,
+    HmacLoad,
+    HmacInit,
+}
+
+/// A hardware failure after which an operation was retried in software.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FallbackEvent {
+    pub operation: Operation,
+    /// The error the hardware reported.
+    pub error: OperationError,
+}
+
+/// Receiver of the [`FallbackEvent`]s of a [`Fallback`].
+///
+/// Implementations are expected to be quick, as they run in the course of the operation; they
+/// might count events, log them, or decide to take the hardware out of service.
+pub trait Recorder {
+    fn record(&mut self, event: FallbackEvent);
+}
+
+/// Discards all events.
+impl Recorder for () {
+    fn record(&mut self, _event: FallbackEvent) {}
+}
+
+/// [`Recorder`] that counts the events and keeps the most recent one.
+#[derive(Debug, Clone, Default)]
+pub struct EventLog {
+    count: usize,
+    last: Option<FallbackEvent>,
+}
+
+impl EventLog {
+    /// Number of operations that were retried in software.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The most recent event, if any.
+    pub fn last(&self) -> Option<FallbackEvent> {
+        self.last
+    }
+}
+
+impl Recorder for EventLog {
+    fn record(&mut self, event: FallbackEvent) {
+        self.count = self.count.saturating_add(1);
+        self.last = Some(event);
+    }
+}
+
+/// A key that was loaded into the hardware, the software, or both.
+///
+/// With key mirroring, keys of hardware algorithms are loaded into the software as well if it has
+/// the algorithm, so that operations with them can be retried there. This takes the space of both
+/// keys, and has the software hold the key material even if the hardware could have kept it
+/// inaccessible. Without mirroring, only public keys are held in both.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Mirrored<H, S> {
+    Both(H, S),
+    Hardware(H),
+    Software(S),
+}
+
+impl<H, S> Mirrored<H, S> {
+    pub fn hardware(&self) -> Option<&H> {
+        match self {
+            Mirrored::Both(h, _) | Mirrored::Hardware(h) => Some(h),
+            Mirrored::Software(_) => None,
+        }
+    }
+
+    pub fn software(&self) -> Option<&S> {
+        match self {
+            Mirrored::Both(_, s) | Mirrored::Software(s) => Some(s),
+            Mirrored::Hardware(_) => None,
+        }
+    }
+}
+
+impl<H: zeroize::ZeroizeOnDrop, S: zeroize::ZeroizeOnDrop> zeroize::ZeroizeOnDrop
+    for Mirrored<H, S>
+{
+}
+
+/// Passes an AAD generator on by reference, so that it can be used again for a retry.
+struct Reborrowed<'a, G>(&'a G);
+
+impl<G: AadGenerator> AadGenerator for Reborrowed<'_, G> {
+    fn items(&self) -> impl Iterator<Item = &[u8]> {
+        self.0.items()
+    }
+}
+
+/// A [`Cal`] that serves algorithms from the hardware implementation `H` where possible, and from
+/// the software implementation `S` otherwise or when the hardware fails.
+///
+/// See the [module level documentation][self] for which operations are retried.
+///
+/// If `MIRROR` is set, AEAD and HMAC keys are loaded into the software as well, so that their
+/// operations can be retried too. This gives up on the hardware keeping those keys inaccessible, and
+/// is therefore not the default.
+pub struct Fallback<H: Cal, S: Cal, R: Recorder = EventLog, const MIRROR: bool = false> {
+    router: Router<H, S, PreferA>,
+    recorder: R,
+}
+
+impl<H: Cal, S: Cal, R: Recorder + Default, const MIRROR: bool> Fallback<H, S, R, MIRROR> {
+    pub fn new(hardware: H, software: S) -> Self {
+        Self::with_recorder(hardware, software, R::default())
+    }
+}
+
+impl<H: Cal, S: Cal, R: Recorder, const MIRROR: bool> Fallback<H, S, R, MIRROR> {
+    pub fn with_recorder(hardware: H, software: S, recorder: R) -> Self {
+        Self {
+            router: Router::new(hardware, software),
+            recorder,
+        }
+    }
+
+    /// Accesses the hardware implementation, e.g. to use its functionality that is not part of
+    /// [`Cal`].
+    pub fn hardware(&mut self) -> &mut H {
+        self.router.a()
+    }
+
+    /// Accesses the software implementation, e.g. to use its functionality that is not part of
+    /// [`Cal`].
+    pub fn software(&mut self) -> &mut S {
+        self.router.b()
+    }
+
+    /// Accesses the recorder, e.g. to read the events an [`EventLog`] collected.
+    pub fn recorder(&mut self) -> &mut R {
+        &mut self.recorder
+    }
+
+    pub fn into_inner(self) -> (H, S, R) {
+        let (hardware, software) = self.router.into_inner();
+        (hardware, software, self.recorder)
+    }
+
+    /// Records that an operation is retried with `software`, or passes on the hardware's error if
+    /// there is nothing to retry with.
+    fn fall_back<T>(
+        &mut self,
+        operation: Operation,
+        error: OperationError,
+        software: Option<T>,
+    ) -> Result<T, OperationError> {
+        let software = software.ok_or(error)?;
+        self.recorder.record(FallbackEvent { operation, error });
+        Ok(software)
+    }
+
+    /// Loads a key into the hardware, and with `MIRROR` also into the software if that has the
+    /// algorithm.
+    ///
+    /// A key that the software fails to load is kept in hardware only; if the hardware fails, the
+    /// software's key is used on its own.
+    fn mirror<HK, SK>(
+        &mut self,
+        operation: Operation,
+        hardware: impl FnOnce(&mut H) -> Result<HK, OperationError>,
+        software: Option<impl FnOnce(&mut S) -> Result<SK, OperationError>>,
+    ) -> Result<Mirrored<HK, SK>, OperationError> {
+        if !MIRROR {
+            return hardware(self.router.a()).map(Mirrored::Hardware);
+        }
+        match hardware(self.router.a()) {
+            Ok(h) => Ok(match software.map(|load| load(self.router.b())) {
+                Some(Ok(s)) => Mirrored::Both(h, s),
+                _ => Mirrored::Hardware(h),
+            }),
+            Err(e) => {
+                let load = self.fall_back(operation, e, software)?;
+                Ok(Mirrored::Software(load(self.router.b())?))
+            }
+        }
+    }
+}
+
+fn software_aead<H: Cal, S: Cal>(alg: &AeadAlgorithmOf<H>) -> Option<AeadAlgorithmOf<S>> {
+    alg.cose_number()
+        .and_then(AeadAlgorithmOf::<S>::from_cose_number)
+}
+
+fn software_dh<H: Cal, S: Cal>(alg: &DhAlgorithmOf<H>) -> Option<DhAlgorithmOf<S>> {
+    alg.cose_ecdh().and_then(DhAlgorithmOf::<S>::from_cose_ecdh)
+}
+
+fn software_hash<H: Cal, S: Cal>(alg: &HashAlgorithmOf<H>) -> Option<HashAlgorithmOf<S>> {
+    alg.cose_number()
+        .and_then(HashAlgorithmOf::<S>::from_cose_number)
+        .or_else(|| alg.ni_id().and_then(HashAlgorithmOf::<S>::from_ni_id))
+}
+
+fn software_hmac<H: Cal, S: Cal>(alg: &HmacAlgorithmOf<H>) -> Option<HmacAlgorithmOf<S>> {
+    alg.cose_number()
+        .and_then(HmacAlgorithmOf::<S>::from_cose_number)
+}
+
+impl<H: Cal, S: Cal, R: Recorder, const MIRROR: bool> Cal for Fallback<H, S, R, MIRROR> {
+    type DhProvider = Self;
+    type AeadProvider = Self;
+    type HashProvider = Self;
+    type HmacProvider = Self;
+    type SignatureProvider = Router<H, S, PreferA>;
+    type KemProvider = Router<H, S, PreferA>;
+    type BlockCipherProvider = Router<H, S, PreferA>;
+    type EccProvider = Router<H, S, PreferA>;
+    type MacProvider = Router<H, S, PreferA>;
+    type KeyWrapProvider = Router<H, S, PreferA>;
+
+    fn dh(&mut self) -> &mut Self::DhProvider {
+        self
+    }
+
+    fn aead(&mut self) -> &mut Self::AeadProvider {
+        self
+    }
+
+    fn hash(&mut self) -> &mut Self::HashProvider {
+        self
+    }
+
+    fn hmac(&mut self) -> &mut Self::HmacProvider {
+        self
+    }
+
+    fn signature(&mut self) -> &mut Self::SignatureProvider {
+        &mut self.router
+    }
+
+    fn kem(&mut self) -> &mut Self::KemProvider {
+        &mut self.router
+    }
+
+    fn block_cipher(&mut self) -> &mut Self::BlockCipherProvider {
+        &mut self.router
+    }
+
+    fn ecc(&mut self) -> &mut Self::EccProvider {
+        &mut self.router
+    }
+
+    fn mac(&mut self) -> &mut Self::MacProvider {
+        &mut self.router
+    }
+
+    fn key_wrap(&mut self) -> &mut Self::KeyWrapProvider {
+        &mut self.router
+    }
+}
+
+impl<H: Cal, S: Cal, R: Recorder, const MIRROR: bool> AeadProvider for Fallback<H, S, R, MIRROR> {
+    type Algorithm = RoutedAlgorithm<AeadAlgorithmOf<H>, AeadAlgorithmOf<S>, PreferA>;
+    type Key = Mirrored<AeadKeyOf<H>, AeadKeyOf<S>>;
+    type Tag = Routed<AeadTagOf<H>, AeadTagOf<S>>;
+
+    fn try_load_from_keydata(
+        &mut self,
+        alg: Self::Algorithm,
+        key: &[u8],
+    ) -> Result<Self::Key, OperationError> {
+        match alg.into_routed() {
+            Routed::A(alg) => {
+                let software = software_aead::<H, S>(&alg);
+                self.mirror(
+                    Operation::AeadLoad,
+                    |h| h.aead().try_load_from_keydata(alg, key),
+                    software.map(|alg| move |s: &mut S| s.aead().try_load_from_keydata(alg, key)),
+                )
+            }
+            Routed::B(alg) => Ok(Mirrored::Software(
+                self.router.b().aead().try_load_from_keydata(alg, key)?,
+            )),
+        }
+    }
+
+    fn try_encrypt_in_place(
+        &mut self,
+        key: &Self::Key,
+        nonce: &[u8],
+        message: &mut [u8],
+        aad: impl AadGenerator,
+    ) -> Result<Self::Tag, OperationError> {
+        let software = match key {
+            Mirrored::Software(s) => s,
+            Mirrored::Hardware(h) | Mirrored::Both(h, _) => {
+                // On error, the message is left unmodified for the retry by the trait's contract.
+                match self.router.a().aead().try_encrypt_in_place(
+                    h,
+                    nonce,
+                    message,
+                    Reborrowed(&aad),
+                ) {
+                    Ok(tag) => return Ok(Routed::A(tag)),
+                    Err(e) => self.fall_back(Operation::AeadEncrypt, e, key.software())?,
+                }
+            }
+        };
+        Ok(Routed::B(
+            self.router
+                .b()
+                .aead()
+                .try_encrypt_in_place(software, nonce, message, aad)?,
+        ))
+    }
+
+    fn try_decrypt_in_place(
+        &mut self,
+        key: &Self::Key,
+        nonce: &[u8],
+        message: &mut [u8],
+        tag: &[u8],
+        aad: impl AadGenerator,
+    ) -> Result<(), TryError<DecryptionFailed>> {
+        let software = match key {
+            Mirrored::Software(s) => s,
+            Mirrored::Hardware(h) | Mirrored::Both(h, _) => {
+                match self.router.a().aead().try_decrypt_in_place(
+                    h,
+                    nonce,
+                    message,
+                    tag,
+                    Reborrowed(&aad),
+                ) {
+                    Err(TryError::Operation(e)) => {
+                        self.fall_back(Operation::AeadDecrypt, e, key.software())?
+                    }
+                    result => return result,
+                }
+            }
+        };
+        self.router
+            .b()
+            .aead()
+            .try_decrypt_in_place(software, nonce, message, tag, aad)
+    }
+}
+
+impl<H: Cal, S: Cal, R: Recorder, const MIRROR: bool> DhProvider for Fallback<H, S, R, MIRROR> {
+    type Algorithm = RoutedAlgorithm<DhAlgorithmOf<H>, DhAlgorithmOf<S>, PreferA>;
+    type VisibleSecretKey = RoutedVisible<DhVisibleSecretKeyOf<H>, DhVisibleSecretKeyOf<S>>;
+    type SecretKey = Routed<DhSecretKeyOf<H>, DhSecretKeyOf<S>>;
+    /// Public keys are imported into both implementations, so that they can be used with a secret
+    /// key that was generated in software after the hardware failed to.
+    type PublicKey = Mirrored<DhPublicKeyOf<H>, DhPublicKeyOf<S>>;
+    type SharedSecret = Routed<DhSharedSecretOf<H>, DhSharedSecretOf<S>>;
+
+    fn try_generate_visible(
+        &mut self,
+        alg: Self::Algorithm,
+    ) -> Result<Self::VisibleSecretKey, OperationError> {
+        let alg = match alg.into_routed() {
+            Routed::A(alg) => {
+                let software = software_dh::<H, S>(&alg);
+                match self.router.a().dh().try_generate_visible(alg) {
+                    Ok(key) => return Ok(RoutedVisible::A(key)),
+                    Err(e) => self.fall_back(Operation::DhGenerate, e, software)?,
+                }
+            }
+            Routed::B(alg) => alg,
+        };
+        Ok(RoutedVisible::B(
+            self.router.b().dh().try_generate_visible(alg)?,
+        ))
+    }
+
+    fn try_generate(&mut self, alg: Self::Algorithm) -> Result<Self::SecretKey, OperationError> {
+        let alg = match alg.into_routed() {
+            Routed::A(alg) => {
+                let software = software_dh::<H, S>(&alg);
+                match self.router.a().dh().try_generate(alg) {
+                    Ok(key) => return Ok(Routed::A(key)),
+                    Err(e) => self.fall_back(Operation::DhGenerate, e, software)?,
+                }
+            }
+            Routed::B(alg) => alg,
+        };
+        Ok(Routed::B(self.router.b().dh().try_generate(alg)?))
+    }
+
+    fn export_secretkey_bytes<'s>(
+        &mut self,
+        secretkey: &'s Self::VisibleSecretKey,
+    ) -> impl AsRef<[u8]> + use<'s, H, S, R, MIRROR> {
+        DhProvider::export_secretkey_bytes(&mut self.router, secretkey)
+    }
+
+    fn import_secretkey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        secret: &[u8],
+    ) -> Result<Self::VisibleSecretKey, ImportError> {
+        DhProvider::import_secretkey_bytes(&mut self.router, alg, secret)
+    }
+
+    fn export_publickey_bytes<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+    ) -> impl AsRef<[u8]> + use<'p, H, S, R, MIRROR> {
+        match public {
+            Mirrored::Both(p, _) | Mirrored::Hardware(p) => {
+                Routed::A(self.router.a().dh().export_publickey_bytes(p))
+            }
+            Mirrored::Software(p) => Routed::B(self.router.b().dh().export_publickey_bytes(p)),
+        }
+    }
+
+    fn import_publickey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::PublicKey, ImportError> {
+        Ok(match alg.into_routed() {
+            Routed::A(alg) => {
+                let software = software_dh::<H, S>(&alg);
+                let h = self.router.a().dh().import_publickey_bytes(alg, data)?;
+                match software.map(|alg| self.router.b().dh().import_publickey_bytes(alg, data)) {
+                    Some(Ok(s)) => Mirrored::Both(h, s),
+                    _ => Mirrored::Hardware(h),
+                }
+            }
+            Routed::B(alg) => {
+                Mirrored::Software(self.router.b().dh().import_publickey_bytes(alg, data)?)
+            }
+        })
+    }
+
+    fn export_publickey<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+        format: PublicKeyFormat,
+    ) -> Result<impl AsRef<[u8]> + use<'p, H, S, R, MIRROR>, UnsupportedFormat> {
+        Ok(match public {
+            Mirrored::Both(p, _) | Mirrored::Hardware(p) => {
+                Routed::A(self.router.a().dh().export_publickey(p, format)?)
+            }
+            Mirrored::Software(p) => Routed::B(self.router.b().dh().export_publickey(p, format)?),
+        })
+    }
+
+    fn import_publickey(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+        format: PublicKeyFormat,
+    ) -> Result<Self::PublicKey, ImportError> {
+        Ok(match alg.into_routed() {
+            Routed::A(alg) => {
+                let software = software_dh::<H, S>(&alg);
+                let h = self.router.a().dh().import_publickey(alg, data, format)?;
+                match software.map(|alg| self.router.b().dh().import_publickey(alg, data, format)) {
+                    Some(Ok(s)) => Mirrored::Both(h, s),
+                    _ => Mirrored::Hardware(h),
+                }
+            }
+            Routed::B(alg) => {
+                Mirrored::Software(self.router.b().dh().import_publickey(alg, data, format)?)
+            }
+        })
+    }
+
+    fn try_shared_secret(
+        &mut self,
+        private: &Self::SecretKey,
+        public: &Self::PublicKey,
+    ) -> Result<Self::SharedSecret, TryError<IncompatibleKeys>> {
+        Ok(match (private, public.hardware(), public.software()) {
+            (Routed::A(s), Some(p), _) => Routed::A(self.router.a().dh().try_shared_secret(s, p)?),
+            (Routed::B(s), _, Some(p)) => Routed::B(self.router.b().dh().try_shared_secret(s, p)?),
+            _ => return Err(TryError::Failed(IncompatibleKeys)),
+        })
+    }
+
+    fn try_public_key(
+        &mut self,
+        private: &Self::SecretKey,
+    ) -> Result<Self::PublicKey, OperationError> {
+        Ok(match private {
+            Routed::A(s) => Mirrored::Hardware(self.router.a().dh().try_public_key(s)?),
+            Routed::B(s) => Mirrored::Software(self.router.b().dh().try_public_key(s)?),
+        })
+    }
+
+    fn raw_secret_bytes<'s>(
+        &mut self,
+        secret: &'s Self::SharedSecret,
+    ) -> impl AsRef<[u8]> + use<'s, H, S, R, MIRROR> {
+        DhProvider::raw_secret_bytes(&mut self.router, secret)
+    }
+}
+
+impl<H: Cal, S: Cal, R: Recorder, const MIRROR: bool> HashProvider for Fallback<H, S, R, MIRROR> {
+    type Algorithm = RoutedAlgorithm<HashAlgorithmOf<H>, HashAlgorithmOf<S>, PreferA>;
+    type State = Routed<HashStateOf<H>, HashStateOf<S>>;
+    type Output = Routed<HashOutputOf<H>, HashOutputOf<S>>;
+    type XofReader = Routed<HashXofReaderOf<H>, HashXofReaderOf<S>>;
+
+    fn try_init(&mut self, algorithm: Self::Algorithm) -> Result<Self::State, OperationError> {
+        let alg = match algorithm.into_routed() {
+            Routed::A(alg) => {
+                let software = software_hash::<H, S>(&alg);
+                match self.router.a().hash().try_init(alg) {
+                    Ok(state) => return Ok(Routed::A(state)),
+                    Err(e) => self.fall_back(Operation::HashInit, e, software)?,
+                }
+            }
+            Routed::B(alg) => alg,
+        };
+        Ok(Routed::B(self.router.b().hash().try_init(alg)?))
+    }
+
+    fn try_update(
+        &mut self,
+        instance: &mut Self::State,
+        data: &[u8],
+    ) -> Result<(), OperationError> {
+        HashProvider::try_update(&mut self.router, instance, data)
+    }
+
+    fn try_finalize(&mut self, instance: Self::State) -> Result<Self::Output, OperationError> {
+        HashProvider::try_finalize(&mut self.router, instance)
+    }
+
+    fn try_hash(
+        &mut self,
+        algorithm: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::Output, OperationError> {
+        let alg = match algorithm.into_routed() {
+            Routed::A(alg) => {
+                let software = software_hash::<H, S>(&alg);
+                match self.router.a().hash().try_hash(alg, data) {
+                    Ok(output) => return Ok(Routed::A(output)),
+                    Err(e) => self.fall_back(Operation::Hash, e, software)?,
+                }
+            }
+            Routed::B(alg) => alg,
+        };
+        Ok(Routed::B(self.router.b().hash().try_hash(alg, data)?))
+    }
+
+    fn finalize_xof(&mut self, instance: Self::State) -> Result<Self::XofReader, Self::State> {
+        HashProvider::finalize_xof(&mut self.router, instance)
+    }
+
+    fn squeeze(&mut self, reader: &mut Self::XofReader, output: &mut [u8]) {
+        HashProvider::squeeze(&mut self.router, reader, output)
+    }
+}
+
+impl<H: Cal, S: Cal, R: Recorder, const MIRROR: bool> HmacProvider for Fallback<H, S, R, MIRROR> {
+    type Algorithm = RoutedAlgorithm<HmacAlgorithmOf<H>, HmacAlgorithmOf<S>, PreferA>;
+    type Key = Mirrored<HmacKeyOf<H>, HmacKeyOf<S>>;
+    type State = Routed<HmacStateOf<H>, HmacStateOf<S>>;
+    type Output = Routed<HmacOutputOf<H>, HmacOutputOf<S>>;
+
+    fn try_load_from_keydata(
+        &mut self,
+        algorithm: Self::Algorithm,
+        key: &[u8],
+    ) -> Result<Self::Key, OperationError> {
+        match algorithm.into_routed() {
+            Routed::A(alg) => {
+                let software = software_hmac::<H, S>(&alg);
+                self.mirror(
+                    Operation::HmacLoad,
+                    |h| h.hmac().try_load_from_keydata(alg, key),
+                    software.map(|alg| move |s: &mut S| s.hmac().try_load_from_keydata(alg, key)),
+                )
+            }
+            Routed::B(alg) => Ok(Mirrored::Software(
+                self.router.b().hmac().try_load_from_keydata(alg, key)?,
+            )),
+        }
+    }
+
+    fn try_init(&mut self, key: Self::Key) -> Result<Self::State, OperationError> {
+        let key = match key {
+            Mirrored::Hardware(h) => return Ok(Routed::A(self.router.a().hmac().try_init(h)?)),
+            Mirrored::Software(s) => s,
+            Mirrored::Both(h, s) => match self.router.a().hmac().try_init(h) {
+                Ok(state) => return Ok(Routed::A(state)),
+                Err(e) => self.fall_back(Operation::HmacInit, e, Some(s))?,
+            },
+        };
+        Ok(Routed::B(self.router.b().hmac().try_init(key)?))
+    }
+
+    fn try_update(&mut self, state: &mut Self::State, data: &[u8]) -> Result<(), OperationError> {
+        HmacProvider::try_update(&mut self.router, state, data)
+    }
+
+    fn try_finalize(&mut self, state: Self::State) -> Result<Self::Output, OperationError> {
+        HmacProvider::try_finalize(&mut self.router, state)
+    }
+
+    fn try_verify(
+        &mut self,
+        state: Self::State,
+        expected: &[u8],
+    ) -> Result<(), TryError<VerificationFailed>> {
+        HmacProvider::try_verify(&mut self.router, state, expected)
+    }
+}
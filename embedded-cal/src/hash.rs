@@ -1,15 +1,18 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
 
+use crate::OperationError;
+
 pub trait HashProvider {
     type Algorithm: HashAlgorithm;
     /// State in which is carried between rounds of feeding data.
     ///
-    /// As construction is not fallible, this can not be a handle into a limited pool. (Cf.
-    /// architecture requirements: "Incomplete operations should not block the system").
+    /// While [`.try_init()`][Self::try_init()] can report an exhausted resource, this should not be
+    /// a handle into a limited pool. (Cf. architecture requirements: "Incomplete operations should
+    /// not block the system").
     ///
     /// If hardware exists that can only hash efficiently in an internal state, this needs to be an
-    /// encapsulation of that state, as construction is not fallible. As this is likely a costly
+    /// encapsulation of that state. As this is likely a costly
     /// process, such implementations are encouraged to implement [`Self::hash`] in an optimized
     /// way. (Also, if such a hardware actually exists, please open an issue about it).
     // FIXME: Link to stable FAQ position once that is more website/documentation shape and not
@@ -25,15 +28,27 @@ pub trait HashProvider {
     // FIXME: Link to stable FAQ position once that is more website/documentation shape and not
     // just a GitHub Markdown document.
     type Output: AsRef<[u8]>;
+    /// Reader from which the output of an extendable-output function (XOF) is squeezed.
+    ///
+    /// Implementations that do not support any XOF can set this to
+    /// [`NoAlgorithms`][crate::empty::NoAlgorithms] and keep the default [`Self::finalize_xof`].
+    type XofReader: Sized;
 
     // Spitballing here to convey the idea and check whether ownership and lifetimes can work this
     // way. FIXME: Pick terminology from existing crates.
 
-    fn init(&mut self, algorithm: Self::Algorithm) -> Self::State;
-    fn update(&mut self, instance: &mut Self::State, data: &[u8]);
+    /// Starts a hash operation.
+    ///
+    /// # Errors
+    ///
+    /// Software implementations generally do not fail; hardware implementations may report
+    /// [`OperationError::HardwareFault`] in this and the other `try_` methods.
+    fn try_init(&mut self, algorithm: Self::Algorithm) -> Result<Self::State, OperationError>;
+    fn try_update(&mut self, instance: &mut Self::State, data: &[u8])
+    -> Result<(), OperationError>;
     // FIXME: (How) do we best carry around that the results's AsRef is exactly the .len() of the
     // algorithm?
-    fn finalize(&mut self, instance: Self::State) -> Self::Output;
+    fn try_finalize(&mut self, instance: Self::State) -> Result<Self::Output, OperationError>;
 
     /// Hash contiguous in-memory data in a single pass.
     ///
@@ -44,11 +59,62 @@ pub trait HashProvider {
     /// Optimized versions are expected to be rare, though, so don't go out of your way using it:
     /// Only buffer the full data, or create special cases for when there actually is just one item
     /// in an iterator, without testing and possibly consulting with the back-end authors first.
+    fn try_hash(
+        &mut self,
+        algorithm: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::Output, OperationError> {
+        let mut state = self.try_init(algorithm)?;
+        self.try_update(&mut state, data)?;
+        self.try_finalize(state)
+    }
+
+    /// Starts a hash operation, panicking where [`.try_init()`][Self::try_init()] errs.
+    fn init(&mut self, algorithm: Self::Algorithm) -> Self::State {
+        self.try_init(algorithm).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Feeds data into a hash operation, panicking where [`.try_update()`][Self::try_update()]
+    /// errs.
+    fn update(&mut self, instance: &mut Self::State, data: &[u8]) {
+        self.try_update(instance, data)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Finishes a hash operation, panicking where [`.try_finalize()`][Self::try_finalize()]
+    /// errs.
+    fn finalize(&mut self, instance: Self::State) -> Self::Output {
+        self.try_finalize(instance)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Hash contiguous in-memory data in a single pass, panicking where
+    /// [`.try_hash()`][Self::try_hash()] errs.
     fn hash(&mut self, algorithm: Self::Algorithm, data: &[u8]) -> Self::Output {
-        let mut state = self.init(algorithm);
-        self.update(&mut state, data);
-        self.finalize(state)
+        self.try_hash(algorithm, data)
+            .unwrap_or_else(|e| panic!("{e}"))
     }
+
+    /// Finishes feeding data into an extendable-output function (XOF) such as SHAKE128, and
+    /// produces a reader from which any amount of output can be squeezed.
+    ///
+    /// If the algorithm is not an XOF, or if the implementation can only produce its fixed-length
+    /// output through [`Self::finalize`], the unmodified `instance` is returned as the error, so
+    /// that it can still be finalized that way.
+    #[inline]
+    #[allow(
+        unused_variables,
+        reason = "Argument names are part of the documentation"
+    )]
+    fn finalize_xof(&mut self, instance: Self::State) -> Result<Self::XofReader, Self::State> {
+        Err(instance)
+    }
+
+    /// Squeezes the next `output.len()` bytes out of an XOF.
+    ///
+    /// Squeezing several times produces the same output as squeezing once into a buffer of the
+    /// combined length.
+    fn squeeze(&mut self, reader: &mut Self::XofReader, output: &mut [u8]);
 }
 
 /// A hash algorithm identifier.
@@ -112,6 +178,54 @@ pub trait HashAlgorithm: Sized + PartialEq + Eq + core::fmt::Debug + Clone {
     fn from_ni_name(name: &str) -> Option<Self> {
         None
     }
+
+    /// Selects a hash algorithm from its TLS HashAlgorithm value.
+    ///
+    /// The value comes from the ["TLS HashAlgorithm"
+    /// registry](https://www.iana.org/assignments/tls-parameters/tls-parameters.xhtml#tls-parameters-18)
+    /// maintained by IANA (e.g. 4 for SHA-256).
+    #[inline]
+    #[allow(
+        unused_variables,
+        reason = "Argument names are part of the documentation"
+    )]
+    fn from_tls_hash_algorithm(number: u8) -> Option<Self> {
+        None
+    }
+
+    /// Lists all algorithms supported by the implementation.
+    ///
+    /// Each algorithm is listed once, in the form in which the constructors (e.g.
+    /// [`Self::from_cose_number()`]) select it. Implementations that extend a base list the base's
+    /// algorithms along with their own, leaving out those that the constructors never select
+    /// because the other side takes precedence for the same identifiers.
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        core::iter::empty()
+    }
+
+    /// The algorithm's number in the COSE Algorithms registry, if it has one.
+    ///
+    /// This is the inverse of [`Self::from_cose_number()`]: For any algorithm listed in
+    /// [`Self::all()`] that has a number `n`, `Self::from_cose_number(n)` selects that algorithm.
+    #[inline]
+    fn cose_number(&self) -> Option<i128> {
+        None
+    }
+
+    /// The algorithm's Suite ID in the Named Information Hash Algorithm Registry, if it has one;
+    /// the inverse of [`Self::from_ni_id()`].
+    #[inline]
+    fn ni_id(&self) -> Option<u8> {
+        None
+    }
+
+    /// The algorithm's Hash Name String in the Named Information Hash Algorithm Registry, if it
+    /// has one; the inverse of [`Self::from_ni_name()`].
+    #[inline]
+    fn ni_name(&self) -> Option<&'static str> {
+        None
+    }
 }
 
 // FIXME: Should we introduce a feature to no build those all the time?
@@ -120,12 +234,59 @@ pub fn test_hash_algorithm_sha256<HA: HashAlgorithm>() {
     let cose_neg10 = HA::from_cose_number(-16);
     let ni_1 = HA::from_ni_id(1);
     let ni_named = HA::from_ni_name("sha-256");
+    let tls_4 = HA::from_tls_hash_algorithm(4);
 
     // Those are not *strictly* required, because there's no rule that any backend needs to
     // recognize all identifiers, but those should be widespread enough.
     assert_eq!(cose_neg10, ni_1);
     assert_eq!(cose_neg10, ni_named);
+    assert_eq!(cose_neg10, tls_4);
+
+    if let Some(sha256) = cose_neg10 {
+        assert_eq!(sha256.cose_number(), Some(-16));
+        assert_eq!(sha256.ni_id(), Some(1));
+        assert_eq!(sha256.ni_name(), Some("sha-256"));
+    }
 
     // When we actually want to test for test vectors here, we'll need to take a &mut Hashing
     // rather than just the algorithm.
 }
+
+pub fn test_hash_algorithm_sha3_256<HA: HashAlgorithm>() {
+    let ni_10 = HA::from_ni_id(10);
+    let ni_named = HA::from_ni_name("sha3-256");
+
+    assert_eq!(ni_10, ni_named);
+    assert_eq!(ni_10.map(|a| a.len()), Some(32));
+}
+
+pub fn test_hash_algorithm_shake256<HA: HashAlgorithm>() {
+    let cose_neg45 = HA::from_cose_number(-45);
+
+    // This is the fixed output length of COSE's SHAKE256; XOF output can be longer.
+    assert_eq!(cose_neg45.map(|a| a.len()), Some(64));
+}
+
+pub fn test_hash_algorithm_sha384<HA: HashAlgorithm>() {
+    let cose_neg43 = HA::from_cose_number(-43);
+    let ni_7 = HA::from_ni_id(7);
+    let ni_named = HA::from_ni_name("sha-384");
+    let tls_5 = HA::from_tls_hash_algorithm(5);
+
+    assert_eq!(cose_neg43, ni_7);
+    assert_eq!(cose_neg43, ni_named);
+    assert_eq!(cose_neg43, tls_5);
+    assert_eq!(cose_neg43.map(|a| a.len()), Some(48));
+}
+
+pub fn test_hash_algorithm_sha512<HA: HashAlgorithm>() {
+    let cose_neg44 = HA::from_cose_number(-44);
+    let ni_8 = HA::from_ni_id(8);
+    let ni_named = HA::from_ni_name("sha-512");
+    let tls_6 = HA::from_tls_hash_algorithm(6);
+
+    assert_eq!(cose_neg44, ni_8);
+    assert_eq!(cose_neg44, ni_named);
+    assert_eq!(cose_neg44, tls_6);
+    assert_eq!(cose_neg44.map(|a| a.len()), Some(64));
+}
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
 
-use crate::{HmacAlgorithm, HmacProvider};
+use crate::{HmacAlgorithm, HmacProvider, OperationError};
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum HkdfError {
@@ -17,12 +17,17 @@ pub enum HkdfError {
 /// This interface is currently provided by a single blanket implementation, as none of the
 /// compoenents initially considered (hardware accelerators) do anything special about it.
 ///
-/// This will be revisited when extending work on inextractable secrets to HKDF extraction output.
+/// The output of the extract step is a [`Self::Prk`], which is not readable unless explicitly
+/// requested through [`.hkdf_extract_exposed()`][Self::hkdf_extract_exposed()]. In the blanket
+/// implementation, this is the HMAC key type, and produced through
+/// [`HmacProvider::finalize_into_key()`]: Back-ends that keep HMAC keys internal (e.g. in key
+/// slots) thus also keep the PRK internal.
 ///
-/// Until then, this interface uses no associated types; after that, it will at least have a type
-/// for the extract step's output. It will likely *not* grow a dedicated Algorithm type, as HKDF is
-/// based on HMAC algorithms.
+/// It will likely *not* grow a dedicated Algorithm type, as HKDF is based on HMAC algorithms.
 pub trait HkdfProvider: HmacProvider {
+    /// A pseudorandom key, as produced by the extract step.
+    type Prk: Sized;
+
     /// HKDF-Extract (RFC 5869): returns a pseudorandom key.
     ///
     /// When `salt` is `None`, a zero-filled byte string of `HashLen` bytes is used
@@ -32,13 +37,44 @@ pub trait HkdfProvider: HmacProvider {
         alg: <Self as HmacProvider>::Algorithm,
         salt: Option<&[u8]>,
         ikm: &[u8],
+    ) -> Result<Self::Prk, HkdfError>;
+
+    /// HKDF-Extract (RFC 5869), returning the pseudorandom key as bytes.
+    ///
+    /// This is for protocols that need to persist or transport the PRK (e.g. for EDHOC's
+    /// `PRK_out` exporter); where possible, [`.hkdf_extract()`][Self::hkdf_extract()] should be
+    /// used.
+    fn hkdf_extract_exposed(
+        &mut self,
+        alg: <Self as HmacProvider>::Algorithm,
+        salt: Option<&[u8]>,
+        ikm: &[u8],
     ) -> Result<impl AsRef<[u8]> + use<Self>, HkdfError>;
 
+    /// Loads a pseudorandom key from bytes, e.g. after it has been obtained through
+    /// [`.hkdf_extract_exposed()`][Self::hkdf_extract_exposed()].
+    ///
+    /// # Errors
+    ///
+    /// This reports the same errors as [`HmacProvider::try_load_from_keydata()`].
+    fn try_load_prk(
+        &mut self,
+        alg: <Self as HmacProvider>::Algorithm,
+        prk: &[u8],
+    ) -> Result<Self::Prk, OperationError>;
+
+    /// Loads a pseudorandom key from bytes, panicking where
+    /// [`.try_load_prk()`][Self::try_load_prk()] errs.
+    fn load_prk(&mut self, alg: <Self as HmacProvider>::Algorithm, prk: &[u8]) -> Self::Prk {
+        self.try_load_prk(alg, prk)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
     /// HKDF-Expand (RFC 5869): fills `okm` with derived key material.
     fn hkdf_expand(
         &mut self,
         alg: <Self as HmacProvider>::Algorithm,
-        prk: &[u8],
+        prk: &Self::Prk,
         info: &[u8],
         okm: &mut [u8],
     ) -> Result<(), HkdfError>;
@@ -53,36 +89,51 @@ pub trait HkdfProvider: HmacProvider {
         okm: &mut [u8],
     ) -> Result<(), HkdfError> {
         let prk = self.hkdf_extract(alg.clone(), salt, ikm)?;
-        self.hkdf_expand(alg, prk.as_ref(), info, okm)
+        self.hkdf_expand(alg, &prk, info, okm)
     }
 }
 
 impl<H: HmacProvider> HkdfProvider for H {
+    type Prk = <H as HmacProvider>::Key;
+
     fn hkdf_extract(
         &mut self,
         alg: <Self as HmacProvider>::Algorithm,
         salt: Option<&[u8]>,
         ikm: &[u8],
+    ) -> Result<Self::Prk, HkdfError> {
+        let mut zero_salt = <<H as HmacProvider>::Algorithm as HmacAlgorithm>::MaxLenBuf::default();
+        let salt_bytes = salt_or_zero(&alg, salt, zero_salt.as_mut());
+        // PRK = HMAC-Hash(salt, IKM)
+        let mut state = self.init_with_keydata(alg.clone(), salt_bytes);
+        self.update(&mut state, ikm);
+        Ok(self.finalize_into_key(alg, state))
+    }
+
+    fn hkdf_extract_exposed(
+        &mut self,
+        alg: <Self as HmacProvider>::Algorithm,
+        salt: Option<&[u8]>,
+        ikm: &[u8],
     ) -> Result<impl AsRef<[u8]> + use<H>, HkdfError> {
-        // When salt is absent, RFC 5869 uses HashLen zero bytes as the HMAC key.
-        // Buffer covers standard algorithms up to SHA-512 (64 bytes).
-        // Ideally this would be H::Algorithm::MAX_OUTPUT_LEN once const_trait_impl stabilises.
         let mut zero_salt = <<H as HmacProvider>::Algorithm as HmacAlgorithm>::MaxLenBuf::default();
-        let zero_salt = zero_salt.as_mut();
-        let hash_len = alg.len();
-        debug_assert!(
-            hash_len <= zero_salt.len(),
-            "algorithm length is longer than type's announced maximum HMAC length"
-        );
-        let salt_bytes = salt.unwrap_or(&zero_salt[..hash_len]);
+        let salt_bytes = salt_or_zero(&alg, salt, zero_salt.as_mut());
         // PRK = HMAC-Hash(salt, IKM)
         Ok(self.hmac_with_keydata(alg, salt_bytes, ikm))
     }
 
-    fn hkdf_expand(
+    fn try_load_prk(
         &mut self,
         alg: <Self as HmacProvider>::Algorithm,
         prk: &[u8],
+    ) -> Result<Self::Prk, OperationError> {
+        self.try_load_from_keydata(alg, prk)
+    }
+
+    fn hkdf_expand(
+        &mut self,
+        alg: <Self as HmacProvider>::Algorithm,
+        prk: &Self::Prk,
         info: &[u8],
         okm: &mut [u8],
     ) -> Result<(), HkdfError> {
@@ -103,7 +154,7 @@ impl<H: HmacProvider> HkdfProvider for H {
             // counter is 1-based block index; pos/hash_len+1 <= 255 enforced above
             let counter = (pos / hash_len + 1) as u8;
             // T(i) = HMAC-Hash(PRK, T(i-1) || info || i)
-            let mut state = self.init_with_keydata(alg.clone(), prk);
+            let mut state = self.init(prk.clone());
             if t_len > 0 {
                 HmacProvider::update(self, &mut state, &t[..t_len]);
             }
@@ -126,3 +177,20 @@ impl<H: HmacProvider> HkdfProvider for H {
         Ok(())
     }
 }
+
+/// Selects the salt for HKDF-Extract.
+///
+/// When salt is absent, RFC 5869 uses HashLen zero bytes as the HMAC key; `zero_salt` is a buffer
+/// of zeros for that purpose (typically the algorithm's `MaxLenBuf`).
+fn salt_or_zero<'a, A: HmacAlgorithm>(
+    alg: &A,
+    salt: Option<&'a [u8]>,
+    zero_salt: &'a [u8],
+) -> &'a [u8] {
+    let hash_len = alg.len();
+    debug_assert!(
+        hash_len <= zero_salt.len(),
+        "algorithm length is longer than type's announced maximum HMAC length"
+    );
+    salt.unwrap_or(&zero_salt[..hash_len])
+}
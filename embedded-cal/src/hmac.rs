@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 // SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
 
+use crate::{OperationError, TryError, VerificationFailed};
+
 pub trait HmacProvider {
     type Algorithm: HmacAlgorithm;
     /// A nascent state that .
@@ -18,11 +20,72 @@ pub trait HmacProvider {
         self.init(key)
     }
     /// Initializes a key from raw bytes.
-    fn load_from_keydata(&mut self, algorithm: Self::Algorithm, key: &[u8]) -> Self::Key;
+    ///
+    /// # Errors
+    ///
+    /// HMAC accepts keys of any length, but implementations with limited key storage may report
+    /// [`OperationError::ResourceExhausted`] for long keys. Hardware implementations may report
+    /// [`OperationError::HardwareFault`] in this and the other `try_` methods.
+    fn try_load_from_keydata(
+        &mut self,
+        algorithm: Self::Algorithm,
+        key: &[u8],
+    ) -> Result<Self::Key, OperationError>;
     /// Starts an HMAC operation.
-    fn init(&mut self, key: Self::Key) -> Self::State;
-    fn update(&mut self, state: &mut Self::State, data: &[u8]);
-    fn finalize(&mut self, state: Self::State) -> Self::Output;
+    fn try_init(&mut self, key: Self::Key) -> Result<Self::State, OperationError>;
+    fn try_update(&mut self, state: &mut Self::State, data: &[u8]) -> Result<(), OperationError>;
+    fn try_finalize(&mut self, state: Self::State) -> Result<Self::Output, OperationError>;
+
+    /// Initializes a key from raw bytes, panicking where
+    /// [`.try_load_from_keydata()`][Self::try_load_from_keydata()] errs.
+    fn load_from_keydata(&mut self, algorithm: Self::Algorithm, key: &[u8]) -> Self::Key {
+        self.try_load_from_keydata(algorithm, key)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+    /// Starts an HMAC operation, panicking where [`.try_init()`][Self::try_init()] errs.
+    fn init(&mut self, key: Self::Key) -> Self::State {
+        self.try_init(key).unwrap_or_else(|e| panic!("{e}"))
+    }
+    /// Feeds data into an HMAC operation, panicking where [`.try_update()`][Self::try_update()]
+    /// errs.
+    fn update(&mut self, state: &mut Self::State, data: &[u8]) {
+        self.try_update(state, data)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+    /// Finishes an HMAC operation, panicking where [`.try_finalize()`][Self::try_finalize()]
+    /// errs.
+    fn finalize(&mut self, state: Self::State) -> Self::Output {
+        self.try_finalize(state).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Finishes an HMAC operation, and uses its output as a key for the same algorithm.
+    ///
+    /// This is how HKDF derives its pseudorandom key (see
+    /// [`HkdfProvider::hkdf_extract()`][crate::HkdfProvider::hkdf_extract()]). The provided
+    /// implementation passes the output through an [`Self::Output`]; implementations that can keep
+    /// keys inaccessible (e.g. in key slots) should override it so that the key never becomes
+    /// readable, and extenders should forward to their base for algorithms they do not implement
+    /// themselves.
+    ///
+    /// # Errors
+    ///
+    /// This reports the errors of [`.try_finalize()`][Self::try_finalize()] and
+    /// [`.try_load_from_keydata()`][Self::try_load_from_keydata()].
+    fn try_finalize_into_key(
+        &mut self,
+        algorithm: Self::Algorithm,
+        state: Self::State,
+    ) -> Result<Self::Key, OperationError> {
+        let output = self.try_finalize(state)?;
+        self.try_load_from_keydata(algorithm, output.as_ref())
+    }
+
+    /// Finishes an HMAC operation into a key, panicking where
+    /// [`.try_finalize_into_key()`][Self::try_finalize_into_key()] errs.
+    fn finalize_into_key(&mut self, algorithm: Self::Algorithm, state: Self::State) -> Self::Key {
+        self.try_finalize_into_key(algorithm, state)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
 
     /// Compute HMAC over contiguous in-memory data in a single pass, based on a key directly
     /// entered as bytes.
@@ -39,6 +102,82 @@ pub trait HmacProvider {
         self.update(&mut state, data);
         self.finalize(state)
     }
+
+    /// Finishes an HMAC operation, and checks its output against an expected tag.
+    ///
+    /// The tag may be truncated, in which case it is compared against the leading bytes of the
+    /// output (as in HMAC 256/64). Its length is not checked against any protocol, so callers
+    /// need to ensure that a received tag has the length they expect; the check only rejects
+    /// tags that are longer than the output or shorter than [`MIN_HMAC_TAG_LEN`].
+    ///
+    /// The provided implementation compares in constant time using [`verify_hmac_tag()`].
+    /// Implementations that can check tags without revealing the output (e.g. hardware that
+    /// compares internally) should override it; extenders should forward to their base for
+    /// algorithms they do not implement themselves.
+    ///
+    /// # Errors
+    ///
+    /// Besides [`VerificationFailed`], this reports the same errors as
+    /// [`.try_finalize()`][Self::try_finalize()].
+    fn try_verify(
+        &mut self,
+        state: Self::State,
+        expected: &[u8],
+    ) -> Result<(), TryError<VerificationFailed>> {
+        let output = self.try_finalize(state)?;
+        verify_hmac_tag(output.as_ref(), expected).map_err(TryError::Failed)
+    }
+
+    /// Finishes an HMAC operation and checks its output, panicking where
+    /// [`.try_verify()`][Self::try_verify()] can not perform the operation.
+    fn verify(&mut self, state: Self::State, expected: &[u8]) -> Result<(), VerificationFailed> {
+        self.try_verify(state, expected)
+            .map_err(TryError::unwrap_failed)
+    }
+
+    /// Checks an HMAC over contiguous in-memory data in a single pass, based on a key directly
+    /// entered as bytes.
+    ///
+    /// This is a shortcut for [`self.init_with_keydata(…)`][Self::init_with_keydata()] /
+    /// [`self.update(…)`][Self::update()] / [`self.verify(…)`][Self::verify()].
+    fn verify_with_keydata(
+        &mut self,
+        algorithm: Self::Algorithm,
+        key: &[u8],
+        data: &[u8],
+        expected: &[u8],
+    ) -> Result<(), VerificationFailed> {
+        let mut state = self.init_with_keydata(algorithm, key);
+        self.update(&mut state, data);
+        self.verify(state, expected)
+    }
+}
+
+/// Shortest tag accepted by [`verify_hmac_tag()`], in bytes.
+///
+/// This is the length of the shortest truncation in use (HMAC 256/64 in COSE); it keeps a tag
+/// that is cut short in transit from passing with only a few bytes checked.
+pub const MIN_HMAC_TAG_LEN: usize = 8;
+
+/// Compares a MAC output with a (possibly truncated) expected tag in constant time.
+///
+/// Only the lengths of the tags influence the time this takes, not their content.
+///
+/// # Errors
+///
+/// … are produced if the tag does not match the leading bytes of the output, or if it is longer
+/// than the output or shorter than [`MIN_HMAC_TAG_LEN`].
+pub fn verify_hmac_tag(output: &[u8], expected: &[u8]) -> Result<(), VerificationFailed> {
+    use subtle::ConstantTimeEq;
+
+    if expected.len() < MIN_HMAC_TAG_LEN || expected.len() > output.len() {
+        return Err(VerificationFailed);
+    }
+    if output[..expected.len()].ct_eq(expected).into() {
+        Ok(())
+    } else {
+        Err(VerificationFailed)
+    }
 }
 
 /// An HMAC algorithm identifier.
@@ -83,6 +222,55 @@ pub trait HmacAlgorithm: Sized + PartialEq + Eq + core::fmt::Debug + Clone {
     fn from_cose_number(number: impl Into<i128>) -> Option<Self> {
         None
     }
+
+    /// Selects HMAC over the hash with the given TLS HashAlgorithm value, with an untruncated
+    /// output.
+    ///
+    /// The value comes from the ["TLS HashAlgorithm"
+    /// registry](https://www.iana.org/assignments/tls-parameters/tls-parameters.xhtml#tls-parameters-18)
+    /// maintained by IANA (e.g. 4 for HMAC-SHA256, as used by the TLS 1.2 PRF and the TLS 1.3 key
+    /// schedule of SHA-256 cipher suites).
+    #[inline]
+    #[allow(
+        unused_variables,
+        reason = "Argument names are part of the documentation"
+    )]
+    fn from_tls_hash_algorithm(number: u8) -> Option<Self> {
+        None
+    }
+
+    /// Lists all algorithms supported by the implementation, as selected by
+    /// [`Self::from_cose_number()`].
+    ///
+    /// See [`HashAlgorithm::all()`][crate::HashAlgorithm::all()] for how implementations that
+    /// extend a base compose the list.
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        core::iter::empty()
+    }
+
+    /// The algorithm's number in the COSE Algorithms registry, if it has one; the inverse of
+    /// [`Self::from_cose_number()`].
+    #[inline]
+    fn cose_number(&self) -> Option<i128> {
+        None
+    }
+
+    /// The algorithm's Suite ID in the Named Information Hash Algorithm Registry, if it has one.
+    ///
+    /// That registry (RFC 6920) only names hash algorithms, so no HMAC algorithm has an entry yet;
+    /// this exists for symmetry with [`HashAlgorithm::ni_id()`][crate::HashAlgorithm::ni_id()].
+    #[inline]
+    fn ni_id(&self) -> Option<u8> {
+        None
+    }
+
+    /// The algorithm's Hash Name String in the Named Information Hash Algorithm Registry, if it
+    /// has one; like [`Self::ni_id()`], this is `None` for all HMAC algorithms as of now.
+    #[inline]
+    fn ni_name(&self) -> Option<&'static str> {
+        None
+    }
 }
 
 pub fn test_hmac_algorithm_hmacsha256<HA: HmacAlgorithm>() {
@@ -92,4 +280,6 @@ pub fn test_hmac_algorithm_hmacsha256<HA: HmacAlgorithm>() {
         "HMAC 256/256 must be recognised by COSE number 5"
     );
     assert_eq!(cose_5.as_ref().map(|a| a.len()), Some(32));
+    assert_eq!(HA::from_tls_hash_algorithm(4), cose_5);
+    assert_eq!(cose_5.and_then(|a| a.cose_number()), Some(5));
 }
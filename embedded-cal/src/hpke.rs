@@ -0,0 +1,892 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+//! Hybrid Public Key Encryption (HPKE, [RFC9180](https://datatracker.ietf.org/doc/html/rfc9180))
+//! on top of any [`Cal`].
+//!
+//! This implements the Base and Auth modes with the DHKEM(P-256, HKDF-SHA256) and DHKEM(X25519,
+//! HKDF-SHA256) KEMs; the KDF and AEAD are whatever the `Cal` provides for the respective
+//! identifiers. The PSK modes and the export-only AEAD are not implemented.
+//!
+//! Unlike the rest of this crate, the operations here are not provided by a trait on the `Cal`:
+//! They are composed from [`DhProvider`], [`HmacProvider`] and [`AeadProvider`], and run on any
+//! `Cal` that has the algorithms of the selected [`Suite`].
+//!
+//! # Serialized public keys
+//!
+//! Public keys are serialized through [`DhProvider::export_publickey()`], which produces the
+//! actual `y` coordinate of P-256 keys that were generated or derived here. A P-256 key that was
+//! imported in compact form, however, only gets one of the two candidate `y` coordinates, which
+//! may be the negation of the one the key was generated with. This is still a valid public key
+//! that results in the same shared secrets, but as HPKE binds the exact bytes of public keys into
+//! the key schedule, public keys received from elsewhere need to be passed around in their
+//! serialized form. For that reason, all public keys of other parties are passed in as bytes, and
+//! [`KeyPair`] carries the serialized public key its owner has published.
+
+use crate::accessor::*;
+use crate::util::WipeOnDrop;
+use crate::{
+    AadGenerator, AeadAlgorithm, AeadProvider, Cal, DhAlgorithm, DhProvider, HmacAlgorithm,
+    HmacProvider, PublicKeyFormat,
+};
+use zeroize::Zeroizing;
+
+const VERSION_LABEL: &[u8] = b"HPKE-v1";
+
+const MODE_BASE: u8 = 0x00;
+const MODE_AUTH: u8 = 0x02;
+
+/// Largest `Nenc` / `Npk` of the supported KEMs (an uncompressed P-256 point).
+const MAX_ENC_LEN: usize = 65;
+/// Largest `Ndh` of the supported KEMs.
+const MAX_DH_LEN: usize = 32;
+/// `Nsecret` of the supported KEMs.
+const SECRET_LEN: usize = 32;
+/// Largest `Nh` of the KDFs (HKDF-SHA512).
+const MAX_HASH_LEN: usize = 64;
+/// Largest `Nk` of the AEADs.
+const MAX_KEY_LEN: usize = 32;
+/// `Nn` of all the AEADs.
+const NONCE_LEN: usize = 12;
+
+/// Errors from HPKE operations, named after the errors of RFC9180 Section 7.1.
+#[derive(Debug, PartialEq, Eq)]
+pub enum HpkeError {
+    /// A serialized public key (`enc`, `pkR` or `pkS`) is malformed or not on the curve.
+    DeserializeError,
+    /// A DH operation failed or produced the all-zero output.
+    ValidationError,
+    /// The ciphertext failed to decrypt.
+    OpenError,
+    /// The context's sequence number is exhausted.
+    MessageLimitReached,
+    /// No valid secret key could be derived from the input keying material.
+    DeriveKeyPairError,
+    /// Requested output length exceeds 255 × `Nh` bytes.
+    OutputTooLong,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kem {
+    DhkemP256HkdfSha256,
+    DhkemX25519HkdfSha256,
+}
+
+impl Kem {
+    fn id(&self) -> u16 {
+        match self {
+            Kem::DhkemP256HkdfSha256 => 0x0010,
+            Kem::DhkemX25519HkdfSha256 => 0x0020,
+        }
+    }
+
+    fn cose_curve(&self) -> i8 {
+        match self {
+            Kem::DhkemP256HkdfSha256 => 1,
+            Kem::DhkemX25519HkdfSha256 => 4,
+        }
+    }
+
+    fn enc_length(&self) -> usize {
+        match self {
+            Kem::DhkemP256HkdfSha256 => 65,
+            Kem::DhkemX25519HkdfSha256 => 32,
+        }
+    }
+
+    /// The format of `SerializePublicKey` (RFC9180 Section 7.1.1).
+    fn public_key_format(&self) -> PublicKeyFormat {
+        match self {
+            Kem::DhkemP256HkdfSha256 => PublicKeyFormat::Uncompressed,
+            Kem::DhkemX25519HkdfSha256 => PublicKeyFormat::Compact,
+        }
+    }
+}
+
+/// A public key in the KEM's `SerializePublicKey` format (also used for `enc`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SerializedPublicKey {
+    data: [u8; MAX_ENC_LEN],
+    len: usize,
+}
+
+impl SerializedPublicKey {
+    /// Wraps the bytes of a serialized public key, e.g. one that was received from a peer.
+    ///
+    /// This only checks the length against the largest supported KEM; the content is checked when
+    /// the key is used.
+    pub fn from_slice(data: &[u8]) -> Result<Self, HpkeError> {
+        let mut result = Self {
+            data: [0; MAX_ENC_LEN],
+            len: data.len(),
+        };
+        result
+            .data
+            .get_mut(..data.len())
+            .ok_or(HpkeError::DeserializeError)?
+            .copy_from_slice(data);
+        Ok(result)
+    }
+}
+
+impl AsRef<[u8]> for SerializedPublicKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.data[..self.len]
+    }
+}
+
+/// A secret key along with the serialized public key under which its owner is known to peers.
+pub struct KeyPair<C: Cal> {
+    pub secret: DhSecretKeyOf<C>,
+    pub public: SerializedPublicKey,
+}
+
+/// A cipher suite, consisting of a KEM, a KDF and an AEAD algorithm.
+pub struct Suite<C: Cal> {
+    kem: Kem,
+    kdf_id: u16,
+    aead_id: u16,
+    dh: DhAlgorithmOf<C>,
+    kem_hmac: HmacAlgorithmOf<C>,
+    hmac: HmacAlgorithmOf<C>,
+    aead: AeadAlgorithmOf<C>,
+}
+
+impl<C: Cal> Clone for Suite<C> {
+    // This is the default implementation, but we can't derive it because C is not Clone.
+    fn clone(&self) -> Self {
+        Self {
+            kem: self.kem,
+            kdf_id: self.kdf_id,
+            aead_id: self.aead_id,
+            dh: self.dh.clone(),
+            kem_hmac: self.kem_hmac.clone(),
+            hmac: self.hmac.clone(),
+            aead: self.aead.clone(),
+        }
+    }
+}
+
+impl<C: Cal> Suite<C> {
+    /// Selects a suite from its identifiers in the IANA [HPKE
+    /// registries](https://www.iana.org/assignments/hpke/hpke.xhtml).
+    ///
+    /// Returns `None` if the identifiers are not known to this implementation, or if the `Cal`
+    /// does not support any of the algorithms.
+    pub fn new(kem_id: u16, kdf_id: u16, aead_id: u16) -> Option<Self> {
+        let kem = match kem_id {
+            0x0010 => Kem::DhkemP256HkdfSha256,
+            0x0020 => Kem::DhkemX25519HkdfSha256,
+            _ => return None,
+        };
+        let dh = DhAlgorithmOf::<C>::from_cose_ecdh(kem.cose_curve())?;
+        if dh.output_length() > MAX_DH_LEN {
+            return None;
+        }
+        // Both KEMs use HKDF-SHA256.
+        let kem_hmac = HmacAlgorithmOf::<C>::from_cose_number(5)?;
+
+        let hmac = HmacAlgorithmOf::<C>::from_cose_number(match kdf_id {
+            0x0001 => 5,
+            0x0002 => 6,
+            0x0003 => 7,
+            _ => return None,
+        })?;
+        if hmac.len() > MAX_HASH_LEN {
+            return None;
+        }
+
+        let aead = AeadAlgorithmOf::<C>::from_cose_number(match aead_id {
+            0x0001 => 1,
+            0x0002 => 3,
+            0x0003 => 24,
+            _ => return None,
+        })?;
+        if aead.nonce_length() != NONCE_LEN || aead.key_length() > MAX_KEY_LEN {
+            return None;
+        }
+
+        Some(Self {
+            kem,
+            kdf_id,
+            aead_id,
+            dh,
+            kem_hmac,
+            hmac,
+            aead,
+        })
+    }
+
+    /// Length of `enc` (and of serialized public keys) in bytes.
+    pub fn enc_length(&self) -> usize {
+        self.kem.enc_length()
+    }
+
+    fn kem_suite_id(&self) -> [u8; 5] {
+        let [k0, k1] = self.kem.id().to_be_bytes();
+        [b'K', b'E', b'M', k0, k1]
+    }
+
+    fn hpke_suite_id(&self) -> [u8; 10] {
+        let [k0, k1] = self.kem.id().to_be_bytes();
+        let [f0, f1] = self.kdf_id.to_be_bytes();
+        let [a0, a1] = self.aead_id.to_be_bytes();
+        [b'H', b'P', b'K', b'E', k0, k1, f0, f1, a0, a1]
+    }
+
+    /// Generates a fresh key pair.
+    pub fn generate_key_pair(&self, cal: &mut C) -> KeyPair<C> {
+        let secret = cal.dh().generate(self.dh.clone());
+        let public = cal.dh().public_key(&secret);
+        let public = self.serialize_public_key(cal, &public);
+        KeyPair { secret, public }
+    }
+
+    /// Deterministically derives a key pair from input keying material (`DeriveKeyPair`).
+    pub fn derive_key_pair(&self, cal: &mut C, ikm: &[u8]) -> Result<KeyPair<C>, HpkeError> {
+        let suite_id = self.kem_suite_id();
+        let dkp_prk = labeled_extract(
+            cal,
+            self.kem_hmac.clone(),
+            &suite_id,
+            b"",
+            b"dkp_prk",
+            &[ikm],
+        );
+
+        let mut candidate = Zeroizing::new([0u8; 32]);
+        let secret = match self.kem {
+            Kem::DhkemX25519HkdfSha256 => {
+                labeled_expand(
+                    cal,
+                    self.kem_hmac.clone(),
+                    &suite_id,
+                    dkp_prk.as_ref(),
+                    b"sk",
+                    &[],
+                    &mut candidate[..],
+                )?;
+                cal.dh()
+                    .import_secretkey_bytes(self.dh.clone(), &candidate[..])
+                    .map_err(|_| HpkeError::DeriveKeyPairError)?
+            }
+            Kem::DhkemP256HkdfSha256 => {
+                // The bitmask for P-256 is 0xff, so candidates are used as they are; the import
+                // rejects those that are zero or exceed the group order.
+                let mut found = None;
+                for counter in 0..=255u8 {
+                    labeled_expand(
+                        cal,
+                        self.kem_hmac.clone(),
+                        &suite_id,
+                        dkp_prk.as_ref(),
+                        b"candidate",
+                        &[&[counter]],
+                        &mut candidate[..],
+                    )?;
+                    if let Ok(secret) = cal
+                        .dh()
+                        .import_secretkey_bytes(self.dh.clone(), &candidate[..])
+                    {
+                        found = Some(secret);
+                        break;
+                    }
+                }
+                found.ok_or(HpkeError::DeriveKeyPairError)?
+            }
+        };
+
+        let secret = secret.into();
+        let public = cal.dh().public_key(&secret);
+        let public = self.serialize_public_key(cal, &public);
+        Ok(KeyPair { secret, public })
+    }
+
+    /// Serializes a public key (`SerializePublicKey`).
+    ///
+    /// See the [module level documentation][self] for caveats on P-256 keys.
+    pub fn serialize_public_key(
+        &self,
+        cal: &mut C,
+        public: &DhPublicKeyOf<C>,
+    ) -> SerializedPublicKey {
+        let exported = cal
+            .dh()
+            .export_publickey(public, self.kem.public_key_format())
+            .expect("the KEM's curve has this format");
+        SerializedPublicKey::from_slice(exported.as_ref())
+            .expect("serialized public keys fit the buffer")
+    }
+
+    fn deserialize_public_key(
+        &self,
+        cal: &mut C,
+        data: &[u8],
+    ) -> Result<DhPublicKeyOf<C>, HpkeError> {
+        if data.len() != self.kem.enc_length() {
+            return Err(HpkeError::DeserializeError);
+        }
+        cal.dh()
+            .import_publickey(self.dh.clone(), data, self.kem.public_key_format())
+            .map_err(|_| HpkeError::DeserializeError)
+    }
+
+    /// Runs DH, writing the shared secret into `out`.
+    fn dh(
+        &self,
+        cal: &mut C,
+        secret: &DhSecretKeyOf<C>,
+        public: &DhPublicKeyOf<C>,
+        out: &mut [u8],
+    ) -> Result<(), HpkeError> {
+        let cal = cal.dh();
+        let shared = cal
+            .shared_secret(secret, public)
+            .map_err(|_| HpkeError::ValidationError)?;
+        let raw = cal.raw_secret_bytes(&shared);
+        out.copy_from_slice(raw.as_ref());
+        // Only relevant for X25519 (RFC9180 Section 7.1.4); P-256 points were validated on import.
+        if out.iter().all(|b| *b == 0) {
+            return Err(HpkeError::ValidationError);
+        }
+        Ok(())
+    }
+
+    fn extract_and_expand(
+        &self,
+        cal: &mut C,
+        dh: &[u8],
+        kem_context: &[&[u8]],
+    ) -> Result<Zeroizing<[u8; SECRET_LEN]>, HpkeError> {
+        let suite_id = self.kem_suite_id();
+        let eae_prk = labeled_extract(
+            cal,
+            self.kem_hmac.clone(),
+            &suite_id,
+            b"",
+            b"eae_prk",
+            &[dh],
+        );
+        let mut shared_secret = Zeroizing::new([0; SECRET_LEN]);
+        labeled_expand(
+            cal,
+            self.kem_hmac.clone(),
+            &suite_id,
+            eae_prk.as_ref(),
+            b"shared_secret",
+            kem_context,
+            &mut shared_secret[..],
+        )?;
+        Ok(shared_secret)
+    }
+
+    fn key_schedule(
+        &self,
+        cal: &mut C,
+        mode: u8,
+        shared_secret: &[u8],
+        info: &[u8],
+    ) -> Result<Context<C>, HpkeError> {
+        let suite_id = self.hpke_suite_id();
+        let hmac = self.hmac.clone();
+
+        // PSK modes are not supported, so psk and psk_id are always empty.
+        let psk_id_hash = labeled_extract(cal, hmac.clone(), &suite_id, b"", b"psk_id_hash", &[]);
+        let info_hash = labeled_extract(cal, hmac.clone(), &suite_id, b"", b"info_hash", &[info]);
+        let key_schedule_context: [&[u8]; 3] = [&[mode], psk_id_hash.as_ref(), info_hash.as_ref()];
+
+        let secret = labeled_extract(cal, hmac.clone(), &suite_id, shared_secret, b"secret", &[]);
+
+        let mut key = Zeroizing::new([0; MAX_KEY_LEN]);
+        let key = &mut key[..self.aead.key_length()];
+        labeled_expand(
+            cal,
+            hmac.clone(),
+            &suite_id,
+            secret.as_ref(),
+            b"key",
+            &key_schedule_context,
+            key,
+        )?;
+        let mut base_nonce = [0; NONCE_LEN];
+        labeled_expand(
+            cal,
+            hmac.clone(),
+            &suite_id,
+            secret.as_ref(),
+            b"base_nonce",
+            &key_schedule_context,
+            &mut base_nonce,
+        )?;
+        let mut exporter_secret = Zeroizing::new([0; MAX_HASH_LEN]);
+        labeled_expand(
+            cal,
+            hmac.clone(),
+            &suite_id,
+            secret.as_ref(),
+            b"exp",
+            &key_schedule_context,
+            &mut exporter_secret[..hmac.len()],
+        )?;
+
+        let aead_key = cal.aead().load_from_keydata(self.aead.clone(), key);
+
+        Ok(Context {
+            suite: self.clone(),
+            key: aead_key,
+            base_nonce,
+            seq: 0,
+            exporter_secret,
+        })
+    }
+
+    /// Sets up a sender context for Base mode (`SetupBaseS`).
+    ///
+    /// On success, the encapsulated key `enc` is returned along with the context.
+    pub fn setup_base_s(
+        &self,
+        cal: &mut C,
+        pk_r: &[u8],
+        info: &[u8],
+    ) -> Result<(SerializedPublicKey, SenderContext<C>), HpkeError> {
+        let ephemeral = cal.dh().generate(self.dh.clone());
+        self.setup_s_with_ephemeral(cal, &ephemeral, pk_r, info, None)
+    }
+
+    /// Sets up a sender context for Auth mode (`SetupAuthS`).
+    ///
+    /// On success, the encapsulated key `enc` is returned along with the context.
+    pub fn setup_auth_s(
+        &self,
+        cal: &mut C,
+        pk_r: &[u8],
+        info: &[u8],
+        sender: &KeyPair<C>,
+    ) -> Result<(SerializedPublicKey, SenderContext<C>), HpkeError> {
+        let ephemeral = cal.dh().generate(self.dh.clone());
+        self.setup_s_with_ephemeral(cal, &ephemeral, pk_r, info, Some(sender))
+    }
+
+    /// Sets up a sender context with a given ephemeral key; the mode is Auth if a `sender` key
+    /// pair is given, and Base otherwise.
+    ///
+    /// This is mainly useful to reproduce test vectors in combination with
+    /// [`.derive_key_pair()`][Self::derive_key_pair()]. The ephemeral key must not be used more
+    /// than once.
+    pub fn setup_s_with_ephemeral(
+        &self,
+        cal: &mut C,
+        ephemeral: &DhSecretKeyOf<C>,
+        pk_r: &[u8],
+        info: &[u8],
+        sender: Option<&KeyPair<C>>,
+    ) -> Result<(SerializedPublicKey, SenderContext<C>), HpkeError> {
+        let dh_len = self.dh.output_length();
+        let recipient = self.deserialize_public_key(cal, pk_r)?;
+
+        let mut dh = Zeroizing::new([0; 2 * MAX_DH_LEN]);
+        self.dh(cal, ephemeral, &recipient, &mut dh[..dh_len])?;
+        let (dh_len, mode) = match sender {
+            Some(sender) => {
+                self.dh(cal, &sender.secret, &recipient, &mut dh[dh_len..2 * dh_len])?;
+                (2 * dh_len, MODE_AUTH)
+            }
+            None => (dh_len, MODE_BASE),
+        };
+
+        let enc = cal.dh().public_key(ephemeral);
+        let enc = self.serialize_public_key(cal, &enc);
+        let pk_s = sender.map_or(&[][..], |s| s.public.as_ref());
+
+        let shared_secret =
+            self.extract_and_expand(cal, &dh[..dh_len], &[enc.as_ref(), pk_r, pk_s])?;
+        let context = self.key_schedule(cal, mode, &shared_secret[..], info)?;
+
+        Ok((enc, SenderContext(context)))
+    }
+
+    /// Sets up a recipient context for Base mode (`SetupBaseR`).
+    pub fn setup_base_r(
+        &self,
+        cal: &mut C,
+        enc: &[u8],
+        recipient: &KeyPair<C>,
+        info: &[u8],
+    ) -> Result<RecipientContext<C>, HpkeError> {
+        self.setup_r(cal, enc, recipient, info, None)
+    }
+
+    /// Sets up a recipient context for Auth mode (`SetupAuthR`).
+    pub fn setup_auth_r(
+        &self,
+        cal: &mut C,
+        enc: &[u8],
+        recipient: &KeyPair<C>,
+        info: &[u8],
+        pk_s: &[u8],
+    ) -> Result<RecipientContext<C>, HpkeError> {
+        self.setup_r(cal, enc, recipient, info, Some(pk_s))
+    }
+
+    fn setup_r(
+        &self,
+        cal: &mut C,
+        enc: &[u8],
+        recipient: &KeyPair<C>,
+        info: &[u8],
+        pk_s: Option<&[u8]>,
+    ) -> Result<RecipientContext<C>, HpkeError> {
+        let dh_len = self.dh.output_length();
+        let ephemeral = self.deserialize_public_key(cal, enc)?;
+
+        let mut dh = Zeroizing::new([0; 2 * MAX_DH_LEN]);
+        self.dh(cal, &recipient.secret, &ephemeral, &mut dh[..dh_len])?;
+        let (dh_len, mode) = match pk_s {
+            Some(pk_s) => {
+                let sender = self.deserialize_public_key(cal, pk_s)?;
+                self.dh(cal, &recipient.secret, &sender, &mut dh[dh_len..2 * dh_len])?;
+                (2 * dh_len, MODE_AUTH)
+            }
+            None => (dh_len, MODE_BASE),
+        };
+
+        let shared_secret = self.extract_and_expand(
+            cal,
+            &dh[..dh_len],
+            &[enc, recipient.public.as_ref(), pk_s.unwrap_or_default()],
+        )?;
+        let context = self.key_schedule(cal, mode, &shared_secret[..], info)?;
+
+        Ok(RecipientContext(context))
+    }
+
+    /// Encrypts a single message in Base mode, returning `enc` and the AEAD tag.
+    pub fn seal_base(
+        &self,
+        cal: &mut C,
+        pk_r: &[u8],
+        info: &[u8],
+        aad: impl AadGenerator,
+        plaintext: &mut [u8],
+    ) -> Result<(SerializedPublicKey, AeadTagOf<C>), HpkeError> {
+        let (enc, mut context) = self.setup_base_s(cal, pk_r, info)?;
+        let tag = context.seal(cal, aad, plaintext)?;
+        Ok((enc, tag))
+    }
+
+    /// Decrypts a single message in Base mode.
+    #[allow(
+        clippy::too_many_arguments,
+        reason = "mirrors the single-shot API of RFC9180 Section 6.1"
+    )]
+    #[must_use = "message must not be accessed after a failed decryption"]
+    pub fn open_base(
+        &self,
+        cal: &mut C,
+        enc: &[u8],
+        recipient: &KeyPair<C>,
+        info: &[u8],
+        aad: impl AadGenerator,
+        ciphertext: &mut [u8],
+        tag: &[u8],
+    ) -> Result<(), HpkeError> {
+        let mut context = self.setup_base_r(cal, enc, recipient, info)?;
+        context.open(cal, aad, ciphertext, tag)
+    }
+
+    /// Encrypts a single message in Auth mode, returning `enc` and the AEAD tag.
+    pub fn seal_auth(
+        &self,
+        cal: &mut C,
+        pk_r: &[u8],
+        info: &[u8],
+        aad: impl AadGenerator,
+        plaintext: &mut [u8],
+        sender: &KeyPair<C>,
+    ) -> Result<(SerializedPublicKey, AeadTagOf<C>), HpkeError> {
+        let (enc, mut context) = self.setup_auth_s(cal, pk_r, info, sender)?;
+        let tag = context.seal(cal, aad, plaintext)?;
+        Ok((enc, tag))
+    }
+
+    /// Decrypts a single message in Auth mode.
+    #[allow(
+        clippy::too_many_arguments,
+        reason = "mirrors the single-shot API of RFC9180 Section 6.1"
+    )]
+    #[must_use = "message must not be accessed after a failed decryption"]
+    pub fn open_auth(
+        &self,
+        cal: &mut C,
+        enc: &[u8],
+        recipient: &KeyPair<C>,
+        info: &[u8],
+        aad: impl AadGenerator,
+        ciphertext: &mut [u8],
+        tag: &[u8],
+        pk_s: &[u8],
+    ) -> Result<(), HpkeError> {
+        let mut context = self.setup_auth_r(cal, enc, recipient, info, pk_s)?;
+        context.open(cal, aad, ciphertext, tag)
+    }
+}
+
+struct Context<C: Cal> {
+    suite: Suite<C>,
+    key: AeadKeyOf<C>,
+    base_nonce: [u8; NONCE_LEN],
+    /// Sequence number of the next message.
+    ///
+    /// This is narrower than the RFC's `Nn` bytes, and thus runs into
+    /// [`HpkeError::MessageLimitReached`] earlier; that is still far beyond what a device can
+    /// send.
+    seq: u64,
+    exporter_secret: Zeroizing<[u8; MAX_HASH_LEN]>,
+}
+
+impl<C: Cal> Context<C> {
+    fn nonce(&self) -> Result<[u8; NONCE_LEN], HpkeError> {
+        if self.seq == u64::MAX {
+            return Err(HpkeError::MessageLimitReached);
+        }
+        let mut nonce = self.base_nonce;
+        for (n, s) in nonce[NONCE_LEN - 8..]
+            .iter_mut()
+            .zip(self.seq.to_be_bytes())
+        {
+            *n ^= s;
+        }
+        Ok(nonce)
+    }
+
+    fn export(
+        &self,
+        cal: &mut C,
+        exporter_context: &[u8],
+        out: &mut [u8],
+    ) -> Result<(), HpkeError> {
+        let hmac = self.suite.hmac.clone();
+        let exporter_secret = &self.exporter_secret[..hmac.len()];
+        labeled_expand(
+            cal,
+            hmac,
+            &self.suite.hpke_suite_id(),
+            exporter_secret,
+            b"sec",
+            &[exporter_context],
+            out,
+        )
+    }
+}
+
+/// The sender's side of an HPKE context.
+pub struct SenderContext<C: Cal>(Context<C>);
+
+impl<C: Cal> SenderContext<C> {
+    /// Encrypts the next message in place, returning the AEAD tag.
+    pub fn seal(
+        &mut self,
+        cal: &mut C,
+        aad: impl AadGenerator,
+        plaintext: &mut [u8],
+    ) -> Result<AeadTagOf<C>, HpkeError> {
+        let nonce = self.0.nonce()?;
+        let tag = cal
+            .aead()
+            .encrypt_in_place(&self.0.key, &nonce, plaintext, aad);
+        self.0.seq += 1;
+        Ok(tag)
+    }
+
+    /// Derives a secret from the context, filling `out` (`Context.Export`).
+    pub fn export(
+        &self,
+        cal: &mut C,
+        exporter_context: &[u8],
+        out: &mut [u8],
+    ) -> Result<(), HpkeError> {
+        self.0.export(cal, exporter_context, out)
+    }
+}
+
+/// The recipient's side of an HPKE context.
+pub struct RecipientContext<C: Cal>(Context<C>);
+
+impl<C: Cal> RecipientContext<C> {
+    /// Decrypts the next message in place.
+    ///
+    /// The sequence number only advances when decryption succeeds.
+    #[must_use = "message must not be accessed after a failed decryption"]
+    pub fn open(
+        &mut self,
+        cal: &mut C,
+        aad: impl AadGenerator,
+        ciphertext: &mut [u8],
+        tag: &[u8],
+    ) -> Result<(), HpkeError> {
+        let nonce = self.0.nonce()?;
+        cal.aead()
+            .decrypt_in_place(&self.0.key, &nonce, ciphertext, tag, aad)
+            .map_err(|_| HpkeError::OpenError)?;
+        self.0.seq += 1;
+        Ok(())
+    }
+
+    /// Derives a secret from the context, filling `out` (`Context.Export`).
+    pub fn export(
+        &self,
+        cal: &mut C,
+        exporter_context: &[u8],
+        out: &mut [u8],
+    ) -> Result<(), HpkeError> {
+        self.0.export(cal, exporter_context, out)
+    }
+}
+
+// The labeled functions are built on HmacProvider rather than on HkdfProvider because their input
+// is assembled from several pieces, and HkdfProvider needs its info in contiguous memory.
+
+fn labeled_extract<C: Cal>(
+    cal: &mut C,
+    alg: HmacAlgorithmOf<C>,
+    suite_id: &[u8],
+    salt: &[u8],
+    label: &[u8],
+    ikm: &[&[u8]],
+) -> HmacOutputOf<C> {
+    let cal = cal.hmac();
+    // An empty salt is equivalent to HashLen zero bytes, as HMAC pads its key with zeros.
+    let mut state = cal.init_with_keydata(alg, salt);
+    cal.update(&mut state, VERSION_LABEL);
+    cal.update(&mut state, suite_id);
+    cal.update(&mut state, label);
+    for item in ikm {
+        cal.update(&mut state, item);
+    }
+    cal.finalize(state)
+}
+
+fn labeled_expand<C: Cal>(
+    cal: &mut C,
+    alg: HmacAlgorithmOf<C>,
+    suite_id: &[u8],
+    prk: &[u8],
+    label: &[u8],
+    info: &[&[u8]],
+    out: &mut [u8],
+) -> Result<(), HpkeError> {
+    let cal = cal.hmac();
+    let hash_len = alg.len();
+    if out.len() > 255 * hash_len {
+        return Err(HpkeError::OutputTooLong);
+    }
+    let length = (out.len() as u16).to_be_bytes();
+
+    let mut t = WipeOnDrop::new(<HmacAlgorithmOf<C> as HmacAlgorithm>::MaxLenBuf::default());
+    let t = t.as_mut();
+    let mut t_len = 0;
+    for (index, chunk) in out.chunks_mut(hash_len).enumerate() {
+        // T(i) = HMAC-Hash(PRK, T(i-1) || labeled_info || i)
+        let mut state = cal.init_with_keydata(alg.clone(), prk);
+        cal.update(&mut state, &t[..t_len]);
+        cal.update(&mut state, &length);
+        cal.update(&mut state, VERSION_LABEL);
+        cal.update(&mut state, suite_id);
+        cal.update(&mut state, label);
+        for item in info {
+            cal.update(&mut state, item);
+        }
+        // index < 255 is enforced by the length check
+        cal.update(&mut state, &[index as u8 + 1]);
+        let result = cal.finalize(state);
+        t[..hash_len].copy_from_slice(result.as_ref());
+        t_len = hash_len;
+        chunk.copy_from_slice(&t[..chunk.len()]);
+    }
+    Ok(())
+}
+
+pub fn test_hpke_selftest<C: Cal>(cal: &mut C, suite: Suite<C>) {
+    let recipient = suite.generate_key_pair(cal);
+    let sender = suite.generate_key_pair(cal);
+    let info = b"test info";
+    let message = b"A message that is encrypted with HPKE";
+
+    let mut buf = *message;
+    let (enc, tag) = suite
+        .seal_base(cal, recipient.public.as_ref(), info, &b"aad"[..], &mut buf)
+        .unwrap();
+    assert_ne!(&buf, message, "message was not encrypted");
+    suite
+        .open_base(
+            cal,
+            enc.as_ref(),
+            &recipient,
+            info,
+            &b"aad"[..],
+            &mut buf,
+            tag.as_ref(),
+        )
+        .expect("freshly sealed message did not open");
+    assert_eq!(&buf, message);
+
+    let (enc, mut sender_context) = suite
+        .setup_auth_s(cal, recipient.public.as_ref(), info, &sender)
+        .unwrap();
+    let mut recipient_context = suite
+        .setup_auth_r(cal, enc.as_ref(), &recipient, info, sender.public.as_ref())
+        .unwrap();
+
+    for aad in [&b"first"[..], b"second", b"third"] {
+        let mut buf = *message;
+        let tag = sender_context.seal(cal, aad, &mut buf).unwrap();
+        let mut tampered = buf;
+        assert!(
+            recipient_context
+                .open(cal, &b"other"[..], &mut tampered, tag.as_ref())
+                .is_err(),
+            "message opened with different AAD"
+        );
+        recipient_context
+            .open(cal, aad, &mut buf, tag.as_ref())
+            .expect("message did not open in sequence");
+        assert_eq!(&buf, message);
+    }
+
+    let mut sender_export = [0; 42];
+    let mut recipient_export = [0; 42];
+    sender_context
+        .export(cal, b"context", &mut sender_export)
+        .unwrap();
+    recipient_context
+        .export(cal, b"context", &mut recipient_export)
+        .unwrap();
+    assert_eq!(sender_export, recipient_export);
+
+    let mut buf = *message;
+    let (enc, tag) = suite
+        .seal_auth(
+            cal,
+            recipient.public.as_ref(),
+            info,
+            &b""[..],
+            &mut buf,
+            &sender,
+        )
+        .unwrap();
+    assert!(
+        suite
+            .open_auth(
+                cal,
+                enc.as_ref(),
+                &recipient,
+                info,
+                &b""[..],
+                &mut buf,
+                tag.as_ref(),
+                recipient.public.as_ref(),
+            )
+            .is_err(),
+        "message opened with wrong sender key"
+    );
+}
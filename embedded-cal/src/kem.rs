@@ -0,0 +1,242 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+use crate::{ImportError, OperationError};
+
+/// Key encapsulation mechanisms (KEMs).
+///
+/// Unlike [`DhProvider`][crate::DhProvider], a KEM does not combine a secret and a public key
+/// symmetrically: The party holding the public key *encapsulates* a fresh shared secret into a
+/// ciphertext, and the holder of the secret key *decapsulates* it from there. This is the interface
+/// of post-quantum mechanisms such as ML-KEM.
+///
+/// Key handling follows the conventions of [`DhProvider`][crate::DhProvider]: There are distinct
+/// types for exportable and non-exportable secret keys, and shared secrets are not exposed unless
+/// explicitly requested.
+pub trait KemProvider {
+    type Algorithm: KemAlgorithm;
+    /// A secret key that is intended to be exported.
+    ///
+    /// See [`DhProvider::VisibleSecretKey`][crate::DhProvider::VisibleSecretKey] for rationale.
+    type VisibleSecretKey: Sized + Into<Self::SecretKey>;
+    type SecretKey: Sized;
+    type PublicKey: Sized;
+    /// The encapsulated form of a shared secret, to be sent to the holder of the secret key.
+    type Ciphertext: AsRef<[u8]>;
+    type SharedSecret: Sized;
+
+    /// Generates a secret key that is intended to be exported / shared (e.g. to be persisted
+    /// across program executions).
+    ///
+    /// # Errors
+    ///
+    /// … are [`OperationError::HardwareFault`] if the random number generator failed.
+    fn try_generate_visible(
+        &mut self,
+        alg: Self::Algorithm,
+    ) -> Result<Self::VisibleSecretKey, OperationError>;
+
+    /// Generates a secret key.
+    ///
+    /// # Errors
+    ///
+    /// … as for [`.try_generate_visible()`][Self::try_generate_visible()].
+    fn try_generate(&mut self, alg: Self::Algorithm) -> Result<Self::SecretKey, OperationError> {
+        self.try_generate_visible(alg).map(Into::into)
+    }
+
+    /// Generates a secret key that is intended to be exported / shared, panicking where
+    /// [`.try_generate_visible()`][Self::try_generate_visible()] errs.
+    fn generate_visible(&mut self, alg: Self::Algorithm) -> Self::VisibleSecretKey {
+        self.try_generate_visible(alg)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Generates a secret key, panicking where [`.try_generate()`][Self::try_generate()] errs.
+    fn generate(&mut self, alg: Self::Algorithm) -> Self::SecretKey {
+        self.try_generate(alg).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Exposes a visible secret key's secret.
+    ///
+    /// Data is stored in the algorithm's native format. For ML-KEM, this is the 64 byte seed
+    /// `d || z` from which the key pair is generated, rather than the expanded decapsulation key.
+    fn export_secretkey_bytes<'s>(
+        &mut self,
+        secretkey: &'s Self::VisibleSecretKey,
+    ) -> impl AsRef<[u8]> + use<'s, Self>;
+
+    /// Inverse operation of [`.export_secretkey_bytes()`][Self::export_secretkey_bytes()].
+    fn import_secretkey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        secret: &[u8],
+    ) -> Result<Self::VisibleSecretKey, ImportError>;
+
+    /// Exposes a public key's key data bytes.
+    ///
+    /// For ML-KEM, this is the encapsulation key in the encoding of FIPS 203.
+    fn export_publickey_bytes<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+    ) -> impl AsRef<[u8]> + use<'p, Self>;
+
+    /// Imports a public key in the inverse operation of
+    /// [`.export_publickey_bytes()`][Self::export_publickey_bytes()].
+    ///
+    /// Implementations must perform the input validation the algorithm prescribes (for ML-KEM, the
+    /// modulus check of FIPS 203 Section 7.2).
+    fn import_publickey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::PublicKey, ImportError>;
+
+    /// Produces the public key corresponding to a private key.
+    fn public_key(&mut self, private: &Self::SecretKey) -> Self::PublicKey;
+
+    /// Generates a fresh shared secret and encapsulates it for the holder of the public key's
+    /// secret key.
+    ///
+    /// # Errors
+    ///
+    /// … are [`OperationError::HardwareFault`] if the random number generator failed.
+    fn try_encapsulate(
+        &mut self,
+        public: &Self::PublicKey,
+    ) -> Result<(Self::Ciphertext, Self::SharedSecret), OperationError>;
+
+    /// Generates and encapsulates a fresh shared secret, panicking where
+    /// [`.try_encapsulate()`][Self::try_encapsulate()] errs.
+    fn encapsulate(&mut self, public: &Self::PublicKey) -> (Self::Ciphertext, Self::SharedSecret) {
+        self.try_encapsulate(public)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Recovers the shared secret from a ciphertext.
+    ///
+    /// Note that for algorithms with implicit rejection (such as ML-KEM), a ciphertext that was
+    /// tampered with does not produce an error, but a shared secret unrelated to the encapsulated
+    /// one.
+    ///
+    /// # Errors
+    ///
+    /// … are produced only if the ciphertext's length does not match the key's
+    /// [`.ciphertext_length()`][KemAlgorithm::ciphertext_length()].
+    fn decapsulate(
+        &mut self,
+        private: &Self::SecretKey,
+        ciphertext: &[u8],
+    ) -> Result<Self::SharedSecret, ImportError>;
+
+    /// Produces the bytes of the shared secret, in the algorithm's
+    /// [`.shared_secret_length()`][KemAlgorithm::shared_secret_length()].
+    ///
+    /// See [`DhProvider::raw_secret_bytes()`][crate::DhProvider::raw_secret_bytes()] for why the
+    /// shared secret is not `AsRef` itself.
+    fn raw_secret_bytes<'s>(
+        &mut self,
+        secret: &'s Self::SharedSecret,
+    ) -> impl AsRef<[u8]> + use<'s, Self>;
+}
+
+/// A key encapsulation mechanism identifier.
+///
+/// This encodes the full set of parameters (e.g. the ML-KEM parameter set).
+pub trait KemAlgorithm: Sized + PartialEq + Eq + core::fmt::Debug + Clone {
+    /// Length of the shared secret produced by keys of this algorithm.
+    fn shared_secret_length(&self) -> usize;
+
+    /// Length of a ciphertext in bytes.
+    fn ciphertext_length(&self) -> usize;
+
+    /// Length of an exported public key in bytes.
+    fn public_key_length(&self) -> usize;
+
+    /// Selects a KEM from its HPKE KEM identifier.
+    ///
+    /// The identifier comes from the ["HPKE KEM
+    /// Identifiers"](https://www.iana.org/assignments/hpke/hpke.xhtml#hpke-kem-ids) registry
+    /// maintained by IANA; ML-KEM-768 is 0x0041.
+    ///
+    /// COSE has no identifiers for KEMs as such yet, as its KEM based algorithms combine them with
+    /// key derivation and wrapping.
+    #[inline]
+    #[allow(
+        unused_variables,
+        reason = "Argument names are part of the documentation"
+    )]
+    fn from_hpke_kem_id(id: u16) -> Option<Self> {
+        None
+    }
+
+    /// Lists all algorithms supported by the implementation, as selected by
+    /// [`Self::from_hpke_kem_id()`].
+    ///
+    /// See [`HashAlgorithm::all()`][crate::HashAlgorithm::all()] for how implementations that
+    /// extend a base compose the list.
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        core::iter::empty()
+    }
+
+    /// The KEM's identifier in the HPKE KEM Identifiers registry, if it has one; the inverse of
+    /// [`Self::from_hpke_kem_id()`].
+    #[inline]
+    fn hpke_kem_id(&self) -> Option<u16> {
+        None
+    }
+}
+
+pub fn test_kem_algorithm_mlkem768<KP: KemProvider>() {
+    let mlkem768 = KP::Algorithm::from_hpke_kem_id(0x0041).expect(
+        "test for type claiming ML-KEM-768 compatibility did not recognize HPKE KEM ID 0x0041",
+    );
+    assert_eq!(mlkem768.shared_secret_length(), 32);
+    assert_eq!(mlkem768.ciphertext_length(), 1088);
+    assert_eq!(mlkem768.public_key_length(), 1184);
+}
+
+pub fn test_kem_selftest<C: crate::Cal>(
+    cal: &mut C,
+    alg: <C::KemProvider as KemProvider>::Algorithm,
+) {
+    let cal = cal.kem();
+
+    let secret = cal.generate_visible(alg.clone());
+    let reimported = {
+        let exported = cal.export_secretkey_bytes(&secret);
+        cal.import_secretkey_bytes(alg.clone(), exported.as_ref())
+            .expect("exported secret key could not be imported")
+            .into()
+    };
+    let secret = secret.into();
+
+    let public = cal.public_key(&secret);
+    let public_bytes = cal.export_publickey_bytes(&public);
+    assert_eq!(public_bytes.as_ref().len(), alg.public_key_length());
+    let public = cal
+        .import_publickey_bytes(alg.clone(), public_bytes.as_ref())
+        .expect("exported public key could not be imported");
+
+    let (ciphertext, sender_secret) = cal.encapsulate(&public);
+    assert_eq!(ciphertext.as_ref().len(), alg.ciphertext_length());
+    let recipient_secret = cal.decapsulate(&secret, ciphertext.as_ref()).unwrap();
+    let reimported_secret = cal.decapsulate(&reimported, ciphertext.as_ref()).unwrap();
+
+    let sender_bytes = cal.raw_secret_bytes(&sender_secret);
+    assert_eq!(sender_bytes.as_ref().len(), alg.shared_secret_length());
+    assert_eq!(
+        sender_bytes.as_ref(),
+        cal.raw_secret_bytes(&recipient_secret).as_ref()
+    );
+    assert_eq!(
+        sender_bytes.as_ref(),
+        cal.raw_secret_bytes(&reimported_secret).as_ref()
+    );
+
+    assert!(
+        cal.decapsulate(&secret, &ciphertext.as_ref()[1..]).is_err(),
+        "ciphertext of wrong length was accepted"
+    );
+}
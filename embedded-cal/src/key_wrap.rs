@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+use crate::DecryptionFailed;
+
+/// Key wrapping, i.e. the transport of keys encrypted with a key-encryption key (KEK).
+///
+/// This is used, for example, by COSE_Encrypt recipients and SUIT encrypted payloads to transport
+/// content keys.
+///
+/// Wrapping adds 8 bytes to the key data; both the key data and the wrapped key need to be
+/// multiples of 8 bytes in length.
+///
+/// Wrapped AEAD keys can be unwrapped directly into the AEAD provider's key type through
+/// [`Cal::unwrap_aead_key`][crate::Cal::unwrap_aead_key].
+pub trait KeyWrapProvider {
+    type Algorithm: KeyWrapAlgorithm;
+    /// A key-encryption key.
+    type Key: Sized;
+
+    /// Loads a key-encryption key from the key's bytes.
+    ///
+    /// # Panics
+    ///
+    /// … if key's length is not `alg.key_length()`.
+    fn load_from_keydata(&mut self, alg: Self::Algorithm, key: &[u8]) -> Self::Key;
+
+    /// Wraps key data.
+    ///
+    /// # Panics
+    ///
+    /// … if `key_data` is not a multiple of 8 bytes long, is shorter than 16 bytes, or if `output`
+    /// is not 8 bytes longer than `key_data`.
+    fn wrap_key(&mut self, kek: &Self::Key, key_data: &[u8], output: &mut [u8]);
+
+    /// Unwraps key data.
+    ///
+    /// An error indicates that the integrity check failed, i.e. that the wrapped key was not
+    /// produced with this key-encryption key. In that case, `output` holds no meaningful data.
+    ///
+    /// # Panics
+    ///
+    /// … if `wrapped` is not a multiple of 8 bytes long, is shorter than 24 bytes, or if `output`
+    /// is not 8 bytes shorter than `wrapped`.
+    #[must_use = "output must not be accessed after a failed unwrapping"]
+    fn unwrap_key(
+        &mut self,
+        kek: &Self::Key,
+        wrapped: &[u8],
+        output: &mut [u8],
+    ) -> Result<(), DecryptionFailed>;
+}
+
+/// A key wrapping algorithm identifier.
+pub trait KeyWrapAlgorithm: Sized + PartialEq + Eq + core::fmt::Debug + Clone {
+    /// Length of a key-encryption key in bytes.
+    fn key_length(&self) -> usize;
+
+    /// Selects a key wrapping algorithm from its COSE number.
+    ///
+    /// The algorithm number comes from the ["COSE Algorithms"
+    /// registry](https://www.iana.org/assignments/cose/cose.xhtml#algorithms) maintained by IANA.
+    #[inline]
+    #[allow(
+        unused_variables,
+        reason = "Argument names are part of the documentation"
+    )]
+    fn from_cose_number(number: impl Into<i128>) -> Option<Self> {
+        None
+    }
+
+    /// Lists all algorithms supported by the implementation, as selected by
+    /// [`Self::from_cose_number()`].
+    ///
+    /// See [`HashAlgorithm::all()`][crate::HashAlgorithm::all()] for how implementations that
+    /// extend a base compose the list.
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        core::iter::empty()
+    }
+
+    /// The algorithm's number in the COSE Algorithms registry, if it has one; the inverse of
+    /// [`Self::from_cose_number()`].
+    #[inline]
+    fn cose_number(&self) -> Option<i128> {
+        None
+    }
+}
+
+pub fn test_key_wrap_algorithm_a128kw<KP: KeyWrapProvider>() {
+    let a128kw = KP::Algorithm::from_cose_number(-3)
+        .expect("test for type claiming A128KW compatibility did not recognize COSE number -3");
+    assert_eq!(a128kw.key_length(), 16);
+}
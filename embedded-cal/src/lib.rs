@@ -2,31 +2,63 @@
 // SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
 #![no_std]
 
+pub mod asynch;
+pub mod cose_key;
+pub mod der;
 pub mod empty;
+pub mod fallback;
+pub mod hpke;
 pub mod p256;
+pub mod router;
+pub mod shared;
 pub mod util;
 
 mod aead;
+mod block_cipher;
 mod dh;
+mod ecc;
+mod error;
 mod hash;
 mod hkdf;
 mod hmac;
+mod kem;
+mod key_wrap;
+mod mac;
 mod rng;
+mod signature;
 // FIXME: Once we start API stability, this should be a dedicated crate.
 pub mod plumbing;
 
 pub use aead::{
     AadGenerator, AeadAlgorithm, AeadProvider, DecryptionFailed, build_b0,
-    test_aead_algorithm_aesccm_16_64_128,
+    test_aead_algorithm_aesccm_16_64_128, test_aead_algorithm_aesgcm_128,
+};
+pub use block_cipher::{
+    BLOCK_SIZE, BlockCipherAlgorithm, BlockCipherProvider, test_block_cipher_algorithm_aes128,
 };
 pub use dh::{
-    DhAlgorithm, DhProvider, ImportError, IncompatibleKeys, test_dh_algorithm_ecdh_p256,
+    DhAlgorithm, DhProvider, ImportError, IncompatibleKeys, PublicKeyFormat, UnsupportedFormat,
+    test_dh_algorithm_ecdh_p256, test_dh_algorithm_x448, test_dh_algorithm_x25519,
     test_dh_selftest,
 };
-pub use hash::{HashAlgorithm, HashProvider, test_hash_algorithm_sha256};
+pub use ecc::{EccAlgorithm, EccProvider, test_ecc_algorithm_p256, test_ecc_selftest};
+pub use error::{OperationError, TryError};
+pub use hash::{
+    HashAlgorithm, HashProvider, test_hash_algorithm_sha3_256, test_hash_algorithm_sha256,
+    test_hash_algorithm_sha384, test_hash_algorithm_sha512, test_hash_algorithm_shake256,
+};
 pub use hkdf::{HkdfError, HkdfProvider};
-pub use hmac::{HmacAlgorithm, HmacProvider, test_hmac_algorithm_hmacsha256};
+pub use hmac::{
+    HmacAlgorithm, HmacProvider, MIN_HMAC_TAG_LEN, test_hmac_algorithm_hmacsha256, verify_hmac_tag,
+};
+pub use kem::{KemAlgorithm, KemProvider, test_kem_algorithm_mlkem768, test_kem_selftest};
+pub use key_wrap::{KeyWrapAlgorithm, KeyWrapProvider, test_key_wrap_algorithm_a128kw};
+pub use mac::{MacAlgorithm, MacProvider, test_mac_algorithm_aes_cmac_128};
 pub use rng::test_tryrng;
+pub use signature::{
+    SignatureAlgorithm, SignatureProvider, VerificationFailed, test_signature_algorithm_es256,
+    test_signature_selftest,
+};
 
 #[allow(
     type_alias_bounds,
@@ -48,6 +80,12 @@ pub mod accessor {
     pub type AeadKeyOf<C: Cal> = <<C as Cal>::AeadProvider as AeadProvider>::Key;
     pub type AeadTagOf<C: Cal> = <<C as Cal>::AeadProvider as AeadProvider>::Tag;
 
+    pub type BlockCipherProviderOf<C: Cal> = <C as Cal>::BlockCipherProvider;
+    pub type BlockCipherAlgorithmOf<C: Cal> =
+        <<C as Cal>::BlockCipherProvider as BlockCipherProvider>::Algorithm;
+    pub type BlockCipherKeyOf<C: Cal> =
+        <<C as Cal>::BlockCipherProvider as BlockCipherProvider>::Key;
+
     pub type DhProviderOf<C: Cal> = <C as Cal>::DhProvider;
     pub type DhAlgorithmOf<C: Cal> = <<C as Cal>::DhProvider as DhProvider>::Algorithm;
     pub type DhVisibleSecretKeyOf<C: Cal> =
@@ -56,16 +94,58 @@ pub mod accessor {
     pub type DhPublicKeyOf<C: Cal> = <<C as Cal>::DhProvider as DhProvider>::PublicKey;
     pub type DhSharedSecretOf<C: Cal> = <<C as Cal>::DhProvider as DhProvider>::SharedSecret;
 
+    pub type EccProviderOf<C: Cal> = <C as Cal>::EccProvider;
+    pub type EccAlgorithmOf<C: Cal> = <<C as Cal>::EccProvider as EccProvider>::Algorithm;
+    pub type EccPointOf<C: Cal> = <<C as Cal>::EccProvider as EccProvider>::Point;
+    pub type EccScalarOf<C: Cal> = <<C as Cal>::EccProvider as EccProvider>::Scalar;
+
     pub type HashProviderOf<C: Cal> = <C as Cal>::HashProvider;
     pub type HashAlgorithmOf<C: Cal> = <<C as Cal>::HashProvider as HashProvider>::Algorithm;
     pub type HashStateOf<C: Cal> = <<C as Cal>::HashProvider as HashProvider>::State;
     pub type HashOutputOf<C: Cal> = <<C as Cal>::HashProvider as HashProvider>::Output;
+    pub type HashXofReaderOf<C: Cal> = <<C as Cal>::HashProvider as HashProvider>::XofReader;
 
     pub type HmacProviderOf<C: Cal> = <C as Cal>::HmacProvider;
     pub type HmacAlgorithmOf<C: Cal> = <<C as Cal>::HmacProvider as HmacProvider>::Algorithm;
     pub type HmacKeyOf<C: Cal> = <<C as Cal>::HmacProvider as HmacProvider>::Key;
     pub type HmacStateOf<C: Cal> = <<C as Cal>::HmacProvider as HmacProvider>::State;
     pub type HmacOutputOf<C: Cal> = <<C as Cal>::HmacProvider as HmacProvider>::Output;
+
+    pub type KemProviderOf<C: Cal> = <C as Cal>::KemProvider;
+    pub type KemAlgorithmOf<C: Cal> = <<C as Cal>::KemProvider as KemProvider>::Algorithm;
+    pub type KemVisibleSecretKeyOf<C: Cal> =
+        <<C as Cal>::KemProvider as KemProvider>::VisibleSecretKey;
+    pub type KemSecretKeyOf<C: Cal> = <<C as Cal>::KemProvider as KemProvider>::SecretKey;
+    pub type KemPublicKeyOf<C: Cal> = <<C as Cal>::KemProvider as KemProvider>::PublicKey;
+    pub type KemCiphertextOf<C: Cal> = <<C as Cal>::KemProvider as KemProvider>::Ciphertext;
+    pub type KemSharedSecretOf<C: Cal> = <<C as Cal>::KemProvider as KemProvider>::SharedSecret;
+
+    pub type KeyWrapProviderOf<C: Cal> = <C as Cal>::KeyWrapProvider;
+    pub type KeyWrapAlgorithmOf<C: Cal> =
+        <<C as Cal>::KeyWrapProvider as KeyWrapProvider>::Algorithm;
+    pub type KeyWrapKeyOf<C: Cal> = <<C as Cal>::KeyWrapProvider as KeyWrapProvider>::Key;
+
+    pub type MacProviderOf<C: Cal> = <C as Cal>::MacProvider;
+    pub type MacAlgorithmOf<C: Cal> = <<C as Cal>::MacProvider as MacProvider>::Algorithm;
+    pub type MacKeyOf<C: Cal> = <<C as Cal>::MacProvider as MacProvider>::Key;
+    pub type MacStateOf<C: Cal> = <<C as Cal>::MacProvider as MacProvider>::State;
+    pub type MacOutputOf<C: Cal> = <<C as Cal>::MacProvider as MacProvider>::Output;
+
+    pub type SignatureProviderOf<C: Cal> = <C as Cal>::SignatureProvider;
+    pub type SignatureAlgorithmOf<C: Cal> =
+        <<C as Cal>::SignatureProvider as SignatureProvider>::Algorithm;
+    pub type SignatureVisibleSecretKeyOf<C: Cal> =
+        <<C as Cal>::SignatureProvider as SignatureProvider>::VisibleSecretKey;
+    pub type SignatureSecretKeyOf<C: Cal> =
+        <<C as Cal>::SignatureProvider as SignatureProvider>::SecretKey;
+    pub type SignaturePublicKeyOf<C: Cal> =
+        <<C as Cal>::SignatureProvider as SignatureProvider>::PublicKey;
+    pub type SignatureSignStateOf<C: Cal> =
+        <<C as Cal>::SignatureProvider as SignatureProvider>::SignState;
+    pub type SignatureVerifyStateOf<C: Cal> =
+        <<C as Cal>::SignatureProvider as SignatureProvider>::VerifyState;
+    pub type SignatureOutputOf<C: Cal> =
+        <<C as Cal>::SignatureProvider as SignatureProvider>::Output;
 }
 
 /// Cryptographic abstraction provider that encompasses all features abstracted by the
@@ -95,9 +175,74 @@ pub trait Cal {
     type AeadProvider: AeadProvider;
     type HashProvider: HashProvider;
     type HmacProvider: HmacProvider;
+    type SignatureProvider: SignatureProvider;
+    type KemProvider: KemProvider;
+    type BlockCipherProvider: BlockCipherProvider;
+    type EccProvider: EccProvider;
+    type MacProvider: MacProvider;
+    type KeyWrapProvider: KeyWrapProvider;
 
     fn dh(&mut self) -> &mut Self::DhProvider;
     fn aead(&mut self) -> &mut Self::AeadProvider;
     fn hash(&mut self) -> &mut Self::HashProvider;
     fn hmac(&mut self) -> &mut Self::HmacProvider;
+    fn signature(&mut self) -> &mut Self::SignatureProvider;
+    fn kem(&mut self) -> &mut Self::KemProvider;
+    fn block_cipher(&mut self) -> &mut Self::BlockCipherProvider;
+    fn ecc(&mut self) -> &mut Self::EccProvider;
+    fn mac(&mut self) -> &mut Self::MacProvider;
+    fn key_wrap(&mut self) -> &mut Self::KeyWrapProvider;
+
+    /// Unwraps a key that was wrapped with the key wrapping provider's `kek`, and loads it into
+    /// the AEAD provider.
+    ///
+    /// The default implementation unwraps into a buffer on the stack that is zeroed afterwards.
+    /// Implementations whose key wrapping and AEAD keys live in the same hardware are encouraged
+    /// to override this, so that the key data is not exposed.
+    ///
+    /// A `wrapped` input that is not 8 bytes longer than `alg.key_length()` fails to unwrap; so
+    /// does, with the default implementation, any key longer than 64 bytes.
+    fn unwrap_aead_key(
+        &mut self,
+        kek: &accessor::KeyWrapKeyOf<Self>,
+        wrapped: &[u8],
+        alg: accessor::AeadAlgorithmOf<Self>,
+    ) -> Result<accessor::AeadKeyOf<Self>, DecryptionFailed>
+    where
+        Self: Sized,
+    {
+        unwrap_through_buffer(
+            self,
+            wrapped,
+            alg.key_length(),
+            |cal, buffer| cal.key_wrap().unwrap_key(kek, wrapped, buffer),
+            |cal, key| cal.aead().load_from_keydata(alg, key),
+        )
+    }
+}
+
+/// Unwraps a key of `key_length` bytes into a buffer on the stack, loads it from there, and zeroes
+/// the buffer.
+///
+/// This is the mechanism behind the default [`Cal::unwrap_aead_key()`]; `cal` is passed on to the
+/// closures so that both can access it.
+fn unwrap_through_buffer<C, K>(
+    cal: &mut C,
+    wrapped: &[u8],
+    key_length: usize,
+    unwrap: impl FnOnce(&mut C, &mut [u8]) -> Result<(), DecryptionFailed>,
+    load: impl FnOnce(&mut C, &[u8]) -> K,
+) -> Result<K, DecryptionFailed> {
+    /// Largest AEAD key that is handled by the default implementation.
+    const MAX_KEY_LENGTH: usize = 64;
+
+    if wrapped.len() != key_length + 8 {
+        return Err(DecryptionFailed);
+    }
+    let mut buffer = [0; MAX_KEY_LENGTH];
+    let buffer = buffer.get_mut(..key_length).ok_or(DecryptionFailed)?;
+    let result = unwrap(cal, buffer);
+    let result = result.map(|()| load(cal, buffer));
+    zeroize::Zeroize::zeroize(buffer);
+    result
 }
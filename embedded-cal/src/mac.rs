@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+/// Message authentication codes that are not hash based.
+///
+/// This is the counterpart of [`HmacProvider`][crate::HmacProvider] for MACs built from block
+/// ciphers, such as AES-CMAC (used in BLE, LoRaWAN and IEEE 802.15.4). Its interface is the same:
+/// Data can be fed in piecemeal between initialization and finalization.
+pub trait MacProvider {
+    type Algorithm: MacAlgorithm;
+    /// A key that can be used to start any number of MAC operations.
+    type Key: Clone + Sized;
+    /// State carried between rounds of feeding data into the MAC.
+    type State: Sized;
+    /// Output of a MAC operation.
+    type Output: AsRef<[u8]>;
+
+    /// Starts a MAC operation based on a key that is entered as raw bytes.
+    ///
+    /// This is a convenience wrapper for `Self::init(Self::load_from_keydata(algorithm, key))`.
+    fn init_with_keydata(&mut self, algorithm: Self::Algorithm, key: &[u8]) -> Self::State {
+        let key = self.load_from_keydata(algorithm, key);
+        self.init(key)
+    }
+    /// Initializes a key from raw bytes.
+    ///
+    /// # Panics
+    ///
+    /// … if key's length is not `algorithm.key_length()`.
+    fn load_from_keydata(&mut self, algorithm: Self::Algorithm, key: &[u8]) -> Self::Key;
+    /// Starts a MAC operation.
+    fn init(&mut self, key: Self::Key) -> Self::State;
+    fn update(&mut self, state: &mut Self::State, data: &[u8]);
+    fn finalize(&mut self, state: Self::State) -> Self::Output;
+
+    /// Computes a MAC over contiguous in-memory data in a single pass, based on a key directly
+    /// entered as bytes.
+    ///
+    /// This is a shortcut for [`self.init_with_keydata(…)`][Self::init_with_keydata()] /
+    /// [`self.update(…)`][Self::update()] / [`self.finalize(…)`][Self::finalize()].
+    fn mac_with_keydata(
+        &mut self,
+        algorithm: Self::Algorithm,
+        key: &[u8],
+        data: &[u8],
+    ) -> Self::Output {
+        let mut state = self.init_with_keydata(algorithm, key);
+        self.update(&mut state, data);
+        self.finalize(state)
+    }
+}
+
+/// A MAC algorithm identifier.
+#[allow(
+    clippy::len_without_is_empty,
+    reason = "Lint only makes sense when length can reasonably be zero, which is not the case here."
+)]
+pub trait MacAlgorithm: Sized + PartialEq + Eq + core::fmt::Debug + Clone {
+    /// Output length in bytes.
+    fn len(&self) -> usize;
+
+    /// Length of a key in bytes.
+    fn key_length(&self) -> usize;
+
+    /// Selects AES-CMAC (as defined in [RFC4493](https://datatracker.ietf.org/doc/html/rfc4493)
+    /// and NIST SP 800-38B) with the given key length in bytes, producing the full 16 byte tag.
+    ///
+    /// There is no registry entry for AES-CMAC in COSE.
+    ///
+    /// Implementations are expected to return `Some` for 16 (AES-CMAC-128) or 32 (AES-CMAC-256)
+    /// if they support that variant.
+    #[inline]
+    #[allow(
+        unused_variables,
+        reason = "Argument names are part of the documentation"
+    )]
+    fn from_aes_cmac_key_length(length: usize) -> Option<Self> {
+        None
+    }
+
+    /// Lists all algorithms supported by the implementation, as selected by
+    /// [`Self::from_aes_cmac_key_length()`].
+    ///
+    /// See [`HashAlgorithm::all()`][crate::HashAlgorithm::all()] for how implementations that
+    /// extend a base compose the list.
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        core::iter::empty()
+    }
+}
+
+pub fn test_mac_algorithm_aes_cmac_128<MP: MacProvider>() {
+    let aes_cmac_128 = MP::Algorithm::from_aes_cmac_key_length(16)
+        .expect("test for type claiming AES-CMAC compatibility did not recognize key length 16");
+    assert_eq!(aes_cmac_128.key_length(), 16);
+    assert_eq!(aes_cmac_128.len(), 16);
+}
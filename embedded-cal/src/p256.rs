@@ -207,6 +207,16 @@ fn pow_mod(base: &[u32; 8], exp: &[u32; 8]) -> [u32; 8] {
     result
 }
 
+// Right-hand side of the curve equation, x^3 + a*x + b mod p.
+fn curve_rhs(x: &[u32; 8]) -> [u32; 8] {
+    // a = p - 3 (since P-256 coefficient a = -3)
+    let a = sub256(&P, &[3, 0, 0, 0, 0, 0, 0, 0]);
+    let x2 = mul_mod(x, x);
+    let x3 = mul_mod(&x2, x);
+    let ax = mul_mod(&a, x);
+    add_mod(&add_mod(&x3, &ax), &B)
+}
+
 // Recover a y coordinate from the compact (x-only) P-256 representation.
 // Either square root is accepted because for ECDH the shared secret is the
 // x-coordinate of the result point, which is the same for both roots.
@@ -217,12 +227,7 @@ pub fn p256_recover_y(x_bytes: &[u8; 32]) -> Result<[u8; 32], crate::ImportError
         return Err(crate::ImportError);
     }
 
-    // a = p - 3 (since P-256 coefficient a = -3)
-    let a = sub256(&P, &[3, 0, 0, 0, 0, 0, 0, 0]);
-    let x2 = mul_mod(&x, &x);
-    let x3 = mul_mod(&x2, &x);
-    let ax = mul_mod(&a, &x);
-    let rhs = add_mod(&add_mod(&x3, &ax), &B);
+    let rhs = curve_rhs(&x);
 
     // y = rhs^((p+1)/4) mod p
     let y = pow_mod(&rhs, &SQRT_EXP);
@@ -233,3 +238,107 @@ pub fn p256_recover_y(x_bytes: &[u8; 32]) -> Result<[u8; 32], crate::ImportError
 
     Ok(words_to_bytes(&y))
 }
+
+// Check that an uncompressed point (x, y) is on the curve, with both coordinates
+// reduced mod p.
+pub fn p256_check_point(x_bytes: &[u8; 32], y_bytes: &[u8; 32]) -> Result<(), crate::ImportError> {
+    let x = bytes_to_words(x_bytes);
+    let y = bytes_to_words(y_bytes);
+
+    if ge(&x, &P) || ge(&y, &P) {
+        return Err(crate::ImportError);
+    }
+
+    if mul_mod(&y, &y) != curve_rhs(&x) {
+        return Err(crate::ImportError);
+    }
+
+    Ok(())
+}
+
+// Recover the y coordinate of the given parity (as indicated by the 0x02 / 0x03 prefix of a SEC1
+// compressed point) from x.
+pub fn p256_decompress(x_bytes: &[u8; 32], y_is_odd: bool) -> Result<[u8; 32], crate::ImportError> {
+    let y = p256_recover_y(x_bytes)?;
+    if (y[31] & 1 == 1) == y_is_odd {
+        return Ok(y);
+    }
+    // The curve has prime order, so there is no point with y = 0, and p - y is in range.
+    Ok(words_to_bytes(&sub256(&P, &bytes_to_words(&y))))
+}
+
+// A P-256 point in one of the public key formats, as produced by `p256_encode_point`.
+pub struct EncodedPoint {
+    data: [u8; 65],
+    len: usize,
+}
+
+impl AsRef<[u8]> for EncodedPoint {
+    fn as_ref(&self) -> &[u8] {
+        &self.data[..self.len]
+    }
+}
+
+pub fn p256_encode_point(
+    x_bytes: &[u8; 32],
+    y_bytes: &[u8; 32],
+    format: crate::PublicKeyFormat,
+) -> EncodedPoint {
+    let mut data = [0u8; 65];
+    let len = match format {
+        crate::PublicKeyFormat::Compact => {
+            data[..32].copy_from_slice(x_bytes);
+            32
+        }
+        crate::PublicKeyFormat::Compressed => {
+            data[0] = 0x02 | (y_bytes[31] & 1);
+            data[1..33].copy_from_slice(x_bytes);
+            33
+        }
+        crate::PublicKeyFormat::Uncompressed => {
+            data[0] = 0x04;
+            data[1..33].copy_from_slice(x_bytes);
+            data[33..].copy_from_slice(y_bytes);
+            65
+        }
+    };
+    EncodedPoint { data, len }
+}
+
+// Decode a point in any of the public key formats into its coordinates, checking that it is on
+// the curve. For the compact format, either y is produced (see `p256_recover_y`).
+pub fn p256_decode_point(
+    data: &[u8],
+    format: crate::PublicKeyFormat,
+) -> Result<([u8; 32], [u8; 32]), crate::ImportError> {
+    let coordinate = |range: core::ops::Range<usize>| -> Result<[u8; 32], crate::ImportError> {
+        data.get(range)
+            .and_then(|c| c.try_into().ok())
+            .ok_or(crate::ImportError)
+    };
+    let expected_len = match format {
+        crate::PublicKeyFormat::Compact => 32,
+        crate::PublicKeyFormat::Compressed => 33,
+        crate::PublicKeyFormat::Uncompressed => 65,
+    };
+    if data.len() != expected_len {
+        return Err(crate::ImportError);
+    }
+    match (format, data[0]) {
+        (crate::PublicKeyFormat::Compact, _) => {
+            let x = coordinate(0..32)?;
+            Ok((x, p256_recover_y(&x)?))
+        }
+        (crate::PublicKeyFormat::Compressed, prefix @ (0x02 | 0x03)) => {
+            let x = coordinate(1..33)?;
+            Ok((x, p256_decompress(&x, prefix == 0x03)?))
+        }
+        (crate::PublicKeyFormat::Uncompressed, 0x04) => {
+            let x = coordinate(1..33)?;
+            let y = coordinate(33..65)?;
+            p256_check_point(&x, &y)?;
+            Ok((x, y))
+        }
+        _ => Err(crate::ImportError),
+    }
+}
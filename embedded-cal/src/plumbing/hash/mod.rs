@@ -5,14 +5,17 @@
 //!
 //! As support for SHA-2 is commonly split by the variant's block size (SHA-224 and SHA-256 with
 //! 512 bit block size use different hardware than SHA-384, SHA-512 and its truncated versions which have
-//! 1024 bit block size), they split in [`Sha2Short`] and `Sha2Long` (currently unimplemented).
+//! 1024 bit block size), they split in [`Sha2Short`] and [`Sha2Long`].
 
+mod sha2long;
 mod sha2short;
+pub use sha2long::*;
 pub use sha2short::*;
 
-pub trait Hash: Sha2Short {}
+pub trait Hash: Sha2Short + Sha2Long {}
 
 pub const SHA2SHORT_BLOCK_SIZE: usize = 64;
+pub const SHA2LONG_BLOCK_SIZE: usize = 128;
 
 /// The maximum buffer needed to spool data for hashing through a back-end type.
 ///
@@ -25,18 +28,27 @@ pub const SHA2SHORT_BLOCK_SIZE: usize = 64;
 /// field in their struct). They could avoid this by applying some clever tricks with putting the
 /// buffer in a possibly-zero-sized array if that is really an issue.
 pub const fn hash_buffer_requirements<T: Hash>() -> usize {
-    let for_sha2 = if <T as Sha2Short>::SUPPORTED {
-        if T::FIRST_CHUNK_SIZE > SHA2SHORT_BLOCK_SIZE {
-            T::FIRST_CHUNK_SIZE
+    let for_sha2short = if <T as Sha2Short>::SUPPORTED {
+        if <T as Sha2Short>::FIRST_CHUNK_SIZE > SHA2SHORT_BLOCK_SIZE {
+            <T as Sha2Short>::FIRST_CHUNK_SIZE
         } else {
             SHA2SHORT_BLOCK_SIZE
         }
     } else {
         0
     };
-    #[allow(
-        clippy::let_and_return,
-        reason = "this will become a maximum of multiple blocks above for different hashes"
-    )]
-    for_sha2
+    let for_sha2long = if <T as Sha2Long>::SUPPORTED {
+        if <T as Sha2Long>::FIRST_CHUNK_SIZE > SHA2LONG_BLOCK_SIZE {
+            <T as Sha2Long>::FIRST_CHUNK_SIZE
+        } else {
+            SHA2LONG_BLOCK_SIZE
+        }
+    } else {
+        0
+    };
+    if for_sha2short > for_sha2long {
+        for_sha2short
+    } else {
+        for_sha2long
+    }
 }
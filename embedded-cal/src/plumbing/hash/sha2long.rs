@@ -0,0 +1,79 @@
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[derive(PartialEq, Eq, Copy, Clone)]
+pub enum Sha2LongVariant {
+    Sha384,
+    Sha512,
+    Sha512_224,
+    Sha512_256,
+}
+
+impl Sha2LongVariant {
+    /// Length of the variant's output in bytes.
+    pub const fn output_length(self) -> usize {
+        match self {
+            Sha2LongVariant::Sha384 => 48,
+            Sha2LongVariant::Sha512 => 64,
+            Sha2LongVariant::Sha512_224 => 28,
+            Sha2LongVariant::Sha512_256 => 32,
+        }
+    }
+}
+
+/// Trait indicating that there is hardware support for the SHA-2 variants with 1024 bit blocks.
+///
+/// This works like [`Sha2Short`][super::Sha2Short], and all its assumptions about the hardware
+/// apply. The differences are in the parameters of the algorithm: Blocks are
+/// [`SHA2LONG_BLOCK_SIZE`][super::SHA2LONG_BLOCK_SIZE] long, and the message length at the end of
+/// the padding is a 128 bit number.
+pub trait Sha2Long {
+    /// Whether this trait is actually supported. See [`Plumbing`][super::super::Plumbing] docs for
+    /// rationale.
+    const SUPPORTED: bool;
+
+    /// If true, the user needs to send all the padding data into the implementation through the
+    /// [`Self::update()`] function.
+    const SEND_PADDING: bool;
+
+    /// Size of the first chunk to be sent to [`Self::update()`], if it differs from the block size.
+    ///
+    /// Implementations that do not need this special handling should set this to 0.
+    const FIRST_CHUNK_SIZE: usize;
+    /// If true, the [`Self::update()`] function can be passed data from consecutive blocks.
+    const UPDATE_MULTICHUNK: bool;
+
+    /// State containing an ongoing operation.
+    ///
+    /// Analogous to [`crate::HashProvider::State`].
+    type State: Sized + Clone;
+
+    /// Initiates a [`Self::State`] according to the selected algorithm.
+    fn init(&mut self, variant: Sha2LongVariant) -> Self::State;
+    /// Iteratively sends data to be hashed into the instance.
+    ///
+    /// The requirements on chunking and padding are the same as in
+    /// [`Sha2Short::update()`][super::Sha2Short::update()].
+    ///
+    /// # Panics
+    ///
+    /// … if data lengths do not adhere to the `CHUNKS` configuration.
+    ///
+    /// (There is no need for the implementation to panic: It may also just produce a wrong result).
+    fn update(&mut self, instance: &mut Self::State, data: &[u8]);
+    /// Extracts the hash into a target slice.
+    ///
+    /// Implementations must accept overly long slices, and may write the full 64 byte of internal
+    /// state rather than the truncated output of SHA-384, SHA-512/224 or SHA-512/256 if the output
+    /// is long enough.
+    ///
+    /// # Panics
+    ///
+    /// * if the target array is insufficient for the type of hash, or
+    /// * if the last chunk data is too large and should really have been sent through the update
+    ///   function. (In particular, for `SEND_PADDING = true` implementations, if the last_chunk is
+    ///   not empty).
+    ///
+    /// (There is no need for the implementation to panic: It may also just produce a wrong result).
+    fn finalize(&mut self, instance: Self::State, last_chunk: &[u8], target: &mut [u8]);
+}
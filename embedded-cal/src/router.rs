@@ -0,0 +1,1811 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+//! A [`Cal`] that combines two complete implementations, and picks for each algorithm which one
+//! serves it.
+//!
+//! Extenders let their own algorithms shadow those of a base, and only for the algorithms they
+//! know. A [`Router`] instead offers the union of two backends' algorithms (e.g. a hardware
+//! implementation for ECDH and a software implementation for everything else). Which backend is
+//! selected when both recognize an identifier is configured through a [`Preference`]. Keys, states
+//! and outputs are [`Routed`] values that remember which backend created them, and all later
+//! operations on them are dispatched to that backend.
+//!
+//! The identifier constructors of the algorithm traits (e.g. [`DhAlgorithm::from_cose_ecdh()`])
+//! have no access to a router instance, so they can only follow the [`Preference`] type. A router
+//! additionally holds [`PreferenceLists`] that can be changed at runtime, and which are applied by
+//! its lookup methods such as [`Router::dh_algorithm()`]. For identifiers that have no such method,
+//! [`RoutedAlgorithm::select()`] applies any list.
+
+use core::marker::PhantomData;
+
+use super::*;
+use accessor::*;
+use util::LongerBuf;
+
+/// One of the two backends of a [`Router`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Backend {
+    A,
+    B,
+}
+
+impl Backend {
+    /// Preference list that selects backend A if it recognizes an identifier, and B otherwise.
+    pub const A_FIRST: &'static [Backend] = &[Backend::A, Backend::B];
+    /// Preference list that selects backend B if it recognizes an identifier, and A otherwise.
+    pub const B_FIRST: &'static [Backend] = &[Backend::B, Backend::A];
+}
+
+/// Order in which a [`Router`] consults its backends when an algorithm is selected by an
+/// identifier (e.g. through [`DhAlgorithm::from_cose_ecdh()`]).
+///
+/// There is one list for each provider; each names the backends in order of descending
+/// preference. A backend that is left out of a list is never selected for that provider. The
+/// provided values prefer backend A everywhere.
+///
+/// ```
+/// use embedded_cal::router::{Backend, Preference};
+///
+/// /// Uses the first backend for ECDH only.
+/// struct HardwareEcdh;
+///
+/// impl Preference for HardwareEcdh {
+///     const AEAD: &'static [Backend] = Backend::B_FIRST;
+///     const HASH: &'static [Backend] = Backend::B_FIRST;
+///     const HMAC: &'static [Backend] = Backend::B_FIRST;
+///     const SIGNATURE: &'static [Backend] = Backend::B_FIRST;
+///     const KEM: &'static [Backend] = Backend::B_FIRST;
+///     const BLOCK_CIPHER: &'static [Backend] = Backend::B_FIRST;
+///     const ECC: &'static [Backend] = Backend::B_FIRST;
+///     const MAC: &'static [Backend] = Backend::B_FIRST;
+///     const KEY_WRAP: &'static [Backend] = Backend::B_FIRST;
+/// }
+/// ```
+pub trait Preference {
+    const DH: &'static [Backend] = Backend::A_FIRST;
+    const AEAD: &'static [Backend] = Backend::A_FIRST;
+    const HASH: &'static [Backend] = Backend::A_FIRST;
+    const HMAC: &'static [Backend] = Backend::A_FIRST;
+    const SIGNATURE: &'static [Backend] = Backend::A_FIRST;
+    const KEM: &'static [Backend] = Backend::A_FIRST;
+    const BLOCK_CIPHER: &'static [Backend] = Backend::A_FIRST;
+    const ECC: &'static [Backend] = Backend::A_FIRST;
+    const MAC: &'static [Backend] = Backend::A_FIRST;
+    const KEY_WRAP: &'static [Backend] = Backend::A_FIRST;
+}
+
+/// [`Preference`] for backend A, falling back to backend B for any algorithm A does not
+/// recognize.
+pub struct PreferA;
+
+impl Preference for PreferA {}
+
+/// [`Preference`] for backend B, falling back to backend A for any algorithm B does not
+/// recognize.
+pub struct PreferB;
+
+impl Preference for PreferB {
+    const DH: &'static [Backend] = Backend::B_FIRST;
+    const AEAD: &'static [Backend] = Backend::B_FIRST;
+    const HASH: &'static [Backend] = Backend::B_FIRST;
+    const HMAC: &'static [Backend] = Backend::B_FIRST;
+    const SIGNATURE: &'static [Backend] = Backend::B_FIRST;
+    const KEM: &'static [Backend] = Backend::B_FIRST;
+    const BLOCK_CIPHER: &'static [Backend] = Backend::B_FIRST;
+    const ECC: &'static [Backend] = Backend::B_FIRST;
+    const MAC: &'static [Backend] = Backend::B_FIRST;
+    const KEY_WRAP: &'static [Backend] = Backend::B_FIRST;
+}
+
+/// Preference lists of a [`Router`] that can be set at runtime.
+///
+/// They start out as the lists of the router's [`Preference`] type, and are applied by the
+/// router's lookup methods (e.g. [`Router::dh_algorithm()`]).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PreferenceLists {
+    pub dh: &'static [Backend],
+    pub aead: &'static [Backend],
+    pub hash: &'static [Backend],
+    pub hmac: &'static [Backend],
+    pub signature: &'static [Backend],
+    pub kem: &'static [Backend],
+    pub block_cipher: &'static [Backend],
+    pub ecc: &'static [Backend],
+    pub mac: &'static [Backend],
+    pub key_wrap: &'static [Backend],
+}
+
+impl PreferenceLists {
+    /// The lists of a [`Preference`] type.
+    pub const fn of<P: Preference>() -> Self {
+        Self {
+            dh: P::DH,
+            aead: P::AEAD,
+            hash: P::HASH,
+            hmac: P::HMAC,
+            signature: P::SIGNATURE,
+            kem: P::KEM,
+            block_cipher: P::BLOCK_CIPHER,
+            ecc: P::ECC,
+            mac: P::MAC,
+            key_wrap: P::KEY_WRAP,
+        }
+    }
+}
+
+/// A key, state or output that was created by one of a [`Router`]'s backends.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Routed<A, B> {
+    A(A),
+    B(B),
+}
+
+impl<T: ?Sized, A: AsRef<T>, B: AsRef<T>> AsRef<T> for Routed<A, B> {
+    fn as_ref(&self) -> &T {
+        match self {
+            Routed::A(a) => a.as_ref(),
+            Routed::B(b) => b.as_ref(),
+        }
+    }
+}
+
+impl<A: zeroize::ZeroizeOnDrop, B: zeroize::ZeroizeOnDrop> zeroize::ZeroizeOnDrop for Routed<A, B> {}
+
+/// A visible secret key that was created by one of a [`Router`]'s backends.
+///
+/// This is a type of its own (rather than a [`Routed`]) so that it can be converted into the
+/// corresponding routed secret key.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum RoutedVisible<A, B> {
+    A(A),
+    B(B),
+}
+
+impl<VA: Into<SA>, VB: Into<SB>, SA, SB> From<RoutedVisible<VA, VB>> for Routed<SA, SB> {
+    fn from(key: RoutedVisible<VA, VB>) -> Self {
+        match key {
+            RoutedVisible::A(a) => Routed::A(a.into()),
+            RoutedVisible::B(b) => Routed::B(b.into()),
+        }
+    }
+}
+
+impl<A: zeroize::ZeroizeOnDrop, B: zeroize::ZeroizeOnDrop> zeroize::ZeroizeOnDrop
+    for RoutedVisible<A, B>
+{
+}
+
+/// An algorithm of one of a [`Router`]'s backends.
+///
+/// When selected by an identifier, the backends are consulted in the order given by the
+/// preference `P`. [`.select()`][Self::select()] applies a preference that is only known at
+/// runtime, and [`.new()`][Self::new()] picks a backend explicitly.
+///
+/// The `all()` methods list the algorithms of both backends, leaving out those of a less
+/// preferred backend whose identifiers are taken by the more preferred one. Identifiers that
+/// algorithms do not report (such as TLS identifiers) are not considered in that.
+pub struct RoutedAlgorithm<A, B, P>(Routed<A, B>, PhantomData<fn() -> P>);
+
+impl<A, B, P> RoutedAlgorithm<A, B, P> {
+    pub const fn new(routed: Routed<A, B>) -> Self {
+        Self(routed, PhantomData)
+    }
+
+    /// Selects the algorithm of the first backend in `preference` that provides one.
+    ///
+    /// This is what the identifier constructors do with the list from `P`; for example,
+    /// `RoutedAlgorithm::select(Backend::B_FIRST, DhAlgorithmOf::<A>::from_cose_ecdh(1),
+    /// DhAlgorithmOf::<B>::from_cose_ecdh(1))` selects P-256 of backend B if that has it,
+    /// irrespective of `P`.
+    pub fn select(preference: &[Backend], a: Option<A>, b: Option<B>) -> Option<Self> {
+        let (mut a, mut b) = (a, b);
+        preference
+            .iter()
+            .find_map(|backend| match backend {
+                Backend::A => a.take().map(Routed::A),
+                Backend::B => b.take().map(Routed::B),
+            })
+            .map(Self::new)
+    }
+
+    pub fn routed(&self) -> &Routed<A, B> {
+        &self.0
+    }
+
+    pub fn into_routed(self) -> Routed<A, B> {
+        self.0
+    }
+
+    /// Lists the algorithms of both backends that can be selected under `preference`.
+    ///
+    /// The `*_shadowed` functions tell whether any identifier of an algorithm is recognized by the
+    /// other backend.
+    fn list(
+        preference: &'static [Backend],
+        a: impl Iterator<Item = A>,
+        b: impl Iterator<Item = B>,
+        a_shadowed: impl Fn(&A) -> bool,
+        b_shadowed: impl Fn(&B) -> bool,
+    ) -> impl Iterator<Item = Self> {
+        let rank = |backend| preference.iter().position(|b| *b == backend);
+        let (a_rank, b_rank) = (rank(Backend::A), rank(Backend::B));
+        let a_behind = matches!((a_rank, b_rank), (Some(a), Some(b)) if a > b);
+        let b_behind = matches!((a_rank, b_rank), (Some(a), Some(b)) if b > a);
+        a.filter(move |x| a_rank.is_some() && !(a_behind && a_shadowed(x)))
+            .map(Routed::A)
+            .chain(
+                b.filter(move |x| b_rank.is_some() && !(b_behind && b_shadowed(x)))
+                    .map(Routed::B),
+            )
+            .map(Self::new)
+    }
+}
+
+// can't derive because P is not Clone
+impl<A: Clone, B: Clone, P> Clone for RoutedAlgorithm<A, B, P> {
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+// can't derive because P is not Debug
+impl<A: core::fmt::Debug, B: core::fmt::Debug, P> core::fmt::Debug for RoutedAlgorithm<A, B, P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+// can't derive because P is not PartialEq
+impl<A: PartialEq, B: PartialEq, P> PartialEq for RoutedAlgorithm<A, B, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<A: Eq, B: Eq, P> Eq for RoutedAlgorithm<A, B, P> {}
+
+impl<A: AeadAlgorithm, B: AeadAlgorithm, P: Preference> AeadAlgorithm for RoutedAlgorithm<A, B, P> {
+    fn key_length(&self) -> usize {
+        match &self.0 {
+            Routed::A(a) => a.key_length(),
+            Routed::B(b) => b.key_length(),
+        }
+    }
+
+    fn tag_length(&self) -> usize {
+        match &self.0 {
+            Routed::A(a) => a.tag_length(),
+            Routed::B(b) => b.tag_length(),
+        }
+    }
+
+    fn nonce_length(&self) -> usize {
+        match &self.0 {
+            Routed::A(a) => a.nonce_length(),
+            Routed::B(b) => b.nonce_length(),
+        }
+    }
+
+    #[inline]
+    fn from_cose_number(number: impl Into<i128>) -> Option<Self> {
+        let number = number.into();
+        Self::select(
+            P::AEAD,
+            A::from_cose_number(number),
+            B::from_cose_number(number),
+        )
+    }
+
+    #[inline]
+    fn from_tls_cipher_suite(suite: u16) -> Option<Self> {
+        Self::select(
+            P::AEAD,
+            A::from_tls_cipher_suite(suite),
+            B::from_tls_cipher_suite(suite),
+        )
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        Self::list(
+            P::AEAD,
+            A::all(),
+            B::all(),
+            |a| a.cose_number().and_then(B::from_cose_number).is_some(),
+            |b| b.cose_number().and_then(A::from_cose_number).is_some(),
+        )
+    }
+
+    fn cose_number(&self) -> Option<i128> {
+        match &self.0 {
+            Routed::A(a) => a.cose_number(),
+            Routed::B(b) => b.cose_number(),
+        }
+    }
+
+    fn ni_id(&self) -> Option<u8> {
+        match &self.0 {
+            Routed::A(a) => a.ni_id(),
+            Routed::B(b) => b.ni_id(),
+        }
+    }
+
+    fn ni_name(&self) -> Option<&'static str> {
+        match &self.0 {
+            Routed::A(a) => a.ni_name(),
+            Routed::B(b) => b.ni_name(),
+        }
+    }
+}
+
+impl<A: BlockCipherAlgorithm, B: BlockCipherAlgorithm, P: Preference> BlockCipherAlgorithm
+    for RoutedAlgorithm<A, B, P>
+{
+    fn key_length(&self) -> usize {
+        match &self.0 {
+            Routed::A(a) => a.key_length(),
+            Routed::B(b) => b.key_length(),
+        }
+    }
+
+    #[inline]
+    fn from_aes_key_length(length: usize) -> Option<Self> {
+        Self::select(
+            P::BLOCK_CIPHER,
+            A::from_aes_key_length(length),
+            B::from_aes_key_length(length),
+        )
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        // The key length is the identifier of the algorithms that are selected by it.
+        Self::list(
+            P::BLOCK_CIPHER,
+            A::all(),
+            B::all(),
+            |a| {
+                A::from_aes_key_length(a.key_length()).as_ref() == Some(a)
+                    && B::from_aes_key_length(a.key_length()).is_some()
+            },
+            |b| {
+                B::from_aes_key_length(b.key_length()).as_ref() == Some(b)
+                    && A::from_aes_key_length(b.key_length()).is_some()
+            },
+        )
+    }
+}
+
+impl<A: DhAlgorithm, B: DhAlgorithm, P: Preference> DhAlgorithm for RoutedAlgorithm<A, B, P> {
+    fn output_length(&self) -> usize {
+        match &self.0 {
+            Routed::A(a) => a.output_length(),
+            Routed::B(b) => b.output_length(),
+        }
+    }
+
+    #[inline]
+    fn from_cose_ecdh(curve: impl Into<i128>) -> Option<Self> {
+        let curve = curve.into();
+        Self::select(P::DH, A::from_cose_ecdh(curve), B::from_cose_ecdh(curve))
+    }
+
+    #[inline]
+    fn from_tls_named_group(group: u16) -> Option<Self> {
+        Self::select(
+            P::DH,
+            A::from_tls_named_group(group),
+            B::from_tls_named_group(group),
+        )
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        Self::list(
+            P::DH,
+            A::all(),
+            B::all(),
+            |a| a.cose_ecdh().and_then(B::from_cose_ecdh).is_some(),
+            |b| b.cose_ecdh().and_then(A::from_cose_ecdh).is_some(),
+        )
+    }
+
+    fn cose_ecdh(&self) -> Option<i128> {
+        match &self.0 {
+            Routed::A(a) => a.cose_ecdh(),
+            Routed::B(b) => b.cose_ecdh(),
+        }
+    }
+
+    fn ni_id(&self) -> Option<u8> {
+        match &self.0 {
+            Routed::A(a) => a.ni_id(),
+            Routed::B(b) => b.ni_id(),
+        }
+    }
+
+    fn ni_name(&self) -> Option<&'static str> {
+        match &self.0 {
+            Routed::A(a) => a.ni_name(),
+            Routed::B(b) => b.ni_name(),
+        }
+    }
+}
+
+impl<A: EccAlgorithm, B: EccAlgorithm, P: Preference> EccAlgorithm for RoutedAlgorithm<A, B, P> {
+    fn coordinate_length(&self) -> usize {
+        match &self.0 {
+            Routed::A(a) => a.coordinate_length(),
+            Routed::B(b) => b.coordinate_length(),
+        }
+    }
+
+    fn scalar_length(&self) -> usize {
+        match &self.0 {
+            Routed::A(a) => a.scalar_length(),
+            Routed::B(b) => b.scalar_length(),
+        }
+    }
+
+    #[inline]
+    fn from_cose_curve(curve: impl Into<i128>) -> Option<Self> {
+        let curve = curve.into();
+        Self::select(P::ECC, A::from_cose_curve(curve), B::from_cose_curve(curve))
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        Self::list(
+            P::ECC,
+            A::all(),
+            B::all(),
+            |a| a.cose_curve().and_then(B::from_cose_curve).is_some(),
+            |b| b.cose_curve().and_then(A::from_cose_curve).is_some(),
+        )
+    }
+
+    fn cose_curve(&self) -> Option<i128> {
+        match &self.0 {
+            Routed::A(a) => a.cose_curve(),
+            Routed::B(b) => b.cose_curve(),
+        }
+    }
+}
+
+impl<A: HashAlgorithm, B: HashAlgorithm, P: Preference> HashAlgorithm for RoutedAlgorithm<A, B, P> {
+    fn len(&self) -> usize {
+        match &self.0 {
+            Routed::A(a) => a.len(),
+            Routed::B(b) => b.len(),
+        }
+    }
+
+    #[inline]
+    fn from_cose_number(number: impl Into<i128>) -> Option<Self> {
+        let number = number.into();
+        Self::select(
+            P::HASH,
+            A::from_cose_number(number),
+            B::from_cose_number(number),
+        )
+    }
+
+    #[inline]
+    fn from_ni_id(number: u8) -> Option<Self> {
+        Self::select(P::HASH, A::from_ni_id(number), B::from_ni_id(number))
+    }
+
+    #[inline]
+    fn from_ni_name(name: &str) -> Option<Self> {
+        Self::select(P::HASH, A::from_ni_name(name), B::from_ni_name(name))
+    }
+
+    #[inline]
+    fn from_tls_hash_algorithm(number: u8) -> Option<Self> {
+        Self::select(
+            P::HASH,
+            A::from_tls_hash_algorithm(number),
+            B::from_tls_hash_algorithm(number),
+        )
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        fn shadowed<H: HashAlgorithm, O: HashAlgorithm>(h: &H) -> bool {
+            h.cose_number().and_then(O::from_cose_number).is_some()
+                || h.ni_id().and_then(O::from_ni_id).is_some()
+                || h.ni_name().and_then(O::from_ni_name).is_some()
+        }
+        Self::list(
+            P::HASH,
+            A::all(),
+            B::all(),
+            shadowed::<A, B>,
+            shadowed::<B, A>,
+        )
+    }
+
+    fn cose_number(&self) -> Option<i128> {
+        match &self.0 {
+            Routed::A(a) => a.cose_number(),
+            Routed::B(b) => b.cose_number(),
+        }
+    }
+
+    fn ni_id(&self) -> Option<u8> {
+        match &self.0 {
+            Routed::A(a) => a.ni_id(),
+            Routed::B(b) => b.ni_id(),
+        }
+    }
+
+    fn ni_name(&self) -> Option<&'static str> {
+        match &self.0 {
+            Routed::A(a) => a.ni_name(),
+            Routed::B(b) => b.ni_name(),
+        }
+    }
+}
+
+impl<A: HmacAlgorithm, B: HmacAlgorithm, P: Preference> HmacAlgorithm for RoutedAlgorithm<A, B, P> {
+    const MAX_LEN: usize = if A::MAX_LEN >= B::MAX_LEN {
+        A::MAX_LEN
+    } else {
+        B::MAX_LEN
+    };
+
+    type MaxLenBuf = LongerBuf<A::MaxLenBuf, B::MaxLenBuf>;
+
+    fn len(&self) -> usize {
+        match &self.0 {
+            Routed::A(a) => a.len(),
+            Routed::B(b) => b.len(),
+        }
+    }
+
+    #[inline]
+    fn from_cose_number(number: impl Into<i128>) -> Option<Self> {
+        let number = number.into();
+        Self::select(
+            P::HMAC,
+            A::from_cose_number(number),
+            B::from_cose_number(number),
+        )
+    }
+
+    #[inline]
+    fn from_tls_hash_algorithm(number: u8) -> Option<Self> {
+        Self::select(
+            P::HMAC,
+            A::from_tls_hash_algorithm(number),
+            B::from_tls_hash_algorithm(number),
+        )
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        Self::list(
+            P::HMAC,
+            A::all(),
+            B::all(),
+            |a| a.cose_number().and_then(B::from_cose_number).is_some(),
+            |b| b.cose_number().and_then(A::from_cose_number).is_some(),
+        )
+    }
+
+    fn cose_number(&self) -> Option<i128> {
+        match &self.0 {
+            Routed::A(a) => a.cose_number(),
+            Routed::B(b) => b.cose_number(),
+        }
+    }
+
+    fn ni_id(&self) -> Option<u8> {
+        match &self.0 {
+            Routed::A(a) => a.ni_id(),
+            Routed::B(b) => b.ni_id(),
+        }
+    }
+
+    fn ni_name(&self) -> Option<&'static str> {
+        match &self.0 {
+            Routed::A(a) => a.ni_name(),
+            Routed::B(b) => b.ni_name(),
+        }
+    }
+}
+
+impl<A: KemAlgorithm, B: KemAlgorithm, P: Preference> KemAlgorithm for RoutedAlgorithm<A, B, P> {
+    fn shared_secret_length(&self) -> usize {
+        match &self.0 {
+            Routed::A(a) => a.shared_secret_length(),
+            Routed::B(b) => b.shared_secret_length(),
+        }
+    }
+
+    fn ciphertext_length(&self) -> usize {
+        match &self.0 {
+            Routed::A(a) => a.ciphertext_length(),
+            Routed::B(b) => b.ciphertext_length(),
+        }
+    }
+
+    fn public_key_length(&self) -> usize {
+        match &self.0 {
+            Routed::A(a) => a.public_key_length(),
+            Routed::B(b) => b.public_key_length(),
+        }
+    }
+
+    #[inline]
+    fn from_hpke_kem_id(id: u16) -> Option<Self> {
+        Self::select(P::KEM, A::from_hpke_kem_id(id), B::from_hpke_kem_id(id))
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        Self::list(
+            P::KEM,
+            A::all(),
+            B::all(),
+            |a| a.hpke_kem_id().and_then(B::from_hpke_kem_id).is_some(),
+            |b| b.hpke_kem_id().and_then(A::from_hpke_kem_id).is_some(),
+        )
+    }
+
+    fn hpke_kem_id(&self) -> Option<u16> {
+        match &self.0 {
+            Routed::A(a) => a.hpke_kem_id(),
+            Routed::B(b) => b.hpke_kem_id(),
+        }
+    }
+}
+
+impl<A: KeyWrapAlgorithm, B: KeyWrapAlgorithm, P: Preference> KeyWrapAlgorithm
+    for RoutedAlgorithm<A, B, P>
+{
+    fn key_length(&self) -> usize {
+        match &self.0 {
+            Routed::A(a) => a.key_length(),
+            Routed::B(b) => b.key_length(),
+        }
+    }
+
+    #[inline]
+    fn from_cose_number(number: impl Into<i128>) -> Option<Self> {
+        let number = number.into();
+        Self::select(
+            P::KEY_WRAP,
+            A::from_cose_number(number),
+            B::from_cose_number(number),
+        )
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        Self::list(
+            P::KEY_WRAP,
+            A::all(),
+            B::all(),
+            |a| a.cose_number().and_then(B::from_cose_number).is_some(),
+            |b| b.cose_number().and_then(A::from_cose_number).is_some(),
+        )
+    }
+
+    fn cose_number(&self) -> Option<i128> {
+        match &self.0 {
+            Routed::A(a) => a.cose_number(),
+            Routed::B(b) => b.cose_number(),
+        }
+    }
+}
+
+impl<A: MacAlgorithm, B: MacAlgorithm, P: Preference> MacAlgorithm for RoutedAlgorithm<A, B, P> {
+    fn len(&self) -> usize {
+        match &self.0 {
+            Routed::A(a) => a.len(),
+            Routed::B(b) => b.len(),
+        }
+    }
+
+    fn key_length(&self) -> usize {
+        match &self.0 {
+            Routed::A(a) => a.key_length(),
+            Routed::B(b) => b.key_length(),
+        }
+    }
+
+    #[inline]
+    fn from_aes_cmac_key_length(length: usize) -> Option<Self> {
+        Self::select(
+            P::MAC,
+            A::from_aes_cmac_key_length(length),
+            B::from_aes_cmac_key_length(length),
+        )
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        Self::list(
+            P::MAC,
+            A::all(),
+            B::all(),
+            |a| {
+                A::from_aes_cmac_key_length(a.key_length()).as_ref() == Some(a)
+                    && B::from_aes_cmac_key_length(a.key_length()).is_some()
+            },
+            |b| {
+                B::from_aes_cmac_key_length(b.key_length()).as_ref() == Some(b)
+                    && A::from_aes_cmac_key_length(b.key_length()).is_some()
+            },
+        )
+    }
+}
+
+impl<A: SignatureAlgorithm, B: SignatureAlgorithm, P: Preference> SignatureAlgorithm
+    for RoutedAlgorithm<A, B, P>
+{
+    fn signature_length(&self) -> usize {
+        match &self.0 {
+            Routed::A(a) => a.signature_length(),
+            Routed::B(b) => b.signature_length(),
+        }
+    }
+
+    #[inline]
+    fn from_cose_number(number: impl Into<i128>) -> Option<Self> {
+        let number = number.into();
+        Self::select(
+            P::SIGNATURE,
+            A::from_cose_number(number),
+            B::from_cose_number(number),
+        )
+    }
+
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        Self::list(
+            P::SIGNATURE,
+            A::all(),
+            B::all(),
+            |a| a.cose_number().and_then(B::from_cose_number).is_some(),
+            |b| b.cose_number().and_then(A::from_cose_number).is_some(),
+        )
+    }
+
+    fn cose_number(&self) -> Option<i128> {
+        match &self.0 {
+            Routed::A(a) => a.cose_number(),
+            Routed::B(b) => b.cose_number(),
+        }
+    }
+}
+
+/// A [`Cal`] that serves the algorithms of two backends `A` and `B`.
+///
+/// See the [module level documentation][self] for how algorithms are assigned to the backends.
+///
+/// Values of different backends can not be combined: For example, deriving a shared secret from a
+/// secret key of one backend and a public key of the other reports [`IncompatibleKeys`], even if
+/// both are for the same curve.
+pub struct Router<A: Cal, B: Cal, P: Preference = PreferA> {
+    a: A,
+    b: B,
+    preference: PreferenceLists,
+    _preference: PhantomData<fn() -> P>,
+}
+
+impl<A: Cal, B: Cal, P: Preference> Router<A, B, P> {
+    pub fn new(a: A, b: B) -> Self {
+        Self {
+            a,
+            b,
+            preference: PreferenceLists::of::<P>(),
+            _preference: PhantomData,
+        }
+    }
+
+    /// The preference lists that the lookup methods apply.
+    pub fn preference(&self) -> &PreferenceLists {
+        &self.preference
+    }
+
+    /// Replaces the preference lists that the lookup methods apply.
+    ///
+    /// This does not affect algorithms that were looked up before.
+    pub fn set_preference(&mut self, preference: PreferenceLists) {
+        self.preference = preference;
+    }
+
+    /// Selects a DH algorithm by its COSE elliptic curve, following the current preference.
+    pub fn dh_algorithm(&self, curve: impl Into<i128>) -> Option<DhAlgorithmOf<Self>> {
+        let curve = curve.into();
+        RoutedAlgorithm::select(
+            self.preference.dh,
+            DhAlgorithmOf::<A>::from_cose_ecdh(curve),
+            DhAlgorithmOf::<B>::from_cose_ecdh(curve),
+        )
+    }
+
+    /// Selects an AEAD algorithm by its COSE number, following the current preference.
+    pub fn aead_algorithm(&self, number: impl Into<i128>) -> Option<AeadAlgorithmOf<Self>> {
+        let number = number.into();
+        RoutedAlgorithm::select(
+            self.preference.aead,
+            AeadAlgorithmOf::<A>::from_cose_number(number),
+            AeadAlgorithmOf::<B>::from_cose_number(number),
+        )
+    }
+
+    /// Selects a hash algorithm by its COSE number, following the current preference.
+    pub fn hash_algorithm(&self, number: impl Into<i128>) -> Option<HashAlgorithmOf<Self>> {
+        let number = number.into();
+        RoutedAlgorithm::select(
+            self.preference.hash,
+            HashAlgorithmOf::<A>::from_cose_number(number),
+            HashAlgorithmOf::<B>::from_cose_number(number),
+        )
+    }
+
+    /// Selects an HMAC algorithm by its COSE number, following the current preference.
+    pub fn hmac_algorithm(&self, number: impl Into<i128>) -> Option<HmacAlgorithmOf<Self>> {
+        let number = number.into();
+        RoutedAlgorithm::select(
+            self.preference.hmac,
+            HmacAlgorithmOf::<A>::from_cose_number(number),
+            HmacAlgorithmOf::<B>::from_cose_number(number),
+        )
+    }
+
+    /// Selects a signature algorithm by its COSE number, following the current preference.
+    pub fn signature_algorithm(
+        &self,
+        number: impl Into<i128>,
+    ) -> Option<SignatureAlgorithmOf<Self>> {
+        let number = number.into();
+        RoutedAlgorithm::select(
+            self.preference.signature,
+            SignatureAlgorithmOf::<A>::from_cose_number(number),
+            SignatureAlgorithmOf::<B>::from_cose_number(number),
+        )
+    }
+
+    /// Selects a KEM by its HPKE KEM identifier, following the current preference.
+    pub fn kem_algorithm(&self, id: u16) -> Option<KemAlgorithmOf<Self>> {
+        RoutedAlgorithm::select(
+            self.preference.kem,
+            KemAlgorithmOf::<A>::from_hpke_kem_id(id),
+            KemAlgorithmOf::<B>::from_hpke_kem_id(id),
+        )
+    }
+
+    /// Selects AES with the given key length, following the current preference.
+    pub fn block_cipher_algorithm(&self, length: usize) -> Option<BlockCipherAlgorithmOf<Self>> {
+        RoutedAlgorithm::select(
+            self.preference.block_cipher,
+            BlockCipherAlgorithmOf::<A>::from_aes_key_length(length),
+            BlockCipherAlgorithmOf::<B>::from_aes_key_length(length),
+        )
+    }
+
+    /// Selects an elliptic curve by its COSE number, following the current preference.
+    pub fn ecc_algorithm(&self, curve: impl Into<i128>) -> Option<EccAlgorithmOf<Self>> {
+        let curve = curve.into();
+        RoutedAlgorithm::select(
+            self.preference.ecc,
+            EccAlgorithmOf::<A>::from_cose_curve(curve),
+            EccAlgorithmOf::<B>::from_cose_curve(curve),
+        )
+    }
+
+    /// Selects AES-CMAC with the given key length, following the current preference.
+    pub fn mac_algorithm(&self, length: usize) -> Option<MacAlgorithmOf<Self>> {
+        RoutedAlgorithm::select(
+            self.preference.mac,
+            MacAlgorithmOf::<A>::from_aes_cmac_key_length(length),
+            MacAlgorithmOf::<B>::from_aes_cmac_key_length(length),
+        )
+    }
+
+    /// Selects a key wrapping algorithm by its COSE number, following the current preference.
+    pub fn key_wrap_algorithm(&self, number: impl Into<i128>) -> Option<KeyWrapAlgorithmOf<Self>> {
+        let number = number.into();
+        RoutedAlgorithm::select(
+            self.preference.key_wrap,
+            KeyWrapAlgorithmOf::<A>::from_cose_number(number),
+            KeyWrapAlgorithmOf::<B>::from_cose_number(number),
+        )
+    }
+
+    /// Accesses backend A, e.g. to use its functionality that is not part of [`Cal`].
+    pub fn a(&mut self) -> &mut A {
+        &mut self.a
+    }
+
+    /// Accesses backend B, e.g. to use its functionality that is not part of [`Cal`].
+    pub fn b(&mut self) -> &mut B {
+        &mut self.b
+    }
+
+    pub fn into_inner(self) -> (A, B) {
+        (self.a, self.b)
+    }
+}
+
+impl<A: Cal, B: Cal, P: Preference> Cal for Router<A, B, P> {
+    type DhProvider = Self;
+    type AeadProvider = Self;
+    type HashProvider = Self;
+    type HmacProvider = Self;
+    type SignatureProvider = Self;
+    type KemProvider = Self;
+    type BlockCipherProvider = Self;
+    type EccProvider = Self;
+    type MacProvider = Self;
+    type KeyWrapProvider = Self;
+
+    fn dh(&mut self) -> &mut Self::DhProvider {
+        self
+    }
+
+    fn aead(&mut self) -> &mut Self::AeadProvider {
+        self
+    }
+
+    fn hash(&mut self) -> &mut Self::HashProvider {
+        self
+    }
+
+    fn hmac(&mut self) -> &mut Self::HmacProvider {
+        self
+    }
+
+    fn signature(&mut self) -> &mut Self::SignatureProvider {
+        self
+    }
+
+    fn kem(&mut self) -> &mut Self::KemProvider {
+        self
+    }
+
+    fn block_cipher(&mut self) -> &mut Self::BlockCipherProvider {
+        self
+    }
+
+    fn ecc(&mut self) -> &mut Self::EccProvider {
+        self
+    }
+
+    fn mac(&mut self) -> &mut Self::MacProvider {
+        self
+    }
+
+    fn key_wrap(&mut self) -> &mut Self::KeyWrapProvider {
+        self
+    }
+
+    fn unwrap_aead_key(
+        &mut self,
+        kek: &KeyWrapKeyOf<Self>,
+        wrapped: &[u8],
+        alg: AeadAlgorithmOf<Self>,
+    ) -> Result<AeadKeyOf<Self>, DecryptionFailed> {
+        // Where both are on one backend, it may keep the key inaccessible.
+        match (kek, alg.into_routed()) {
+            (Routed::A(kek), Routed::A(alg)) => {
+                self.a.unwrap_aead_key(kek, wrapped, alg).map(Routed::A)
+            }
+            (Routed::B(kek), Routed::B(alg)) => {
+                self.b.unwrap_aead_key(kek, wrapped, alg).map(Routed::B)
+            }
+            (Routed::A(kek), Routed::B(alg)) => unwrap_through_buffer(
+                self,
+                wrapped,
+                alg.key_length(),
+                |router, buffer| router.a.key_wrap().unwrap_key(kek, wrapped, buffer),
+                |router, key| Routed::B(router.b.aead().load_from_keydata(alg, key)),
+            ),
+            (Routed::B(kek), Routed::A(alg)) => unwrap_through_buffer(
+                self,
+                wrapped,
+                alg.key_length(),
+                |router, buffer| router.b.key_wrap().unwrap_key(kek, wrapped, buffer),
+                |router, key| Routed::A(router.a.aead().load_from_keydata(alg, key)),
+            ),
+        }
+    }
+}
+
+impl<A: Cal, B: Cal, P: Preference> AeadProvider for Router<A, B, P> {
+    type Algorithm = RoutedAlgorithm<AeadAlgorithmOf<A>, AeadAlgorithmOf<B>, P>;
+    type Key = Routed<AeadKeyOf<A>, AeadKeyOf<B>>;
+    type Tag = Routed<AeadTagOf<A>, AeadTagOf<B>>;
+
+    fn try_load_from_keydata(
+        &mut self,
+        alg: Self::Algorithm,
+        key: &[u8],
+    ) -> Result<Self::Key, OperationError> {
+        Ok(match alg.into_routed() {
+            Routed::A(alg) => Routed::A(self.a.aead().try_load_from_keydata(alg, key)?),
+            Routed::B(alg) => Routed::B(self.b.aead().try_load_from_keydata(alg, key)?),
+        })
+    }
+
+    fn try_encrypt_in_place(
+        &mut self,
+        key: &Self::Key,
+        nonce: &[u8],
+        message: &mut [u8],
+        aad: impl AadGenerator,
+    ) -> Result<Self::Tag, OperationError> {
+        Ok(match key {
+            Routed::A(key) => Routed::A(
+                self.a
+                    .aead()
+                    .try_encrypt_in_place(key, nonce, message, aad)?,
+            ),
+            Routed::B(key) => Routed::B(
+                self.b
+                    .aead()
+                    .try_encrypt_in_place(key, nonce, message, aad)?,
+            ),
+        })
+    }
+
+    fn try_decrypt_in_place(
+        &mut self,
+        key: &Self::Key,
+        nonce: &[u8],
+        message: &mut [u8],
+        tag: &[u8],
+        aad: impl AadGenerator,
+    ) -> Result<(), TryError<DecryptionFailed>> {
+        match key {
+            Routed::A(key) => self
+                .a
+                .aead()
+                .try_decrypt_in_place(key, nonce, message, tag, aad),
+            Routed::B(key) => self
+                .b
+                .aead()
+                .try_decrypt_in_place(key, nonce, message, tag, aad),
+        }
+    }
+}
+
+impl<A: Cal, B: Cal, P: Preference> BlockCipherProvider for Router<A, B, P> {
+    type Algorithm = RoutedAlgorithm<BlockCipherAlgorithmOf<A>, BlockCipherAlgorithmOf<B>, P>;
+    type Key = Routed<BlockCipherKeyOf<A>, BlockCipherKeyOf<B>>;
+
+    fn load_from_keydata(&mut self, alg: Self::Algorithm, key: &[u8]) -> Self::Key {
+        match alg.into_routed() {
+            Routed::A(alg) => Routed::A(self.a.block_cipher().load_from_keydata(alg, key)),
+            Routed::B(alg) => Routed::B(self.b.block_cipher().load_from_keydata(alg, key)),
+        }
+    }
+
+    fn encrypt_block(&mut self, key: &Self::Key, block: &mut [u8; BLOCK_SIZE]) {
+        match key {
+            Routed::A(key) => self.a.block_cipher().encrypt_block(key, block),
+            Routed::B(key) => self.b.block_cipher().encrypt_block(key, block),
+        }
+    }
+
+    fn decrypt_block(&mut self, key: &Self::Key, block: &mut [u8; BLOCK_SIZE]) {
+        match key {
+            Routed::A(key) => self.a.block_cipher().decrypt_block(key, block),
+            Routed::B(key) => self.b.block_cipher().decrypt_block(key, block),
+        }
+    }
+
+    fn encrypt_blocks(&mut self, key: &Self::Key, blocks: &mut [[u8; BLOCK_SIZE]]) {
+        match key {
+            Routed::A(key) => self.a.block_cipher().encrypt_blocks(key, blocks),
+            Routed::B(key) => self.b.block_cipher().encrypt_blocks(key, blocks),
+        }
+    }
+
+    fn decrypt_blocks(&mut self, key: &Self::Key, blocks: &mut [[u8; BLOCK_SIZE]]) {
+        match key {
+            Routed::A(key) => self.a.block_cipher().decrypt_blocks(key, blocks),
+            Routed::B(key) => self.b.block_cipher().decrypt_blocks(key, blocks),
+        }
+    }
+}
+
+impl<A: Cal, B: Cal, P: Preference> DhProvider for Router<A, B, P> {
+    type Algorithm = RoutedAlgorithm<DhAlgorithmOf<A>, DhAlgorithmOf<B>, P>;
+    type VisibleSecretKey = RoutedVisible<DhVisibleSecretKeyOf<A>, DhVisibleSecretKeyOf<B>>;
+    type SecretKey = Routed<DhSecretKeyOf<A>, DhSecretKeyOf<B>>;
+    type PublicKey = Routed<DhPublicKeyOf<A>, DhPublicKeyOf<B>>;
+    type SharedSecret = Routed<DhSharedSecretOf<A>, DhSharedSecretOf<B>>;
+
+    fn try_generate_visible(
+        &mut self,
+        alg: Self::Algorithm,
+    ) -> Result<Self::VisibleSecretKey, OperationError> {
+        Ok(match alg.into_routed() {
+            Routed::A(alg) => RoutedVisible::A(self.a.dh().try_generate_visible(alg)?),
+            Routed::B(alg) => RoutedVisible::B(self.b.dh().try_generate_visible(alg)?),
+        })
+    }
+
+    fn try_generate(&mut self, alg: Self::Algorithm) -> Result<Self::SecretKey, OperationError> {
+        Ok(match alg.into_routed() {
+            Routed::A(alg) => Routed::A(self.a.dh().try_generate(alg)?),
+            Routed::B(alg) => Routed::B(self.b.dh().try_generate(alg)?),
+        })
+    }
+
+    fn export_secretkey_bytes<'s>(
+        &mut self,
+        secretkey: &'s Self::VisibleSecretKey,
+    ) -> impl AsRef<[u8]> + use<'s, A, B, P> {
+        match secretkey {
+            RoutedVisible::A(k) => Routed::A(self.a.dh().export_secretkey_bytes(k)),
+            RoutedVisible::B(k) => Routed::B(self.b.dh().export_secretkey_bytes(k)),
+        }
+    }
+
+    fn import_secretkey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        secret: &[u8],
+    ) -> Result<Self::VisibleSecretKey, ImportError> {
+        Ok(match alg.into_routed() {
+            Routed::A(alg) => RoutedVisible::A(self.a.dh().import_secretkey_bytes(alg, secret)?),
+            Routed::B(alg) => RoutedVisible::B(self.b.dh().import_secretkey_bytes(alg, secret)?),
+        })
+    }
+
+    fn export_publickey_bytes<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+    ) -> impl AsRef<[u8]> + use<'p, A, B, P> {
+        match public {
+            Routed::A(p) => Routed::A(self.a.dh().export_publickey_bytes(p)),
+            Routed::B(p) => Routed::B(self.b.dh().export_publickey_bytes(p)),
+        }
+    }
+
+    fn import_publickey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::PublicKey, ImportError> {
+        Ok(match alg.into_routed() {
+            Routed::A(alg) => Routed::A(self.a.dh().import_publickey_bytes(alg, data)?),
+            Routed::B(alg) => Routed::B(self.b.dh().import_publickey_bytes(alg, data)?),
+        })
+    }
+
+    fn export_publickey<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+        format: PublicKeyFormat,
+    ) -> Result<impl AsRef<[u8]> + use<'p, A, B, P>, UnsupportedFormat> {
+        Ok(match public {
+            Routed::A(p) => Routed::A(self.a.dh().export_publickey(p, format)?),
+            Routed::B(p) => Routed::B(self.b.dh().export_publickey(p, format)?),
+        })
+    }
+
+    fn import_publickey(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+        format: PublicKeyFormat,
+    ) -> Result<Self::PublicKey, ImportError> {
+        Ok(match alg.into_routed() {
+            Routed::A(alg) => Routed::A(self.a.dh().import_publickey(alg, data, format)?),
+            Routed::B(alg) => Routed::B(self.b.dh().import_publickey(alg, data, format)?),
+        })
+    }
+
+    fn try_shared_secret(
+        &mut self,
+        private: &Self::SecretKey,
+        public: &Self::PublicKey,
+    ) -> Result<Self::SharedSecret, TryError<IncompatibleKeys>> {
+        Ok(match (private, public) {
+            (Routed::A(s), Routed::A(p)) => Routed::A(self.a.dh().try_shared_secret(s, p)?),
+            (Routed::B(s), Routed::B(p)) => Routed::B(self.b.dh().try_shared_secret(s, p)?),
+            _ => return Err(TryError::Failed(IncompatibleKeys)),
+        })
+    }
+
+    fn try_public_key(
+        &mut self,
+        private: &Self::SecretKey,
+    ) -> Result<Self::PublicKey, OperationError> {
+        Ok(match private {
+            Routed::A(s) => Routed::A(self.a.dh().try_public_key(s)?),
+            Routed::B(s) => Routed::B(self.b.dh().try_public_key(s)?),
+        })
+    }
+
+    fn raw_secret_bytes<'s>(
+        &mut self,
+        secret: &'s Self::SharedSecret,
+    ) -> impl AsRef<[u8]> + use<'s, A, B, P> {
+        match secret {
+            Routed::A(s) => Routed::A(self.a.dh().raw_secret_bytes(s)),
+            Routed::B(s) => Routed::B(self.b.dh().raw_secret_bytes(s)),
+        }
+    }
+}
+
+impl<A: Cal, B: Cal, P: Preference> EccProvider for Router<A, B, P> {
+    type Algorithm = RoutedAlgorithm<EccAlgorithmOf<A>, EccAlgorithmOf<B>, P>;
+    type Point = Routed<EccPointOf<A>, EccPointOf<B>>;
+    type Scalar = Routed<EccScalarOf<A>, EccScalarOf<B>>;
+
+    fn import_point(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::Point, ImportError> {
+        Ok(match alg.into_routed() {
+            Routed::A(alg) => Routed::A(self.a.ecc().import_point(alg, data)?),
+            Routed::B(alg) => Routed::B(self.b.ecc().import_point(alg, data)?),
+        })
+    }
+
+    fn import_affine(
+        &mut self,
+        alg: Self::Algorithm,
+        x: &[u8],
+        y: &[u8],
+    ) -> Result<Self::Point, ImportError> {
+        Ok(match alg.into_routed() {
+            Routed::A(alg) => Routed::A(self.a.ecc().import_affine(alg, x, y)?),
+            Routed::B(alg) => Routed::B(self.b.ecc().import_affine(alg, x, y)?),
+        })
+    }
+
+    fn export_point<'p>(
+        &mut self,
+        point: &'p Self::Point,
+        compressed: bool,
+    ) -> impl AsRef<[u8]> + use<'p, A, B, P> {
+        match point {
+            Routed::A(p) => Routed::A(self.a.ecc().export_point(p, compressed)),
+            Routed::B(p) => Routed::B(self.b.ecc().export_point(p, compressed)),
+        }
+    }
+
+    fn import_scalar(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::Scalar, ImportError> {
+        Ok(match alg.into_routed() {
+            Routed::A(alg) => Routed::A(self.a.ecc().import_scalar(alg, data)?),
+            Routed::B(alg) => Routed::B(self.b.ecc().import_scalar(alg, data)?),
+        })
+    }
+
+    fn generator(&mut self, alg: Self::Algorithm) -> Self::Point {
+        match alg.into_routed() {
+            Routed::A(alg) => Routed::A(self.a.ecc().generator(alg)),
+            Routed::B(alg) => Routed::B(self.b.ecc().generator(alg)),
+        }
+    }
+
+    fn identity(&mut self, alg: Self::Algorithm) -> Self::Point {
+        match alg.into_routed() {
+            Routed::A(alg) => Routed::A(self.a.ecc().identity(alg)),
+            Routed::B(alg) => Routed::B(self.b.ecc().identity(alg)),
+        }
+    }
+
+    fn is_identity(&mut self, point: &Self::Point) -> bool {
+        match point {
+            Routed::A(p) => self.a.ecc().is_identity(p),
+            Routed::B(p) => self.b.ecc().is_identity(p),
+        }
+    }
+
+    fn negate(&mut self, point: &Self::Point) -> Self::Point {
+        match point {
+            Routed::A(p) => Routed::A(self.a.ecc().negate(p)),
+            Routed::B(p) => Routed::B(self.b.ecc().negate(p)),
+        }
+    }
+
+    fn add(&mut self, a: &Self::Point, b: &Self::Point) -> Result<Self::Point, IncompatibleKeys> {
+        Ok(match (a, b) {
+            (Routed::A(x), Routed::A(y)) => Routed::A(self.a.ecc().add(x, y)?),
+            (Routed::B(x), Routed::B(y)) => Routed::B(self.b.ecc().add(x, y)?),
+            _ => return Err(IncompatibleKeys),
+        })
+    }
+
+    fn mul(
+        &mut self,
+        scalar: &Self::Scalar,
+        point: &Self::Point,
+    ) -> Result<Self::Point, IncompatibleKeys> {
+        Ok(match (scalar, point) {
+            (Routed::A(k), Routed::A(p)) => Routed::A(self.a.ecc().mul(k, p)?),
+            (Routed::B(k), Routed::B(p)) => Routed::B(self.b.ecc().mul(k, p)?),
+            _ => return Err(IncompatibleKeys),
+        })
+    }
+}
+
+impl<A: Cal, B: Cal, P: Preference> HashProvider for Router<A, B, P> {
+    type Algorithm = RoutedAlgorithm<HashAlgorithmOf<A>, HashAlgorithmOf<B>, P>;
+    type State = Routed<HashStateOf<A>, HashStateOf<B>>;
+    type Output = Routed<HashOutputOf<A>, HashOutputOf<B>>;
+    type XofReader = Routed<HashXofReaderOf<A>, HashXofReaderOf<B>>;
+
+    fn try_init(&mut self, algorithm: Self::Algorithm) -> Result<Self::State, OperationError> {
+        Ok(match algorithm.into_routed() {
+            Routed::A(alg) => Routed::A(self.a.hash().try_init(alg)?),
+            Routed::B(alg) => Routed::B(self.b.hash().try_init(alg)?),
+        })
+    }
+
+    fn try_update(
+        &mut self,
+        instance: &mut Self::State,
+        data: &[u8],
+    ) -> Result<(), OperationError> {
+        match instance {
+            Routed::A(s) => self.a.hash().try_update(s, data),
+            Routed::B(s) => self.b.hash().try_update(s, data),
+        }
+    }
+
+    fn try_finalize(&mut self, instance: Self::State) -> Result<Self::Output, OperationError> {
+        Ok(match instance {
+            Routed::A(s) => Routed::A(self.a.hash().try_finalize(s)?),
+            Routed::B(s) => Routed::B(self.b.hash().try_finalize(s)?),
+        })
+    }
+
+    fn try_hash(
+        &mut self,
+        algorithm: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::Output, OperationError> {
+        Ok(match algorithm.into_routed() {
+            Routed::A(alg) => Routed::A(self.a.hash().try_hash(alg, data)?),
+            Routed::B(alg) => Routed::B(self.b.hash().try_hash(alg, data)?),
+        })
+    }
+
+    fn finalize_xof(&mut self, instance: Self::State) -> Result<Self::XofReader, Self::State> {
+        match instance {
+            Routed::A(s) => self
+                .a
+                .hash()
+                .finalize_xof(s)
+                .map(Routed::A)
+                .map_err(Routed::A),
+            Routed::B(s) => self
+                .b
+                .hash()
+                .finalize_xof(s)
+                .map(Routed::B)
+                .map_err(Routed::B),
+        }
+    }
+
+    fn squeeze(&mut self, reader: &mut Self::XofReader, output: &mut [u8]) {
+        match reader {
+            Routed::A(r) => self.a.hash().squeeze(r, output),
+            Routed::B(r) => self.b.hash().squeeze(r, output),
+        }
+    }
+}
+
+impl<A: Cal, B: Cal, P: Preference> HmacProvider for Router<A, B, P> {
+    type Algorithm = RoutedAlgorithm<HmacAlgorithmOf<A>, HmacAlgorithmOf<B>, P>;
+    type Key = Routed<HmacKeyOf<A>, HmacKeyOf<B>>;
+    type State = Routed<HmacStateOf<A>, HmacStateOf<B>>;
+    type Output = Routed<HmacOutputOf<A>, HmacOutputOf<B>>;
+
+    fn try_load_from_keydata(
+        &mut self,
+        algorithm: Self::Algorithm,
+        key: &[u8],
+    ) -> Result<Self::Key, OperationError> {
+        Ok(match algorithm.into_routed() {
+            Routed::A(alg) => Routed::A(self.a.hmac().try_load_from_keydata(alg, key)?),
+            Routed::B(alg) => Routed::B(self.b.hmac().try_load_from_keydata(alg, key)?),
+        })
+    }
+
+    fn try_init(&mut self, key: Self::Key) -> Result<Self::State, OperationError> {
+        Ok(match key {
+            Routed::A(k) => Routed::A(self.a.hmac().try_init(k)?),
+            Routed::B(k) => Routed::B(self.b.hmac().try_init(k)?),
+        })
+    }
+
+    fn try_update(&mut self, state: &mut Self::State, data: &[u8]) -> Result<(), OperationError> {
+        match state {
+            Routed::A(s) => self.a.hmac().try_update(s, data),
+            Routed::B(s) => self.b.hmac().try_update(s, data),
+        }
+    }
+
+    fn try_finalize(&mut self, state: Self::State) -> Result<Self::Output, OperationError> {
+        Ok(match state {
+            Routed::A(s) => Routed::A(self.a.hmac().try_finalize(s)?),
+            Routed::B(s) => Routed::B(self.b.hmac().try_finalize(s)?),
+        })
+    }
+
+    fn try_finalize_into_key(
+        &mut self,
+        algorithm: Self::Algorithm,
+        state: Self::State,
+    ) -> Result<Self::Key, OperationError> {
+        Ok(match (algorithm.into_routed(), state) {
+            (Routed::A(alg), Routed::A(s)) => {
+                Routed::A(self.a.hmac().try_finalize_into_key(alg, s)?)
+            }
+            (Routed::B(alg), Routed::B(s)) => {
+                Routed::B(self.b.hmac().try_finalize_into_key(alg, s)?)
+            }
+            // Passing the key between the backends is only possible through memory.
+            (Routed::A(alg), Routed::B(s)) => {
+                let output = self.b.hmac().try_finalize(s)?;
+                Routed::A(self.a.hmac().try_load_from_keydata(alg, output.as_ref())?)
+            }
+            (Routed::B(alg), Routed::A(s)) => {
+                let output = self.a.hmac().try_finalize(s)?;
+                Routed::B(self.b.hmac().try_load_from_keydata(alg, output.as_ref())?)
+            }
+        })
+    }
+
+    fn try_verify(
+        &mut self,
+        state: Self::State,
+        expected: &[u8],
+    ) -> Result<(), TryError<VerificationFailed>> {
+        match state {
+            Routed::A(s) => self.a.hmac().try_verify(s, expected),
+            Routed::B(s) => self.b.hmac().try_verify(s, expected),
+        }
+    }
+}
+
+impl<A: Cal, B: Cal, P: Preference> KemProvider for Router<A, B, P> {
+    type Algorithm = RoutedAlgorithm<KemAlgorithmOf<A>, KemAlgorithmOf<B>, P>;
+    type VisibleSecretKey = RoutedVisible<KemVisibleSecretKeyOf<A>, KemVisibleSecretKeyOf<B>>;
+    type SecretKey = Routed<KemSecretKeyOf<A>, KemSecretKeyOf<B>>;
+    type PublicKey = Routed<KemPublicKeyOf<A>, KemPublicKeyOf<B>>;
+    type Ciphertext = Routed<KemCiphertextOf<A>, KemCiphertextOf<B>>;
+    type SharedSecret = Routed<KemSharedSecretOf<A>, KemSharedSecretOf<B>>;
+
+    fn try_generate_visible(
+        &mut self,
+        alg: Self::Algorithm,
+    ) -> Result<Self::VisibleSecretKey, OperationError> {
+        Ok(match alg.into_routed() {
+            Routed::A(alg) => RoutedVisible::A(self.a.kem().try_generate_visible(alg)?),
+            Routed::B(alg) => RoutedVisible::B(self.b.kem().try_generate_visible(alg)?),
+        })
+    }
+
+    fn try_generate(&mut self, alg: Self::Algorithm) -> Result<Self::SecretKey, OperationError> {
+        Ok(match alg.into_routed() {
+            Routed::A(alg) => Routed::A(self.a.kem().try_generate(alg)?),
+            Routed::B(alg) => Routed::B(self.b.kem().try_generate(alg)?),
+        })
+    }
+
+    fn export_secretkey_bytes<'s>(
+        &mut self,
+        secretkey: &'s Self::VisibleSecretKey,
+    ) -> impl AsRef<[u8]> + use<'s, A, B, P> {
+        match secretkey {
+            RoutedVisible::A(k) => Routed::A(self.a.kem().export_secretkey_bytes(k)),
+            RoutedVisible::B(k) => Routed::B(self.b.kem().export_secretkey_bytes(k)),
+        }
+    }
+
+    fn import_secretkey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        secret: &[u8],
+    ) -> Result<Self::VisibleSecretKey, ImportError> {
+        Ok(match alg.into_routed() {
+            Routed::A(alg) => RoutedVisible::A(self.a.kem().import_secretkey_bytes(alg, secret)?),
+            Routed::B(alg) => RoutedVisible::B(self.b.kem().import_secretkey_bytes(alg, secret)?),
+        })
+    }
+
+    fn export_publickey_bytes<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+    ) -> impl AsRef<[u8]> + use<'p, A, B, P> {
+        match public {
+            Routed::A(p) => Routed::A(self.a.kem().export_publickey_bytes(p)),
+            Routed::B(p) => Routed::B(self.b.kem().export_publickey_bytes(p)),
+        }
+    }
+
+    fn import_publickey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::PublicKey, ImportError> {
+        Ok(match alg.into_routed() {
+            Routed::A(alg) => Routed::A(self.a.kem().import_publickey_bytes(alg, data)?),
+            Routed::B(alg) => Routed::B(self.b.kem().import_publickey_bytes(alg, data)?),
+        })
+    }
+
+    fn public_key(&mut self, private: &Self::SecretKey) -> Self::PublicKey {
+        match private {
+            Routed::A(s) => Routed::A(self.a.kem().public_key(s)),
+            Routed::B(s) => Routed::B(self.b.kem().public_key(s)),
+        }
+    }
+
+    fn try_encapsulate(
+        &mut self,
+        public: &Self::PublicKey,
+    ) -> Result<(Self::Ciphertext, Self::SharedSecret), OperationError> {
+        Ok(match public {
+            Routed::A(p) => {
+                let (ciphertext, secret) = self.a.kem().try_encapsulate(p)?;
+                (Routed::A(ciphertext), Routed::A(secret))
+            }
+            Routed::B(p) => {
+                let (ciphertext, secret) = self.b.kem().try_encapsulate(p)?;
+                (Routed::B(ciphertext), Routed::B(secret))
+            }
+        })
+    }
+
+    fn decapsulate(
+        &mut self,
+        private: &Self::SecretKey,
+        ciphertext: &[u8],
+    ) -> Result<Self::SharedSecret, ImportError> {
+        Ok(match private {
+            Routed::A(s) => Routed::A(self.a.kem().decapsulate(s, ciphertext)?),
+            Routed::B(s) => Routed::B(self.b.kem().decapsulate(s, ciphertext)?),
+        })
+    }
+
+    fn raw_secret_bytes<'s>(
+        &mut self,
+        secret: &'s Self::SharedSecret,
+    ) -> impl AsRef<[u8]> + use<'s, A, B, P> {
+        match secret {
+            Routed::A(s) => Routed::A(self.a.kem().raw_secret_bytes(s)),
+            Routed::B(s) => Routed::B(self.b.kem().raw_secret_bytes(s)),
+        }
+    }
+}
+
+impl<A: Cal, B: Cal, P: Preference> KeyWrapProvider for Router<A, B, P> {
+    type Algorithm = RoutedAlgorithm<KeyWrapAlgorithmOf<A>, KeyWrapAlgorithmOf<B>, P>;
+    type Key = Routed<KeyWrapKeyOf<A>, KeyWrapKeyOf<B>>;
+
+    fn load_from_keydata(&mut self, alg: Self::Algorithm, key: &[u8]) -> Self::Key {
+        match alg.into_routed() {
+            Routed::A(alg) => Routed::A(self.a.key_wrap().load_from_keydata(alg, key)),
+            Routed::B(alg) => Routed::B(self.b.key_wrap().load_from_keydata(alg, key)),
+        }
+    }
+
+    fn wrap_key(&mut self, kek: &Self::Key, key_data: &[u8], output: &mut [u8]) {
+        match kek {
+            Routed::A(kek) => self.a.key_wrap().wrap_key(kek, key_data, output),
+            Routed::B(kek) => self.b.key_wrap().wrap_key(kek, key_data, output),
+        }
+    }
+
+    fn unwrap_key(
+        &mut self,
+        kek: &Self::Key,
+        wrapped: &[u8],
+        output: &mut [u8],
+    ) -> Result<(), DecryptionFailed> {
+        match kek {
+            Routed::A(kek) => self.a.key_wrap().unwrap_key(kek, wrapped, output),
+            Routed::B(kek) => self.b.key_wrap().unwrap_key(kek, wrapped, output),
+        }
+    }
+}
+
+impl<A: Cal, B: Cal, P: Preference> MacProvider for Router<A, B, P> {
+    type Algorithm = RoutedAlgorithm<MacAlgorithmOf<A>, MacAlgorithmOf<B>, P>;
+    type Key = Routed<MacKeyOf<A>, MacKeyOf<B>>;
+    type State = Routed<MacStateOf<A>, MacStateOf<B>>;
+    type Output = Routed<MacOutputOf<A>, MacOutputOf<B>>;
+
+    fn load_from_keydata(&mut self, algorithm: Self::Algorithm, key: &[u8]) -> Self::Key {
+        match algorithm.into_routed() {
+            Routed::A(alg) => Routed::A(self.a.mac().load_from_keydata(alg, key)),
+            Routed::B(alg) => Routed::B(self.b.mac().load_from_keydata(alg, key)),
+        }
+    }
+
+    fn init(&mut self, key: Self::Key) -> Self::State {
+        match key {
+            Routed::A(k) => Routed::A(self.a.mac().init(k)),
+            Routed::B(k) => Routed::B(self.b.mac().init(k)),
+        }
+    }
+
+    fn update(&mut self, state: &mut Self::State, data: &[u8]) {
+        match state {
+            Routed::A(s) => self.a.mac().update(s, data),
+            Routed::B(s) => self.b.mac().update(s, data),
+        }
+    }
+
+    fn finalize(&mut self, state: Self::State) -> Self::Output {
+        match state {
+            Routed::A(s) => Routed::A(self.a.mac().finalize(s)),
+            Routed::B(s) => Routed::B(self.b.mac().finalize(s)),
+        }
+    }
+}
+
+impl<A: Cal, B: Cal, P: Preference> SignatureProvider for Router<A, B, P> {
+    type Algorithm = RoutedAlgorithm<SignatureAlgorithmOf<A>, SignatureAlgorithmOf<B>, P>;
+    type VisibleSecretKey =
+        RoutedVisible<SignatureVisibleSecretKeyOf<A>, SignatureVisibleSecretKeyOf<B>>;
+    type SecretKey = Routed<SignatureSecretKeyOf<A>, SignatureSecretKeyOf<B>>;
+    type PublicKey = Routed<SignaturePublicKeyOf<A>, SignaturePublicKeyOf<B>>;
+    type SignState = Routed<SignatureSignStateOf<A>, SignatureSignStateOf<B>>;
+    type VerifyState = Routed<SignatureVerifyStateOf<A>, SignatureVerifyStateOf<B>>;
+    type Output = Routed<SignatureOutputOf<A>, SignatureOutputOf<B>>;
+
+    fn generate_visible(&mut self, alg: Self::Algorithm) -> Self::VisibleSecretKey {
+        match alg.into_routed() {
+            Routed::A(alg) => RoutedVisible::A(self.a.signature().generate_visible(alg)),
+            Routed::B(alg) => RoutedVisible::B(self.b.signature().generate_visible(alg)),
+        }
+    }
+
+    fn generate(&mut self, alg: Self::Algorithm) -> Self::SecretKey {
+        match alg.into_routed() {
+            Routed::A(alg) => Routed::A(self.a.signature().generate(alg)),
+            Routed::B(alg) => Routed::B(self.b.signature().generate(alg)),
+        }
+    }
+
+    fn export_secretkey_bytes<'s>(
+        &mut self,
+        secretkey: &'s Self::VisibleSecretKey,
+    ) -> impl AsRef<[u8]> + use<'s, A, B, P> {
+        match secretkey {
+            RoutedVisible::A(k) => Routed::A(self.a.signature().export_secretkey_bytes(k)),
+            RoutedVisible::B(k) => Routed::B(self.b.signature().export_secretkey_bytes(k)),
+        }
+    }
+
+    fn import_secretkey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        secret: &[u8],
+    ) -> Result<Self::VisibleSecretKey, ImportError> {
+        Ok(match alg.into_routed() {
+            Routed::A(alg) => {
+                RoutedVisible::A(self.a.signature().import_secretkey_bytes(alg, secret)?)
+            }
+            Routed::B(alg) => {
+                RoutedVisible::B(self.b.signature().import_secretkey_bytes(alg, secret)?)
+            }
+        })
+    }
+
+    fn export_publickey_bytes<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+    ) -> impl AsRef<[u8]> + use<'p, A, B, P> {
+        match public {
+            Routed::A(p) => Routed::A(self.a.signature().export_publickey_bytes(p)),
+            Routed::B(p) => Routed::B(self.b.signature().export_publickey_bytes(p)),
+        }
+    }
+
+    fn import_publickey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::PublicKey, ImportError> {
+        Ok(match alg.into_routed() {
+            Routed::A(alg) => Routed::A(self.a.signature().import_publickey_bytes(alg, data)?),
+            Routed::B(alg) => Routed::B(self.b.signature().import_publickey_bytes(alg, data)?),
+        })
+    }
+
+    fn public_key(&mut self, private: &Self::SecretKey) -> Self::PublicKey {
+        match private {
+            Routed::A(s) => Routed::A(self.a.signature().public_key(s)),
+            Routed::B(s) => Routed::B(self.b.signature().public_key(s)),
+        }
+    }
+
+    fn sign_init(&mut self, key: &Self::SecretKey) -> Self::SignState {
+        match key {
+            Routed::A(k) => Routed::A(self.a.signature().sign_init(k)),
+            Routed::B(k) => Routed::B(self.b.signature().sign_init(k)),
+        }
+    }
+
+    fn try_sign_update(
+        &mut self,
+        state: &mut Self::SignState,
+        data: &[u8],
+    ) -> Result<(), OperationError> {
+        match state {
+            Routed::A(s) => self.a.signature().try_sign_update(s, data),
+            Routed::B(s) => self.b.signature().try_sign_update(s, data),
+        }
+    }
+
+    fn sign_finalize(&mut self, state: Self::SignState) -> Self::Output {
+        match state {
+            Routed::A(s) => Routed::A(self.a.signature().sign_finalize(s)),
+            Routed::B(s) => Routed::B(self.b.signature().sign_finalize(s)),
+        }
+    }
+
+    fn try_sign(
+        &mut self,
+        key: &Self::SecretKey,
+        message: &[u8],
+    ) -> Result<Self::Output, OperationError> {
+        Ok(match key {
+            Routed::A(k) => Routed::A(self.a.signature().try_sign(k, message)?),
+            Routed::B(k) => Routed::B(self.b.signature().try_sign(k, message)?),
+        })
+    }
+
+    fn verify_init(&mut self, key: &Self::PublicKey) -> Self::VerifyState {
+        match key {
+            Routed::A(k) => Routed::A(self.a.signature().verify_init(k)),
+            Routed::B(k) => Routed::B(self.b.signature().verify_init(k)),
+        }
+    }
+
+    fn try_verify_update(
+        &mut self,
+        state: &mut Self::VerifyState,
+        data: &[u8],
+    ) -> Result<(), OperationError> {
+        match state {
+            Routed::A(s) => self.a.signature().try_verify_update(s, data),
+            Routed::B(s) => self.b.signature().try_verify_update(s, data),
+        }
+    }
+
+    fn verify_finalize(
+        &mut self,
+        state: Self::VerifyState,
+        signature: &[u8],
+    ) -> Result<(), VerificationFailed> {
+        match state {
+            Routed::A(s) => self.a.signature().verify_finalize(s, signature),
+            Routed::B(s) => self.b.signature().verify_finalize(s, signature),
+        }
+    }
+
+    fn try_verify(
+        &mut self,
+        key: &Self::PublicKey,
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), TryError<VerificationFailed>> {
+        match key {
+            Routed::A(k) => self.a.signature().try_verify(k, message, signature),
+            Routed::B(k) => self.b.signature().try_verify(k, message, signature),
+        }
+    }
+}
@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+//! Shared access to a [`Cal`][crate::Cal] from several tasks or threads.
+//!
+//! All providers are accessed through an exclusive reference. Where several independent components
+//! of a system need cryptographic operations, the [`SharedCal`] wrapper serializes their accesses
+//! through a mutex, handing out the exclusive reference only for the duration of a closure.
+//!
+//! As operation states (e.g. [`HashProvider::State`][crate::HashProvider::State] or
+//! [`HmacProvider::State`][crate::HmacProvider::State]) are independent values that are not
+//! borrowed from the Cal, they can be kept across lock releases, and operations of different users
+//! can be interleaved:
+//!
+//! ```
+//! # use embedded_cal::{Cal, HashProvider, shared::{RawMutex, SharedCal}};
+//! fn hash_in_parts<M: RawMutex, C: Cal>(
+//!     shared: &SharedCal<M, C>,
+//!     alg: embedded_cal::accessor::HashAlgorithmOf<C>,
+//! ) {
+//!     let mut state = shared.lock(|cal| cal.hash().init(alg));
+//!     // Other users may take the lock here.
+//!     shared.lock(|cal| cal.hash().update(&mut state, b"hello "));
+//!     shared.lock(|cal| cal.hash().update(&mut state, b"world"));
+//!     let _result = shared.lock(|cal| cal.hash().finalize(state));
+//! }
+//! ```
+//!
+//! Back-ends whose hardware keeps state of a pending operation need to save and restore that
+//! state as part of their operation states anyway (see the "Incomplete operations should not block
+//! the system" design goal), so this works with them too.
+
+use core::cell::RefCell;
+
+/// A mutex without data, in the style of `embassy-sync`'s `RawMutex`.
+///
+/// This is implemented for `CriticalSectionRawMutex` (with the `critical-section` feature) and
+/// for all [`lock_api::RawMutex`] types (with the `lock_api` feature). Other mutexes (e.g. those
+/// of `embassy-sync`, or [`std::sync::Mutex<()>`]) can easily be wrapped in a newtype.
+///
+/// # Safety
+///
+/// Implementations must ensure that no two calls to [`.lock()`][Self::lock()] run their closures
+/// concurrently (on different threads, cores or interrupt levels). They may allow *reentrant*
+/// calls (as critical sections do): [`SharedCal`] guards against those on its own.
+///
+/// [`lock_api::RawMutex`]: https://docs.rs/lock_api/latest/lock_api/trait.RawMutex.html
+/// [`std::sync::Mutex<()>`]: https://doc.rust-lang.org/std/sync/struct.Mutex.html
+pub unsafe trait RawMutex {
+    /// Runs `f` while holding the lock.
+    fn lock<R>(&self, f: impl FnOnce() -> R) -> R;
+}
+
+/// Wrapper that provides shared access to a [`Cal`][crate::Cal] (or any of its providers).
+///
+/// See the [module level documentation][self] for usage.
+pub struct SharedCal<M, C> {
+    mutex: M,
+    // The RefCell is not for synchronization (that's what M is for), but detects reentrant use
+    // when M allows it.
+    cal: RefCell<C>,
+}
+
+impl<M, C> SharedCal<M, C> {
+    /// Wraps a Cal in a mutex.
+    pub const fn new(mutex: M, cal: C) -> Self {
+        Self {
+            mutex,
+            cal: RefCell::new(cal),
+        }
+    }
+
+    /// Accesses the Cal without locking, as is possible when the wrapper is not shared.
+    pub fn get_mut(&mut self) -> &mut C {
+        self.cal.get_mut()
+    }
+
+    /// Unwraps the Cal.
+    pub fn into_inner(self) -> C {
+        self.cal.into_inner()
+    }
+}
+
+impl<M: RawMutex, C> SharedCal<M, C> {
+    /// Runs `f` with exclusive access to the Cal.
+    ///
+    /// Users should keep the closure short (typically, a single operation), so that other users
+    /// are not blocked for long.
+    ///
+    /// # Panics
+    ///
+    /// … if called from inside `f` (or from an interrupt that preempted it, if the mutex allows
+    /// that).
+    pub fn lock<R>(&self, f: impl FnOnce(&mut C) -> R) -> R {
+        self.mutex.lock(|| {
+            let mut cal = self
+                .cal
+                .try_borrow_mut()
+                .expect("SharedCal was locked reentrantly");
+            f(&mut cal)
+        })
+    }
+}
+
+// SAFETY: The RefCell is only ever accessed from inside the mutex (or through an exclusive
+// reference), so it is never accessed concurrently, and C only needs to be sent between the
+// threads that take turns.
+unsafe impl<M: RawMutex + Sync, C: Send> Sync for SharedCal<M, C> {}
+
+/// A [`RawMutex`] that locks by taking a global critical section.
+///
+/// This is the most portable choice, but blocks all interrupts (or other cores) while an
+/// operation is running.
+#[cfg(feature = "critical-section")]
+#[derive(Default)]
+pub struct CriticalSectionRawMutex(());
+
+#[cfg(feature = "critical-section")]
+impl CriticalSectionRawMutex {
+    pub const fn new() -> Self {
+        Self(())
+    }
+}
+
+// SAFETY: Critical sections are exclusive (albeit reentrant).
+#[cfg(feature = "critical-section")]
+unsafe impl RawMutex for CriticalSectionRawMutex {
+    fn lock<R>(&self, f: impl FnOnce() -> R) -> R {
+        critical_section::with(|_| f())
+    }
+}
+
+// SAFETY: lock_api's RawMutex provides mutual exclusion, and the guard ensures it is unlocked
+// only after the closure has finished (also when panicking).
+#[cfg(feature = "lock_api")]
+unsafe impl<T: lock_api::RawMutex> RawMutex for T {
+    fn lock<R>(&self, f: impl FnOnce() -> R) -> R {
+        struct Unlock<'a, T: lock_api::RawMutex>(&'a T);
+        impl<T: lock_api::RawMutex> Drop for Unlock<'_, T> {
+            fn drop(&mut self) {
+                // SAFETY: The lock was taken right before this guard was created.
+                unsafe { self.0.unlock() }
+            }
+        }
+
+        lock_api::RawMutex::lock(self);
+        let _unlock = Unlock(self);
+        f()
+    }
+}
@@ -0,0 +1,291 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+use crate::{ImportError, OperationError, TryError};
+
+/// Digital signatures over a message that is fed in piecemeal.
+///
+/// This trait takes inspiration from the [`signature`](https://docs.rs/signature/latest/signature/)
+/// crate, but does not use it directly because
+/// - `embedded-cal` passes around an exclusive reference to its engine,
+/// - its operation is cryptographically agile rather than monomorphized over algorithms, and
+/// - the message is not required to be present in contiguous memory: Like with
+///   [`HashProvider`][crate::HashProvider], it is fed through an init / update / finalize sequence
+///   (e.g. to sign EDHOC's `Sig_structure` or a SUIT manifest that is only available in pieces).
+///
+/// Key handling follows the conventions of [`DhProvider`][crate::DhProvider]: There are distinct
+/// types for exportable and non-exportable secret keys, and all keys can be converted from and to
+/// their bytes in the algorithm's native format.
+pub trait SignatureProvider {
+    type Algorithm: SignatureAlgorithm;
+    /// A secret key that is intended to be exported.
+    ///
+    /// See [`DhProvider::VisibleSecretKey`][crate::DhProvider::VisibleSecretKey] for rationale.
+    type VisibleSecretKey: Sized + Into<Self::SecretKey>;
+    type SecretKey: Sized;
+    type PublicKey: Sized;
+    /// State carried between rounds of feeding the message into a signing operation.
+    ///
+    /// As construction is not fallible, this can not be a handle into a limited pool (cf. the
+    /// discussion at [`HashProvider::State`][crate::HashProvider::State]).
+    type SignState: Sized;
+    /// State carried between rounds of feeding the message into a verification operation.
+    type VerifyState: Sized;
+    /// A signature produced by this provider.
+    type Output: AsRef<[u8]>;
+
+    /// Generates a secret key that is intended to be exported / shared (e.g. to be persisted
+    /// across program executions).
+    fn generate_visible(&mut self, alg: Self::Algorithm) -> Self::VisibleSecretKey;
+
+    /// Generates a secret key.
+    fn generate(&mut self, alg: Self::Algorithm) -> Self::SecretKey {
+        self.generate_visible(alg).into()
+    }
+
+    /// Exposes a visible secret key's secret.
+    ///
+    /// Data is stored in the algorithm's native format. For ECDSA, this is the scalar (COSE's `d`
+    /// value); for EdDSA, it is the seed that RFC8032 calls the private key.
+    fn export_secretkey_bytes<'s>(
+        &mut self,
+        secretkey: &'s Self::VisibleSecretKey,
+    ) -> impl AsRef<[u8]> + use<'s, Self>;
+
+    /// Inverse operation of [`.export_secretkey_bytes()`][Self::export_secretkey_bytes()].
+    fn import_secretkey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        secret: &[u8],
+    ) -> Result<Self::VisibleSecretKey, ImportError>;
+
+    /// Exposes a public key's key data bytes.
+    ///
+    /// For ECDSA keys, this is the uncompressed SEC1 representation (`0x04 || x || y`); unlike with
+    /// ECDH, the compact representation is not sufficient to verify signatures. For EdDSA keys, it
+    /// is the RFC8032 encoding (COSE's `x` value).
+    fn export_publickey_bytes<'p>(
+        &mut self,
+        public: &'p Self::PublicKey,
+    ) -> impl AsRef<[u8]> + use<'p, Self>;
+
+    /// Imports a public key in the inverse operation of
+    /// [`.export_publickey_bytes()`][Self::export_publickey_bytes()].
+    ///
+    /// Implementations must reject points that are not on the curve.
+    fn import_publickey_bytes(
+        &mut self,
+        alg: Self::Algorithm,
+        data: &[u8],
+    ) -> Result<Self::PublicKey, ImportError>;
+
+    /// Produces the public key corresponding to a private key.
+    fn public_key(&mut self, private: &Self::SecretKey) -> Self::PublicKey;
+
+    /// Starts a signing operation.
+    fn sign_init(&mut self, key: &Self::SecretKey) -> Self::SignState;
+    /// Feeds a part of the message into a signing operation.
+    ///
+    /// # Errors
+    ///
+    /// … are [`OperationError::ResourceExhausted`] if the algorithm needs to gather the whole
+    /// message (as PureEdDSA does), and the implementation's space for that was exceeded.
+    fn try_sign_update(
+        &mut self,
+        state: &mut Self::SignState,
+        data: &[u8],
+    ) -> Result<(), OperationError>;
+    /// Completes a signing operation.
+    fn sign_finalize(&mut self, state: Self::SignState) -> Self::Output;
+
+    /// Feeds a part of the message into a signing operation, panicking where
+    /// [`.try_sign_update()`][Self::try_sign_update()] errs.
+    fn sign_update(&mut self, state: &mut Self::SignState, data: &[u8]) {
+        self.try_sign_update(state, data)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Signs contiguous in-memory data in a single pass.
+    ///
+    /// This is a shortcut for [`self.sign_init(…)`][Self::sign_init()] /
+    /// [`self.try_sign_update(…)`][Self::try_sign_update()] /
+    /// [`self.sign_finalize(…)`][Self::sign_finalize()].
+    fn try_sign(
+        &mut self,
+        key: &Self::SecretKey,
+        message: &[u8],
+    ) -> Result<Self::Output, OperationError> {
+        let mut state = self.sign_init(key);
+        self.try_sign_update(&mut state, message)?;
+        Ok(self.sign_finalize(state))
+    }
+
+    /// Signs contiguous in-memory data in a single pass, panicking where
+    /// [`.try_sign()`][Self::try_sign()] errs.
+    fn sign(&mut self, key: &Self::SecretKey, message: &[u8]) -> Self::Output {
+        self.try_sign(key, message)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Starts a verification operation.
+    fn verify_init(&mut self, key: &Self::PublicKey) -> Self::VerifyState;
+    /// Feeds a part of the message into a verification operation.
+    ///
+    /// # Errors
+    ///
+    /// … are produced in the same cases as for
+    /// [`.try_sign_update()`][Self::try_sign_update()].
+    fn try_verify_update(
+        &mut self,
+        state: &mut Self::VerifyState,
+        data: &[u8],
+    ) -> Result<(), OperationError>;
+
+    /// Feeds a part of the message into a verification operation, panicking where
+    /// [`.try_verify_update()`][Self::try_verify_update()] errs.
+    fn verify_update(&mut self, state: &mut Self::VerifyState, data: &[u8]) {
+        self.try_verify_update(state, data)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+    /// Completes a verification operation, checking the message against the signature.
+    ///
+    /// # Errors
+    ///
+    /// … are produced if the signature does not match the message and key, including when the
+    /// signature's length does not match the algorithm's
+    /// [`.signature_length()`][SignatureAlgorithm::signature_length()].
+    fn verify_finalize(
+        &mut self,
+        state: Self::VerifyState,
+        signature: &[u8],
+    ) -> Result<(), VerificationFailed>;
+
+    /// Verifies a signature over contiguous in-memory data in a single pass.
+    ///
+    /// This is a shortcut for [`self.verify_init(…)`][Self::verify_init()] /
+    /// [`self.try_verify_update(…)`][Self::try_verify_update()] /
+    /// [`self.verify_finalize(…)`][Self::verify_finalize()].
+    fn try_verify(
+        &mut self,
+        key: &Self::PublicKey,
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), TryError<VerificationFailed>> {
+        let mut state = self.verify_init(key);
+        self.try_verify_update(&mut state, message)?;
+        self.verify_finalize(state, signature)
+            .map_err(TryError::Failed)
+    }
+
+    /// Verifies a signature over contiguous in-memory data in a single pass, panicking where
+    /// [`.try_verify()`][Self::try_verify()] reports an [`OperationError`].
+    fn verify(
+        &mut self,
+        key: &Self::PublicKey,
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), VerificationFailed> {
+        self.try_verify(key, message, signature)
+            .map_err(TryError::unwrap_failed)
+    }
+}
+
+/// Error indicating that a signature or MAC did not verify.
+///
+/// No further details are given; in particular, malformed signatures and signatures that are
+/// well-formed but do not match are not distinguished.
+#[derive(Debug)]
+pub struct VerificationFailed;
+
+impl core::fmt::Display for VerificationFailed {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("verification failed")
+    }
+}
+
+impl core::error::Error for VerificationFailed {}
+
+/// A signature algorithm identifier.
+///
+/// This encodes the full set of parameters, i.e. for ECDSA both the curve and the hash algorithm.
+pub trait SignatureAlgorithm: Sized + PartialEq + Eq + core::fmt::Debug + Clone {
+    /// Length of a signature in bytes.
+    fn signature_length(&self) -> usize;
+
+    /// Selects a signature algorithm from its COSE number.
+    ///
+    /// The algorithm number comes from the ["COSE Algorithms"
+    /// registry](https://www.iana.org/assignments/cose/cose.xhtml#algorithms) maintained by IANA.
+    ///
+    /// Implementations are encouraged to accept both the polymorphic identifiers (eg. -7 "ES256")
+    /// and their [fully specified](https://datatracker.ietf.org/doc/html/rfc9864) counterparts (eg.
+    /// -9 "ESP256") if they only support a single curve for the former.
+    #[inline]
+    #[allow(
+        unused_variables,
+        reason = "Argument names are part of the documentation"
+    )]
+    fn from_cose_number(number: impl Into<i128>) -> Option<Self> {
+        None
+    }
+
+    /// Lists all algorithms supported by the implementation, as selected by
+    /// [`Self::from_cose_number()`].
+    ///
+    /// See [`HashAlgorithm::all()`][crate::HashAlgorithm::all()] for how implementations that
+    /// extend a base compose the list.
+    #[inline]
+    fn all() -> impl Iterator<Item = Self> {
+        core::iter::empty()
+    }
+
+    /// The algorithm's number in the COSE Algorithms registry, if it has one; the inverse of
+    /// [`Self::from_cose_number()`].
+    ///
+    /// Algorithms that are selected by several numbers (e.g. by ES256 and ESP256) report one of
+    /// them.
+    #[inline]
+    fn cose_number(&self) -> Option<i128> {
+        None
+    }
+}
+
+pub fn test_signature_algorithm_es256<SP: SignatureProvider>() {
+    let cose_neg7 = SP::Algorithm::from_cose_number(-7i8).expect(
+        "test for type claiming ECDSA P-256 compatibility did not recognize COSE number -7",
+    );
+    assert_eq!(cose_neg7.signature_length(), 64)
+}
+
+pub fn test_signature_selftest<C: crate::Cal + rand_core::CryptoRng>(
+    cal: &mut C,
+    alg: <C::SignatureProvider as SignatureProvider>::Algorithm,
+) {
+    let cal = cal.signature();
+
+    let secret = cal.generate(alg.clone());
+    let public = cal.public_key(&secret);
+
+    let message = b"A message that is signed in multiple pieces";
+
+    let mut state = cal.sign_init(&secret);
+    cal.sign_update(&mut state, &message[..10]);
+    cal.sign_update(&mut state, &message[10..]);
+    let signature = cal.sign_finalize(state);
+    assert_eq!(signature.as_ref().len(), alg.signature_length());
+
+    cal.verify(&public, message, signature.as_ref())
+        .expect("freshly created signature did not verify");
+
+    let mut state = cal.verify_init(&public);
+    cal.verify_update(&mut state, &message[..20]);
+    cal.verify_update(&mut state, &message[20..]);
+    cal.verify_finalize(state, signature.as_ref())
+        .expect("freshly created signature did not verify when fed in chunks");
+
+    assert!(
+        cal.verify(&public, &message[1..], signature.as_ref())
+            .is_err(),
+        "signature verified on different message"
+    );
+}
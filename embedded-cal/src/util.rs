@@ -26,3 +26,80 @@ impl<T: ?Sized, Own: AsRef<T>, Base: AsRef<T>> AsRef<T> for Either<Own, Base> {
         }
     }
 }
+
+/// Buffer for the longer of two byte buffers, e.g. for an [`HmacAlgorithm::MaxLenBuf`] of an
+/// implementation that combines its own algorithms with those of a base.
+///
+/// As const generics can not yet express the larger of two lengths, this holds both buffers, and
+/// hands out the longer one.
+///
+/// [`HmacAlgorithm::MaxLenBuf`]: crate::HmacAlgorithm::MaxLenBuf
+#[derive(Default)]
+pub struct LongerBuf<A, B>(A, B);
+
+impl<A: AsMut<[u8]>, B: AsMut<[u8]>> AsMut<[u8]> for LongerBuf<A, B> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        let (a, b) = (self.0.as_mut(), self.1.as_mut());
+        if a.len() >= b.len() { a } else { b }
+    }
+}
+
+/// A container that wipes the memory of its content when dropped.
+///
+/// This is for types from other crates that hold secrets inline but do not implement
+/// [`zeroize::ZeroizeOnDrop`] themselves (typically because they do not expose their internal
+/// state). The content's own drop code runs first; then, the bytes it occupied are overwritten
+/// with zeros.
+///
+/// Like with [`zeroize::Zeroizing`], copies created by moving the value before it was wrapped (or
+/// after [`.into_inner()`][Self::into_inner]) are not covered, and neither is any data the content
+/// refers to.
+pub struct WipeOnDrop<T>(core::mem::MaybeUninit<T>);
+
+impl<T> WipeOnDrop<T> {
+    pub const fn new(value: T) -> Self {
+        Self(core::mem::MaybeUninit::new(value))
+    }
+
+    /// Moves the content out, wiping the memory it occupied in the container.
+    pub fn into_inner(self) -> T {
+        let mut this = core::mem::ManuallyDrop::new(self);
+        // SAFETY: The content is initialized until the container is dropped, which `ManuallyDrop`
+        // prevents; it is not read again after this.
+        let value = unsafe { this.0.assume_init_read() };
+        zeroize::Zeroize::zeroize(&mut this.0);
+        value
+    }
+}
+
+impl<T> core::ops::Deref for WipeOnDrop<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: The content is initialized until the container is dropped.
+        unsafe { self.0.assume_init_ref() }
+    }
+}
+
+impl<T> core::ops::DerefMut for WipeOnDrop<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: The content is initialized until the container is dropped.
+        unsafe { self.0.assume_init_mut() }
+    }
+}
+
+impl<T: Clone> Clone for WipeOnDrop<T> {
+    fn clone(&self) -> Self {
+        Self::new(T::clone(self))
+    }
+}
+
+impl<T> Drop for WipeOnDrop<T> {
+    fn drop(&mut self) {
+        // SAFETY: The content is initialized until now, and not read again after this.
+        unsafe { self.0.assume_init_drop() };
+        zeroize::Zeroize::zeroize(&mut self.0);
+    }
+}
+
+impl<T> zeroize::ZeroizeOnDrop for WipeOnDrop<T> {}
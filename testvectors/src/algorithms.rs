@@ -0,0 +1,243 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+//! Checks that the algorithms a Cal lists are the ones it recognizes by their identifiers, and
+//! that it reports those identifiers back.
+
+use embedded_cal::accessor::*;
+use embedded_cal::{
+    AeadAlgorithm, BlockCipherAlgorithm, Cal, DhAlgorithm, EccAlgorithm, HashAlgorithm,
+    HmacAlgorithm, KemAlgorithm, KeyWrapAlgorithm, MacAlgorithm, SignatureAlgorithm,
+};
+
+/// COSE numbers that are scanned for algorithms and curves.
+///
+/// This covers all values outside the private use range.
+const COSE_NUMBERS: core::ops::RangeInclusive<i32> = -65536..=65535;
+
+/// Hash Name Strings of the IANA Named Information Hash Algorithm Registry.
+const NI_NAMES: &[&str] = &[
+    "sha-256",
+    "sha-256-128",
+    "sha-256-120",
+    "sha-256-96",
+    "sha-256-64",
+    "sha-256-32",
+    "sha-384",
+    "sha-512",
+    "sha3-224",
+    "sha3-256",
+    "sha3-384",
+    "sha3-512",
+    "blake2s-256",
+    "blake2b-256",
+    "blake2b-512",
+    "k12-256",
+    "k12-512",
+];
+
+/// Key lengths that are scanned for AES based algorithms.
+const KEY_LENGTHS: core::ops::RangeInclusive<usize> = 0..=64;
+
+/// Asserts that the listed algorithms are unique, and that every listed algorithm is selected by
+/// some identifier and vice versa.
+fn check<A, L, S>(trait_name: &str, all: impl Fn() -> L, selected: impl Fn() -> S)
+where
+    A: PartialEq + core::fmt::Debug,
+    L: Iterator<Item = A>,
+    S: Iterator<Item = A>,
+{
+    for (i, a) in all().enumerate() {
+        assert!(
+            all().skip(i + 1).all(|b| b != a),
+            "{trait_name} {a:?} is listed twice"
+        );
+        assert!(
+            selected().any(|s| s == a),
+            "{trait_name} {a:?} is listed but not selected by any identifier"
+        );
+    }
+    for s in selected() {
+        assert!(
+            all().any(|a| a == s),
+            "{trait_name} {s:?} is selected by an identifier but not listed"
+        );
+    }
+}
+
+/// Checks that every algorithm reported by the `all()` methods of a Cal's algorithm types
+/// round-trips through its identifiers.
+///
+/// All identifiers of the constructors are scanned (for COSE numbers, all outside the private use
+/// range), so this takes a while on slow systems.
+pub fn test_algorithm_enumeration<C: Cal>() {
+    check("AEAD", AeadAlgorithmOf::<C>::all, || {
+        COSE_NUMBERS
+            .filter_map(AeadAlgorithmOf::<C>::from_cose_number)
+            .chain((0..=u16::MAX).filter_map(AeadAlgorithmOf::<C>::from_tls_cipher_suite))
+    });
+    check("block cipher", BlockCipherAlgorithmOf::<C>::all, || {
+        KEY_LENGTHS.filter_map(BlockCipherAlgorithmOf::<C>::from_aes_key_length)
+    });
+    check("DH", DhAlgorithmOf::<C>::all, || {
+        COSE_NUMBERS
+            .filter_map(DhAlgorithmOf::<C>::from_cose_ecdh)
+            .chain((0..=u16::MAX).filter_map(DhAlgorithmOf::<C>::from_tls_named_group))
+    });
+    check("ECC", EccAlgorithmOf::<C>::all, || {
+        COSE_NUMBERS.filter_map(EccAlgorithmOf::<C>::from_cose_curve)
+    });
+    check("hash", HashAlgorithmOf::<C>::all, || {
+        COSE_NUMBERS
+            .filter_map(HashAlgorithmOf::<C>::from_cose_number)
+            .chain((0..=u8::MAX).filter_map(HashAlgorithmOf::<C>::from_ni_id))
+            .chain(
+                NI_NAMES
+                    .iter()
+                    .filter_map(|n| HashAlgorithmOf::<C>::from_ni_name(n)),
+            )
+            .chain((0..=u8::MAX).filter_map(HashAlgorithmOf::<C>::from_tls_hash_algorithm))
+    });
+    check("HMAC", HmacAlgorithmOf::<C>::all, || {
+        COSE_NUMBERS
+            .filter_map(HmacAlgorithmOf::<C>::from_cose_number)
+            .chain((0..=u8::MAX).filter_map(HmacAlgorithmOf::<C>::from_tls_hash_algorithm))
+    });
+    check("KEM", KemAlgorithmOf::<C>::all, || {
+        (0..=u16::MAX).filter_map(KemAlgorithmOf::<C>::from_hpke_kem_id)
+    });
+    check("key wrap", KeyWrapAlgorithmOf::<C>::all, || {
+        COSE_NUMBERS.filter_map(KeyWrapAlgorithmOf::<C>::from_cose_number)
+    });
+    check("MAC", MacAlgorithmOf::<C>::all, || {
+        KEY_LENGTHS.filter_map(MacAlgorithmOf::<C>::from_aes_cmac_key_length)
+    });
+    check("signature", SignatureAlgorithmOf::<C>::all, || {
+        COSE_NUMBERS.filter_map(SignatureAlgorithmOf::<C>::from_cose_number)
+    });
+}
+
+/// Asserts that an algorithm is selected by the identifier it reports, or, if it reports none,
+/// that no identifier selects it.
+fn round_trip<A, I>(
+    trait_name: &str,
+    alg: &A,
+    reported: Option<I>,
+    select: impl Fn(I) -> Option<A>,
+    identifiers: impl Iterator<Item = I>,
+) where
+    A: PartialEq + core::fmt::Debug,
+    I: Copy + core::fmt::Debug,
+{
+    match reported {
+        Some(id) => {
+            let selected = select(id);
+            assert!(
+                selected.as_ref() == Some(alg),
+                "{trait_name} {alg:?} reports identifier {id:?}, which selects {selected:?}"
+            );
+        }
+        None => {
+            for id in identifiers {
+                assert!(
+                    select(id).as_ref() != Some(alg),
+                    "{trait_name} {alg:?} reports no identifier, but is selected by {id:?}"
+                );
+            }
+        }
+    }
+}
+
+/// Checks that for every algorithm in the `all()` methods of a Cal's algorithm types, the
+/// identifier accessors (e.g. [`AeadAlgorithm::cose_number()`]) are the inverse of the
+/// constructors: `from_cose_number(x.cose_number()) == x`.
+pub fn test_algorithm_identifiers<C: Cal>() {
+    let cose_numbers = || COSE_NUMBERS.map(i128::from);
+
+    for a in AeadAlgorithmOf::<C>::all() {
+        round_trip(
+            "AEAD",
+            &a,
+            a.cose_number(),
+            AeadAlgorithmOf::<C>::from_cose_number,
+            cose_numbers(),
+        );
+    }
+    for a in DhAlgorithmOf::<C>::all() {
+        round_trip(
+            "DH",
+            &a,
+            a.cose_ecdh(),
+            DhAlgorithmOf::<C>::from_cose_ecdh,
+            cose_numbers(),
+        );
+    }
+    for a in EccAlgorithmOf::<C>::all() {
+        round_trip(
+            "ECC",
+            &a,
+            a.cose_curve(),
+            EccAlgorithmOf::<C>::from_cose_curve,
+            cose_numbers(),
+        );
+    }
+    for a in HashAlgorithmOf::<C>::all() {
+        round_trip(
+            "hash",
+            &a,
+            a.cose_number(),
+            HashAlgorithmOf::<C>::from_cose_number,
+            cose_numbers(),
+        );
+        round_trip(
+            "hash",
+            &a,
+            a.ni_id(),
+            HashAlgorithmOf::<C>::from_ni_id,
+            0..=u8::MAX,
+        );
+        round_trip(
+            "hash",
+            &a,
+            a.ni_name(),
+            HashAlgorithmOf::<C>::from_ni_name,
+            NI_NAMES.iter().copied(),
+        );
+    }
+    for a in HmacAlgorithmOf::<C>::all() {
+        round_trip(
+            "HMAC",
+            &a,
+            a.cose_number(),
+            HmacAlgorithmOf::<C>::from_cose_number,
+            cose_numbers(),
+        );
+    }
+    for a in KemAlgorithmOf::<C>::all() {
+        round_trip(
+            "KEM",
+            &a,
+            a.hpke_kem_id(),
+            KemAlgorithmOf::<C>::from_hpke_kem_id,
+            0..=u16::MAX,
+        );
+    }
+    for a in KeyWrapAlgorithmOf::<C>::all() {
+        round_trip(
+            "key wrap",
+            &a,
+            a.cose_number(),
+            KeyWrapAlgorithmOf::<C>::from_cose_number,
+            cose_numbers(),
+        );
+    }
+    for a in SignatureAlgorithmOf::<C>::all() {
+        round_trip(
+            "signature",
+            &a,
+            a.cose_number(),
+            SignatureAlgorithmOf::<C>::from_cose_number,
+            cose_numbers(),
+        );
+    }
+}
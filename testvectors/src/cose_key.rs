@@ -0,0 +1,286 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+use embedded_cal::cose_key::{CoseKey, CoseKeyError, Y, encode_public_key, encode_secret_key};
+use hexlit::hex;
+
+pub struct CoseKeyVector {
+    ecdh_curve: i8,
+    /// The public key as a COSE_Key in deterministic encoding.
+    public: &'static [u8],
+    /// The secret key's `d` parameter.
+    d: &'static [u8],
+}
+
+impl CoseKeyVector {
+    /// Checks that the public key decodes, and encodes again to the same bytes.
+    ///
+    /// This does not need a Cal, and thus also runs for vectors of curves no Cal at hand supports.
+    pub fn test_codec(&self) {
+        let key = CoseKey::decode(self.public).expect("vector should decode");
+        assert_eq!(i128::from(self.ecdh_curve), key.crv);
+        let mut buf = [0; 256];
+        assert_eq!(key.encode(&mut buf), Ok(self.public));
+    }
+
+    /// Runs the test vector by the Cal implementation.
+    ///
+    /// Panics if the curve is not supported, if the public or the secret key can not be imported,
+    /// or if exporting them does not produce the same key again.
+    pub fn test_with<C: embedded_cal::Cal>(&self, cal: &mut C) {
+        use embedded_cal::{DhAlgorithm, DhProvider};
+
+        let dh = cal.dh();
+
+        let key = CoseKey::decode(self.public).expect("vector should decode");
+        let (alg, public) = key
+            .import_public_key(dh)
+            .expect("failed to import public key");
+        assert_eq!(
+            Some(&alg),
+            <C::DhProvider as DhProvider>::Algorithm::from_cose_ecdh(self.ecdh_curve).as_ref()
+        );
+        let with_d = CoseKey {
+            d: Some(self.d),
+            ..key.clone()
+        };
+        let (_, secret) = with_d
+            .import_secret_key(dh)
+            .expect("failed to import secret key along with its public key");
+
+        let mut buf = [0; 256];
+        let exported = encode_public_key(dh, &alg, &public, key.kid, &mut buf)
+            .expect("failed to export public key");
+        assert_eq!(exported, self.public);
+
+        let mut buf = [0; 256];
+        let exported = encode_secret_key(dh, &alg, &secret, key.kid, &mut buf)
+            .expect("failed to export secret key");
+        let exported = CoseKey::decode(exported).expect("exported key should decode");
+        assert_eq!(exported.d, Some(self.d));
+        assert_eq!(exported.x, key.x);
+        assert_eq!(exported.y, key.y);
+        exported
+            .import_secret_key(dh)
+            .expect("exported secret key should import");
+
+        let mut other_d = [0; 64];
+        let other_d = &mut other_d[..self.d.len()];
+        other_d.copy_from_slice(self.d);
+        other_d[self.d.len() / 2] ^= 1;
+        let mismatched = CoseKey {
+            d: Some(other_d),
+            ..key.clone()
+        };
+        assert_eq!(
+            mismatched.import_secret_key(dh).err(),
+            Some(CoseKeyError::InvalidKey),
+            "public key that does not belong to d was accepted"
+        );
+    }
+}
+
+// Keys from Section C.7 of RFC9052, encoded deterministically
+// <https://datatracker.ietf.org/doc/html/rfc9052#appendix-C.7>
+pub const RFC9052_P256: &[CoseKeyVector] = &[
+    CoseKeyVector {
+        ecdh_curve: 1,
+        public: &hex!(
+            "a501020258246d65726961646f632e6272616e64796275636b406275636b6c616e642e6578616d706c65200121582065eda5a12577c2bae829437fe338701a10aaa375e1bb5b5de108de439c08551d2258201e52ed75701163f7f9e40ddf9f341b3dc9ba860af7e0ca7ca7e9eecd0084d19c"
+        ),
+        d: &hex!("aff907c99f9ad3aae6c4cdf21122bce2bd68b5283e6907154ad911840fa208cf"),
+    },
+    CoseKeyVector {
+        ecdh_curve: 1,
+        public: &hex!(
+            "a50102024231312001215820bac5b11cad8f99f9c72b05cf4b9e26d244dc189f745228255a219a86d6a09eff22582020138bf82dc1b6d562be0fa54ab7804a3a64b6d72ccfed6b6fb6ed28bbfc117e"
+        ),
+        d: &hex!("57c92077664146e876760c9520d054aa93c3afb04e306705db6090308507b4d3"),
+    },
+];
+
+// Authentication keys of the Responder and the Initiator (in CRED_R and CRED_I) from Section 3 of
+// RFC9529 <https://datatracker.ietf.org/doc/html/rfc9529#section-3>
+pub const RFC9529_P256: &[CoseKeyVector] = &[
+    CoseKeyVector {
+        ecdh_curve: 1,
+        public: &hex!(
+            "a501020241322001215820bbc34960526ea4d32e940cad2a234148ddc21791a12afbcbac93622046dd44f02258204519e257236b2a0ce2023f0931f1f386ca7afda64fcde0108c224c51eabf6072"
+        ),
+        d: &hex!("72cc4761dbd4c78f758931aa589d348d1ef874a7e303ede2f140dcf3e6aa4aac"),
+    },
+    CoseKeyVector {
+        ecdh_curve: 1,
+        public: &hex!(
+            "a5010202412b2001215820ac75e9ece3e50bfc8ed60399889522405c47bf16df96660a41298cb4307f7eb62258206e5de611388a4b8a8211334ac7d37ecb52a387d257e6db3c2a93df21ff3affc8"
+        ),
+        d: &hex!("fb13adeb6518cee5f88417660841142e830a81fe334380a953406a1305e8706b"),
+    },
+];
+
+// Alice's keys from Section 6.1 of RFC7748, as OKP COSE_Key
+// <https://datatracker.ietf.org/doc/html/rfc7748.html#section-6.1>
+pub const RFC7748_X25519: &[CoseKeyVector] = &[CoseKeyVector {
+    ecdh_curve: 4,
+    public: &hex!(
+        "a3010120042158208520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a"
+    ),
+    d: &hex!("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a"),
+}];
+
+// Alice's keys from Section 6.2 of RFC7748, as OKP COSE_Key
+// <https://datatracker.ietf.org/doc/html/rfc7748.html#section-6.2>
+pub const RFC7748_X448: &[CoseKeyVector] = &[CoseKeyVector {
+    ecdh_curve: 5,
+    public: &hex!(
+        "a3010120052158389b08f7cc31b7e3e67d22d5aea121074a273bd2b83de09c63faa73d2c22c5d9bbc836647241d953d40c5b12da88120d53177f80e532c41fa0"
+    ),
+    d: &hex!(
+        "9a8f4925d1519f5775cf46b04b5800d4ee9ee8bae8bc5565d498c28dd9c9baf574a9419744897391006382a6f127ab1d9ac2d8c0a598726b"
+    ),
+}];
+
+/// Runs all vectors' [`CoseKeyVector::test_codec()`], and checks decoding of keys in other
+/// encodings, of malformed data, and encoding into a buffer that is too small.
+pub fn test_codec() {
+    for vec in [RFC9052_P256, RFC9529_P256, RFC7748_X25519, RFC7748_X448]
+        .into_iter()
+        .flatten()
+    {
+        vec.test_codec();
+    }
+
+    // The "11" key of RFC9052, in the order of its diagnostic notation
+    let reordered = CoseKey::decode(&hex!(
+        "a5 2001 215820bac5b11cad8f99f9c72b05cf4b9e26d244dc189f745228255a219a86d6a09eff 22582020138bf82dc1b6d562be0fa54ab7804a3a64b6d72ccfed6b6fb6ed28bbfc117e 0102 02423131"
+    ));
+    assert_eq!(reordered, CoseKey::decode(RFC9052_P256[1].public));
+
+    // … and compressed to its sign bit
+    let compressed = hex!(
+        "a5 0102 02423131 2001 215820bac5b11cad8f99f9c72b05cf4b9e26d244dc189f745228255a219a86d6a09eff 22f4"
+    );
+    let key = CoseKey::decode(&compressed).expect("compressed key should decode");
+    assert_eq!(key.y, Some(Y::Sign(false)));
+    let mut buf = [0; 256];
+    assert_eq!(key.encode(&mut buf), Ok(&compressed[..]));
+
+    // Alice's X25519 key with alg, key_ops and a parameter with a text label
+    let key = CoseKey::decode(&hex!(
+        "a6 0101 2004 2158208520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a 033818 04820708 67636f6d6d656e74 a1 6161 40"
+    ));
+    assert_eq!(key, CoseKey::decode(RFC7748_X25519[0].public));
+
+    let malformed: &[(&str, &[u8])] = &[
+        ("empty", &[]),
+        ("not a map", &hex!("82 0101")),
+        ("indefinite length map", &hex!("bf 0101 2004 ff")),
+        ("truncated", &hex!("a3 0101 2004 215820 8520")),
+        ("trailing data", &hex!("a2 0101 2004 00")),
+        ("duplicate label", &hex!("a3 0101 2004 2004")),
+        ("kty missing", &hex!("a1 2004")),
+        ("crv missing", &hex!("a1 0101")),
+        ("x not a byte string", &hex!("a3 0101 2004 2101")),
+        ("OKP key with y", &hex!("a4 0101 2004 2140 2240")),
+        ("EC2 key type on OKP curve", &hex!("a2 0102 2004")),
+        ("OKP key type on EC2 curve", &hex!("a2 0101 2001")),
+        ("reserved additional information", &hex!("a2 011c 2004")),
+        (
+            "skipped value nested too deeply",
+            &hex!("a3 0101 2004 03 818181818181818181818100"),
+        ),
+    ];
+    for (description, data) in malformed {
+        assert_eq!(
+            CoseKey::decode(data),
+            Err(CoseKeyError::Malformed),
+            "{description}"
+        );
+    }
+    assert_eq!(
+        CoseKey::decode(&hex!("a2 0104 2004")),
+        Err(CoseKeyError::Unsupported),
+        "symmetric key"
+    );
+
+    let key = CoseKey::decode(RFC7748_X448[0].public).expect("vector should decode");
+    let mut buf = [0; 60];
+    assert_eq!(key.encode(&mut buf), Err(CoseKeyError::BufferTooSmall));
+}
+
+/// Checks that keys that are well-formed but not valid or not supported are not imported.
+///
+/// This needs a Cal that supports P-256.
+pub fn test_import_errors<C: embedded_cal::Cal>(cal: &mut C) {
+    let dh = cal.dh();
+
+    let key = CoseKey::decode(RFC9052_P256[1].public).expect("vector should decode");
+    let mut off_curve = [0; 32];
+    off_curve.copy_from_slice(match key.y {
+        Some(Y::Coordinate(y)) => y,
+        _ => unreachable!("vector has a y coordinate"),
+    });
+    off_curve[31] ^= 1;
+    let cases = [
+        (
+            "point not on the curve",
+            CoseKey {
+                y: Some(Y::Coordinate(&off_curve)),
+                ..key.clone()
+            },
+            CoseKeyError::InvalidKey,
+        ),
+        (
+            "short x",
+            CoseKey {
+                x: key.x.map(|x| &x[1..]),
+                ..key.clone()
+            },
+            CoseKeyError::InvalidKey,
+        ),
+        (
+            "y missing",
+            CoseKey {
+                y: None,
+                ..key.clone()
+            },
+            CoseKeyError::Malformed,
+        ),
+        (
+            "unknown curve",
+            CoseKey {
+                crv: -65537,
+                ..key.clone()
+            },
+            CoseKeyError::Unsupported,
+        ),
+    ];
+    for (description, key, error) in cases {
+        assert_eq!(
+            key.import_public_key(dh).err(),
+            Some(error),
+            "{description}"
+        );
+    }
+
+    assert_eq!(
+        key.import_secret_key(dh).err(),
+        Some(CoseKeyError::Malformed),
+        "secret key without d"
+    );
+
+    // The "11" key's y is even, so this is the negated point, which belongs to the negated d.
+    let negated = CoseKey {
+        y: Some(Y::Sign(true)),
+        d: Some(RFC9052_P256[1].d),
+        ..key.clone()
+    };
+    negated
+        .import_public_key(dh)
+        .expect("negated point is on the curve");
+    assert_eq!(
+        negated.import_secret_key(dh).err(),
+        Some(CoseKeyError::InvalidKey),
+        "secret key with the negated public key"
+    );
+}
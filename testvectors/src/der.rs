@@ -0,0 +1,329 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+use embedded_cal::der::{
+    DerError, export_pkcs8, export_sec1, export_spki, import_pkcs8, import_sec1, import_spki,
+};
+use hexlit::hex;
+
+pub struct DerVector {
+    ecdh_curve: i8,
+    /// SubjectPublicKeyInfo, uncompressed for P-256
+    spki: &'static [u8],
+    /// PKCS#8 without the public key, as exported
+    pkcs8: &'static [u8],
+    /// SEC1 ECPrivateKey with parameters and without the public key, as exported (P-256 only)
+    sec1: Option<&'static [u8]>,
+    /// Other encodings of the public key that are imported, but not exported
+    other_spki: &'static [&'static [u8]],
+    /// Encodings in PKCS#8 that end in the public key
+    pkcs8_with_public: &'static [&'static [u8]],
+    /// Encodings in SEC1 that end in the public key
+    sec1_with_public: &'static [&'static [u8]],
+}
+
+impl DerVector {
+    /// Runs the test vector by the Cal implementation.
+    ///
+    /// Panics if the curve is not supported, if any of the encodings can not be imported, if
+    /// exporting does not produce the expected encodings, or if a secret key with a mismatched
+    /// public key is accepted.
+    pub fn test_with<C: embedded_cal::Cal>(&self, cal: &mut C) {
+        use embedded_cal::{DhAlgorithm, DhProvider};
+
+        let dh = cal.dh();
+
+        let (alg, public) = import_spki(dh, self.spki).expect("failed to import SPKI");
+        assert_eq!(
+            Some(&alg),
+            <C::DhProvider as DhProvider>::Algorithm::from_cose_ecdh(self.ecdh_curve).as_ref()
+        );
+        let mut public_bytes = [0; 64];
+        let public_bytes = {
+            let exported = dh.export_publickey_bytes(&public);
+            let exported = exported.as_ref();
+            public_bytes[..exported.len()].copy_from_slice(exported);
+            &public_bytes[..exported.len()]
+        };
+
+        let mut buf = [0; 128];
+        assert_eq!(export_spki(dh, &alg, &public, &mut buf), Ok(self.spki));
+
+        for spki in self.other_spki {
+            let (_, public) = import_spki(dh, spki).expect("failed to import SPKI");
+            assert_eq!(dh.export_publickey_bytes(&public).as_ref(), public_bytes);
+        }
+
+        let (_, secret) = import_pkcs8(dh, self.pkcs8).expect("failed to import PKCS#8");
+        let mut buf = [0; 128];
+        assert_eq!(export_pkcs8(dh, &alg, &secret, &mut buf), Ok(self.pkcs8));
+        assert!(
+            buf[self.pkcs8.len()..].iter().all(|&b| b == 0),
+            "secret key left behind the exported PKCS#8"
+        );
+        let private = secret.into();
+        let derived = dh.public_key(&private);
+        let mut buf = [0; 128];
+        assert_eq!(
+            export_spki(dh, &alg, &derived, &mut buf),
+            Ok(self.spki),
+            "secret key does not belong to public key"
+        );
+
+        match self.sec1 {
+            Some(sec1) => {
+                let (_, secret) = import_sec1(dh, sec1).expect("failed to import SEC1");
+                let mut buf = [0; 128];
+                assert_eq!(export_sec1(dh, &alg, &secret, &mut buf), Ok(sec1));
+            }
+            None => {
+                let (_, secret) = import_pkcs8(dh, self.pkcs8).expect("failed to import PKCS#8");
+                let mut buf = [0; 128];
+                assert_eq!(
+                    export_sec1(dh, &alg, &secret, &mut buf),
+                    Err(DerError::Unsupported)
+                );
+            }
+        }
+
+        let import = |dh: &mut C::DhProvider, data: &[u8], is_sec1: bool| {
+            if is_sec1 {
+                import_sec1(dh, data)
+            } else {
+                import_pkcs8(dh, data)
+            }
+        };
+        let mut tampered = [0; 256];
+        for (data, is_sec1) in self
+            .pkcs8_with_public
+            .iter()
+            .map(|data| (*data, false))
+            .chain(self.sec1_with_public.iter().map(|data| (*data, true)))
+        {
+            let (_, secret) = import(dh, data, is_sec1).expect("failed to import secret key");
+            let private = secret.into();
+            let derived = dh.public_key(&private);
+            assert_eq!(dh.export_publickey_bytes(&derived).as_ref(), public_bytes);
+
+            let tampered = &mut tampered[..data.len()];
+            tampered.copy_from_slice(data);
+            tampered[data.len() - 1] ^= 1;
+            assert_eq!(
+                import(dh, tampered, is_sec1).err(),
+                Some(DerError::InvalidKey),
+                "public key that does not belong to the secret key was accepted"
+            );
+        }
+    }
+}
+
+// Keys of the initiator from Section 8.1 of RFC5903, encoded by OpenSSL
+// <https://datatracker.ietf.org/doc/html/rfc5903#section-8.1>
+pub const RFC5903_P256: &[DerVector] = &[DerVector {
+    ecdh_curve: 1,
+    spki: &hex!(
+        "3059301306072a8648ce3d020106082a8648ce3d03010703420004dad0b65394221cf9b051e1feca5787d098dfe637fc90b9ef945d0c37725811805271a0461cdb8252d61f1c456fa3e59ab1f45b33accf5f58389e0577b8990bb3"
+    ),
+    pkcs8: &hex!(
+        "3041020100301306072a8648ce3d020106082a8648ce3d030107042730250201010420c88f01f510d9ac3f70a292daa2316de544e9aab8afe84049c62a9c57862d1433"
+    ),
+    sec1: Some(&hex!(
+        "30310201010420c88f01f510d9ac3f70a292daa2316de544e9aab8afe84049c62a9c57862d1433a00a06082a8648ce3d030107"
+    )),
+    other_spki: &[&hex!(
+        "3039301306072a8648ce3d020106082a8648ce3d03010703220003dad0b65394221cf9b051e1feca5787d098dfe637fc90b9ef945d0c3772581180"
+    )],
+    pkcs8_with_public: &[&hex!(
+        "308187020100301306072a8648ce3d020106082a8648ce3d030107046d306b0201010420c88f01f510d9ac3f70a292daa2316de544e9aab8afe84049c62a9c57862d1433a14403420004dad0b65394221cf9b051e1feca5787d098dfe637fc90b9ef945d0c37725811805271a0461cdb8252d61f1c456fa3e59ab1f45b33accf5f58389e0577b8990bb3"
+    )],
+    sec1_with_public: &[&hex!(
+        "30770201010420c88f01f510d9ac3f70a292daa2316de544e9aab8afe84049c62a9c57862d1433a00a06082a8648ce3d030107a14403420004dad0b65394221cf9b051e1feca5787d098dfe637fc90b9ef945d0c37725811805271a0461cdb8252d61f1c456fa3e59ab1f45b33accf5f58389e0577b8990bb3"
+    )],
+}];
+
+// Alice's keys from Section 6.1 of RFC7748, encoded by OpenSSL, and as OneAsymmetricKey with
+// public key in the form of the example of Section 10.3 of RFC8410
+// <https://datatracker.ietf.org/doc/html/rfc7748.html#section-6.1>
+pub const RFC7748_X25519: &[DerVector] = &[DerVector {
+    ecdh_curve: 4,
+    spki: &hex!(
+        "302a300506032b656e0321008520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a"
+    ),
+    pkcs8: &hex!(
+        "302e020100300506032b656e0422042077076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a"
+    ),
+    sec1: None,
+    other_spki: &[],
+    pkcs8_with_public: &[&hex!(
+        "3051020101300506032b656e0422042077076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a8121008520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a"
+    )],
+    sec1_with_public: &[],
+}];
+
+// Alice's keys from Section 6.2 of RFC7748, encoded by OpenSSL
+// <https://datatracker.ietf.org/doc/html/rfc7748.html#section-6.2>
+pub const RFC7748_X448: &[DerVector] = &[DerVector {
+    ecdh_curve: 5,
+    spki: &hex!(
+        "3042300506032b656f0339009b08f7cc31b7e3e67d22d5aea121074a273bd2b83de09c63faa73d2c22c5d9bbc836647241d953d40c5b12da88120d53177f80e532c41fa0"
+    ),
+    pkcs8: &hex!(
+        "3046020100300506032b656f043a04389a8f4925d1519f5775cf46b04b5800d4ee9ee8bae8bc5565d498c28dd9c9baf574a9419744897391006382a6f127ab1d9ac2d8c0a598726b"
+    ),
+    sec1: None,
+    other_spki: &[],
+    pkcs8_with_public: &[],
+    sec1_with_public: &[],
+}];
+
+/// Checks that malformed, invalid and unsupported keys are rejected.
+///
+/// This needs a Cal that supports P-256 and X25519.
+pub fn test_malformed<C: embedded_cal::Cal>(cal: &mut C) {
+    let dh = cal.dh();
+
+    let spki: &[(&str, &[u8], DerError)] = &[
+        ("empty", &[], DerError::Malformed),
+        (
+            "trailing data",
+            &hex!(
+                "302a300506032b656e0321008520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a 00"
+            ),
+            DerError::Malformed,
+        ),
+        (
+            "truncated",
+            &hex!(
+                "302a300506032b656e0321008520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e"
+            ),
+            DerError::Malformed,
+        ),
+        (
+            "length not in shortest form",
+            &hex!(
+                "30812a300506032b656e0321008520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a"
+            ),
+            DerError::Malformed,
+        ),
+        (
+            "indefinite length",
+            &hex!(
+                "3080300506032b656e0321008520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a0000"
+            ),
+            DerError::Malformed,
+        ),
+        (
+            "unused bits in public key",
+            &hex!(
+                "302a300506032b656e0321018520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a"
+            ),
+            DerError::Malformed,
+        ),
+        (
+            "X25519 with parameters",
+            &hex!(
+                "302c300706032b656e05000321008520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a"
+            ),
+            DerError::Malformed,
+        ),
+        (
+            "RSA key",
+            &hex!(
+                "3032300d06092a864886f70d01010105000321008520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a"
+            ),
+            DerError::Unsupported,
+        ),
+        (
+            "P-384 key",
+            &hex!(
+                "3056301006072a8648ce3d020106052b8104002203420004dad0b65394221cf9b051e1feca5787d098dfe637fc90b9ef945d0c37725811805271a0461cdb8252d61f1c456fa3e59ab1f45b33accf5f58389e0577b8990bb3"
+            ),
+            DerError::Unsupported,
+        ),
+        (
+            "short X25519 key",
+            &hex!(
+                "3029300506032b656e0320008520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e"
+            ),
+            DerError::InvalidKey,
+        ),
+        (
+            "P-256 point not on the curve",
+            &hex!(
+                "3059301306072a8648ce3d020106082a8648ce3d03010703420004dad0b65394221cf9b051e1feca5787d098dfe637fc90b9ef945d0c37725811805271a0461cdb8252d61f1c456fa3e59ab1f45b33accf5f58389e0577b8990bb2"
+            ),
+            DerError::InvalidKey,
+        ),
+        (
+            "P-256 point in hybrid form",
+            &hex!(
+                "3059301306072a8648ce3d020106082a8648ce3d03010703420006dad0b65394221cf9b051e1feca5787d098dfe637fc90b9ef945d0c37725811805271a0461cdb8252d61f1c456fa3e59ab1f45b33accf5f58389e0577b8990bb3"
+            ),
+            DerError::InvalidKey,
+        ),
+    ];
+    for (description, data, error) in spki {
+        assert_eq!(import_spki(dh, data).err(), Some(*error), "{description}");
+    }
+
+    let pkcs8: &[(&str, &[u8], DerError)] = &[
+        ("empty", &[], DerError::Malformed),
+        (
+            "version 2",
+            &hex!(
+                "302e020102300506032b656e0422042077076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a"
+            ),
+            DerError::Malformed,
+        ),
+        (
+            "public key in version 1",
+            &hex!(
+                "3051020100300506032b656e0422042077076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a8121008520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a"
+            ),
+            DerError::Malformed,
+        ),
+        (
+            "X25519 key not wrapped in CurvePrivateKey",
+            &hex!(
+                "302c020100300506032b656e042077076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a"
+            ),
+            DerError::Malformed,
+        ),
+        (
+            "ECPrivateKey version 0",
+            &hex!(
+                "3041020100301306072a8648ce3d020106082a8648ce3d030107042730250201000420c88f01f510d9ac3f70a292daa2316de544e9aab8afe84049c62a9c57862d1433"
+            ),
+            DerError::Malformed,
+        ),
+    ];
+    for (description, data, error) in pkcs8 {
+        assert_eq!(import_pkcs8(dh, data).err(), Some(*error), "{description}");
+    }
+
+    let sec1: &[(&str, &[u8], DerError)] = &[
+        ("empty", &[], DerError::Malformed),
+        (
+            "parameters missing",
+            &hex!("30250201010420c88f01f510d9ac3f70a292daa2316de544e9aab8afe84049c62a9c57862d1433"),
+            DerError::Malformed,
+        ),
+        (
+            "short secret key",
+            &hex!(
+                "3030020101041f8f01f510d9ac3f70a292daa2316de544e9aab8afe84049c62a9c57862d1433a00a06082a8648ce3d030107"
+            ),
+            DerError::InvalidKey,
+        ),
+    ];
+    for (description, data, error) in sec1 {
+        assert_eq!(import_sec1(dh, data).err(), Some(*error), "{description}");
+    }
+
+    let (alg, public) = import_spki(dh, RFC5903_P256[0].spki).expect("vector should import");
+    let mut buf = [0; 90];
+    assert_eq!(
+        export_spki(dh, &alg, &public, &mut buf).err(),
+        Some(DerError::BufferTooSmall)
+    );
+}
@@ -106,3 +106,241 @@ pub const RFC5903_P256: &[EccVector] = &[EccVector {
     bob_public: &hex!("D12DFB52 89C8D4F8 1208B702 70398C34 2296970A 0BCCB74C 736FC755 4494BF63"),
     shared_secret: &hex!("D6840F6B 42F6EDAF D13116E0 E1256520 2FEF8E9E CE7DCE03 812464D0 4B9442DE"),
 }];
+
+pub struct PublicKeyFormatVector {
+    ecdh_curve: i8,
+    private: &'static [u8],
+    compact: &'static [u8],
+    /// The SEC1 compressed and uncompressed encodings, for curves that have them
+    sec1: Option<(&'static [u8], &'static [u8])>,
+}
+
+impl PublicKeyFormatVector {
+    /// Runs the test vector by the Cal implementation.
+    ///
+    /// Panics if the algorithm is not supported, if the public key (derived from the private key
+    /// or imported in any format) is not exported as expected in all formats, or if a format the
+    /// curve does not have is accepted.
+    pub fn test_with<C: embedded_cal::Cal>(&self, cal: &mut C) {
+        use embedded_cal::{DhAlgorithm, DhProvider, PublicKeyFormat};
+
+        let cal = cal.dh();
+
+        let alg = <C::DhProvider as DhProvider>::Algorithm::from_cose_ecdh(self.ecdh_curve)
+            .expect("algorithm not supported by CAL");
+        let private = cal
+            .import_secretkey_bytes(alg.clone(), self.private)
+            .expect("failed to load secret key")
+            .into();
+        let derived = cal.public_key(&private);
+
+        let Some((compressed, uncompressed)) = self.sec1 else {
+            assert_eq!(
+                cal.export_publickey(&derived, PublicKeyFormat::Compact)
+                    .expect("compact format is always available")
+                    .as_ref(),
+                self.compact
+            );
+            let public = cal
+                .import_publickey(alg.clone(), self.compact, PublicKeyFormat::Compact)
+                .expect("failed to import public key");
+            assert_eq!(cal.export_publickey_bytes(&public).as_ref(), self.compact);
+            for format in [PublicKeyFormat::Compressed, PublicKeyFormat::Uncompressed] {
+                assert!(
+                    cal.export_publickey(&derived, format).is_err(),
+                    "{format:?} export of a key without y coordinate"
+                );
+                assert!(
+                    cal.import_publickey(alg.clone(), self.compact, format)
+                        .is_err(),
+                    "{format:?} import of a key without y coordinate"
+                );
+            }
+            return;
+        };
+
+        let encodings = [
+            (PublicKeyFormat::Compact, self.compact),
+            (PublicKeyFormat::Compressed, compressed),
+            (PublicKeyFormat::Uncompressed, uncompressed),
+        ];
+        for (import_format, data) in encodings {
+            let imported = cal
+                .import_publickey(alg.clone(), data, import_format)
+                .expect("failed to import public key");
+            for (format, expected) in encodings {
+                let exported = cal
+                    .export_publickey(&imported, format)
+                    .expect("all formats are available");
+                let exported = exported.as_ref();
+                if import_format == PublicKeyFormat::Compact && format != PublicKeyFormat::Compact {
+                    // Either y is acceptable, but it needs to be one of the point's.
+                    assert_eq!(exported.len(), expected.len());
+                    assert_eq!(exported[1..33], expected[1..33]);
+                    cal.import_publickey(alg.clone(), exported, format)
+                        .expect("exported public key should import");
+                } else {
+                    assert_eq!(
+                        exported, expected,
+                        "{import_format:?} key not exported as expected in {format:?}"
+                    );
+                }
+            }
+        }
+        for (format, expected) in encodings {
+            assert_eq!(
+                cal.export_publickey(&derived, format)
+                    .expect("all formats are available")
+                    .as_ref(),
+                expected,
+                "derived key not exported as expected in {format:?}"
+            );
+        }
+        assert_eq!(cal.export_publickey_bytes(&derived).as_ref(), self.compact);
+    }
+}
+
+// The initiator and responder keys of Section 8.1 of RFC5903
+// <https://datatracker.ietf.org/doc/html/rfc5903#section-8.1>, whose y are odd, and the "11" key of
+// Section C.7 of RFC9052 <https://datatracker.ietf.org/doc/html/rfc9052#appendix-C.7>, whose y is
+// even
+pub const P256_FORMATS: &[PublicKeyFormatVector] = &[
+    PublicKeyFormatVector {
+        ecdh_curve: 1,
+        private: &hex!("C88F01F5 10D9AC3F 70A292DA A2316DE5 44E9AAB8 AFE84049 C62A9C57 862D1433"),
+        compact: &hex!("DAD0B653 94221CF9 B051E1FE CA5787D0 98DFE637 FC90B9EF 945D0C37 72581180"),
+        sec1: Some((
+            &hex!("03 DAD0B653 94221CF9 B051E1FE CA5787D0 98DFE637 FC90B9EF 945D0C37 72581180"),
+            &hex!(
+                "04 DAD0B653 94221CF9 B051E1FE CA5787D0 98DFE637 FC90B9EF 945D0C37 72581180 5271A046 1CDB8252 D61F1C45 6FA3E59A B1F45B33 ACCF5F58 389E0577 B8990BB3"
+            ),
+        )),
+    },
+    PublicKeyFormatVector {
+        ecdh_curve: 1,
+        private: &hex!("C6EF9C5D 78AE012A 011164AC B397CE20 88685D8F 06BF9BE0 B283AB46 476BEE53"),
+        compact: &hex!("D12DFB52 89C8D4F8 1208B702 70398C34 2296970A 0BCCB74C 736FC755 4494BF63"),
+        sec1: Some((
+            &hex!("03 D12DFB52 89C8D4F8 1208B702 70398C34 2296970A 0BCCB74C 736FC755 4494BF63"),
+            &hex!(
+                "04 D12DFB52 89C8D4F8 1208B702 70398C34 2296970A 0BCCB74C 736FC755 4494BF63 56FBF3CA 366CC23E 8157854C 13C58D6A AC23F046 ADA30F83 53E74F33 039872AB"
+            ),
+        )),
+    },
+    PublicKeyFormatVector {
+        ecdh_curve: 1,
+        private: &hex!("57c92077664146e876760c9520d054aa93c3afb04e306705db6090308507b4d3"),
+        compact: &hex!("bac5b11cad8f99f9c72b05cf4b9e26d244dc189f745228255a219a86d6a09eff"),
+        sec1: Some((
+            &hex!("02 bac5b11cad8f99f9c72b05cf4b9e26d244dc189f745228255a219a86d6a09eff"),
+            &hex!(
+                "04 bac5b11cad8f99f9c72b05cf4b9e26d244dc189f745228255a219a86d6a09eff 20138bf82dc1b6d562be0fa54ab7804a3a64b6d72ccfed6b6fb6ed28bbfc117e"
+            ),
+        )),
+    },
+];
+
+// Alice's key from Section 6.1 of RFC7748
+// <https://datatracker.ietf.org/doc/html/rfc7748.html#section-6.1>
+pub const X25519_FORMATS: &[PublicKeyFormatVector] = &[PublicKeyFormatVector {
+    ecdh_curve: 4,
+    private: &hex!("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a"),
+    compact: &hex!("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a"),
+    sec1: None,
+}];
+
+// Alice's key from Section 6.2 of RFC7748
+// <https://datatracker.ietf.org/doc/html/rfc7748.html#section-6.2>
+pub const X448_FORMATS: &[PublicKeyFormatVector] = &[PublicKeyFormatVector {
+    ecdh_curve: 5,
+    private: &hex!(
+        "9a8f4925d1519f5775cf46b04b5800d4ee9ee8bae8bc5565d498c28dd9c9baf574a9419744897391006382a6f127ab1d9ac2d8c0a598726b"
+    ),
+    compact: &hex!(
+        "9b08f7cc31b7e3e67d22d5aea121074a273bd2b83de09c63faa73d2c22c5d9bbc836647241d953d40c5b12da88120d53177f80e532c41fa0"
+    ),
+    sec1: None,
+}];
+
+/// Checks that P-256 public keys that are not on the curve, or that are not in the requested
+/// format, are rejected.
+///
+/// This needs a Cal that supports P-256.
+pub fn test_public_key_format_errors<C: embedded_cal::Cal>(cal: &mut C) {
+    use embedded_cal::{DhAlgorithm, DhProvider, PublicKeyFormat};
+
+    let cal = cal.dh();
+    let alg = <C::DhProvider as DhProvider>::Algorithm::from_cose_ecdh(1)
+        .expect("algorithm not supported by CAL");
+
+    let (compressed, uncompressed) = P256_FORMATS[0].sec1.expect("vector has SEC1 encodings");
+    let mut off_curve = [0; 65];
+    off_curve.copy_from_slice(uncompressed);
+    off_curve[64] ^= 1;
+    let mut wrong_prefix = [0; 65];
+    wrong_prefix.copy_from_slice(uncompressed);
+    wrong_prefix[0] = 0x03;
+    let mut x_unreduced = [0xff; 65];
+    x_unreduced[0] = 0x04;
+    x_unreduced[33..].copy_from_slice(&uncompressed[33..]);
+
+    let cases: &[(&str, &[u8], PublicKeyFormat)] = &[
+        (
+            "uncompressed point not on the curve",
+            &off_curve,
+            PublicKeyFormat::Uncompressed,
+        ),
+        (
+            "uncompressed point with x not reduced",
+            &x_unreduced,
+            PublicKeyFormat::Uncompressed,
+        ),
+        (
+            "compressed x that has no point",
+            &hex!("02 0000000000000000000000000000000000000000000000000000000000000001"),
+            PublicKeyFormat::Compressed,
+        ),
+        (
+            "compressed x not reduced",
+            &x_unreduced[..33],
+            PublicKeyFormat::Compressed,
+        ),
+        (
+            "compact x that has no point",
+            &hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+            PublicKeyFormat::Compact,
+        ),
+        (
+            "uncompressed point with compressed prefix",
+            &wrong_prefix,
+            PublicKeyFormat::Uncompressed,
+        ),
+        (
+            "compressed point as uncompressed",
+            compressed,
+            PublicKeyFormat::Uncompressed,
+        ),
+        (
+            "uncompressed point as compressed",
+            uncompressed,
+            PublicKeyFormat::Compressed,
+        ),
+        (
+            "compressed point as compact",
+            compressed,
+            PublicKeyFormat::Compact,
+        ),
+        (
+            "truncated uncompressed point",
+            &uncompressed[..64],
+            PublicKeyFormat::Uncompressed,
+        ),
+        ("point at infinity", &[0x00], PublicKeyFormat::Compressed),
+    ];
+    for (description, data, format) in cases {
+        assert!(
+            cal.import_publickey(alg.clone(), data, *format).is_err(),
+            "{description}"
+        );
+    }
+}
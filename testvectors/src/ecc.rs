@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+use hexlit::hex;
+
+pub struct MulVector {
+    cose_curve: i8,
+    scalar: &'static [u8],
+    /// Point to multiply with in uncompressed form; `None` stands for the generator.
+    point: Option<&'static [u8]>,
+    product: &'static [u8],
+    product_compressed: &'static [u8],
+}
+
+impl MulVector {
+    /// Runs the test vector by the Cal implementation.
+    ///
+    /// Panics if the curve is not supported, if any input can not be imported, or if the product
+    /// does not match in either encoding.
+    pub fn test_with<C: embedded_cal::Cal>(&self, cal: &mut C) {
+        use embedded_cal::{EccAlgorithm, EccProvider};
+
+        let cal = cal.ecc();
+
+        let alg = <C::EccProvider as EccProvider>::Algorithm::from_cose_curve(self.cose_curve)
+            .expect("curve not supported by CAL");
+        let scalar = cal
+            .import_scalar(alg.clone(), self.scalar)
+            .expect("failed to load scalar");
+        let point = match self.point {
+            Some(point) => cal
+                .import_point(alg.clone(), point)
+                .expect("failed to load point"),
+            None => cal.generator(alg.clone()),
+        };
+
+        let product = cal.mul(&scalar, &point).expect("inputs are on one curve");
+        assert_eq!(
+            cal.export_point(&product, false).as_ref(),
+            self.product,
+            "uncompressed product not as expected"
+        );
+        assert_eq!(
+            cal.export_point(&product, true).as_ref(),
+            self.product_compressed,
+            "compressed product not as expected"
+        );
+
+        let reimported = cal
+            .import_point(alg, self.product_compressed)
+            .expect("failed to load compressed product");
+        assert_eq!(
+            cal.export_point(&reimported, false).as_ref(),
+            self.product,
+            "compressed product decompressed incorrectly"
+        );
+    }
+}
+
+// Multiples of the generator, computed with OpenSSL; the last one is the `gi` value of RFC5903
+// Section 8.1, and the vector after it reproduces its shared secret `z` as the x coordinate.
+pub const P256_MUL: &[MulVector] = &[
+    MulVector {
+        cose_curve: 1,
+        scalar: &hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+        point: None,
+        product: &hex!(
+            "046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c2964fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5"
+        ),
+        product_compressed: &hex!(
+            "036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"
+        ),
+    },
+    MulVector {
+        cose_curve: 1,
+        scalar: &hex!("0000000000000000000000000000000000000000000000000000000000000002"),
+        point: None,
+        product: &hex!(
+            "047cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc4766997807775510db8ed040293d9ac69f7430dbba7dade63ce982299e04b79d227873d1"
+        ),
+        product_compressed: &hex!(
+            "037cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978"
+        ),
+    },
+    MulVector {
+        cose_curve: 1,
+        scalar: &hex!("000000000000000000000000000000000000000000000000000000000000002a"),
+        point: None,
+        product: &hex!(
+            "046780c5fc70275e2c7061a0e7877bb174deadeb9887027f3fa83654158ba7f50c3cba8c34bc35d20e81f730ac1c7bd6d661a942f90c6a9ca55c512f9e4a001266"
+        ),
+        product_compressed: &hex!(
+            "026780c5fc70275e2c7061a0e7877bb174deadeb9887027f3fa83654158ba7f50c"
+        ),
+    },
+    MulVector {
+        cose_curve: 1,
+        // n - 1
+        scalar: &hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550"),
+        point: None,
+        product: &hex!(
+            "046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296b01cbd1c01e58065711814b583f061e9d431cca994cea1313449bf97c840ae0a"
+        ),
+        product_compressed: &hex!(
+            "026b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"
+        ),
+    },
+    MulVector {
+        cose_curve: 1,
+        scalar: &hex!("c88f01f510d9ac3f70a292daa2316de544e9aab8afe84049c62a9c57862d1433"),
+        point: None,
+        product: &hex!(
+            "04dad0b65394221cf9b051e1feca5787d098dfe637fc90b9ef945d0c37725811805271a0461cdb8252d61f1c456fa3e59ab1f45b33accf5f58389e0577b8990bb3"
+        ),
+        product_compressed: &hex!(
+            "03dad0b65394221cf9b051e1feca5787d098dfe637fc90b9ef945d0c3772581180"
+        ),
+    },
+    MulVector {
+        cose_curve: 1,
+        scalar: &hex!("c88f01f510d9ac3f70a292daa2316de544e9aab8afe84049c62a9c57862d1433"),
+        point: Some(&hex!(
+            "04d12dfb5289c8d4f81208b70270398c342296970a0bccb74c736fc7554494bf6356fbf3ca366cc23e8157854c13c58d6aac23f046ada30f8353e74f33039872ab"
+        )),
+        product: &hex!(
+            "04d6840f6b42f6edafd13116e0e12565202fef8e9ece7dce03812464d04b9442de522bde0af0d8585b8def9c183b5ae38f50235206a8674ecb5d98edb20eb153a2"
+        ),
+        product_compressed: &hex!(
+            "02d6840f6b42f6edafd13116e0e12565202fef8e9ece7dce03812464d04b9442de"
+        ),
+    },
+];
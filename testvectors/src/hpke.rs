@@ -0,0 +1,297 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+use hexlit::hex;
+
+pub struct HpkeVector {
+    kem_id: u16,
+    kdf_id: u16,
+    aead_id: u16,
+    info: &'static [u8],
+    ikm_e: &'static [u8],
+    pk_em: &'static [u8],
+    ikm_r: &'static [u8],
+    pk_rm: &'static [u8],
+    // Present only for Auth mode
+    ikm_s: Option<&'static [u8]>,
+    pk_sm: &'static [u8],
+    // Ciphertexts and tags for sequence numbers 0, 1, 2, …
+    encryptions: &'static [HpkeEncryption],
+    exports: &'static [HpkeExport],
+}
+
+pub struct HpkeEncryption {
+    aad: &'static [u8],
+    plaintext: &'static [u8],
+    ciphertext: &'static [u8],
+    tag: &'static [u8],
+}
+
+pub struct HpkeExport {
+    exporter_context: &'static [u8],
+    value: &'static [u8],
+}
+
+impl HpkeVector {
+    /// Runs the test vector by the Cal implementation.
+    ///
+    /// Panics if the suite is not supported, or any derived key, ciphertext or exported value
+    /// does not match.
+    pub fn test_with<C: embedded_cal::Cal>(&self, cal: &mut C) {
+        use embedded_cal::hpke::{KeyPair, SerializedPublicKey, Suite};
+
+        let suite =
+            Suite::<C>::new(self.kem_id, self.kdf_id, self.aead_id).expect("suite not supported");
+        let recipient = suite
+            .derive_key_pair(cal, self.ikm_r)
+            .expect("failed to derive recipient key");
+        assert_eq!(
+            recipient.public.as_ref(),
+            self.pk_rm,
+            "recipient public key not derived as expected"
+        );
+        // The sender uses the public key in the vector's serialization
+        let recipient = KeyPair {
+            secret: recipient.secret,
+            public: SerializedPublicKey::from_slice(self.pk_rm).unwrap(),
+        };
+
+        let sender = self.ikm_s.map(|ikm_s| {
+            let sender = suite
+                .derive_key_pair(cal, ikm_s)
+                .expect("failed to derive sender key");
+            assert_eq!(
+                sender.public.as_ref(),
+                self.pk_sm,
+                "sender public key not derived as expected"
+            );
+            KeyPair {
+                secret: sender.secret,
+                public: SerializedPublicKey::from_slice(self.pk_sm).unwrap(),
+            }
+        });
+
+        // Receiving the vector's messages
+        let mut context = match sender {
+            Some(_) => suite.setup_auth_r(cal, self.pk_em, &recipient, self.info, self.pk_sm),
+            None => suite.setup_base_r(cal, self.pk_em, &recipient, self.info),
+        }
+        .expect("failed to set up recipient");
+        let mut buf = [0; 256];
+        for encryption in self.encryptions {
+            let buf = &mut buf[..encryption.ciphertext.len()];
+            buf.copy_from_slice(encryption.ciphertext);
+            context
+                .open(cal, encryption.aad, buf, encryption.tag)
+                .expect("failed to open ciphertext");
+            assert_eq!(buf, encryption.plaintext, "decryption mismatch");
+        }
+        let mut out = [0; 256];
+        for export in self.exports {
+            let out = &mut out[..export.value.len()];
+            context
+                .export(cal, export.exporter_context, out)
+                .expect("failed to export");
+            assert_eq!(out, export.value, "recipient export mismatch");
+        }
+
+        // Sending with the vector's ephemeral key
+        let ephemeral = suite
+            .derive_key_pair(cal, self.ikm_e)
+            .expect("failed to derive ephemeral key");
+        let (enc, mut context) = suite
+            .setup_s_with_ephemeral(
+                cal,
+                &ephemeral.secret,
+                self.pk_rm,
+                self.info,
+                sender.as_ref(),
+            )
+            .expect("failed to set up sender");
+        assert_eq!(enc.as_ref(), self.pk_em, "enc not produced as expected");
+        let mut recipient_context = match sender {
+            Some(_) => suite.setup_auth_r(cal, enc.as_ref(), &recipient, self.info, self.pk_sm),
+            None => suite.setup_base_r(cal, enc.as_ref(), &recipient, self.info),
+        }
+        .expect("failed to set up recipient for produced enc");
+        for encryption in self.encryptions {
+            let buf = &mut buf[..encryption.plaintext.len()];
+            buf.copy_from_slice(encryption.plaintext);
+            let tag = context
+                .seal(cal, encryption.aad, buf)
+                .expect("failed to seal");
+            assert_eq!(buf, encryption.ciphertext, "ciphertext mismatch");
+            assert_eq!(tag.as_ref(), encryption.tag, "tag mismatch");
+            recipient_context
+                .open(cal, encryption.aad, buf, tag.as_ref())
+                .expect("failed to open produced ciphertext");
+            assert_eq!(buf, encryption.plaintext, "round-trip mismatch");
+        }
+        for export in self.exports {
+            let out = &mut out[..export.value.len()];
+            context
+                .export(cal, export.exporter_context, out)
+                .expect("failed to export");
+            assert_eq!(out, export.value, "sender export mismatch");
+        }
+    }
+}
+
+const INFO: &[u8] = &hex!("4f6465206f6e2061204772656369616e2055726e");
+const PLAINTEXT: &[u8] = b"Beauty is truth, truth beauty";
+
+// Test vectors from Appendix A.1.1 of RFC9180 (DHKEM(X25519, HKDF-SHA256), HKDF-SHA256,
+// AES-128-GCM, Base mode)
+// <https://datatracker.ietf.org/doc/html/rfc9180#appendix-A.1.1>
+pub const RFC9180_A1_1: &[HpkeVector] = &[HpkeVector {
+    kem_id: 0x0020,
+    kdf_id: 0x0001,
+    aead_id: 0x0001,
+    info: INFO,
+    ikm_e: &hex!("7268600d403fce431561aef583ee1613527cff655c1343f29812e66706df3234"),
+    pk_em: &hex!("37fda3567bdbd628e88668c3c8d7e97d1d1253b6d4ea6d44c150f741f1bf4431"),
+    ikm_r: &hex!("6db9df30aa07dd42ee5e8181afdb977e538f5e1fec8a06223f33f7013e525037"),
+    pk_rm: &hex!("3948cfe0ad1ddb695d780e59077195da6c56506b027329794ab02bca80815c4d"),
+    ikm_s: None,
+    pk_sm: &[],
+    encryptions: &[
+        HpkeEncryption {
+            aad: b"Count-0",
+            plaintext: PLAINTEXT,
+            ciphertext: &hex!("f938558b5d72f1a23810b4be2ab4f84331acc02fc97babc53a52ae8218"),
+            tag: &hex!("a355a96d8770ac83d07bea87e13c512a"),
+        },
+        HpkeEncryption {
+            aad: b"Count-1",
+            plaintext: PLAINTEXT,
+            ciphertext: &hex!("af2d7e9ac9ae7e270f46ba1f975be53c09f8d875bdc8535458c2494e8a"),
+            tag: &hex!("6eab251c03d0c22a56b8ca42c2063b84"),
+        },
+        HpkeEncryption {
+            aad: b"Count-2",
+            plaintext: PLAINTEXT,
+            ciphertext: &hex!("498dfcabd92e8acedc281e85af1cb4e3e31c7dc394a1ca20e173cb7251"),
+            tag: &hex!("6491588d96a19ad4a683518973dcc180"),
+        },
+    ],
+    exports: &[
+        HpkeExport {
+            exporter_context: b"",
+            value: &hex!("3853fe2b4035195a573ffc53856e77058e15d9ea064de3e59f4961d0095250ee"),
+        },
+        HpkeExport {
+            exporter_context: &hex!("00"),
+            value: &hex!("2e8f0b54673c7029649d4eb9d5e33bf1872cf76d623ff164ac185da9e88c21a5"),
+        },
+        HpkeExport {
+            exporter_context: b"TestContext",
+            value: &hex!("e9e43065102c3836401bed8c3c3c75ae46be1639869391d62c61f1ec7af54931"),
+        },
+    ],
+}];
+
+// Test vectors from Appendix A.1.3 of RFC9180 (DHKEM(X25519, HKDF-SHA256), HKDF-SHA256,
+// AES-128-GCM, Auth mode)
+// <https://datatracker.ietf.org/doc/html/rfc9180#appendix-A.1.3>
+pub const RFC9180_A1_3: &[HpkeVector] = &[HpkeVector {
+    kem_id: 0x0020,
+    kdf_id: 0x0001,
+    aead_id: 0x0001,
+    info: INFO,
+    ikm_e: &hex!("6e6d8f200ea2fb20c30b003a8b4f433d2f4ed4c2658d5bc8ce2fef718059c9f7"),
+    pk_em: &hex!("23fb952571a14a25e3d678140cd0e5eb47a0961bb18afcf85896e5453c312e76"),
+    ikm_r: &hex!("f1d4a30a4cef8d6d4e3b016e6fd3799ea057db4f345472ed302a67ce1c20cdec"),
+    pk_rm: &hex!("1632d5c2f71c2b38d0a8fcc359355200caa8b1ffdf28618080466c909cb69b2e"),
+    ikm_s: Some(&hex!(
+        "94b020ce91d73fca4649006c7e7329a67b40c55e9e93cc907d282bbbff386f58"
+    )),
+    pk_sm: &hex!("8b0c70873dc5aecb7f9ee4e62406a397b350e57012be45cf53b7105ae731790b"),
+    encryptions: &[
+        HpkeEncryption {
+            aad: b"Count-0",
+            plaintext: PLAINTEXT,
+            ciphertext: &hex!("5fd92cc9d46dbf8943e72a07e42f363ed5f721212cd90bcfd072bfd9f4"),
+            tag: &hex!("4e06b80fd17824947496e21b680c141b"),
+        },
+        HpkeEncryption {
+            aad: b"Count-1",
+            plaintext: PLAINTEXT,
+            ciphertext: &hex!("d3736bb256c19bfa93d79e8f80b7971262cb7c887e35c26370cfed6225"),
+            tag: &hex!("4369a1b52e3d505b79dd699f002bc8ed"),
+        },
+        HpkeEncryption {
+            aad: b"Count-2",
+            plaintext: PLAINTEXT,
+            ciphertext: &hex!("122175cfd5678e04894e4ff8789e85dd381df48dcaf970d52057df2c9a"),
+            tag: &hex!("cc3b121313a2bfeaa986050f82d93645"),
+        },
+    ],
+    exports: &[
+        HpkeExport {
+            exporter_context: b"",
+            value: &hex!("28c70088017d70c896a8420f04702c5a321d9cbf0279fba899b59e51bac72c85"),
+        },
+        HpkeExport {
+            exporter_context: &hex!("00"),
+            value: &hex!("25dfc004b0892be1888c3914977aa9c9bbaf2c7471708a49e1195af48a6f29ce"),
+        },
+        HpkeExport {
+            exporter_context: b"TestContext",
+            value: &hex!("5a0131813abc9a522cad678eb6bafaabc43389934adb8097d23c5ff68059eb64"),
+        },
+    ],
+}];
+
+// Test vectors from Appendix A.3.1 of RFC9180 (DHKEM(P-256, HKDF-SHA256), HKDF-SHA256,
+// AES-128-GCM, Base mode)
+// <https://datatracker.ietf.org/doc/html/rfc9180#appendix-A.3.1>
+pub const RFC9180_A3_1: &[HpkeVector] = &[HpkeVector {
+    kem_id: 0x0010,
+    kdf_id: 0x0001,
+    aead_id: 0x0001,
+    info: INFO,
+    ikm_e: &hex!("4270e54ffd08d79d5928020af4686d8f6b7d35dbe470265f1f5aa22816ce860e"),
+    pk_em: &hex!(
+        "04a92719c6195d5085104f469a8b9814d5838ff72b60501e2c4466e5e67b325ac98536d7b61a1af4b78e5b7f951c0900be863c403ce65c9bfcb9382657222d18c4"
+    ),
+    ikm_r: &hex!("668b37171f1072f3cf12ea8a236a45df23fc13b82af3609ad1e354f6ef817550"),
+    pk_rm: &hex!(
+        "04fe8c19ce0905191ebc298a9245792531f26f0cece2460639e8bc39cb7f706a826a779b4cf969b8a0e539c7f62fb3d30ad6aa8f80e30f1d128aafd68a2ce72ea0"
+    ),
+    ikm_s: None,
+    pk_sm: &[],
+    encryptions: &[
+        HpkeEncryption {
+            aad: b"Count-0",
+            plaintext: PLAINTEXT,
+            ciphertext: &hex!("5ad590bb8baa577f8619db35a36311226a896e7342a6d836d8b7bcd2f2"),
+            tag: &hex!("0b6c7f9076ac232e3ab2523f39513434"),
+        },
+        HpkeEncryption {
+            aad: b"Count-1",
+            plaintext: PLAINTEXT,
+            ciphertext: &hex!("fa6f037b47fc21826b610172ca9637e82d6e5801eb31cbd3748271affd"),
+            tag: &hex!("4ecb06646e0329cbdf3c3cd655b28e82"),
+        },
+        HpkeEncryption {
+            aad: b"Count-2",
+            plaintext: PLAINTEXT,
+            ciphertext: &hex!("895cabfac50ce6c6eb02ffe6c048bf53b7f7be9a91fc559402cbc5b8dc"),
+            tag: &hex!("aeb52b2ccc93e466c28fb55fed7a7fec"),
+        },
+    ],
+    exports: &[
+        HpkeExport {
+            exporter_context: b"",
+            value: &hex!("5e9bc3d236e1911d95e65b576a8a86d478fb827e8bdfe77b741b289890490d4d"),
+        },
+        HpkeExport {
+            exporter_context: &hex!("00"),
+            value: &hex!("6cff87658931bda83dc857e6353efe4987a201b849658d9b047aab4cf216e796"),
+        },
+        HpkeExport {
+            exporter_context: b"TestContext",
+            value: &hex!("d8f1ea7942adbba7412c6d431c62d01371ea476b823eb697e1f6e6cae1dab85a"),
+        },
+    ],
+}];
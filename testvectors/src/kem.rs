@@ -0,0 +1,325 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+use hexlit::hex;
+
+pub struct KemVector {
+    hpke_kem_id: u16,
+    secret: &'static [u8],
+    public: &'static [u8],
+    ciphertext: &'static [u8],
+    shared_secret: &'static [u8],
+    /// Shared secret obtained after flipping the lowest bit of the ciphertext's first byte
+    /// (implicit rejection).
+    tampered_shared_secret: &'static [u8],
+}
+
+impl KemVector {
+    /// Runs the test vector by the Cal implementation.
+    ///
+    /// As encapsulation is randomized, only the recipient side is checked against the vector:
+    /// Panics if the algorithm is not supported, the public key derived from the secret key
+    /// differs, or decapsulation of the original or tampered ciphertext does not result in the
+    /// expected shared secret.
+    pub fn test_with<C: embedded_cal::Cal>(&self, cal: &mut C) {
+        use embedded_cal::{KemAlgorithm, KemProvider};
+
+        let cal = cal.kem();
+
+        let alg = <C::KemProvider as KemProvider>::Algorithm::from_hpke_kem_id(self.hpke_kem_id)
+            .expect("algorithm not supported by CAL");
+        let secret = cal
+            .import_secretkey_bytes(alg.clone(), self.secret)
+            .expect("failed to load secret key")
+            .into();
+        let public = cal.public_key(&secret);
+        assert_eq!(
+            cal.export_publickey_bytes(&public).as_ref(),
+            self.public,
+            "public key not exported as expected"
+        );
+        cal.import_publickey_bytes(alg, self.public)
+            .expect("failed to load public key");
+
+        let shared = cal
+            .decapsulate(&secret, self.ciphertext)
+            .expect("ciphertext should have the right length");
+        assert_eq!(cal.raw_secret_bytes(&shared).as_ref(), self.shared_secret);
+
+        let mut tampered = [0; 2048];
+        let tampered = &mut tampered[..self.ciphertext.len()];
+        tampered.copy_from_slice(self.ciphertext);
+        tampered[0] ^= 1;
+        let shared = cal
+            .decapsulate(&secret, tampered)
+            .expect("ciphertext should have the right length");
+        assert_eq!(
+            cal.raw_secret_bytes(&shared).as_ref(),
+            self.tampered_shared_secret
+        );
+    }
+}
+
+// Self-generated interoperability vector for ML-KEM-768; this is not a NIST ACVP vector.
+// Produced with the ML-KEM implementation of OpenSSL from the seed
+// SHA-512("embedded-cal ML-KEM-768 test vector"), so it only shows agreement with OpenSSL.
+// The FIPS 203 ACVP vectors still need to be added; the cases below have their shape, but are
+// self-generated as well.
+pub const INTEROP_MLKEM768: &[KemVector] = &[KemVector {
+    hpke_kem_id: 0x0041,
+    secret: &hex!(
+        "937de226fc6053dfeeda0f10c9bb11093c7f999d6770151ace3d4f92a9839f35c77aa779c069af41206dd3ea411a48e2dd32b8cadc99baaa287f5169ea6516c2"
+    ),
+    public: &hex!(
+        "b4aaaa30b156b20c4aeea501329c90610572fd123b85d63e1915c27d8c9f2db5a737a82790636c28b7cbff1651c7411ef7c8909cf515be06182043b03d3222e35c6c17704bfe05759ce60d0fd58211420751f448a34057eaa13102c88dbd002c20b2a28b96b28d1b09eb9805cf52ba03b74281f1288fc9594bf8893ab250e3da8ab1854f9014302e7740e2759824a21bd6e5860cc7193387450f999be5c2b11d95cbb5b5226e391f2413652b3951c1e97d462c03b0b9533cc93d6441c68f0757c8f9788413bf82e25d3222a834e678c1ea03d4374720a9bb3ac29842aba848f5031c51b7b99c8e985853f9fb5baf69a984564c03304e8bcb651a212d96d442c8a90204754611e1c5d301c581fba683ababa6147044aacf598345f7e927b7c5190c74c46ff570d2840b9c23c9a38023582c0d3ecc97a57c9f316962d0fbb52dda505ee523308263ec35b470e819d64c65997bcc4bc08ac57280c86551db0b0cb18322bc135af0e7aa6011441bd137cfa66898c10468d37fb0c3297dbc136fc13d16e4320f1a2f5ebc6326b69bf1565f4dd45b33111981a1506ac8aca9f33216b2b3e7cc69f158c752b59bf9ac92d19394cd98be7c756973206a3f41cb81513923449d0ef401d4c7324f8718587ac95b9662e4e7814e8c5c806a851da33b0d523735dc0a5d25ca2273066cab09685b01ec636b6e0350485c604a619da772548124ce5755008d9acd1d712869b30f4ab9843b21791526b181a8b459aac0f93918a2ba3f4c58173f517ab76c86ae99bb18f9c19e44150458cdac54962d4249167b38f9c20a873850ef15bf89b337fa31634e66862ecc04f35a1eda9a95c712cdc4d7581a589b4725cff64b772b068e23b896d79a7ec7075a66164d56c88250e419fbb9959b03691f320c1d90b432abce8f2c8724924476c488cbac90125a0c0d135747c75259d35564710e0a4cb9506256a25038fe978df2817cd2b762a69cceab89c74f42a751f8c38bca9662aa89309abe4238cdbb619a8f720dd9fb13a66b04d08086e145556ba5005736a2949061f603598a103d2e80a7ff935577755ef8607b5e25976fd4334578a8a1885979d513bc88ab7791a2eccc5e72a1b9f5bbbb5da0b66e674517a8609d438abde763db150641b20b3738668de33c75012230e584e6916fde41050b31b9ee616b7254011dc729eafca9bd35a30b021d421694eb1b34949b0daa9439e732beee434474a4a8fcb38b98897790297a91068f02995da152cd50d95b4f34733d919074bbb00067205dd85ed9b6abeb348ea4faa7ddb52aac190f31e828e8545400430afe6267ed7276da2702b20ac023ea344c4ac2bd0b63583393d048966aeb99f08c53d2688fb512372a20a803067ea4bb4d3f251c41a812ca687e11a18961063b83e982d1bc0d89a939a78a75eeb5321570a2c48276201438e7ec78340b400b2911a948a852a07e8d745f78ca5d614892cb281eca188aba3476f4a22141203d72aab71f14139e8b498d650a3a09500910ac43008f9204ab9b414973336aa7716fc788a3425941d6b1101e4763f3522edbe05d032730165874b4d9078e114014daac7ad0a4dd982deeb8670e94bebdcb93db7c894cb28fcddd8a287f50a7a9097b6d81c2925319cf43e95234dc3602885481081b1c29ac"
+    ),
+    ciphertext: &hex!(
+        "77f04e4ddcfe237d813d6f8ccfa0eeaa7ce6076a05334f53f62fa2561d8984eec76b1c0e99824c2f23069764412c482f525c7b4f1cb2d679222e27fbe7679d69d2e5817d74da2f43db7de6b314d2b0401c3fbb2c62e2ecada7e7cbc3875d2c5024f1dba43f18e6d7e21a29b85bf34586973892b7537ac1936b6deb9f421d953d71ec2d31178fa872b3e4284b798dc3a670d0fedb74d09f5587323fdc1100e1f21dd0ced21c1909df477ce18a8b7207bf87efed23bce13fd932ac233f624a4198662c88111c8ba53b6d2c14db5177d9d83e21b9d03e2c7f4e908c5f0f387f417e58ed9394fa0cdb0a3e33e607134aa46ae2e994d20cf946ccc1e1d2aeb2dc7057171b22839e6ababc767a071e3cbd19e676cad95d3334336d18336ff105923256db2ba8179aaef3561b3a83a2235b55ffb0708dcd7dfd14c0b74c2c2e32b55a27b06f314edee2b8dd221cbca10888148e99f0a1cf822d1b40c2e47ec63f822da6eb478ddb5f85a292d1812ac2ba78e5dbcd50e7c6359010e3c6ea3a2cf6121d0afa9038e8b58fc85b302b00acc5767c9c4cca555a290278105cba0ebcce7b82bb42e63056c0a10654e16a745c44c97f34eb8d2a3ed7e96b5de5506ed316b58d279a9b55b8c176287a561c7a5b0fbab1aa7eba4796dbe195fc793ee50a2066f6e0f495291987731f6fae2a6f1e259790c556a8d1dede3e2f7712038c3f8f7e60705aba0eeaa7e75aee8ed69f59fb198c8807189b4822be9ed64f93968eb9cc819c8583287ede75e990dbaac76064ef81e245ea2ebbe7c020b968697a721be429100e4785eee28deb117a779ff8a9a06f6be20c36cf3f27115822d847543316cbe25d21e253a23bc35a4795268d245e457562d658e381d88c2a6043910ab1c0791f957f9e232f612466b5a13b4eeb3e6b77efac7c0068aa1eb806ff22e4f08b59c3c5623bdfc6555b386dc54343bf8c102fe4cb208aeb26b4f2323268eac295833973d4e1c33f8e239bbc8aef9fb030eda2c71f0e59a65dc514adc083b568fd7b1e7eda527cec11659bea94c852c3ff146425c47dd7cef57eac28f1d4ab2b3d0c382b732ac5127b847bbc2d66cf2f653aa4e5defff1c2f4e6294447cda405101337be6f8d4ef3c043a85bd0d25026d1cb0a40eed26817ace9206e98475bac5ce0b13b56a5c7381e4b605238b55b4c28a8a97bce1ae933905b2375b22d0799eb75e7ba1ea817505f73680fe4d68b9604204d679b7f674c24fa91a2b349dff988ef22c01f757964d133655f9dae24070615f33aba0a12117d3ca2b261d48c60eb9e8bb6d124c9dac9d529d4ac36803b7cc93175e2dbc483f2cecac2c3e6a06430ab0d0461a699f817192e1d78b678b92918b01e9aabf274112d975846402784b9f5b07bf1833e337b568981e1ac90351d42262744f2a2b3bc26a20dc0cc8c97880f0ceebe50ad15ad43b349bff7bb3a72250fa9fbcc1e89c791c08da6be2d3eeb484353fdfb4d194c6cf12948ccadadb12c0e8f403d83b3978ab0614582c133c3f10d"
+    ),
+    shared_secret: &hex!("b53ed843e1b73bd01c890bcee08d8cbdbb4ab8cbf84af5ab3fcd3dd1e8da7eb4"),
+    tampered_shared_secret: &hex!(
+        "9dbbd30bfd75e1fad1d4f24ef86974c732788686a4a17a5319fd5748052d39b3"
+    ),
+}];
+
+/// An ML-KEM key generation case, in the shape of the FIPS 203 ACVP "keyGen" tests.
+pub struct KemKeyGenVector {
+    hpke_kem_id: u16,
+    d: &'static [u8],
+    z: &'static [u8],
+    ek: &'static [u8],
+    /// Decapsulation key in the expanded form of FIPS 203.
+    dk: &'static [u8],
+}
+
+impl KemKeyGenVector {
+    /// Runs the test vector by the Cal implementation.
+    ///
+    /// The secret key is imported as the seed `d || z`. Panics if the algorithm is not supported or
+    /// the encapsulation key derived from it differs.
+    ///
+    /// The expanded decapsulation key is not accessible through the [`KemProvider`]; the secret
+    /// key is returned so that implementations can check it against [`Self::dk()`] themselves.
+    ///
+    /// [`KemProvider`]: embedded_cal::KemProvider
+    pub fn test_with<C: embedded_cal::Cal>(
+        &self,
+        cal: &mut C,
+    ) -> embedded_cal::accessor::KemSecretKeyOf<C> {
+        use embedded_cal::{KemAlgorithm, KemProvider};
+
+        let cal = cal.kem();
+
+        let alg = <C::KemProvider as KemProvider>::Algorithm::from_hpke_kem_id(self.hpke_kem_id)
+            .expect("algorithm not supported by CAL");
+        let mut seed = [0; 64];
+        let seed = &mut seed[..self.d.len() + self.z.len()];
+        seed[..self.d.len()].copy_from_slice(self.d);
+        seed[self.d.len()..].copy_from_slice(self.z);
+        let secret = cal
+            .import_secretkey_bytes(alg, seed)
+            .expect("failed to load secret key")
+            .into();
+        let public = cal.public_key(&secret);
+        assert_eq!(
+            cal.export_publickey_bytes(&public).as_ref(),
+            self.ek,
+            "encapsulation key not derived as expected"
+        );
+        secret
+    }
+
+    /// The expanded decapsulation key.
+    pub fn dk(&self) -> &'static [u8] {
+        self.dk
+    }
+}
+
+// Self-generated ML-KEM-768 key generation cases, produced with OpenSSL from the seeds `d || z` =
+// SHA-512("embedded-cal ML-KEM-768 keyGen 1") and "… 2"; like the interoperability vector above,
+// these only show agreement with OpenSSL.
+pub const INTEROP_MLKEM768_KEYGEN: &[KemKeyGenVector] = &[
+    KemKeyGenVector {
+        hpke_kem_id: 0x0041,
+        d: &hex!("bf80dda287b8d3486e1de5c42da33c35b68c5b9846f0d3c6808f4391d476d49f"),
+        z: &hex!("83421c427b9f70fd721f5fef747c0b25b56dc386292ec545dec29d8c1582e698"),
+        ek: &hex!(
+            "974319a960ae7e031c9651ca18000cb349baf950244f025205f088d4da99266b1850594d5475a7fc33b881466b0a2b45ad8105c0fa9bf8127847b9a5bb1c053a9532ea853720f96d89798332aa73be549c77dc2fb6e0b51c9767ed613ec6f3a0686c51a3d596ef5a7b2d3772b1b742853b9d1514bd18a557a6b9a41decbc436aad11a6304e117812d330b185af49ba25e8552c27fb38bfa03cffcc1bf31a3f6caa426ad80521863a604a336ee83f909a27802b4a0da16795b51ae2620d7dd89713b89c3a29a9f4b1461865639a6c03fea63ffa48bcfa264f2d993850aa8cdd173834f706107022691964c3197772d874badb6d79d002581280f82183620928d375a94485b0c705795fd0cc9608780397987e4170fcc2c37ce3728a6b23a91743f78247930c73b6ab3b4b459831358bf3f029eab88966d619a5d9245da31608b973b38b5796f588b9b7260b58b59c4bb3b947249a97cbf1c33fd1320d69b89660c2b0b999aa7fc639d6b664921448a3c50b43fb3672dc2080bb510229c0aaa612886219d5100a59e79c36a11ac195b2156506fa71c5724b831ff05003b1be34c51543b14a08da73ab52bb1adaa0fad568f58c1630d1ce83b72d0e5864cc381b181aae2ac410b3391e727ca4e6d313f8b79b4c88412547ac3815079c594b10c99b02a4c7094a93b60c820c00192d188d3c1c363fbb2f1e309f6f20c9526abf8f5cb490584481761fe49b0ff8a17188b89c8e8670ce789aac612796103cae36204396a26ef80482a72549fa2a516042104168fe632c080a5b66394ce9462dd4883d326c5c510630ac61680690ba96c26544a47fb0d6032c252f82729c679b785ef1039186503a7905c1126eac763a8e095aaa16921c105b0d8cc7e1d2a1cb337b3d1c8d9aac430e45c27d966363654e31655415287b3c131a539c70e558caeaea99823cb6eeb44a7937803fa95b10413d24607bbd45c47d91696e092e27172599b88d1ffb32f0f3822aec86d66930f9776d433652e001380b5a12b484b76478a1e1d2707562c9f8c17280c53bdf71601b388afd1c9b574093e37c0bfaf302b1f661b8f0bc299c50c0294ff0cb2b4246606314725f429a4d39c301a975104abe7f7a72078a1cb5bcbef9a68f50174a99035552d22bc46b6df39cc430ca600a4117ca19c4ae350b075c6c45340bb8c6cb0ddc211937768de08749591381623f15f08a9680afd9b2bf133100a0491b0143b699b81f2e49a75c643b6be15c9b22a013996eb6eacc6f490c21dbb78d5b481918b4d711223181cc32b31e7927786167111b811ae3495d4528bbd9633284d00a7e159e82465f46f650c25047042c26c59360546339ca9191f3452480441562c8ac7b50a94edb81c77b0d549788f7f05598ec932c040887a4b2c5961a901c9634600f690c735ab2c45cb63a5e2826d98c8e72ac681485b45ec0cb416abd7775c867b3024e0908ea6211cc416c2761a444d15446a82938584748d17f27e7788a59743eda6d2f20a249503bdcc64dbac2c400d3a593b677199baf39e62b0311ae25b733fef07c883a56071b99c77a5542252309acab63079bc01940ba99b0f0797b82ea6165907858764457568f6d35b7c53b1d8a840e180960bdd7fc74cfc8b04d38b4bba57bdee95f784cd324ae3ccd0828c4"
+        ),
+        dk: &hex!(
+            "f9891eb38b5649a998812c2a2f72269c0ccede0c2464c40089dc7b177a443b844d11a873dba80118b2398d4b71f6f1761f139c8068a5b01832a801a9ae0cc0a8e979462c3c4c0a1b3921af13b58d59723ccfc96d1ec60dd5e30c19e2b25791b188a867ed177772455fb676554935ad5186a1292693e81a1cd789b8087800ab22374155b8f1c974db77771d598aa106548e060c92e51383f7a41a56248385593319919bf616755a99cfab7bbe2088b06344be13c1b1e83a3c6b3d43d89b1f49874898b7f950559d4109e45255de5378abd68d5a024a2b2b85695b52fcc29cb0f072d6431d17401be929786cb9bf04f95e8ec1294a697dda8133c0002db80b8ef849478b022ef9080b75a6a575e209151360beec906b493ffa1c5e8569c33f095afc1c8729a29c190202cbb543053b34e83669261546d0e83d21b18a0c3bc9bcd0b9442718e14a5b8db102ce100cdab6ceaff281eab52637e2c8244676096729dcb25649926a608c2eafd778d6769634f06b72e934558c213f229a432839d30b249523161bdc83be2ccf6545809ee5bdf0d24e66d98c0edb86a29695bcf59bf0b67027eaba57ca8e4b15b4d459cc923ca922b249b642a1fed5b411122c593638ce9c02221bbcf3c17b839023f4d3c344a2233077032ad23ed7f1cc446425f124552ceb6564e36c26fc4ddfd60fd458b2e349588f02489a128262a524bac97fac23c748969176bb62c4ebb5a41476bc378c5af97088185fc63498880b194d846cc0506d0199cd0b28c0e6b34a00fb1e5fe17392974774589be1e258555c70e2d4224a6713341283343953839c37a2a88545430c52ba6841f65811d8a2813231a88166086b24e1fa73110887b684467ed64d45f2467dcb278c828b9454c84d8617bcd48835929592e61cf806cc34065c7668a728038915f911434c0fa64227d1251a5bcc76290b03bebbb2bbb785331522059651b16a3431531aa8e29c19b00fdad7c0973863fbfa1fa1515ed5f28b79871675562e773072ebc19d93d51c878c4e427c1a31708f48b4caa4610b500c3c48718b0b01414b65308dd80498664467947b67e7413790a4ef2a8ed62063171481a16690e6911118d6640db4a31d59af33a91786d1578129bf0e54cdab70b0bc171d88f262ac2c1fcf0abd91ec582c2c0d4df1865d03bd9ec92c51b43be6d35d3401693dba2bc8e63c9722a728f840f5b3c18e6b85a890a232c5013d26cafda67edab86ef0617c394a8cebba949cd250ba72bb18818dcb1196e8364d7f58c4d01b1e23ea903f2a40e00a079e562072f61a932c5001f7a55bca510ad935b099a816a4482436758ef06cbc23c076920f2be847e73077bcd499d4b36ce18b2a6a344aee4901dcfb5f143b918d95c2597c5ef8cc91a0142d3f511c6e2a6c5798acd0a14daeb444500bc0b9501f618995a4e74979bc1129b1cfe80374c4e9b716da564c532347b90b1b561d493343d005b2297b675751593f41c316aa7430920721ebad9e18832a5b7edb826dafcc1c9d77baee83b9f3b02cb48bae642759c646b039622ccf295946855fa27a3d4085cd7e1b9967439f9be1942b1829940a5b35a9120a728e67944e88186795118ac98ba75b6750974319a960ae7e031c9651ca18000cb349baf950244f025205f088d4da99266b1850594d5475a7fc33b881466b0a2b45ad8105c0fa9bf8127847b9a5bb1c053a9532ea853720f96d89798332aa73be549c77dc2fb6e0b51c9767ed613ec6f3a0686c51a3d596ef5a7b2d3772b1b742853b9d1514bd18a557a6b9a41decbc436aad11a6304e117812d330b185af49ba25e8552c27fb38bfa03cffcc1bf31a3f6caa426ad80521863a604a336ee83f909a27802b4a0da16795b51ae2620d7dd89713b89c3a29a9f4b1461865639a6c03fea63ffa48bcfa264f2d993850aa8cdd173834f706107022691964c3197772d874badb6d79d002581280f82183620928d375a94485b0c705795fd0cc9608780397987e4170fcc2c37ce3728a6b23a91743f78247930c73b6ab3b4b459831358bf3f029eab88966d619a5d9245da31608b973b38b5796f588b9b7260b58b59c4bb3b947249a97cbf1c33fd1320d69b89660c2b0b999aa7fc639d6b664921448a3c50b43fb3672dc2080bb510229c0aaa612886219d5100a59e79c36a11ac195b2156506fa71c5724b831ff05003b1be34c51543b14a08da73ab52bb1adaa0fad568f58c1630d1ce83b72d0e5864cc381b181aae2ac410b3391e727ca4e6d313f8b79b4c88412547ac3815079c594b10c99b02a4c7094a93b60c820c00192d188d3c1c363fbb2f1e309f6f20c9526abf8f5cb490584481761fe49b0ff8a17188b89c8e8670ce789aac612796103cae36204396a26ef80482a72549fa2a516042104168fe632c080a5b66394ce9462dd4883d326c5c510630ac61680690ba96c26544a47fb0d6032c252f82729c679b785ef1039186503a7905c1126eac763a8e095aaa16921c105b0d8cc7e1d2a1cb337b3d1c8d9aac430e45c27d966363654e31655415287b3c131a539c70e558caeaea99823cb6eeb44a7937803fa95b10413d24607bbd45c47d91696e092e27172599b88d1ffb32f0f3822aec86d66930f9776d433652e001380b5a12b484b76478a1e1d2707562c9f8c17280c53bdf71601b388afd1c9b574093e37c0bfaf302b1f661b8f0bc299c50c0294ff0cb2b4246606314725f429a4d39c301a975104abe7f7a72078a1cb5bcbef9a68f50174a99035552d22bc46b6df39cc430ca600a4117ca19c4ae350b075c6c45340bb8c6cb0ddc211937768de08749591381623f15f08a9680afd9b2bf133100a0491b0143b699b81f2e49a75c643b6be15c9b22a013996eb6eacc6f490c21dbb78d5b481918b4d711223181cc32b31e7927786167111b811ae3495d4528bbd9633284d00a7e159e82465f46f650c25047042c26c59360546339ca9191f3452480441562c8ac7b50a94edb81c77b0d549788f7f05598ec932c040887a4b2c5961a901c9634600f690c735ab2c45cb63a5e2826d98c8e72ac681485b45ec0cb416abd7775c867b3024e0908ea6211cc416c2761a444d15446a82938584748d17f27e7788a59743eda6d2f20a249503bdcc64dbac2c400d3a593b677199baf39e62b0311ae25b733fef07c883a56071b99c77a5542252309acab63079bc01940ba99b0f0797b82ea6165907858764457568f6d35b7c53b1d8a840e180960bdd7fc74cfc8b04d38b4bba57bdee95f784cd324ae3ccd0828c452399faca06d311ab94d868082f4d4a9ea3892b7accf80267ec289dd7450180483421c427b9f70fd721f5fef747c0b25b56dc386292ec545dec29d8c1582e698"
+        ),
+    },
+    KemKeyGenVector {
+        hpke_kem_id: 0x0041,
+        d: &hex!("6d1d1655a5a97760b78cb849976c7488822345319ca6dad4c66a960ab12c11f6"),
+        z: &hex!("dbdc4aa58bcd8139996d7fa11fdb7ddcbc497846dbacc5a31fcf92844feefcda"),
+        ek: &hex!(
+            "f7a438b144ae64b95d1ca87c8cd293a4525c0ef79c9bb032281a62121ac155cbcb63f1bfe7321ec73a81ed12967b5917e4667f4dd7820f96b822a50d9e8826a2197df8a5435b1978715295f3c0ce46f6a7bc0b533ce01cfd36ad2fc6c497586b84d5757b3ca487f3aef2376cedd678114948bed3163a2c51f1e2770d7ba592f95734981c5028649af7bcaf767204543c1b055f48fa2ceb60c24c200290f0a1da1b8abf9c44bde1302c041f6b257b45751ba36972935b0531fabd77c258b6a5bd1376acbe5b117373211311a91ea347ec5972c475c317c722be847f757baf54fa61ee1730ab16496dcbc525c948c460b018d512aaa88404945b5c12003d045485f3c613465b32041a2479a99f3117f3409f6bc5326bc2ca7b16215fa99452ec3df87acf4698a8c99129ec964e42775416ba4efb1676907a103a481e02e9c29dd94d44e8cbb95c0b996b2fedc8480c6c22384292eea694389ba029d11a4e734afe9a1954a20f3b4b322cc457c5d039cef83e205c719ed1361eea21832ab415b28cf72c58be0c953fbb62e640093fe177be36ceee6b842f5291e5fa031a5820c52c7fd8517579a98d100ab791645df9152f871855853cc7c329bbaa50a844d576d1db4f667433c00a436ac7461356ab6f19b464898482e95a9cf5a22cac089eb5501b695d0dd69fb2c92993753c3ee6130bbc232257b2c93b1ece3579cce5283561073384c597564db864391abb370191071ad373f33823df5a342da67956c039c02596c11379abd81e8e75273d1114f43510c22670bdfaa475c942b7b12a7d579a1d238e055308b82a622f41a187540fa95320917585344c45e63b09e7414bb1a481ca3049fca01842754622a16ace842632f30a59d555195357d2989d4506cf59c53a6ca21abf500e0652608a200abbe57295bb17d072ab946770c282a8e24787cf02c129f0307ab6c1957b16d646c500d874499314cb1167a1ea57f74455ad652108b239da8215d006c45d106763b455d73a36b8589f277cc4e630648e483ae81413fdd89888c0175ea694cb31a321648ffd16657b57b46efc0b3b5ba9f491776a06c29939aef9e5003e907f82ecb599d15eb1a8ca76975a72074d9d58c4ce6c01e7d43821e10ba0e55cc6c3c4ab1c3317326d5269cb40675d728b54563168e9d640511c8ed6fcbaeff2837b3c677ff507742932616b78eef817e2fb856726662f6a5e1dd31feb86939dd58245a24dfa6b609832c341758a3d3146fa4962a55396fcdb19f76561f2694b26c29155fa3eff073cee39b6c8168097e567843605a2805ae1d47436622958302930702fa644065946bccff28889f35f6bb261a0493c576b308a3940e241989b77a5ec744bf7301ab09069d7e6b495eaa242d5286288602fcb3bfe8686768a2230b03ce72c6840ca4927a07a8f6824170c81ebbb8c0a85895000944b7c576da4768699057350807b462581460155e07cf0d54b0a41a1cb09b30f3c0634751028c6842219518e893635847bab1a0194784c6ad912d0077c44ca9612198496d26510aa43c9b5c7ae553cfba48c619b0dd0f0b0bbdb8b1af3506506c316673b8af58b706083bea47433426589d9acd86a93904c6c4fa7bb9cf7e75886b3d18e12fbe54c4cee11fbf3ea0639101248a8a805624f2f6cb6bc"
+        ),
+        dk: &hex!(
+            "50e23b7d539e08a346459106a3ea52b6780714e2ba4ce01d64292890e87d735101da47b0d3fb5003156b4c129d5f16abd9241a19f05672432d5dda0636193c3ef3ae23d152babccdfe21256f62c1d2920fde689bb2a2bdb18348949c3cf936784b1cb8a8e6b3dffc2c1385503ef62d5a380dfd59301b47cd8ba35be9820b7f950b484c30917137e35cb5032175d88880379c02114344b3501c46db2489cc0213a30f927719308784539c9eadd18c25933b70b4852692574cd127b2d59272402e007ccf0412865423939fb387fa57b8125a39031a3a89a99657010d95f09b59ca8b2d923ced965a019463a82b7b46fc5eb527382529176487b2e7c8406786b59e54462219ce214493b4ac5d3be06f5610ad38a2637031373ae6cfc2893ab617cdddb4bb65092e5b63365e3618d1fb2c9c67af0a169d1a075bd86295a4c81dfb678621462ad664523aa9252fc924224c49c1453090fc4bba804a130343989727f139928391682755391555279330553b84288d507cb0c80b381aa6f2874c9719c0f0542856b8542a924b737c3672f3a166390190f52bbd2553fc508fd2660910710d3c1520726cb9d976489eb8afb0c08610b5b55fb504964c415f4c0a9c3083f083090d440ed5201be4f2695b78130d4042b28919b09a50d09a1f14b8bd86d2b74ef3a9f2e73d75541970476fcb3489f698b1d7d45e505cc023323b1ad97579369908b88302f864cdcc7dcc83b8314a419c816ca476a4095b1a093a921930aacf31039de5441f75b10a208c90229341082ed7aab0cec565214932cab0ace056af5e501dc2e9159b11981bd9732f59adc1eac1ad0144921c6671d4ba3603634e60106d1633d2a07405c75ebc87cc3846ac937b73d0883039f8928d9b7518d6a7e8992c0cb9a3f56290709b932fca7d0681c5f31272512a276815752d1b37e1db20fe3a798ec4ce465b2c366c1fb65b1fc9a409fe887a135560ed4712059622e81944336b560b126f5662447c08c05b8b81cab66d10304c69a3085994c984208a7a9896976256c114af45cb59cf824009b5cecb853f7d4a2f07924b2f015a1d847f41a05e8a09af2cd18651a17e6b284147986acfea831ae5a348226168689237e35258737494cb72c8343ea8fa9d6b8534e100b0509625b8739fba15bd818b3b6dcb8769ec27d826b57bf49739610098a343586bc66bc93ba2957c8be6bcb931be2ae82c40355245b76e0e4159ff99a1c317850c24785cfb34c46c37820913ffb9b2c0215f69bc1effdb93cd497ef20267497c9645cb9e64223d10a316b410bb45332222438f51521cc6452a576c7314d886ec7b96d1267d4eb427a9c180259a08d0c7607c4b43eaeb9089d23b30e0402b494f600a1c08cab1232553a2c641cde1448607b88ca549f374553428807ab802a90ac74fd25ddcca79d0a30e6aa87957fbc21d6cae3e0a97e1d69446b343e678990f6570cca5bd2ef767ae0b4e4edc4b8beb630a0c1047328aecf4bc29885abb68a2fcac88a3960e1072596ec29af6624d1c282fd6e5345dbcb5d547aabb7bbf99dca837fc149b9379ce87be9c004614a664bf3c04364a867192878a747edec510c385007e8ca2d9a495c3850c974318f7a438b144ae64b95d1ca87c8cd293a4525c0ef79c9bb032281a62121ac155cbcb63f1bfe7321ec73a81ed12967b5917e4667f4dd7820f96b822a50d9e8826a2197df8a5435b1978715295f3c0ce46f6a7bc0b533ce01cfd36ad2fc6c497586b84d5757b3ca487f3aef2376cedd678114948bed3163a2c51f1e2770d7ba592f95734981c5028649af7bcaf767204543c1b055f48fa2ceb60c24c200290f0a1da1b8abf9c44bde1302c041f6b257b45751ba36972935b0531fabd77c258b6a5bd1376acbe5b117373211311a91ea347ec5972c475c317c722be847f757baf54fa61ee1730ab16496dcbc525c948c460b018d512aaa88404945b5c12003d045485f3c613465b32041a2479a99f3117f3409f6bc5326bc2ca7b16215fa99452ec3df87acf4698a8c99129ec964e42775416ba4efb1676907a103a481e02e9c29dd94d44e8cbb95c0b996b2fedc8480c6c22384292eea694389ba029d11a4e734afe9a1954a20f3b4b322cc457c5d039cef83e205c719ed1361eea21832ab415b28cf72c58be0c953fbb62e640093fe177be36ceee6b842f5291e5fa031a5820c52c7fd8517579a98d100ab791645df9152f871855853cc7c329bbaa50a844d576d1db4f667433c00a436ac7461356ab6f19b464898482e95a9cf5a22cac089eb5501b695d0dd69fb2c92993753c3ee6130bbc232257b2c93b1ece3579cce5283561073384c597564db864391abb370191071ad373f33823df5a342da67956c039c02596c11379abd81e8e75273d1114f43510c22670bdfaa475c942b7b12a7d579a1d238e055308b82a622f41a187540fa95320917585344c45e63b09e7414bb1a481ca3049fca01842754622a16ace842632f30a59d555195357d2989d4506cf59c53a6ca21abf500e0652608a200abbe57295bb17d072ab946770c282a8e24787cf02c129f0307ab6c1957b16d646c500d874499314cb1167a1ea57f74455ad652108b239da8215d006c45d106763b455d73a36b8589f277cc4e630648e483ae81413fdd89888c0175ea694cb31a321648ffd16657b57b46efc0b3b5ba9f491776a06c29939aef9e5003e907f82ecb599d15eb1a8ca76975a72074d9d58c4ce6c01e7d43821e10ba0e55cc6c3c4ab1c3317326d5269cb40675d728b54563168e9d640511c8ed6fcbaeff2837b3c677ff507742932616b78eef817e2fb856726662f6a5e1dd31feb86939dd58245a24dfa6b609832c341758a3d3146fa4962a55396fcdb19f76561f2694b26c29155fa3eff073cee39b6c8168097e567843605a2805ae1d47436622958302930702fa644065946bccff28889f35f6bb261a0493c576b308a3940e241989b77a5ec744bf7301ab09069d7e6b495eaa242d5286288602fcb3bfe8686768a2230b03ce72c6840ca4927a07a8f6824170c81ebbb8c0a85895000944b7c576da4768699057350807b462581460155e07cf0d54b0a41a1cb09b30f3c0634751028c6842219518e893635847bab1a0194784c6ad912d0077c44ca9612198496d26510aa43c9b5c7ae553cfba48c619b0dd0f0b0bbdb8b1af3506506c316673b8af58b706083bea47433426589d9acd86a93904c6c4fa7bb9cf7e75886b3d18e12fbe54c4cee11fbf3ea0639101248a8a805624f2f6cb6bc742761419a0dfcdb0b0c3e8323fe7af5ba8505f6059bc33b9add760a3117c9d7dbdc4aa58bcd8139996d7fa11fdb7ddcbc497846dbacc5a31fcf92844feefcda"
+        ),
+    },
+];
+
+/// An ML-KEM encapsulation case, in the shape of the FIPS 203 ACVP "encapDecap" tests of the
+/// "encapsulation" function.
+pub struct KemEncapVector {
+    hpke_kem_id: u16,
+    ek: &'static [u8],
+    m: &'static [u8],
+    c: &'static [u8],
+    k: &'static [u8],
+}
+
+impl KemEncapVector {
+    /// Runs the test vector by the Cal implementation, whose RNG needs to produce [`Self::m()`]
+    /// when encapsulating.
+    ///
+    /// Panics if the algorithm is not supported, the encapsulation key is not accepted, or the
+    /// ciphertext or shared secret differ.
+    pub fn test_with<C: embedded_cal::Cal>(&self, cal: &mut C) {
+        use embedded_cal::{KemAlgorithm, KemProvider};
+
+        let cal = cal.kem();
+
+        let alg = <C::KemProvider as KemProvider>::Algorithm::from_hpke_kem_id(self.hpke_kem_id)
+            .expect("algorithm not supported by CAL");
+        let public = cal
+            .import_publickey_bytes(alg, self.ek)
+            .expect("failed to load public key");
+        let (ciphertext, shared) = cal.encapsulate(&public);
+        assert_eq!(ciphertext.as_ref(), self.c, "ciphertext differs");
+        assert_eq!(
+            cal.raw_secret_bytes(&shared).as_ref(),
+            self.k,
+            "shared secret differs"
+        );
+    }
+
+    /// The randomness that encapsulation draws.
+    pub fn m(&self) -> &'static [u8] {
+        self.m
+    }
+}
+
+// Self-generated ML-KEM-768 encapsulation cases, produced with OpenSSL (passing `m` as its "ikme"
+// parameter) from the seeds SHA-512("embedded-cal ML-KEM-768 encapsulation 1") and "… 2", with
+// `m` = SHA-256("embedded-cal ML-KEM-768 encapsulation 1 m") and "… 2 m".
+pub const INTEROP_MLKEM768_ENCAP: &[KemEncapVector] = &[
+    KemEncapVector {
+        hpke_kem_id: 0x0041,
+        ek: &hex!(
+            "7bc1a538b5130e88c4ac0bba05c5c4920622e809c2e6c00baa2102c636700e7a8d852c749c1a1c4c809e9216ac02a3a37486ca615290d5524ada9abc72e2646b3527ddda207989c9b6c5462c9b379e2c77e6dac649322d5c66b7303b4b0ce44501bcb44b5c44152784cd8a482f859810e7b649f81a6beb4c95b31673a9523b53bcf0b011481923a2563908e691eca3069e51583468604805bb53b16c5de839292b3d1022326f42503cb544ccb68cfdd48e34d2baecf977e2937d9aa3795895afa5d93908fb5cf33c9bd4fa9830a72d8c7c56ede69394a084818206cf0c9724c062d2837dbcc17ee2e981343bb9fc6a15ecc731812897e6f6ba67c0615dfa8e6305572d226840112234cc6faaa72c49c54d43e377d79a440c86a533e629af729c914272fb2836c3c797fb8c5c83c8897dcb336fa88ac4e17d7d281c0a642f645c6ca0d46b75da5fd09b134d260a5400be3b96871a558c16c946a3785878d9541692bd28207b8e874557438759b97f011950874a135006a09dc79a07540f8dfb66a692928c590190e362976a18a3a247eda47c03090abfb1638f115bc29296313c5dad0a908894750ab63d0205ad48a1401f1c7ce389c519078fc2800731a4585c0389400359a2d432763898cd80054afb414ebb4642118ad678938e969df07317436b66a820c65a423a4293a126185a699a5c2bb5b46aa81c4209a99df46e3f5b5a2b704b7230648870110b8471c3d226e04730a4b846705b55cdc3120a9bcdc38a564f7ccc3a821bd3591f6da1c8cf58979783c69c1507f19a04f183bf5810a236f21c780595d7d45b0de08b2eea21ba73af4dfb6bd0ba6024205ef056771d959859907cbd9a8c4e69369da47f2386129eeb82a139a681051bad980271a16b9f3a5e4dd970436ccfb1d775c934c9ed93b5d7c7ac37490e260562ce878cbacc5bc8019f1fc6b6fb578ad9f088d298746b5c1338309f40778b6e803031ec0edc20c2b5d12d9847acf79c24d3e6a8d495adc770076028940ea89188150f59b4013f25a5b8260eac3184744579e6f4277ed49dd7c23a76889f4b037b67633e364bcb19b14448471e0988c930b641ef755ff7c043f2eb2583987f874a6eff148386934b7222bde8904cbbe581d9fb2e8de6ae7e0c126dfa2b32230e9b95b2d4b477d4bc8c3abbcb0d4a05671b638e7160ea2b9c671a2bcd090d19869cc3f43b9c69579aa83309f55c5a409a17b5813782a7dbd40753757a571a53121a72e92827951902cb6641202c5fcdb86fa8482c6b3059688367531a936453a469052c34a8b355b350f1492db6a65706a56dd1253a96a40a60c6604ac49a5db35ee91c33dfa14ef32601f77ab8ba98bc89aba363a27889f834cae9a4854624880645e1d3b3b2256a76f134f0ac7f5d9a1848d540e14c25d673cfe7999625e09afde300e841271858695962b73a00cb8168a56941658293bf319499adbb7fd206bf6c0ac7ef44123532b9169081ac475cc1eb68f5aa48aeb199a1027ade8101c109816625c01790a8a087af8cac7ef0d436e716cd88ccbdc90a32c8487ffaa5b4190925761c7d018a24228a8c34c2a780d30b9699ae90e4ade8b91a3a056a1ac373c499371a55a17123cbe4c7a299887313c2b904919798c70c12a85367575ce980826913a2ee45"
+        ),
+        m: &hex!("2681cf1a2ffe396d90015f9a22b472664ae6d2646cec8c287c9d3047fbd800bb"),
+        c: &hex!(
+            "b3fe25cfd9e3e2b89d0ed818714a2d0608e2b1df2a19ecbdbd88e54b9f789a4df27c9aead12e491a369c9cc3002a821507c1a2dc17c32cb0aaee95aef8da2cba75548c5d4b9d89e55123fd5ffb2e57137d28b9e99142ac81a1e16a75c3c2d7c135a2f0a4388eff53564c286d43abff83ebf614041eed96b340204360b4b3d5494707abce6a9b83bc9d32ac8b3429ac8026837ac11bbc68287beb2f09e856bf29b2ec95a51761721abd0636b7b5c87bfcb22ea1987b561472ef9a959146114e1f52e7915d1bf16f2cdf8610f242acafa6c764a48a1f2dfaf9acd45cb26dd287435290a689bf16ae6ea154b5c6981215c3084f3b6e44e761279e23d34a6f3418c9d227c5d803a6c960821b24ef526f726cd4464bbac287d8fbad00aa89e495c59405b437b3238606646e4e5fda032147a601f404a8155d8347e49b84042106c902bf8361a91fbcf8139c2dc3d1daa53b5d4dce56eb6fed9509178342fd17dab765087154c1bf0515b849cee10f6e2d2b6c2b6856ec7cec1157e0db41f46ceeb29804ea2c0fdcbcadd90512808534571434beb549cfcc373f7b5cfcd23a88cf760ef937c7b07eb3fbc1b615da2222d12bcb4a407080540b8bed9a8822bfe47669834cd17e3169f7b5f3033583fdbcb21b786f4e00c3801570107c3b622885d1101ac558d94e40550da72fc1fbebdba8220693ce4bf0ce9745c364dcc5009403bdf0f3cbc0ffc5104dc33d8dd740febcf102b1fdd0867834b692326ac4ad5200947e236545151ce417c133da6c4e53ad48899beb34df00928bc67b5c76264d7ee78b50075f33b144956bae4dfa534760c22f2958d48aa811379c94f1b51b19d603cd1c840e4fa444f0e3ffc75c7562579352096dc5dae5f432ff4710c9f8841d664deb1feb6cfd786b73adf4c1f1b4bc3504a800c468d7a25b622b6f0ef6a9c2738780991ab13215d6c478e87f5aa027e119c9020b47cdee7bd9834a4f3eb1adff572dc77be08c861cb9c5aeaf70e7873676a753bb5414c45c80fabb8b62ece25af825758c4c6928db090c5d623e8db4cee38a63132c73ffdf8a50f6f3a9be2abbd181ebd786f66e22fb79705c44a21410b9fb1fee43687e789a240347d5d24753b5a3e85408d5fa912864ce192d24661ac24b77478f7837f5f7092939b32ae55c3ec1e04aa752a7ebf9d96b0b030776557a6c3858966b3844f33f49210aaabb360cc102f364cbe7c88705ecddfc9e9637810bfa2084cd2d08044d8be4fdd794a81257082336d35fbcb75a543b0623fb0a6092d16efa1bfe9d73d81d3c22c87532115031ef572c439f0b92cd5d9a149dc3cddc77a45caa017c3c1c1b4b9de0f82bf8896da39e44a0a4b5950efacc57152e23975e6dec1583f0c3d0a04f14ced1ff52cd3f9060f77515fdfc61265fc2239a85bd20158fc2880419d8f140ddfab37719be08e387e44c41a4751868687a6e41fb9e20207a826d02ca0b63ef911f396b8a72cc194c2c62a286f77e5f06c2aeb6676f62b41b325c930714741e8ae86f6f0d"
+        ),
+        k: &hex!("0b395fc681d1fcd9fde3f6515641902e495da1ed237cd8dacc198c8f50b87a53"),
+    },
+    KemEncapVector {
+        hpke_kem_id: 0x0041,
+        ek: &hex!(
+            "95572d5839b072690c94f8cfc0f5ab542549e9c40ad4d779bf2c337833c18d4c63f384cef4ac7d801590911113c06aa57526755746ba4f2ab2b90b9f2994aaeafbc916d27496e31e3abc43b4989893476c97018c0dcc0f9f4a341d740316609ce59616e84832932aa265eb052aa1719abc5ef8a0542da44f1806431d7c6b31c1cec6d7ccb03236a37b44865a76e4c21370a99592a730c1c5730824aed407918d9ccb70eb7a10fb0eb4d6221d6a8ceea41aaec5555f60a7aef05c854620cda785ac4c386dbb53347b6db78b80913aaa76000578d552624834d39aa0be0a92cc6b96e98b1f5a36a8af40c7553106295bc8f5502b1ba18e957957972118575a0eec182f68a8a4cf7c52e89a14948388c8383038718869da8d09826546c9a4ede19776b64ea604030cb3cc9168cddb78ab6d3366031681f3ca52c2fa15e65510dc160ec6c391f5cc753d71c76b8a48444a6b662686ebca35be26a3af07a549f019f9fba265b2bea7e3b1d3b1cf473c8e0fb87319674626334d1e5c576e6190b18336a573abdc226a5317ba2df5466af66e86b67dee956c031041e3712065e407442b701d1acb1ab1ce3c0bbb8435c3f533b697176c2694c26328b7570b522e0260d1e9a0dd7b9b61c0a63a466d647b25473b374d94352d04bd1decbc01b0bce9525e4c5804a8011443f357d830c74b8caf6eba2f7b65188738057dc60d5934688c3aaed6582e7a3a067e705550c8a734920c4893b809e91941b622e651666a1c39f7195707bc8edb92c07b7a200a266a00537f3b1b716464698e012069f757beb54874889305074ebf0670e9450667abb978362447b14ebc8b955e29b3bf777509d8065ad25fb0b34e0797ce1d2831b41acfa69938ba747112f696f684b8a952ac36c6936e39c1535662fe799915d51e9fa4c4cd8b0a02143f09e2a3b3e43bf07b523f087cb149919f484df21b2950052c658a8c50a11f57ea4c9f76686ee9271a61b391ba7a0151b5b1801288d2832e37b3714756a94a111d1a10a8a5a0019556082490dd6a2cae242b4466a8309366800c55a2774c7a890658bc0ce2057939da7cef05595b335dd462c6c4b33ba0343706cbb1d9b110b9678d84b537ecf10cb03a9ae2a69714055dac436645c760ebf7187fc641f7ba1f416b1121a70d93b48a0e765e4321342573b1513589c7860394394e9a5bcb46ba1ccd3ab683239e41005a6f37803ec77c7ff35aeb247aa0e20818ca3da38603eb147de1f25b35a41d94e6bec55649026a8171d4179f284db267cd81844e652012bdc652650a50dddc545e19473f01addf717f89286aca026810255c5c50128568cee8415ab6386d128a2622987548808adf34681a9b9ea5eb2383628b7e52102bf9724ba03733c253e5520d234a6707ec39c71ab2bb204af1b54c81037d1c9cbd371464768817e2fb8aa166c2d8010662a06596b1845ecc49c74bb93fc787e83389a8f714a6f8c0122ab682a58a50093187c2033a39a2cc158233115c608b225a646c62915c9712b76651719e508d1ef9a049857a1dbba50d934b1c9219b32013dae6a02e223648d5031a1bc768da2bdd8837bedc0985ba97828227ff627537e95745867556a667fb094bd95cdbc2ed8286de2031bc690f739069c74fe3c03f042ee63e5d88c63d6360ba"
+        ),
+        m: &hex!("8cc052bbaa3692b0f96da7dbb42d65cebead60f26bedbf2027eaba635b180018"),
+        c: &hex!(
+            "963ff6122f9f737516dbe4f93e8f7a67682b25883f4287cc73a6a68f9b7a051c6d0161487c8f4ec228981daa94a262b8c0ef36996121b3d8626af1b8404b1eeb0141310dd2e656ae99d04d60aae082fd641972dc0d1f62b1c0e275c1ca9a12b61ad5407144996abc17a978edf963f575a901355cada32191e53fbbabe92e47e52be1903eb7a68464fff9ba20eede0b0f11940d075935bee7830b6b45a7bb56e82a495ab7f18a5de0bfab45a5ed0b5dbf84a11383ef3846da1b1c1d5fe9150223eec88b2d99afbf70df9088ca0bdb35d3813a2a754e033a0b5a15ec50f30b10c64751519c3068f8c73ccdb2faeda8b95a59cc69725416da4a293c06f1a7c1698d14a4cf268427e9d575aa1c12af40924393d068c6ffdbe6ee453512510e93039f1fb781a78885284742ec1b849c4b9334c959f7f6b6f7d56e7d7fd9ea566a9d227c0624271dcb46cfadb28e3984606074ad6b473ca0574b3227cee007fe2243d3e50033f2d8c034f76ec764c2a00dc4c96521be929f3f1af5935d377d0ee2a31aa0e5db172a6947fa94789058abc66c63e550c73b38b50ab2ac2d035add6b1322eda7a12d841bc68f99d0f1797cb2d019d6e6a2d5191f24ff895370954aec2bb7f02ca23cc8d8b019080b488a4a9cc65d2ae3d94442d8649aec1d865ae7c23ee4bea0d40f8a0c1d4e390bfa5be60781908c85bed0a07540ea98280b00fe95f64bb708555c67fe7080b38f087c390bab015ed3e45bfd8ba2295c1a1059c00d41c6ec4f8287dfd4997543f9c317660ed54c4f46920d4dc9388aa4e1bb21a09de1f23571ab252386b4cc8284f73221261c037febbdf48e76a58285f4b89deca6610e5c35f5f531c758baf05f431d26e860c467e7a8be62be670a45bcd299059dc1b25ee3765b1f900525b98a0e0528b9284d162bab275f4739f508c9350d85ad2b3858fd7da11f0ec8f4e9a6e7397f77061205fd97ec5e15f83c6ec999c3db3b7f0e16c223c79b1fd1b8becda6f9e6a8dc34570fe1c4e896cb0ee4da35ceac92cc79e444a06c94ecdf6da8a7a4c008621ea04c20bb4b086b477f2dd1033bf30f16423066df3a1d2e51ee434698757724a6382791b0a9f2a5999edcfa90abdeb0d29dbeff3eb920a109505caa7989053c64273c1fbcca51ca7cfcc3fc30fbdddbb4c52f70bc49ffa71eb5fa55dbcf3acd00f4c86754f0a83b61fd588744c052ee902c682fee8c9fb2e141f608c72642d8e7041fb46c6ae72b8b5fe5f7114c9af1870a4e2d0ba2cf1bc0dbedf2deea2825d5af28b3194564ec43fd1edf4e2915f09a26c8e729d1f01dcb86c266c4dfb75a6ec96755e80fb8e95e980b6ce5983ef6df4496f68fe363518f4540cacb807d0322ccce53b15942505df99b917c48d8b5775a17ae9a4522e8734da5eec6a82da916c0d61e8347c778295da65f6568b398cf33f2b3a09c5753445de83e020a24d48491cc42938df56054a760cc1e422c1249f66acbfe7039caf078554e4437a02727ffe9dc62ccb18a57fab448d6572e8ee428"
+        ),
+        k: &hex!("34ade76388a9d5b5a848d676ee97231c71a1593168204b968089c5c4f95eeb09"),
+    },
+];
+
+/// An ML-KEM decapsulation case, in the shape of the FIPS 203 ACVP "encapDecap" tests of the
+/// "decapsulation" function.
+///
+/// Unlike the ACVP cases, this carries the seed `d || z` instead of the expanded decapsulation
+/// key, as that is what the [`KemProvider`][embedded_cal::KemProvider] imports.
+pub struct KemDecapVector {
+    hpke_kem_id: u16,
+    d: &'static [u8],
+    z: &'static [u8],
+    /// Ciphertext, which may have been modified to exercise implicit rejection.
+    c: &'static [u8],
+    k: &'static [u8],
+}
+
+impl KemDecapVector {
+    /// Runs the test vector by the Cal implementation.
+    ///
+    /// Panics if the algorithm is not supported, or decapsulation does not result in the expected
+    /// shared secret.
+    pub fn test_with<C: embedded_cal::Cal>(&self, cal: &mut C) {
+        use embedded_cal::{KemAlgorithm, KemProvider};
+
+        let cal = cal.kem();
+
+        let alg = <C::KemProvider as KemProvider>::Algorithm::from_hpke_kem_id(self.hpke_kem_id)
+            .expect("algorithm not supported by CAL");
+        let mut seed = [0; 64];
+        let seed = &mut seed[..self.d.len() + self.z.len()];
+        seed[..self.d.len()].copy_from_slice(self.d);
+        seed[self.d.len()..].copy_from_slice(self.z);
+        let secret = cal
+            .import_secretkey_bytes(alg, seed)
+            .expect("failed to load secret key")
+            .into();
+        let shared = cal
+            .decapsulate(&secret, self.c)
+            .expect("ciphertext should have the right length");
+        assert_eq!(
+            cal.raw_secret_bytes(&shared).as_ref(),
+            self.k,
+            "shared secret differs"
+        );
+    }
+}
+
+// Self-generated ML-KEM-768 decapsulation cases, produced with OpenSSL from the seed
+// SHA-512("embedded-cal ML-KEM-768 decapsulation"), for a ciphertext encapsulated with
+// `m` = SHA-256("embedded-cal ML-KEM-768 decapsulation m") and modified versions of it, for which
+// the shared secret is the implicit rejection value.
+pub const INTEROP_MLKEM768_DECAP: &[KemDecapVector] = &[
+    // unmodified ciphertext
+    KemDecapVector {
+        hpke_kem_id: 0x0041,
+        d: &hex!("ff11834034fa5118991a5c05da634609f2c3a78037f7cb4485e1c3ba5e90c6ff"),
+        z: &hex!("50a78fd3acb50cd13ba00214ec3591bb713cc3ec8fa57b36d9f6eb677922f1a6"),
+        c: &hex!(
+            "781b5eecd7160b52e86edf231841c21e0f4beca24b4e90af56ee4234c0a663f387d3b2f539bb3652053e853272cc28710844fbe803191d29e42513f38c9f0005f501a7be8fa494d1c4d094b861dfcae07e35f1990922597f4b7bac434e54d20451f7a553d6851a9e348ab19a2bd73b4c0b5cf245b93f75615b2c1bb856a950df6163d8ba2406a8f164af81dc78ec6bfda979ff33f7f90c81d7c9ce41942515ecccbe2fbcbf442b0c0fa01cf32b0c2364ab253b954ee2d960cc202439892ec8ef18f8dacaa842174d4a56fad24e25de9f1fcd71d57ef1db961fe6d399579328e488b83abff6243a6aa5c3519c5590133fbbe7b56dc34fe34b79e3aabf784c30cfe251b40f418f995299cb99b6090b1321dddccd99139970ddce54a8ab19cba85d4b4bc2df008c860dee167ae96759712516840fcc264f5069cb958a9141b525790e9cf93277faf86c3a8e01cf35720839153bc833c0cc6ac39ab18abc8781d5bc19cd5f91e005026e80fe6283ee04e1c6c9599051beef6d2e7a5ae575b4d30d359e62ec15f6d1840ccc274fc6ddea0a26be7faaf9b696ccf601d1d9321f8ee361518778ec03524c1437a1fb914952f542bb22a1499472c728e41babd3e22a96b52bd3b9b74daae2848240a26e92bb9d1356a85533b498b58fda0e51c00ab6ffe0f41d1cf0c9f0fe9e991b06dbabfa5be978c933ab9ca6c1cf9e116057e39b217002bfc0cc77ad42ad71ab21360d6f9b6e8ad3787699795174d305557337276651252176868d6d71617d725a96c3b07f35819e1291ac63dc31fa7d4c70b37535d56fac20eaa6a93f7617a4925cfa7dc05f2fdadf7be3d8aff57dd96b6fa293de1d4ad9cfe887fc6e3831c7994ed7144ad648b304e07a7d0f29af4b6e81fc1e8e84a8f170c7ef2bf8a3156aafc534f2c4c50321ad17088a24a9299efb2922c4cae7d287c3266f6736013f40d6a5533c8dcbfb979268d392b3ef4c63190f1742785113b2adba0307fee2e223a6d957a6bc5ad445a8051edd0c8b209460dad1366a8a4cfdb3311777f3f1004e2ac4983ed7e0d56852fc5c54fbaca1ce9a2498d723e500e3b897f4d84b49cd2c7f6c31a1726699f0b8d9304a1170d7a81647f5dbb211ac99c3e5b69fc87207343fdb86f8a9f889217c4c6981ae3f7a6e8c9b865696393024743e10203fadc89031f0da188193bc2580460e606ca1047b255bbaece025d6b0874d83867b2172b96c992b6c66c499fc073f07bc0c900403b16ba9f8158ca939615916462c2544e5c25dc70708b0a5ee0804ad69798112d5ec601135087a758d948d486447f0d1e7cda139a45c292a5aa52c7b03432c92721085be7d80ef00664180ff7d73da439e77d8a8678876156a505eff8a61e20df01c03b4751113dbbb00561e810bedd3e83aea9ddf2e9787f1968e81989525a6b8556d3f689381ef9a47a37d55a852377ef93224bfc0fc51d615ca3d88a8e54f2245d30c14d9f778f6c09fc3694bfcb19136a7e3bbb1cb36d799c6d7e2db06c8a26fb6ff71e6f8"
+        ),
+        k: &hex!("f20ab69cf455449c169b5662b9eb1eb54ed0ca01028a6b3e7406f51908fc5bc3"),
+    },
+    // first byte (of u) flipped in its lowest bit
+    KemDecapVector {
+        hpke_kem_id: 0x0041,
+        d: &hex!("ff11834034fa5118991a5c05da634609f2c3a78037f7cb4485e1c3ba5e90c6ff"),
+        z: &hex!("50a78fd3acb50cd13ba00214ec3591bb713cc3ec8fa57b36d9f6eb677922f1a6"),
+        c: &hex!(
+            "791b5eecd7160b52e86edf231841c21e0f4beca24b4e90af56ee4234c0a663f387d3b2f539bb3652053e853272cc28710844fbe803191d29e42513f38c9f0005f501a7be8fa494d1c4d094b861dfcae07e35f1990922597f4b7bac434e54d20451f7a553d6851a9e348ab19a2bd73b4c0b5cf245b93f75615b2c1bb856a950df6163d8ba2406a8f164af81dc78ec6bfda979ff33f7f90c81d7c9ce41942515ecccbe2fbcbf442b0c0fa01cf32b0c2364ab253b954ee2d960cc202439892ec8ef18f8dacaa842174d4a56fad24e25de9f1fcd71d57ef1db961fe6d399579328e488b83abff6243a6aa5c3519c5590133fbbe7b56dc34fe34b79e3aabf784c30cfe251b40f418f995299cb99b6090b1321dddccd99139970ddce54a8ab19cba85d4b4bc2df008c860dee167ae96759712516840fcc264f5069cb958a9141b525790e9cf93277faf86c3a8e01cf35720839153bc833c0cc6ac39ab18abc8781d5bc19cd5f91e005026e80fe6283ee04e1c6c9599051beef6d2e7a5ae575b4d30d359e62ec15f6d1840ccc274fc6ddea0a26be7faaf9b696ccf601d1d9321f8ee361518778ec03524c1437a1fb914952f542bb22a1499472c728e41babd3e22a96b52bd3b9b74daae2848240a26e92bb9d1356a85533b498b58fda0e51c00ab6ffe0f41d1cf0c9f0fe9e991b06dbabfa5be978c933ab9ca6c1cf9e116057e39b217002bfc0cc77ad42ad71ab21360d6f9b6e8ad3787699795174d305557337276651252176868d6d71617d725a96c3b07f35819e1291ac63dc31fa7d4c70b37535d56fac20eaa6a93f7617a4925cfa7dc05f2fdadf7be3d8aff57dd96b6fa293de1d4ad9cfe887fc6e3831c7994ed7144ad648b304e07a7d0f29af4b6e81fc1e8e84a8f170c7ef2bf8a3156aafc534f2c4c50321ad17088a24a9299efb2922c4cae7d287c3266f6736013f40d6a5533c8dcbfb979268d392b3ef4c63190f1742785113b2adba0307fee2e223a6d957a6bc5ad445a8051edd0c8b209460dad1366a8a4cfdb3311777f3f1004e2ac4983ed7e0d56852fc5c54fbaca1ce9a2498d723e500e3b897f4d84b49cd2c7f6c31a1726699f0b8d9304a1170d7a81647f5dbb211ac99c3e5b69fc87207343fdb86f8a9f889217c4c6981ae3f7a6e8c9b865696393024743e10203fadc89031f0da188193bc2580460e606ca1047b255bbaece025d6b0874d83867b2172b96c992b6c66c499fc073f07bc0c900403b16ba9f8158ca939615916462c2544e5c25dc70708b0a5ee0804ad69798112d5ec601135087a758d948d486447f0d1e7cda139a45c292a5aa52c7b03432c92721085be7d80ef00664180ff7d73da439e77d8a8678876156a505eff8a61e20df01c03b4751113dbbb00561e810bedd3e83aea9ddf2e9787f1968e81989525a6b8556d3f689381ef9a47a37d55a852377ef93224bfc0fc51d615ca3d88a8e54f2245d30c14d9f778f6c09fc3694bfcb19136a7e3bbb1cb36d799c6d7e2db06c8a26fb6ff71e6f8"
+        ),
+        k: &hex!("02a216164cf1a3681d7fe3c1b0aaa3d889413782b49362d25679e96732a396e4"),
+    },
+    // last byte (of v) flipped in its highest bit
+    KemDecapVector {
+        hpke_kem_id: 0x0041,
+        d: &hex!("ff11834034fa5118991a5c05da634609f2c3a78037f7cb4485e1c3ba5e90c6ff"),
+        z: &hex!("50a78fd3acb50cd13ba00214ec3591bb713cc3ec8fa57b36d9f6eb677922f1a6"),
+        c: &hex!(
+            "781b5eecd7160b52e86edf231841c21e0f4beca24b4e90af56ee4234c0a663f387d3b2f539bb3652053e853272cc28710844fbe803191d29e42513f38c9f0005f501a7be8fa494d1c4d094b861dfcae07e35f1990922597f4b7bac434e54d20451f7a553d6851a9e348ab19a2bd73b4c0b5cf245b93f75615b2c1bb856a950df6163d8ba2406a8f164af81dc78ec6bfda979ff33f7f90c81d7c9ce41942515ecccbe2fbcbf442b0c0fa01cf32b0c2364ab253b954ee2d960cc202439892ec8ef18f8dacaa842174d4a56fad24e25de9f1fcd71d57ef1db961fe6d399579328e488b83abff6243a6aa5c3519c5590133fbbe7b56dc34fe34b79e3aabf784c30cfe251b40f418f995299cb99b6090b1321dddccd99139970ddce54a8ab19cba85d4b4bc2df008c860dee167ae96759712516840fcc264f5069cb958a9141b525790e9cf93277faf86c3a8e01cf35720839153bc833c0cc6ac39ab18abc8781d5bc19cd5f91e005026e80fe6283ee04e1c6c9599051beef6d2e7a5ae575b4d30d359e62ec15f6d1840ccc274fc6ddea0a26be7faaf9b696ccf601d1d9321f8ee361518778ec03524c1437a1fb914952f542bb22a1499472c728e41babd3e22a96b52bd3b9b74daae2848240a26e92bb9d1356a85533b498b58fda0e51c00ab6ffe0f41d1cf0c9f0fe9e991b06dbabfa5be978c933ab9ca6c1cf9e116057e39b217002bfc0cc77ad42ad71ab21360d6f9b6e8ad3787699795174d305557337276651252176868d6d71617d725a96c3b07f35819e1291ac63dc31fa7d4c70b37535d56fac20eaa6a93f7617a4925cfa7dc05f2fdadf7be3d8aff57dd96b6fa293de1d4ad9cfe887fc6e3831c7994ed7144ad648b304e07a7d0f29af4b6e81fc1e8e84a8f170c7ef2bf8a3156aafc534f2c4c50321ad17088a24a9299efb2922c4cae7d287c3266f6736013f40d6a5533c8dcbfb979268d392b3ef4c63190f1742785113b2adba0307fee2e223a6d957a6bc5ad445a8051edd0c8b209460dad1366a8a4cfdb3311777f3f1004e2ac4983ed7e0d56852fc5c54fbaca1ce9a2498d723e500e3b897f4d84b49cd2c7f6c31a1726699f0b8d9304a1170d7a81647f5dbb211ac99c3e5b69fc87207343fdb86f8a9f889217c4c6981ae3f7a6e8c9b865696393024743e10203fadc89031f0da188193bc2580460e606ca1047b255bbaece025d6b0874d83867b2172b96c992b6c66c499fc073f07bc0c900403b16ba9f8158ca939615916462c2544e5c25dc70708b0a5ee0804ad69798112d5ec601135087a758d948d486447f0d1e7cda139a45c292a5aa52c7b03432c92721085be7d80ef00664180ff7d73da439e77d8a8678876156a505eff8a61e20df01c03b4751113dbbb00561e810bedd3e83aea9ddf2e9787f1968e81989525a6b8556d3f689381ef9a47a37d55a852377ef93224bfc0fc51d615ca3d88a8e54f2245d30c14d9f778f6c09fc3694bfcb19136a7e3bbb1cb36d799c6d7e2db06c8a26fb6ff71e678"
+        ),
+        k: &hex!("4ddb51cb4d8cc71fe8c62b736881be10692c8032c53dfe7f110553078744306c"),
+    },
+    // first byte of v (byte 960) inverted
+    KemDecapVector {
+        hpke_kem_id: 0x0041,
+        d: &hex!("ff11834034fa5118991a5c05da634609f2c3a78037f7cb4485e1c3ba5e90c6ff"),
+        z: &hex!("50a78fd3acb50cd13ba00214ec3591bb713cc3ec8fa57b36d9f6eb677922f1a6"),
+        c: &hex!(
+            "781b5eecd7160b52e86edf231841c21e0f4beca24b4e90af56ee4234c0a663f387d3b2f539bb3652053e853272cc28710844fbe803191d29e42513f38c9f0005f501a7be8fa494d1c4d094b861dfcae07e35f1990922597f4b7bac434e54d20451f7a553d6851a9e348ab19a2bd73b4c0b5cf245b93f75615b2c1bb856a950df6163d8ba2406a8f164af81dc78ec6bfda979ff33f7f90c81d7c9ce41942515ecccbe2fbcbf442b0c0fa01cf32b0c2364ab253b954ee2d960cc202439892ec8ef18f8dacaa842174d4a56fad24e25de9f1fcd71d57ef1db961fe6d399579328e488b83abff6243a6aa5c3519c5590133fbbe7b56dc34fe34b79e3aabf784c30cfe251b40f418f995299cb99b6090b1321dddccd99139970ddce54a8ab19cba85d4b4bc2df008c860dee167ae96759712516840fcc264f5069cb958a9141b525790e9cf93277faf86c3a8e01cf35720839153bc833c0cc6ac39ab18abc8781d5bc19cd5f91e005026e80fe6283ee04e1c6c9599051beef6d2e7a5ae575b4d30d359e62ec15f6d1840ccc274fc6ddea0a26be7faaf9b696ccf601d1d9321f8ee361518778ec03524c1437a1fb914952f542bb22a1499472c728e41babd3e22a96b52bd3b9b74daae2848240a26e92bb9d1356a85533b498b58fda0e51c00ab6ffe0f41d1cf0c9f0fe9e991b06dbabfa5be978c933ab9ca6c1cf9e116057e39b217002bfc0cc77ad42ad71ab21360d6f9b6e8ad3787699795174d305557337276651252176868d6d71617d725a96c3b07f35819e1291ac63dc31fa7d4c70b37535d56fac20eaa6a93f7617a4925cfa7dc05f2fdadf7be3d8aff57dd96b6fa293de1d4ad9cfe887fc6e3831c7994ed7144ad648b304e07a7d0f29af4b6e81fc1e8e84a8f170c7ef2bf8a3156aafc534f2c4c50321ad17088a24a9299efb2922c4cae7d287c3266f6736013f40d6a5533c8dcbfb979268d392b3ef4c63190f1742785113b2adba0307fee2e223a6d957a6bc5ad445a8051edd0c8b209460dad1366a8a4cfdb3311777f3f1004e2ac4983ed7e0d56852fc5c54fbaca1ce9a2498d723e500e3b897f4d84b49cd2c7f6c31a1726699f0b8d9304a1170d7a81647f5dbb211ac99c3e5b69fc87207343fdb86f8a9f889217c4c6981ae3f7a6e8c9b865696393024743e10203fadc89031f0da188193bc2580460e606ca1047b255bbaece025d6b0874d83867b2172b96c992b6c66c499fc073f07bc0c900403b16ba9f8158ca939615916462c2544e5c25dc70708b0a5ee0804ad69798112d5ec601135087a758d948d486447f0d1e7cda139a45c292a5aa52c7b03432c92721085be7d80efff664180ff7d73da439e77d8a8678876156a505eff8a61e20df01c03b4751113dbbb00561e810bedd3e83aea9ddf2e9787f1968e81989525a6b8556d3f689381ef9a47a37d55a852377ef93224bfc0fc51d615ca3d88a8e54f2245d30c14d9f778f6c09fc3694bfcb19136a7e3bbb1cb36d799c6d7e2db06c8a26fb6ff71e6f8"
+        ),
+        k: &hex!("ae864ab958c8e959f4d23445ff20ccf6b93176235c8ab0934349eb42f0798ebc"),
+    },
+];
@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+use hexlit::hex;
+
+pub struct KeyWrapVector {
+    cose_alg: i8,
+    kek: &'static [u8],
+    key_data: &'static [u8],
+    wrapped: &'static [u8],
+}
+
+impl KeyWrapVector {
+    /// Runs the test vector by the Cal implementation.
+    ///
+    /// Panics if the algorithm is not supported, if wrapping or unwrapping does not produce the
+    /// expected output, or if a corrupted wrapped key is not rejected.
+    ///
+    /// Where the key data has the size of an AES-GCM key and the Cal supports that, unwrapping into
+    /// an AEAD key is tested as well.
+    pub fn test_with<C: embedded_cal::Cal>(&self, cal: &mut C) {
+        use embedded_cal::{
+            AeadAlgorithm, AeadProvider, DecryptionFailed, KeyWrapAlgorithm, KeyWrapProvider,
+        };
+
+        let alg =
+            <C::KeyWrapProvider as KeyWrapProvider>::Algorithm::from_cose_number(self.cose_alg)
+                .expect("algorithm not supported by CAL");
+        assert_eq!(alg.key_length(), self.kek.len());
+        let kek = cal.key_wrap().load_from_keydata(alg, self.kek);
+
+        let mut buffer = [0; 40];
+
+        let wrapped = &mut buffer[..self.wrapped.len()];
+        cal.key_wrap().wrap_key(&kek, self.key_data, wrapped);
+        assert_eq!(wrapped, self.wrapped, "wrapped key not as expected");
+
+        let unwrapped = &mut buffer[..self.key_data.len()];
+        cal.key_wrap()
+            .unwrap_key(&kek, self.wrapped, unwrapped)
+            .expect("unwrapping failed");
+        assert_eq!(unwrapped, self.key_data, "unwrapped key not as expected");
+
+        let mut corrupted = [0; 40];
+        let corrupted = &mut corrupted[..self.wrapped.len()];
+        corrupted.copy_from_slice(self.wrapped);
+        corrupted[10] ^= 0x01;
+        assert!(matches!(
+            cal.key_wrap().unwrap_key(&kek, corrupted, unwrapped),
+            Err(DecryptionFailed)
+        ));
+
+        let aes_gcm = match self.key_data.len() {
+            16 => 1,
+            32 => 3,
+            _ => return,
+        };
+        let Some(aead_alg) =
+            <C::AeadProvider as AeadProvider>::Algorithm::from_cose_number(aes_gcm)
+        else {
+            return;
+        };
+        // The key types are opaque, so they are compared by what they produce.
+        let tag_of = |cal: &mut C, key| {
+            let nonce = [0; 12];
+            let tag = cal.aead().encrypt_in_place(&key, &nonce, &mut [], &b""[..]);
+            let mut tag_bytes = [0; 16];
+            tag_bytes.copy_from_slice(tag.as_ref());
+            tag_bytes
+        };
+        let direct = cal
+            .aead()
+            .load_from_keydata(aead_alg.clone(), self.key_data);
+        let unwrapped = cal
+            .unwrap_aead_key(&kek, self.wrapped, aead_alg.clone())
+            .expect("unwrapping failed");
+        assert_eq!(
+            tag_of(cal, unwrapped),
+            tag_of(cal, direct),
+            "key unwrapped into AEAD key does not behave like the key data"
+        );
+        assert!(matches!(
+            cal.unwrap_aead_key(&kek, &self.wrapped[..self.wrapped.len() - 8], aead_alg),
+            Err(DecryptionFailed)
+        ));
+    }
+}
+
+// Examples from RFC3394 Section 4.
+pub const RFC3394: &[KeyWrapVector] = &[
+    // Section 4.1
+    KeyWrapVector {
+        cose_alg: -3,
+        kek: &hex!("000102030405060708090a0b0c0d0e0f"),
+        key_data: &hex!("00112233445566778899aabbccddeeff"),
+        wrapped: &hex!("1fa68b0a8112b447aef34bd8fb5a7b829d3e862371d2cfe5"),
+    },
+    // Section 4.2
+    KeyWrapVector {
+        cose_alg: -4,
+        kek: &hex!("000102030405060708090a0b0c0d0e0f1011121314151617"),
+        key_data: &hex!("00112233445566778899aabbccddeeff"),
+        wrapped: &hex!("96778b25ae6ca435f92b5b97c050aed2468ab8a17ad84e5d"),
+    },
+    // Section 4.3
+    KeyWrapVector {
+        cose_alg: -5,
+        kek: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        key_data: &hex!("00112233445566778899aabbccddeeff"),
+        wrapped: &hex!("64e8c3f9ce0f5ba263e9777905818a2a93c8191e7d6e8ae7"),
+    },
+    // Section 4.4
+    KeyWrapVector {
+        cose_alg: -4,
+        kek: &hex!("000102030405060708090a0b0c0d0e0f1011121314151617"),
+        key_data: &hex!("00112233445566778899aabbccddeeff0001020304050607"),
+        wrapped: &hex!("031d33264e15d33268f24ec260743edce1c6c7ddee725a936ba814915c6762d2"),
+    },
+    // Section 4.5
+    KeyWrapVector {
+        cose_alg: -5,
+        kek: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        key_data: &hex!("00112233445566778899aabbccddeeff0001020304050607"),
+        wrapped: &hex!("a8f9bc1612c68b3ff6e6f4fbe30e71e4769c8b80a32cb8958cd5d17d6b254da1"),
+    },
+    // Section 4.6
+    KeyWrapVector {
+        cose_alg: -5,
+        kek: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        key_data: &hex!("00112233445566778899aabbccddeeff000102030405060708090a0b0c0d0e0f"),
+        wrapped: &hex!(
+            "28c9f404c4b810f4cbccb35cfb87f8263f5786e2d80ed326cbc7f0e71a99f43bfb988b9b7a02dd21"
+        ),
+    },
+];
@@ -4,7 +4,17 @@
 
 use hexlit::hex;
 
+pub mod algorithms;
+pub mod cose_key;
+pub mod der;
 pub mod dh;
+pub mod ecc;
+pub mod hpke;
+pub mod kem;
+pub mod key_wrap;
+pub mod mac;
+pub mod signature;
+pub mod zeroize;
 
 pub const SHA256HASHES: &[(&[u8], [u8; 32])] = &[
     (
@@ -49,6 +59,230 @@ pub const SHA256HASHES: &[(&[u8], [u8; 32])] = &[
     ),
 ];
 
+/// SHA-384 test vectors: The one- and two-block examples of FIPS 180-2 Appendix D, followed by
+/// inputs around the padding boundaries (computed with Python's `hashlib`).
+pub const SHA384HASHES: &[(&[u8], [u8; 48])] = &[
+    (
+        b"",
+        hex!(
+            "38b060a751ac96384cd9327eb1b1e36a21fdb71114be07434c0cc7bf63f6e1da274edebfe76f65fbd51ad2f14898b95b"
+        ),
+    ),
+    (
+        b"abc",
+        hex!(
+            "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7"
+        ),
+    ),
+    (
+        b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+        hex!(
+            "09330c33f71147e83d192fc782cd1b4753111b173b3b05d22fa08086e3b0f712fcc7c71a557e2db966c3e9fa91746039"
+        ),
+    ),
+    (
+        &[0x61; 111],
+        hex!(
+            "3c37955051cb5c3026f94d551d5b5e2ac38d572ae4e07172085fed81f8466b8f90dc23a8ffcdea0b8d8e58e8fdacc80a"
+        ),
+    ),
+    (
+        &[0x61; 112],
+        hex!(
+            "187d4e07cb306103c69967bf544d0dfbe9042577599c73c330abc0cb64c61236d5ed565ee19119d8c31779a38f791fcd"
+        ),
+    ),
+    (
+        &[0x61; 128],
+        hex!(
+            "edb12730a366098b3b2beac75a3bef1b0969b15c48e2163c23d96994f8d1bef760c7e27f3c464d3829f56c0d53808b0b"
+        ),
+    ),
+    (
+        &[0x61; 239],
+        hex!(
+            "e247c35f4bc1aa38026f8880c8c97305545d00d3f859e00c57d1c1f0a176b3c6b749c4eb081f08bd0fba500969cd056a"
+        ),
+    ),
+];
+
+/// SHA-512 test vectors: The one- and two-block examples of FIPS 180-2 Appendix C, followed by
+/// inputs around the padding boundaries (computed with Python's `hashlib`).
+pub const SHA512HASHES: &[(&[u8], [u8; 64])] = &[
+    (
+        b"",
+        hex!(
+            "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e"
+        ),
+    ),
+    (
+        b"abc",
+        hex!(
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+        ),
+    ),
+    (
+        b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+        hex!(
+            "8e959b75dae313da8cf4f72814fc143f8f7779c6eb9f7fa17299aeadb6889018501d289e4900f7e4331b99dec4b5433ac7d329eeb6dd26545e96e55b874be909"
+        ),
+    ),
+    (
+        &[0x61; 111],
+        hex!(
+            "fa9121c7b32b9e01733d034cfc78cbf67f926c7ed83e82200ef86818196921760b4beff48404df811b953828274461673c68d04e297b0eb7b2b4d60fc6b566a2"
+        ),
+    ),
+    (
+        &[0x61; 112],
+        hex!(
+            "c01d080efd492776a1c43bd23dd99d0a2e626d481e16782e75d54c2503b5dc32bd05f0f1ba33e568b88fd2d970929b719ecbb152f58f130a407c8830604b70ca"
+        ),
+    ),
+    (
+        &[0x61; 128],
+        hex!(
+            "b73d1929aa615934e61a871596b3f3b33359f42b8175602e89f7e06e5f658a243667807ed300314b95cacdd579f3e33abdfbe351909519a846d465c59582f321"
+        ),
+    ),
+    (
+        &[0x61; 239],
+        hex!(
+            "52c853cb8d907f3d4d6b889beb027985d7c273486d75f8baf26f80d24e90c74c6c3de3e22131582380a7d14d43f2941a31385439cd6ddc469f628015e50bf286"
+        ),
+    ),
+];
+
+/// SHA3-256 test vectors: The empty and 1600 bit messages of NIST's "Examples with Intermediate
+/// Values", and the "abc" and 448 bit messages familiar from FIPS 180 (computed with Python's
+/// `hashlib`).
+pub const SHA3_256HASHES: &[(&[u8], [u8; 32])] = &[
+    (
+        b"",
+        hex!("a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a"),
+    ),
+    (
+        b"abc",
+        hex!("3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"),
+    ),
+    (
+        b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+        hex!("41c0dba2a9d6240849100376a8235e2c82e1b9998a999e21db32dd97496d3376"),
+    ),
+    (
+        &[0xa3; 200],
+        hex!("79f38adec5c20307a98ef76e8324afbfd46cfd81b22e3973c65fa1bd9de31787"),
+    ),
+];
+
+/// SHA3-384 test vectors: The empty and 1600 bit messages of NIST's "Examples with Intermediate
+/// Values", and the "abc" and 448 bit messages familiar from FIPS 180 (computed with Python's
+/// `hashlib`).
+pub const SHA3_384HASHES: &[(&[u8], [u8; 48])] = &[
+    (
+        b"",
+        hex!(
+            "0c63a75b845e4f7d01107d852e4c2485c51a50aaaa94fc61995e71bbee983a2ac3713831264adb47fb6bd1e058d5f004"
+        ),
+    ),
+    (
+        b"abc",
+        hex!(
+            "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b298d88cea927ac7f539f1edf228376d25"
+        ),
+    ),
+    (
+        b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+        hex!(
+            "991c665755eb3a4b6bbdfb75c78a492e8c56a22c5c4d7e429bfdbc32b9d4ad5aa04a1f076e62fea19eef51acd0657c22"
+        ),
+    ),
+    (
+        &[0xa3; 200],
+        hex!(
+            "1881de2ca7e41ef95dc4732b8f5f002b189cc1e42b74168ed1732649ce1dbcdd76197a31fd55ee989f2d7050dd473e8f"
+        ),
+    ),
+];
+
+/// SHA3-512 test vectors: The empty and 1600 bit messages of NIST's "Examples with Intermediate
+/// Values", and the "abc" and 448 bit messages familiar from FIPS 180 (computed with Python's
+/// `hashlib`).
+pub const SHA3_512HASHES: &[(&[u8], [u8; 64])] = &[
+    (
+        b"",
+        hex!(
+            "a69f73cca23a9ac5c8b567dc185a756e97c982164fe25859e0d1dcc1475c80a615b2123af1f5f94c11e3e9402c3ac558f500199d95b6d3e301758586281dcd26"
+        ),
+    ),
+    (
+        b"abc",
+        hex!(
+            "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0"
+        ),
+    ),
+    (
+        b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+        hex!(
+            "04a371e84ecfb5b8b77cb48610fca8182dd457ce6f326a0fd3d7ec2f1e91636dee691fbe0c985302ba1b0d8dc78c086346b533b49c030d99a27daf1139d6e75e"
+        ),
+    ),
+    (
+        &[0xa3; 200],
+        hex!(
+            "e76dfad22084a8b1467fcf2ffa58361bec7628edf5f3fdc0e4805dc48caeeca81b7c13c30adf52a3659584739a2df46be589c51ca1a4a8416df6545a1ce8ba00"
+        ),
+    ),
+];
+
+/// SHAKE128 test vectors with 256 bytes of output each: The empty and 1600 bit messages of NIST's
+/// "Examples with Intermediate Values" (truncated from 4096 bits of output), and an "abc" input
+/// (computed with Python's `hashlib`).
+pub const SHAKE128OUTPUTS: &[(&[u8], [u8; 256])] = &[
+    (
+        b"",
+        hex!(
+            "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef263cb1eea988004b93103cfb0aeefd2a686e01fa4a58e8a3639ca8a1e3f9ae57e235b8cc873c23dc62b8d260169afa2f75ab916a58d974918835d25e6a435085b2badfd6dfaac359a5efbb7bcc4b59d538df9a04302e10c8bc1cbf1a0b3a5120ea17cda7cfad765f5623474d368ccca8af0007cd9f5e4c849f167a580b14aabdefaee7eef47cb0fca9767be1fda69419dfb927e9df07348b196691abaeb580b32def58538b8d23f87732ea63b02b4fa0f4873360e2841928cd60dd4cee8cc0d4c922a96188d032675c8ac850933c7aff1533b94c834adbb69c6115bad4692d8619"
+        ),
+    ),
+    (
+        b"abc",
+        hex!(
+            "5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc844c50af32acd3f2cdd066568706f509bc1bdde58295dae3f891a9a0fca5783789a41f8611214ce612394df286a62d1a2252aa94db9c538956c717dc2bed4f232a0294c857c730aa16067ac1062f1201fb0d377cfb9cde4c63599b27f3462bba4a0ed296c801f9ff7f57302bb3076ee145f97a32ae68e76ab66c48d51675bd49acc29082f5647584e6aa01b3f5af057805f973ff8ecb8b226ac32ada6f01c1fcd4818cb006aa5b4cdb3611eb1e533c8964cacfdf31012cd3fb744d02225b988b475375faad996eb1b9176ecb0f8b2871723d6dbb804e23357e50732f5cfc904b1"
+        ),
+    ),
+    (
+        &[0xa3; 200],
+        hex!(
+            "131ab8d2b594946b9c81333f9bb6e0ce75c3b93104fa3469d3917457385da037cf232ef7164a6d1eb448c8908186ad852d3f85a5cf28da1ab6fe3438171978467f1c05d58c7ef38c284c41f6c2221a76f12ab1c04082660250802294fb87180213fdef5b0ecb7df50ca1f8555be14d32e10f6edcde892c09424b29f597afc270c904556bfcb47a7d40778d390923642b3cbd0579e60908d5a000c1d08b98ef933f806445bf87f8b009ba9e94f7266122ed7ac24e5e266c42a82fa1bbefb7b8db0066e16a85e0493f07df4809aec084a593748ac3dde5a6d7aae1e8b6e5352b2d71efbb47d4caeed5e6d633805d2d323e6fd81b4684b93a2677d45e7421c2c6ae"
+        ),
+    ),
+];
+
+/// SHAKE256 test vectors with 256 bytes of output each: The empty and 1600 bit messages of NIST's
+/// "Examples with Intermediate Values" (truncated from 4096 bits of output), and an "abc" input
+/// (computed with Python's `hashlib`).
+pub const SHAKE256OUTPUTS: &[(&[u8], [u8; 256])] = &[
+    (
+        b"",
+        hex!(
+            "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762fd75dc4ddd8c0f200cb05019d67b592f6fc821c49479ab48640292eacb3b7c4be141e96616fb13957692cc7edd0b45ae3dc07223c8e92937bef84bc0eab862853349ec75546f58fb7c2775c38462c5010d846c185c15111e595522a6bcd16cf86f3d122109e3b1fdd943b6aec468a2d621a7c06c6a957c62b54dafc3be87567d677231395f6147293b68ceab7a9e0c58d864e8efde4e1b9a46cbe854713672f5caaae314ed9083dab4b099f8e300f01b8650f1f4b1d8fcf3f3cb53fb8e9eb2ea203bdc970f50ae55428a91f7f53ac266b28419c3778a15fd248d339ede785fb7f"
+        ),
+    ),
+    (
+        b"abc",
+        hex!(
+            "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739d5a15bef186a5386c75744c0527e1faa9f8726e462a12a4feb06bd8801e751e41385141204f329979fd3047a13c5657724ada64d2470157b3cdc288620944d78dbcddbd912993f0913f164fb2ce95131a2d09a3e6d51cbfc622720d7a75c6334e8a2d7ec71a7cc29cf0ea610eeff1a588290a53000faa79932becec0bd3cd0b33a7e5d397fed1ada9442b99903f4dcfd8559ed3950faf40fe6f3b5d710ed3b677513771af6bfe11934817e8762d9896ba579d88d84ba7aa3cdc7055f6796f195bd9ae788f2f5bb96100d6bbaff7fbc6eea24d4449a2477d172a5507dcc931412"
+        ),
+    ),
+    (
+        &[0xa3; 200],
+        hex!(
+            "cd8a920ed141aa0407a22d59288652e9d9f1a7ee0c1e7c1ca699424da84a904d2d700caae7396ece96604440577da4f3aa22aeb8857f961c4cd8e06f0ae6610b1048a7f64e1074cd629e85ad7566048efc4fb500b486a3309a8f26724c0ed628001a1099422468de726f1061d99eb9e93604d5aa7467d4b1bd6484582a384317d7f47d750b8f5499512bb85a226c4243556e696f6bd072c5aa2d9b69730244b56853d16970ad817e213e470618178001c9fb56c54fefa5fee67d2da524bb3b0b61ef0e9114a92cdbb6cccb98615cfe76e3510dd88d1cc28ff99287512f24bfafa1a76877b6f37198e3a641c68a7c42d45fa7acc10dae5f3cefb7b735f12d4e58"
+        ),
+    ),
+];
+
 /// HMAC-SHA256 test vectors from RFC 4231, test cases 1–4, 6–7.
 ///
 /// Each entry is `(key, data, expected_mac)`.
@@ -213,20 +447,34 @@ where
         .expect("HkdfProvider must recognize COSE 5 (HMAC-SHA-256)");
 
     for (salt, ikm, info, expected_prk, expected_okm) in HKDF_SHA256 {
-        let prk = cal
-            .hkdf_extract(alg.clone(), *salt, ikm)
+        let exposed_prk = cal
+            .hkdf_extract_exposed(alg.clone(), *salt, ikm)
             .expect("HKDF-Extract failed");
         assert_eq!(
-            prk.as_ref(),
+            exposed_prk.as_ref(),
             expected_prk.as_ref(),
             "HKDF-Extract PRK mismatch"
         );
 
+        let prk = cal
+            .hkdf_extract(alg.clone(), *salt, ikm)
+            .expect("HKDF-Extract failed");
         let mut okm = [0u8; 82]; // large enough for test case 2 (82 bytes)
         let okm = &mut okm[..expected_okm.len()];
-        cal.hkdf_expand(alg.clone(), prk.as_ref(), info, okm)
+        cal.hkdf_expand(alg.clone(), &prk, info, okm)
             .expect("HKDF-Expand failed");
         assert_eq!(okm, *expected_okm, "HKDF-Expand OKM mismatch");
+
+        // Expanding from a loaded PRK gives the same result.
+        let loaded_prk = cal.load_prk(alg.clone(), expected_prk);
+        let mut okm_loaded = [0u8; 82];
+        let okm_loaded = &mut okm_loaded[..expected_okm.len()];
+        cal.hkdf_expand(alg.clone(), &loaded_prk, info, okm_loaded)
+            .expect("HKDF-Expand failed");
+        assert_eq!(
+            okm_loaded, *expected_okm,
+            "HKDF-Expand OKM mismatch on loaded PRK"
+        );
         // Also test the combined hkdf() method using test case 1 only (salt is Some).
         if salt.is_some() {
             let mut okm2 = [0u8; 82];
@@ -325,6 +573,45 @@ pub fn test_hmac_sha256<Cal: embedded_cal::HmacProvider>(cal: &mut Cal) {
             tv_mac,
             "HMAC values mismatch when input is fed in chunks"
         );
+
+        // HMAC 256/64 uses the leading 8 bytes.
+        let truncated = &tv_mac[..8];
+        let mut flipped = [0; 32];
+        flipped.copy_from_slice(tv_mac);
+        flipped[flipped.len() - 1] ^= 1;
+        let mut overlong = [0; 33];
+        overlong[..32].copy_from_slice(tv_mac);
+
+        for (expected, valid, what) in [
+            (&tv_mac[..], true, "full tag"),
+            (truncated, true, "truncated tag"),
+            (&flipped[..], false, "tampered tag"),
+            (
+                &flipped[..8],
+                true,
+                "tag truncated before the tampered byte",
+            ),
+            (&tv_mac[..7], false, "tag shorter than the minimum"),
+            (&tv_mac[..0], false, "empty tag"),
+            (&overlong[..], false, "tag longer than the output"),
+        ] {
+            assert_eq!(
+                cal.verify_with_keydata(hmac_sha256.clone(), tv_key, tv_data, expected)
+                    .is_ok(),
+                valid,
+                "HMAC verification of {what} gave the wrong result"
+            );
+        }
+
+        let mut state = cal.init_with_keydata(hmac_sha256.clone(), tv_key);
+        cal.update(&mut state, tv_data);
+        let mut tampered_truncated = [0; 8];
+        tampered_truncated.copy_from_slice(truncated);
+        tampered_truncated[0] ^= 0x80;
+        assert!(
+            cal.verify(state, &tampered_truncated).is_err(),
+            "HMAC verification accepted a tampered truncated tag"
+        );
     }
 }
 
@@ -332,19 +619,123 @@ pub fn test_hash_algorithm_sha256<Cal: embedded_cal::HashProvider>(cal: &mut Cal
     // Equivalence with other constructors can be handled via
     // embedded_cal::test_hash_algorithm_sha256 (or should we move this in here?)
 
+    use embedded_cal::HashAlgorithm;
+
     // If this test is run on a concrete type, we expect it to provide the algorithm.
     let sha256 = Cal::Algorithm::from_ni_id(1).unwrap();
 
+    test_hash_vectors(cal, sha256, SHA256HASHES);
+}
+
+pub fn test_hash_algorithm_sha384<Cal: embedded_cal::HashProvider>(cal: &mut Cal) {
     use embedded_cal::HashAlgorithm;
 
-    for (tv_data, tv_result) in SHA256HASHES {
+    let sha384 = Cal::Algorithm::from_ni_id(7).unwrap();
+
+    test_hash_vectors(cal, sha384, SHA384HASHES);
+}
+
+pub fn test_hash_algorithm_sha512<Cal: embedded_cal::HashProvider>(cal: &mut Cal) {
+    use embedded_cal::HashAlgorithm;
+
+    let sha512 = Cal::Algorithm::from_ni_id(8).unwrap();
+
+    test_hash_vectors(cal, sha512, SHA512HASHES);
+}
+
+pub fn test_hash_algorithm_sha3_256<Cal: embedded_cal::HashProvider>(cal: &mut Cal) {
+    use embedded_cal::HashAlgorithm;
+
+    let sha3_256 = Cal::Algorithm::from_ni_id(10).unwrap();
+
+    test_hash_vectors(cal, sha3_256, SHA3_256HASHES);
+}
+
+pub fn test_hash_algorithm_sha3_384<Cal: embedded_cal::HashProvider>(cal: &mut Cal) {
+    use embedded_cal::HashAlgorithm;
+
+    let sha3_384 = Cal::Algorithm::from_ni_id(11).unwrap();
+
+    test_hash_vectors(cal, sha3_384, SHA3_384HASHES);
+}
+
+pub fn test_hash_algorithm_sha3_512<Cal: embedded_cal::HashProvider>(cal: &mut Cal) {
+    use embedded_cal::HashAlgorithm;
+
+    let sha3_512 = Cal::Algorithm::from_ni_id(12).unwrap();
+
+    test_hash_vectors(cal, sha3_512, SHA3_512HASHES);
+}
+
+/// Tests SHAKE128 both as a fixed-length hash (as selected through COSE) and as an XOF.
+pub fn test_hash_algorithm_shake128<Cal: embedded_cal::HashProvider>(cal: &mut Cal) {
+    use embedded_cal::HashAlgorithm;
+
+    let shake128 = Cal::Algorithm::from_cose_number(-18).unwrap();
+
+    test_xof_vectors(cal, shake128, SHAKE128OUTPUTS);
+}
+
+/// Tests SHAKE256 both as a fixed-length hash (as selected through COSE) and as an XOF.
+pub fn test_hash_algorithm_shake256<Cal: embedded_cal::HashProvider>(cal: &mut Cal) {
+    use embedded_cal::HashAlgorithm;
+
+    let shake256 = Cal::Algorithm::from_cose_number(-45).unwrap();
+
+    test_xof_vectors(cal, shake256, SHAKE256OUTPUTS);
+}
+
+fn test_xof_vectors<Cal: embedded_cal::HashProvider, const N: usize>(
+    cal: &mut Cal,
+    alg: Cal::Algorithm,
+    vectors: &[(&[u8], [u8; N])],
+) {
+    use embedded_cal::HashAlgorithm;
+
+    for (tv_data, tv_output) in vectors {
+        // The fixed-length output is a prefix of the XOF output.
+        assert_eq!(
+            cal.hash(alg.clone(), tv_data).as_ref(),
+            &tv_output[..alg.len()],
+            "Hash values mismatch"
+        );
+
+        let mut hash = cal.init(alg.clone());
+        cal.update(&mut hash, tv_data);
+        let Ok(mut reader) = cal.finalize_xof(hash) else {
+            panic!("XOF not supported");
+        };
+
+        // Squeezing in odd chunks that cross the SHAKE rates of 136 and 168 bytes.
+        let mut output = [0; N];
+        let mut chunks = [1, 30, 150].iter().cycle();
+        let mut written = 0;
+        while written < N {
+            let end = (written + chunks.next().unwrap()).min(N);
+            cal.squeeze(&mut reader, &mut output[written..end]);
+            written = end;
+        }
+        assert_eq!(&output, tv_output, "XOF output mismatch");
+    }
+}
+
+fn test_hash_vectors<Cal: embedded_cal::HashProvider, const N: usize>(
+    cal: &mut Cal,
+    alg: Cal::Algorithm,
+    vectors: &[(&[u8], [u8; N])],
+) {
+    use embedded_cal::HashAlgorithm;
+
+    assert_eq!(alg.len(), N, "Hash length mismatch");
+
+    for (tv_data, tv_result) in vectors {
         assert_eq!(
-            cal.hash(sha256.clone(), tv_data).as_ref(),
+            cal.hash(alg.clone(), tv_data).as_ref(),
             tv_result,
             "Hash values mismatch"
         );
 
-        let mut hash = cal.init(sha256.clone());
+        let mut hash = cal.init(alg.clone());
         let mid = tv_data.len() / 2;
         let postmid = mid + 1;
         if tv_data.len() < postmid {
@@ -361,6 +752,36 @@ pub fn test_hash_algorithm_sha256<Cal: embedded_cal::HashProvider>(cal: &mut Cal
     }
 }
 
+/// AES-GCM test cases from "The Galois/Counter Mode of Operation (GCM)" by McGrew and Viega
+/// (test cases 4 and 16), as used in NIST's GCM validation.
+pub const AES_GCM_128: &[AeadCase] = &[AeadCase {
+    alg_cose: 1,
+    key: &hex!("feffe9928665731c6d6a8f9467308308"),
+    nonce: &hex!("cafebabefacedbaddecaf888"),
+    aad: &hex!("feedfacedeadbeeffeedfacedeadbeefabaddad2"),
+    plaintext: &hex!(
+        "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a721c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39"
+    ),
+    ciphertext: &hex!(
+        "42831ec2217774244b7221b784d0d49ce3aa212f2c02a4e035c17e2329aca12e21d514b25466931c7d8f6a5aac84aa051ba30b396a0aac973d58e091"
+    ),
+    tag: &hex!("5bc94fbc3221a5db94fae95ae7121a47"),
+}];
+
+pub const AES_GCM_256: &[AeadCase] = &[AeadCase {
+    alg_cose: 3,
+    key: &hex!("feffe9928665731c6d6a8f9467308308feffe9928665731c6d6a8f9467308308"),
+    nonce: &hex!("cafebabefacedbaddecaf888"),
+    aad: &hex!("feedfacedeadbeeffeedfacedeadbeefabaddad2"),
+    plaintext: &hex!(
+        "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a721c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39"
+    ),
+    ciphertext: &hex!(
+        "522dc1f099567d07f47f37a32a84427d643a8cdcbfe5c0c97598a2bd2555d1aa8cb08e48590dbb3da7b08b1056828838c5f61e6393ba7a0abcc9f662"
+    ),
+    tag: &hex!("76fc6ece0f4e1768cddf8853bb2d551b"),
+}];
+
 pub struct AeadCase {
     alg_cose: i16,
     key: &'static [u8],
@@ -422,3 +843,82 @@ pub fn test_aead_aesccm_16_64_256(cal: &mut impl embedded_cal::AeadProvider) {
         case.test(cal);
     }
 }
+
+pub fn test_aead_aesgcm_128(cal: &mut impl embedded_cal::AeadProvider) {
+    for case in AES_GCM_128 {
+        case.test(cal);
+    }
+}
+
+pub fn test_aead_aesgcm_256(cal: &mut impl embedded_cal::AeadProvider) {
+    for case in AES_GCM_256 {
+        case.test(cal);
+    }
+}
+
+// Examples from Appendices B and C of FIPS 197
+// <https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.197-upd1.pdf>
+pub const FIPS197_AES: &[BlockCipherCase] = &[
+    BlockCipherCase {
+        key: &hex!("2b7e151628aed2a6abf7158809cf4f3c"),
+        plaintext: hex!("3243f6a8885a308d313198a2e0370734"),
+        ciphertext: hex!("3925841d02dc09fbdc118597196a0b32"),
+    },
+    BlockCipherCase {
+        key: &hex!("000102030405060708090a0b0c0d0e0f"),
+        plaintext: hex!("00112233445566778899aabbccddeeff"),
+        ciphertext: hex!("69c4e0d86a7b0430d8cdb78070b4c55a"),
+    },
+    BlockCipherCase {
+        key: &hex!("000102030405060708090a0b0c0d0e0f1011121314151617"),
+        plaintext: hex!("00112233445566778899aabbccddeeff"),
+        ciphertext: hex!("dda97ca4864cdfe06eaf70a0ec0d7191"),
+    },
+    BlockCipherCase {
+        key: &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        plaintext: hex!("00112233445566778899aabbccddeeff"),
+        ciphertext: hex!("8ea2b7ca516745bfeafc49904b496089"),
+    },
+];
+
+pub struct BlockCipherCase {
+    // The AES variant is selected by the key length
+    key: &'static [u8],
+    plaintext: [u8; 16],
+    ciphertext: [u8; 16],
+}
+
+impl BlockCipherCase {
+    fn test<Cal: embedded_cal::BlockCipherProvider>(&self, cal: &mut Cal) {
+        use embedded_cal::BlockCipherAlgorithm;
+
+        let alg = Cal::Algorithm::from_aes_key_length(self.key.len())
+            .expect("algorithm not present for test");
+
+        let key = cal.load_from_keydata(alg, self.key);
+
+        let mut block = self.plaintext;
+        cal.encrypt_block(&key, &mut block);
+        assert_eq!(block, self.ciphertext, "single block encryption mismatch");
+        cal.decrypt_block(&key, &mut block);
+        assert_eq!(block, self.plaintext, "single block decryption mismatch");
+
+        let mut blocks = [self.plaintext; 3];
+        cal.encrypt_blocks(&key, &mut blocks);
+        assert_eq!(
+            blocks, [self.ciphertext; 3],
+            "multi-block encryption mismatch"
+        );
+        cal.decrypt_blocks(&key, &mut blocks);
+        assert_eq!(
+            blocks, [self.plaintext; 3],
+            "multi-block decryption mismatch"
+        );
+    }
+}
+
+pub fn test_block_cipher_aes(cal: &mut impl embedded_cal::BlockCipherProvider) {
+    for case in FIPS197_AES {
+        case.test(cal);
+    }
+}
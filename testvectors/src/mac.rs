@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+use hexlit::hex;
+
+pub struct MacVector {
+    /// Key length in bytes, which selects the AES-CMAC variant.
+    key_length: usize,
+    key: &'static [u8],
+    message: &'static [u8],
+    tag: &'static [u8],
+}
+
+impl MacVector {
+    /// Runs the test vector by the Cal implementation.
+    ///
+    /// The message is processed both in one go and fed in chunks of different sizes (which do not
+    /// align with the AES block size), to exercise the streaming interface.
+    ///
+    /// Panics if AES-CMAC with the given key length is not supported, or if the tag does not
+    /// match.
+    pub fn test_with<C: embedded_cal::Cal>(&self, cal: &mut C) {
+        use embedded_cal::{MacAlgorithm, MacProvider};
+
+        let cal = cal.mac();
+
+        let alg =
+            <C::MacProvider as MacProvider>::Algorithm::from_aes_cmac_key_length(self.key_length)
+                .expect("AES-CMAC variant not supported by CAL");
+        assert_eq!(alg.key_length(), self.key.len());
+        assert_eq!(alg.len(), self.tag.len());
+
+        let tag = cal.mac_with_keydata(alg.clone(), self.key, self.message);
+        assert_eq!(tag.as_ref(), self.tag, "tag not as expected");
+
+        let key = cal.load_from_keydata(alg, self.key);
+        for chunk_size in [1, 7, 16, 17] {
+            let mut state = cal.init(key.clone());
+            for chunk in self.message.chunks(chunk_size) {
+                cal.update(&mut state, chunk);
+            }
+            let tag = cal.finalize(state);
+            assert_eq!(
+                tag.as_ref(),
+                self.tag,
+                "tag not as expected when fed in chunks of {chunk_size} bytes"
+            );
+        }
+    }
+}
+
+// Examples from RFC4493 Section 4 (which are identical to NIST SP 800-38B Appendix D.1).
+pub const RFC4493_AES_CMAC_128: &[MacVector] = &[
+    MacVector {
+        key_length: 16,
+        key: &hex!("2b7e151628aed2a6abf7158809cf4f3c"),
+        message: &hex!(""),
+        tag: &hex!("bb1d6929e95937287fa37d129b756746"),
+    },
+    MacVector {
+        key_length: 16,
+        key: &hex!("2b7e151628aed2a6abf7158809cf4f3c"),
+        message: &hex!("6bc1bee22e409f96e93d7e117393172a"),
+        tag: &hex!("070a16b46b4d4144f79bdd9dd04a287c"),
+    },
+    MacVector {
+        key_length: 16,
+        key: &hex!("2b7e151628aed2a6abf7158809cf4f3c"),
+        message: &hex!(
+            "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411"
+        ),
+        tag: &hex!("dfa66747de9ae63030ca32611497c827"),
+    },
+    MacVector {
+        key_length: 16,
+        key: &hex!("2b7e151628aed2a6abf7158809cf4f3c"),
+        message: &hex!(
+            "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710"
+        ),
+        tag: &hex!("51f0bebf7e3b9d92fc49741779363cfe"),
+    },
+];
+
+// Examples from NIST SP 800-38B Appendix D.3.
+pub const SP800_38B_AES_CMAC_256: &[MacVector] = &[
+    MacVector {
+        key_length: 32,
+        key: &hex!("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4"),
+        message: &hex!(""),
+        tag: &hex!("028962f61b7bf89efc6b551f4667d983"),
+    },
+    MacVector {
+        key_length: 32,
+        key: &hex!("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4"),
+        message: &hex!("6bc1bee22e409f96e93d7e117393172a"),
+        tag: &hex!("28a7023f452e8f82bd4bf28d8c37c35c"),
+    },
+    MacVector {
+        key_length: 32,
+        key: &hex!("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4"),
+        message: &hex!(
+            "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411"
+        ),
+        tag: &hex!("aaf3d8f1de5640c232f5b169b9c911e6"),
+    },
+    MacVector {
+        key_length: 32,
+        key: &hex!("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4"),
+        message: &hex!(
+            "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710"
+        ),
+        tag: &hex!("e1992190549f6ed5696a2c056c315410"),
+    },
+];
@@ -0,0 +1,496 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+use hexlit::hex;
+
+pub struct SignatureVector {
+    // extend as needed
+    cose_alg: i8,
+    secret: &'static [u8],
+    /// Public key in the format produced by
+    /// [`export_publickey_bytes`][embedded_cal::SignatureProvider::export_publickey_bytes]
+    public: &'static [u8],
+    message: &'static [u8],
+    signature: &'static [u8],
+    /// Whether all implementations are expected to produce the given signature.
+    ///
+    /// This is the case for EdDSA, and for ECDSA vectors taken from RFC6979 (which implementations
+    /// that run them are expected to follow), but not for randomized signatures such as ML-DSA's.
+    deterministic: bool,
+}
+
+impl SignatureVector {
+    /// Runs the test vector by the Cal implementation.
+    ///
+    /// Panics if the algorithm is not supported, the public key is not derived as expected, the
+    /// vector's signature does not verify (or a corrupted version does), or a fresh signature does
+    /// not verify.
+    pub fn test_with<C: embedded_cal::Cal>(&self, cal: &mut C) {
+        use embedded_cal::{SignatureAlgorithm, SignatureProvider};
+
+        let cal = cal.signature();
+
+        let alg =
+            <C::SignatureProvider as SignatureProvider>::Algorithm::from_cose_number(self.cose_alg)
+                .expect("algorithm not supported by CAL");
+        assert_eq!(alg.signature_length(), self.signature.len());
+
+        let visible_secret = cal
+            .import_secretkey_bytes(alg.clone(), self.secret)
+            .expect("failed to load secret key");
+        assert_eq!(
+            cal.export_secretkey_bytes(&visible_secret).as_ref(),
+            self.secret,
+            "secret key does not round-trip"
+        );
+        let secret = visible_secret.into();
+        let public = cal.public_key(&secret);
+        assert_eq!(
+            cal.export_publickey_bytes(&public).as_ref(),
+            self.public,
+            "public key not exported as expected"
+        );
+        let public = cal
+            .import_publickey_bytes(alg, self.public)
+            .expect("failed to load public key");
+
+        // Fed in chunks to exercise the streaming API.
+        let mut state = cal.verify_init(&public);
+        for chunk in self.message.chunks(3) {
+            cal.verify_update(&mut state, chunk);
+        }
+        cal.verify_finalize(state, self.signature)
+            .expect("signature from test vector did not verify");
+
+        // Large enough for the largest supported signatures (ML-DSA-65)
+        let mut corrupted = [0; 3309];
+        let corrupted = &mut corrupted[..self.signature.len()];
+        corrupted.copy_from_slice(self.signature);
+        corrupted[5] ^= 0x01;
+        assert!(
+            cal.verify(&public, self.message, corrupted).is_err(),
+            "corrupted signature verified"
+        );
+
+        let mut state = cal.sign_init(&secret);
+        for chunk in self.message.chunks(5) {
+            cal.sign_update(&mut state, chunk);
+        }
+        let signature = cal.sign_finalize(state);
+        if self.deterministic {
+            assert_eq!(
+                signature.as_ref(),
+                self.signature,
+                "signature differs from test vector"
+            );
+        }
+        cal.verify(&public, self.message, signature.as_ref())
+            .expect("fresh signature did not verify");
+    }
+}
+
+// Test vectors from Section A.2.5 of RFC6979 (with SHA-256)
+// <https://datatracker.ietf.org/doc/html/rfc6979#appendix-A.2.5>
+pub const RFC6979_P256_SHA256: &[SignatureVector] = &[
+    SignatureVector {
+        cose_alg: -7,
+        secret: &hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721"),
+        public: &hex!(
+            "0460fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb67903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299"
+        ),
+        message: b"sample",
+        signature: &hex!(
+            "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8"
+        ),
+        deterministic: true,
+    },
+    SignatureVector {
+        cose_alg: -7,
+        secret: &hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721"),
+        public: &hex!(
+            "0460fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb67903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299"
+        ),
+        message: b"test",
+        signature: &hex!(
+            "f1abb023518351cd71d881567b1ea663ed3efcf6c5132b354f28d3b0b7d38367019f4113742a2b14bd25926b49c649155f267e60d3814b4c0cc84250e46f0083"
+        ),
+        deterministic: true,
+    },
+];
+
+// Test vectors from Section 7.1 of RFC8032
+// <https://datatracker.ietf.org/doc/html/rfc8032#section-7.1>
+pub const RFC8032_ED25519: &[SignatureVector] = &[
+    SignatureVector {
+        cose_alg: -8,
+        secret: &hex!("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60"),
+        public: &hex!("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"),
+        message: &hex!(""),
+        signature: &hex!(
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b"
+        ),
+        deterministic: true,
+    },
+    SignatureVector {
+        cose_alg: -8,
+        secret: &hex!("4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb"),
+        public: &hex!("3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c"),
+        message: &hex!("72"),
+        signature: &hex!(
+            "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00"
+        ),
+        deterministic: true,
+    },
+    SignatureVector {
+        cose_alg: -8,
+        secret: &hex!("c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7"),
+        public: &hex!("fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025"),
+        message: &hex!("af82"),
+        signature: &hex!(
+            "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a"
+        ),
+        deterministic: true,
+    },
+    SignatureVector {
+        cose_alg: -8,
+        secret: &hex!("833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42"),
+        public: &hex!("ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf"),
+        message: &hex!(
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+        ),
+        signature: &hex!(
+            "dc2a4459e7369633a52b1bf277839a00201009a3efbf3ecb69bea2186c26b58909351fc9ac90b3ecfdfbc7c66431e0303dca179c138ac17ad9bef1177331a704"
+        ),
+        deterministic: true,
+    },
+];
+
+// Self-generated interoperability vector for ML-DSA-44; these are not NIST ACVP vectors.
+// Produced with the ML-DSA implementation of OpenSSL from the seed
+// SHA-256("embedded-cal ML-DSA-44 test vector") and with an empty context, so they only show
+// agreement with OpenSSL. Signing is hedged, so the signature can only be used for verification.
+pub const INTEROP_MLDSA44: &[SignatureVector] = &[SignatureVector {
+    cose_alg: -48,
+    secret: &hex!("87c41f61a552911d8762c578d0e7f954b5b163648f7d196bdbd0477c758a8142"),
+    public: &hex!(
+        "7b93bd3ae3ab5045ff86fafa2ff324371dd9e55d791e6a9875a58bb9ea0d1b03453da7af6f4a4e9280d7769bb2bdd995c8b9037fe7d60c7f4370e9647bb226c5e562d834e652a06cc360c477608e4e98445658bbf54841b80c52ef94eabfc70ad9496ab0d18f4a67f05a937c03ac4947edf504d823492f2bceb9df306cba75021547c96304dcc3797c5e15f360b4f93047696b010ead4a3e6f3820b599685ec4e0b6248e3b3ac8047c69b3ac67e5922526fe76c496e32b5898995f3a31c8a9358160605d58dd6c95fd1178a39706854d2c5cff73af809f34c0a0cc7a03e538c51f9a9c5a6eb1631a99e8ecb56322a76fa2858106fbd098931be7ab8346d753cc8f29af306070ca90dfc2c335568b92d51c6461e403e558fc8e09c88311860a2f587dbbaf09d8c96e1a346eccb604f847734b2d45440f5ee1990d353a628efcd91e0fb8030155240e0b0e24332fdad4a73da1f81aabf2dc2eba6714a55c1f389428bdfbbe53b3be99a7af10626b265e4dc357a67136acb7f132ef872d7777e684ed3ecdbb190ac9e4edabd842fa00454945537244436c7c1ea710e244d8c88c9858bf1f4753c8aa4dbcdb0e145a53dfe9b4fb32a4f5dec949c79e4b59b68bf6a1afb66987f38a814d5d82c7ebf4ff9ac005e8f8e3fcbabf0ab5bceec6cea9112a753c574ee0a12483223ceff312e9f9870662ee52250f2267901711ddabcd90cbf5c2681f381568c07c5643ffe10a68635750906ee8f0a4f373b5af49418dccf9b8fabd177e88444103a57ed5d64453ed24d6afb627718ffbce0f63defe87724be2f4af10723c7ed646f50dee86e99d71edd768bcae34f5fb6ba5fc854171ab763f4c59e1c32336a64b75129c0f30389db15b24f5c4b616d933161245adcd84f12dc19726b1fb8500f253895c27b3e0fe9c2a32b335a5d367fbeb0f8c50930b665a10886c83ecff97fae8085364e279883097a2fd28bf92157b7d56c32528d936de4ac3684a04d7917e66a632683c342d578287179f8bfbd60f2cda2e06bc2284f760d98e665428e302f2385b3192e11b9d57c80cf80f049336b0bfe8569fe60a29e14049f58f54e89dcaeb679fca86c66d75d5121bdb7939905caa1028362a2c5498a90b672f612365ab5fea04d0bce121fad5ea347df293a5302a0ba08e43138fa6f1a6913e39553ed2819cfa5190ad2a11d187c883dbf0c897fa043dd6a93ecb4ec5c46fb7423e67571dce106efd06e36804c1ca4fe1a5bc081da2bccaee88747113b3d52d5adc2377b015b019233f55a545ebfb6844bc38ab075e475a4037f7be6c93070c91e7f253096a52c2bdaf1bcce62425270c05c3f5fd1c962331e72eeb2483ac6c7ae84e8a4d1065d7c75c5e129c852e98fed9abcd777f4d9a29805e09aeb76d31c11987e23c3dd4f6813d2c3c5f54755face4d5e3d5cbb328b2f643ef4155c5cba4dad719896dadb4bfe94333b036088c49a8d1b5e3b040ccfbdf15b8f7e6efbe2b05b1336ec5cc7e562660f9a84d1c9ca428384f5a99b0cde84b0339f40ce0b2ee9b8ba420c47e745100c9b752591da6bb30eff6fcc678a17a8c7870aabd8556953dbbc36923930c4c5f00c2d1990ea830698a9f77f73d440d1e6276b2ffb53362eb330337d3e41234a0381b2ef2b5f84fbae84f140081e10d6970d8b592a63002a450e6fd72ac9c9a57601e2042f3cb58240d529ada9a332009bc744d25a7c65b5115ad3aa3febe26ce06d1fb2e16c454aa170b23eca4e073e71e29651559f5cf8f40c56380937c5e6e30cfb84fdfda551b0cd2cec068452a69102dcb162cc757716ecd2331acdcf2e2cf0257a00a1a1743883d0da4822bde1f"
+    ),
+    message: b"A SUIT manifest, or any other message",
+    signature: &hex!(
+        "4ada3013554b29eb140f685b4a4db5d65bcc5d5661e0c7d9c3e2cae79192836512ca79a2646237f5c92c6c0f4acfcf805547d44db495a209df0c308d4b0fe9db55ffaf7420b06069c578d67733b8d7a463d8a7ba6147a3365edd9a7b0aebdecc4f40a3152f0247dbb674e0c94e8dfdeabc2f50aec5f326ae3c37556f95b7c8ebb0d41787d42d547196ba2b875e07ab3dba75526d0949743da69091d879dc6658b5c1b11143b7d5e40f15ad822916e1a411892d1c9ae0efd9b41b89a6e41d3e1723bb5eadbe04595853528d112168efa402515a877734aae9212f46135347b4ae7c9651d036469afd50aced4aeb80222757f49cf3027d2e63e1290c24a8471233ab520804213d6560581710020e2d6a97ec202a5383936f4f17d564bbfdc7174a5af39fb326846fc9445472a715517c6880452edfb8eb6c5c6709315200a94a7c534bd9cb7b0fe70c92dbd5a1f71eb2857af21f7396693df736fc2385787dc746638c4bfc92ab3366257f71e3789c2d0ed5bc3c98c8e43a87e9ea7283675f04139cf2365e04692817ac95ebb1eb0db3053828226c8393a827428f73a29f2ae4c43837f37c34a3cecaf5531e5442f66b39eae77719284ca2736dd2e871894b3586f947a297656099bbb066261e6c8d3ec8adb91716cddac4d0c0645149fe2b8624997ea3aa86d0469802649e9a219861fd50f1d134e1c0ec70cae5b654aef40dd9df17efa92d7069a7c0bb11062535155aacf436ee9b67570e54d0f433c08b7c5607ce2f42568b44d4762bd02ba706f95de49757b6055be4c824e19f3e0e31b963a3ead00e9f372bfc7b9b591bef129822344b449d85aed6bee338ad427e10d844f4eeec128aad047ad6ff75b74937c4a456b565c8788e222ebf842b4d086cff73c8fbb43c89cce751d83d3f1e0fae1ad317fac579d89090103b9ed2bd1b75861e06448dad2ceebdff7f4028b9f044272879a66f03075d23c419e084ae1423d385007f3ef5c0768fd52b82e955c8bc0a04bfbeb38a788cec5ab538c20fa0bedc181ca96aa5c0505c0484b45ef549ef086680779a467c7132c32036d080f40e4bf5dbafb44a834e3b9eb3130486e72ba2c01d555daed44b027e0090fc254c97c28bc83aea4b9560e7d78ec5b8334cdf9b21711b76cc51eb546db4009ad43314062ac55383639b130bdd7d476735076338d0593af5517c8df319337992ca1738912cabf69c04f4811ebbea944c4ec27b7b8872c4f10b0d63e15d65b16722c8e7b154c9ecf3a929f8a24171db1293ea380d0ea5e2944ad04250bb378c4bb39f4637046c60a60ad832396472d7395fad984273a009071ed55a6715745bd77bf59839927e25a34edd7dac01b2560a96baea11f777628747c4f3a15a419e85481d89ca92a28e916ea030b1070de98d61606a85b7a880b33209f59c2a1f93715452af4a3c363dacc627bd4625b6dc120d3b367e04284e13f044ff67d98ccf1032e01e6ffa91f1b84e00c3b4ee63645ac4f51ae5282d49bc20a45cb5211dd44741e9d46dc1bb034c5b8e1b351685c82969795b32746630f1e2c7eafcaf70f1519ad5a5e919cb0805955d33c74f337b368e260e734cdf5ff84b6ecfcf17f743e8ea09cf8b20fa05cae879beebd8306871e8c7b1f4e42f5843c2e9635ee0095ebda2add71db5e72daf393d61bc0eb2c8e03ec1a629ab9713211c52a1aad10b18f965784ec6ad4f7bd14ff638aac23feee102c4ba6dd1991129ac58a9c3382fa0336d632a014c794fb7a38b767a532f12f516fe9ef659d0b1d7328280c03a63a5b1406cd472b57d27b6c49b7b38da0e72609e96d7376bdb96058bf55baf51c391edd85fd79428341d226e236391e72c7d4b7a5a9c2ca737aba18c9cc03c6ceb865dd4aa1172cd679290f12ede4a60325a60a46bea8a4eacae749593d0b9a97eb7ff632c52d253e5b70b28f1354509ff91704f9c0dc11ab00e59b349ff3bd8638cb797de1707287b1c5519126044d67fb869c2c062e49ab514bb141b99b5e8d4647fa99da7b07d7121ddbdf16a0b244d8f34e7149833c29ae2305d5536ac4bf06d8484193a64805073336537070e40d173b845421e25ef97436f43032415540f16b1a5e1087e5fa6aab2a6d782ce742096992c5d677d21a8016c2934fd90c7d9e0cf96611131d6a5c9d7b57cfd0131614f10eae135e58f8a0ddc713ca5f5aa552a447a146dbdb89a8ff883de6477188d1f4ffb238b3c4e3f40aaefd700ffdbd536634da37f52a6186f0ff8cca6fb9a5c6f981da112c1d7e1f93b53026cf30ee6ef2983d75b1dcaed5de7b59256c7e56b2a5c6d87a633f3a0f985f4ed74c627aca48b0c58f4f005556ada647afd3f54e22303ea296750fc8d7f46ca8b7576adb1b5f2c521a93c9e33ef0f59c328de70965c63b2fe941a4c5df157a01f4e53d454ebaf1a6a31fd0f7e8cf1741e5f6eb7b4d3f521e83baae0848554d6041881407d4320a92451c1e169997f2e89e1044be6980f4ee2ab75efcf6214823bf0165f90459d591ec33d0ceb64df65c7621d16dbcc4f758beb978f8e590d594fbffc9870e80d381350a56c879f364b36b7b41724fb32b9452e9844f8cfb2f0a933de37085fc43a9387acb2fd9d44c88c0e6a48a5aa78fb3ece2389d247984d2e4a491a98b15e23fd91501919fc1835cc0ad69aab22d16fe068063f5da777a52e826f8df8e02501ac016042ae9bc82b75fc03a46d12e7b24d031fe4aa7ec5e26b9c5cf2d3f45cf811698d580b9c1a5a298249c95ae4f1987bcd8a0332771db30202da172c5b263932548eee88003e89883acf1b82e5dda8e3b46e0b2fcdaa167dc3c2ca5ebc2e6c654ad05466cd1c39e95f9bb3b59a9e27bacc9b524efd063be8f75f29cf0a3beede32cc0cb483bfa870056477f2d02e5adf048f5f63c50a3ff3e648077c4b9e021039995145490c918bc2790ef68bbca085bbbd0818c8416af34a3154a08f669cfb52c735020f994a672cfc763a05c7cdb6ef1bee4e8ecbc999ee6e7e614ccc81d05fd9c1bc0477283e125659093be6f4fe660d5c7d2612b9e5eb9b63c0a3e2c5cc34a88ca71a9f66f0be64e081d31eab78b392aba999b42fbda83109cba1caf5dcbf17856e57ab9fa1c91634e8e4fe878bec6b045c69f9773366fa4874a9edd170bc666c4d3c0903ebbb3b85e73dc9db281978abab17800d626366b64bb79eff8b9f73f4c5210981ad424b7cd5773843c9b1c6719f88c2ff54d009929bb74416b836d6a0dcde67900fef79fc2839393bca95ed92110baa019848206d5116c938e233c45c0003233954555a9cb0ccdee4e8ecfb000910253c3d4b525f6d6f7277949fa0aec2e7fd080f222e2f3b819ea3ced1f11368828491afd0e4000000000000000000000000000000000000000000000000000f232f37"
+    ),
+    deterministic: false,
+}];
+
+// Self-generated interoperability vector for ML-DSA-65; these are not NIST ACVP vectors.
+// Produced with the ML-DSA implementation of OpenSSL from the seed
+// SHA-256("embedded-cal ML-DSA-65 test vector") and with an empty context, so they only show
+// agreement with OpenSSL. Signing is hedged, so the signature can only be used for verification.
+pub const INTEROP_MLDSA65: &[SignatureVector] = &[SignatureVector {
+    cose_alg: -49,
+    secret: &hex!("8d6aa8a39c0d4c2e208a517f589417fae4d93f2591abdfa5cd3a6bb988a60c00"),
+    public: &hex!(
+        "ec76455f44dda3f4f1e0a69d374b3b7a15fa0e0b7ad3e5746760c0172b5339ec094976d4912bd5807e39a33b1376a0b6de6062b36f516c82c4713064d64886b47ad541c2c379b196969c4502fd1c4ffc7b4df703a1f053be7840e6956ac8f5051eaf0ef8c56593e3fe3d9e1d025292f7985d0286ffaa3c23594a3f971680e4e65d66547c9d2782e32e7337b6ba02bd890236746d1f1074ce9542efbcc97c9db26f2d95979cf081c5b535ab9e55627eca0947a2ee043d731e65dc8e2e296be520a8115cb28b6f7f52605f15171ddef1d58fe779f043e7a209aa65fc07ef55dbe5894facfb5e8d2195f54ac907d018d452565f3affd3e9a20eb1daa1c7b1eea85389b86f1b04bd517c03399905a02fff284db9b3763b466ad1b00d3e5a3555b4512b53b38b6df83ea1823569643f86ae5e208db2863e6ae37f1eaa77d19310950ffecf48887360853e1f01548716a6b1f56412101c3e82325f41c3fa51ff57b4a7e5afdb87cf8a09721507f007a5e557ea72db8d05cc2a1fd343479a2f59ec9dc89513a41fde8f19fcee1e6b1b3776ce41ed89c1817f051586386ea58bcf36de5ef21c7699d25e4d2275427d044f77de63d74f7c9e758ed5fbbcf5a087dbe0336c6ce07565342a11c52391cd6b33c060f1304341da18741e987c3b9e5ea765b7ba2ae7fa114fbab4286ba99066a86fc75d2ebbcffd8d80ea1b430e9bc96eb3e501ca88d2a8dd56c7c845a875a3edb621c567deb9a4598688cc0bd5706582184a9d79cda4a49d2b043c02c8d3b469b170884f1b13571f1c1cccc2fb69ba8dc7e6b99a38bdfce1253ec3ede85abee9110728bfb2f1240999b233abf0f7870a1e15ef8ee020f15cca56dd46a8b7fbe5af98bfea0ba58b8bae478558d9a6711ec2b780ab1ec9a1bcaaf066fb7c1cf570af8eac7d15d82337717942d59d7acb5850ca7087124d6a2f3e690f7419c9490a096865fa1c48410f5560ed1322e04932607f6079609b078a59bd071570df5f379cc41708d534a90300630f92aedafbd45e5663f1b2112257535a73d543632f51c66e5dc7f62e4e11e2737a9cdf6850c47205d16cb53847ff7aacb790bfd7377b94b230e2ee11ad6430f544ecffa53fe6fe9753604d28de521dcb8d6075e40bc1294e107e2c93bf77b449239ddf8611289e552162a29d454882be93e22233e4246351a50ff939f33b223710d3bc7fa0fd6d741bc99517461e98f6895cfcbb80afe4f67601f1f526ae8c747109939c29e18d8941d6f722e9b284e9bed264856778d9fd556813f8589a8c7a489f1c703c96e301b51b111275bb4c17abd31d2a5f3568a0a6d61f670327dbecd070b530966de66d12b3245bd1658871e7680eb2294c329e87f44b9408bd2734c2c144b99dd2a39ce778fbacfddbb81e90832228e0880a2871a078b27e6a663da6b33e2d7444520fa718d12f77e0162dcefb31e7b0af805652a5daeb5e07f7741f38ab7697ee9e13a824a4d3048d9d9fb1d88f0c4eec0cc90087950d5d404cf65f97212b5a7b8291a88bf414aaa2d9e419b65e6233ee1156df83b047eb68583660359039dd124b6d78adfde6c7da097edb1f2bb7790cca3306b1e093421947ca551cb7c61c978a12323280af783d69af404c0016544b7b469e2a6c39d45ece78aafd2b691ad7d38b3da74ad75e8e64e2624d4ea079ae0f5509a2f610ca82e113d46ea056a2520a4cc434350f108cdef37391d28d7fd7c9460e0841b38a026e93eb8fd17a4745325b58ce9c8a31a5330e8f1ba1c29ce2e4299f5b433b87ec241e31561597465a7cdbb0099821ab96fe728aa0a7819dd5f5c9af6da6ecebc9e6d3007957a3a748bcb5cb164e29866142d0574c775d8932d87dc30368db65d3b5c87441d080877b8647c787a4406ac0f97d2aebd8ec13bb9c7add39b178021092426b125c9d91ef9913172b2d54e152192f8ea92b34e291616236d3e6c4f167585286617f6520ee343b89c238c7cb56d0b1d4307cdc8070daf5ede47d2508c56b5892ec795e487f3d6f0403de9a04e5a4242c45c468ef69da19db318a99a7f077c00417c9b39e9bb3a9ae8f17b44fdcd866cb19b59e452acbd4fe754014432350096359a434c4dfc1d09643cfa1586ea346249da9c2e9eed81f7978684d1160918dee79c8f1e2bebd4b6d7ce4f9cab9a127d0646eb7f9bf79f3fc24fcad84e7646832dda52ba41732f41c58f2e31de76c4df8d5cac788c9ebc800a92c15f936d2696d7eb767f8788d01a5eb1f6cf44dd67d23b667f65c0006cbdf60025d2f591c6b3f1284b35d564ebe529ef0a5ebda3024031b5fa1e489e8b4b7034ceb8d4341aa19603dafabeac0949005e93dacf60cc808f60091a06093b47413190c7faed78883b4c20312a300dd4d0ca90be631b8a0b07240b7ffb93e23e418587e936780a829bfeb8dfa5e389da8f11ac55205fc784f8ff12cad01c1132da59a72a8445f0d33d62f8ab91c732397eb8ac49fff85c5e193023be2ff86e48ae7bbea17283f2e99e2777e7451ffcdabfc4a31141880eb4ba033d9738a10d0d89b61ffa0e12357820e42f31a6c1adfeb9866c9fbff70227135561409075667d80d6d3d93180df535dac922c8cd54432abdfe66d930b40a4c78fff6f80aca57c5f9afa590183de1ea8cf493bface34f27d546b9f23384fd900023cf25f59e515e3cf95b378b4d98aaf561e65545b37341701f73015c67e111aad8a2ea46dcfe18324ffde895db9e9103ed76ed15f9e60d"
+    ),
+    message: b"A SUIT manifest, or any other message",
+    signature: &hex!(
+        "1aaf967bbc152451d111b389c04da8b7f1161c1ba92a8ca688577da241afa2728fee3f3669a1944c5e4bd32f42f0977bf77db26429349da3c8a4f7688a78736a3e5e0220f351465d2fd181330372a80ce8218e42f192cafb883090a1a064d8a258dedb4933a31dea080015331268237a51251dc71120fcfa59ca5daf5e58ca76c81f4a8a684122221a5aa1758d5867ad46da52cdd97f4abdf6ca5ab925efda1211e640103b8106ddac6b0ef490cfe9327f0ed8e89b6d8567e5b2302618323a7aa6f04ae6eac326343c14ff0598a8acddcf34c4a84371c9b5099f72f2837dd283b4cab172e422473351ec77ec562aa0947818b7779187a867960d7ae989ab27a53c7abda5b09540136064370e953acab7792692b17c316cf05eed856aea6bef29a14fedb736030cd532a6cf78c8ce992b7642023354853aad0e92595ebee2f2fb61bbf79e87f95b51857d28cb597d3a5e2235d76f21ef5171505aed4400b8e8a2302e6a0d9c34913b0b9fb05ead1c4d88de518ae5fc969c6f94cbf24884fa22b3e1b72be5890c0656f480a5adb0f4c42027776e44de6ee093d68d004842f589e1a596e681e5e90e754fe7cd7a3790532fdfa12efa08cad7534376d5518e0822a7b8c92a570c164f90be9ba6a0378ea4a84957e3f8d45fcaf3c4dfd401235527c87298946091cbeb89ded4c52eeee05f60b67ef473ac45ceac2e41db7f99e807118c14ea8fc4d07e09b17dec80913eed66718d63401f0b65a2ac52e826559b37927ab7e1071a5f2ee979d2690e9fe742bc71665dd2d5515689c91d302ce344b428b1d9a44e7b09c3d3e7c2b949a2f75aee201cfb80917b757ffc00b56e7f85571cbb952d4897b819233d51676a5fb52af96c13b35c8f6ea47f73e58864b84adfaa092f08119f267f53ec1111754d2442fd87a11179b3f763967ad7df57b93a45ef1fb550f9dbc58661e2d074610a139fe8dc4238fee958dc05df677b542c93dae52a0f4542f8c5abb0e59dfe244211b90f09db88463851dfa310e35721432ccefa4d5aba0d1b530c41a3ea7d2e72018a808d591aa5ebb3c903ea1b0a78a89c540169fb2685acc860777d20a7285741eabf8619cbb49190422df6548eb2702df272825c39eaa098b5a320026c0833d2bfbc886e070017c9d6ac8a9b9c5131cb5e2320bf027affb8e12ac8551eac26852346e17334db8967f6f242402c6f470e16fef21b27f70c4dca2d4c01d0befd0ee7350d34e318bf92e35c47cd2f156775cf9c646a917ee03762827bd0e45747637a61897db725ea658a67c918e8b69d3d5ee9f3227e83936eac71a6e8934dd8800193f8c0021612098a360458e301a5d884ede353d46743a90495b93566633a982a5b7bc0a01a1cfd284f9bab0a764bad8e2fa30bf8682a73f0587ca802a85fd849c623e4ddc6e38c42404325cd3d722a40e286ef4d88bc22fca8f8beffe03c08f9b13d3887a095eff657bc9326d7b593eae348fc15985212c577d4819c8ae63197da144fae76a1d66899d4260edc24065346c1d545c5b31ccadd73afd7e131fbc9f7937308c51c6454c63accfe03d5037b9b000a9ad00b67cc26b415f4bd06fdb7bdda9e458352b16a3fd6f606454135891cbef94c2046f56ed08e735a89bc023618124bc1e46321ff0f6ae7c2fc49c0c79f0a544efffc465f6017e360bfb0f31e12ecdce456bbed540cc4152162370c82debe864e4c4e93c5289b44fffa4c8ba970645f4f44e106b4a77332c9af8e51b7ef20988e85399ddae1557fe6bf6b99ddadd09406b0ca52455239296ffac83cceb91ab7ea72e20b733dfbd37aa285156eb5667597741559a8fed76ee5f98153dae0680e846fe5556bee10c093d9a558d3cce15540a026bd7b7d7ba5c4bb08192b5bc3f884f4425779e327aee1125ce75188560c23ed4896467b5304998ca7ea7a2a6756fb79e5997c761006e152167e2081895e58e27472ec0defbe625a5b26ed9e6572bb8ae392a5375d2c040b359aad2f623c8965cf8bc98727b944c78a20120e1692068bb37ad9a806e38954b03ba1ce4a5f6bc5be5750adaad220d328f680424797d2843444db04d4bbb490bb6775279edcf07d5278d3fa50fbcd324a5f5c6f85f8162c9c0aa704389d67b4a64547918d5d8d63d69429cabc3b2362026d4a5b4469172357dc436e9a28d4338749cc100f08c79e51d1f02dbc62d80f0c6cfaf4b1326856ed2cf30fd81cfe033b3379c791bb88d09e53f07e80a8fb653db311d4c664f54f23e1cb1e6b85ba15fb907996228e406699fce37698e9cd138d442152577e47d3e65820737eefae6fc1c888fcc0c2d33f9b1d9b227911f040d502bbcc26a799b868260f7c54bf84928f4d09c74017127e29adca3ff4043206c9ce20bd648c6da075782f74e23fc3879686ffed2e9d5b8869e6430fd6897f27491db8afbb0731d81f992293562fb92de9f61bd5f14ee46c4c7844e7d02fb87b5adadf31c61eba2be1378035cf002ec9cbf301a488364b7ed4101a1764b6bff1f42326f2142fef688b4a9d35d19b4df7b6195ce1233e0c395e1498bae42812c474ddcb24287a92a70715a348707574548afbf6a2185fc07c8a3cf3b2100b85422f9bb6c3cd0b9431550c2fd714ac2357489094f3f5eea26cbfc3b8aee71a1dec4e991c4f3ec20fe009e491b882b9796eec79d505742d782244b544542d569bfa907c790e13d14eb92d45853f288bff10e8b8782f190b7a0b21f44f3c2d2eb713494a261ee01c268c2b24d3ead2a0cae1f413240785f4313c662de85ca3f58c2238b4a19bb6a485083cf019d17358980ff2d0e5e7fec5577d0754caef080ce445e8b4b7d45bfbffc84660a08e3ab1d4bfed2e7aa14a442c5d91cdb1058a1e1d571adee9c2d2814d15a668b1e372cef47cc75f5286950cb1e0635131fbaa4a467e292bd096a2ce2b3217581d7a1e63167a49c00d06a78527167a1b5b8ebb437fda282249819bc139dbfe7171eeeaea163737af33c427c59d0dfe86c7692e6e524e822a6596f7318c38211a2cbb806e29e8d26da20d6629fde52aee795844c6b8a19e54c28c9127f5bfcadcaad12b504d45b64aada339cacfb00cf3c8ab55980ed5011d37733bd5715ddf957b697715842540033ba7dbf37546e27c57cec0964af3bdb6e43cd52e547afa291a25dc14368c6bfcadfce3f539eb4a0c1650fd78a8dc37094629f80f6ae9f663151f0b850e4ff9ce00e057719494610c5766d102d738ac09d81d26a786d9d570776c8518dd50fc0254ea3219ddb34a93c781b2788f893901488b7c3864e144920dc452bf65c705af9e9240cfff64c2bf60f7a8b0aa402d15771b002d03d5214e381eda2537f414ec04c09206c5a49f0d7ab945bf0e36d48be9b8517049ea60d84caa7be16710c28717abb656e6bd534ba8b74bb1f48b2b196dd74e1c7c2272e76012de63c1a91a88e604cf975e5b69ab647ffb4b8d72e69e910b097ce56bad514c611a69e97d991ecf6b1ec74a519588c40295d0a0c5b5f7acf75df222bfa8626ce1df69144d6934c9650b6f53ff400218b5aafa9971988200db69ee2f33ec659b96ef61ec67477bbc30b46237bc05238b9ae9e73c8407efcda06f4b3bc7463d338c50cb6585ae1ab143d2e5c80c508e42f8f84602d705469bcc41b0360130bb88d8b853db74035618e31be6c3aed6355faa0f487904ade1b7ac2b8f86d353809c79c713aa7a7db2d5054b62f2c32c439405423a215892376f69ab94198faad89b65f4dc471357d15fe266844950e1d433fb30fc11615a972307e95cd0800fd442e2794eb0250cad5293bfe42f90ca593b39c94ff97034caaf30fc8ed8546b7f8702f00bbdf8d62e7cf512e568ba706e15d3c8e6403f1a737f178eaa1b8b51cb462edf9f8a7257bb867b2b443cbc09c4ac195ee254e88b5c8d31225f0df9c68f46b41fe482fb50071869bff51abebd4a04bc05039f04819b50178feb8086924bf8f002014793662ec24b4f00e298accdf1865aec8e89fe9ca85f14913562971251149301cd2f64d8ff82c12585a54d79fcc99bccbbdcd7ead996afefb9ea3cb9a6db4e8ba9eedabb70652eb53c3976973ddad89035366f7944a81ced4765d6d5c094c4641f1ff0cbd499adeba8574e91693110ef30203981f14750729bf7930addae6eae0da3a0d2e82e979a2431594fc8104141c14a01420aaa6dd9b9c39e505db209f17d8ee7dc7ed194cd38787ff93517cdaa09178725dc742aa2612b0afb074fc02627f6ba6fd54274b4c36dcbbb905dfc6349c5d05916a5aef058294ff81edd99c887d48702f27bb8d8f756ed6ba3cc2b300c6f7409b20cc0ca4a0389d4b163c4719445185c14dd93d882c8966f90a6a12cd0e038eb63845d7320d563c6d68538c1c03ac569a113097c84307e997a20939aaf7554d09ca3d246d8451cbfb71a9453a1c2e75153ab77476dbe54abaa6785488025642ee31aac8d7d81d51cb5814c9703b69f1d47e2b0374cf398adfdfa930218a13e1914eca4dd6ba36a25ca356e1ed7a35d38ecd0ce82728a57b46867af0ee9243f325eda63dbc94bc58f5c55b8e6fe5f1992f3d707373f58678e94a9cadbde14185f848791d6f11e306990b0d4f3f62d63686ab3b7ccf9fd0c193c647695c30617192c359b9fa9c6cd00000b131b242b35"
+    ),
+    deterministic: false,
+}];
+
+/// An ML-DSA key generation case, in the shape of the FIPS 204 ACVP "keyGen" tests.
+pub struct SignatureKeyGenVector {
+    cose_alg: i8,
+    seed: &'static [u8],
+    pk: &'static [u8],
+    /// Secret key in the expanded form of FIPS 204.
+    sk: &'static [u8],
+}
+
+impl SignatureKeyGenVector {
+    /// Runs the test vector by the Cal implementation.
+    ///
+    /// The secret key is imported as the seed. Panics if the algorithm is not supported or the
+    /// public key derived from it differs.
+    ///
+    /// The expanded secret key is not accessible through the [`SignatureProvider`]; the secret key
+    /// is returned so that implementations can check it against [`Self::sk()`] themselves.
+    ///
+    /// [`SignatureProvider`]: embedded_cal::SignatureProvider
+    pub fn test_with<C: embedded_cal::Cal>(
+        &self,
+        cal: &mut C,
+    ) -> embedded_cal::accessor::SignatureSecretKeyOf<C> {
+        use embedded_cal::{SignatureAlgorithm, SignatureProvider};
+
+        let cal = cal.signature();
+
+        let alg =
+            <C::SignatureProvider as SignatureProvider>::Algorithm::from_cose_number(self.cose_alg)
+                .expect("algorithm not supported by CAL");
+        let secret = cal
+            .import_secretkey_bytes(alg, self.seed)
+            .expect("failed to load secret key")
+            .into();
+        let public = cal.public_key(&secret);
+        assert_eq!(
+            cal.export_publickey_bytes(&public).as_ref(),
+            self.pk,
+            "public key not derived as expected"
+        );
+        secret
+    }
+
+    /// The expanded secret key.
+    pub fn sk(&self) -> &'static [u8] {
+        self.sk
+    }
+}
+
+// Self-generated ML-DSA-44 key generation cases, produced with OpenSSL from the seeds
+// SHA-256("embedded-cal ML-DSA-44 keyGen 1") and "… 2"; like the interoperability vectors above,
+// these only show agreement with OpenSSL.
+pub const INTEROP_MLDSA44_KEYGEN: &[SignatureKeyGenVector] = &[
+    SignatureKeyGenVector {
+        cose_alg: -48,
+        seed: &hex!("7af655303c61f0e34c8c3ae9f69644014c7bcf0a3a9bdea04d2f675c19eb7241"),
+        pk: &hex!(
+            "7ef84821b731fc2767f66695315f82627bd36b7d97579709b59ff9c4b491c5e8f528f1c072f8dec0d4d1209aa87bae5f89bb670eaf7c7873cada8f1ff28c0bebc2e19c11629ad589f4039396e8847082b0d812ff224135475572dd3b3f76b85d58f46c847411c7d434244850ad4a614f2f34b45aaeb9c8f9bbf0cbc74d2c792f885ac490a39a09aa85f80c537e79b444944cc5166e759fa978e382709975a87de46c490bb313bcf7d04cb4aa70bff63e1e72bdbb6056bb26b4d1fc8728b74474e4493099aef4c4240498753d11279a9eee04ec4282bd1c945a5e6aeb53b395b891122ccd511325d3b73bd62936ee9f3585340b9e7cd3a83b246d639908cc39c0ebfbfbdade1a3797882d50ba98ce12c990cca4e7278742a6ca8b39b8649b2b8b426eafed4f84b75329f8a8513ce24f592e6d39c517c3078beac51ea76cec9c9210d9f1e7b92c4e7db7b9934c5094fb1b79eba0292ad031e98935206532a3f0eb42ad216ce1aa1d687829959d430f9ceb802be4a8c3f54087648154bd4b9c523a59fb6dab53127723391b83383f9b00ac3994f0f5ffcff0a75f39bbb82d47ff24ad6abe064aa3c95a04c5d7bd2f143639a7fb1e92671131b74d60d4fcd5f5a20dd063147b8eb6017b254a229dfdcead5963f6858cd279e32ba9a79f3cb9cf67b5cd117d09a03a94c5bc4470f95408b557a4cfac3c439f3b453af63c7986d1036e5e68e2e83746b18286766e93256703b4f4b1d56258eb0ce7522d5003e7d356f17ac1d4ecb459a4a96c298f60579eb745ca9ff741d391c378324708341a0181302da0eda2677420c8492172f74f975906ca318bfdd19824f5608c052708c4c81a2508c438e15f0acea27296267e2707529f549b93cc0b29a5ea12d1fa4bb75df117b4267ca64fe3b5c3afa4916d8d64767b0bcde91011a4aeed9b9c4d25b5cd9bff2c18b70810846cb37c556a3f5d9691e26bff81676300a23965185091b76ac9be580c2d5fcb270e04b8e0c78270e13204e48833189a3bbb169924fa5f60d8bdd9f88528b4d48272586e8a0c5d4fe1de6efa21ba7de8f3547b0487e4903f307c892c0a55340c8df7badab0d67469654e847ac44a783f783e703b2e671bf41914e245ce2e011ad38cf59a74e9a76c8a2bb83fcbd7f8bd214e5af607af2936a516748ed238c202cfb9f569bc4d5b612e64776f97e18f953b27f16bbef761c2269ba0e9fa3fa5238bad17fccd79c5963a45048bb8b4837deb6f36e9b3e94470429c9bd89b70a39cbfe72a5d35abfade201d8863da59ee2026468d07776845d9459897cfcfea1dcd47e8119c19098349395e53476c49b613d9892ac6314721b1c52c9375c67c9b1132b45731174c24627f6977cd6482785291dbedd13aff066903953eb409b309ecc106615aa35fdb6e0c2b98149c6013117f046f3c36d5cbf71fcab6e0ed92210b009151398caaf31aedb629f69dda5c474ed4835fb9fad8b4934067d95bb6e3cac99fbbc436fac2984c417651b05638ae8d18e4f1eb19a302b70e55efc1c1ab3c5dca653dd6b856a50771287aeecbe0a33962080b317a886aeecddb78b9e73f6effe51a05a1c5973a8a098e0270785c9ae5a94ca9c963e58d2acb1b3a895e03ecb32740bd0b814288e5c69f61ea259e3af8dc51aafafb536b807120f543c7603339df18a444642c7815ef6e304efe7241d1fc31731b31268d8932c6869f9dcff205b64382c6b8c757326a9439fe8139db7f8d35188038abd18d3d476473c25dd2cb186e19ef49362c58caf69b70dff9cebeb2db0a82bb82d5af90c2488cb58ed34ba34a9c38829e8236fce1bed30ff067afc5180f564b64ad8996"
+        ),
+        sk: &hex!(
+            "7ef84821b731fc2767f66695315f82627bd36b7d97579709b59ff9c4b491c5e8655361ae69076181a10b837eabf9224144aa17c0975761336868d8ecbf9efe65df5172ab7dd7d6c54a043633445812458b4950b7f7ce9bc4d88854c780591227436bce5e6b4e581aa40f7521e09f4b7a6e427dd4ded11593d0899ecd685050f7448048038425d0b8101a1092011592c23866e4466a544022522824d14070e3c24503484199b2245b808d0a494a5182211a4662c1844950006ad3282619b40898389012b73010948089180d10227288a6090039405ac81199202c932205e38665022684e0344dd3b46413886823256482066d533830a32210193806e0188243b20401a0701327411a86009c0052514068cb1206c8c82cd1348214c344249121ca906598360dc42408dca004e3381150206212328202b724229105128580c8c24c634649804484d9368092c069d846645128609b32050306714a02601a1781a3863141a2308b046611a16c92b60808812902034152322c60260a1107301842860843445b400c228650a4c865ca426c402486d8466c23b990a2046d1b0968a00260493622c3a871004486a4b0884a344a441689090760183870014972d022820c8968e4b681e3b08812130aa2a870e4906819054e1345820ca14c0b430a408629d002618c8821643608c2324d0941101002108492480ca20d0985915c188d9c4806e000660085615992840c07658bb6008100109a828dd2366540106008890c60c25152428c13b92cc01880c0226514989098b828a49801c0c86198c04c6398608c369113036a51208d434262e23800a3a4894148812020700309261a204c24a920401626513406d3004cc222904aa4615a4025ca32856016859c18880bc311a21271442464c134528c40121b37841437600c10441ac68813425283c24c80a264908225db1824cb42665b366191004924a14191842cc14671e4a8044902704a426591405052a8810040499344064926105242600b830d003130438200d1362059b26899186c51a8481aa00c922488ca38466346681003440a992564b688c91249984472e4008424a029a004249aa2704ab48c8c00609328251a36600c9885182209e3c24563a66084a484c1c8294a327213436124c668429020a3360990a625ca46205aa265441872a23022d8a045e3044a40a6051a3440c1a861181010c810299246280ac2648920091b34468436499c040c134951c316900b37025b264c0b2828fdd6f5cde6d08c35182b352562e1a09c7678290945426426d350f2097d045921f1b751f29bb23a36665b9016cd4e5b914043b60b71cf9475b90ddac907a8b427683040a3c86240314c486f7c1be4dcd11a86ec82482cdc7aaf3949b99197effc8e4ba486c2573ea87c3011ad9c673c7127a827e05e1538569814cd5836162c72e1f7c38a14aa56eb9773223c059b4bad6d571b0622edbfb3960c344bf90fbf4fad629f5ed72fb925f5ab60d1f0b8c4f5901c84b3183b321bd7580f6ec92fd6adb821214c1d4b0953802115fbd2686ce598e0a805b40b64e3812c7269e96289514b7b82608dc17a5aa34035b476f6ffb0ce2f91e6e922688809be4c44751f71111c9b3e7b36b4fb6aef340ea7552547c2a12693a5090fd755e53c4e31aae1041623148bacd3a85809df28e178cdbc561b461479f353ce219f5a9da6cd2b74cd8ce2b3306caa8976e20ecf617b5b6de9b8923349d0a2b729450f6db4047dab00506bd6883b82d1aa57ec319b147707ac6c4efae69018785698bc57726138c9320f52e6ad62a4a5f8fe4308f43955967d2e213879effe48ac582105e986e51b074da024d344412dbea4e3f81bd2d563e3e8cc0dbc9bed7ac01c590d770d15a7f6e74796bc161558c9b0939efcedb8e6cd2eefebc8b3100203c714e48c882d9afea3f5d7bed7ab5c90534a6222307dd5baec81e1056744e02dc7322b0e14b947824866d5e57eb3a1feebb22b01b3f9fbdee1e2a2dcff465b969d206a812bdba0f9a5242496b2cb0c49cd4a440335555bce9de2ac41e50752e2918c772d14d9781e6d82777f2a6d3a6fa9e8aa56a4ecd5b94388a75cf1f539cfc6db8023accb29e219c264f21a466f9df9174d20aa3ece01057236f3baa7416fa0e2b9db0026e2fceba903b66c2008534bd993a2768a89f933ef15c56c3fbb3592c90748c7e48e29f939d8d86276eecf64d7897193f4b452e42d1d2b92e9c8615a077a4fe55b9a528107c107b004194cd57e7c8ab3278a61f275f51f5698fcb20b65710c4220e61b863886785fc8ea7d057c9183abfcc90b5d4070315260e3bc99842b7b02396f79d65e2331bf0eae677a917977cc8a1dd25fe0667ecf4bec65057f339a5fcf82c0fd69b6c79b491087aaa5dafd9a3dc0bb9111bdc341c8596ed33b09babe095c0719bdff739b03489296e0246bc9dcfebe6915096418e0a33ae844c5ab5cdfd52ca4e6d56ad3649170051998b65846d0b09abb91fe480fa4534a2223fd6b578ed86b0d4330c49a1beadc8ece3c8923ec3d376aa02d72e6b91a81b1c1ffc270533df8d414c19da34db321de3c809b929ed91d013ee588550e868b25e77847c98054ed249cbda1307d1d436f6b5c91dd36384092698de1028dcec4859526aab8f16783a8b0d6873272e4734265736e614e5af596f56fb2b529055c9b6dad435e5c5c3bb34a9feeee48eb3fe9d690dc23b40ab9768f113d4f6f143d7e4a9fa5cf2b5209959cbac931ad8e9e5bf4ebe152201e77e12b98d44069bc2d5c3b434fd86d31658104e65f2ff6abdccabd58894e50ab04a47c60770f5b4daed650f76263b1c67bc330f14d787b4d089ffbab59f366e06b8930a9e9c1a96e6f4b8679ecb291a8e5a27d9d7e2d28687ee2916024b4ae99163a9d1581ab2032f6f2e3bc416d5569ea06f665286f79e82714b79b65002f128fceb063e5cbf3e920537281a6975afa79d3e604cf89733b3ebc3860670776a89b9947eaee7eaf781ce496261561bdfad3e17574be6bfc347d02bfbdd69b0654e34d735a05e1f26af799ac9e552857135b8ff3c1a3108661f4de26a899c83234ee13bcd07d85e4f537fd1edc4fe555d021b0b9ba6cd00f08307d07d8df5f0ef9975c05921bb951cffed70e853ff91485e4130e839a1c1db596940c135fa38b8c545f8d05b92639488d3cfcdc454b36ffcfc9380bf55fdf4b5c69db930acff6f88d2146a1a7a7b43841c954ad26a82ab535d4cdeb6bf3b45fcb141cda424531d7f9f6c2e4781e3c76dd4957e63dda26aeb74eaac2babeb22acf799e83f1ceb65a5c6662245a01be18cb01ac34b8a3a1d710abdc7144b4d20aacc6fe4869a0d67ef5f7112ab35fd29174298241e526171852999e39c8fd8971de500ee85cc646a113212726803d117f105f4001dc2144fb26e61eb851bb8b0c0c0d163c9cc977a4eb10521cd24177fd1025fbddc3eabc0f3d1aec510e0a1e43afb7c02899e644705719135c7d7d89dd9a6ac2b52166acf14afbc16292a4d7a17872a33edeccff90e698b7a3ba8c24f6996d57b024905cfa7d04e97e1b7d021308af2146e19d2e530bcb1c72f6d69ce4a8d87a3ebc287d839f"
+        ),
+    },
+    SignatureKeyGenVector {
+        cose_alg: -48,
+        seed: &hex!("3aa63e0457032c391437a1fd27215b7876fbd44d92a899f3559e98b1c037bfa1"),
+        pk: &hex!(
+            "0a6b240c14fbf5416896a1da56a816ff16f47c9e6811cc7f019959a4a4c8e49934420406d6f0d01ab4b4f63fc328661963f90cc0c0e6203ea65e9a317b3d2f76101371d30f6a531cb104db29a7f51cc5c37e0281915d5ab33b6bc5b2b781b431cf8c017bb1ab97f677a5e4a185d15b3ab1c61ee24b03e11414ba02dab1e127a811cb18a08480042cf7bc1b6a4e1fe0d166b0586be80603323f063510991c2d23c1e9c9b089076d99ea871ca76b6475271f80be55c44097bcbdc353df49505f9f1c5c68eb6b3095eebc2a12adb655ab8daf55d21acbbed5325369e5a3e01d05e22014d678358988a7f755be2ec53a02f8e47da1944f8bf7802016046ef2f0e4f04681a38ee1ce841db3c33adcb6207200d74a3c68c2595f13403ab26dfb45c6641b6fa8de2e487015d01c73dd77461fb9cc9f6a0fdc8e40cbee787438f3fd3d929a666d0130731e014b244477b4bbbd42c4ccdd95b3fb0dac16b009b6cfecbbcef5ef672e770437585d58174c7598a4b1658018881eb4506693083d2079407ef40424977b0f969b8880e62fb7d40b75631a8360a81fc33fd2ef012cafec189307efda325448ea299353c1793130c0c983b7c0b3410f58fa5286b91ef12a4d1d072f3b93ae7f03c6db3c71d9a7d8cd38f77b5cb32374e85ba5c893bbb43ff88492041649a40b9af5b9e37bd4069facdc802a96a0d39f1582813285d6f7e7674b2ae790b3bec3aa2f64035499443563bf07965872b13efa05c77ed7ed9904d408b177779dd467e1638cb43fb4f957b376f8b12704fde3c0118e0b48524b45778048df0ba229ba615ad486c86a95590d4e0d4007c371da7143553809d8cf7def327e4d846fbac976b69d96bffefc5cce83915260f864f511bdd4baab9b3dda8797ce0415b0d76cff9cfc4f7a4eddf6a408892ba4b816de1e4123178880f80bae5d91e3a7f5f2e84fd197fc7670a6267b3310c5b4071c81bb7b74df7ceff8f44d37ae16bd4ede4afec0e88eea5d2cbb4ef3d7c09cede84945fc2ff9207f803fe402543e7652eabb8db41d07debca3207546f6417e972427850b89ff550716487388849113a29b315d820cca37e7689adb305594be0a84d3c694af3ef1167b3223a6d0db77e59f295f859698cd74f3f22e8850c7e7ab9b009d445da5df06000688170fe8270160f7bd1a041ebb155993aa478da9d67cdc2186285fdabddc3f87e8ee685d7d3ae2f76c5d9dd5d208b8177b513c44fc48179f572a2c7416291deef38e773f3772b7121ce4a81245732492ed40180194e41aa988134be962c10ac1bf22e541415dbb9edaa2b4449465b4242291015753ce4c93d8a8db762847a8e5fa960a7fdeb564d9af00ea2b3014286093e738feb2aafc68a2f3aa49776d039844e95e6fb7e610b9eefe009c9431ca72d2c9a4f81e70e9bb7a764da81c6dca1bc8f8f653541d6904441fa9699d38c81405dd501d614575c1d67be1cea95e11ce3f6837e40e9fa74340ee995337682aff10c9cba55b2ffa9fc852913c04ad8fec1a14d58d106a94f57a9eed3d418a9b23c832a9d993083878402871fa5e424a53a67f62168707ffc60d4ddda5881af83c7dd61f5d9c582b27c606814f6e5cc7de7dbe8965d61631f9e60c4d84ea2fe75d3372d1611939d6ab28ffd9a0e783af6776177f5629ee71002f024886e26d8ca4525b0f90a8149d33a090380de815d6e1edb3d39a00efe0b771a04ddf71a3856c95d58d4423283313dd280219743a40ffca5c44569005e1af22988a38c6daef14ccea16dface6fb3092ba6d7fa7b553d8af0f68ae963adeb077cb7c1adb84c5fc5ad0b6f25964014998d85f7d08298ea5770889"
+        ),
+        sk: &hex!(
+            "0a6b240c14fbf5416896a1da56a816ff16f47c9e6811cc7f019959a4a4c8e499a1ed4fe15f845f51b5de67b22036130848b8c940f288618ec3beec7539d76ea2b6ad9d4ec07cf0f6950a9f21a012a6124582e4a7dce469cec29ec5b542a5de83a66bbf53105aa5b31e5cdffb759d44fd64a30f52b248498e29e4fd3977f981b0db080d02456142b229219831194409da00229ca889c3964c0b416c14914c02b871ca462ea3902044422a12000e5894919b34101308006132688ca2315404051181505c9630d81404a1b24d90b80dcca600941608483888d04250543029a026410207650ca68064a44904842022a590d81011582882c0048a0cc32c21410401468880860913036210244824a6880980694c06721b3730e140819ca88104c62c8a188d80844cd4280659380a61262d108685112111838684d41425a348894ac02c8b801021b70d42042dcb486220912810156d53900d4b922dca32010b461113a10522b9694b00610b3861c080414c883124a46514a32c18c48909876c02866d82044542a65020218ed3a868d4c6048b14715b004224136e101370c21030604090cb006880060c82c80d00302ccb028160b80504376cd44026c2360d0b323111342520c08819a86518b7811007920a29610b325024c3008c389088c805da389222a3401a37281a968d03c88910482ac2104682146553107223a368992611db380aa384841b032acbc02012024103b048ccb44514888493923021936d0849109236849c960041c4701892690384100445225080050a163214402263b8681944281c926018200e9aa889943401cb2225413489e3243041224c9222666396105b4890d490811a292d21951003954403864c4c8861a1c68454b80001918c10420a124709428648592282ca380e60b24053a48988406914238e021411a1a4281c1251929010a0c82d08270de222242011205b4691e02440203852604066ca882c913404cb968804a2450114018c34311ac181c0a890db96818812106004065b402004472c23016a92042ce4c20c48202a4cb249083410083366a1b8259c4800803008cb8284d0a6810c473002c28d02c9880ac764508268141111d83251c2122d1ca32c24490aa4c06d02c9400886411a03291a402d13a56d1a2528c3a0255c1226010624829669d840694940111ab5258ab070dac0408b04720b858c0c100184c8314ab88514252e04c4488830520c234e1ca324214108239705044622148750029521e751fc5a8971d8da738a3646844676a6df2ce07642191bcafb95b80477c04262af057b54cd7492639d630b79bd064a01d2cb015c3f2468c5b2920019550c76bf98e625f1b96e5bb340b22a2d632a8543e5d04b0fbb41926371f81891e1fa200ba24be4752e67b7b9999929fc2f3fecba5155fa343a62725593828c878729823db2fb0563d0120d95cf1ac51d5c3aa8f6e6fc36fbefe15867829d3c3f614fa653bd37bf401778e4ac21c837eeccb16c4602b3bf2f56f1672c0f1fd7ab9bc2f367ad59fc5faa99d4c85a74ab86c2fe066ed9000ba8dc8d48a6f56bb43414dce66db2b17ebc6107c2ee062078b28443e8ce23705a02cdb71b637811e60f69bdf4e221d088f636a2c1c00d279350ba6d431158cfcec443d10fda3d44eb4d1195a8c4524104f799ec21c06f8e2735bfc13f0d77cda022dbb008dbca9011cd53239acabe57af2f30b578e7962a839db6cc2db655491469ccee85493f1cae14f341cf4de2c0b28904f1cd61033babcc9b2b341a15aa64b0fdaff9f41e213f84199def29fab1d2535cc713ca5ff44fbd73284b219bb0ceffd34424a66c62571e9de80f6fd15801e68d0cb493fe8c896b242458579d51aa6878e29c942d7e1453b3c74cc29cf9ed312cc044d10bf2e448c4162c803b07be2576fda08a03d82a812392add05b8aee481ab2db90d826a734b662efa30ec5afb9f0b244bdb772df44262eaba400e51ca7af54d874e59d21759cf96f50a91eeba9195479c17a0dca012611f4810ace2c26f1ff78519706b01dafa56f306f5b2916691309677fc0778fb6386fd8064d9db3a7ec90398388c8ba7a4af29176bce3536d62da466f89c605ec78d915372e21b5bde187452df8baf0d4f42379813a2e5b3cce06cf743451410ee5df6bfd320959edf1fb757d10e495c6bd7a6c6720fe2b2b449e9b80d82559c2f17d564749b33d580f268dbc48e2668ee89068d122946aabfdd8679d6075f56941c1a3a91d0ccd699dd008959a467ff355bb92b44673d232a309071bf1bfa82bddad32cd8ac9967a0b4890ffeed66eeb883a3a714752ece4518f671157f47f75f764b31c04f33ecfbb9b39371ac45d8df137aa7f5bb22bbde3e1da02ebd824302d98535f8efee51d3a4222f03491d2f69d90d5bb2036491ff4097f28ba6e5bba2c0377284cfa727b9695da349b0ea79d905af3dce2af50ddec303432a14987e9b633edc5a01c9a0f7e16f7ef7827cde2b7fe9c0064321a1bbad96a68dc9c06b7564f05377cc4a58d1c5d27c5affc9e6184ec9bf8faa93540179b15b3666b93d71c587e610587fc1304c9ae699caddf9a86addac5ac85af11dd6450380f595ce1f074ceb49f530a780e269b8ff1b2434451f50dba477fe06954812d8a42a71e3c3f6b15efc7d6d64dd3871a6ee5fc4dccce5b8d52504991c095ca97fc1ab56e6eac5bba12b3007b74cfbb8336c79d31354c81bc1df380e0489381e3dcf0674d3422e8b317f2089444940eb8c79991f6b9872b2a1b2f3f90075702158171d56e7294cbfa317f76b492e43039956a2f34b3a92492e1fa3c111e3304ef5cbdc5e35272518d18fb5dd408dc913514d3c06b64b875802736ea3d86f435b807a7d326cba0dfe09cd4e4ac2e75b53dbeb87bb9799456e8fa0ba0e165e03216515e77d3d14af87a42e5be4448351e738a31767ccd47508049746844e83446a7967b2908e31ada29a3d11d1f1c5c3f79a96ae17eb806a9e7cdea61fe363d2f2956ac13b56f70d979d5b070fea385be873dafbd92803205176cdca20e8ebcea574620c547a084f4807da126fc5e53b0ab16d6657c90e7aadbc443a85fe89c788ea7b0ce4e64cf277615d14ea12c55f66a8b3722bd28b6591a699cff3c08fdb9a5ab44f240a4623e4b434cf10bc8d5148811dc68bfda9a1639e1828bfbde05bed2ccbd20725c0d0d0f272456c07481c71c9db4f55a41d4f2f7c4b63f4c681713e1cfe88df463365adef51be99c2491d0f85b645f1559e33d968a48150991ad470b18c40e6f34193fd3cc8aa8e8d9d9349207ca5661fa36b99acd17cb0ec344ea730007f5fb2a646e8f02fec5fc646fd60ce5199d9af77cb39a58da83d86c1f59117695628319c3cfe16cc4701d9c33276a26aa1e83fe5bfab95c0d33a6e370a8f32112f1bd9e3e5513385bd615fa153de8a29c257cb06e89833f0f205be425a266490e88462dfca83acdf20cc3cbb07269b8d833074d3ff5da99c76445cffa413912f964a6bd0e229745ba8683bcf41a6e36c31a50bacc4cf367512b5ceee17bc47a7323d625875eca795d63813b4841123f49b6dcebbf3098e41265a7c3f1864f0cff107257257948"
+        ),
+    },
+];
+
+// Self-generated ML-DSA-65 key generation cases, produced with OpenSSL from the seeds
+// SHA-256("embedded-cal ML-DSA-65 keyGen 1") and "… 2"; like the interoperability vectors above,
+// these only show agreement with OpenSSL.
+pub const INTEROP_MLDSA65_KEYGEN: &[SignatureKeyGenVector] = &[
+    SignatureKeyGenVector {
+        cose_alg: -49,
+        seed: &hex!("a750a8e8d4c91d6acc297725aad1f79026eb186cd3c27da4a7a8f5d64fb8ce09"),
+        pk: &hex!(
+            "a6cb83a24c182b80657e3333e2b005f04925d593d43904df8088092e8bbba20374b97b44cf54c73e4e452eeff25924be3cabb9dc91ba14b15b5f9e9e22a0583d4341102c5c6ac60d79b7cd277cb67fa585ba6f98e74d3465e673cb42a960d570086ab8a4edfbe0945d3cc0e100f56019c654c88f08f35dedf30dcae0c7b0d7fd2b499504f87c8fa749f6a90de6f9a5fa6c81c23948bad3de48bdffe49d34b6dedaca457392db2a7dc67e040f1de20c89a7b1a39361d1214b3098fdd9dcde60f128e0f0f6c2336ddeb87257bc53c164f35c5870e18343ce89c37c9bf0804af34c171b54ea049973f5b810b3c4160f1f58c8426fd1218711ae99a358a0c19bcda146390f27f73615d0117ca48b54c19ef9295df562231354ba38cf8c9edd946a7a0977eeb374f448f3aa8c3e48390d0f3e4e8c0b9a8d08447d2850d73b879d794208cce45e0f83967273277a3d38aec7b08c564c93ca0cfde2599c1c57d52dd76619cd3ae91ea926ea90d43276b4613f237543ae26bb388f7ad437996d1d6f13a3ae5b780f4ac6e3876db6b2caa405d967b7e68dd61d566f83e2278e7547b763c8022b190dd2fc651c20c11cb613b47d849a5f57b79c534362dc7dcdcd08c2844744880a19e6a5249a9196dac4c75c4471a53caf0909e95c3d0402747a53df0ada7a7e678eea3da4561cbd36fb1f8369967b22df91428794f70d6a660508a1f22d8673188493c0f8cfc87344f07c0a48bd3bc07b4e72132dec3c720a9621eda4845d75ad40b4fc0022aeee3f2e68e2f8c6febe361d900d583fd644323ce83b08aad8fede23e351c248395e3a2e65f7a587cb9a05439bad9f3aeeb1998aa6b76b702820add2b993dd009e646b07013fd6e8289a573e667cdc7ea9d0c228d77071101d78eea9b1deb2c6f7c50e1936c83a811fe9effe419a2cc71c318515800c8ba18d7fb49acdeab36aa949302b84f7d23bbbc3233d6f57066919b728fc35000291d74220fc45f9ea5c02d08196bf1ab6af74fec02f7a3698362ad78c1be7a58c40cbed7cc8003716d491a124b1788f5e71f7169128091037751ad40714690e33ef3d6b89097ca60b4f07f7df32c4912203b280136dcc97040fe3aabcea8e190c259c53d6275cbfae055959e6365e2987daaf1b31377a6a42692da83327fd559780f5f016b591fe1df63b33e38541688d5a308c1474c0841f1d31c5bf3ef6bdb7cadddd2eb048d03ee3fed5bde0d6676ae263125aaa9ebc878912990c93265f9577e3a6d21ecffeb01f9bdf68b4cc27196529a9746082085eaad142d421579364d51adbd57e98f1b464f6b1bff7e0c9ee176f8039c59022b5e2f41a35bde6215225ba691c6c238edd0f9f2b0761eec70039f257ac3b8f44e42eeb46bdf96ce68e37ca3843ec64abdae4e56fbefba369e73727f64e03968008b0081af34d3d4d0223998e3c616a943c7d23ed01039d9f6fd73abeeb4b3c809658346288bc784130d0a3907edf1efcd01586849bf56b57cde14d0b5c49dc24e8aab99279f5dfa2983c340704c78a077e8054d9cb77d615ae51fb63bba3b350b3de7d3362e4e76f36ea5632b30e2bbafd26a104b5885f339fd40bd2b9a2d75ac3ecc09438ffaa3d99ec603c25f00dd9f9fad2417f1bdc8af30df411c1aae2ad7cf6e5f69ce94c93acdbd3164b6dae925775c5ea679433b233d6c4f5ae33bfaf3fcf147c7f31dbf6bb8ad0ed6d7df316939fb6fa0e9d2126a3e4e1567bf25b7bac8d13a970d0a3d95b9b8139fb8d7cbc3e69805aa73dd0e2ecc77988bdebe16e0fef294cebb28a7896830354b6cca9affa932da4b41d13d966416232e4610b5cba40032d27313029aa33ac27c148c0c8a9d9ea7aedd131eda984aef1f740ae5218fac88b3c19ad90e806d5106b01fe4d38795bb727efe4cace7fbfc82d7de0574e169be2d947c8c6eaa3672fe2ea3c9a8aa198aa2f4470e740b26fc5edafba2df2901c8dc918efe3a51ef8ebfb44eb8fd8013cd6f2c479a31adfdf370bff3b502afb6d5f31ca9d36d451d0fc456bb2bbebc44cc9286d55e05b9d36c39c1e93c6b55f4a31e51f609e76d9d9f62f8857d81fa79d4760eacc60e08327591c81c479dd719cc948261c6eabe2f8c2c8f8ff12ee07cef2c956de50ba3ef42816695e77ca08010d7b09236402cb978ae945b6e8456ff305f985c35fadf3df23445460b38a16d41974b85a8f8183deb864db0394be07f971be0b9fb22784778fec7564bd7c9bfbe2a5a812f83c96797c27fa30c38ca4f67a75ab79b71bbd90130049672a7186e00c0dd13f7b4c3b0b6ec452f47e7ad4a04ebe385843ef4c5ce4f1bb87a2af859da70fef023c736bfaacbb19dbc676b12b62a0ba22b956ac0a610016bda0b6bc71c32e3c46da9f309ffd1215eef88823cd460c4df61cca42d2ed34af72d7c912416454b949c7611185e78c32d8df79ecec78a16445645598c08bc9dc5d9aee1834b3f2fa480cdb0c8b6458884b62f7b0bff3efbe084f567cd96e337e5320747ac3970af9631381f2ccb7c0a1b2a3cc4ba41e32a05a65aeabebe8480467aa461a7540159a0ce3ce6e1a18a50354fa6a6e4e1c232c93bd3ee8382087526f79fc300045aafe0a79cc6a9d4f26197d2fb53e3d32445bf56b2c996a9fc4d53a3b68cad5b6994ed3d9804c70d523b33f40e142e4e31b8cc1699e3160aa366f7651a5aaad921938df8889c166b4f6e30dd7d3472c81a0d17929b9d55a1229dea470051d3213013c028e528d"
+        ),
+        sk: &hex!(
+            "a6cb83a24c182b80657e3333e2b005f04925d593d43904df8088092e8bbba203c219d387836c39b7351667e66a49dc1ea024f2aea0e841ffe4fd9ce9a888b7376143e836e14b3ae55a639ae1df4ddc95b0db1e4c7d386609ce68fcd0aa2019b33c4913331f4fe31d98e070763dfa28c2b447aa201a29a8569db0562a377fadff11273736464403407072336660443171823200020846807466634184242850680715034308168736516478247377447842412526506755383446333503720565256021331523713581172074257861668333225148664115148302425084644157186800620414813226131236534570454766131084266833675728303732051604137570433476180582216747627233653038107066053701838102870372140132812342302665458760171866511440737538831274550733146121353237711075583153876003261714852256312837338077682157642351082480083507110152132835473638563002615073336475678450477273711220405640784070724800280238138436471684706301124358436650658810210128626633508300588046223266633505508705627723846264220876864531818388738180538660835563773627832323248707288013671738060705806434448108013713251750638065003180283538435363638333583576578653241184457106180404058044236774683821804838815773482475148575155424341030101502043711378638652223248344581386744078148252115400612341602660883423038000853717185458034502181746081460820408720642045364050157473317541173327062182433437481685782753754661881842472585376127766823378812475882108724516672734030011327506214363383313577527048412824265113804768044556021048168642664663724614645562324222686008008287165260338734486564312758858675066167452786661361262235746413324675640502175880758784256345230565868728777800187084403587278726137803812836422582466604322466812206364221325037586632174632645888434335140081118323732155360734361123683520488874083316835741730586008130675717672606311281125462545730267358625554510785723343654881342244687333260800260413280700827825153658142816055047130646610030606678680485160847500586531030571666225601203714800160174711265447780125084715002474120483545802301613446635846414361158277546824142402036012051287610407658118345002887502340356033850667458646355822388758061236741300560364810305682183618573585706214257378782443480330584284441363313175870153305643230878156340638674465216484242606007136754104140254016274624837287086853533733136045320511456184012315666266760375724285241525357284701702607360833858772882512231534250427656750783757200338848058358640054676112781788528135232712835738821152575533232426443185467678567230241427022126860578101070318830432223066382738772251311571427284237162444151581682776052420528168331442844154712602604745424084341888354144852453066767278830756823544756214572430370260147831344254145341400715008570281680530411075075350710534312034436367258347381011006774200218387711286385863215736878482640531125371415631818415140548317162874810783314601418113822042864848238121008876013161483111484382347888703301233241414208240332865478662682384772031550074562312675157858504568376370180144412862575340660326556844826738723186152151765728880366048075768450713143838487177081574716371301053307707274675805332010584612554014061506101770661238856082304150584752413435270347885414084332132684561481c623bd5c9c7966b9c3234bcbb632c08c1eb33846b41439fadf08bc2c9ec6540cb13dc24c872c6288d8ce1664625127ea10b9543c85c3e957978611293872ed83ce2e00caa6ded11beeb6b60fa3edea4f71f1ac21dfb9efbdf7e39e8ac6dfae8db0515f914274f9430bebd8aaa926d5c3a177006e7bdf0f4e3228bd06f2866330517289307e311154759fecb05576c9c8b2ed22787a535e5dfd8006f4d77df48c50c6313414a744a393c15a4ac8dabd7c18e817d584547a03547d8bd77e2e1cd613f54d9bef4d3f46c6565b2fc4a7a1df998f577ce2a203f09f673f2203d104531b4cb9ac37f0be48f91a94d6c873afb9a11d3d3aa9a358e406bcddcf92495092f6d5132ad29a76cf01a569db94f950f0f3c0a62332749fcda18b03964a4581f411739cecf53e84c0202bf4a2d281d54d0d54bab15d48c6ca515d2fe065b0483c976a9904d047369b576cb547b36c82a9c4c2c32f8151a33dc46932243c2d1c5f92203a8a7c3426f8b9fc022c6f0e6e3ffae116f23c7fab7fbd4babe27b8334fbf747a9d21014161ce2b0d94d0632d6dde6eda40f295aa0e52e390fe80fc4f78354e8f2b73463081cad062920f77eead5d77396b9f8bf4b32472860f878db3a7b8eccfd139a0629f37ca569fd9f1980631da7d48d46e92f6e5bfa5c382cf1030471f9f195c7f76ac7c6056f650263fe12cc42f6fc58c635a0a9910da08867ff8570b5c9aeb5ca8ad9ae9b286ed790f5df6f4342fdd3b8ceb518ec11a06e204f7ff6daf067f5d0a5b316d02f791bc3bc525c396c0760abd4212bc4db567113070949515c8f155dd8337767c5b8f7c910124c4a2d995f1888dba5f04100eaa3695d725dcf4dda4e6b486cbcfd2605411ac19b9453f6c65790a03cbddf346057839762cf3a49967eebdfb7425c2ef7584277345ed519ccf0147c5a0c8821618308d278739f0d5d0cec8d7320237757ed36eb9908faa0dad9d3a39abe616712e37f7a273df4c53b2b4f51876e992f02a43a26797ffc39e913e0a6fcaf9b3f40462d406f70514a2af48cc30968c2f1c707e06aec1fb0c0bb1423ac6a0a412ef79f41a7fa17a623a452f2411449ef97d9c87cb661cde43df3c1531eec1f4086f21ed98a2ff754d83d723a133c2dd1a5aa05fb97ba9e4768793c8ac5530fec809d7a1cecb5e50aab85c4991342116d22509b67072559d1b725b62f69e8aa0e2d25c1a9f70f6da5c19928369eac5dd4aabfe75cefd65ab8ccb209f0e7911d75de6e2ecce6ad05740c6f397fcc47163f517738cb5df2ba3bc624001ab1c9323ae7f2f11defdb0b7be60016928f894a5e2d918e41f620e2b97b196c24016bb96ed687819e8e06ff48f5b9db9e6df2f0009a251955476937fb7cfe47f640e1e2339406a0d09765a726c8ef7be8b868ce97ed72d1efeef0e12ff14eafb8e581b94c7d0b65072946245e83503309615bd8fc10b629eb78459579ed2c3f047150d73aea6cb3de916d24667d890f24c09db67b272141d822d70267d62044ee880a48b4b73fae03b2892fae529532dc5894aaa201c90fa80d5c23fcc3c3611b12c53b2074bbc410cd8f5a6d2bb6910b1fa2a8c482c1fac42c0eca9d7b181f759058b63fba45bae360b92ac18cee998d3fd38d9d19b9a82ff23eb8c5f6c84703900328e6fb59668d8cdc39f9bd645a88b470070ac7e0c58b46f5ad9560bf22e0eee0dab3fe9db1c2083a8ed06b7e2f209726e2dbd41fff7271067b70382a55548d4a1955b11828f3610ca47f7d59d9222691e135200e43bc0c2eb04142fd1911fb50d8d33a17a7d949165929b5bfd6a401b6550ae2c796dd842b4be59453a26c69bebc287f882230262134166c05f9d6c7569c4f98d84dab0fe7ef3621fc7386388ca9bdcbd58d964beee227e70b1b16bec47bd9757825f72028c320ea186a890754ba3ec6d16b4d5504133b5aa38871327a5a61ea2b5c913d80c36c47461d8b1eed096d6c9edab1522dc3589e20125132382929e3cfcc67f561fd2c51a1e91b3047fe29eae7d6304f37aed56d85207e0ddcae3f8a32faccb07fa3c52643f05bdac2d610e459b205236d66e99596f03f0f20578801ad4ce457d6ce8c2fc0f6ad54c1d31bafd74f0f7b931d7b0fa8d696a2ddd56ff3aad967fe51bb49f27c19c454092921a8dedb698cb9e55c3155f52818726b3d9f5276a486c8d0c2e44a88cbe7d4600874a94120e9ea7e601a75d7dc0c85f0a92e08f17fb3bfc6ccf4c42a36c33f4eafe08fa52254aa5e6fe86e8309bab6ae9ebc1f15cc0a69e577ce67a788ce0c7357ed7f11da72ef5f5d9110beee19c067b256892c61c89af011804880d3b228c57dbbc989a368999a584e71cd857b6791a0034ee6db83fb8bfd03e32849d8c48c141918100b5daa5c98455461d18567a704b9e653154294c1c92a8b84b1dd09cb68cfd557ff8747430fbf07ec8c23f5d7a802007000d8e97791cdef5fa3a65810f181f5eada3a75d6453edb5c79abf022a75b01e572d08621ef5e0f1fd93113d49f8bf7abb9ae3c2c33bcd161169b2792161444c106bc90003fc09e2c5493e294f69c9c8925cbeca5b7b6387081036dd701f47343b9430a288a4662d31b6e1c712f3ae0a319266f04c79a0bb90a301eeae63db1d5731363b555d0f2ffcd223d81546cfc5cfa7dcdf38d86b29d13c092fe1e08130c77cf32a6b04b9c9e14c3cffab5aac9682a5bc9a198fcd04fc172d3ba638a71f0da2284995c4d552864564012a138938acad64a106b7526fa6b5381be5bb1e049206c734e144e82cfb293de6501e10cbcd3c48ed69cdaa4323ec9960f3e899f66a23ed9a9e9789ea6699b7a699eb101d560656682866c22fec54a9626247c177ad014a72fd4dca7632c9731ed6676c9ecb54c7725da58ff600ca1fc17e4d323ed737d572f6dd3fd669b59c415695175584b637568b83f536e0f91ad5ef0d164c2d7872c03fdddc3ec04d167d4591568f62a031527a1c20dae09b41b3d1728599c9062148027e41ff0a3a4fd35aa51c8bca34903fbedb6c5847fe0377ba5e9a94fd4f9cbb0a7820281401b1de925ded4957bcacf9d33d49c6faf03da79b09641527e77ec140c75ee1d4e8f3a48dca026ac9f140f5ba2b83984e1a21a63d3b106f15a432e3d4d995456c71ce39770104448c109ce13d25360a0832e0c8a3c4b2f1847d3de5bf927740eadbf5e4e607c53cfb1e1d1d303f288e3e9d756213487d2d3ae412f5fbca30c0a0a645a9e8917ab012d15e28b5d76e8ec5e5a54f45590d412a48b4f5f993d0310eb2d981effce9e3ab7e4d7687d792fe234171736a5c9a07e56c6107709938893a5294e5af8ad1fc9052776be3641d99061c68cd9aa38ec4c564ae44040d53af882e569b482fd6d655f9219c927321431118cbd213e72223a4a9e54821e4de0e45c6f6f8f96e55c84d58583a659759d54bf086ff0a73726d8d346cebfc7333dc670df15ee15476cdf5d34395f0411ee1e661b2c5a30b6b236a9ee4a9d2c7fa8b872d565fd93278204fec8"
+        ),
+    },
+    SignatureKeyGenVector {
+        cose_alg: -49,
+        seed: &hex!("947ea60be5be3219c308cdde4f3f0f30774606530c55878283e95df57dafb0bb"),
+        pk: &hex!(
+            "96b1b6ee256c0136c0fc5141a48cb8fb4659c1e086f66ade12389d76a5256fbf2af1bd97906ad3ecbfd77dfee8f824c8f2e49b73047e01be70d4c7a365cfaeacc4e426e0dd60bb156b25831ceb7345671d55333bd26e3a93aeb4fb0deaf15aa7ab28e2b582d336d03804809efbd1246879de4bd0db662c74dbff02fe2f72a167685418f154cc99e9038f42b454d4c68ac2b038f948fba1d8c91934f766374d58712c5998579cda6f0fc53571dee4fc3cc211c9af2e590f9000e9975804d4ff382ebef652b17625e0b75328f2cbd0c9f722234dcae8852e9541ae4ec0268f200531c9e5203fb6ac92b1815a5bb3fa4d9b9b354d5354c9dbf30d3d257229c52bb3f3f26ae8fb1b63d2aae28fd396b32367622985d6968dd4affbefc6442f729de36ffec895074343bb656defedcd4a9650370f21c27464632aa1ebfca4183f0438667cfb8f6fc6812a818b23f6bfe5d1aa8af8d2deacb8b76a45d3a2ba178a036c68e451e2d3ada6ecc6c79638d25b9f5d87a0c2799a9c91555040d76a0d1af01313b60edbd615e5bf035efa4c4bc00d9d31ff8c76ecac589da8c2ef046ddb40b9a8312a2990844289f822e0e66a952f6c04b6970c15a256617ad99b92ab18ea5bf2e0cf7b5555297275cae92ed70de922e2727c3df0a3f773a5a203ae541b9df2a485c5d4eb7a5c0b982bb28a7f12bb0c91c46435a266b022993a38f8c835e8d190aeda38ca74012a49107b5554741949d0bd8e702e47a6204a7f2bc1e01f252adbc86cf8bba562921b7531df3d59821465d8acb803ca8a34ec834d878edf9347e1866f465894651cb37283067389e4cb5552531ff1270b26af59f57a980fc4389b824377c6c257ef38903b744b44f260fce0cf2e809ab82acc9222f9cb5d4dd459a9cb51ed9190858be9404af4d5658cd9dd8d09011ac3a691f1f403e7f3b83ca376af3c3139c404c83302cb108296568d5bfa44e4c2f6e1d480684faf08f217222b646eebf1222a76548eb9d9deed1d9a7aaf591a60841c6a02b952a2f08c664ae6d4aa430a7aa919a4e52bf032745882ac5c3c7f5094633a30ce990bde7a2c3b1f938e5b609f0c2d3b18c5767e32d6455362e9d58fde900aefb3d71f3da4bdbe58c2e9fdb00f0fc671bd319f1d8e6aa13f3c0b9ba429b918fe34c330053bfec6bb7af08fab2f5c71c4a6b517f32ad62801996f3771d846930b27abebfac637b6f5b96bcb29883a03c66298e65a1dc45f8623ef431cf9ca59d3ff48a82a6a13b28ff89451d7f28fbc36fb17d0ab8fc66655e162afd93df1e229a59d5a998d286fe9a27f25e7fdb9860d3a38332e4fa082b8cada406067479f07ea4a4a55126695a178a80e9a69675c6ab949e78a91acb834b5853681f954862a0b0076ebc29a28071f62ffd4c2edb77e4456b87dd554aafb28f770e12f6b7741510cb1394f893bbb586677f6bc57dbdb010955071d5c023e73b1a0b613d27a03d476fece253b74b9b59b4b6be9999e18bf3935f5cfbb30d8d1cded36ca98d7fe97fe2b3cd3235bcf87929d24075abe4025b3476ba3f0347645005a162cc94b83c2ea5eb5ceb7bd66fb6f3e738dca56559cb58b121bf5ba9d80c00a1da18c8e0212e02c763c47da5cbd02c043b34b0bd123f3bef65eec1138bdfef4ba15d34b1b98fb031170f344ec554aaa3a6c41fe611793610e402a89e7065734a0477d940b15d0a9a7f5899554ef6771cb9f92615b751309b5d648f12515b37e9d483b4cdc37ddd58da9040f1b0c9ef7174c080851921da6ae4e7abb0a5aa537ea177121f6449dffb8c6c4cf837ffdf31ab2b3e4fcb335c89b8df2f230739abd340358d512e5942192a1b07331bf8e07ffc52d5f0c0c89dfaf3ba206b7ce21a55be8da79705fdc1232b86bbf122feda7390286dc46f31f13104b69292b69c2dfd125ce9ed69a7e37a1cd235233d93e75a4ceb81e327ce6850b5fa1bee0fff4dea11534b061932a0150a3158b043d33aa2587bfd943ff979b7ef3524a896a7253e23d368c7ac2b00f8a23d0bc98e791ff3c49a335b4aef70e5334fa9dfa5f3bbd5288c52a4f2a4bda328a54eeac0d95b4bcab9d1c8141bb2b651302f84d3bd55e8fcb980fb7e3760169679c5aa81fc4022dfde45e8bd5ac95fe72ca562836f06005de2ba8f91bc391e31a1739268566fba6e6cd51fc0ac683ec8775fe930ad58ac79f3c31fd71be169c11ff86a76e622cdc73ffb6954688c6f4bc360c7c832689b7b8620a5e926ebbe4e863107c0611800ef55dcfea2cc45210e5d34d1f3fc0dcda1d70be823d44b92471b63c95e5d9d8063b42bf5f1353fc86742121463a4ddb02ca855285f1525f19270e4c310ea4f21ec2f2f3fdfb31d278efb50950cc9f5bbfaeba6f29d18999553953bc60ba98269789271e512c50619c99749818b1f0cfef850af073532eed0e11e9c69119b55994c71f2fded940082dd0c735915d326f63d581da3ba79ec2510f1f5195887409473758158ab33c3bc6fcb17c2edeabe9f98557261bdd150b1c8ec5230a6759353a765c70d4b000c6169780aabd53320af7c642ee89a780513696ecb1e5a4a717ccc3533a1973c07002e91fb5476d7862e6b2106cd56a9c0c3e5cce1868fb96aa512686e0543e161d9c30baab4787a91d92897e4ed4fe40c7ef27f65a946392dbb28444ebf5566b214f73632e123edb708f0fdfc30832b85232f3782b367445d6e335b466641a74806d91bb17db42bef1fe57d78b4d1a3eb5fd7e0f"
+        ),
+        sk: &hex!(
+            "96b1b6ee256c0136c0fc5141a48cb8fb4659c1e086f66ade12389d76a5256fbf3401b2f8eb28582dd95d9ecd0c06054d1040ac2c52cc317c3990eee2996e80053b98ad821bf0a6369e7f0d9e41f774aa8cfcfedf94b678f2558e412656bf57ef841762f8f925a8a88c9c1ea9e9700bb9aa2c07424e1dd18124253e370d25885f50675121102124312038544552761276583265347812221438405663022666446056356575857253574633824666235837208384503817303785060387640453237077247303660748250424316434278004408217657310730557778530002485714030730128683786834025768218523027463248370110670014582408486547610346278026784485735230581701707302277685060100461587006551431126623358188381362355130347120634050016403057808080535528413685050233801480707827214433888123333286046716060544552772864433015288500265800310460817185484218858086184584688613247263243187480734263370506456635756608110556224515465754307172231810616856008242517320315847215100125775251231437326715662280143573314655353727852620007366703457253468780512035483605823771517753353013440566336184316588448875582561177765133081756286176638747707054836807718864306761106642451238460714482062300823616203700388758481451350448032301286628572623543704486601385141505848886745833080607416867770771415656477186101800774631462486020623882332188451122562178075812266383080201027064267768826847544735452820735822834446280327010817626226682267567868200242120187865065825333278532472014262827128188700616001402711353762852652581858075163836153871807545514772262828788545278505601311376323031841366578172215572808188748371350401087755211037552001435830432332753818762116532332485005870878616004485286582566103553708676374568150255764426365562284801740056060420477248055786438756461460847682046058201765834327552576044276384141348713563065842510877815110504365255677878181234805303660238170280753566751127118262130541064787438662844344523568275844368046388747206845836573640844660864581776131576203166841670151285537576886016137627467116316142887862068764701543312201838167680734448687218746170785471183636343123047207288371567646112804507032712278240726220265282030686165727302478234544266481204441040582882011470635375720471861586807568701247872656365137772830610126362776572631337448232161542818668844802426808787850363446101730474382132380110151506638513806466743320704673261562087201831748425342187655444321543247635368430288743872224031257020551785562341874775635255465717430401446618827660045727676550066033314687815760027823428323250584465158471760307672205516476618127632363226686157342320714544323066723558515367004084180382424485731565584180326708532702846124685326753047357820016740118731138401057744367814038625365728370315082521237555048015054714131634546010780137086134642203112761524146000633426488311808184864305676820732142744833440871252830674623625710027281468835131634373134641077454887155336560014651778057418007485371330265101076804630008107264435838368873682387356614568663466274027774247846533124883265805254761840585547231550704206651015701761201573544848675732856766311243255661175571024344545157307215468266231813815030007567665666402184557811514175431572083518203344067107567587817863176192adb1ffd0634043ac76522438e8ed1771c60b34a3ca585363aae005385fbe0879e0c60c3bb596f2d350bc72bc4f18149816c2717de7b581addeee46605c67bb58af3af04a1ecc706ca9f9e20aaf03ab418e49cdfda1a6b93981f869c530a98400e0381fb9a7644990c8ad74fbbbb8336b26fa4d057a1b00ea50f088f0caab023bb991589b814d93ccbdff28e53ede7e494b3c0c6bae8c26786b498b56d42245db5b755635bdcf7437cf2799e87ad2358eb1f8c8bb345d48a117a1763c59974277202b3b707ebd6adf46efc5fd6501931b6b7b8afa08adef8f06bbf69c1d9271723bd7e059c4058839491c4d66fda8753dcba7179af304bc272e43a6d16a21151b7f8f863bb146f694b1f9867cccb549794c9854d4b0dfa397b2984779873b65ed9e2ff22b8c8498305cb5bd7ab70d4c0de469def8a7c8270fcece88b40bfea97fed8caae6415eba207e2aa6778969d24471c76cd03db91e848c592c6eee579ecde7401f0a75548686a5ccbd216d8769d4ba1fe8c208c41ad7ea2720fb851e4c36d64043f1c0af865ee6487fc4a565be2ba9b764171662ef93ce312335143fa9eef18df1166a170300fe64b42943e0512390819a3abfe425e43fe856583e82c1ed49ee37c15c17148a3bbdb5c327af728d48a38f5dd9ac23a6d38d5fcbbccf740c71208a5df323d91efa79e20830bff065c9c38c913dc9529c5ff31c6d48a3afc48f983c8cfb1e1904a997379d2436de06b2a1838205d3ae970cb6008ef365587611e06b4c4b9c9730be316fac46c489458e8acaff0e5fae54d02c14a56d19b7bd87e47fe06eb7e81b8ea9fdee5b4f1c860ff85374cf55883d4b83874609118a846e1da8dde2c3ab6523be418bb3d7e096271872ae9105c8ee43989e80036fbf2098c76be7009dfae2a9cf7ffec3fedfb3de10b12db0cd3aa5f1fbb2dd031c444996ba398b312cd59ab0b1db468563e4f5340f23d522d2c4abeea9f2fd865d03d2bdd638f3e8b970ced86a37177bc850c278c61262c65196b1b8a6bda7894d8595ce30c5d6e33503c4938babfbfe61eab07ee07713722111ab73a09298ee37c863639265bb4c25e57de6680df07fe1e5ccd73f644794d5f570bd8de83ef85ab440f3a07714b806076f6ca76876e81d1d400a0ca836ce6d6a59fee6c83f740d8e440c05d0fd8f1740b3eb84fe8edb0f27fd7d5a49eb3ba17a503e53f53a4461548ec5e17840edda1d1d38ed3c5a02e5df3ec129767fc9a170d52d5e554fd8ec3d10ed2a2b6b9cefdebd34af793389056fb031af2695d8c716d0dd6f2cddb228ab9a0b552f84366127a1a4e581c6b83829a19cd23f5c6e2f509f44003a3ecc8c29c59ae8075c3167bcb0dd2f7c3d1e72a0dbf6505f3dcda20933decf4c5b4050ba302094f07f8d08abdcc5ff184a1b3c81778ad3635ae309992ce6ab2ad1b0716d017da99c13255e17810b75e8ccfdcb25e74a484e66fb7adca9b24e3e5097c45ae5b15c2d96f9868073f8ca6340333c1ad672e3c41e488ae54f885170752c85711e4d947f71d801a5256525ba14ded0b893d03b9b9100ffa4a6a257519177e53dec4a38be56169ac954478e38bca79b501514543150d7c8a2761bbc0e6e670e7584066810c4987b5bc2d006265bd186a4825dcc5d33a44c0ea34d9d04283f2ab004d4dff822aac60a109737f10e48d4126af7dabd1c5b2dfa7c0bd39f8163dfb51bca051f071a13c1a4feb52210e7aaba985080e266f4111276d50002b371f51d364a8dfee873c259fbc919bcf9519b855c43651d73294fe5276fe48a1b76d4f31ae9ae541aa013348daca81e2ed6c62dde10d93d6ea506509ca152a00ea36bf58f7502780dc3e00cb73471a946844f67114feaeda462f195b9cf8bf92f9d00394716a302812826aa0b4573423474f6ff744cbdc91263ad661889af868f0afb3ebeaac282f058db5cd9a5e0d9fa339a12c3e13546d7afc9f350461162e8e86f995a90e57e37f9463be63b11608cd696fba741f4e16758fbba7e9075076f0630fa5b0d25d97b11debb3b8128dfe7f51dfd244c5ffc4818cbd9ffe16f6dbd552e4bc96731f057bcf3e87891b3213fd6fddd30bc539747da3322adb51a007878c9e7772ae0caa934527f3781b8e1ba29ec3e4703468d266a41679e3ec7201efc0c392992aaba75e7e94bc5841b1c44247480a43b0beb2f2ae86339f15493a323b2e2c870edb0809842a5fb645ad20e45919f59afdeb916af9ac63ef35b5e9ba4f00eb7eb1889fbeee298f6b2b6394414ca6e04ea9e3ca1f23bc0541fe26bc12453a05e1bbf814465e4647be0b4f984b90c31bdebd34534f90c2f8adb9d156bb25a1d1be4d3978ffa4773dc82462d4b3de9eb4a0b9c27d1758967c0af477420cba68f04827da26b42be7b2a286d7c533b4ea760c37d5f0c402fd67b53920c8523086a13e19221713f12b8e9dd5e6f42e6e3316d79d23b18dd73feee1adcb3e5ea2dae4f62bb7d44e7756f87c18c28c95de897c58487e1b07a79d950135cffcb99c0cafaf7110bd19665cb2904853ee4a38b369476d0ee3c983836b87b768c0ab3414dd5eee72e9bfd49a126270c19e1e7a38a1445fc6496d4f7327a657ff13b1d4d3a7c6d8893f462ab19ebf71f36d8349e4322aa3bd2c279d50cf0b8420b66f1cec317cb4affb9ff1d989772c0c747797f4a2dbf697342c8d093f87bdc7333d382b695902cab4387c9d55d8279f255a7e1cb318cd07a9cec888b0f474d307d99018355bd823370fa2df3e5f910b240bbc25f86944018ad0ccf74477bf03bc4dae77ea39b79914b06fc90945287b234ff1a829dffaee99b306f4165409f4f844c5be966118e880ded196618285c0bb017105ce3f3f4ebc164cd1c1ebb1bdf9f70f94e29e134130b21466265c7c30b9f42a0cd2504506a762f611bbe0aed28eb1815d51f1b047c1a9d697780ba77a9a8b8d9213efe33aa5e47a5fab97aa02229a2b580e434d62f219584c69128d3097b060754d7d4671abf5c74a12a1fea2ec58f174df3aaf73aaa3f813505172d76b030e9ea674e58d8952b365fe99c3a2aba6e85b5109424ad7b758705b799544c40c740a15b4d7ccf07f2ea7488b30d9a8c23fa5f311fcd1c3e7b51824e74630ea4e3b16276cc91f36d17fc649c5dffe942a5c338e1c06504951bee54ebaa31d0b2365a601773a76635abbcb4a66a0c96e8876e6f26f6566524c6b3634e4e7958f81f78f6735be83bb4f6c2748953a02b75f1cf1fe0c855de694a8f7c3349a26f2bf2dad5c80636f1b05b4ef2e001eb30b1c7f88c21c23a4c3d343f482515c2bb0458aceabd90bb996453291534505816c412b797002b39f0ae3518375f603f1b8b250415467c1a7f8e304500b2c360329ba1b0a25acdf8c57511cd8f1f8e2e009422120060822a3a3358839853ac86d78369bcac12cfd7e33bbe4d7b55c560aaa09250766f58d5e7a9a3dbfe2eef9825bbc6c694c497737025fcf713adf2cb4d36713ecc9110963ce16312e5c2ec6227959c2cf"
+        ),
+    },
+];
+
+/// An ML-DSA verification case, in the shape of the FIPS 204 ACVP "sigVer" tests.
+///
+/// Only cases of the external interface for pure ML-DSA with an empty context are expressed, as
+/// that is how COSE uses ML-DSA.
+pub struct SignatureVerifyVector {
+    cose_alg: i8,
+    pk: &'static [u8],
+    message: &'static [u8],
+    signature: &'static [u8],
+    /// Whether the signature is valid.
+    test_passed: bool,
+}
+
+impl SignatureVerifyVector {
+    /// Runs the test vector by the Cal implementation.
+    ///
+    /// Panics if the algorithm is not supported, the public key is not accepted, or verification
+    /// does not come to the expected result.
+    pub fn test_with<C: embedded_cal::Cal>(&self, cal: &mut C) {
+        use embedded_cal::{SignatureAlgorithm, SignatureProvider};
+
+        let cal = cal.signature();
+
+        let alg =
+            <C::SignatureProvider as SignatureProvider>::Algorithm::from_cose_number(self.cose_alg)
+                .expect("algorithm not supported by CAL");
+        let public = cal
+            .import_publickey_bytes(alg, self.pk)
+            .expect("failed to load public key");
+        assert_eq!(
+            cal.verify(&public, self.message, self.signature).is_ok(),
+            self.test_passed,
+            "verification result differs"
+        );
+    }
+}
+
+// Self-generated ML-DSA-44 verification cases: A signature produced with OpenSSL by the key from
+// the seed SHA-256("embedded-cal ML-DSA-44 sigVer"), and modified versions of it and its inputs,
+// which OpenSSL rejects. The "other key" is generated from
+// SHA-256("embedded-cal ML-DSA-44 sigVer other key").
+pub const INTEROP_MLDSA44_SIGVER: &[SignatureVerifyVector] = &[
+    // unmodified
+    SignatureVerifyVector {
+        cose_alg: -48,
+        pk: &hex!(
+            "1924367d701603cd6612032d74520cdf8df3d94fc705aa77ee6e6dec01ec23b69b14109f946ad8495aa1b99b0d07c1657b277c6fc4baa02f82746f265b6cfdf4579e8a6f4cdbccece1e9b4422f74d7770d194d52b25894ead0946a7a61108b4cde2403ec74ae5c88c1ff134ae5a70d034ac2c02bdab81ad08a934511e806da4795dbe04b366d603bd5ddb4a2f5c21cc67e3c352919dfedd2b849a72a324bf310255f0806eddcd341fd5d49c88486b30d50c990fd381069d3fbed9dd56aab69f3099164b4538b12cef59d6a85679caf97d297b586375d779129987cabc4b9f2092aeff2f639099b36c60e8a6758923a978da7f0e796350e01756f0a82c95aef9b50a81deb04de31d66db02403fe43967f8a3ef6771b1e7b8d60ba0c189444a939191b877f6413a1527c996fa6d6204533c9d3b932fe0832439cb3e1797ed43127be87d61be7f91ab5912c0bf8ca2e0c7a6635af63c2351541e2e132beb5d374978f875fa978111880e1b30ab086801d475e436dd4f462e757ebe03ef7fa84da30dad88a47adb49cd3aa577cae36b1e9de86c685f92f95c9a3f90ece8918b9912b7707d2eef946665770a28540e532d97238722a4a0b194907edcbf2b3bb17914ef01d602dceb4bb4d8908d43942cce178a514581b29c2cf48e77a1872868a2ee0caa41d80c7592ebf06bec8e618f1ad9e18dbb1f89f92fa34dc30a17942826b420ecc6ead4cb2ad34be35737a3f3d01db80896b0eccffbe374e49b9458b3027fff8e694a7c55cbb3a06570a1bd6c8f0cae31672e240f636456fbff76073d7b31276bb545f1cddadc3f42bd3c8ad10975e1d2444e3e7a15a80b9792bd1e7e5d1412d31407cf803522b8315571c1fdf9b5d30548e44ee04bab3ea8839bd54c2f3aec002cfe214c93374060c8f85314b7619aa91080cd5fc922b661191fb8929f6ad0157a07a2851b1cc97351f74fd5a0d2e989ef0423e1291d694485437015595d78873e717a07b0b049b6ea83a6df26b678310718ee941c857e68138cf1468fdfd4eed4300509492c5baad6787af32161c4fe735198b238fe115481ee386409692fa632d2eb60fc5c15d6c33503a502f5ccb92484d5b9a794a03206a8695a0500d5f2f98a10774bff9e9b45ea5a1d0bc0703d3205ba1064a0bc0876fd78124bb2af4d88f59418a6ccb4216707d398ace5fbd4904cb62f0f211155e2f8d361f563b532d2de9c7edac0e912955ba2b30ed898101eaa9eca811a66cd1d329cc1c9c0e22f52d1dae95122e1b0892031fd3a87ae23073bca254cb9a3d24a9553e3a5b4a3932f1ab810c964b7b1fc4b5a6ebdf14e44ad73939494b78cb40dc383202e34188a805a76d870958c52fa690bb5fab245710fa52bad0f3f2bf165ea1f16ad33312e33661645b8aa008f92415f24e314e0912ff5fc9b251b263e1150f22a00f51bae1bb1121d9f6148c7d2d1920fe854ebfd0ee1c96529ba4ad5f2520199e6b3711e5a1d5c3c1ff92f6e3aaa94115796d49bd1f383e5e0c7f58bbc9580c38040cea55c5f57c563d1e75dcf5e00d1f1cd5b4e5b4854a45adf7196a6d4f964ee843faddee35134352883b1b9197f8c494236e7caad2a9c045b10e0f2ffe5af9210b63b34234dc3d98903d32681b95bc4ca23e8d4d2e7ee356166b5c495fd707df0f0039eb7beb231505859bc609ca5712d6fc2f5fe0d288e27c860536eadaecc5497cc848ffe8b544625917ce04f3398611edc703832a6e9b236465f514dcc3b348cd8ad0a783d07e85728050110a26667417ddfe9b85dbaea8f0063863a80735a4d42785e3d666fb40c049fa2009bf56c12050565c945dbca81c6fb9b4ff517b6a"
+        ),
+        message: b"embedded-cal ML-DSA-44 sigVer message",
+        signature: &hex!(
+            "ea216ae0c368f456097187e64bd7b5b9a4a60490a0edcbda800f698c93ad75ccf6cc0543eabb23934e180d9fc4ee205fbb9a10a7c22e2548420d07d54b3be00b048d6704cd291a6e376ed9132493a72b80b0fc53f071da968dbac8dd214cc9d50f3e36bcb307cd4e580dc1646f8a646545bbf5605490e053e61bd8bbd5669af30ecf9a63869ad3bab5d816a532485c17f0765a81647d37c63367c8b7eb6a09182793233ae09e0f78c345ed559a966fc9ff6acf7d19ae54132bc7a15355dac8106360b02c17f252244433afaba057ffa4d29923097fb6b93dbf9126101bee110ef2aa0b225885024bacd45a40028ecb6ad4fc833b44aae703a4cad3606663b75ea2efbe7c87de43569e962ee41f2bbae248285a17afa433b182166a3815956e6e5cc8c6a67b569b6e4953caf8316069915a4828283cb327db8b236860aeccabea255071a437d811057b45843dc1e534b8c906d7ca07005b219af83a2d9bb4217c7e6990cbfec2101eee84cea801cc3e79b52e5d0652c8882174f3c8333923d3e39294db663f5514982cdb1d3f39cdef85a4b291388851495dda733e4580f2719a593155e75d6900dbc78331245656086d678bb8b4b3eb8e32fc1f34952c6a0750b89c2a71531bbadd129fee160655b7d87daa4d98a4dfd1cf42ef997f3ae5223b7b5854d38b861a483ecaccb32edba31d2ddae926780e19c28ebab7d6b649f3bb4b4ccf51d7f65f536944c34703eaea3d60a10621ff20666458b168982400edd616725527c6830de34f58e08860ebf8df93b7be7a71f687de59bd75a67094472667bb5a38916c21781295c634a307b300528113e08417a060e2fe67e619e5f8d1e0467d1c5cef35fe37378af3a4ebc36c6e003e0c570a705bcf7e82303c50c256aadd8bd30e9dab2515473e40cd74f3ac486257a72605dfd0bb5f4b5550d63546abb640770bac22d8e370ae77b7da81cfb115546fa5a7f069359ab2224125c28d52d520d68bfd51f1465ee0fedc81baa0c1618fae4e0fc256f7781fa8823a1f101cca72ef66cc8c6291374c5abac98f4e2e15958bb9850c1073215fa49ee0b6979d3b636e99151028f3d72f0f299dea64a5e53f2897fb2be0dbc2c0148d78612a6ddbd79de7413560186fd5a4fa2beac9fa96a3078d2dcfe0330b11152c8f6cf5b3c442b884fd65b3e2dad810031343005d17ffad65a38677d28ff1b0649a9e98a3a631b978ea9f61cdf6799228cce18780b4367fdc816678ec8e97432685c4fda5131a87f3e113083fdf98209977fae76eac3a37cff2d91c74a07f58c7906e0c93f07809686b342c556e3088277b7df76bbfc4b94843368c11a3e3e4052f53aaca34f2b12c2178c0a70bc58702ecf6f57b9b774d9474047746eb8ac25ef3fcf4923fb9732432ed81d2b37035d323b72f217b6f9e196e5cb080cf3f1e4fb507dbd5cd3a40ff97f88f5808b7520de747714ee15c0101689a5bea8e18271f641c87726a757e3fed7556f6224cdd3520ab039d2f74e2aa69d923cead67ef4e200ff45537d41970e0f927adb56c9ecee93131b14ac8c35453318e911c3483e045ea806007a94106ba6fe2d08eae12c9215a29950ea78d5de0c6a79a6e480ae786492786f2ec8148c115fed6169c69be4f04afdcaf6dc86ccf8c9a2566504b9a29a6f0dcfc6983224683ba8a080e4504dea18594ab1aea2a356d426da30e698c5a0378025a85c34e1d940697ec4ced8c5392f551711a7d3abae00d645986a6baadedeaf5b257b8acb22b27197d0de4a5e17b06748a3e4dd773bf40b8247e7c96c0c38b026705d2d2076d1dda277b296fa2a30a29efa58789a03ad1f8f7aad64dbe25907242f28e4ccb1b62bf5ad060167ecc57805e531940d091d0a8395a56447a32697ba0d3f6a438143e7176967b758ef4e02acf0b1b36e9618e6dbedf65f619a599b5c2867aeb20b4615fcf56d553c7fd3eb80c69abbf4ccff2082d9f89bd320afc1e553627d6fa5d0866a7468d48f054c808fb7ab4599f986df6ddec9f118ff04c50004673a7871e06e5e04e8016381dec6e960cae4917021ee668c1487de7a61699cc89c8ff200240fb5534acde689feb1e134f74f33939593dba8e366ac3e46972083aa77cb68231e44dfabe46edab316e84496dadd3a63a2999d44455bc7a24438ece9e88cb031c02e85e2d2b99303dbc273a2f2625e6b5dedfdeca055a4957268da2707158a880b47f593803809d6d400c43d60e16765d28ced5b8efb023f88f76a7a83f79f5eb6685068d3ba1a286cd1891d3e7e3f9627251799ba658fbd431655238c6fa6859d1139fbd6208d58977d63e41a5d4bcf06edec6582b3c9e001640e08cf6769afb658f52b8cb1cd6add947e0a3a4a9641d127acb946cf2a3a8c8b42f692cb6fdf7656c9df58345599c41593ed88fd3a4e0eaeab1f3ae3a29f28a37e2fc02d1b570cfa923e86e310216fcb8dd27244ee1e3e97a6c603e4775ca32bde6e654be9a4fb3acf39aa756893987612ab0ab198c3736714da6c62a13ace1a765871b9f2ed6d5390b6974dd83d8464de8dac01c42f0dbd3266a26590d23f6061a588a311cad956cba786d424d66a233d69f1723b05cf9687dedbeafbcd606d2cfe45c658fa93f7c60b2dff3192de4a46a5b3c5d433a7ce10ab4839e2d68ec1b1212fe59e00194546304872cf1cdf70cfd8f95e1ff29a0d8f4c6af06ef76f84ae1aaef9e49b9819c727043d4bad41125020d6ae4795d0b2d149c4c512d67eb90d7ae719b54007480e71cb7f2afbf0540d3185c3d406da673956541ec8036f9a58f757e761c8ed8c8ce7abcced08e611e51fc0055a1af838ff1fbfcd8d8700eedcf398a5af7993b07d431a2a4e47e01780c2b9e0538718a8c1e509d45011b9dbdc6d88d27d653ad99a736660b4631a59b4c2c96424044102c090bcb5b7d0470060af269d566df4cf7debd8c65d0eff5daef4eb5c31eae6c1deea303d850eaf9b5de2c6ec44d1c9a0a22503f1b07060b6e3efaa9e886150afdee91ec3e624bb67b1b6acae68e91221197727f54fde5399b400a73a8164bfc60785b208123404d311372236c1bdeeb88b49b8e29b19648b6c0f6ec6d28b9229344737ec81ed56bc207082f77bb0cc2f5a50e496cbfd2e9f6ff32ce30e1342b4a2e4ab6fb99637efeb6345822c0ab2cfa24ef511e4001316345d55e731a4da7ce41c847223440a3230bbfddcebf691ed059666ab12725b73a98216b8a1dcc5fca24e25aab3c5b62d5ff4e37d334f64bf5979601f7ab56450c5a15b277d906fd1c5ff61d1ad4f10720355a5f647586022b308fa1b2bacacdd4dbee0a23254b578ae9f0031a1b2122273d47686b767d98a6bbfd00000000000000000000000000000000000000000000000000000000000000000000000008141c2c"
+        ),
+        test_passed: true,
+    },
+    // modified message
+    SignatureVerifyVector {
+        cose_alg: -48,
+        pk: &hex!(
+            "1924367d701603cd6612032d74520cdf8df3d94fc705aa77ee6e6dec01ec23b69b14109f946ad8495aa1b99b0d07c1657b277c6fc4baa02f82746f265b6cfdf4579e8a6f4cdbccece1e9b4422f74d7770d194d52b25894ead0946a7a61108b4cde2403ec74ae5c88c1ff134ae5a70d034ac2c02bdab81ad08a934511e806da4795dbe04b366d603bd5ddb4a2f5c21cc67e3c352919dfedd2b849a72a324bf310255f0806eddcd341fd5d49c88486b30d50c990fd381069d3fbed9dd56aab69f3099164b4538b12cef59d6a85679caf97d297b586375d779129987cabc4b9f2092aeff2f639099b36c60e8a6758923a978da7f0e796350e01756f0a82c95aef9b50a81deb04de31d66db02403fe43967f8a3ef6771b1e7b8d60ba0c189444a939191b877f6413a1527c996fa6d6204533c9d3b932fe0832439cb3e1797ed43127be87d61be7f91ab5912c0bf8ca2e0c7a6635af63c2351541e2e132beb5d374978f875fa978111880e1b30ab086801d475e436dd4f462e757ebe03ef7fa84da30dad88a47adb49cd3aa577cae36b1e9de86c685f92f95c9a3f90ece8918b9912b7707d2eef946665770a28540e532d97238722a4a0b194907edcbf2b3bb17914ef01d602dceb4bb4d8908d43942cce178a514581b29c2cf48e77a1872868a2ee0caa41d80c7592ebf06bec8e618f1ad9e18dbb1f89f92fa34dc30a17942826b420ecc6ead4cb2ad34be35737a3f3d01db80896b0eccffbe374e49b9458b3027fff8e694a7c55cbb3a06570a1bd6c8f0cae31672e240f636456fbff76073d7b31276bb545f1cddadc3f42bd3c8ad10975e1d2444e3e7a15a80b9792bd1e7e5d1412d31407cf803522b8315571c1fdf9b5d30548e44ee04bab3ea8839bd54c2f3aec002cfe214c93374060c8f85314b7619aa91080cd5fc922b661191fb8929f6ad0157a07a2851b1cc97351f74fd5a0d2e989ef0423e1291d694485437015595d78873e717a07b0b049b6ea83a6df26b678310718ee941c857e68138cf1468fdfd4eed4300509492c5baad6787af32161c4fe735198b238fe115481ee386409692fa632d2eb60fc5c15d6c33503a502f5ccb92484d5b9a794a03206a8695a0500d5f2f98a10774bff9e9b45ea5a1d0bc0703d3205ba1064a0bc0876fd78124bb2af4d88f59418a6ccb4216707d398ace5fbd4904cb62f0f211155e2f8d361f563b532d2de9c7edac0e912955ba2b30ed898101eaa9eca811a66cd1d329cc1c9c0e22f52d1dae95122e1b0892031fd3a87ae23073bca254cb9a3d24a9553e3a5b4a3932f1ab810c964b7b1fc4b5a6ebdf14e44ad73939494b78cb40dc383202e34188a805a76d870958c52fa690bb5fab245710fa52bad0f3f2bf165ea1f16ad33312e33661645b8aa008f92415f24e314e0912ff5fc9b251b263e1150f22a00f51bae1bb1121d9f6148c7d2d1920fe854ebfd0ee1c96529ba4ad5f2520199e6b3711e5a1d5c3c1ff92f6e3aaa94115796d49bd1f383e5e0c7f58bbc9580c38040cea55c5f57c563d1e75dcf5e00d1f1cd5b4e5b4854a45adf7196a6d4f964ee843faddee35134352883b1b9197f8c494236e7caad2a9c045b10e0f2ffe5af9210b63b34234dc3d98903d32681b95bc4ca23e8d4d2e7ee356166b5c495fd707df0f0039eb7beb231505859bc609ca5712d6fc2f5fe0d288e27c860536eadaecc5497cc848ffe8b544625917ce04f3398611edc703832a6e9b236465f514dcc3b348cd8ad0a783d07e85728050110a26667417ddfe9b85dbaea8f0063863a80735a4d42785e3d666fb40c049fa2009bf56c12050565c945dbca81c6fb9b4ff517b6a"
+        ),
+        message: b"embedded-cal ML-DSA-44 sigVer messag?",
+        signature: &hex!(
+            "ea216ae0c368f456097187e64bd7b5b9a4a60490a0edcbda800f698c93ad75ccf6cc0543eabb23934e180d9fc4ee205fbb9a10a7c22e2548420d07d54b3be00b048d6704cd291a6e376ed9132493a72b80b0fc53f071da968dbac8dd214cc9d50f3e36bcb307cd4e580dc1646f8a646545bbf5605490e053e61bd8bbd5669af30ecf9a63869ad3bab5d816a532485c17f0765a81647d37c63367c8b7eb6a09182793233ae09e0f78c345ed559a966fc9ff6acf7d19ae54132bc7a15355dac8106360b02c17f252244433afaba057ffa4d29923097fb6b93dbf9126101bee110ef2aa0b225885024bacd45a40028ecb6ad4fc833b44aae703a4cad3606663b75ea2efbe7c87de43569e962ee41f2bbae248285a17afa433b182166a3815956e6e5cc8c6a67b569b6e4953caf8316069915a4828283cb327db8b236860aeccabea255071a437d811057b45843dc1e534b8c906d7ca07005b219af83a2d9bb4217c7e6990cbfec2101eee84cea801cc3e79b52e5d0652c8882174f3c8333923d3e39294db663f5514982cdb1d3f39cdef85a4b291388851495dda733e4580f2719a593155e75d6900dbc78331245656086d678bb8b4b3eb8e32fc1f34952c6a0750b89c2a71531bbadd129fee160655b7d87daa4d98a4dfd1cf42ef997f3ae5223b7b5854d38b861a483ecaccb32edba31d2ddae926780e19c28ebab7d6b649f3bb4b4ccf51d7f65f536944c34703eaea3d60a10621ff20666458b168982400edd616725527c6830de34f58e08860ebf8df93b7be7a71f687de59bd75a67094472667bb5a38916c21781295c634a307b300528113e08417a060e2fe67e619e5f8d1e0467d1c5cef35fe37378af3a4ebc36c6e003e0c570a705bcf7e82303c50c256aadd8bd30e9dab2515473e40cd74f3ac486257a72605dfd0bb5f4b5550d63546abb640770bac22d8e370ae77b7da81cfb115546fa5a7f069359ab2224125c28d52d520d68bfd51f1465ee0fedc81baa0c1618fae4e0fc256f7781fa8823a1f101cca72ef66cc8c6291374c5abac98f4e2e15958bb9850c1073215fa49ee0b6979d3b636e99151028f3d72f0f299dea64a5e53f2897fb2be0dbc2c0148d78612a6ddbd79de7413560186fd5a4fa2beac9fa96a3078d2dcfe0330b11152c8f6cf5b3c442b884fd65b3e2dad810031343005d17ffad65a38677d28ff1b0649a9e98a3a631b978ea9f61cdf6799228cce18780b4367fdc816678ec8e97432685c4fda5131a87f3e113083fdf98209977fae76eac3a37cff2d91c74a07f58c7906e0c93f07809686b342c556e3088277b7df76bbfc4b94843368c11a3e3e4052f53aaca34f2b12c2178c0a70bc58702ecf6f57b9b774d9474047746eb8ac25ef3fcf4923fb9732432ed81d2b37035d323b72f217b6f9e196e5cb080cf3f1e4fb507dbd5cd3a40ff97f88f5808b7520de747714ee15c0101689a5bea8e18271f641c87726a757e3fed7556f6224cdd3520ab039d2f74e2aa69d923cead67ef4e200ff45537d41970e0f927adb56c9ecee93131b14ac8c35453318e911c3483e045ea806007a94106ba6fe2d08eae12c9215a29950ea78d5de0c6a79a6e480ae786492786f2ec8148c115fed6169c69be4f04afdcaf6dc86ccf8c9a2566504b9a29a6f0dcfc6983224683ba8a080e4504dea18594ab1aea2a356d426da30e698c5a0378025a85c34e1d940697ec4ced8c5392f551711a7d3abae00d645986a6baadedeaf5b257b8acb22b27197d0de4a5e17b06748a3e4dd773bf40b8247e7c96c0c38b026705d2d2076d1dda277b296fa2a30a29efa58789a03ad1f8f7aad64dbe25907242f28e4ccb1b62bf5ad060167ecc57805e531940d091d0a8395a56447a32697ba0d3f6a438143e7176967b758ef4e02acf0b1b36e9618e6dbedf65f619a599b5c2867aeb20b4615fcf56d553c7fd3eb80c69abbf4ccff2082d9f89bd320afc1e553627d6fa5d0866a7468d48f054c808fb7ab4599f986df6ddec9f118ff04c50004673a7871e06e5e04e8016381dec6e960cae4917021ee668c1487de7a61699cc89c8ff200240fb5534acde689feb1e134f74f33939593dba8e366ac3e46972083aa77cb68231e44dfabe46edab316e84496dadd3a63a2999d44455bc7a24438ece9e88cb031c02e85e2d2b99303dbc273a2f2625e6b5dedfdeca055a4957268da2707158a880b47f593803809d6d400c43d60e16765d28ced5b8efb023f88f76a7a83f79f5eb6685068d3ba1a286cd1891d3e7e3f9627251799ba658fbd431655238c6fa6859d1139fbd6208d58977d63e41a5d4bcf06edec6582b3c9e001640e08cf6769afb658f52b8cb1cd6add947e0a3a4a9641d127acb946cf2a3a8c8b42f692cb6fdf7656c9df58345599c41593ed88fd3a4e0eaeab1f3ae3a29f28a37e2fc02d1b570cfa923e86e310216fcb8dd27244ee1e3e97a6c603e4775ca32bde6e654be9a4fb3acf39aa756893987612ab0ab198c3736714da6c62a13ace1a765871b9f2ed6d5390b6974dd83d8464de8dac01c42f0dbd3266a26590d23f6061a588a311cad956cba786d424d66a233d69f1723b05cf9687dedbeafbcd606d2cfe45c658fa93f7c60b2dff3192de4a46a5b3c5d433a7ce10ab4839e2d68ec1b1212fe59e00194546304872cf1cdf70cfd8f95e1ff29a0d8f4c6af06ef76f84ae1aaef9e49b9819c727043d4bad41125020d6ae4795d0b2d149c4c512d67eb90d7ae719b54007480e71cb7f2afbf0540d3185c3d406da673956541ec8036f9a58f757e761c8ed8c8ce7abcced08e611e51fc0055a1af838ff1fbfcd8d8700eedcf398a5af7993b07d431a2a4e47e01780c2b9e0538718a8c1e509d45011b9dbdc6d88d27d653ad99a736660b4631a59b4c2c96424044102c090bcb5b7d0470060af269d566df4cf7debd8c65d0eff5daef4eb5c31eae6c1deea303d850eaf9b5de2c6ec44d1c9a0a22503f1b07060b6e3efaa9e886150afdee91ec3e624bb67b1b6acae68e91221197727f54fde5399b400a73a8164bfc60785b208123404d311372236c1bdeeb88b49b8e29b19648b6c0f6ec6d28b9229344737ec81ed56bc207082f77bb0cc2f5a50e496cbfd2e9f6ff32ce30e1342b4a2e4ab6fb99637efeb6345822c0ab2cfa24ef511e4001316345d55e731a4da7ce41c847223440a3230bbfddcebf691ed059666ab12725b73a98216b8a1dcc5fca24e25aab3c5b62d5ff4e37d334f64bf5979601f7ab56450c5a15b277d906fd1c5ff61d1ad4f10720355a5f647586022b308fa1b2bacacdd4dbee0a23254b578ae9f0031a1b2122273d47686b767d98a6bbfd00000000000000000000000000000000000000000000000000000000000000000000000008141c2c"
+        ),
+        test_passed: false,
+    },
+    // modified signature (commitment hash)
+    SignatureVerifyVector {
+        cose_alg: -48,
+        pk: &hex!(
+            "1924367d701603cd6612032d74520cdf8df3d94fc705aa77ee6e6dec01ec23b69b14109f946ad8495aa1b99b0d07c1657b277c6fc4baa02f82746f265b6cfdf4579e8a6f4cdbccece1e9b4422f74d7770d194d52b25894ead0946a7a61108b4cde2403ec74ae5c88c1ff134ae5a70d034ac2c02bdab81ad08a934511e806da4795dbe04b366d603bd5ddb4a2f5c21cc67e3c352919dfedd2b849a72a324bf310255f0806eddcd341fd5d49c88486b30d50c990fd381069d3fbed9dd56aab69f3099164b4538b12cef59d6a85679caf97d297b586375d779129987cabc4b9f2092aeff2f639099b36c60e8a6758923a978da7f0e796350e01756f0a82c95aef9b50a81deb04de31d66db02403fe43967f8a3ef6771b1e7b8d60ba0c189444a939191b877f6413a1527c996fa6d6204533c9d3b932fe0832439cb3e1797ed43127be87d61be7f91ab5912c0bf8ca2e0c7a6635af63c2351541e2e132beb5d374978f875fa978111880e1b30ab086801d475e436dd4f462e757ebe03ef7fa84da30dad88a47adb49cd3aa577cae36b1e9de86c685f92f95c9a3f90ece8918b9912b7707d2eef946665770a28540e532d97238722a4a0b194907edcbf2b3bb17914ef01d602dceb4bb4d8908d43942cce178a514581b29c2cf48e77a1872868a2ee0caa41d80c7592ebf06bec8e618f1ad9e18dbb1f89f92fa34dc30a17942826b420ecc6ead4cb2ad34be35737a3f3d01db80896b0eccffbe374e49b9458b3027fff8e694a7c55cbb3a06570a1bd6c8f0cae31672e240f636456fbff76073d7b31276bb545f1cddadc3f42bd3c8ad10975e1d2444e3e7a15a80b9792bd1e7e5d1412d31407cf803522b8315571c1fdf9b5d30548e44ee04bab3ea8839bd54c2f3aec002cfe214c93374060c8f85314b7619aa91080cd5fc922b661191fb8929f6ad0157a07a2851b1cc97351f74fd5a0d2e989ef0423e1291d694485437015595d78873e717a07b0b049b6ea83a6df26b678310718ee941c857e68138cf1468fdfd4eed4300509492c5baad6787af32161c4fe735198b238fe115481ee386409692fa632d2eb60fc5c15d6c33503a502f5ccb92484d5b9a794a03206a8695a0500d5f2f98a10774bff9e9b45ea5a1d0bc0703d3205ba1064a0bc0876fd78124bb2af4d88f59418a6ccb4216707d398ace5fbd4904cb62f0f211155e2f8d361f563b532d2de9c7edac0e912955ba2b30ed898101eaa9eca811a66cd1d329cc1c9c0e22f52d1dae95122e1b0892031fd3a87ae23073bca254cb9a3d24a9553e3a5b4a3932f1ab810c964b7b1fc4b5a6ebdf14e44ad73939494b78cb40dc383202e34188a805a76d870958c52fa690bb5fab245710fa52bad0f3f2bf165ea1f16ad33312e33661645b8aa008f92415f24e314e0912ff5fc9b251b263e1150f22a00f51bae1bb1121d9f6148c7d2d1920fe854ebfd0ee1c96529ba4ad5f2520199e6b3711e5a1d5c3c1ff92f6e3aaa94115796d49bd1f383e5e0c7f58bbc9580c38040cea55c5f57c563d1e75dcf5e00d1f1cd5b4e5b4854a45adf7196a6d4f964ee843faddee35134352883b1b9197f8c494236e7caad2a9c045b10e0f2ffe5af9210b63b34234dc3d98903d32681b95bc4ca23e8d4d2e7ee356166b5c495fd707df0f0039eb7beb231505859bc609ca5712d6fc2f5fe0d288e27c860536eadaecc5497cc848ffe8b544625917ce04f3398611edc703832a6e9b236465f514dcc3b348cd8ad0a783d07e85728050110a26667417ddfe9b85dbaea8f0063863a80735a4d42785e3d666fb40c049fa2009bf56c12050565c945dbca81c6fb9b4ff517b6a"
+        ),
+        message: b"embedded-cal ML-DSA-44 sigVer message",
+        signature: &hex!(
+            "eb216ae0c368f456097187e64bd7b5b9a4a60490a0edcbda800f698c93ad75ccf6cc0543eabb23934e180d9fc4ee205fbb9a10a7c22e2548420d07d54b3be00b048d6704cd291a6e376ed9132493a72b80b0fc53f071da968dbac8dd214cc9d50f3e36bcb307cd4e580dc1646f8a646545bbf5605490e053e61bd8bbd5669af30ecf9a63869ad3bab5d816a532485c17f0765a81647d37c63367c8b7eb6a09182793233ae09e0f78c345ed559a966fc9ff6acf7d19ae54132bc7a15355dac8106360b02c17f252244433afaba057ffa4d29923097fb6b93dbf9126101bee110ef2aa0b225885024bacd45a40028ecb6ad4fc833b44aae703a4cad3606663b75ea2efbe7c87de43569e962ee41f2bbae248285a17afa433b182166a3815956e6e5cc8c6a67b569b6e4953caf8316069915a4828283cb327db8b236860aeccabea255071a437d811057b45843dc1e534b8c906d7ca07005b219af83a2d9bb4217c7e6990cbfec2101eee84cea801cc3e79b52e5d0652c8882174f3c8333923d3e39294db663f5514982cdb1d3f39cdef85a4b291388851495dda733e4580f2719a593155e75d6900dbc78331245656086d678bb8b4b3eb8e32fc1f34952c6a0750b89c2a71531bbadd129fee160655b7d87daa4d98a4dfd1cf42ef997f3ae5223b7b5854d38b861a483ecaccb32edba31d2ddae926780e19c28ebab7d6b649f3bb4b4ccf51d7f65f536944c34703eaea3d60a10621ff20666458b168982400edd616725527c6830de34f58e08860ebf8df93b7be7a71f687de59bd75a67094472667bb5a38916c21781295c634a307b300528113e08417a060e2fe67e619e5f8d1e0467d1c5cef35fe37378af3a4ebc36c6e003e0c570a705bcf7e82303c50c256aadd8bd30e9dab2515473e40cd74f3ac486257a72605dfd0bb5f4b5550d63546abb640770bac22d8e370ae77b7da81cfb115546fa5a7f069359ab2224125c28d52d520d68bfd51f1465ee0fedc81baa0c1618fae4e0fc256f7781fa8823a1f101cca72ef66cc8c6291374c5abac98f4e2e15958bb9850c1073215fa49ee0b6979d3b636e99151028f3d72f0f299dea64a5e53f2897fb2be0dbc2c0148d78612a6ddbd79de7413560186fd5a4fa2beac9fa96a3078d2dcfe0330b11152c8f6cf5b3c442b884fd65b3e2dad810031343005d17ffad65a38677d28ff1b0649a9e98a3a631b978ea9f61cdf6799228cce18780b4367fdc816678ec8e97432685c4fda5131a87f3e113083fdf98209977fae76eac3a37cff2d91c74a07f58c7906e0c93f07809686b342c556e3088277b7df76bbfc4b94843368c11a3e3e4052f53aaca34f2b12c2178c0a70bc58702ecf6f57b9b774d9474047746eb8ac25ef3fcf4923fb9732432ed81d2b37035d323b72f217b6f9e196e5cb080cf3f1e4fb507dbd5cd3a40ff97f88f5808b7520de747714ee15c0101689a5bea8e18271f641c87726a757e3fed7556f6224cdd3520ab039d2f74e2aa69d923cead67ef4e200ff45537d41970e0f927adb56c9ecee93131b14ac8c35453318e911c3483e045ea806007a94106ba6fe2d08eae12c9215a29950ea78d5de0c6a79a6e480ae786492786f2ec8148c115fed6169c69be4f04afdcaf6dc86ccf8c9a2566504b9a29a6f0dcfc6983224683ba8a080e4504dea18594ab1aea2a356d426da30e698c5a0378025a85c34e1d940697ec4ced8c5392f551711a7d3abae00d645986a6baadedeaf5b257b8acb22b27197d0de4a5e17b06748a3e4dd773bf40b8247e7c96c0c38b026705d2d2076d1dda277b296fa2a30a29efa58789a03ad1f8f7aad64dbe25907242f28e4ccb1b62bf5ad060167ecc57805e531940d091d0a8395a56447a32697ba0d3f6a438143e7176967b758ef4e02acf0b1b36e9618e6dbedf65f619a599b5c2867aeb20b4615fcf56d553c7fd3eb80c69abbf4ccff2082d9f89bd320afc1e553627d6fa5d0866a7468d48f054c808fb7ab4599f986df6ddec9f118ff04c50004673a7871e06e5e04e8016381dec6e960cae4917021ee668c1487de7a61699cc89c8ff200240fb5534acde689feb1e134f74f33939593dba8e366ac3e46972083aa77cb68231e44dfabe46edab316e84496dadd3a63a2999d44455bc7a24438ece9e88cb031c02e85e2d2b99303dbc273a2f2625e6b5dedfdeca055a4957268da2707158a880b47f593803809d6d400c43d60e16765d28ced5b8efb023f88f76a7a83f79f5eb6685068d3ba1a286cd1891d3e7e3f9627251799ba658fbd431655238c6fa6859d1139fbd6208d58977d63e41a5d4bcf06edec6582b3c9e001640e08cf6769afb658f52b8cb1cd6add947e0a3a4a9641d127acb946cf2a3a8c8b42f692cb6fdf7656c9df58345599c41593ed88fd3a4e0eaeab1f3ae3a29f28a37e2fc02d1b570cfa923e86e310216fcb8dd27244ee1e3e97a6c603e4775ca32bde6e654be9a4fb3acf39aa756893987612ab0ab198c3736714da6c62a13ace1a765871b9f2ed6d5390b6974dd83d8464de8dac01c42f0dbd3266a26590d23f6061a588a311cad956cba786d424d66a233d69f1723b05cf9687dedbeafbcd606d2cfe45c658fa93f7c60b2dff3192de4a46a5b3c5d433a7ce10ab4839e2d68ec1b1212fe59e00194546304872cf1cdf70cfd8f95e1ff29a0d8f4c6af06ef76f84ae1aaef9e49b9819c727043d4bad41125020d6ae4795d0b2d149c4c512d67eb90d7ae719b54007480e71cb7f2afbf0540d3185c3d406da673956541ec8036f9a58f757e761c8ed8c8ce7abcced08e611e51fc0055a1af838ff1fbfcd8d8700eedcf398a5af7993b07d431a2a4e47e01780c2b9e0538718a8c1e509d45011b9dbdc6d88d27d653ad99a736660b4631a59b4c2c96424044102c090bcb5b7d0470060af269d566df4cf7debd8c65d0eff5daef4eb5c31eae6c1deea303d850eaf9b5de2c6ec44d1c9a0a22503f1b07060b6e3efaa9e886150afdee91ec3e624bb67b1b6acae68e91221197727f54fde5399b400a73a8164bfc60785b208123404d311372236c1bdeeb88b49b8e29b19648b6c0f6ec6d28b9229344737ec81ed56bc207082f77bb0cc2f5a50e496cbfd2e9f6ff32ce30e1342b4a2e4ab6fb99637efeb6345822c0ab2cfa24ef511e4001316345d55e731a4da7ce41c847223440a3230bbfddcebf691ed059666ab12725b73a98216b8a1dcc5fca24e25aab3c5b62d5ff4e37d334f64bf5979601f7ab56450c5a15b277d906fd1c5ff61d1ad4f10720355a5f647586022b308fa1b2bacacdd4dbee0a23254b578ae9f0031a1b2122273d47686b767d98a6bbfd00000000000000000000000000000000000000000000000000000000000000000000000008141c2c"
+        ),
+        test_passed: false,
+    },
+    // modified signature (response)
+    SignatureVerifyVector {
+        cose_alg: -48,
+        pk: &hex!(
+            "1924367d701603cd6612032d74520cdf8df3d94fc705aa77ee6e6dec01ec23b69b14109f946ad8495aa1b99b0d07c1657b277c6fc4baa02f82746f265b6cfdf4579e8a6f4cdbccece1e9b4422f74d7770d194d52b25894ead0946a7a61108b4cde2403ec74ae5c88c1ff134ae5a70d034ac2c02bdab81ad08a934511e806da4795dbe04b366d603bd5ddb4a2f5c21cc67e3c352919dfedd2b849a72a324bf310255f0806eddcd341fd5d49c88486b30d50c990fd381069d3fbed9dd56aab69f3099164b4538b12cef59d6a85679caf97d297b586375d779129987cabc4b9f2092aeff2f639099b36c60e8a6758923a978da7f0e796350e01756f0a82c95aef9b50a81deb04de31d66db02403fe43967f8a3ef6771b1e7b8d60ba0c189444a939191b877f6413a1527c996fa6d6204533c9d3b932fe0832439cb3e1797ed43127be87d61be7f91ab5912c0bf8ca2e0c7a6635af63c2351541e2e132beb5d374978f875fa978111880e1b30ab086801d475e436dd4f462e757ebe03ef7fa84da30dad88a47adb49cd3aa577cae36b1e9de86c685f92f95c9a3f90ece8918b9912b7707d2eef946665770a28540e532d97238722a4a0b194907edcbf2b3bb17914ef01d602dceb4bb4d8908d43942cce178a514581b29c2cf48e77a1872868a2ee0caa41d80c7592ebf06bec8e618f1ad9e18dbb1f89f92fa34dc30a17942826b420ecc6ead4cb2ad34be35737a3f3d01db80896b0eccffbe374e49b9458b3027fff8e694a7c55cbb3a06570a1bd6c8f0cae31672e240f636456fbff76073d7b31276bb545f1cddadc3f42bd3c8ad10975e1d2444e3e7a15a80b9792bd1e7e5d1412d31407cf803522b8315571c1fdf9b5d30548e44ee04bab3ea8839bd54c2f3aec002cfe214c93374060c8f85314b7619aa91080cd5fc922b661191fb8929f6ad0157a07a2851b1cc97351f74fd5a0d2e989ef0423e1291d694485437015595d78873e717a07b0b049b6ea83a6df26b678310718ee941c857e68138cf1468fdfd4eed4300509492c5baad6787af32161c4fe735198b238fe115481ee386409692fa632d2eb60fc5c15d6c33503a502f5ccb92484d5b9a794a03206a8695a0500d5f2f98a10774bff9e9b45ea5a1d0bc0703d3205ba1064a0bc0876fd78124bb2af4d88f59418a6ccb4216707d398ace5fbd4904cb62f0f211155e2f8d361f563b532d2de9c7edac0e912955ba2b30ed898101eaa9eca811a66cd1d329cc1c9c0e22f52d1dae95122e1b0892031fd3a87ae23073bca254cb9a3d24a9553e3a5b4a3932f1ab810c964b7b1fc4b5a6ebdf14e44ad73939494b78cb40dc383202e34188a805a76d870958c52fa690bb5fab245710fa52bad0f3f2bf165ea1f16ad33312e33661645b8aa008f92415f24e314e0912ff5fc9b251b263e1150f22a00f51bae1bb1121d9f6148c7d2d1920fe854ebfd0ee1c96529ba4ad5f2520199e6b3711e5a1d5c3c1ff92f6e3aaa94115796d49bd1f383e5e0c7f58bbc9580c38040cea55c5f57c563d1e75dcf5e00d1f1cd5b4e5b4854a45adf7196a6d4f964ee843faddee35134352883b1b9197f8c494236e7caad2a9c045b10e0f2ffe5af9210b63b34234dc3d98903d32681b95bc4ca23e8d4d2e7ee356166b5c495fd707df0f0039eb7beb231505859bc609ca5712d6fc2f5fe0d288e27c860536eadaecc5497cc848ffe8b544625917ce04f3398611edc703832a6e9b236465f514dcc3b348cd8ad0a783d07e85728050110a26667417ddfe9b85dbaea8f0063863a80735a4d42785e3d666fb40c049fa2009bf56c12050565c945dbca81c6fb9b4ff517b6a"
+        ),
+        message: b"embedded-cal ML-DSA-44 sigVer message",
+        signature: &hex!(
+            "ea216ae0c368f456097187e64bd7b5b9a4a60490a0edcbda800f698c93ad75ccf6cc0543eabb23934e180d9fc4ee205fbb9a10a7c22e2548420d07d54b3be00b048d6704cd291a6e376ed9132493a72b80b0fc53f071da968dbac8dd214cc9d50f3e36bcb307cd4e580dc1646f8a646545bbf5605490e053e61bd8bbd5669af30ecf9a63869ad3bab5d816a532485c17f0765a81647d37c63367c8b7eb6a09182793233ae09e0f78c345ed559a966fc9ff6acf7d19ae54132bc7a15355dac8106360b02c17f252244433afaba057ffa4d29923097fb6b93dbf9126101bee110ef2aa0b225885024bacd45a40028ecb6ad4fc833b44aae703a4cad3606663b75ea2efbe7c87de43569e962ee41f2bbae248285a17afa433b182166a3815956e6e5cc8c6a67b569b6e4953caf8316069915a4828283cb327db8b236860aeccabea255071a437d811057b45843dc1e534b8c906d7ca07005b219af83a2d9bb4217c7e6990cbfec2101eee84cea801cc3e79b52e5d0652c8882174f3c8333923d3e39294db663f5514982cdb1d3f39cdef85a4b291388851495dda733e4580f2719a593155e75d6900dbc78331245656086d678bb8b4b3eb8e32fc1f34952c6a0750b89c2a71531bbadd129fee160655b7d87daa4d98a4dfd1cf42ef997f3ae5223b7b5854d38b861a483ecaccb32edba31d2ddae926780e19c28ebab7d6b649f3bb4b4ccf51d7f65f536944c34703eaea3d60a10621ff20666458b168982400edd616725527c6830de34f58e08860ebf8df93b7be7a71f687de59bd75a67094472667bb5a38916c21781295c634a307b300528113e08417a060e2fe67e619e5f8d1e0467d1c5cef35fe37378af3a4ebc36c6e003e0c570a705bcf7e82303c50c256aadd8bd30e9dab2515473e40cd74f3ac486257a72605dfd0bb5f4b5550d63546abb640770bac22d8e370ae77b7da81cfb115546fa5a7f069359ab2224125c28d52d520d68bfd51f1465ee0fedc81baa0c1618fae4e0fc256f7781fa8823a1f101cca72ef66cc8c6291374c5abac98f4e2e15958bb9850c1073215fa49ee0b6979d3b636e99151028f3d72f0f299dea64a5e53f2897fb2be0dbc2c0148d78612a6ddbd79de7413560186fd5a4fa2beac9fa96a3078d2dcfe0330b11152c8f6cf5b3c442b884fd65b3e2dad810031343005d17ffad65a38677d28ff1b0649a9e98a3a631b978ea9f61cdf6799228cce18780b4367fdc816678ec8e97432685c4fda5131a87f3e113083fdf98209977fae76eac3a37cff2d91c74a07f58c7906e0c93f07809686b342c556e3088277b7df76bbfc4b94843368c11a3e3e4052f53aaca34f2b12c2178c0a70bc58702ecf6f57b9b774d9474047746eb8ac25ef3fcf4923fb9732432ed81d2b37035d323b72f217b6f9e196e5cb080cf3f1e4fb507dbd5cd3a40ff97f88f5808b7520de747714ee15c0101689a5bea8e18271f641c87726a757e3fed7556f6224cdd3520ab039d2f74e2aa69d923cead67ef4e200ff45537d41970e0f927adb56c9ecee93131b14ac8c35453318e911c3483e045ea806007a94106ba6fe2d08eae12c9215a29950ea78d5de0c6a79a6e480ae786492786f2ec8148c115fed6169c69be4f04afdcaf6dc86ccf8c9a2566504b9a29a6f0dcfc6983224683ba8a080e4504dea18594ab1aea2a356d426da31e698c5a0378025a85c34e1d940697ec4ced8c5392f551711a7d3abae00d645986a6baadedeaf5b257b8acb22b27197d0de4a5e17b06748a3e4dd773bf40b8247e7c96c0c38b026705d2d2076d1dda277b296fa2a30a29efa58789a03ad1f8f7aad64dbe25907242f28e4ccb1b62bf5ad060167ecc57805e531940d091d0a8395a56447a32697ba0d3f6a438143e7176967b758ef4e02acf0b1b36e9618e6dbedf65f619a599b5c2867aeb20b4615fcf56d553c7fd3eb80c69abbf4ccff2082d9f89bd320afc1e553627d6fa5d0866a7468d48f054c808fb7ab4599f986df6ddec9f118ff04c50004673a7871e06e5e04e8016381dec6e960cae4917021ee668c1487de7a61699cc89c8ff200240fb5534acde689feb1e134f74f33939593dba8e366ac3e46972083aa77cb68231e44dfabe46edab316e84496dadd3a63a2999d44455bc7a24438ece9e88cb031c02e85e2d2b99303dbc273a2f2625e6b5dedfdeca055a4957268da2707158a880b47f593803809d6d400c43d60e16765d28ced5b8efb023f88f76a7a83f79f5eb6685068d3ba1a286cd1891d3e7e3f9627251799ba658fbd431655238c6fa6859d1139fbd6208d58977d63e41a5d4bcf06edec6582b3c9e001640e08cf6769afb658f52b8cb1cd6add947e0a3a4a9641d127acb946cf2a3a8c8b42f692cb6fdf7656c9df58345599c41593ed88fd3a4e0eaeab1f3ae3a29f28a37e2fc02d1b570cfa923e86e310216fcb8dd27244ee1e3e97a6c603e4775ca32bde6e654be9a4fb3acf39aa756893987612ab0ab198c3736714da6c62a13ace1a765871b9f2ed6d5390b6974dd83d8464de8dac01c42f0dbd3266a26590d23f6061a588a311cad956cba786d424d66a233d69f1723b05cf9687dedbeafbcd606d2cfe45c658fa93f7c60b2dff3192de4a46a5b3c5d433a7ce10ab4839e2d68ec1b1212fe59e00194546304872cf1cdf70cfd8f95e1ff29a0d8f4c6af06ef76f84ae1aaef9e49b9819c727043d4bad41125020d6ae4795d0b2d149c4c512d67eb90d7ae719b54007480e71cb7f2afbf0540d3185c3d406da673956541ec8036f9a58f757e761c8ed8c8ce7abcced08e611e51fc0055a1af838ff1fbfcd8d8700eedcf398a5af7993b07d431a2a4e47e01780c2b9e0538718a8c1e509d45011b9dbdc6d88d27d653ad99a736660b4631a59b4c2c96424044102c090bcb5b7d0470060af269d566df4cf7debd8c65d0eff5daef4eb5c31eae6c1deea303d850eaf9b5de2c6ec44d1c9a0a22503f1b07060b6e3efaa9e886150afdee91ec3e624bb67b1b6acae68e91221197727f54fde5399b400a73a8164bfc60785b208123404d311372236c1bdeeb88b49b8e29b19648b6c0f6ec6d28b9229344737ec81ed56bc207082f77bb0cc2f5a50e496cbfd2e9f6ff32ce30e1342b4a2e4ab6fb99637efeb6345822c0ab2cfa24ef511e4001316345d55e731a4da7ce41c847223440a3230bbfddcebf691ed059666ab12725b73a98216b8a1dcc5fca24e25aab3c5b62d5ff4e37d334f64bf5979601f7ab56450c5a15b277d906fd1c5ff61d1ad4f10720355a5f647586022b308fa1b2bacacdd4dbee0a23254b578ae9f0031a1b2122273d47686b767d98a6bbfd00000000000000000000000000000000000000000000000000000000000000000000000008141c2c"
+        ),
+        test_passed: false,
+    },
+    // modified signature (hint)
+    SignatureVerifyVector {
+        cose_alg: -48,
+        pk: &hex!(
+            "1924367d701603cd6612032d74520cdf8df3d94fc705aa77ee6e6dec01ec23b69b14109f946ad8495aa1b99b0d07c1657b277c6fc4baa02f82746f265b6cfdf4579e8a6f4cdbccece1e9b4422f74d7770d194d52b25894ead0946a7a61108b4cde2403ec74ae5c88c1ff134ae5a70d034ac2c02bdab81ad08a934511e806da4795dbe04b366d603bd5ddb4a2f5c21cc67e3c352919dfedd2b849a72a324bf310255f0806eddcd341fd5d49c88486b30d50c990fd381069d3fbed9dd56aab69f3099164b4538b12cef59d6a85679caf97d297b586375d779129987cabc4b9f2092aeff2f639099b36c60e8a6758923a978da7f0e796350e01756f0a82c95aef9b50a81deb04de31d66db02403fe43967f8a3ef6771b1e7b8d60ba0c189444a939191b877f6413a1527c996fa6d6204533c9d3b932fe0832439cb3e1797ed43127be87d61be7f91ab5912c0bf8ca2e0c7a6635af63c2351541e2e132beb5d374978f875fa978111880e1b30ab086801d475e436dd4f462e757ebe03ef7fa84da30dad88a47adb49cd3aa577cae36b1e9de86c685f92f95c9a3f90ece8918b9912b7707d2eef946665770a28540e532d97238722a4a0b194907edcbf2b3bb17914ef01d602dceb4bb4d8908d43942cce178a514581b29c2cf48e77a1872868a2ee0caa41d80c7592ebf06bec8e618f1ad9e18dbb1f89f92fa34dc30a17942826b420ecc6ead4cb2ad34be35737a3f3d01db80896b0eccffbe374e49b9458b3027fff8e694a7c55cbb3a06570a1bd6c8f0cae31672e240f636456fbff76073d7b31276bb545f1cddadc3f42bd3c8ad10975e1d2444e3e7a15a80b9792bd1e7e5d1412d31407cf803522b8315571c1fdf9b5d30548e44ee04bab3ea8839bd54c2f3aec002cfe214c93374060c8f85314b7619aa91080cd5fc922b661191fb8929f6ad0157a07a2851b1cc97351f74fd5a0d2e989ef0423e1291d694485437015595d78873e717a07b0b049b6ea83a6df26b678310718ee941c857e68138cf1468fdfd4eed4300509492c5baad6787af32161c4fe735198b238fe115481ee386409692fa632d2eb60fc5c15d6c33503a502f5ccb92484d5b9a794a03206a8695a0500d5f2f98a10774bff9e9b45ea5a1d0bc0703d3205ba1064a0bc0876fd78124bb2af4d88f59418a6ccb4216707d398ace5fbd4904cb62f0f211155e2f8d361f563b532d2de9c7edac0e912955ba2b30ed898101eaa9eca811a66cd1d329cc1c9c0e22f52d1dae95122e1b0892031fd3a87ae23073bca254cb9a3d24a9553e3a5b4a3932f1ab810c964b7b1fc4b5a6ebdf14e44ad73939494b78cb40dc383202e34188a805a76d870958c52fa690bb5fab245710fa52bad0f3f2bf165ea1f16ad33312e33661645b8aa008f92415f24e314e0912ff5fc9b251b263e1150f22a00f51bae1bb1121d9f6148c7d2d1920fe854ebfd0ee1c96529ba4ad5f2520199e6b3711e5a1d5c3c1ff92f6e3aaa94115796d49bd1f383e5e0c7f58bbc9580c38040cea55c5f57c563d1e75dcf5e00d1f1cd5b4e5b4854a45adf7196a6d4f964ee843faddee35134352883b1b9197f8c494236e7caad2a9c045b10e0f2ffe5af9210b63b34234dc3d98903d32681b95bc4ca23e8d4d2e7ee356166b5c495fd707df0f0039eb7beb231505859bc609ca5712d6fc2f5fe0d288e27c860536eadaecc5497cc848ffe8b544625917ce04f3398611edc703832a6e9b236465f514dcc3b348cd8ad0a783d07e85728050110a26667417ddfe9b85dbaea8f0063863a80735a4d42785e3d666fb40c049fa2009bf56c12050565c945dbca81c6fb9b4ff517b6a"
+        ),
+        message: b"embedded-cal ML-DSA-44 sigVer message",
+        signature: &hex!(
+            "ea216ae0c368f456097187e64bd7b5b9a4a60490a0edcbda800f698c93ad75ccf6cc0543eabb23934e180d9fc4ee205fbb9a10a7c22e2548420d07d54b3be00b048d6704cd291a6e376ed9132493a72b80b0fc53f071da968dbac8dd214cc9d50f3e36bcb307cd4e580dc1646f8a646545bbf5605490e053e61bd8bbd5669af30ecf9a63869ad3bab5d816a532485c17f0765a81647d37c63367c8b7eb6a09182793233ae09e0f78c345ed559a966fc9ff6acf7d19ae54132bc7a15355dac8106360b02c17f252244433afaba057ffa4d29923097fb6b93dbf9126101bee110ef2aa0b225885024bacd45a40028ecb6ad4fc833b44aae703a4cad3606663b75ea2efbe7c87de43569e962ee41f2bbae248285a17afa433b182166a3815956e6e5cc8c6a67b569b6e4953caf8316069915a4828283cb327db8b236860aeccabea255071a437d811057b45843dc1e534b8c906d7ca07005b219af83a2d9bb4217c7e6990cbfec2101eee84cea801cc3e79b52e5d0652c8882174f3c8333923d3e39294db663f5514982cdb1d3f39cdef85a4b291388851495dda733e4580f2719a593155e75d6900dbc78331245656086d678bb8b4b3eb8e32fc1f34952c6a0750b89c2a71531bbadd129fee160655b7d87daa4d98a4dfd1cf42ef997f3ae5223b7b5854d38b861a483ecaccb32edba31d2ddae926780e19c28ebab7d6b649f3bb4b4ccf51d7f65f536944c34703eaea3d60a10621ff20666458b168982400edd616725527c6830de34f58e08860ebf8df93b7be7a71f687de59bd75a67094472667bb5a38916c21781295c634a307b300528113e08417a060e2fe67e619e5f8d1e0467d1c5cef35fe37378af3a4ebc36c6e003e0c570a705bcf7e82303c50c256aadd8bd30e9dab2515473e40cd74f3ac486257a72605dfd0bb5f4b5550d63546abb640770bac22d8e370ae77b7da81cfb115546fa5a7f069359ab2224125c28d52d520d68bfd51f1465ee0fedc81baa0c1618fae4e0fc256f7781fa8823a1f101cca72ef66cc8c6291374c5abac98f4e2e15958bb9850c1073215fa49ee0b6979d3b636e99151028f3d72f0f299dea64a5e53f2897fb2be0dbc2c0148d78612a6ddbd79de7413560186fd5a4fa2beac9fa96a3078d2dcfe0330b11152c8f6cf5b3c442b884fd65b3e2dad810031343005d17ffad65a38677d28ff1b0649a9e98a3a631b978ea9f61cdf6799228cce18780b4367fdc816678ec8e97432685c4fda5131a87f3e113083fdf98209977fae76eac3a37cff2d91c74a07f58c7906e0c93f07809686b342c556e3088277b7df76bbfc4b94843368c11a3e3e4052f53aaca34f2b12c2178c0a70bc58702ecf6f57b9b774d9474047746eb8ac25ef3fcf4923fb9732432ed81d2b37035d323b72f217b6f9e196e5cb080cf3f1e4fb507dbd5cd3a40ff97f88f5808b7520de747714ee15c0101689a5bea8e18271f641c87726a757e3fed7556f6224cdd3520ab039d2f74e2aa69d923cead67ef4e200ff45537d41970e0f927adb56c9ecee93131b14ac8c35453318e911c3483e045ea806007a94106ba6fe2d08eae12c9215a29950ea78d5de0c6a79a6e480ae786492786f2ec8148c115fed6169c69be4f04afdcaf6dc86ccf8c9a2566504b9a29a6f0dcfc6983224683ba8a080e4504dea18594ab1aea2a356d426da30e698c5a0378025a85c34e1d940697ec4ced8c5392f551711a7d3abae00d645986a6baadedeaf5b257b8acb22b27197d0de4a5e17b06748a3e4dd773bf40b8247e7c96c0c38b026705d2d2076d1dda277b296fa2a30a29efa58789a03ad1f8f7aad64dbe25907242f28e4ccb1b62bf5ad060167ecc57805e531940d091d0a8395a56447a32697ba0d3f6a438143e7176967b758ef4e02acf0b1b36e9618e6dbedf65f619a599b5c2867aeb20b4615fcf56d553c7fd3eb80c69abbf4ccff2082d9f89bd320afc1e553627d6fa5d0866a7468d48f054c808fb7ab4599f986df6ddec9f118ff04c50004673a7871e06e5e04e8016381dec6e960cae4917021ee668c1487de7a61699cc89c8ff200240fb5534acde689feb1e134f74f33939593dba8e366ac3e46972083aa77cb68231e44dfabe46edab316e84496dadd3a63a2999d44455bc7a24438ece9e88cb031c02e85e2d2b99303dbc273a2f2625e6b5dedfdeca055a4957268da2707158a880b47f593803809d6d400c43d60e16765d28ced5b8efb023f88f76a7a83f79f5eb6685068d3ba1a286cd1891d3e7e3f9627251799ba658fbd431655238c6fa6859d1139fbd6208d58977d63e41a5d4bcf06edec6582b3c9e001640e08cf6769afb658f52b8cb1cd6add947e0a3a4a9641d127acb946cf2a3a8c8b42f692cb6fdf7656c9df58345599c41593ed88fd3a4e0eaeab1f3ae3a29f28a37e2fc02d1b570cfa923e86e310216fcb8dd27244ee1e3e97a6c603e4775ca32bde6e654be9a4fb3acf39aa756893987612ab0ab198c3736714da6c62a13ace1a765871b9f2ed6d5390b6974dd83d8464de8dac01c42f0dbd3266a26590d23f6061a588a311cad956cba786d424d66a233d69f1723b05cf9687dedbeafbcd606d2cfe45c658fa93f7c60b2dff3192de4a46a5b3c5d433a7ce10ab4839e2d68ec1b1212fe59e00194546304872cf1cdf70cfd8f95e1ff29a0d8f4c6af06ef76f84ae1aaef9e49b9819c727043d4bad41125020d6ae4795d0b2d149c4c512d67eb90d7ae719b54007480e71cb7f2afbf0540d3185c3d406da673956541ec8036f9a58f757e761c8ed8c8ce7abcced08e611e51fc0055a1af838ff1fbfcd8d8700eedcf398a5af7993b07d431a2a4e47e01780c2b9e0538718a8c1e509d45011b9dbdc6d88d27d653ad99a736660b4631a59b4c2c96424044102c090bcb5b7d0470060af269d566df4cf7debd8c65d0eff5daef4eb5c31eae6c1deea303d850eaf9b5de2c6ec44d1c9a0a22503f1b07060b6e3efaa9e886150afdee91ec3e624bb67b1b6acae68e91221197727f54fde5399b400a73a8164bfc60785b208123404d311372236c1bdeeb88b49b8e29b19648b6c0f6ec6d28b9229344737ec81ed56bc207082f77bb0cc2f5a50e496cbfd2e9f6ff32ce30e1342b4a2e4ab6fb99637efeb6345822c0ab2cfa24ef511e4001316345d55e731a4da7ce41c847223440a3230bbfddcebf691ed059666ab12725b73a98216b8a1dcc5fca24e25aab3c5b62d5ff4e37d334f64bf5979601f7ab56450c5a15b277d906fd1c5ff61d1ad4f10720355a5f647586022b308fa1b2bacacdd4dbee0a23254b578ae9f0031a1b2122273d47686b767d98a6bbfd00000000000000000000000000000000000000000000000000000000000000000000000008141cd3"
+        ),
+        test_passed: false,
+    },
+    // other key
+    SignatureVerifyVector {
+        cose_alg: -48,
+        pk: &hex!(
+            "2170adadf6d330c82c1ddf7c665c7070213bb0d1ee9d7c255a8069d8178dc39d83c5a7db411392e1d7694ff93269d48985b88009339ae06a22fa4f55728df9185717bf834680ed97b87a3605d5ddaf359b4ed3f3152d1be5b47d9b3bb5182ebc011ab5d83636348589ee8e36297a1ff1ac70da9f9b2a4e122e6e5a13c3151ad24059d152df0fd166a24fe0d1280415bcc25e795f1c0f7be107742532db1dbf969b71361b112de9addd6471ec531954616b1e912d336af08058b5203bb72398641aa6503281dedf0c22e826d88fd935df44bf472bda671024e574502e380aa19bcc25d257a98073e584d233235a09c43499a08275527219c03e969c1ca5de615e952387ab46948ddaa3f782e8a2682a8efbf2e06a14ae42a859f41656ee65c2841089b9bb5e5adfcca4155d75c83b5aaf02c73381d02a37cd11f122ba0cc10096028e097cbccb50a690c58489599ae3e38a38ad2b1d929e0d2240e33ad81e62cbc8f01407a3c8a190510f4f887dc68945fce3d405d7da197aeca8b6400748da22ad816ae792a5d4af8265162d37739e8dad4b86a0d6cac9c1bb24f34499947cd4610f663f1f50d871c64677bd56b844ae5f46f47b185988f9e325ec018c28341c791dd6daaab075d98e4498ae7c60aa3abf072cb023cdaf90af85507e8c660ac9e2b2dc3a2debcbfb0afa3113a01a8eb4a662d5ec8bb85e0ce660c05e1f74920a1af6feb0b90641576d8aa8948500facfd84bebe5b3f4e528da1c438e8169412616bfa8ce4411ce9512dabe930b899a030ebc4412d60880b9cd03d2bf5d65380783e52b71f238374aa30f731f08d72c7c11fcabad3cb5640be041eadb3287d1cb2953bd00320cacd3ada584d0999d472ee729729b6a1a4ffb8a093b8441ad33261b645df121b0cb90ccded91947d3b1b9dc4441308a9fabc1ab9c9f86027e9056d6876846c7f2e8cc7e5f2b2ab6c299c0217c28e61c44321f4f02dac4df7982e1c4b157d58d5bc6814f417ea4c284e2548e73c3744ff4db8e2cebba88dcf22b39b9ff388b7f8c377534938480cf87a4d96ca26c10f90c013f4376668e4208040b2b7b8990a6767b4d6806f5d972e4e2d00bb3de3cbc1bd0da245e4f57acd4bfcd33326e0598ea2d8d1311b592d93f7c20de49127c83106a05771c4af1fc4e62fbe347b34bac1d0937041dfb37a63865a5217bb99ec14c9fcfac6f9d8f3a6cbeab87630a6b3686af688792d68389126ee888cb61021be41fc55355b2b51331ecc46118dea37aa0627034095e067c832a0a3e1fa1142a1e34e9b18173f96e9af42c7f1515592e2b8431de34ef6ae885220537fb7aba75e660d34c157c346fa836c8da771f5d3965d9d3d1d9aed82901255e887733a0a4d93dbc3e7ac07455c3a1904a5ecfe7767eaba284bb1893c4922045ae52be4714b58a368bcd2f9ec9d7c1d373dce51fec4a727d496e08f86efb7be5a464fa4af4bd8d6ad3257ab131fb6709e2e1b135f8cf3acafd165e60b6807578b5f7ebf9223f556d7853eb34e0f14c98e1b74438a24d627cacc0e3238ba76cad4aef41966e48e165dbcfa0f823fd2fec646dac98af336191eab05e8ee3f2d27bfc64e7078f299027ef36d82623f5b919b37742401e60fb9769dbe40c241489122fc5adf5fcadc0fb1433254fad418ed41e8dccf1dbc1e6e1d7be9b4f3c48842dd793d7844b85eda819f189117edb813ee5749209b55beb78c14b951c9c11009c6a180f697a19b62781e540aef6ebcc13a9d1b3c5987d010e6d093cb15196f405e56a154094f2e2d45393e81dcb3f9b3ec667ab889874fc5b345ad11380f1ac4a00d98032e6b05dcad2c2c4d64434179c"
+        ),
+        message: b"embedded-cal ML-DSA-44 sigVer message",
+        signature: &hex!(
+            "ea216ae0c368f456097187e64bd7b5b9a4a60490a0edcbda800f698c93ad75ccf6cc0543eabb23934e180d9fc4ee205fbb9a10a7c22e2548420d07d54b3be00b048d6704cd291a6e376ed9132493a72b80b0fc53f071da968dbac8dd214cc9d50f3e36bcb307cd4e580dc1646f8a646545bbf5605490e053e61bd8bbd5669af30ecf9a63869ad3bab5d816a532485c17f0765a81647d37c63367c8b7eb6a09182793233ae09e0f78c345ed559a966fc9ff6acf7d19ae54132bc7a15355dac8106360b02c17f252244433afaba057ffa4d29923097fb6b93dbf9126101bee110ef2aa0b225885024bacd45a40028ecb6ad4fc833b44aae703a4cad3606663b75ea2efbe7c87de43569e962ee41f2bbae248285a17afa433b182166a3815956e6e5cc8c6a67b569b6e4953caf8316069915a4828283cb327db8b236860aeccabea255071a437d811057b45843dc1e534b8c906d7ca07005b219af83a2d9bb4217c7e6990cbfec2101eee84cea801cc3e79b52e5d0652c8882174f3c8333923d3e39294db663f5514982cdb1d3f39cdef85a4b291388851495dda733e4580f2719a593155e75d6900dbc78331245656086d678bb8b4b3eb8e32fc1f34952c6a0750b89c2a71531bbadd129fee160655b7d87daa4d98a4dfd1cf42ef997f3ae5223b7b5854d38b861a483ecaccb32edba31d2ddae926780e19c28ebab7d6b649f3bb4b4ccf51d7f65f536944c34703eaea3d60a10621ff20666458b168982400edd616725527c6830de34f58e08860ebf8df93b7be7a71f687de59bd75a67094472667bb5a38916c21781295c634a307b300528113e08417a060e2fe67e619e5f8d1e0467d1c5cef35fe37378af3a4ebc36c6e003e0c570a705bcf7e82303c50c256aadd8bd30e9dab2515473e40cd74f3ac486257a72605dfd0bb5f4b5550d63546abb640770bac22d8e370ae77b7da81cfb115546fa5a7f069359ab2224125c28d52d520d68bfd51f1465ee0fedc81baa0c1618fae4e0fc256f7781fa8823a1f101cca72ef66cc8c6291374c5abac98f4e2e15958bb9850c1073215fa49ee0b6979d3b636e99151028f3d72f0f299dea64a5e53f2897fb2be0dbc2c0148d78612a6ddbd79de7413560186fd5a4fa2beac9fa96a3078d2dcfe0330b11152c8f6cf5b3c442b884fd65b3e2dad810031343005d17ffad65a38677d28ff1b0649a9e98a3a631b978ea9f61cdf6799228cce18780b4367fdc816678ec8e97432685c4fda5131a87f3e113083fdf98209977fae76eac3a37cff2d91c74a07f58c7906e0c93f07809686b342c556e3088277b7df76bbfc4b94843368c11a3e3e4052f53aaca34f2b12c2178c0a70bc58702ecf6f57b9b774d9474047746eb8ac25ef3fcf4923fb9732432ed81d2b37035d323b72f217b6f9e196e5cb080cf3f1e4fb507dbd5cd3a40ff97f88f5808b7520de747714ee15c0101689a5bea8e18271f641c87726a757e3fed7556f6224cdd3520ab039d2f74e2aa69d923cead67ef4e200ff45537d41970e0f927adb56c9ecee93131b14ac8c35453318e911c3483e045ea806007a94106ba6fe2d08eae12c9215a29950ea78d5de0c6a79a6e480ae786492786f2ec8148c115fed6169c69be4f04afdcaf6dc86ccf8c9a2566504b9a29a6f0dcfc6983224683ba8a080e4504dea18594ab1aea2a356d426da30e698c5a0378025a85c34e1d940697ec4ced8c5392f551711a7d3abae00d645986a6baadedeaf5b257b8acb22b27197d0de4a5e17b06748a3e4dd773bf40b8247e7c96c0c38b026705d2d2076d1dda277b296fa2a30a29efa58789a03ad1f8f7aad64dbe25907242f28e4ccb1b62bf5ad060167ecc57805e531940d091d0a8395a56447a32697ba0d3f6a438143e7176967b758ef4e02acf0b1b36e9618e6dbedf65f619a599b5c2867aeb20b4615fcf56d553c7fd3eb80c69abbf4ccff2082d9f89bd320afc1e553627d6fa5d0866a7468d48f054c808fb7ab4599f986df6ddec9f118ff04c50004673a7871e06e5e04e8016381dec6e960cae4917021ee668c1487de7a61699cc89c8ff200240fb5534acde689feb1e134f74f33939593dba8e366ac3e46972083aa77cb68231e44dfabe46edab316e84496dadd3a63a2999d44455bc7a24438ece9e88cb031c02e85e2d2b99303dbc273a2f2625e6b5dedfdeca055a4957268da2707158a880b47f593803809d6d400c43d60e16765d28ced5b8efb023f88f76a7a83f79f5eb6685068d3ba1a286cd1891d3e7e3f9627251799ba658fbd431655238c6fa6859d1139fbd6208d58977d63e41a5d4bcf06edec6582b3c9e001640e08cf6769afb658f52b8cb1cd6add947e0a3a4a9641d127acb946cf2a3a8c8b42f692cb6fdf7656c9df58345599c41593ed88fd3a4e0eaeab1f3ae3a29f28a37e2fc02d1b570cfa923e86e310216fcb8dd27244ee1e3e97a6c603e4775ca32bde6e654be9a4fb3acf39aa756893987612ab0ab198c3736714da6c62a13ace1a765871b9f2ed6d5390b6974dd83d8464de8dac01c42f0dbd3266a26590d23f6061a588a311cad956cba786d424d66a233d69f1723b05cf9687dedbeafbcd606d2cfe45c658fa93f7c60b2dff3192de4a46a5b3c5d433a7ce10ab4839e2d68ec1b1212fe59e00194546304872cf1cdf70cfd8f95e1ff29a0d8f4c6af06ef76f84ae1aaef9e49b9819c727043d4bad41125020d6ae4795d0b2d149c4c512d67eb90d7ae719b54007480e71cb7f2afbf0540d3185c3d406da673956541ec8036f9a58f757e761c8ed8c8ce7abcced08e611e51fc0055a1af838ff1fbfcd8d8700eedcf398a5af7993b07d431a2a4e47e01780c2b9e0538718a8c1e509d45011b9dbdc6d88d27d653ad99a736660b4631a59b4c2c96424044102c090bcb5b7d0470060af269d566df4cf7debd8c65d0eff5daef4eb5c31eae6c1deea303d850eaf9b5de2c6ec44d1c9a0a22503f1b07060b6e3efaa9e886150afdee91ec3e624bb67b1b6acae68e91221197727f54fde5399b400a73a8164bfc60785b208123404d311372236c1bdeeb88b49b8e29b19648b6c0f6ec6d28b9229344737ec81ed56bc207082f77bb0cc2f5a50e496cbfd2e9f6ff32ce30e1342b4a2e4ab6fb99637efeb6345822c0ab2cfa24ef511e4001316345d55e731a4da7ce41c847223440a3230bbfddcebf691ed059666ab12725b73a98216b8a1dcc5fca24e25aab3c5b62d5ff4e37d334f64bf5979601f7ab56450c5a15b277d906fd1c5ff61d1ad4f10720355a5f647586022b308fa1b2bacacdd4dbee0a23254b578ae9f0031a1b2122273d47686b767d98a6bbfd00000000000000000000000000000000000000000000000000000000000000000000000008141c2c"
+        ),
+        test_passed: false,
+    },
+];
+
+// Self-generated ML-DSA-65 verification cases: A signature produced with OpenSSL by the key from
+// the seed SHA-256("embedded-cal ML-DSA-65 sigVer"), and modified versions of it and its inputs,
+// which OpenSSL rejects. The "other key" is generated from
+// SHA-256("embedded-cal ML-DSA-65 sigVer other key").
+pub const INTEROP_MLDSA65_SIGVER: &[SignatureVerifyVector] = &[
+    // unmodified
+    SignatureVerifyVector {
+        cose_alg: -49,
+        pk: &hex!(
+            "284ab35e2646ad6eadfaca68920a1abb59ad38acee92ea01d54f27e5ab616b85c0aefabd734894c309e05346e0e2554da850ada2657852f1032858fff647ea35b8193dcf67f1f606fdff755926d5e0424acbcb97c561b2cabf8aa9f745ddfeb5c1421f2944a5c590be36ffa1c04bfecc627c43c1a24c4e16750c70035d9d30cc3269e7985163ee232c0821f1d56f86d7b5c5a2cda8e25ecbdd835f20d5216fd9094c9a3b2f3f16d7123f27b9d01a831add593c3df0a3ffc0da44818cb3afadc5e9fe31b2304386ab9042660dc06fcedac7b69e74039fe6b08a52d1a4f5c17c2bd54f1937bc834ae928bfc8c08bea2a38a750e0e65895444ac426a0257667929426a10d1c524f5f3ff3b3d07019b450991c1eb38017d2d1d8ff162cdd7d58ae374d745aa8992e7b003d775c8b83775ca97191264bdfcef5886977107ff470ff6365e046c564615a7b2213890dd965ffd55751154115dd9b307523f220d3da1fc7e17f2d2406eedaef6b35dfef14ac040c7a3c7251aa3d72522ab476ecfbf1cbda9625ec8ad53d7c0c0f950f4a3201b9c37b446f257a167355b622d7c69248b48cbe878ada0397cda11ec6e8a327dee5efe2cb77c92b5e424dd81fc3e4af0fca68fd4270ce255cd2bbaff04dcd428ba87ee2d18f5d43935237d47c73ae6363e308c32ffeee2456ff6ae0aaefb9439635c20adb5c5d1d05f75bf31057f3785a17191efd3d274ab6175a40aec6f2ac91b2687c430dbfdb59784875df7cdcf14db4de921d411d15959b4cd49a9c6800c4c4a25f6646d4092675e32376baf04cf2dae819d6ad2c5f1d9b88f1fd85f1724d324f011783df40db677277080988beeba23d1580f34c186ec259ad4da25ee57f3bf117e7f723b15ac71370e7fa9022905260b689035e56471305fe96b138f476a71141566a0c02587b7f17b2c4d76edce466f385e0f9960e49937d992144c468fca86094f4c6a820ce9350bdbe134588c6995733bb6aa1bb4d67a0704d2013a20188d4ea025945dde2f641faf06efa789a82e813b365aeffa8c765a8e6edcfabbc2df9920798b7a8e2bbbe827fd20bf0dde2f0ceeda0d9d40c076e1cfa9f65856aee6d92c3c782a9d326090a9a0dba368362c1b0a4f8742d269314c63e7b1886c255e7c0c745c8422fa36f018c1f2323267c66b9f3f9b38b10a8def00d08366a122973657728a4638a96c5f7f05f4f7ede7d06133b0e459e397b3391284dc3d83f4d4a4f986f46aa055f00c506a3c55d78cdfa392bf319324492b1e0210796c6893eb4e90062aebb5e4c885ea23df36e0a43310418c0e7ac02db0a99bd230b458815af21b2ede44875ffa859e98df4fef66cac2eca8a72ef3f2a99f56e7637bc45d130c7b25985e4d7b469ddcee62ed5c1ec0f7f3b40a4a62270a56794a00d0d10c72542ce58c84c474450524b759d23eb34588ce17fdeb607bc9e01cea325360415e22d10ba8b86541a533805650aa22771ff8174311f366f0880e69c74ac3fd8474947d72d1b7965e1aa8625efd0b63c198dcc47f1862458db359a16809570f3f7b46438c7f0cbd5a53970bfc7ab9b629915dc011785d62052a4be88449625173be21fb197741423ba76c259ca3bfb880cfb72366dcddd0abd294e13d56473eb851af685eb68918044b6dc2a433b5b252dddaf79a75ef54dd89790842db5df92c6ff5f7dcb97971a12e0fdcc2b297eba7be81462fbb3f5d6306b9686a45b4a09e5e455c1917209b676ec7f71afd67e6228be60914c182d0fa24b76a44a3ecd8c8db43bf9e0a3516f4468a83c9c0468215dab558154df1995e3ce866a920133e9ec8088fc97960fe20207d64b6e4f4989d6422ae1618196c4be46dc5cf3ba64f3c4a66b41293bd7b43bf7f4d169f60035f3d0a13fbbbda30dc665125d71e65ef9640890bbe98476219c1ebe5871000b86f5c26a13cd6edccbf62db74ef07163cf8a03167bd61f2c0b5817161c40d372632ab00be65c1ba64ce24603ba0804dea8d6d1ce655d21916ef1b759a071c7ecd91ddf893367287d6626cb4163d3f9562a881e93260064c9d75d486ef69846700e50d860e9d505a9bb45d7a7322d2595af407d7abe6b5a0332af1af9af3ff75c55aa37fb31962ba3657f72ad0f77095978bdfe587017256305b823350c8428befae38c3ae351ddd8352bfe3232140d523feaa94a3281aa625c06cfff7d6fa2fb0101b55aa29d6c24847a1a76be425143e570301f630a3cb9d203987c96591c587647c79821f2918359ad6bd36fcf475ceb71f5fb111ffe31604e07218216a2e4dfd9c76e6f88d41cfe431e5138069796e75ae4e2f9c9a7715b4325bd2d1561ec89e739c8071b2865f165600322cbb1276d1b55b724055ad8ccaba6e1537b9b3d79c7d235bbc8a9618baf62b762593dbea11575f2a2c0a2d007290a8ff1bfd06fcd1dbe30954f98f901ccd967757408db98ef3708d61c34367409d1a045d1b7ef9364ff32013cb5386f46c91d23380cbd42521f38ec90b1af19744a4c5ae915ff200b29767976620649ff740374bbd39801ae4c643f26a3354e843416c644f3a22a51c921121796e92ae0966ac1b0080fe7377babb0959ce4f4b1c3cd0c3999a3555b077965366f569484246b8a4ec2b8b3efbb0f3e9245f74bb367d830b9fcafb54c9103dd91dda235f07952759ed35bd70e0193cfa465104f025d86cb36afef88cdf667587510daa46c72eaae0d9890681099171314087d099e942da91e4f591328"
+        ),
+        message: b"embedded-cal ML-DSA-65 sigVer message",
+        signature: &hex!(
+            "e89378ed25582e0b83013761351f0d6f0306c70d4bf4d4eabc066ca08afea7921dc5f4dca0ba7045a45b619672290504f6b23d4e9ec2701ae3027dc689d712f775ffe5d25ed564c6ae30a85854de6f1b283d325ed852cc5af5d8b5612c51eccc85503b7be77ec80bc553551402fc292a8a19e45efb9be538d6435cf3920d07f6b388ce774c0c7b557432e0cbcdc5b43cb2a823fd9e1ba4160194f9b3e68e70df7ab81ea6cd1488561d7384144a5f703462a3da5eedea33988934e4191496bdcc0a9c52b1adac8fbc926c99afa1e58bfd9423a2e8ca7bacda34d71e7902c07c941b05d3868511e9419ff906885a9c7eb5355644e2513c8b1610c50cbd488a62fc6106b5e59a95339d3f49e3237579773b104eb3fc594fb9a496436443758844a0e56061008719508fada766a7b52f69bf5c72b9b5d089f75c5de58575db78fb10f9cbad9579e1b31d4914e43b1c2d049394c853647d1575b6b31e336d14c98be8731d070fcde355d6164906e3debc19be394caebab84c1412fe98d62f8f0227be390057e028827b63c9dc7758da7fea745626f39730a89165c8b7c4bb27bcc2bbad44687b4d0f52485228223b9b680ad4a22ac9e44f11618dbcea6ababb53c3cc657402bb7e65e99ffc6b8f0d02fb69f9dd59212d0f93f324fbb79f6c56aae69bfe3d428cb9802e1be49c7f49c8664926c6b5cd95f7ba5996e3cecbcd4ef78acbc353c6e3dd922d08055696b9d34f56cd2732ad9ef8babc9b3c7684bf6e564ae06c7edc4b7ed07c17dc46b2b5890c9bd7f963dd8a8d637196ccf6634d51dec2ce3c45e549a961dee05ca72ed58c803a5132bc6846ca8bb174bf50c16d7b136dccce4a90624c67b0631c91944db580d76380a8eda12cc28fda05c2bcc6e649f9a54fe35b85821489ccd76006026184b592942a4159eae214c0a5f5df8cdfbf5ae2a9ed4654cbf70251b3ae7c153c4cd6096675e0e172e44d9dd1ad2b82972c34a644f4c69e751602e25c2280a9bcc90c4e9d43f0c4831b1720340196e1beb2440c420d77dec14b47c9dfd241537f32ca6f95074da807cbe59a1a8f76f9d6518e9d667e1bd69fe4c1615cbbc5bc05327f9e73c2d2799681a0c1758782c542affe5ded9e601a87ae108f0f6a1b56004ec526cc82827b970ebab4cfefdc1b5af5147c4b3cad06da758f9e1a9f75ba1d37d40fd5892e091a72ebd2be38206228cfc01833eee210e46dd1ff68e4c5567c08fca7f7816a09a25292d26709bc43606f72bfa50339438b6527f16837c342165e6dbb96cbf46ff990942affc037f47d9489be3bab952620f441d5bbd29ca0e470af4328cf20b59db841c296787237773c235e971a565e511db019345a5ac02d32a1777b1c3b2b2e16d7c0158194e46fb7ab65e5f9a839dd33cb943de4a4005d5132457a8aa9663b751285347f0c5f268741dfcab819a530f2845530ecad099d6115997cdfe488b0faa1761849c894a90206ad2eba665185813b5422f1fa6ff45aac47cd77a1f527b12b45be0dab52ee1f079057b66993ac2d73e3790669c47dc96d7158fa4d2249f7788672ece9ec35dc988a118ce7afa92127b346cceec56a851ca30e67fa59b802bec888ec268db1fcd3a9dd9b760fa17da07814813249b633ea00ba34906cddd6decc60adba73d64017f4a6cc6381ec916ee8ef71c1a96440a1470f46ef708f41d2396b4a5e10bae611452557dde2d2c0880bfe09b348c7b6935e0a7d7f6a18916204d3f4b23df30526d44a0a89f9203251a6187842dacff605d84a54b33a7002f7a24910c0a3f1054f38e2d85832c2bc8a632ac6b8f99f08b3e000d46702ca7e1fb5ecf39cb730482fdf1a262d46c4d2c7ffc7efa8ffbb19a8345e05695aaa164cf2a4fde37c6310af4dc9aca16e8dda20ebf7747244bcc766e0398b41cc7d16ddd96776d99e816d9211d5b175dded173b272491513792673cfc8cf7d4987e16f6064c4ef6835dd4c571ca16f1435650561cc789f1732f3dcffe7bc88928912bf80ccedf9912dff28367f8f05e98ae1372e0415ede84e9c1a32c8a1c5978985dd0d3b37291e9ce1d0fde6fc41c662b8a7f16aec5c4dcfb220edeb966e033f283691c23393de3b88b620f4482bfb43b6f814c56592644185666c64ba363c9f402bb8472d2ceecf4e7bc1ef16f5c2a3fb916c4722ad6e65632f4412418e8cd65d76e16f4d9da4b9324be9617e8e8d247b60da5f9603149329c2cfcbc0bae87fe71bf6623e2eb7a983aecacadf7a66deebabd7989265f0ca529f8537e61698b06f5e6c5f1a6b4e27df08073106dc34b1afeea3531784c518ff356820f33a139e419d86b40dac01266fb6e21c66031776a738b9fa9f1d45df75864656005d0430b44b0510f234c63480de7cf15ae3cdd3d4c3e0c1f6ae8e0ae1c5aad256283ef44a1184b9640a98976853297e71ffbd726f3f81e85c5d6e58680e3f912685aeccb208779cf1502fcbacfbb3fbf659eaeb3881f446ce709e248afa26ee961a03de2a0b1dafb1471ac06b3fb42cbc59a077a4579615a0d69d2a67d11d1159ee3ee493641a1ed6c639a96ece7b8c3e2eea03c68d58b327da3450a67d4af9fc72c58a7f86d3f6f2d288aff30ed6d2a101239592272bdfe14b9dc6283d203b02b66a3942e06c301e4d93bd8a62d1be90e05eab09c8a3fb01dd9f18eb8761981ea2cdd0d792203feb84146209579cceae9772ec96aa25369dbe15b8b40869ad8375d90de9cd317c808cfc99a2ae48b8f6389b2f57441e31a50aa87ca1e8a0b02e665d7b14e58900d26af5b17c5e6aae17724431ce6e3fc0e127fa1036418f4f40b3eee5088a304461631bda4cc86dd961950ed1357b639f62e1251a8501e1fba2cdee979d357edaf731dbaf93995c2c3f3a3583c664ded19d964b2b9e46e58c0c295bdea60e19579f8ec78c16c590ddf8f4d3258ffced2b42cd9e8f6f58d606c2419361557857fc20a2ef16f2d919bd76df788d7f4fd39612c72076557ff23f356cd7cdc5cfa7e7261eabfa72f1f701e7ec47eb1bcb878a2ca37f4aa2826887c57bc440e0f55985e9ba80b44675b15e5a7d36128cb306aef36dfea1d826ff0631fcbc3c2736447ba1bc517fad7b7081efd2044c98421eaacdcfc7027e13b550d8589c96327d70eccae4d8098ec770e4749329348cf2e0d6f117577e5a63fc0ee1b8a5dcfa9e56d25725387ff69509a0170aa04cd60ab1c8c9094cb5e6eb28274c451d7d8d4b86452a4c86b9ddfe7fd20597f3fd5d2284cdddbfccf962c4bc60f22e3967dfc32ab7791b45abafa2d336310b034a8103b37ba072916d1ed5974af45b8aa6e48542dda3bb72066f0b7b0624bfd6c55a0b7fb366db40ee8490ab8ad1a4061c63570f3e2303b7f83499900c6f47670d27058a0062008654b3df857dea9b4194da7dd9009cc8c9106ae90e2fca1383b800b24a508eaf7e4dd34de0051543c1c0e60af1efbd48775da28538ebfbe1fa64cb13c67119a8ecccb9364bae74081628762586b5ffb2693fb23e7578efc2f7494b1a7d6c066c9949f178f89026e8c3030cb9925c4eeb6908243a7d9986f7eb3902be701e6b2ee7d1e581594f5250e99c6adf716e05a7913be58fc153635d86b04308c3049935398fd7cb1a3ecd0b66552a5fd47d5e20173e6783be3b15d1c094f71c91507333f738fba093a6e2ad694431e02dff19c8cb196cfee0d19a46dbc0214bae08d4af8e4461097aeaf6c3c70e8b1e08dc16e573adc8e7e6ea6e7059deb14c88c2318ec9f01252ce905e34a7165bbe0b05254457efc2952b13c43920fea2e9286767c87a5eb0c505ee406468573548a20aaad37be3fa96c6dd73618cc009b8884126b1f6d43a19dcb0bd5232b492886c533d5b2bb7b13971598ab55c5fc53b2555bd3c69a63f091f78af7cd1fda4a8249bce74723ee79a5433073f978ac699e5d899cc27a2464c19194d2479c479eee77d155c420e4de8bb526b36e6039922ae1240f479a845e6e72250a85fa8dc55a7b0b44f5a145741c3d0fcb42286e8f987e544c26f27ed3f9cd865592a513a32f951bd9ee5dea942c898b3cd5b64a8f6e5c04916f65442b3cfb57aec35943913d1ef784de47fd06fc86f1aa96554d171e96f844ac05f4381a16ccdc3c6f5194ca933fe30ea8ced6e8f27f2506b4e0bf604997492328fa37e73bf16b195a762f11f2bacd276a93c086a2eddf0750189ba47e8a69bc27253f67410ca60d5923f7751ab538dab1e2cd44ff5a10d1f322703c2ab9d25df0b6389fc9d7b308179db91362a5b9a7913ce1ff82a20e585eac97fe129688a243c986948d760a37935d1e030d011a123f6e3660a44dfb8eb183d6683e918604e8a76303f041246037cfaeb42698d63b4afa111f4bcc4b93d88ef18dd4854eae218df6d3a94487327aa2a91876ea023e355638e890a49bfc60e0033bae260767b049cc6d5c4b324f8404ab8789b84da637fde28ef71721165af151deeb35cc47d700258495c6a26534b65519a64268b6a2419dd40375ec48be419ec0f662e8bf01488bd65f6f6cae81ee0eb6c3192af83dd102b54210b665d9cfca62abd2a1abdee4668a9f011b2c394560678fcde8ed25353958bf15181d4f6b0122435f6e767779878da2f1fc16398dccea37588293c7f1000000000000000000000b101522272d"
+        ),
+        test_passed: true,
+    },
+    // modified message
+    SignatureVerifyVector {
+        cose_alg: -49,
+        pk: &hex!(
+            "284ab35e2646ad6eadfaca68920a1abb59ad38acee92ea01d54f27e5ab616b85c0aefabd734894c309e05346e0e2554da850ada2657852f1032858fff647ea35b8193dcf67f1f606fdff755926d5e0424acbcb97c561b2cabf8aa9f745ddfeb5c1421f2944a5c590be36ffa1c04bfecc627c43c1a24c4e16750c70035d9d30cc3269e7985163ee232c0821f1d56f86d7b5c5a2cda8e25ecbdd835f20d5216fd9094c9a3b2f3f16d7123f27b9d01a831add593c3df0a3ffc0da44818cb3afadc5e9fe31b2304386ab9042660dc06fcedac7b69e74039fe6b08a52d1a4f5c17c2bd54f1937bc834ae928bfc8c08bea2a38a750e0e65895444ac426a0257667929426a10d1c524f5f3ff3b3d07019b450991c1eb38017d2d1d8ff162cdd7d58ae374d745aa8992e7b003d775c8b83775ca97191264bdfcef5886977107ff470ff6365e046c564615a7b2213890dd965ffd55751154115dd9b307523f220d3da1fc7e17f2d2406eedaef6b35dfef14ac040c7a3c7251aa3d72522ab476ecfbf1cbda9625ec8ad53d7c0c0f950f4a3201b9c37b446f257a167355b622d7c69248b48cbe878ada0397cda11ec6e8a327dee5efe2cb77c92b5e424dd81fc3e4af0fca68fd4270ce255cd2bbaff04dcd428ba87ee2d18f5d43935237d47c73ae6363e308c32ffeee2456ff6ae0aaefb9439635c20adb5c5d1d05f75bf31057f3785a17191efd3d274ab6175a40aec6f2ac91b2687c430dbfdb59784875df7cdcf14db4de921d411d15959b4cd49a9c6800c4c4a25f6646d4092675e32376baf04cf2dae819d6ad2c5f1d9b88f1fd85f1724d324f011783df40db677277080988beeba23d1580f34c186ec259ad4da25ee57f3bf117e7f723b15ac71370e7fa9022905260b689035e56471305fe96b138f476a71141566a0c02587b7f17b2c4d76edce466f385e0f9960e49937d992144c468fca86094f4c6a820ce9350bdbe134588c6995733bb6aa1bb4d67a0704d2013a20188d4ea025945dde2f641faf06efa789a82e813b365aeffa8c765a8e6edcfabbc2df9920798b7a8e2bbbe827fd20bf0dde2f0ceeda0d9d40c076e1cfa9f65856aee6d92c3c782a9d326090a9a0dba368362c1b0a4f8742d269314c63e7b1886c255e7c0c745c8422fa36f018c1f2323267c66b9f3f9b38b10a8def00d08366a122973657728a4638a96c5f7f05f4f7ede7d06133b0e459e397b3391284dc3d83f4d4a4f986f46aa055f00c506a3c55d78cdfa392bf319324492b1e0210796c6893eb4e90062aebb5e4c885ea23df36e0a43310418c0e7ac02db0a99bd230b458815af21b2ede44875ffa859e98df4fef66cac2eca8a72ef3f2a99f56e7637bc45d130c7b25985e4d7b469ddcee62ed5c1ec0f7f3b40a4a62270a56794a00d0d10c72542ce58c84c474450524b759d23eb34588ce17fdeb607bc9e01cea325360415e22d10ba8b86541a533805650aa22771ff8174311f366f0880e69c74ac3fd8474947d72d1b7965e1aa8625efd0b63c198dcc47f1862458db359a16809570f3f7b46438c7f0cbd5a53970bfc7ab9b629915dc011785d62052a4be88449625173be21fb197741423ba76c259ca3bfb880cfb72366dcddd0abd294e13d56473eb851af685eb68918044b6dc2a433b5b252dddaf79a75ef54dd89790842db5df92c6ff5f7dcb97971a12e0fdcc2b297eba7be81462fbb3f5d6306b9686a45b4a09e5e455c1917209b676ec7f71afd67e6228be60914c182d0fa24b76a44a3ecd8c8db43bf9e0a3516f4468a83c9c0468215dab558154df1995e3ce866a920133e9ec8088fc97960fe20207d64b6e4f4989d6422ae1618196c4be46dc5cf3ba64f3c4a66b41293bd7b43bf7f4d169f60035f3d0a13fbbbda30dc665125d71e65ef9640890bbe98476219c1ebe5871000b86f5c26a13cd6edccbf62db74ef07163cf8a03167bd61f2c0b5817161c40d372632ab00be65c1ba64ce24603ba0804dea8d6d1ce655d21916ef1b759a071c7ecd91ddf893367287d6626cb4163d3f9562a881e93260064c9d75d486ef69846700e50d860e9d505a9bb45d7a7322d2595af407d7abe6b5a0332af1af9af3ff75c55aa37fb31962ba3657f72ad0f77095978bdfe587017256305b823350c8428befae38c3ae351ddd8352bfe3232140d523feaa94a3281aa625c06cfff7d6fa2fb0101b55aa29d6c24847a1a76be425143e570301f630a3cb9d203987c96591c587647c79821f2918359ad6bd36fcf475ceb71f5fb111ffe31604e07218216a2e4dfd9c76e6f88d41cfe431e5138069796e75ae4e2f9c9a7715b4325bd2d1561ec89e739c8071b2865f165600322cbb1276d1b55b724055ad8ccaba6e1537b9b3d79c7d235bbc8a9618baf62b762593dbea11575f2a2c0a2d007290a8ff1bfd06fcd1dbe30954f98f901ccd967757408db98ef3708d61c34367409d1a045d1b7ef9364ff32013cb5386f46c91d23380cbd42521f38ec90b1af19744a4c5ae915ff200b29767976620649ff740374bbd39801ae4c643f26a3354e843416c644f3a22a51c921121796e92ae0966ac1b0080fe7377babb0959ce4f4b1c3cd0c3999a3555b077965366f569484246b8a4ec2b8b3efbb0f3e9245f74bb367d830b9fcafb54c9103dd91dda235f07952759ed35bd70e0193cfa465104f025d86cb36afef88cdf667587510daa46c72eaae0d9890681099171314087d099e942da91e4f591328"
+        ),
+        message: b"embedded-cal ML-DSA-65 sigVer messag?",
+        signature: &hex!(
+            "e89378ed25582e0b83013761351f0d6f0306c70d4bf4d4eabc066ca08afea7921dc5f4dca0ba7045a45b619672290504f6b23d4e9ec2701ae3027dc689d712f775ffe5d25ed564c6ae30a85854de6f1b283d325ed852cc5af5d8b5612c51eccc85503b7be77ec80bc553551402fc292a8a19e45efb9be538d6435cf3920d07f6b388ce774c0c7b557432e0cbcdc5b43cb2a823fd9e1ba4160194f9b3e68e70df7ab81ea6cd1488561d7384144a5f703462a3da5eedea33988934e4191496bdcc0a9c52b1adac8fbc926c99afa1e58bfd9423a2e8ca7bacda34d71e7902c07c941b05d3868511e9419ff906885a9c7eb5355644e2513c8b1610c50cbd488a62fc6106b5e59a95339d3f49e3237579773b104eb3fc594fb9a496436443758844a0e56061008719508fada766a7b52f69bf5c72b9b5d089f75c5de58575db78fb10f9cbad9579e1b31d4914e43b1c2d049394c853647d1575b6b31e336d14c98be8731d070fcde355d6164906e3debc19be394caebab84c1412fe98d62f8f0227be390057e028827b63c9dc7758da7fea745626f39730a89165c8b7c4bb27bcc2bbad44687b4d0f52485228223b9b680ad4a22ac9e44f11618dbcea6ababb53c3cc657402bb7e65e99ffc6b8f0d02fb69f9dd59212d0f93f324fbb79f6c56aae69bfe3d428cb9802e1be49c7f49c8664926c6b5cd95f7ba5996e3cecbcd4ef78acbc353c6e3dd922d08055696b9d34f56cd2732ad9ef8babc9b3c7684bf6e564ae06c7edc4b7ed07c17dc46b2b5890c9bd7f963dd8a8d637196ccf6634d51dec2ce3c45e549a961dee05ca72ed58c803a5132bc6846ca8bb174bf50c16d7b136dccce4a90624c67b0631c91944db580d76380a8eda12cc28fda05c2bcc6e649f9a54fe35b85821489ccd76006026184b592942a4159eae214c0a5f5df8cdfbf5ae2a9ed4654cbf70251b3ae7c153c4cd6096675e0e172e44d9dd1ad2b82972c34a644f4c69e751602e25c2280a9bcc90c4e9d43f0c4831b1720340196e1beb2440c420d77dec14b47c9dfd241537f32ca6f95074da807cbe59a1a8f76f9d6518e9d667e1bd69fe4c1615cbbc5bc05327f9e73c2d2799681a0c1758782c542affe5ded9e601a87ae108f0f6a1b56004ec526cc82827b970ebab4cfefdc1b5af5147c4b3cad06da758f9e1a9f75ba1d37d40fd5892e091a72ebd2be38206228cfc01833eee210e46dd1ff68e4c5567c08fca7f7816a09a25292d26709bc43606f72bfa50339438b6527f16837c342165e6dbb96cbf46ff990942affc037f47d9489be3bab952620f441d5bbd29ca0e470af4328cf20b59db841c296787237773c235e971a565e511db019345a5ac02d32a1777b1c3b2b2e16d7c0158194e46fb7ab65e5f9a839dd33cb943de4a4005d5132457a8aa9663b751285347f0c5f268741dfcab819a530f2845530ecad099d6115997cdfe488b0faa1761849c894a90206ad2eba665185813b5422f1fa6ff45aac47cd77a1f527b12b45be0dab52ee1f079057b66993ac2d73e3790669c47dc96d7158fa4d2249f7788672ece9ec35dc988a118ce7afa92127b346cceec56a851ca30e67fa59b802bec888ec268db1fcd3a9dd9b760fa17da07814813249b633ea00ba34906cddd6decc60adba73d64017f4a6cc6381ec916ee8ef71c1a96440a1470f46ef708f41d2396b4a5e10bae611452557dde2d2c0880bfe09b348c7b6935e0a7d7f6a18916204d3f4b23df30526d44a0a89f9203251a6187842dacff605d84a54b33a7002f7a24910c0a3f1054f38e2d85832c2bc8a632ac6b8f99f08b3e000d46702ca7e1fb5ecf39cb730482fdf1a262d46c4d2c7ffc7efa8ffbb19a8345e05695aaa164cf2a4fde37c6310af4dc9aca16e8dda20ebf7747244bcc766e0398b41cc7d16ddd96776d99e816d9211d5b175dded173b272491513792673cfc8cf7d4987e16f6064c4ef6835dd4c571ca16f1435650561cc789f1732f3dcffe7bc88928912bf80ccedf9912dff28367f8f05e98ae1372e0415ede84e9c1a32c8a1c5978985dd0d3b37291e9ce1d0fde6fc41c662b8a7f16aec5c4dcfb220edeb966e033f283691c23393de3b88b620f4482bfb43b6f814c56592644185666c64ba363c9f402bb8472d2ceecf4e7bc1ef16f5c2a3fb916c4722ad6e65632f4412418e8cd65d76e16f4d9da4b9324be9617e8e8d247b60da5f9603149329c2cfcbc0bae87fe71bf6623e2eb7a983aecacadf7a66deebabd7989265f0ca529f8537e61698b06f5e6c5f1a6b4e27df08073106dc34b1afeea3531784c518ff356820f33a139e419d86b40dac01266fb6e21c66031776a738b9fa9f1d45df75864656005d0430b44b0510f234c63480de7cf15ae3cdd3d4c3e0c1f6ae8e0ae1c5aad256283ef44a1184b9640a98976853297e71ffbd726f3f81e85c5d6e58680e3f912685aeccb208779cf1502fcbacfbb3fbf659eaeb3881f446ce709e248afa26ee961a03de2a0b1dafb1471ac06b3fb42cbc59a077a4579615a0d69d2a67d11d1159ee3ee493641a1ed6c639a96ece7b8c3e2eea03c68d58b327da3450a67d4af9fc72c58a7f86d3f6f2d288aff30ed6d2a101239592272bdfe14b9dc6283d203b02b66a3942e06c301e4d93bd8a62d1be90e05eab09c8a3fb01dd9f18eb8761981ea2cdd0d792203feb84146209579cceae9772ec96aa25369dbe15b8b40869ad8375d90de9cd317c808cfc99a2ae48b8f6389b2f57441e31a50aa87ca1e8a0b02e665d7b14e58900d26af5b17c5e6aae17724431ce6e3fc0e127fa1036418f4f40b3eee5088a304461631bda4cc86dd961950ed1357b639f62e1251a8501e1fba2cdee979d357edaf731dbaf93995c2c3f3a3583c664ded19d964b2b9e46e58c0c295bdea60e19579f8ec78c16c590ddf8f4d3258ffced2b42cd9e8f6f58d606c2419361557857fc20a2ef16f2d919bd76df788d7f4fd39612c72076557ff23f356cd7cdc5cfa7e7261eabfa72f1f701e7ec47eb1bcb878a2ca37f4aa2826887c57bc440e0f55985e9ba80b44675b15e5a7d36128cb306aef36dfea1d826ff0631fcbc3c2736447ba1bc517fad7b7081efd2044c98421eaacdcfc7027e13b550d8589c96327d70eccae4d8098ec770e4749329348cf2e0d6f117577e5a63fc0ee1b8a5dcfa9e56d25725387ff69509a0170aa04cd60ab1c8c9094cb5e6eb28274c451d7d8d4b86452a4c86b9ddfe7fd20597f3fd5d2284cdddbfccf962c4bc60f22e3967dfc32ab7791b45abafa2d336310b034a8103b37ba072916d1ed5974af45b8aa6e48542dda3bb72066f0b7b0624bfd6c55a0b7fb366db40ee8490ab8ad1a4061c63570f3e2303b7f83499900c6f47670d27058a0062008654b3df857dea9b4194da7dd9009cc8c9106ae90e2fca1383b800b24a508eaf7e4dd34de0051543c1c0e60af1efbd48775da28538ebfbe1fa64cb13c67119a8ecccb9364bae74081628762586b5ffb2693fb23e7578efc2f7494b1a7d6c066c9949f178f89026e8c3030cb9925c4eeb6908243a7d9986f7eb3902be701e6b2ee7d1e581594f5250e99c6adf716e05a7913be58fc153635d86b04308c3049935398fd7cb1a3ecd0b66552a5fd47d5e20173e6783be3b15d1c094f71c91507333f738fba093a6e2ad694431e02dff19c8cb196cfee0d19a46dbc0214bae08d4af8e4461097aeaf6c3c70e8b1e08dc16e573adc8e7e6ea6e7059deb14c88c2318ec9f01252ce905e34a7165bbe0b05254457efc2952b13c43920fea2e9286767c87a5eb0c505ee406468573548a20aaad37be3fa96c6dd73618cc009b8884126b1f6d43a19dcb0bd5232b492886c533d5b2bb7b13971598ab55c5fc53b2555bd3c69a63f091f78af7cd1fda4a8249bce74723ee79a5433073f978ac699e5d899cc27a2464c19194d2479c479eee77d155c420e4de8bb526b36e6039922ae1240f479a845e6e72250a85fa8dc55a7b0b44f5a145741c3d0fcb42286e8f987e544c26f27ed3f9cd865592a513a32f951bd9ee5dea942c898b3cd5b64a8f6e5c04916f65442b3cfb57aec35943913d1ef784de47fd06fc86f1aa96554d171e96f844ac05f4381a16ccdc3c6f5194ca933fe30ea8ced6e8f27f2506b4e0bf604997492328fa37e73bf16b195a762f11f2bacd276a93c086a2eddf0750189ba47e8a69bc27253f67410ca60d5923f7751ab538dab1e2cd44ff5a10d1f322703c2ab9d25df0b6389fc9d7b308179db91362a5b9a7913ce1ff82a20e585eac97fe129688a243c986948d760a37935d1e030d011a123f6e3660a44dfb8eb183d6683e918604e8a76303f041246037cfaeb42698d63b4afa111f4bcc4b93d88ef18dd4854eae218df6d3a94487327aa2a91876ea023e355638e890a49bfc60e0033bae260767b049cc6d5c4b324f8404ab8789b84da637fde28ef71721165af151deeb35cc47d700258495c6a26534b65519a64268b6a2419dd40375ec48be419ec0f662e8bf01488bd65f6f6cae81ee0eb6c3192af83dd102b54210b665d9cfca62abd2a1abdee4668a9f011b2c394560678fcde8ed25353958bf15181d4f6b0122435f6e767779878da2f1fc16398dccea37588293c7f1000000000000000000000b101522272d"
+        ),
+        test_passed: false,
+    },
+    // modified signature (commitment hash)
+    SignatureVerifyVector {
+        cose_alg: -49,
+        pk: &hex!(
+            "284ab35e2646ad6eadfaca68920a1abb59ad38acee92ea01d54f27e5ab616b85c0aefabd734894c309e05346e0e2554da850ada2657852f1032858fff647ea35b8193dcf67f1f606fdff755926d5e0424acbcb97c561b2cabf8aa9f745ddfeb5c1421f2944a5c590be36ffa1c04bfecc627c43c1a24c4e16750c70035d9d30cc3269e7985163ee232c0821f1d56f86d7b5c5a2cda8e25ecbdd835f20d5216fd9094c9a3b2f3f16d7123f27b9d01a831add593c3df0a3ffc0da44818cb3afadc5e9fe31b2304386ab9042660dc06fcedac7b69e74039fe6b08a52d1a4f5c17c2bd54f1937bc834ae928bfc8c08bea2a38a750e0e65895444ac426a0257667929426a10d1c524f5f3ff3b3d07019b450991c1eb38017d2d1d8ff162cdd7d58ae374d745aa8992e7b003d775c8b83775ca97191264bdfcef5886977107ff470ff6365e046c564615a7b2213890dd965ffd55751154115dd9b307523f220d3da1fc7e17f2d2406eedaef6b35dfef14ac040c7a3c7251aa3d72522ab476ecfbf1cbda9625ec8ad53d7c0c0f950f4a3201b9c37b446f257a167355b622d7c69248b48cbe878ada0397cda11ec6e8a327dee5efe2cb77c92b5e424dd81fc3e4af0fca68fd4270ce255cd2bbaff04dcd428ba87ee2d18f5d43935237d47c73ae6363e308c32ffeee2456ff6ae0aaefb9439635c20adb5c5d1d05f75bf31057f3785a17191efd3d274ab6175a40aec6f2ac91b2687c430dbfdb59784875df7cdcf14db4de921d411d15959b4cd49a9c6800c4c4a25f6646d4092675e32376baf04cf2dae819d6ad2c5f1d9b88f1fd85f1724d324f011783df40db677277080988beeba23d1580f34c186ec259ad4da25ee57f3bf117e7f723b15ac71370e7fa9022905260b689035e56471305fe96b138f476a71141566a0c02587b7f17b2c4d76edce466f385e0f9960e49937d992144c468fca86094f4c6a820ce9350bdbe134588c6995733bb6aa1bb4d67a0704d2013a20188d4ea025945dde2f641faf06efa789a82e813b365aeffa8c765a8e6edcfabbc2df9920798b7a8e2bbbe827fd20bf0dde2f0ceeda0d9d40c076e1cfa9f65856aee6d92c3c782a9d326090a9a0dba368362c1b0a4f8742d269314c63e7b1886c255e7c0c745c8422fa36f018c1f2323267c66b9f3f9b38b10a8def00d08366a122973657728a4638a96c5f7f05f4f7ede7d06133b0e459e397b3391284dc3d83f4d4a4f986f46aa055f00c506a3c55d78cdfa392bf319324492b1e0210796c6893eb4e90062aebb5e4c885ea23df36e0a43310418c0e7ac02db0a99bd230b458815af21b2ede44875ffa859e98df4fef66cac2eca8a72ef3f2a99f56e7637bc45d130c7b25985e4d7b469ddcee62ed5c1ec0f7f3b40a4a62270a56794a00d0d10c72542ce58c84c474450524b759d23eb34588ce17fdeb607bc9e01cea325360415e22d10ba8b86541a533805650aa22771ff8174311f366f0880e69c74ac3fd8474947d72d1b7965e1aa8625efd0b63c198dcc47f1862458db359a16809570f3f7b46438c7f0cbd5a53970bfc7ab9b629915dc011785d62052a4be88449625173be21fb197741423ba76c259ca3bfb880cfb72366dcddd0abd294e13d56473eb851af685eb68918044b6dc2a433b5b252dddaf79a75ef54dd89790842db5df92c6ff5f7dcb97971a12e0fdcc2b297eba7be81462fbb3f5d6306b9686a45b4a09e5e455c1917209b676ec7f71afd67e6228be60914c182d0fa24b76a44a3ecd8c8db43bf9e0a3516f4468a83c9c0468215dab558154df1995e3ce866a920133e9ec8088fc97960fe20207d64b6e4f4989d6422ae1618196c4be46dc5cf3ba64f3c4a66b41293bd7b43bf7f4d169f60035f3d0a13fbbbda30dc665125d71e65ef9640890bbe98476219c1ebe5871000b86f5c26a13cd6edccbf62db74ef07163cf8a03167bd61f2c0b5817161c40d372632ab00be65c1ba64ce24603ba0804dea8d6d1ce655d21916ef1b759a071c7ecd91ddf893367287d6626cb4163d3f9562a881e93260064c9d75d486ef69846700e50d860e9d505a9bb45d7a7322d2595af407d7abe6b5a0332af1af9af3ff75c55aa37fb31962ba3657f72ad0f77095978bdfe587017256305b823350c8428befae38c3ae351ddd8352bfe3232140d523feaa94a3281aa625c06cfff7d6fa2fb0101b55aa29d6c24847a1a76be425143e570301f630a3cb9d203987c96591c587647c79821f2918359ad6bd36fcf475ceb71f5fb111ffe31604e07218216a2e4dfd9c76e6f88d41cfe431e5138069796e75ae4e2f9c9a7715b4325bd2d1561ec89e739c8071b2865f165600322cbb1276d1b55b724055ad8ccaba6e1537b9b3d79c7d235bbc8a9618baf62b762593dbea11575f2a2c0a2d007290a8ff1bfd06fcd1dbe30954f98f901ccd967757408db98ef3708d61c34367409d1a045d1b7ef9364ff32013cb5386f46c91d23380cbd42521f38ec90b1af19744a4c5ae915ff200b29767976620649ff740374bbd39801ae4c643f26a3354e843416c644f3a22a51c921121796e92ae0966ac1b0080fe7377babb0959ce4f4b1c3cd0c3999a3555b077965366f569484246b8a4ec2b8b3efbb0f3e9245f74bb367d830b9fcafb54c9103dd91dda235f07952759ed35bd70e0193cfa465104f025d86cb36afef88cdf667587510daa46c72eaae0d9890681099171314087d099e942da91e4f591328"
+        ),
+        message: b"embedded-cal ML-DSA-65 sigVer message",
+        signature: &hex!(
+            "e99378ed25582e0b83013761351f0d6f0306c70d4bf4d4eabc066ca08afea7921dc5f4dca0ba7045a45b619672290504f6b23d4e9ec2701ae3027dc689d712f775ffe5d25ed564c6ae30a85854de6f1b283d325ed852cc5af5d8b5612c51eccc85503b7be77ec80bc553551402fc292a8a19e45efb9be538d6435cf3920d07f6b388ce774c0c7b557432e0cbcdc5b43cb2a823fd9e1ba4160194f9b3e68e70df7ab81ea6cd1488561d7384144a5f703462a3da5eedea33988934e4191496bdcc0a9c52b1adac8fbc926c99afa1e58bfd9423a2e8ca7bacda34d71e7902c07c941b05d3868511e9419ff906885a9c7eb5355644e2513c8b1610c50cbd488a62fc6106b5e59a95339d3f49e3237579773b104eb3fc594fb9a496436443758844a0e56061008719508fada766a7b52f69bf5c72b9b5d089f75c5de58575db78fb10f9cbad9579e1b31d4914e43b1c2d049394c853647d1575b6b31e336d14c98be8731d070fcde355d6164906e3debc19be394caebab84c1412fe98d62f8f0227be390057e028827b63c9dc7758da7fea745626f39730a89165c8b7c4bb27bcc2bbad44687b4d0f52485228223b9b680ad4a22ac9e44f11618dbcea6ababb53c3cc657402bb7e65e99ffc6b8f0d02fb69f9dd59212d0f93f324fbb79f6c56aae69bfe3d428cb9802e1be49c7f49c8664926c6b5cd95f7ba5996e3cecbcd4ef78acbc353c6e3dd922d08055696b9d34f56cd2732ad9ef8babc9b3c7684bf6e564ae06c7edc4b7ed07c17dc46b2b5890c9bd7f963dd8a8d637196ccf6634d51dec2ce3c45e549a961dee05ca72ed58c803a5132bc6846ca8bb174bf50c16d7b136dccce4a90624c67b0631c91944db580d76380a8eda12cc28fda05c2bcc6e649f9a54fe35b85821489ccd76006026184b592942a4159eae214c0a5f5df8cdfbf5ae2a9ed4654cbf70251b3ae7c153c4cd6096675e0e172e44d9dd1ad2b82972c34a644f4c69e751602e25c2280a9bcc90c4e9d43f0c4831b1720340196e1beb2440c420d77dec14b47c9dfd241537f32ca6f95074da807cbe59a1a8f76f9d6518e9d667e1bd69fe4c1615cbbc5bc05327f9e73c2d2799681a0c1758782c542affe5ded9e601a87ae108f0f6a1b56004ec526cc82827b970ebab4cfefdc1b5af5147c4b3cad06da758f9e1a9f75ba1d37d40fd5892e091a72ebd2be38206228cfc01833eee210e46dd1ff68e4c5567c08fca7f7816a09a25292d26709bc43606f72bfa50339438b6527f16837c342165e6dbb96cbf46ff990942affc037f47d9489be3bab952620f441d5bbd29ca0e470af4328cf20b59db841c296787237773c235e971a565e511db019345a5ac02d32a1777b1c3b2b2e16d7c0158194e46fb7ab65e5f9a839dd33cb943de4a4005d5132457a8aa9663b751285347f0c5f268741dfcab819a530f2845530ecad099d6115997cdfe488b0faa1761849c894a90206ad2eba665185813b5422f1fa6ff45aac47cd77a1f527b12b45be0dab52ee1f079057b66993ac2d73e3790669c47dc96d7158fa4d2249f7788672ece9ec35dc988a118ce7afa92127b346cceec56a851ca30e67fa59b802bec888ec268db1fcd3a9dd9b760fa17da07814813249b633ea00ba34906cddd6decc60adba73d64017f4a6cc6381ec916ee8ef71c1a96440a1470f46ef708f41d2396b4a5e10bae611452557dde2d2c0880bfe09b348c7b6935e0a7d7f6a18916204d3f4b23df30526d44a0a89f9203251a6187842dacff605d84a54b33a7002f7a24910c0a3f1054f38e2d85832c2bc8a632ac6b8f99f08b3e000d46702ca7e1fb5ecf39cb730482fdf1a262d46c4d2c7ffc7efa8ffbb19a8345e05695aaa164cf2a4fde37c6310af4dc9aca16e8dda20ebf7747244bcc766e0398b41cc7d16ddd96776d99e816d9211d5b175dded173b272491513792673cfc8cf7d4987e16f6064c4ef6835dd4c571ca16f1435650561cc789f1732f3dcffe7bc88928912bf80ccedf9912dff28367f8f05e98ae1372e0415ede84e9c1a32c8a1c5978985dd0d3b37291e9ce1d0fde6fc41c662b8a7f16aec5c4dcfb220edeb966e033f283691c23393de3b88b620f4482bfb43b6f814c56592644185666c64ba363c9f402bb8472d2ceecf4e7bc1ef16f5c2a3fb916c4722ad6e65632f4412418e8cd65d76e16f4d9da4b9324be9617e8e8d247b60da5f9603149329c2cfcbc0bae87fe71bf6623e2eb7a983aecacadf7a66deebabd7989265f0ca529f8537e61698b06f5e6c5f1a6b4e27df08073106dc34b1afeea3531784c518ff356820f33a139e419d86b40dac01266fb6e21c66031776a738b9fa9f1d45df75864656005d0430b44b0510f234c63480de7cf15ae3cdd3d4c3e0c1f6ae8e0ae1c5aad256283ef44a1184b9640a98976853297e71ffbd726f3f81e85c5d6e58680e3f912685aeccb208779cf1502fcbacfbb3fbf659eaeb3881f446ce709e248afa26ee961a03de2a0b1dafb1471ac06b3fb42cbc59a077a4579615a0d69d2a67d11d1159ee3ee493641a1ed6c639a96ece7b8c3e2eea03c68d58b327da3450a67d4af9fc72c58a7f86d3f6f2d288aff30ed6d2a101239592272bdfe14b9dc6283d203b02b66a3942e06c301e4d93bd8a62d1be90e05eab09c8a3fb01dd9f18eb8761981ea2cdd0d792203feb84146209579cceae9772ec96aa25369dbe15b8b40869ad8375d90de9cd317c808cfc99a2ae48b8f6389b2f57441e31a50aa87ca1e8a0b02e665d7b14e58900d26af5b17c5e6aae17724431ce6e3fc0e127fa1036418f4f40b3eee5088a304461631bda4cc86dd961950ed1357b639f62e1251a8501e1fba2cdee979d357edaf731dbaf93995c2c3f3a3583c664ded19d964b2b9e46e58c0c295bdea60e19579f8ec78c16c590ddf8f4d3258ffced2b42cd9e8f6f58d606c2419361557857fc20a2ef16f2d919bd76df788d7f4fd39612c72076557ff23f356cd7cdc5cfa7e7261eabfa72f1f701e7ec47eb1bcb878a2ca37f4aa2826887c57bc440e0f55985e9ba80b44675b15e5a7d36128cb306aef36dfea1d826ff0631fcbc3c2736447ba1bc517fad7b7081efd2044c98421eaacdcfc7027e13b550d8589c96327d70eccae4d8098ec770e4749329348cf2e0d6f117577e5a63fc0ee1b8a5dcfa9e56d25725387ff69509a0170aa04cd60ab1c8c9094cb5e6eb28274c451d7d8d4b86452a4c86b9ddfe7fd20597f3fd5d2284cdddbfccf962c4bc60f22e3967dfc32ab7791b45abafa2d336310b034a8103b37ba072916d1ed5974af45b8aa6e48542dda3bb72066f0b7b0624bfd6c55a0b7fb366db40ee8490ab8ad1a4061c63570f3e2303b7f83499900c6f47670d27058a0062008654b3df857dea9b4194da7dd9009cc8c9106ae90e2fca1383b800b24a508eaf7e4dd34de0051543c1c0e60af1efbd48775da28538ebfbe1fa64cb13c67119a8ecccb9364bae74081628762586b5ffb2693fb23e7578efc2f7494b1a7d6c066c9949f178f89026e8c3030cb9925c4eeb6908243a7d9986f7eb3902be701e6b2ee7d1e581594f5250e99c6adf716e05a7913be58fc153635d86b04308c3049935398fd7cb1a3ecd0b66552a5fd47d5e20173e6783be3b15d1c094f71c91507333f738fba093a6e2ad694431e02dff19c8cb196cfee0d19a46dbc0214bae08d4af8e4461097aeaf6c3c70e8b1e08dc16e573adc8e7e6ea6e7059deb14c88c2318ec9f01252ce905e34a7165bbe0b05254457efc2952b13c43920fea2e9286767c87a5eb0c505ee406468573548a20aaad37be3fa96c6dd73618cc009b8884126b1f6d43a19dcb0bd5232b492886c533d5b2bb7b13971598ab55c5fc53b2555bd3c69a63f091f78af7cd1fda4a8249bce74723ee79a5433073f978ac699e5d899cc27a2464c19194d2479c479eee77d155c420e4de8bb526b36e6039922ae1240f479a845e6e72250a85fa8dc55a7b0b44f5a145741c3d0fcb42286e8f987e544c26f27ed3f9cd865592a513a32f951bd9ee5dea942c898b3cd5b64a8f6e5c04916f65442b3cfb57aec35943913d1ef784de47fd06fc86f1aa96554d171e96f844ac05f4381a16ccdc3c6f5194ca933fe30ea8ced6e8f27f2506b4e0bf604997492328fa37e73bf16b195a762f11f2bacd276a93c086a2eddf0750189ba47e8a69bc27253f67410ca60d5923f7751ab538dab1e2cd44ff5a10d1f322703c2ab9d25df0b6389fc9d7b308179db91362a5b9a7913ce1ff82a20e585eac97fe129688a243c986948d760a37935d1e030d011a123f6e3660a44dfb8eb183d6683e918604e8a76303f041246037cfaeb42698d63b4afa111f4bcc4b93d88ef18dd4854eae218df6d3a94487327aa2a91876ea023e355638e890a49bfc60e0033bae260767b049cc6d5c4b324f8404ab8789b84da637fde28ef71721165af151deeb35cc47d700258495c6a26534b65519a64268b6a2419dd40375ec48be419ec0f662e8bf01488bd65f6f6cae81ee0eb6c3192af83dd102b54210b665d9cfca62abd2a1abdee4668a9f011b2c394560678fcde8ed25353958bf15181d4f6b0122435f6e767779878da2f1fc16398dccea37588293c7f1000000000000000000000b101522272d"
+        ),
+        test_passed: false,
+    },
+    // modified signature (response)
+    SignatureVerifyVector {
+        cose_alg: -49,
+        pk: &hex!(
+            "284ab35e2646ad6eadfaca68920a1abb59ad38acee92ea01d54f27e5ab616b85c0aefabd734894c309e05346e0e2554da850ada2657852f1032858fff647ea35b8193dcf67f1f606fdff755926d5e0424acbcb97c561b2cabf8aa9f745ddfeb5c1421f2944a5c590be36ffa1c04bfecc627c43c1a24c4e16750c70035d9d30cc3269e7985163ee232c0821f1d56f86d7b5c5a2cda8e25ecbdd835f20d5216fd9094c9a3b2f3f16d7123f27b9d01a831add593c3df0a3ffc0da44818cb3afadc5e9fe31b2304386ab9042660dc06fcedac7b69e74039fe6b08a52d1a4f5c17c2bd54f1937bc834ae928bfc8c08bea2a38a750e0e65895444ac426a0257667929426a10d1c524f5f3ff3b3d07019b450991c1eb38017d2d1d8ff162cdd7d58ae374d745aa8992e7b003d775c8b83775ca97191264bdfcef5886977107ff470ff6365e046c564615a7b2213890dd965ffd55751154115dd9b307523f220d3da1fc7e17f2d2406eedaef6b35dfef14ac040c7a3c7251aa3d72522ab476ecfbf1cbda9625ec8ad53d7c0c0f950f4a3201b9c37b446f257a167355b622d7c69248b48cbe878ada0397cda11ec6e8a327dee5efe2cb77c92b5e424dd81fc3e4af0fca68fd4270ce255cd2bbaff04dcd428ba87ee2d18f5d43935237d47c73ae6363e308c32ffeee2456ff6ae0aaefb9439635c20adb5c5d1d05f75bf31057f3785a17191efd3d274ab6175a40aec6f2ac91b2687c430dbfdb59784875df7cdcf14db4de921d411d15959b4cd49a9c6800c4c4a25f6646d4092675e32376baf04cf2dae819d6ad2c5f1d9b88f1fd85f1724d324f011783df40db677277080988beeba23d1580f34c186ec259ad4da25ee57f3bf117e7f723b15ac71370e7fa9022905260b689035e56471305fe96b138f476a71141566a0c02587b7f17b2c4d76edce466f385e0f9960e49937d992144c468fca86094f4c6a820ce9350bdbe134588c6995733bb6aa1bb4d67a0704d2013a20188d4ea025945dde2f641faf06efa789a82e813b365aeffa8c765a8e6edcfabbc2df9920798b7a8e2bbbe827fd20bf0dde2f0ceeda0d9d40c076e1cfa9f65856aee6d92c3c782a9d326090a9a0dba368362c1b0a4f8742d269314c63e7b1886c255e7c0c745c8422fa36f018c1f2323267c66b9f3f9b38b10a8def00d08366a122973657728a4638a96c5f7f05f4f7ede7d06133b0e459e397b3391284dc3d83f4d4a4f986f46aa055f00c506a3c55d78cdfa392bf319324492b1e0210796c6893eb4e90062aebb5e4c885ea23df36e0a43310418c0e7ac02db0a99bd230b458815af21b2ede44875ffa859e98df4fef66cac2eca8a72ef3f2a99f56e7637bc45d130c7b25985e4d7b469ddcee62ed5c1ec0f7f3b40a4a62270a56794a00d0d10c72542ce58c84c474450524b759d23eb34588ce17fdeb607bc9e01cea325360415e22d10ba8b86541a533805650aa22771ff8174311f366f0880e69c74ac3fd8474947d72d1b7965e1aa8625efd0b63c198dcc47f1862458db359a16809570f3f7b46438c7f0cbd5a53970bfc7ab9b629915dc011785d62052a4be88449625173be21fb197741423ba76c259ca3bfb880cfb72366dcddd0abd294e13d56473eb851af685eb68918044b6dc2a433b5b252dddaf79a75ef54dd89790842db5df92c6ff5f7dcb97971a12e0fdcc2b297eba7be81462fbb3f5d6306b9686a45b4a09e5e455c1917209b676ec7f71afd67e6228be60914c182d0fa24b76a44a3ecd8c8db43bf9e0a3516f4468a83c9c0468215dab558154df1995e3ce866a920133e9ec8088fc97960fe20207d64b6e4f4989d6422ae1618196c4be46dc5cf3ba64f3c4a66b41293bd7b43bf7f4d169f60035f3d0a13fbbbda30dc665125d71e65ef9640890bbe98476219c1ebe5871000b86f5c26a13cd6edccbf62db74ef07163cf8a03167bd61f2c0b5817161c40d372632ab00be65c1ba64ce24603ba0804dea8d6d1ce655d21916ef1b759a071c7ecd91ddf893367287d6626cb4163d3f9562a881e93260064c9d75d486ef69846700e50d860e9d505a9bb45d7a7322d2595af407d7abe6b5a0332af1af9af3ff75c55aa37fb31962ba3657f72ad0f77095978bdfe587017256305b823350c8428befae38c3ae351ddd8352bfe3232140d523feaa94a3281aa625c06cfff7d6fa2fb0101b55aa29d6c24847a1a76be425143e570301f630a3cb9d203987c96591c587647c79821f2918359ad6bd36fcf475ceb71f5fb111ffe31604e07218216a2e4dfd9c76e6f88d41cfe431e5138069796e75ae4e2f9c9a7715b4325bd2d1561ec89e739c8071b2865f165600322cbb1276d1b55b724055ad8ccaba6e1537b9b3d79c7d235bbc8a9618baf62b762593dbea11575f2a2c0a2d007290a8ff1bfd06fcd1dbe30954f98f901ccd967757408db98ef3708d61c34367409d1a045d1b7ef9364ff32013cb5386f46c91d23380cbd42521f38ec90b1af19744a4c5ae915ff200b29767976620649ff740374bbd39801ae4c643f26a3354e843416c644f3a22a51c921121796e92ae0966ac1b0080fe7377babb0959ce4f4b1c3cd0c3999a3555b077965366f569484246b8a4ec2b8b3efbb0f3e9245f74bb367d830b9fcafb54c9103dd91dda235f07952759ed35bd70e0193cfa465104f025d86cb36afef88cdf667587510daa46c72eaae0d9890681099171314087d099e942da91e4f591328"
+        ),
+        message: b"embedded-cal ML-DSA-65 sigVer message",
+        signature: &hex!(
+            "e89378ed25582e0b83013761351f0d6f0306c70d4bf4d4eabc066ca08afea7921dc5f4dca0ba7045a45b619672290504f6b23d4e9ec2701ae3027dc689d712f775ffe5d25ed564c6ae30a85854de6f1b283d325ed852cc5af5d8b5612c51eccc85503b7be77ec80bc553551402fc292a8a19e45efb9be538d6435cf3920d07f6b388ce774c0c7b557432e0cbcdc5b43cb2a823fd9e1ba4160194f9b3e68e70df7ab81ea6cd1488561d7384144a5f703462a3da5eedea33988934e4191496bdcc0a9c52b1adac8fbc926c99afa1e58bfd9423a2e8ca7bacda34d71e7902c07c941b05d3868511e9419ff906885a9c7eb5355644e2513c8b1610c50cbd488a62fc6106b5e59a95339d3f49e3237579773b104eb3fc594fb9a496436443758844a0e56061008719508fada766a7b52f69bf5c72b9b5d089f75c5de58575db78fb10f9cbad9579e1b31d4914e43b1c2d049394c853647d1575b6b31e336d14c98be8731d070fcde355d6164906e3debc19be394caebab84c1412fe98d62f8f0227be390057e028827b63c9dc7758da7fea745626f39730a89165c8b7c4bb27bcc2bbad44687b4d0f52485228223b9b680ad4a22ac9e44f11618dbcea6ababb53c3cc657402bb7e65e99ffc6b8f0d02fb69f9dd59212d0f93f324fbb79f6c56aae69bfe3d428cb9802e1be49c7f49c8664926c6b5cd95f7ba5996e3cecbcd4ef78acbc353c6e3dd922d08055696b9d34f56cd2732ad9ef8babc9b3c7684bf6e564ae06c7edc4b7ed07c17dc46b2b5890c9bd7f963dd8a8d637196ccf6634d51dec2ce3c45e549a961dee05ca72ed58c803a5132bc6846ca8bb174bf50c16d7b136dccce4a90624c67b0631c91944db580d76380a8eda12cc28fda05c2bcc6e649f9a54fe35b85821489ccd76006026184b592942a4159eae214c0a5f5df8cdfbf5ae2a9ed4654cbf70251b3ae7c153c4cd6096675e0e172e44d9dd1ad2b82972c34a644f4c69e751602e25c2280a9bcc90c4e9d43f0c4831b1720340196e1beb2440c420d77dec14b47c9dfd241537f32ca6f95074da807cbe59a1a8f76f9d6518e9d667e1bd69fe4c1615cbbc5bc05327f9e73c2d2799681a0c1758782c542affe5ded9e601a87ae108f0f6a1b56004ec526cc82827b970ebab4cfefdc1b5af5147c4b3cad06da758f9e1a9f75ba1d37d40fd5892e091a72ebd2be38206228cfc01833eee210e46dd1ff68e4c5567c08fca7f7816a09a25292d26709bc43606f72bfa50339438b6527f16837c342165e6dbb96cbf46ff990942affc037f47d9489be3bab952620f441d5bbd29ca0e470af4328cf20b59db841c296787237773c235e971a565e511db019345a5ac02d32a1777b1c3b2b2e16d7c0158194e46fb7ab65e5f9a839dd33cb943de4a4005d5132457a8aa9663b751285347f0c5f268741dfcab819a530f2845530ecad099d6115997cdfe488b0faa1761849c894a90206ad2eba665185813b5422f1fa6ff45aac47cd77a1f527b12b45be0dab52ee1f079057b66993ac2d73e3790669c47dc96d7158fa4d2249f7788672ece9ec35dc988a118ce7afa92127b346cceec56a851ca30e67fa59b802bec888ec268db1fcd3a9dd9b760fa17da07814813249b633ea00ba34906cddd6decc60adba73d64017f4a6cc6381ec916ee8ef71c1a96440a1470f46ef708f41d2396b4a5e10bae611452557dde2d2c0880bfe09b348c7b6935e0a7d7f6a18916204d3f4b23df30526d44a0a89f9203251a6187842dacff605d84a54b33a7002f7a24910c0a3f1054f38e2d85832c2bc8a632ac6b8f99f08b3e000d46702ca7e1fb5ecf39cb730482fdf1a262d46c4d2c7ffc7efa8ffbb19a8345e05695aaa164cf2a4fde37c6310af4dc9aca16e8dda20ebf7747244bcc766e0398b41cc7d16ddd96776d99e816d9211d5b175dded173b272491513792673cfc8cf7d4987e16f6064c4ef6835dd4c571ca16f1435650561cc789f1732f3dcffe7bc88928912bf80ccedf9912dff28367f8f05e98ae1372e0415ede84e9c1a32c8a1c5978985dd0d3b37291e9ce1d0fde6fc41c662b8a7f16aec5c4dcfb220edeb966e033f283691c23393de3b88b620f4482bfb43b6f814c56592644185666c64ba363c9f402bb8472d2ceecf4e7bc1ef16f5c2a3fb916c4722ad6e65632f4412418e8cd65d76e16f4d9da4b9324be9617e8e8d247b60da5f9603149329c2cfcbc0bae87fe71bf6623e2eb7a983aecacadf7a66deebabd7989265f0ca529f8537e61698b06f5e6c5f1a6b4e27df08073106dc34b1afeea3531784c518ff356820f33a139e419c86b40dac01266fb6e21c66031776a738b9fa9f1d45df75864656005d0430b44b0510f234c63480de7cf15ae3cdd3d4c3e0c1f6ae8e0ae1c5aad256283ef44a1184b9640a98976853297e71ffbd726f3f81e85c5d6e58680e3f912685aeccb208779cf1502fcbacfbb3fbf659eaeb3881f446ce709e248afa26ee961a03de2a0b1dafb1471ac06b3fb42cbc59a077a4579615a0d69d2a67d11d1159ee3ee493641a1ed6c639a96ece7b8c3e2eea03c68d58b327da3450a67d4af9fc72c58a7f86d3f6f2d288aff30ed6d2a101239592272bdfe14b9dc6283d203b02b66a3942e06c301e4d93bd8a62d1be90e05eab09c8a3fb01dd9f18eb8761981ea2cdd0d792203feb84146209579cceae9772ec96aa25369dbe15b8b40869ad8375d90de9cd317c808cfc99a2ae48b8f6389b2f57441e31a50aa87ca1e8a0b02e665d7b14e58900d26af5b17c5e6aae17724431ce6e3fc0e127fa1036418f4f40b3eee5088a304461631bda4cc86dd961950ed1357b639f62e1251a8501e1fba2cdee979d357edaf731dbaf93995c2c3f3a3583c664ded19d964b2b9e46e58c0c295bdea60e19579f8ec78c16c590ddf8f4d3258ffced2b42cd9e8f6f58d606c2419361557857fc20a2ef16f2d919bd76df788d7f4fd39612c72076557ff23f356cd7cdc5cfa7e7261eabfa72f1f701e7ec47eb1bcb878a2ca37f4aa2826887c57bc440e0f55985e9ba80b44675b15e5a7d36128cb306aef36dfea1d826ff0631fcbc3c2736447ba1bc517fad7b7081efd2044c98421eaacdcfc7027e13b550d8589c96327d70eccae4d8098ec770e4749329348cf2e0d6f117577e5a63fc0ee1b8a5dcfa9e56d25725387ff69509a0170aa04cd60ab1c8c9094cb5e6eb28274c451d7d8d4b86452a4c86b9ddfe7fd20597f3fd5d2284cdddbfccf962c4bc60f22e3967dfc32ab7791b45abafa2d336310b034a8103b37ba072916d1ed5974af45b8aa6e48542dda3bb72066f0b7b0624bfd6c55a0b7fb366db40ee8490ab8ad1a4061c63570f3e2303b7f83499900c6f47670d27058a0062008654b3df857dea9b4194da7dd9009cc8c9106ae90e2fca1383b800b24a508eaf7e4dd34de0051543c1c0e60af1efbd48775da28538ebfbe1fa64cb13c67119a8ecccb9364bae74081628762586b5ffb2693fb23e7578efc2f7494b1a7d6c066c9949f178f89026e8c3030cb9925c4eeb6908243a7d9986f7eb3902be701e6b2ee7d1e581594f5250e99c6adf716e05a7913be58fc153635d86b04308c3049935398fd7cb1a3ecd0b66552a5fd47d5e20173e6783be3b15d1c094f71c91507333f738fba093a6e2ad694431e02dff19c8cb196cfee0d19a46dbc0214bae08d4af8e4461097aeaf6c3c70e8b1e08dc16e573adc8e7e6ea6e7059deb14c88c2318ec9f01252ce905e34a7165bbe0b05254457efc2952b13c43920fea2e9286767c87a5eb0c505ee406468573548a20aaad37be3fa96c6dd73618cc009b8884126b1f6d43a19dcb0bd5232b492886c533d5b2bb7b13971598ab55c5fc53b2555bd3c69a63f091f78af7cd1fda4a8249bce74723ee79a5433073f978ac699e5d899cc27a2464c19194d2479c479eee77d155c420e4de8bb526b36e6039922ae1240f479a845e6e72250a85fa8dc55a7b0b44f5a145741c3d0fcb42286e8f987e544c26f27ed3f9cd865592a513a32f951bd9ee5dea942c898b3cd5b64a8f6e5c04916f65442b3cfb57aec35943913d1ef784de47fd06fc86f1aa96554d171e96f844ac05f4381a16ccdc3c6f5194ca933fe30ea8ced6e8f27f2506b4e0bf604997492328fa37e73bf16b195a762f11f2bacd276a93c086a2eddf0750189ba47e8a69bc27253f67410ca60d5923f7751ab538dab1e2cd44ff5a10d1f322703c2ab9d25df0b6389fc9d7b308179db91362a5b9a7913ce1ff82a20e585eac97fe129688a243c986948d760a37935d1e030d011a123f6e3660a44dfb8eb183d6683e918604e8a76303f041246037cfaeb42698d63b4afa111f4bcc4b93d88ef18dd4854eae218df6d3a94487327aa2a91876ea023e355638e890a49bfc60e0033bae260767b049cc6d5c4b324f8404ab8789b84da637fde28ef71721165af151deeb35cc47d700258495c6a26534b65519a64268b6a2419dd40375ec48be419ec0f662e8bf01488bd65f6f6cae81ee0eb6c3192af83dd102b54210b665d9cfca62abd2a1abdee4668a9f011b2c394560678fcde8ed25353958bf15181d4f6b0122435f6e767779878da2f1fc16398dccea37588293c7f1000000000000000000000b101522272d"
+        ),
+        test_passed: false,
+    },
+    // modified signature (hint)
+    SignatureVerifyVector {
+        cose_alg: -49,
+        pk: &hex!(
+            "284ab35e2646ad6eadfaca68920a1abb59ad38acee92ea01d54f27e5ab616b85c0aefabd734894c309e05346e0e2554da850ada2657852f1032858fff647ea35b8193dcf67f1f606fdff755926d5e0424acbcb97c561b2cabf8aa9f745ddfeb5c1421f2944a5c590be36ffa1c04bfecc627c43c1a24c4e16750c70035d9d30cc3269e7985163ee232c0821f1d56f86d7b5c5a2cda8e25ecbdd835f20d5216fd9094c9a3b2f3f16d7123f27b9d01a831add593c3df0a3ffc0da44818cb3afadc5e9fe31b2304386ab9042660dc06fcedac7b69e74039fe6b08a52d1a4f5c17c2bd54f1937bc834ae928bfc8c08bea2a38a750e0e65895444ac426a0257667929426a10d1c524f5f3ff3b3d07019b450991c1eb38017d2d1d8ff162cdd7d58ae374d745aa8992e7b003d775c8b83775ca97191264bdfcef5886977107ff470ff6365e046c564615a7b2213890dd965ffd55751154115dd9b307523f220d3da1fc7e17f2d2406eedaef6b35dfef14ac040c7a3c7251aa3d72522ab476ecfbf1cbda9625ec8ad53d7c0c0f950f4a3201b9c37b446f257a167355b622d7c69248b48cbe878ada0397cda11ec6e8a327dee5efe2cb77c92b5e424dd81fc3e4af0fca68fd4270ce255cd2bbaff04dcd428ba87ee2d18f5d43935237d47c73ae6363e308c32ffeee2456ff6ae0aaefb9439635c20adb5c5d1d05f75bf31057f3785a17191efd3d274ab6175a40aec6f2ac91b2687c430dbfdb59784875df7cdcf14db4de921d411d15959b4cd49a9c6800c4c4a25f6646d4092675e32376baf04cf2dae819d6ad2c5f1d9b88f1fd85f1724d324f011783df40db677277080988beeba23d1580f34c186ec259ad4da25ee57f3bf117e7f723b15ac71370e7fa9022905260b689035e56471305fe96b138f476a71141566a0c02587b7f17b2c4d76edce466f385e0f9960e49937d992144c468fca86094f4c6a820ce9350bdbe134588c6995733bb6aa1bb4d67a0704d2013a20188d4ea025945dde2f641faf06efa789a82e813b365aeffa8c765a8e6edcfabbc2df9920798b7a8e2bbbe827fd20bf0dde2f0ceeda0d9d40c076e1cfa9f65856aee6d92c3c782a9d326090a9a0dba368362c1b0a4f8742d269314c63e7b1886c255e7c0c745c8422fa36f018c1f2323267c66b9f3f9b38b10a8def00d08366a122973657728a4638a96c5f7f05f4f7ede7d06133b0e459e397b3391284dc3d83f4d4a4f986f46aa055f00c506a3c55d78cdfa392bf319324492b1e0210796c6893eb4e90062aebb5e4c885ea23df36e0a43310418c0e7ac02db0a99bd230b458815af21b2ede44875ffa859e98df4fef66cac2eca8a72ef3f2a99f56e7637bc45d130c7b25985e4d7b469ddcee62ed5c1ec0f7f3b40a4a62270a56794a00d0d10c72542ce58c84c474450524b759d23eb34588ce17fdeb607bc9e01cea325360415e22d10ba8b86541a533805650aa22771ff8174311f366f0880e69c74ac3fd8474947d72d1b7965e1aa8625efd0b63c198dcc47f1862458db359a16809570f3f7b46438c7f0cbd5a53970bfc7ab9b629915dc011785d62052a4be88449625173be21fb197741423ba76c259ca3bfb880cfb72366dcddd0abd294e13d56473eb851af685eb68918044b6dc2a433b5b252dddaf79a75ef54dd89790842db5df92c6ff5f7dcb97971a12e0fdcc2b297eba7be81462fbb3f5d6306b9686a45b4a09e5e455c1917209b676ec7f71afd67e6228be60914c182d0fa24b76a44a3ecd8c8db43bf9e0a3516f4468a83c9c0468215dab558154df1995e3ce866a920133e9ec8088fc97960fe20207d64b6e4f4989d6422ae1618196c4be46dc5cf3ba64f3c4a66b41293bd7b43bf7f4d169f60035f3d0a13fbbbda30dc665125d71e65ef9640890bbe98476219c1ebe5871000b86f5c26a13cd6edccbf62db74ef07163cf8a03167bd61f2c0b5817161c40d372632ab00be65c1ba64ce24603ba0804dea8d6d1ce655d21916ef1b759a071c7ecd91ddf893367287d6626cb4163d3f9562a881e93260064c9d75d486ef69846700e50d860e9d505a9bb45d7a7322d2595af407d7abe6b5a0332af1af9af3ff75c55aa37fb31962ba3657f72ad0f77095978bdfe587017256305b823350c8428befae38c3ae351ddd8352bfe3232140d523feaa94a3281aa625c06cfff7d6fa2fb0101b55aa29d6c24847a1a76be425143e570301f630a3cb9d203987c96591c587647c79821f2918359ad6bd36fcf475ceb71f5fb111ffe31604e07218216a2e4dfd9c76e6f88d41cfe431e5138069796e75ae4e2f9c9a7715b4325bd2d1561ec89e739c8071b2865f165600322cbb1276d1b55b724055ad8ccaba6e1537b9b3d79c7d235bbc8a9618baf62b762593dbea11575f2a2c0a2d007290a8ff1bfd06fcd1dbe30954f98f901ccd967757408db98ef3708d61c34367409d1a045d1b7ef9364ff32013cb5386f46c91d23380cbd42521f38ec90b1af19744a4c5ae915ff200b29767976620649ff740374bbd39801ae4c643f26a3354e843416c644f3a22a51c921121796e92ae0966ac1b0080fe7377babb0959ce4f4b1c3cd0c3999a3555b077965366f569484246b8a4ec2b8b3efbb0f3e9245f74bb367d830b9fcafb54c9103dd91dda235f07952759ed35bd70e0193cfa465104f025d86cb36afef88cdf667587510daa46c72eaae0d9890681099171314087d099e942da91e4f591328"
+        ),
+        message: b"embedded-cal ML-DSA-65 sigVer message",
+        signature: &hex!(
+            "e89378ed25582e0b83013761351f0d6f0306c70d4bf4d4eabc066ca08afea7921dc5f4dca0ba7045a45b619672290504f6b23d4e9ec2701ae3027dc689d712f775ffe5d25ed564c6ae30a85854de6f1b283d325ed852cc5af5d8b5612c51eccc85503b7be77ec80bc553551402fc292a8a19e45efb9be538d6435cf3920d07f6b388ce774c0c7b557432e0cbcdc5b43cb2a823fd9e1ba4160194f9b3e68e70df7ab81ea6cd1488561d7384144a5f703462a3da5eedea33988934e4191496bdcc0a9c52b1adac8fbc926c99afa1e58bfd9423a2e8ca7bacda34d71e7902c07c941b05d3868511e9419ff906885a9c7eb5355644e2513c8b1610c50cbd488a62fc6106b5e59a95339d3f49e3237579773b104eb3fc594fb9a496436443758844a0e56061008719508fada766a7b52f69bf5c72b9b5d089f75c5de58575db78fb10f9cbad9579e1b31d4914e43b1c2d049394c853647d1575b6b31e336d14c98be8731d070fcde355d6164906e3debc19be394caebab84c1412fe98d62f8f0227be390057e028827b63c9dc7758da7fea745626f39730a89165c8b7c4bb27bcc2bbad44687b4d0f52485228223b9b680ad4a22ac9e44f11618dbcea6ababb53c3cc657402bb7e65e99ffc6b8f0d02fb69f9dd59212d0f93f324fbb79f6c56aae69bfe3d428cb9802e1be49c7f49c8664926c6b5cd95f7ba5996e3cecbcd4ef78acbc353c6e3dd922d08055696b9d34f56cd2732ad9ef8babc9b3c7684bf6e564ae06c7edc4b7ed07c17dc46b2b5890c9bd7f963dd8a8d637196ccf6634d51dec2ce3c45e549a961dee05ca72ed58c803a5132bc6846ca8bb174bf50c16d7b136dccce4a90624c67b0631c91944db580d76380a8eda12cc28fda05c2bcc6e649f9a54fe35b85821489ccd76006026184b592942a4159eae214c0a5f5df8cdfbf5ae2a9ed4654cbf70251b3ae7c153c4cd6096675e0e172e44d9dd1ad2b82972c34a644f4c69e751602e25c2280a9bcc90c4e9d43f0c4831b1720340196e1beb2440c420d77dec14b47c9dfd241537f32ca6f95074da807cbe59a1a8f76f9d6518e9d667e1bd69fe4c1615cbbc5bc05327f9e73c2d2799681a0c1758782c542affe5ded9e601a87ae108f0f6a1b56004ec526cc82827b970ebab4cfefdc1b5af5147c4b3cad06da758f9e1a9f75ba1d37d40fd5892e091a72ebd2be38206228cfc01833eee210e46dd1ff68e4c5567c08fca7f7816a09a25292d26709bc43606f72bfa50339438b6527f16837c342165e6dbb96cbf46ff990942affc037f47d9489be3bab952620f441d5bbd29ca0e470af4328cf20b59db841c296787237773c235e971a565e511db019345a5ac02d32a1777b1c3b2b2e16d7c0158194e46fb7ab65e5f9a839dd33cb943de4a4005d5132457a8aa9663b751285347f0c5f268741dfcab819a530f2845530ecad099d6115997cdfe488b0faa1761849c894a90206ad2eba665185813b5422f1fa6ff45aac47cd77a1f527b12b45be0dab52ee1f079057b66993ac2d73e3790669c47dc96d7158fa4d2249f7788672ece9ec35dc988a118ce7afa92127b346cceec56a851ca30e67fa59b802bec888ec268db1fcd3a9dd9b760fa17da07814813249b633ea00ba34906cddd6decc60adba73d64017f4a6cc6381ec916ee8ef71c1a96440a1470f46ef708f41d2396b4a5e10bae611452557dde2d2c0880bfe09b348c7b6935e0a7d7f6a18916204d3f4b23df30526d44a0a89f9203251a6187842dacff605d84a54b33a7002f7a24910c0a3f1054f38e2d85832c2bc8a632ac6b8f99f08b3e000d46702ca7e1fb5ecf39cb730482fdf1a262d46c4d2c7ffc7efa8ffbb19a8345e05695aaa164cf2a4fde37c6310af4dc9aca16e8dda20ebf7747244bcc766e0398b41cc7d16ddd96776d99e816d9211d5b175dded173b272491513792673cfc8cf7d4987e16f6064c4ef6835dd4c571ca16f1435650561cc789f1732f3dcffe7bc88928912bf80ccedf9912dff28367f8f05e98ae1372e0415ede84e9c1a32c8a1c5978985dd0d3b37291e9ce1d0fde6fc41c662b8a7f16aec5c4dcfb220edeb966e033f283691c23393de3b88b620f4482bfb43b6f814c56592644185666c64ba363c9f402bb8472d2ceecf4e7bc1ef16f5c2a3fb916c4722ad6e65632f4412418e8cd65d76e16f4d9da4b9324be9617e8e8d247b60da5f9603149329c2cfcbc0bae87fe71bf6623e2eb7a983aecacadf7a66deebabd7989265f0ca529f8537e61698b06f5e6c5f1a6b4e27df08073106dc34b1afeea3531784c518ff356820f33a139e419d86b40dac01266fb6e21c66031776a738b9fa9f1d45df75864656005d0430b44b0510f234c63480de7cf15ae3cdd3d4c3e0c1f6ae8e0ae1c5aad256283ef44a1184b9640a98976853297e71ffbd726f3f81e85c5d6e58680e3f912685aeccb208779cf1502fcbacfbb3fbf659eaeb3881f446ce709e248afa26ee961a03de2a0b1dafb1471ac06b3fb42cbc59a077a4579615a0d69d2a67d11d1159ee3ee493641a1ed6c639a96ece7b8c3e2eea03c68d58b327da3450a67d4af9fc72c58a7f86d3f6f2d288aff30ed6d2a101239592272bdfe14b9dc6283d203b02b66a3942e06c301e4d93bd8a62d1be90e05eab09c8a3fb01dd9f18eb8761981ea2cdd0d792203feb84146209579cceae9772ec96aa25369dbe15b8b40869ad8375d90de9cd317c808cfc99a2ae48b8f6389b2f57441e31a50aa87ca1e8a0b02e665d7b14e58900d26af5b17c5e6aae17724431ce6e3fc0e127fa1036418f4f40b3eee5088a304461631bda4cc86dd961950ed1357b639f62e1251a8501e1fba2cdee979d357edaf731dbaf93995c2c3f3a3583c664ded19d964b2b9e46e58c0c295bdea60e19579f8ec78c16c590ddf8f4d3258ffced2b42cd9e8f6f58d606c2419361557857fc20a2ef16f2d919bd76df788d7f4fd39612c72076557ff23f356cd7cdc5cfa7e7261eabfa72f1f701e7ec47eb1bcb878a2ca37f4aa2826887c57bc440e0f55985e9ba80b44675b15e5a7d36128cb306aef36dfea1d826ff0631fcbc3c2736447ba1bc517fad7b7081efd2044c98421eaacdcfc7027e13b550d8589c96327d70eccae4d8098ec770e4749329348cf2e0d6f117577e5a63fc0ee1b8a5dcfa9e56d25725387ff69509a0170aa04cd60ab1c8c9094cb5e6eb28274c451d7d8d4b86452a4c86b9ddfe7fd20597f3fd5d2284cdddbfccf962c4bc60f22e3967dfc32ab7791b45abafa2d336310b034a8103b37ba072916d1ed5974af45b8aa6e48542dda3bb72066f0b7b0624bfd6c55a0b7fb366db40ee8490ab8ad1a4061c63570f3e2303b7f83499900c6f47670d27058a0062008654b3df857dea9b4194da7dd9009cc8c9106ae90e2fca1383b800b24a508eaf7e4dd34de0051543c1c0e60af1efbd48775da28538ebfbe1fa64cb13c67119a8ecccb9364bae74081628762586b5ffb2693fb23e7578efc2f7494b1a7d6c066c9949f178f89026e8c3030cb9925c4eeb6908243a7d9986f7eb3902be701e6b2ee7d1e581594f5250e99c6adf716e05a7913be58fc153635d86b04308c3049935398fd7cb1a3ecd0b66552a5fd47d5e20173e6783be3b15d1c094f71c91507333f738fba093a6e2ad694431e02dff19c8cb196cfee0d19a46dbc0214bae08d4af8e4461097aeaf6c3c70e8b1e08dc16e573adc8e7e6ea6e7059deb14c88c2318ec9f01252ce905e34a7165bbe0b05254457efc2952b13c43920fea2e9286767c87a5eb0c505ee406468573548a20aaad37be3fa96c6dd73618cc009b8884126b1f6d43a19dcb0bd5232b492886c533d5b2bb7b13971598ab55c5fc53b2555bd3c69a63f091f78af7cd1fda4a8249bce74723ee79a5433073f978ac699e5d899cc27a2464c19194d2479c479eee77d155c420e4de8bb526b36e6039922ae1240f479a845e6e72250a85fa8dc55a7b0b44f5a145741c3d0fcb42286e8f987e544c26f27ed3f9cd865592a513a32f951bd9ee5dea942c898b3cd5b64a8f6e5c04916f65442b3cfb57aec35943913d1ef784de47fd06fc86f1aa96554d171e96f844ac05f4381a16ccdc3c6f5194ca933fe30ea8ced6e8f27f2506b4e0bf604997492328fa37e73bf16b195a762f11f2bacd276a93c086a2eddf0750189ba47e8a69bc27253f67410ca60d5923f7751ab538dab1e2cd44ff5a10d1f322703c2ab9d25df0b6389fc9d7b308179db91362a5b9a7913ce1ff82a20e585eac97fe129688a243c986948d760a37935d1e030d011a123f6e3660a44dfb8eb183d6683e918604e8a76303f041246037cfaeb42698d63b4afa111f4bcc4b93d88ef18dd4854eae218df6d3a94487327aa2a91876ea023e355638e890a49bfc60e0033bae260767b049cc6d5c4b324f8404ab8789b84da637fde28ef71721165af151deeb35cc47d700258495c6a26534b65519a64268b6a2419dd40375ec48be419ec0f662e8bf01488bd65f6f6cae81ee0eb6c3192af83dd102b54210b665d9cfca62abd2a1abdee4668a9f011b2c394560678fcde8ed25353958bf15181d4f6b0122435f6e767779878da2f1fc16398dccea37588293c7f1000000000000000000000b10152227d2"
+        ),
+        test_passed: false,
+    },
+    // other key
+    SignatureVerifyVector {
+        cose_alg: -49,
+        pk: &hex!(
+            "16de6402d81e7f147ed4cb6ff0766f6cf71c6e0238f8c31c96117e710d64423c26d8c40ac226bb876311abecb1415c76903827953de6513eeb341a76263e760b251674f23a28fb7f53eb7bdbd3c894a97c49b93b684c762469109cd98739376f21747d28da2eb3094adda1bab3af3b6061720a185614977cc78e8bf00cd21a9d3622d6dd3665eccdf28c01ede27d78036f33d0b2a2134e3da15db62556a3b35344aa31c12287f5c5335d28d548e57daf46586576ba8a35993cb2974cc231091cb35d9fbaabdd9a9aa6e95f6dba4693372dad12e6330a4dd438a87a21a9526dc76c6f526a4c1656e9811299213c38690850f3e6971def6803ca8a43241e70830402026472b0dcfd7ec90cde5b6d089b0a1f69a13ede4591b31e1caec319e3bd003d2b9371773701a78b28f91b4ea5ea950d0b95e109ea464ba781a81ec39ff36604cf396e55f552bd9a5676093fadcfe1218b2d83fff938bae1bfbed33a9237ad39cce99237b321320fc1c6b8c1c82cf518273352ba5a07e92412b890f4dc83e8a90d6c5246bb9729419093bae2cca1d63ab40b6fbdf96d7db66855c78c13681fdc14349ab0c61599ff8accc197ce141e81ee73a277c0a7f764d8b7b9abc0404d2882ce83ffa4fed27711489932f87cab536ea75ce6833656b0fb21752077cb35bea3f13ecbf8b409f2f7b60fcc639c09b1c110bab6aaedf87e0e9893c6ea2b54069f18e0467a4cacf55138684fdbc6cce518ed6a27bca6912b4160c9d4bcf3f1ad526af4b7b22ad18d7a9ffa6d292e12fc00e91d526ca54ab2433bee0f3a436ae571fd63d7b9c9ef1fcc1d6c521419324ffd361cfb178faf3cc414fafd8e8b571f7dd5332ff6f1a31aedee935a58cf2e9de90cf7d2fa8af58c8e1b2a67aa6913e517666b74df9cddf47466d9330e36718f0b449f4c60b1d186b3151692cd57736da7c6860e7b826081a57a342c7199701b6d21fd97a693f4f01c5dc360202e88bd1e1a557545ffa50e342d3d834420ee74b676e221475a39793ce457ce4d5ca25c44edc8f98fed6c1cd52bb9d78915b543d6c9b76a1e0380fbbe7e2b4cb35e28f942c6db307bf2333f32c5c718d88700d7674e57adc7d641117ca184e548b6e571f1d421d52b92228d5b77faddb9fa5f6648247b9159b3531f29c0c1b2f197c6de4b6b4893459fff81b91df38954aeb44d5a03ad8c10fbb87d6c1afd9f76677fbd377c3da03e71c43a5ef0b33357ff1c015b0fc949df2a1bb5f49ea5c688930484b80382b1d4601fa2676dd21fce3ffbe10ac3e3fb52f0f15ec2014e2f70447b4feffbd8ec4ec19529da718cb4710bfb5fd7a8ce1ab424a4a5f79c2b3e9d7eaf593e5b3fcb936022ea9f56ff4bac997eba7e5d634df9db609d5757c818853ef3be567eb9045cb3a51dcbc79035ea117a80d656bf710186520ccca1ea212102216ea818715d5039aa4788a31b04b213364cd761987b2d4aef5ad0cde78ae63627241ca4d218d790afd1270586523d23541349c5364e6bb26f0660f4c311c2fe8238163c6fb141895f30ff857a8cde3339f2e3049a8a9a0595b2012949d0b7ee093751efe80ec600b2ce290c22cc569ba9a593a3dfdef8e74218559c9891a1039edb384896a2d03ec191561fe6d3826c2667ba923292d51114823bb9b8e5949cd29feb6cba4ef859957dc2b949a734f2432b1de81f25fd9906f2eaa660e695c1090d59bf7c58bce712cea7522711a599dede1ed899e9d1afbf385e204a393767f64a7faa52214f5728c9500888fa5f792979474fd40788b7a5ced077ca3e9edb4531a1a623f41b22a95209cd55d4bb18bf0b3c35808226390e5755a2a221afd27addc0c24e2da2240c3b448e25ff1d4ec5346d290ae0565c2ced9dc8a206b6b4d68d08fbda60fed5c6b9d02799aecb2f4eb4648fca17549d51661bea9f004045c86f49acb5fe96c11ebff17a519633f6c7dd312bd570c2ba1cd1bfaa77b1067add3a0873292d8de043f26678671c99822628f25103503a5d9d46cd6cd2256bbd6dc40ba2f516cdad91a0db6c6e2524569aebdc4119f3e2968284c2d2e18ca3085a05d2f497f6d299e6d6e0b65bfaba3c371de4fc249a742fc806c814a4b6cb5a03599a21ea11256e081ef9ec1c67ac823691ff0e32b324f1c10285c91fc8685639f726ce3725aa9facd1010d5bdb93a4223b8cd84c81f1dc41d19316ec3f20d730d3ed0d7c1c40555d5a51afc1721a0c903bb98f7c4374773926f5afb05a8a681082126b136ab42fb9606cfd42b7c59a99f0d3d25fd34caedb29f97624ca290bfd7315a8329100290cc6f4ab01773ff8d2f26b6ef0e6bf736d75c369411ec63a4a96366f5079012ff3db346b991d7e289c086303236f8708a5382d51e42fb884bf29c0174a8227d1a1f798292231624b2d15403207b665d4d55ac554ae53e147dd03c6f51d6e36eead2121bf11ac40ad0c7e457a0bdfe8eb0952026e3230e72be1b8dbc8124b11ee08ca078d1410af4f68f02db3647687163b5ba5c8598cae6ffd44e86bde59769553e181d2464c2339ee201b49b4f1862817007a7c342fe00839f65ef1b1e4d4ad13f6891ac7a80d620c8b469f1635e2c68fb224354aae689935c5e55ccaef3104b3cb5696d0879d5f0673629996df55028cc0944aade6e0136e357684ce2e0364e11e418497462a7da6df47b49f7e1edac792139537127a222e789ed6d96453e7f54bea6d58e13f0807fbf3028e68ca2f8c1f3cad393e4c57cf"
+        ),
+        message: b"embedded-cal ML-DSA-65 sigVer message",
+        signature: &hex!(
+            "e89378ed25582e0b83013761351f0d6f0306c70d4bf4d4eabc066ca08afea7921dc5f4dca0ba7045a45b619672290504f6b23d4e9ec2701ae3027dc689d712f775ffe5d25ed564c6ae30a85854de6f1b283d325ed852cc5af5d8b5612c51eccc85503b7be77ec80bc553551402fc292a8a19e45efb9be538d6435cf3920d07f6b388ce774c0c7b557432e0cbcdc5b43cb2a823fd9e1ba4160194f9b3e68e70df7ab81ea6cd1488561d7384144a5f703462a3da5eedea33988934e4191496bdcc0a9c52b1adac8fbc926c99afa1e58bfd9423a2e8ca7bacda34d71e7902c07c941b05d3868511e9419ff906885a9c7eb5355644e2513c8b1610c50cbd488a62fc6106b5e59a95339d3f49e3237579773b104eb3fc594fb9a496436443758844a0e56061008719508fada766a7b52f69bf5c72b9b5d089f75c5de58575db78fb10f9cbad9579e1b31d4914e43b1c2d049394c853647d1575b6b31e336d14c98be8731d070fcde355d6164906e3debc19be394caebab84c1412fe98d62f8f0227be390057e028827b63c9dc7758da7fea745626f39730a89165c8b7c4bb27bcc2bbad44687b4d0f52485228223b9b680ad4a22ac9e44f11618dbcea6ababb53c3cc657402bb7e65e99ffc6b8f0d02fb69f9dd59212d0f93f324fbb79f6c56aae69bfe3d428cb9802e1be49c7f49c8664926c6b5cd95f7ba5996e3cecbcd4ef78acbc353c6e3dd922d08055696b9d34f56cd2732ad9ef8babc9b3c7684bf6e564ae06c7edc4b7ed07c17dc46b2b5890c9bd7f963dd8a8d637196ccf6634d51dec2ce3c45e549a961dee05ca72ed58c803a5132bc6846ca8bb174bf50c16d7b136dccce4a90624c67b0631c91944db580d76380a8eda12cc28fda05c2bcc6e649f9a54fe35b85821489ccd76006026184b592942a4159eae214c0a5f5df8cdfbf5ae2a9ed4654cbf70251b3ae7c153c4cd6096675e0e172e44d9dd1ad2b82972c34a644f4c69e751602e25c2280a9bcc90c4e9d43f0c4831b1720340196e1beb2440c420d77dec14b47c9dfd241537f32ca6f95074da807cbe59a1a8f76f9d6518e9d667e1bd69fe4c1615cbbc5bc05327f9e73c2d2799681a0c1758782c542affe5ded9e601a87ae108f0f6a1b56004ec526cc82827b970ebab4cfefdc1b5af5147c4b3cad06da758f9e1a9f75ba1d37d40fd5892e091a72ebd2be38206228cfc01833eee210e46dd1ff68e4c5567c08fca7f7816a09a25292d26709bc43606f72bfa50339438b6527f16837c342165e6dbb96cbf46ff990942affc037f47d9489be3bab952620f441d5bbd29ca0e470af4328cf20b59db841c296787237773c235e971a565e511db019345a5ac02d32a1777b1c3b2b2e16d7c0158194e46fb7ab65e5f9a839dd33cb943de4a4005d5132457a8aa9663b751285347f0c5f268741dfcab819a530f2845530ecad099d6115997cdfe488b0faa1761849c894a90206ad2eba665185813b5422f1fa6ff45aac47cd77a1f527b12b45be0dab52ee1f079057b66993ac2d73e3790669c47dc96d7158fa4d2249f7788672ece9ec35dc988a118ce7afa92127b346cceec56a851ca30e67fa59b802bec888ec268db1fcd3a9dd9b760fa17da07814813249b633ea00ba34906cddd6decc60adba73d64017f4a6cc6381ec916ee8ef71c1a96440a1470f46ef708f41d2396b4a5e10bae611452557dde2d2c0880bfe09b348c7b6935e0a7d7f6a18916204d3f4b23df30526d44a0a89f9203251a6187842dacff605d84a54b33a7002f7a24910c0a3f1054f38e2d85832c2bc8a632ac6b8f99f08b3e000d46702ca7e1fb5ecf39cb730482fdf1a262d46c4d2c7ffc7efa8ffbb19a8345e05695aaa164cf2a4fde37c6310af4dc9aca16e8dda20ebf7747244bcc766e0398b41cc7d16ddd96776d99e816d9211d5b175dded173b272491513792673cfc8cf7d4987e16f6064c4ef6835dd4c571ca16f1435650561cc789f1732f3dcffe7bc88928912bf80ccedf9912dff28367f8f05e98ae1372e0415ede84e9c1a32c8a1c5978985dd0d3b37291e9ce1d0fde6fc41c662b8a7f16aec5c4dcfb220edeb966e033f283691c23393de3b88b620f4482bfb43b6f814c56592644185666c64ba363c9f402bb8472d2ceecf4e7bc1ef16f5c2a3fb916c4722ad6e65632f4412418e8cd65d76e16f4d9da4b9324be9617e8e8d247b60da5f9603149329c2cfcbc0bae87fe71bf6623e2eb7a983aecacadf7a66deebabd7989265f0ca529f8537e61698b06f5e6c5f1a6b4e27df08073106dc34b1afeea3531784c518ff356820f33a139e419d86b40dac01266fb6e21c66031776a738b9fa9f1d45df75864656005d0430b44b0510f234c63480de7cf15ae3cdd3d4c3e0c1f6ae8e0ae1c5aad256283ef44a1184b9640a98976853297e71ffbd726f3f81e85c5d6e58680e3f912685aeccb208779cf1502fcbacfbb3fbf659eaeb3881f446ce709e248afa26ee961a03de2a0b1dafb1471ac06b3fb42cbc59a077a4579615a0d69d2a67d11d1159ee3ee493641a1ed6c639a96ece7b8c3e2eea03c68d58b327da3450a67d4af9fc72c58a7f86d3f6f2d288aff30ed6d2a101239592272bdfe14b9dc6283d203b02b66a3942e06c301e4d93bd8a62d1be90e05eab09c8a3fb01dd9f18eb8761981ea2cdd0d792203feb84146209579cceae9772ec96aa25369dbe15b8b40869ad8375d90de9cd317c808cfc99a2ae48b8f6389b2f57441e31a50aa87ca1e8a0b02e665d7b14e58900d26af5b17c5e6aae17724431ce6e3fc0e127fa1036418f4f40b3eee5088a304461631bda4cc86dd961950ed1357b639f62e1251a8501e1fba2cdee979d357edaf731dbaf93995c2c3f3a3583c664ded19d964b2b9e46e58c0c295bdea60e19579f8ec78c16c590ddf8f4d3258ffced2b42cd9e8f6f58d606c2419361557857fc20a2ef16f2d919bd76df788d7f4fd39612c72076557ff23f356cd7cdc5cfa7e7261eabfa72f1f701e7ec47eb1bcb878a2ca37f4aa2826887c57bc440e0f55985e9ba80b44675b15e5a7d36128cb306aef36dfea1d826ff0631fcbc3c2736447ba1bc517fad7b7081efd2044c98421eaacdcfc7027e13b550d8589c96327d70eccae4d8098ec770e4749329348cf2e0d6f117577e5a63fc0ee1b8a5dcfa9e56d25725387ff69509a0170aa04cd60ab1c8c9094cb5e6eb28274c451d7d8d4b86452a4c86b9ddfe7fd20597f3fd5d2284cdddbfccf962c4bc60f22e3967dfc32ab7791b45abafa2d336310b034a8103b37ba072916d1ed5974af45b8aa6e48542dda3bb72066f0b7b0624bfd6c55a0b7fb366db40ee8490ab8ad1a4061c63570f3e2303b7f83499900c6f47670d27058a0062008654b3df857dea9b4194da7dd9009cc8c9106ae90e2fca1383b800b24a508eaf7e4dd34de0051543c1c0e60af1efbd48775da28538ebfbe1fa64cb13c67119a8ecccb9364bae74081628762586b5ffb2693fb23e7578efc2f7494b1a7d6c066c9949f178f89026e8c3030cb9925c4eeb6908243a7d9986f7eb3902be701e6b2ee7d1e581594f5250e99c6adf716e05a7913be58fc153635d86b04308c3049935398fd7cb1a3ecd0b66552a5fd47d5e20173e6783be3b15d1c094f71c91507333f738fba093a6e2ad694431e02dff19c8cb196cfee0d19a46dbc0214bae08d4af8e4461097aeaf6c3c70e8b1e08dc16e573adc8e7e6ea6e7059deb14c88c2318ec9f01252ce905e34a7165bbe0b05254457efc2952b13c43920fea2e9286767c87a5eb0c505ee406468573548a20aaad37be3fa96c6dd73618cc009b8884126b1f6d43a19dcb0bd5232b492886c533d5b2bb7b13971598ab55c5fc53b2555bd3c69a63f091f78af7cd1fda4a8249bce74723ee79a5433073f978ac699e5d899cc27a2464c19194d2479c479eee77d155c420e4de8bb526b36e6039922ae1240f479a845e6e72250a85fa8dc55a7b0b44f5a145741c3d0fcb42286e8f987e544c26f27ed3f9cd865592a513a32f951bd9ee5dea942c898b3cd5b64a8f6e5c04916f65442b3cfb57aec35943913d1ef784de47fd06fc86f1aa96554d171e96f844ac05f4381a16ccdc3c6f5194ca933fe30ea8ced6e8f27f2506b4e0bf604997492328fa37e73bf16b195a762f11f2bacd276a93c086a2eddf0750189ba47e8a69bc27253f67410ca60d5923f7751ab538dab1e2cd44ff5a10d1f322703c2ab9d25df0b6389fc9d7b308179db91362a5b9a7913ce1ff82a20e585eac97fe129688a243c986948d760a37935d1e030d011a123f6e3660a44dfb8eb183d6683e918604e8a76303f041246037cfaeb42698d63b4afa111f4bcc4b93d88ef18dd4854eae218df6d3a94487327aa2a91876ea023e355638e890a49bfc60e0033bae260767b049cc6d5c4b324f8404ab8789b84da637fde28ef71721165af151deeb35cc47d700258495c6a26534b65519a64268b6a2419dd40375ec48be419ec0f662e8bf01488bd65f6f6cae81ee0eb6c3192af83dd102b54210b665d9cfca62abd2a1abdee4668a9f011b2c394560678fcde8ed25353958bf15181d4f6b0122435f6e767779878da2f1fc16398dccea37588293c7f1000000000000000000000b101522272d"
+        ),
+        test_passed: false,
+    },
+];
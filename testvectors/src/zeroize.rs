@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// SPDX-FileCopyrightText: Inria-AIO, Cryspen, and Christian Amsüss
+
+//! Helpers for checking that secrets are wiped from memory.
+
+use core::mem::MaybeUninit;
+
+/// Drops `value`, and returns what is left afterwards in the memory of the array that `secret`
+/// selects from it.
+///
+/// The value is dropped in place, so the memory is still reserved (and not reused) when it is
+/// read.
+pub fn memory_after_drop<T, const N: usize>(
+    value: T,
+    secret: impl FnOnce(&T) -> &[u8; N],
+) -> [u8; N] {
+    let mut slot = MaybeUninit::new(value);
+    let start = slot.as_ptr().cast::<u8>();
+    // SAFETY: The slot was just initialized.
+    let secret: *const u8 = secret(unsafe { slot.assume_init_ref() }).as_ptr();
+    // Pointers into the value are not used across the drop, just their position.
+    let offset = secret as usize - start as usize;
+    assert!(
+        offset + N <= size_of::<T>(),
+        "secret needs to be inline in the value"
+    );
+
+    // SAFETY: The slot is still initialized; it is not read as a `T` any more after this.
+    unsafe { slot.assume_init_drop() };
+    // SAFETY: The range is inside the slot (as checked above), and was initialized as a byte array
+    // before the drop; dropping does not uninitialize memory.
+    unsafe { core::ptr::read_volatile(slot.as_ptr().cast::<u8>().add(offset).cast::<[u8; N]>()) }
+}